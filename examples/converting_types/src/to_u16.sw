@@ -1,5 +1,7 @@
 library;
 
+use std::convert::ConversionError;
+
 pub fn convert_uint_to_u16() {
     // Convert any unsigned integer to `u16`
     // ANCHOR: to_u16
@@ -11,11 +13,11 @@ pub fn convert_uint_to_u16() {
     let u16_from_u8: u16 = u8_1.as_u16();
 
     let u16_from_u32_1: Option<u16> = u32_1.try_as_u16();
-    let u16_from_u32_2: Option<u16> = <u16 as TryFrom<u32>>::try_from(u32_1);
+    let u16_from_u32_2: Result<u16, ConversionError> = <u16 as TryFrom<u32>>::try_from(u32_1);
 
     let u16_from_u64_1: Option<u16> = u64_1.try_as_u16();
-    let u16_from_u64_2: Option<u16> = <u16 as TryFrom<u64>>::try_from(u64_1);
+    let u16_from_u64_2: Result<u16, ConversionError> = <u16 as TryFrom<u64>>::try_from(u64_1);
 
-    let u16_from_u256: Option<u16> = <u16 as TryFrom<u256>>::try_from(u256_1);
+    let u16_from_u256: Result<u16, ConversionError> = <u16 as TryFrom<u256>>::try_from(u256_1);
     // ANCHOR_END: to_u16
 }
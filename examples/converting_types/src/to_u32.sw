@@ -1,5 +1,7 @@
 library;
 
+use std::convert::ConversionError;
+
 pub fn convert_uint_to_u32() {
     // Convert any unsigned integer to `u32`
     // ANCHOR: to_u32
@@ -13,8 +15,8 @@ pub fn convert_uint_to_u32() {
     let u32_from_u16: u32 = u16_1.as_u32();
 
     let u32_from_u64_1: Option<u32> = u64_1.try_as_u32();
-    let u32_from_u64_2: Option<u32> = <u32 as TryFrom<u64>>::try_from(u64_1);
+    let u32_from_u64_2: Result<u32, ConversionError> = <u32 as TryFrom<u64>>::try_from(u64_1);
 
-    let u32_from_u256: Option<u32> = <u32 as TryFrom<u256>>::try_from(u256_1);
+    let u32_from_u256: Result<u32, ConversionError> = <u32 as TryFrom<u256>>::try_from(u256_1);
     // ANCHOR_END: to_u32
 }
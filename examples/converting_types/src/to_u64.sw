@@ -1,5 +1,7 @@
 library;
 
+use std::convert::ConversionError;
+
 pub fn convert_uint_to_u64() {
     // Convert any unsigned integer to `u64`
     // ANCHOR: to_u64
@@ -14,6 +16,6 @@ pub fn convert_uint_to_u64() {
 
     let u64_from_u32: u64 = u32_1.as_u64();
 
-    let u64_from_u256: Option<u64> = <u64 as TryFrom<u256>>::try_from(u256_1);
+    let u64_from_u256: Result<u64, ConversionError> = <u64 as TryFrom<u256>>::try_from(u256_1);
     // ANCHOR_END: to_u64
 }
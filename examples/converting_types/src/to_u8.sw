@@ -1,5 +1,7 @@
 library;
 
+use std::convert::ConversionError;
+
 pub fn convert_uint_to_u8() {
     // Convert any unsigned integer to `u8`
     // ANCHOR: to_u8
@@ -9,15 +11,15 @@ pub fn convert_uint_to_u8() {
     let u256_1: u256 = 0x0000000000000000000000000000000000000000000000000000000000000002u256;
 
     let u8_from_u16_1: Option<u8> = u16_1.try_as_u8();
-    let u8_from_u16_2: Option<u8> = <u8 as TryFrom<u16>>::try_from(u16_1);
+    let u8_from_u16_2: Result<u8, ConversionError> = <u8 as TryFrom<u16>>::try_from(u16_1);
 
     let u8_from_u32_1: Option<u8> = u32_1.try_as_u8();
-    let u8_from_u32_2: Option<u8> = <u8 as TryFrom<u32>>::try_from(u32_1);
+    let u8_from_u32_2: Result<u8, ConversionError> = <u8 as TryFrom<u32>>::try_from(u32_1);
 
     let u8_from_u64_1: Option<u8> = u64_1.try_as_u8();
-    let u8_from_u64_2: Option<u8> = <u8 as TryFrom<u64>>::try_from(u64_1);
+    let u8_from_u64_2: Result<u8, ConversionError> = <u8 as TryFrom<u64>>::try_from(u64_1);
 
-    let u8_from_u256: Option<u8> = <u8 as TryFrom<u256>>::try_from(u256_1);
+    let u8_from_u256: Result<u8, ConversionError> = <u8 as TryFrom<u256>>::try_from(u256_1);
     // ANCHOR_END: to_u8
     assert(u8_from_u16_1.unwrap() == 2u8);
     assert(u8_from_u16_2.unwrap() == 2u8);
@@ -27,3 +29,12 @@ pub fn convert_uint_to_u8() {
     assert(u8_from_u64_2.unwrap() == 2u8);
     assert(u8_from_u256.unwrap() == 2u8);
 }
+
+pub fn try_into_u8() {
+    // ANCHOR: try_into_u8
+    let fits: Result<u8, ConversionError> = 255u64.try_into();
+    let overflows: Result<u8, ConversionError> = 256u64.try_into();
+    // ANCHOR_END: try_into_u8
+    assert(fits.unwrap() == 255u8);
+    assert(overflows == Err(ConversionError::Overflow));
+}
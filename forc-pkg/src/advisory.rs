@@ -0,0 +1,329 @@
+//! Checking a resolved package graph against a database of known security advisories.
+//!
+//! An advisory database is a TOML file containing a list of `[[advisory]]` entries. Each advisory
+//! targets a package by name and source and declares the range of affected versions:
+//!
+//! ```toml
+//! [[advisory]]
+//! id = "FORC-2024-0001"
+//! package = "std"
+//! source = "git+https://github.com/fuellabs/sway"
+//! severity = "high"
+//! title = "Unchecked overflow in `pow`"
+//! url = "https://example.com/advisories/FORC-2024-0001"
+//! affected = "<0.49.2"
+//! patched = "0.49.2"
+//! ```
+
+use crate::{source, Graph, Pinned};
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+/// The file name of the locally cached advisory database within the user's `.forc` directory.
+pub const DB_FILE_NAME: &str = "advisory-db.toml";
+
+/// The default location of the local advisory database, `$HOME/.forc/advisory-db.toml`.
+pub fn default_db_path() -> PathBuf {
+    forc_util::user_forc_directory().join(DB_FILE_NAME)
+}
+
+/// How severe the impact of an advisory is.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+/// A single advisory affecting some versions of a package.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Advisory {
+    /// The unique identifier of the advisory, e.g. `FORC-2024-0001`.
+    pub id: String,
+    /// The name of the affected package.
+    pub package: String,
+    /// The source of the affected package in the form produced by [source_key].
+    pub source: String,
+    pub severity: Severity,
+    /// A short, single line description of the issue.
+    pub title: String,
+    /// A link to the full description of the advisory.
+    pub url: Option<String>,
+    /// The range of affected versions.
+    pub affected: semver::VersionReq,
+    /// The first version in which the issue is fixed, if any.
+    pub patched: Option<semver::Version>,
+}
+
+/// A collection of advisories.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Database {
+    #[serde(default, rename = "advisory")]
+    pub advisories: Vec<Advisory>,
+}
+
+/// An advisory that applies to a package within the graph.
+#[derive(Clone, Debug)]
+pub struct Finding<'a> {
+    /// The affected package.
+    pub pkg: &'a Pinned,
+    /// The version of the affected package.
+    pub version: semver::Version,
+    pub advisory: &'a Advisory,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl std::str::FromStr for Database {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let db: Self = toml::from_str(s).map_err(|e| anyhow!("{}", e))?;
+        Ok(db)
+    }
+}
+
+impl Database {
+    /// Read the advisory database at the given path.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read advisory database {}", path.display()))?;
+        contents
+            .parse()
+            .with_context(|| format!("failed to parse advisory database {}", path.display()))
+    }
+
+    /// Fetch the advisory database from the given URL and cache it at `dst`.
+    pub fn fetch(url: &str, dst: &Path) -> Result<Self> {
+        let contents = futures::executor::block_on(async {
+            let res = reqwest::get(url).await?;
+            if !res.status().is_success() {
+                bail!(
+                    "failed to fetch advisory database from {url:?}: {}",
+                    res.status()
+                );
+            }
+            Ok(res.text().await?)
+        })?;
+        let db: Self = contents
+            .parse()
+            .with_context(|| format!("failed to parse advisory database fetched from {url:?}"))?;
+        if let Some(dir) = dst.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(dst, contents)
+            .with_context(|| format!("failed to write advisory database {}", dst.display()))?;
+        Ok(db)
+    }
+
+    /// Collect all advisories that apply to the packages within the given graph.
+    ///
+    /// Advisories whose ID is contained within `ignore` are skipped. Packages whose version cannot
+    /// be determined from their source (e.g. path dependencies) are never reported. Findings are
+    /// ordered by descending severity.
+    pub fn audit<'a>(&'a self, graph: &'a Graph, ignore: &[String]) -> Vec<Finding<'a>> {
+        let mut findings = vec![];
+        for pkg in graph.node_weights() {
+            let (Some(key), Some(version)) = (source_key(&pkg.source), source_version(&pkg.source))
+            else {
+                continue;
+            };
+            findings.extend(
+                self.advisories
+                    .iter()
+                    .filter(|advisory| {
+                        advisory.package == pkg.name
+                            && normalize_source(&advisory.source) == key
+                            && advisory.affected.matches(&version)
+                            && !ignore.contains(&advisory.id)
+                    })
+                    .map(|advisory| Finding {
+                        pkg,
+                        version: version.clone(),
+                        advisory,
+                    }),
+            );
+        }
+        findings.sort_by(|a, b| {
+            b.advisory
+                .severity
+                .cmp(&a.advisory.severity)
+                .then_with(|| a.advisory.id.cmp(&b.advisory.id))
+                .then_with(|| a.pkg.name.cmp(&b.pkg.name))
+        });
+        findings
+    }
+}
+
+/// The key by which advisories refer to the given source.
+///
+/// Returns `None` for local member and path sources, as these are not published.
+pub fn source_key(source: &source::Pinned) -> Option<String> {
+    match source {
+        source::Pinned::Member(_) | source::Pinned::Path(_) => None,
+        source::Pinned::Git(git) => Some(normalize_source(&format!(
+            "{}+{}",
+            source::git::Pinned::PREFIX,
+            git.source.repo
+        ))),
+        source::Pinned::Ipfs(ipfs) => Some(ipfs.to_string()),
+        source::Pinned::Registry(_) => Some("registry".to_string()),
+    }
+}
+
+/// The version of the package pinned by the given source, if it can be determined.
+///
+/// Git sources have a version only when pinned to a tag that is a valid semver version, with an
+/// optional `v` prefix.
+pub fn source_version(source: &source::Pinned) -> Option<semver::Version> {
+    match source {
+        source::Pinned::Git(git) => match &git.source.reference {
+            source::git::Reference::Tag(tag) => tag.trim_start_matches('v').parse().ok(),
+            _ => None,
+        },
+        source::Pinned::Registry(reg) => Some(reg.version.clone()),
+        source::Pinned::Member(_) | source::Pinned::Path(_) | source::Pinned::Ipfs(_) => None,
+    }
+}
+
+/// Normalize a source key so that trivially different forms of the same URL compare equal.
+fn normalize_source(source: &str) -> String {
+    source
+        .trim()
+        .trim_end_matches('/')
+        .trim_end_matches(".git")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DB: &str = r#"
+        [[advisory]]
+        id = "FORC-0001"
+        package = "std"
+        source = "git+https://github.com/fuellabs/sway.git"
+        severity = "medium"
+        title = "Medium issue"
+        affected = "<0.2.0"
+        patched = "0.2.0"
+
+        [[advisory]]
+        id = "FORC-0002"
+        package = "std"
+        source = "git+https://github.com/fuellabs/sway"
+        severity = "critical"
+        title = "Critical issue"
+        url = "https://example.com/FORC-0002"
+        affected = ">=0.1.0, <0.1.5"
+
+        [[advisory]]
+        id = "FORC-0003"
+        package = "other"
+        source = "git+https://github.com/fuellabs/sway"
+        severity = "high"
+        title = "Other package"
+        affected = "*"
+    "#;
+
+    fn git_pkg(name: &str, repo: &str, tag: &str) -> Pinned {
+        let source = source::git::Source {
+            repo: repo.parse().unwrap(),
+            reference: source::git::Reference::Tag(tag.to_string()),
+        };
+        Pinned {
+            name: name.to_string(),
+            source: source::Pinned::Git(source::git::Pinned {
+                source,
+                commit_hash: "0".repeat(40),
+            }),
+        }
+    }
+
+    fn graph_of(pkgs: Vec<Pinned>) -> Graph {
+        let mut graph = Graph::default();
+        for pkg in pkgs {
+            graph.add_node(pkg);
+        }
+        graph
+    }
+
+    fn graph_with_tag(tag: &str) -> Graph {
+        graph_of(vec![git_pkg(
+            "std",
+            "https://github.com/fuellabs/sway",
+            tag,
+        )])
+    }
+
+    fn ids(findings: &[Finding]) -> Vec<String> {
+        findings.iter().map(|f| f.advisory.id.clone()).collect()
+    }
+
+    #[test]
+    fn parse_database() {
+        let db: Database = DB.parse().unwrap();
+        assert_eq!(db.advisories.len(), 3);
+        assert_eq!(db.advisories[1].severity, Severity::Critical);
+        assert_eq!(
+            db.advisories[0].patched,
+            Some(semver::Version::new(0, 2, 0))
+        );
+        assert_eq!(db.advisories[1].patched, None);
+    }
+
+    #[test]
+    fn audit_matches_source_and_version() {
+        let db: Database = DB.parse().unwrap();
+        let graph = graph_of(vec![git_pkg(
+            "std",
+            "https://github.com/FuelLabs/sway/",
+            "v0.1.2",
+        )]);
+        let findings = db.audit(&graph, &[]);
+        assert_eq!(ids(&findings), vec!["FORC-0002", "FORC-0001"]);
+        assert_eq!(findings[0].version, semver::Version::new(0, 1, 2));
+
+        let graph = graph_with_tag("v0.1.7");
+        assert_eq!(ids(&db.audit(&graph, &[])), vec!["FORC-0001"]);
+
+        let graph = graph_with_tag("v0.2.0");
+        assert!(db.audit(&graph, &[]).is_empty());
+    }
+
+    #[test]
+    fn audit_ignores_other_sources() {
+        let db: Database = DB.parse().unwrap();
+        let graph = graph_of(vec![git_pkg(
+            "std",
+            "https://github.com/someone/sway",
+            "v0.1.2",
+        )]);
+        assert!(db.audit(&graph, &[]).is_empty());
+    }
+
+    #[test]
+    fn audit_respects_ignored_ids() {
+        let db: Database = DB.parse().unwrap();
+        let graph = graph_with_tag("v0.1.2");
+        let findings = db.audit(&graph, &["FORC-0002".to_string()]);
+        assert_eq!(ids(&findings), vec!["FORC-0001"]);
+    }
+}
@@ -0,0 +1,265 @@
+//! Serializing the resolved package dependency graph for documentation and debugging.
+//!
+//! The graph can be written either in GraphViz DOT format, suitable for rendering with
+//! `dot -Tpng`, or as JSON. Packages that appear in the graph more than once with different
+//! sources are marked as duplicates, as they are compiled separately and usually indicate
+//! conflicting dependency declarations.
+
+use crate::{DepKind, Graph, NodeIx};
+use anyhow::Result;
+use petgraph::visit::{Bfs, EdgeRef};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+
+/// A package within the serialized dependency graph.
+#[derive(Clone, Debug, Serialize)]
+pub struct GraphPackage {
+    /// The unique ID of the package, produced from its name and pinned source.
+    pub id: String,
+    pub name: String,
+    /// The pinned source of the package.
+    pub source: String,
+    /// Whether the package appears in the graph with more than one source.
+    pub duplicate: bool,
+}
+
+/// A dependency relation between two packages within the serialized dependency graph.
+#[derive(Clone, Debug, Serialize)]
+pub struct GraphDependency {
+    /// The ID of the dependent package.
+    pub from: String,
+    /// The ID of the package that is depended upon.
+    pub to: String,
+    /// The name of the dependency as declared in the dependent package's manifest.
+    pub name: String,
+    /// Either `library` or `contract`.
+    pub kind: String,
+}
+
+/// The dependency graph in the form in which it is serialized to JSON.
+#[derive(Clone, Debug, Serialize)]
+pub struct GraphExport {
+    pub packages: Vec<GraphPackage>,
+    pub dependencies: Vec<GraphDependency>,
+}
+
+impl GraphExport {
+    /// Collect the packages and dependencies of the given graph.
+    ///
+    /// If a `root` is specified, only the root and the packages it transitively depends upon are
+    /// included.
+    pub fn new(graph: &Graph, root: Option<NodeIx>) -> Self {
+        let nodes = included_nodes(graph, root);
+        let duplicates = duplicate_names(graph, &nodes);
+        let packages = nodes
+            .iter()
+            .map(|&node| {
+                let pkg = &graph[node];
+                GraphPackage {
+                    id: pkg.id().to_string(),
+                    name: pkg.name.clone(),
+                    source: pkg.source.to_string(),
+                    duplicate: duplicates.contains(&pkg.name),
+                }
+            })
+            .collect();
+        let dependencies = nodes
+            .iter()
+            .flat_map(|&node| graph.edges(node))
+            .map(|edge| GraphDependency {
+                from: graph[edge.source()].id().to_string(),
+                to: graph[edge.target()].id().to_string(),
+                name: edge.weight().name.clone(),
+                kind: match edge.weight().kind {
+                    DepKind::Library => "library".to_string(),
+                    DepKind::Contract { .. } => "contract".to_string(),
+                },
+            })
+            .collect();
+        Self {
+            packages,
+            dependencies,
+        }
+    }
+
+    /// Serialize the graph to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Serialize the graph in GraphViz DOT format.
+    ///
+    /// Nodes are labelled with the package name and source. Duplicate packages are highlighted in
+    /// red and contract dependencies are drawn with dashed edges.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph dependencies {\n    node [shape = box];\n");
+        for pkg in &self.packages {
+            let highlight = if pkg.duplicate {
+                ", color = red, fontcolor = red"
+            } else {
+                ""
+            };
+            dot.push_str(&format!(
+                "    \"{}\" [label = \"{}\\n{}\"{highlight}];\n",
+                pkg.id,
+                escape(&pkg.name),
+                escape(&pkg.source),
+            ));
+        }
+        for dep in &self.dependencies {
+            let style = if dep.kind == "contract" {
+                ", style = dashed"
+            } else {
+                ""
+            };
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label = \"{}\"{style}];\n",
+                dep.from,
+                dep.to,
+                escape(&dep.name),
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// The nodes to include in the serialized graph, in BFS order from the root if one is specified
+/// and in index order otherwise.
+fn included_nodes(graph: &Graph, root: Option<NodeIx>) -> Vec<NodeIx> {
+    match root {
+        Some(root) => {
+            let mut bfs = Bfs::new(graph, root);
+            std::iter::from_fn(|| bfs.next(graph)).collect()
+        }
+        None => graph.node_indices().collect(),
+    }
+}
+
+/// The names of the packages that appear among the given nodes with more than one source.
+fn duplicate_names(graph: &Graph, nodes: &[NodeIx]) -> BTreeSet<String> {
+    let mut sources: HashMap<&str, BTreeSet<String>> = HashMap::new();
+    for &node in nodes {
+        let pkg = &graph[node];
+        sources
+            .entry(pkg.name.as_str())
+            .or_default()
+            .insert(pkg.source.to_string());
+    }
+    sources
+        .into_iter()
+        .filter(|(_, sources)| sources.len() > 1)
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// Escape a string for use within a quoted DOT identifier.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{source, Edge, Pinned, PinnedId};
+
+    fn path_pkg(name: &str, path_root: PinnedId) -> Pinned {
+        Pinned {
+            name: name.to_string(),
+            source: source::Pinned::Path(source::path::Pinned { path_root }),
+        }
+    }
+
+    /// Produces the following graph, in which `shared` is depended upon from two path roots:
+    ///
+    /// ```ignore
+    /// app -> lib_a -> shared
+    ///     -> shared'
+    /// other -> contract (contract dependency)
+    /// ```
+    fn test_graph() -> (Graph, NodeIx) {
+        let mut graph = Graph::default();
+        let app = Pinned {
+            name: "app".to_string(),
+            source: source::Pinned::MEMBER,
+        };
+        let lib_a = path_pkg("lib_a", app.id());
+        let shared = path_pkg("shared", lib_a.id());
+        let shared_alt = path_pkg("shared", app.id());
+        let other = Pinned {
+            name: "other".to_string(),
+            source: source::Pinned::MEMBER,
+        };
+        let contract = path_pkg("contract", other.id());
+        let app = graph.add_node(app);
+        let lib_a = graph.add_node(lib_a);
+        let shared = graph.add_node(shared);
+        let shared_alt = graph.add_node(shared_alt);
+        let other = graph.add_node(other);
+        let contract = graph.add_node(contract);
+        graph.add_edge(app, lib_a, Edge::new("lib_a".into(), DepKind::Library));
+        graph.add_edge(lib_a, shared, Edge::new("shared".into(), DepKind::Library));
+        graph.add_edge(
+            app,
+            shared_alt,
+            Edge::new("shared".into(), DepKind::Library),
+        );
+        graph.add_edge(
+            other,
+            contract,
+            Edge::new(
+                "contract".into(),
+                DepKind::Contract {
+                    salt: Default::default(),
+                },
+            ),
+        );
+        (graph, app)
+    }
+
+    #[test]
+    fn export_marks_duplicates() {
+        let (graph, _) = test_graph();
+        let export = GraphExport::new(&graph, None);
+        assert_eq!(export.packages.len(), 6);
+        assert_eq!(export.dependencies.len(), 4);
+        let duplicates: Vec<_> = export
+            .packages
+            .iter()
+            .filter(|pkg| pkg.duplicate)
+            .map(|pkg| pkg.name.as_str())
+            .collect();
+        assert_eq!(duplicates, ["shared", "shared"]);
+    }
+
+    #[test]
+    fn export_from_root() {
+        let (graph, app) = test_graph();
+        let export = GraphExport::new(&graph, Some(app));
+        let names: BTreeSet<_> = export
+            .packages
+            .iter()
+            .map(|pkg| pkg.name.as_str())
+            .collect();
+        assert_eq!(names, BTreeSet::from(["app", "lib_a", "shared"]));
+        assert_eq!(export.dependencies.len(), 3);
+        assert!(export.dependencies.iter().all(|dep| dep.kind == "library"));
+    }
+
+    #[test]
+    fn dot_output() {
+        let (graph, _) = test_graph();
+        let dot = GraphExport::new(&graph, None).to_dot();
+        assert!(dot.starts_with("digraph dependencies {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert_eq!(dot.matches("fontcolor = red").count(), 2);
+        assert_eq!(dot.matches(" -> ").count(), 4);
+        assert_eq!(dot.matches("style = dashed").count(), 1);
+        assert!(dot.contains("[label = \"app\\nmember\"];"));
+    }
+
+    #[test]
+    fn dot_escapes_labels() {
+        assert_eq!(escape(r#"a"b\c"#), r#"a\"b\\c"#);
+    }
+}
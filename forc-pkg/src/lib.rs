@@ -4,10 +4,13 @@
 //! The project should consist of one or more Sway modules under a `src` directory. It may also
 //! declare a set of forc package dependencies within its manifest.
 
+pub mod advisory;
+pub mod graph;
 pub mod lock;
 pub mod manifest;
 mod pkg;
 pub mod source;
+pub mod vendor;
 
 pub use lock::Lock;
 pub use manifest::{
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use sway_core::{OptLevel, PrintAsm, PrintIr};
+use sway_core::{EmitKind, OptLevel, OverflowBehavior, PrintAsm, PrintIr};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "kebab-case")]
@@ -40,6 +40,20 @@ pub struct BuildProfile {
     #[serde(default)]
     pub optimization_level: OptLevel,
     #[serde(default)]
+    pub overflow: OverflowBehavior,
+    #[serde(default, alias = "warn_redundant_clone")]
+    pub warn_redundant_clone: bool,
+    #[serde(default, alias = "const_eval_limit")]
+    pub const_eval_limit: Option<usize>,
+    #[serde(default, alias = "max_function_parameters")]
+    pub max_function_parameters: Option<usize>,
+    #[serde(default, alias = "max_cyclomatic_complexity")]
+    pub max_cyclomatic_complexity: Option<usize>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    #[serde(default)]
+    pub emit: Vec<EmitKind>,
+    #[serde(default)]
     pub experimental: ExperimentalFlags,
 }
 
@@ -65,6 +79,13 @@ impl BuildProfile {
             error_on_warnings: false,
             reverse_results: false,
             optimization_level: OptLevel::Opt0,
+            overflow: OverflowBehavior::Trap,
+            warn_redundant_clone: false,
+            const_eval_limit: None,
+            max_function_parameters: None,
+            max_cyclomatic_complexity: None,
+            features: vec![],
+            emit: vec![],
             experimental: ExperimentalFlags {
                 new_encoding: false,
             },
@@ -88,6 +109,13 @@ impl BuildProfile {
             error_on_warnings: false,
             reverse_results: false,
             optimization_level: OptLevel::Opt1,
+            overflow: OverflowBehavior::Trap,
+            warn_redundant_clone: false,
+            const_eval_limit: None,
+            max_function_parameters: None,
+            max_cyclomatic_complexity: None,
+            features: vec![],
+            emit: vec![],
             experimental: ExperimentalFlags {
                 new_encoding: false,
             },
@@ -103,7 +131,7 @@ impl Default for BuildProfile {
 
 #[cfg(test)]
 mod tests {
-    use sway_core::{OptLevel, PrintAsm, PrintIr};
+    use sway_core::{EmitKind, OptLevel, OverflowBehavior, PrintAsm, PrintIr};
 
     use crate::{manifest::build_profile::ExperimentalFlags, BuildProfile, PackageManifest};
 
@@ -160,6 +188,13 @@ mod tests {
             error_on_warnings: true,
             reverse_results: true,
             optimization_level: OptLevel::Opt0,
+            overflow: OverflowBehavior::Wrap,
+            warn_redundant_clone: true,
+            const_eval_limit: Some(1000),
+            max_function_parameters: Some(7),
+            max_cyclomatic_complexity: Some(10),
+            features: vec!["extra".into()],
+            emit: vec![EmitKind::Ir, EmitKind::TypedAst],
             experimental: ExperimentalFlags { new_encoding: true },
         };
         let profile = build_profiles.get("release").expect("release profile");
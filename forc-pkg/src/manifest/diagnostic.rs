@@ -0,0 +1,369 @@
+//! Span-aware deserialization of manifests.
+//!
+//! Errors and warnings produced while deserializing a `Forc.toml` point at the line and column of
+//! the offending item, along with a snippet of the surrounding source.
+//!
+//! Before deserializing, references to environment variables of the form `${VAR}` or
+//! `${VAR:-default}` within string values are substituted with the values of the variables.
+
+use anyhow::{anyhow, bail, Result};
+use serde::de::DeserializeOwned;
+use std::{borrow::Cow, ops::Range, path::Path};
+
+/// A segment within the path to an item in a TOML document.
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Deserialize a manifest of type `T` from the TOML source read from the file at `path`.
+///
+/// Keys that are not used by `T` do not produce an error. Instead, a warning pointing at each of
+/// the unused keys is returned alongside the manifest so that the caller may decide whether or
+/// not to display them.
+pub(crate) fn deserialize<T: DeserializeOwned>(
+    path: &Path,
+    manifest_str: &str,
+) -> Result<(T, Vec<String>)> {
+    let manifest_str = &*substitute_env_vars(path, manifest_str, &|name| std::env::var(name).ok())?;
+    let mut unused = vec![];
+    let toml_de = toml::de::Deserializer::new(manifest_str);
+    let manifest: T = serde_ignored::deserialize(toml_de, |key_path| {
+        unused.push((key_path.to_string(), segments(&key_path)));
+    })
+    .map_err(|e| {
+        let location = e
+            .span()
+            .map(|span| (span.clone(), location(manifest_str, span)));
+        match location {
+            Some((span, (line, col))) => anyhow!(
+                "failed to parse manifest at {}:{line}:{col}: {}\n{}",
+                path.display(),
+                e.message(),
+                snippet(manifest_str, span),
+            ),
+            None => anyhow!(
+                "failed to parse manifest at {}: {}",
+                path.display(),
+                e.message()
+            ),
+        }
+    })?;
+
+    // The manifest deserialized successfully, so the document is known to be valid TOML.
+    let doc = toml_edit::ImDocument::parse(manifest_str).ok();
+    let warnings = unused
+        .into_iter()
+        .map(|(key_path, segments)| {
+            let span = doc
+                .as_ref()
+                .and_then(|doc| item_span(doc.as_item(), segments.as_deref()?));
+            match span {
+                Some(span) => {
+                    let (line, col) = location(manifest_str, span.clone());
+                    format!(
+                        "{}:{line}:{col}: unused manifest key: {key_path}\n{}",
+                        path.display(),
+                        snippet(manifest_str, span),
+                    )
+                }
+                None => format!("{}: unused manifest key: {key_path}", path.display()),
+            }
+        })
+        .collect();
+    Ok((manifest, warnings))
+}
+
+/// Substitute the references to environment variables within the string values of the manifest,
+/// using `lookup` to find the value of a variable.
+///
+/// Keys are never substituted. Each substituted value is written back as a basic string, so the
+/// line numbers of the items following it are preserved.
+///
+/// If the manifest is not valid TOML, it is returned as is so that the deserializer can report
+/// the syntax error.
+fn substitute_env_vars<'a>(
+    path: &Path,
+    manifest_str: &'a str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<Cow<'a, str>> {
+    if !manifest_str.contains("${") {
+        return Ok(Cow::Borrowed(manifest_str));
+    }
+    let Ok(doc) = toml_edit::ImDocument::parse(manifest_str) else {
+        return Ok(Cow::Borrowed(manifest_str));
+    };
+    let mut strings = vec![];
+    collect_strings(doc.as_item(), &mut strings);
+
+    let mut substituted = String::with_capacity(manifest_str.len());
+    let mut last_end = 0;
+    for (span, value) in strings {
+        if !value.contains("${") {
+            continue;
+        }
+        let new_value = substitute_value(value, lookup).map_err(|e| {
+            let (line, col) = location(manifest_str, span.clone());
+            anyhow!(
+                "failed to parse manifest at {}:{line}:{col}: {e}\n{}",
+                path.display(),
+                snippet(manifest_str, span.clone()),
+            )
+        })?;
+        substituted.push_str(&manifest_str[last_end..span.start]);
+        substituted.push_str(&toml_edit::Value::from(new_value).to_string());
+        last_end = span.end;
+    }
+    substituted.push_str(&manifest_str[last_end..]);
+    Ok(Cow::Owned(substituted))
+}
+
+/// Collect the spans and the values of all of the string values within the given item, in the
+/// order in which they appear in the document.
+fn collect_strings<'a>(item: &'a toml_edit::Item, strings: &mut Vec<(Range<usize>, &'a str)>) {
+    fn collect_value_strings<'a>(
+        value: &'a toml_edit::Value,
+        strings: &mut Vec<(Range<usize>, &'a str)>,
+    ) {
+        match value {
+            toml_edit::Value::String(s) => {
+                if let Some(span) = s.span() {
+                    strings.push((span, s.value()));
+                }
+            }
+            toml_edit::Value::Array(array) => array
+                .iter()
+                .for_each(|value| collect_value_strings(value, strings)),
+            toml_edit::Value::InlineTable(table) => table
+                .iter()
+                .for_each(|(_, value)| collect_value_strings(value, strings)),
+            _ => (),
+        }
+    }
+    match item {
+        toml_edit::Item::Value(value) => collect_value_strings(value, strings),
+        toml_edit::Item::Table(table) => table
+            .iter()
+            .for_each(|(_, item)| collect_strings(item, strings)),
+        toml_edit::Item::ArrayOfTables(tables) => tables
+            .iter()
+            .flat_map(|table| table.iter())
+            .for_each(|(_, item)| collect_strings(item, strings)),
+        toml_edit::Item::None => (),
+    }
+    strings.sort_by_key(|(span, _)| span.start);
+}
+
+/// Substitute the references to environment variables within a single string value.
+///
+/// A reference of the form `${VAR}` requires `VAR` to be set, while `${VAR:-default}` falls back
+/// to `default` in the case that `VAR` is unset.
+fn substitute_value(value: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Result<String> {
+    let mut substituted = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        substituted.push_str(&rest[..start]);
+        let Some(len) = rest[start + 2..].find('}') else {
+            bail!(
+                "unterminated environment variable reference `{}`",
+                &rest[start..]
+            );
+        };
+        let reference = &rest[start + 2..start + 2 + len];
+        let (name, default) = match reference.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (reference, None),
+        };
+        if name.is_empty() {
+            bail!("missing environment variable name in `${{{reference}}}`");
+        }
+        match (lookup(name), default) {
+            (Some(var), _) => substituted.push_str(&var),
+            (None, Some(default)) => substituted.push_str(default),
+            (None, None) => bail!(
+                "environment variable `{name}` is not set. \
+                 Set it, or provide a default value with `${{{name}:-default}}`"
+            ),
+        }
+        rest = &rest[start + 2 + len + 1..];
+    }
+    substituted.push_str(rest);
+    Ok(substituted)
+}
+
+/// Flatten the path to an ignored key into the segments required to look it up in the document.
+///
+/// Returns `None` in the case that the path cannot be represented within a TOML document.
+fn segments(path: &serde_ignored::Path) -> Option<Vec<Segment>> {
+    let mut segments = match path {
+        serde_ignored::Path::Root => return Some(vec![]),
+        serde_ignored::Path::Seq { parent, .. }
+        | serde_ignored::Path::Map { parent, .. }
+        | serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => segments(parent)?,
+    };
+    match path {
+        serde_ignored::Path::Seq { index, .. } => segments.push(Segment::Index(*index)),
+        serde_ignored::Path::Map { key, .. } => segments.push(Segment::Key(key.clone())),
+        _ => (),
+    }
+    Some(segments)
+}
+
+/// The span of the item at the given path within the document.
+///
+/// In the case that the path ends with a key, the span of the key itself is returned.
+fn item_span(root: &toml_edit::Item, segments: &[Segment]) -> Option<Range<usize>> {
+    let (last, parents) = segments.split_last()?;
+    let parent = parents
+        .iter()
+        .try_fold(root, |item, segment| match segment {
+            Segment::Key(key) => item.get(key),
+            Segment::Index(index) => item.get(index),
+        })?;
+    match last {
+        Segment::Key(key) => {
+            let (key, item) = parent.as_table_like()?.get_key_value(key)?;
+            key.span().or_else(|| item.span())
+        }
+        Segment::Index(index) => parent.get(index)?.span(),
+    }
+}
+
+/// The one-based line and column at which the given span starts.
+fn location(src: &str, span: Range<usize>) -> (usize, usize) {
+    let before = &src[..span.start.min(src.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|ix| ix + 1).unwrap_or(0);
+    let col = before[line_start..].chars().count() + 1;
+    (line, col)
+}
+
+/// Render the line on which the given span starts, underlining the span.
+fn snippet(src: &str, span: Range<usize>) -> String {
+    let (line, col) = location(src, span.clone());
+    let text = src.lines().nth(line - 1).unwrap_or_default();
+    let line_start = src[..span.start.min(src.len())]
+        .rfind('\n')
+        .map(|ix| ix + 1)
+        .unwrap_or(0);
+    // Only underline the portion of the span that lies on the first line.
+    let end = (span.end - line_start)
+        .min(text.len())
+        .max(span.start - line_start);
+    let width = text
+        .get(span.start - line_start..end)
+        .map(|s| s.chars().count())
+        .unwrap_or(0)
+        .max(1);
+    let gutter = " ".repeat(line.to_string().len());
+    format!(
+        "{gutter} |\n{line} | {text}\n{gutter} | {}{}",
+        " ".repeat(col - 1),
+        "^".repeat(width),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct Manifest {
+        project: Project,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct Project {
+        name: String,
+        implicit_std: Option<bool>,
+    }
+
+    fn deserialize_str(src: &str) -> Result<(Manifest, Vec<String>)> {
+        deserialize(Path::new("Forc.toml"), src)
+    }
+
+    #[test]
+    fn misspelled_key() {
+        let src = "[project]\nname = \"foo\"\nimplict-std = false\n";
+        let (manifest, warnings) = deserialize_str(src).unwrap();
+        assert_eq!(manifest.project.name, "foo");
+        assert_eq!(manifest.project.implicit_std, None);
+        assert_eq!(
+            warnings,
+            vec![
+                "Forc.toml:3:1: unused manifest key: project.implict-std\n  |\n3 | implict-std = false\n  | ^^^^^^^^^^^"
+            ]
+        );
+    }
+
+    #[test]
+    fn wrong_typed_value() {
+        let src = "[project]\nname = \"foo\"\nimplicit-std = \"no\"\n";
+        let err = deserialize_str(src).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "failed to parse manifest at Forc.toml:3:16: invalid type: string \"no\", expected a boolean\n  |\n3 | implicit-std = \"no\"\n  |                ^^^^"
+        );
+    }
+
+    fn lookup(name: &str) -> Option<String> {
+        (name == "FORC_TEST_VAR").then(|| "bar".to_string())
+    }
+
+    fn substitute_str(src: &str) -> Result<String> {
+        substitute_env_vars(Path::new("Forc.toml"), src, &lookup).map(Cow::into_owned)
+    }
+
+    #[test]
+    fn env_var_set() {
+        let src =
+            "[project]\nname = \"foo-${FORC_TEST_VAR}\"\nentry = \"${FORC_TEST_VAR:-main}.sw\"\n";
+        assert_eq!(
+            substitute_str(src).unwrap(),
+            "[project]\nname = \"foo-bar\"\nentry = \"bar.sw\"\n"
+        );
+    }
+
+    #[test]
+    fn env_var_unset_with_default() {
+        let src = "[dependencies]\nstd = { path = \"${FORC_TEST_UNSET:-../std}\" }\n";
+        assert_eq!(
+            substitute_str(src).unwrap(),
+            "[dependencies]\nstd = { path = \"../std\" }\n"
+        );
+    }
+
+    #[test]
+    fn env_var_unset_without_default() {
+        let src = "[project]\nname = \"foo\"\nentry = \"${FORC_TEST_UNSET}\"\n";
+        let err = substitute_str(src).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "failed to parse manifest at Forc.toml:3:9: environment variable `FORC_TEST_UNSET` is not set. Set it, or provide a default value with `${FORC_TEST_UNSET:-default}`\n  |\n3 | entry = \"${FORC_TEST_UNSET}\"\n  |         ^^^^^^^^^^^^^^^^^^^^"
+        );
+    }
+
+    #[test]
+    fn env_var_in_key_is_not_substituted() {
+        let src = "[dependencies]\n\"${FORC_TEST_VAR}\" = { path = \"../${FORC_TEST_VAR}\" }\n";
+        assert_eq!(
+            substitute_str(src).unwrap(),
+            "[dependencies]\n\"${FORC_TEST_VAR}\" = { path = \"../bar\" }\n"
+        );
+    }
+
+    #[test]
+    fn missing_required_field() {
+        let src = "[project]\nimplicit-std = false\n";
+        let err = deserialize_str(src).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "failed to parse manifest at Forc.toml:1:1: missing field `name`\n  |\n1 | [project]\n  | ^^^^^^^^^"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 pub mod build_profile;
+mod diagnostic;
 
 use crate::pkg::{manifest_file_missing, parsing_failed, wrong_program_type};
 use anyhow::{anyhow, bail, Context, Result};
@@ -16,8 +17,8 @@ use std::{
 use sway_core::{fuel_prelude::fuel_tx, language::parsed::TreeType, parse_tree_type, BuildTarget};
 use sway_error::handler::Handler;
 use sway_utils::{
-    constants, find_nested_manifest_dir, find_parent_manifest_dir,
-    find_parent_manifest_dir_with_check,
+    constants, find_nested_manifest_dir, find_nested_manifest_dir_excluding,
+    find_parent_manifest_dir, find_parent_manifest_dir_with_check,
 };
 
 use self::build_profile::BuildProfile;
@@ -187,6 +188,7 @@ pub struct PackageManifest {
     build_profile: Option<BTreeMap<String, BuildProfile>>,
     pub contract_dependencies: Option<BTreeMap<String, ContractDependency>>,
     pub proxy: Option<Proxy>,
+    pub vendor: Option<Vendor>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -285,6 +287,15 @@ pub struct Proxy {
     pub address: Option<String>,
 }
 
+/// Describes the directory from which the remote dependencies are sourced instead of fetching
+/// them, as created by `forc vendor`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Vendor {
+    /// The path to the vendor directory, relative to the manifest directory.
+    pub directory: PathBuf,
+}
+
 impl DependencyDetails {
     /// Checks if dependency details reserved for a specific dependency type used without the main
     /// detail for that type.
@@ -357,6 +368,30 @@ impl PackageManifestFile {
             .map(|(_, patch)| patch))
     }
 
+    /// Returns the canonical path of the vendor directory specified in the underlying
+    /// `PackageManifest` if this is a standalone package.
+    ///
+    /// If this package is a member of a workspace, the vendor directory is taken from the
+    /// workspace manifest file.
+    pub fn resolve_vendor_dir(&self) -> Result<Option<PathBuf>> {
+        let (dir, vendor) = match self.workspace().ok().flatten() {
+            Some(workspace) => (workspace.dir().to_path_buf(), workspace.vendor.clone()),
+            None => (self.dir().to_path_buf(), self.vendor.clone()),
+        };
+        vendor
+            .map(|vendor| {
+                let path = dir.join(vendor.directory);
+                path.canonicalize().map_err(|e| {
+                    anyhow!(
+                        "Failed to find vendor directory {:?}: {}. Run `forc vendor` to create it",
+                        path,
+                        e
+                    )
+                })
+            })
+            .transpose()
+    }
+
     /// Given the directory in which the file associated with this `PackageManifest` resides, produce the
     /// path to the entry file as specified in the manifest.
     ///
@@ -478,7 +513,14 @@ impl PackageManifestFile {
         // to be removed.
         let mut pkg_dir = self.path.to_path_buf();
         pkg_dir.pop();
-        if let Some(nested_package) = find_nested_manifest_dir(&pkg_dir) {
+        // The vendor directory contains the manifests of the vendored packages.
+        let nested_package = match &self.vendor {
+            Some(vendor) => {
+                find_nested_manifest_dir_excluding(&pkg_dir, &pkg_dir.join(&vendor.directory))
+            }
+            None => find_nested_manifest_dir(&pkg_dir),
+        };
+        if let Some(nested_package) = nested_package {
             // remove file name from nested_package_manifest
             bail!("Nested packages are not supported, please consider separating the nested package at {} from the package at {}, or if it makes sense consider creating a workspace.", nested_package.display(), pkg_dir.display())
         }
@@ -568,15 +610,9 @@ impl PackageManifest {
         // package or a workspace. While doing so, we should be printing the warnings if the given
         // file parses so that we only see warnings for the correct type of manifest.
         let path = path.as_ref();
-        let mut warnings = vec![];
         let manifest_str = std::fs::read_to_string(path)
             .map_err(|e| anyhow!("failed to read manifest at {:?}: {}", path, e))?;
-        let toml_de = toml::de::Deserializer::new(&manifest_str);
-        let mut manifest: Self = serde_ignored::deserialize(toml_de, |path| {
-            let warning = format!("unused manifest key: {path}");
-            warnings.push(warning);
-        })
-        .map_err(|e| anyhow!("failed to parse manifest: {}.", e))?;
+        let (mut manifest, warnings): (Self, _) = diagnostic::deserialize(path, &manifest_str)?;
         for warning in warnings {
             println_warning(&warning);
         }
@@ -842,6 +878,7 @@ pub struct WorkspaceManifestFile {
 pub struct WorkspaceManifest {
     workspace: Workspace,
     patch: Option<BTreeMap<String, PatchMap>>,
+    vendor: Option<Vendor>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -962,15 +999,9 @@ impl WorkspaceManifest {
         // While creating a `ManifestFile` we need to check if the given path corresponds to a
         // package or a workspace. While doing so, we should be printing the warnings if the given
         // file parses so that we only see warnings for the correct type of manifest.
-        let mut warnings = vec![];
         let manifest_str = std::fs::read_to_string(path)
             .map_err(|e| anyhow!("failed to read manifest at {:?}: {}", path, e))?;
-        let toml_de = toml::de::Deserializer::new(&manifest_str);
-        let manifest: Self = serde_ignored::deserialize(toml_de, |path| {
-            let warning = format!("unused manifest key: {path}");
-            warnings.push(warning);
-        })
-        .map_err(|e| anyhow!("failed to parse manifest: {}.", e))?;
+        let (manifest, warnings): (Self, _) = diagnostic::deserialize(path, &manifest_str)?;
         for warning in warnings {
             println_warning(&warning);
         }
@@ -22,14 +22,17 @@ use petgraph::{
 };
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{hash_map, BTreeSet, HashMap, HashSet},
+    collections::{hash_map, BTreeMap, BTreeSet, HashMap, HashSet},
     fmt,
     fs::{self, File},
     hash::{Hash, Hasher},
     io::Write,
     path::{Path, PathBuf},
     str::FromStr,
-    sync::{atomic::AtomicBool, Arc},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc, Arc, Mutex,
+    },
 };
 pub use sway_core::Programs;
 use sway_core::{
@@ -47,7 +50,7 @@ use sway_core::{
     semantic_analysis::namespace,
     source_map::SourceMap,
     transform::AttributeKind,
-    write_dwarf, BuildTarget, Engines, FinalizedEntry, LspConfig,
+    write_dwarf, BuildTarget, EmitKind, Engines, FinalizedEntry, LspConfig, OverflowBehavior,
 };
 use sway_core::{PrintAsm, PrintIr};
 use sway_error::{error::CompileError, handler::Handler, warning::CompileWarning};
@@ -95,6 +98,13 @@ pub type EdgeIx = petgraph::graph::EdgeIndex<GraphIx>;
 pub type NodeIx = petgraph::graph::NodeIndex<GraphIx>;
 pub type ManifestMap = HashMap<PinnedId, PackageManifestFile>;
 
+/// A remote package that was pinned and fetched ahead of the graph traversal, along with the
+/// manifests loaded while fetching it.
+type Prefetched = (source::Pinned, ManifestMap);
+
+/// The maximum number of remote packages fetched at the same time.
+const MAX_CONCURRENT_FETCHES: usize = 8;
+
 /// A unique ID for a pinned package.
 ///
 /// The internal value is produced by hashing the package's name and `source::Pinned`.
@@ -117,6 +127,9 @@ pub struct BuiltPackage {
     ///
     /// For non-contract members, this is always `None`.
     pub bytecode_without_tests: Option<BuiltPackageBytecode>,
+    /// The textual compiler outputs, i.e. the AST, typed AST, IR and ASM, requested by the
+    /// `emit` option of the build profile.
+    pub emitted: BTreeMap<EmitKind, String>,
 }
 
 /// The package descriptors that a `BuiltPackage` holds so that the source used for building the
@@ -163,6 +176,8 @@ pub struct PkgTestEntry {
     pub pass_condition: TestPassCondition,
     pub span: Span,
     pub file_path: Arc<PathBuf>,
+    /// Whether the entry is a benchmark, i.e. decorated with `#[bench]` rather than `#[test]`.
+    pub is_bench: bool,
 }
 
 /// The result of successfully compiling a workspace.
@@ -186,6 +201,7 @@ pub struct CompiledPackage {
     pub root_module: namespace::Module,
     pub warnings: Vec<CompileWarning>,
     pub metrics: PerformanceData,
+    pub emitted: BTreeMap<EmitKind, String>,
 }
 
 /// Compiled contract dependency parts relevant to calculating a contract's ID.
@@ -237,6 +253,9 @@ pub struct PkgOpts {
     /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
     /// needs to be updated, Forc will exit with an error
     pub locked: bool,
+    /// Requires that the Forc.lock file is up-to-date and prevents Forc from using the network.
+    /// Implies both `locked` and `offline`.
+    pub frozen: bool,
     /// The directory in which the sway compiler output artifacts are placed.
     ///
     /// By default, this is `<project-root>/out`.
@@ -308,6 +327,18 @@ pub struct BuildOpts {
     pub error_on_warnings: bool,
     /// Include all test functions within the build.
     pub tests: bool,
+    /// The behavior of integer arithmetic on overflow, overriding the one of the build profile.
+    pub overflow: Option<OverflowBehavior>,
+    /// The maximum number of steps taken when evaluating a constant expression, overriding the
+    /// one of the build profile.
+    pub const_eval_limit: Option<usize>,
+    /// The features to enable for the member packages, which include the items annotated with
+    /// `#[cfg(feature = "...")]` in the build.
+    pub features: Vec<String>,
+    /// The kinds of compiler outputs to write to the output directory of the member packages.
+    ///
+    /// If empty, the bytecode and the JSON ABI are written.
+    pub emit: Vec<EmitKind>,
     /// The set of options to filter by member project kind.
     pub member_filter: MemberFilter,
     /// Set of experimental flags
@@ -480,24 +511,60 @@ impl BuiltPackage {
     }
 
     /// Writes BuiltPackage to `output_dir`.
+    ///
+    /// Only the outputs of the given `emit` kinds are written, or the bytecode and the JSON ABI
+    /// if `emit` is empty. The outputs are written to the following paths:
+    ///
+    /// - `ast`: `<pkg_name>.ast`
+    /// - `typed-ast`: `<pkg_name>.typed-ast`
+    /// - `ir`: `<pkg_name>.ir`
+    /// - `asm`: `<pkg_name>.asm`
+    /// - `bytecode`: `<pkg_name>.bin`, along with the storage slots of contracts, the root of
+    ///   predicates and the bytecode hash of scripts
+    /// - `abi`: `<pkg_name>-abi.json`
+    /// - `abi-spans`: `<pkg_name>-abi-spans.json`
     pub fn write_output(
         &self,
         minify: &MinifyOpts,
         pkg_name: &str,
         output_dir: &Path,
+        emit: &[EmitKind],
     ) -> Result<()> {
         if !output_dir.exists() {
             fs::create_dir_all(output_dir)?;
         }
+        let emit = if emit.is_empty() {
+            &EmitKind::DEFAULT[..]
+        } else {
+            emit
+        };
+
+        // Place the textual outputs into the output directory.
+        for (kind, output) in &self.emitted {
+            if emit.contains(kind) {
+                let path = match kind {
+                    EmitKind::AbiSpans => output_dir.join(format!("{pkg_name}-abi-spans.json")),
+                    _ => output_dir.join(pkg_name).with_extension(kind.as_str()),
+                };
+                fs::write(path, output)?;
+            }
+        }
+
+        if emit.contains(&EmitKind::Abi) {
+            let program_abi_stem = format!("{pkg_name}-abi");
+            let json_abi_path = output_dir.join(program_abi_stem).with_extension("json");
+            self.write_json_abi(&json_abi_path, minify)?;
+        }
+
+        if !emit.contains(&EmitKind::Bytecode) {
+            return Ok(());
+        }
+
         // Place build artifacts into the output directory.
         let bin_path = output_dir.join(pkg_name).with_extension("bin");
 
         self.write_bytecode(&bin_path)?;
 
-        let program_abi_stem = format!("{pkg_name}-abi");
-        let json_abi_path = output_dir.join(program_abi_stem).with_extension("json");
-        self.write_json_abi(&json_abi_path, minify)?;
-
         debug!(
             "      Bytecode size: {} bytes ({})",
             self.bytecode.bytes.len(),
@@ -599,8 +666,8 @@ impl BuildPlan {
         Self::from_lock_and_manifests(
             &lock_path,
             &member_manifests,
-            pkg_options.locked,
-            pkg_options.offline,
+            pkg_options.locked || pkg_options.frozen,
+            pkg_options.offline || pkg_options.frozen,
             &pkg_options.ipfs_node,
         )
     }
@@ -617,10 +684,17 @@ impl BuildPlan {
         validate_version(manifests)?;
         let mut graph = Graph::default();
         let mut manifest_map = ManifestMap::default();
-        fetch_graph(manifests, offline, ipfs_node, &mut graph, &mut manifest_map)?;
+        fetch_graph(
+            manifests,
+            offline,
+            ipfs_node,
+            None,
+            &mut graph,
+            &mut manifest_map,
+        )?;
         // Validate the graph, since we constructed the graph from scratch the paths will not be a
         // problem but the version check is still needed
-        validate_graph(&graph, manifests)?;
+        validate_graph(&graph, manifests, None)?;
         let compilation_order = compilation_order(&graph)?;
         Ok(Self {
             graph,
@@ -641,6 +715,9 @@ impl BuildPlan {
     ///
     /// The resulting build plan should always be in a valid state that is ready for building or
     /// checking.
+    ///
+    /// If the manifests specify a vendor directory, git and IPFS dependencies are sourced from it
+    /// instead of being fetched.
     // TODO: Currently (if `--locked` isn't specified) this writes the updated lock directly. This
     // probably should not be the role of the `BuildPlan` constructor - instead, we should return
     // the manifest alongside some lock diff type that can be used to optionally write the updated
@@ -651,6 +728,31 @@ impl BuildPlan {
         locked: bool,
         offline: bool,
         ipfs_node: &IPFSNode,
+    ) -> Result<Self> {
+        let vendor_dir = match manifests.values().next() {
+            Some(manifest) => manifest.resolve_vendor_dir()?,
+            None => None,
+        };
+        Self::from_lock_and_manifests_with_vendor_dir(
+            lock_path,
+            manifests,
+            locked,
+            offline,
+            ipfs_node,
+            vendor_dir.as_deref(),
+        )
+    }
+
+    /// Create a new build plan like `from_lock_and_manifests`, sourcing the git and IPFS
+    /// dependencies from the given `vendor_dir` if any, regardless of the vendor directory
+    /// specified by the manifests.
+    pub(crate) fn from_lock_and_manifests_with_vendor_dir(
+        lock_path: &Path,
+        manifests: &MemberManifestFiles,
+        locked: bool,
+        offline: bool,
+        ipfs_node: &IPFSNode,
+        vendor_dir: Option<&Path>,
     ) -> Result<Self> {
         // Check toolchain version
         validate_version(manifests)?;
@@ -678,7 +780,7 @@ impl BuildPlan {
         // might have edited the `Forc.lock` file when they shouldn't have, a path dependency no
         // longer exists at its specified location, etc. We must first remove all invalid nodes
         // before we can determine what we need to fetch.
-        let invalid_deps = validate_graph(&graph, manifests)?;
+        let invalid_deps = validate_graph(&graph, manifests, vendor_dir)?;
         let members: HashSet<String> = manifests
             .iter()
             .map(|(member_name, _)| member_name.clone())
@@ -687,10 +789,17 @@ impl BuildPlan {
 
         // We know that the remaining nodes have valid paths, otherwise they would have been
         // removed. We can safely produce an initial `manifest_map`.
-        let mut manifest_map = graph_to_manifest_map(manifests, &graph)?;
+        let mut manifest_map = graph_to_manifest_map(manifests, &graph, vendor_dir)?;
 
         // Attempt to fetch the remainder of the graph.
-        let _added = fetch_graph(manifests, offline, ipfs_node, &mut graph, &mut manifest_map)?;
+        let _added = fetch_graph(
+            manifests,
+            offline,
+            ipfs_node,
+            vendor_dir,
+            &mut graph,
+            &mut manifest_map,
+        )?;
 
         // Determine the compilation order.
         let compilation_order = compilation_order(&graph)?;
@@ -713,7 +822,7 @@ impl BuildPlan {
             if locked {
                 bail!(
                     "The lock file {} needs to be updated (Cause: {}) \
-                    but --locked was passed to prevent this.",
+                    but --locked or --frozen was passed to prevent this.",
                     lock_path.to_string_lossy(),
                     cause,
                 );
@@ -921,7 +1030,11 @@ fn member_nodes(g: &Graph) -> impl Iterator<Item = NodeIx> + '_ {
 /// Validates the state of the pinned package graph against the given ManifestFile.
 ///
 /// Returns the set of invalid dependency edges.
-fn validate_graph(graph: &Graph, manifests: &MemberManifestFiles) -> Result<BTreeSet<EdgeIx>> {
+fn validate_graph(
+    graph: &Graph,
+    manifests: &MemberManifestFiles,
+    vendor_dir: Option<&Path>,
+) -> Result<BTreeSet<EdgeIx>> {
     let mut member_pkgs: HashMap<&String, &PackageManifestFile> = manifests.iter().collect();
     let member_nodes: Vec<_> = member_nodes(graph)
         .filter_map(|n| {
@@ -939,7 +1052,7 @@ fn validate_graph(graph: &Graph, manifests: &MemberManifestFiles) -> Result<BTre
     let mut visited = HashSet::new();
     let edges = member_nodes
         .into_iter()
-        .flat_map(move |(n, _)| validate_deps(graph, n, manifests, &mut visited))
+        .flat_map(move |(n, _)| validate_deps(graph, n, manifests, vendor_dir, &mut visited))
         .collect();
 
     Ok(edges)
@@ -952,19 +1065,20 @@ fn validate_deps(
     graph: &Graph,
     node: NodeIx,
     manifests: &MemberManifestFiles,
+    vendor_dir: Option<&Path>,
     visited: &mut HashSet<NodeIx>,
 ) -> BTreeSet<EdgeIx> {
     let mut remove = BTreeSet::default();
     for edge in graph.edges_directed(node, Direction::Outgoing) {
         let dep_name = edge.weight();
         let dep_node = edge.target();
-        match validate_dep(graph, manifests, dep_name, dep_node) {
+        match validate_dep(graph, manifests, vendor_dir, dep_name, dep_node) {
             Err(_) => {
                 remove.insert(edge.id());
             }
             Ok(_) => {
                 if visited.insert(dep_node) {
-                    let rm = validate_deps(graph, dep_node, manifests, visited);
+                    let rm = validate_deps(graph, dep_node, manifests, vendor_dir, visited);
                     remove.extend(rm);
                 }
                 continue;
@@ -980,6 +1094,7 @@ fn validate_deps(
 fn validate_dep(
     graph: &Graph,
     manifests: &MemberManifestFiles,
+    vendor_dir: Option<&Path>,
     dep_edge: &Edge,
     dep_node: NodeIx,
 ) -> Result<PackageManifestFile> {
@@ -988,13 +1103,14 @@ fn validate_dep(
         .get(dep_name)
         .ok_or_else(|| anyhow!("Couldn't find manifest file for {}", dep_name))?;
     // Check the validity of the dependency path, including its path root.
-    let dep_path = dep_path(graph, node_manifest, dep_node, manifests).map_err(|e| {
-        anyhow!(
-            "failed to construct path for dependency {:?}: {}",
-            dep_name,
-            e
-        )
-    })?;
+    let dep_path =
+        dep_path(graph, node_manifest, dep_node, manifests, vendor_dir).map_err(|e| {
+            anyhow!(
+                "failed to construct path for dependency {:?}: {}",
+                dep_name,
+                e
+            )
+        })?;
 
     // Ensure the manifest is accessible.
     let dep_manifest = PackageManifestFile::from_dir(&dep_path)?;
@@ -1055,10 +1171,11 @@ fn dep_path(
     node_manifest: &PackageManifestFile,
     dep_node: NodeIx,
     manifests: &MemberManifestFiles,
+    vendor_dir: Option<&Path>,
 ) -> Result<PathBuf> {
     let dep = &graph[dep_node];
     let dep_name = &dep.name;
-    match dep.source.dep_path(&dep.name)? {
+    match dep.source.dep_path(&dep.name, vendor_dir)? {
         source::DependencyPath::ManifestPath(path) => Ok(path),
         source::DependencyPath::Root(path_root) => {
             validate_path_root(graph, dep_node, path_root)?;
@@ -1229,11 +1346,17 @@ pub fn compilation_order(graph: &Graph) -> Result<Vec<NodeIx>> {
 /// Given a graph collects ManifestMap while taking in to account that manifest can be a
 /// ManifestFile::Workspace. In the case of a workspace each pkg manifest map is collected and
 /// their added node lists are merged.
-fn graph_to_manifest_map(manifests: &MemberManifestFiles, graph: &Graph) -> Result<ManifestMap> {
+fn graph_to_manifest_map(
+    manifests: &MemberManifestFiles,
+    graph: &Graph,
+    vendor_dir: Option<&Path>,
+) -> Result<ManifestMap> {
     let mut manifest_map = HashMap::new();
     for pkg_manifest in manifests.values() {
         let pkg_name = &pkg_manifest.project.name;
-        manifest_map.extend(pkg_graph_to_manifest_map(manifests, pkg_name, graph)?);
+        manifest_map.extend(pkg_graph_to_manifest_map(
+            manifests, pkg_name, graph, vendor_dir,
+        )?);
     }
     Ok(manifest_map)
 }
@@ -1249,6 +1372,7 @@ fn pkg_graph_to_manifest_map(
     manifests: &MemberManifestFiles,
     pkg_name: &str,
     graph: &Graph,
+    vendor_dir: Option<&Path>,
 ) -> Result<ManifestMap> {
     let proj_manifest = manifests
         .get(pkg_name)
@@ -1278,13 +1402,14 @@ fn pkg_graph_to_manifest_map(
                 Some((parent_manifest, dep_name))
             })
             .ok_or_else(|| anyhow!("more than one root package detected in graph"))?;
-        let dep_path = dep_path(graph, parent_manifest, dep_node, manifests).map_err(|e| {
-            anyhow!(
-                "failed to construct path for dependency {:?}: {}",
-                dep_name,
-                e
-            )
-        })?;
+        let dep_path =
+            dep_path(graph, parent_manifest, dep_node, manifests, vendor_dir).map_err(|e| {
+                anyhow!(
+                    "failed to construct path for dependency {:?}: {}",
+                    dep_name,
+                    e
+                )
+            })?;
         let dep_manifest = PackageManifestFile::from_dir(&dep_path)?;
         let dep = &graph[dep_node];
         manifest_map.insert(dep.id(), dep_manifest);
@@ -1309,7 +1434,7 @@ fn validate_path_root(graph: &Graph, path_dep: NodeIx, path_root: PinnedId) -> R
 }
 
 /// Given any node in the graph, find the node that is the path root for that node.
-fn find_path_root(graph: &Graph, mut node: NodeIx) -> Result<NodeIx> {
+pub(crate) fn find_path_root(graph: &Graph, mut node: NodeIx) -> Result<NodeIx> {
     loop {
         let pkg = &graph[node];
         match pkg.source {
@@ -1347,6 +1472,7 @@ fn fetch_graph(
     member_manifests: &MemberManifestFiles,
     offline: bool,
     ipfs_node: &IPFSNode,
+    vendor_dir: Option<&Path>,
     graph: &mut Graph,
     manifest_map: &mut ManifestMap,
 ) -> Result<HashSet<NodeIx>> {
@@ -1356,6 +1482,7 @@ fn fetch_graph(
             member_pkg_manifest,
             offline,
             ipfs_node,
+            vendor_dir,
             graph,
             manifest_map,
             member_manifests,
@@ -1382,6 +1509,7 @@ fn fetch_pkg_graph(
     proj_manifest: &PackageManifestFile,
     offline: bool,
     ipfs_node: &IPFSNode,
+    vendor_dir: Option<&Path>,
     graph: &mut Graph,
     manifest_map: &mut ManifestMap,
     member_manifests: &MemberManifestFiles,
@@ -1411,21 +1539,213 @@ fn fetch_pkg_graph(
             (pkg, n)
         })
         .collect();
+    // Vendored packages are not fetched, so there is nothing to fetch ahead of time.
+    let mut prefetched = match vendor_dir {
+        Some(_) => HashMap::default(),
+        None => prefetch_remote_deps(
+            fetch_id,
+            offline,
+            ipfs_node,
+            proj_manifest,
+            path_root,
+            graph,
+            manifest_map,
+            &fetched,
+            member_manifests,
+        )?,
+    };
     let mut visited = HashSet::default();
     fetch_deps(
         fetch_id,
         offline,
         ipfs_node,
+        vendor_dir,
         proj_node,
         path_root,
         graph,
         manifest_map,
         &mut fetched,
+        &mut prefetched,
         &mut visited,
         member_manifests,
     )
 }
 
+/// The dependencies declared within the given manifest.
+///
+/// Contract dependencies come first, followed by the library dependencies.
+fn manifest_deps(manifest: &PackageManifestFile) -> Vec<(String, Dependency, DepKind)> {
+    manifest
+        .contract_deps()
+        .map(|(n, d)| {
+            (
+                n.clone(),
+                d.dependency.clone(),
+                DepKind::Contract { salt: d.salt.0 },
+            )
+        })
+        .chain(
+            manifest
+                .deps()
+                .map(|(n, d)| (n.clone(), d.clone(), DepKind::Library)),
+        )
+        .collect()
+}
+
+/// Discover the remote (git and IPFS) dependencies reachable from the given project and pin and
+/// fetch all of them that are missing from the `graph`, fetching independent packages
+/// concurrently.
+///
+/// Dependencies are discovered one level at a time, as the manifest of a remote package is only
+/// known after it has been fetched. Local path and member dependencies are only traversed, as
+/// they are cheap to load during the graph traversal itself.
+///
+/// This only warms up the fetch step. The resulting packages are handed to `fetch_deps` which
+/// remains responsible for building the graph, so the resolution result and the order of the
+/// graph nodes are the same as when fetching sequentially.
+#[allow(clippy::too_many_arguments)]
+fn prefetch_remote_deps(
+    fetch_id: u64,
+    offline: bool,
+    ipfs_node: &IPFSNode,
+    proj_manifest: &PackageManifestFile,
+    path_root: PinnedId,
+    graph: &Graph,
+    manifest_map: &ManifestMap,
+    fetched: &HashMap<Pkg, NodeIx>,
+    member_manifests: &MemberManifestFiles,
+) -> Result<HashMap<Pkg, Prefetched>> {
+    let mut prefetched = HashMap::default();
+    let mut seen = HashSet::new();
+    let mut level = vec![proj_manifest.clone()];
+    while !level.is_empty() {
+        let mut next_level = vec![];
+        let mut remote = vec![];
+        for manifest in &level {
+            for (dep_name, dep, _) in manifest_deps(manifest) {
+                let name = dep.package().unwrap_or(&dep_name);
+                // Errors are reported by `fetch_deps` when it reaches this dependency.
+                let Ok(source) =
+                    Source::from_manifest_dep_patched(manifest, name, &dep, member_manifests)
+                else {
+                    continue;
+                };
+                let dep_pkg = Pkg {
+                    name: name.to_string(),
+                    source,
+                };
+                if !seen.insert(dep_pkg.clone()) {
+                    continue;
+                }
+                if let Some(&node) = fetched.get(&dep_pkg) {
+                    next_level.push(manifest_map[&graph[node].id()].clone());
+                    continue;
+                }
+                match dep_pkg.source.local_path() {
+                    Some(path) => {
+                        if let Ok(manifest) = PackageManifestFile::from_dir(path) {
+                            next_level.push(manifest);
+                        }
+                    }
+                    None if matches!(dep_pkg.source, Source::Git(_) | Source::Ipfs(_)) => {
+                        remote.push(dep_pkg)
+                    }
+                    None => (),
+                }
+            }
+        }
+        for (pkg, (pinned, manifests)) in
+            fetch_remote_pkgs(fetch_id, offline, ipfs_node, None, path_root, remote)?
+        {
+            next_level.extend(manifests.values().cloned());
+            prefetched.insert(pkg, (pinned, manifests));
+        }
+        level = next_level;
+    }
+    Ok(prefetched)
+}
+
+/// Pin and fetch the given packages, running at most `MAX_CONCURRENT_FETCHES` fetches at a time.
+///
+/// The results are returned in the order of `pkgs`. Once a fetch fails no new fetches are
+/// started, the ones in flight are completed and the error of the first failed package is
+/// returned.
+///
+/// The fetching threads don't print their progress themselves, but send it to the calling
+/// thread which prints it as it arrives.
+fn fetch_remote_pkgs(
+    fetch_id: u64,
+    offline: bool,
+    ipfs_node: &IPFSNode,
+    git_checkouts_dir: Option<&Path>,
+    path_root: PinnedId,
+    pkgs: Vec<Pkg>,
+) -> Result<Vec<(Pkg, Prefetched)>> {
+    let next_ix = AtomicUsize::new(0);
+    let cancelled = AtomicBool::new(false);
+    let results: Vec<Mutex<Option<Result<Prefetched>>>> =
+        pkgs.iter().map(|_| Mutex::new(None)).collect();
+    let fetch = |pkg: &Pkg, progress: &mpsc::Sender<String>| -> Result<Prefetched> {
+        // Each package gets its own fetch ID so that concurrent fetches of the same repository,
+        // e.g. at different references, don't share a temporary directory.
+        let mut hasher = hash_map::DefaultHasher::new();
+        fetch_id.hash(&mut hasher);
+        pkg.hash(&mut hasher);
+        let ctx = source::PinCtx {
+            fetch_id: hasher.finish(),
+            path_root,
+            name: &pkg.name,
+            offline,
+            ipfs_node,
+            vendor_dir: None,
+            git_checkouts_dir,
+            progress: source::FetchProgress::Send(progress),
+        };
+        let mut manifests = ManifestMap::default();
+        let pinned = pkg.source.pin(ctx, &mut manifests)?;
+        Ok((pinned, manifests))
+    };
+    let (progress_sender, progress_receiver) = mpsc::channel();
+    std::thread::scope(|scope| {
+        for _ in 0..pkgs.len().min(MAX_CONCURRENT_FETCHES) {
+            let progress = progress_sender.clone();
+            let (fetch, pkgs, results) = (&fetch, &pkgs, &results);
+            let (next_ix, cancelled) = (&next_ix, &cancelled);
+            scope.spawn(move || {
+                while !cancelled.load(Ordering::SeqCst) {
+                    let ix = next_ix.fetch_add(1, Ordering::SeqCst);
+                    let Some(pkg) = pkgs.get(ix) else {
+                        break;
+                    };
+                    let result = fetch(pkg, &progress);
+                    if result.is_err() {
+                        cancelled.store(true, Ordering::SeqCst);
+                    }
+                    *results[ix].lock().unwrap() = Some(result);
+                }
+            });
+        }
+        // The receiver is exhausted once all the fetching threads are done with their senders.
+        drop(progress_sender);
+        for message in progress_receiver {
+            println_action_green("Fetching", &message);
+        }
+    });
+
+    let mut prefetched = vec![];
+    for (pkg, result) in pkgs.into_iter().zip(results) {
+        match result.into_inner().unwrap() {
+            Some(Ok(fetched)) => prefetched.push((pkg, fetched)),
+            Some(Err(e)) => {
+                return Err(e.context(format!("Failed to fetch dependency: {}", pkg.name)))
+            }
+            // Skipped due to the failure of another fetch.
+            None => (),
+        }
+    }
+    Ok(prefetched)
+}
+
 /// Visit the unvisited dependencies of the given node and fetch missing nodes as necessary.
 ///
 /// Assumes the `node`'s manifest already exists within the `manifest_map`.
@@ -1434,33 +1754,20 @@ fn fetch_deps(
     fetch_id: u64,
     offline: bool,
     ipfs_node: &IPFSNode,
+    vendor_dir: Option<&Path>,
     node: NodeIx,
     path_root: PinnedId,
     graph: &mut Graph,
     manifest_map: &mut ManifestMap,
     fetched: &mut HashMap<Pkg, NodeIx>,
+    prefetched: &mut HashMap<Pkg, Prefetched>,
     visited: &mut HashSet<NodeIx>,
     member_manifests: &MemberManifestFiles,
 ) -> Result<HashSet<NodeIx>> {
     let mut added = HashSet::default();
     let parent_id = graph[node].id();
-    let package_manifest = &manifest_map[&parent_id];
     // If the current package is a contract, we need to first get the deployment dependencies
-    let deps: Vec<(String, Dependency, DepKind)> = package_manifest
-        .contract_deps()
-        .map(|(n, d)| {
-            (
-                n.clone(),
-                d.dependency.clone(),
-                DepKind::Contract { salt: d.salt.0 },
-            )
-        })
-        .chain(
-            package_manifest
-                .deps()
-                .map(|(n, d)| (n.clone(), d.clone(), DepKind::Library)),
-        )
-        .collect();
+    let deps = manifest_deps(&manifest_map[&parent_id]);
     for (dep_name, dep, dep_kind) in deps {
         let name = dep.package().unwrap_or(&dep_name);
         let parent_manifest = &manifest_map[&parent_id];
@@ -1477,14 +1784,27 @@ fn fetch_deps(
             hash_map::Entry::Occupied(entry) => *entry.get(),
             hash_map::Entry::Vacant(entry) => {
                 let pkg = entry.key();
-                let ctx = source::PinCtx {
-                    fetch_id,
-                    path_root,
-                    name: &pkg.name,
-                    offline,
-                    ipfs_node,
+                let source = match prefetched.remove(pkg) {
+                    Some((source, manifests)) => {
+                        for (id, manifest) in manifests {
+                            manifest_map.entry(id).or_insert(manifest);
+                        }
+                        source
+                    }
+                    None => {
+                        let ctx = source::PinCtx {
+                            fetch_id,
+                            path_root,
+                            name: &pkg.name,
+                            offline,
+                            ipfs_node,
+                            vendor_dir,
+                            git_checkouts_dir: None,
+                            progress: source::FetchProgress::Print,
+                        };
+                        pkg.source.pin(ctx, manifest_map)?
+                    }
                 };
-                let source = pkg.source.pin(ctx, manifest_map)?;
                 let name = pkg.name.clone();
                 let dep_pinned = Pinned { name, source };
                 let dep_node = graph.add_node(dep_pinned);
@@ -1527,11 +1847,13 @@ fn fetch_deps(
             fetch_id,
             offline,
             ipfs_node,
+            vendor_dir,
             dep_node,
             path_root,
             graph,
             manifest_map,
             fetched,
+            prefetched,
             visited,
             member_manifests,
         )?);
@@ -1563,9 +1885,16 @@ pub fn sway_build_config(
     )
     .with_print_ir(build_profile.print_ir.clone())
     .with_include_tests(build_profile.include_tests)
+    .with_features(build_profile.features.clone())
+    .with_emit_ir(build_profile.emit.contains(&EmitKind::Ir))
     .with_time_phases(build_profile.time_phases)
     .with_metrics(build_profile.metrics_outfile.clone())
     .with_optimization_level(build_profile.optimization_level)
+    .with_overflow_behavior(build_profile.overflow)
+    .with_warn_redundant_clone(build_profile.warn_redundant_clone)
+    .with_const_eval_limit(build_profile.const_eval_limit)
+    .with_max_function_parameters(build_profile.max_function_parameters)
+    .with_max_cyclomatic_complexity(build_profile.max_cyclomatic_complexity)
     .with_experimental(sway_core::ExperimentalFlags {
         new_encoding: build_profile.experimental.new_encoding,
     });
@@ -1814,6 +2143,14 @@ pub fn compile(
         tracing::info!("{:#?}", typed_program);
     }
 
+    let mut emitted = BTreeMap::new();
+    if profile.emit.contains(&EmitKind::Ast) {
+        emitted.insert(EmitKind::Ast, format!("{:#?}", programs.parsed));
+    }
+    if profile.emit.contains(&EmitKind::TypedAst) {
+        emitted.insert(EmitKind::TypedAst, format!("{:#?}", typed_program));
+    }
+
     let storage_slots = typed_program.storage_slots.clone();
     let tree_type = typed_program.kind.tree_type();
 
@@ -1838,17 +2175,19 @@ pub fn compile(
 
     let mut program_abi = match pkg.target {
         BuildTarget::Fuel => {
+            let mut abi_ctx = AbiContext {
+                program: typed_program,
+                abi_with_callpaths: true,
+                type_ids_to_full_type_str: HashMap::<String, String>::new(),
+                type_field_spans: HashMap::new(),
+            };
             let program_abi_res = time_expr!(
                 pkg.name,
                 "generate JSON ABI program",
                 "generate_json_abi",
                 fuel_abi::generate_program_abi(
                     &handler,
-                    &mut AbiContext {
-                        program: typed_program,
-                        abi_with_callpaths: true,
-                        type_ids_to_full_type_str: HashMap::<String, String>::new(),
-                    },
+                    &mut abi_ctx,
                     engines,
                     profile
                         .experimental
@@ -1864,6 +2203,14 @@ pub fn compile(
                 Err(_) => return fail(handler),
                 Ok(program_abi) => program_abi,
             };
+            if profile.emit.contains(&EmitKind::AbiSpans) {
+                let abi_spans =
+                    fuel_abi::generate_program_abi_spans(&abi_ctx, engines, &program_abi);
+                emitted.insert(
+                    EmitKind::AbiSpans,
+                    serde_json::to_string_pretty(&abi_spans)?,
+                );
+            }
             ProgramABI::Fuel(program_abi)
         }
         BuildTarget::EVM => {
@@ -1908,11 +2255,24 @@ pub fn compile(
         Ok(asm) => asm,
     };
 
+    if let Some(ir) = asm.1.take() {
+        emitted.insert(EmitKind::Ir, ir);
+    }
+    if profile.emit.contains(&EmitKind::Asm) {
+        emitted.insert(EmitKind::Asm, asm.0.to_string());
+    }
+
     let bc_res = time_expr!(
         pkg.name,
         "compile asm to bytecode",
         "compile_asm_to_bytecode",
-        sway_core::asm_to_bytecode(&handler, &mut asm, source_map, engines.se(), &sway_build_config),
+        sway_core::asm_to_bytecode(
+            &handler,
+            &mut asm,
+            source_map,
+            engines.se(),
+            &sway_build_config
+        ),
         Some(sway_build_config.clone()),
         metrics
     );
@@ -1961,6 +2321,7 @@ pub fn compile(
         root_module: namespace.root_module().clone(),
         warnings,
         metrics,
+        emitted,
     };
 
     #[cfg(feature = "profiler")]
@@ -1979,7 +2340,16 @@ fn report_assembly_information(
     let mut bytes = compiled_package.bytecode.bytes.clone();
 
     // Attempt to get the data section offset out of the compiled package bytes.
-    let data_offset = u64::from_be_bytes(bytes.iter().skip(8).take(8).cloned().collect::<Vec<_>>().try_into().unwrap());
+    let data_offset = u64::from_be_bytes(
+        bytes
+            .iter()
+            .skip(8)
+            .take(8)
+            .cloned()
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+    );
     let data_section_size = bytes.len() as u64 - data_offset;
 
     // Remove the data section from the compiled package bytes.
@@ -1993,7 +2363,7 @@ fn report_assembly_information(
             sway_core::asm_generation::Datum::Byte(value) => std::mem::size_of_val(value) as u64,
 
             sway_core::asm_generation::Datum::Word(value) => std::mem::size_of_val(value) as u64,
-            
+
             sway_core::asm_generation::Datum::ByteArray(bytes)
             | sway_core::asm_generation::Datum::Slice(bytes) => {
                 if bytes.len() % 8 == 0 {
@@ -2002,8 +2372,10 @@ fn report_assembly_information(
                     ((bytes.len() + 7) & 0xfffffff8_usize) as u64
                 }
             }
-            
-            sway_core::asm_generation::Datum::Collection(items) => items.iter().map(calculate_entry_size).sum(),
+
+            sway_core::asm_generation::Datum::Collection(items) => {
+                items.iter().map(calculate_entry_size).sum()
+            }
         }
     }
 
@@ -2012,13 +2384,22 @@ fn report_assembly_information(
         bytecode_size: bytes.len() as _,
         data_section: sway_core::asm_generation::DataSectionInformation {
             size: data_section_size,
-            used: compiled_asm.0.data_section.value_pairs.iter().map(calculate_entry_size).sum(),
+            used: compiled_asm
+                .0
+                .data_section
+                .value_pairs
+                .iter()
+                .map(calculate_entry_size)
+                .sum(),
             value_pairs: compiled_asm.0.data_section.value_pairs.clone(),
-        }
+        },
     };
 
     // Report the assembly information to the `dyno` process through `stdout`.
-    println!("/dyno info {}", serde_json::to_string(&asm_information).unwrap());
+    println!(
+        "/dyno info {}",
+        serde_json::to_string(&asm_information).unwrap()
+    );
 }
 
 impl PkgEntry {
@@ -2060,9 +2441,24 @@ impl PkgTestEntry {
 
         const FAILING_TEST_KEYWORD: &str = "should_revert";
 
+        let is_bench = test_function_decl.is_bench();
+        if is_bench
+            && test_function_decl
+                .attributes
+                .contains_key(&AttributeKind::Test)
+        {
+            let test_name = &test_function_decl.name;
+            bail!("Function {test_name} cannot be both a test and a benchmark.")
+        }
+
+        let entry_attr = if is_bench {
+            AttributeKind::Bench
+        } else {
+            AttributeKind::Test
+        };
         let test_args: HashMap<String, Option<String>> = test_function_decl
             .attributes
-            .get(&AttributeKind::Test)
+            .get(&entry_attr)
             .expect("test declaration is missing test attribute")
             .iter()
             .flat_map(|attr| attr.args.iter())
@@ -2078,6 +2474,9 @@ impl PkgTestEntry {
 
         let pass_condition = if test_args.is_empty() {
             anyhow::Ok(TestPassCondition::ShouldNotRevert)
+        } else if is_bench {
+            let bench_name = &test_function_decl.name;
+            bail!("Benchmark {bench_name} does not accept any arguments.")
         } else if let Some(args) = test_args.get(FAILING_TEST_KEYWORD) {
             let expected_revert_code = args
                 .as_ref()
@@ -2102,6 +2501,7 @@ impl PkgTestEntry {
             pass_condition,
             span,
             file_path,
+            is_bench,
         })
     }
 }
@@ -2127,6 +2527,10 @@ fn build_profile_from_opts(
         release,
         metrics_outfile,
         tests,
+        overflow,
+        const_eval_limit,
+        features,
+        emit,
         error_on_warnings,
         experimental,
         ..
@@ -2168,6 +2572,22 @@ fn build_profile_from_opts(
         profile.metrics_outfile.clone_from(metrics_outfile);
     }
     profile.include_tests |= tests;
+    if let Some(overflow) = overflow {
+        profile.overflow = *overflow;
+    }
+    if let Some(const_eval_limit) = const_eval_limit {
+        profile.const_eval_limit = Some(*const_eval_limit);
+    }
+    for feature in features {
+        if !profile.features.contains(feature) {
+            profile.features.push(feature.clone());
+        }
+    }
+    for kind in emit {
+        if !profile.emit.contains(kind) {
+            profile.emit.push(*kind);
+        }
+    }
     profile.error_on_warnings |= error_on_warnings;
     profile.experimental = ExperimentalFlags {
         new_encoding: experimental.new_encoding,
@@ -2290,7 +2710,12 @@ pub fn build_with_options(build_options: &BuildOpts) -> Result<Built> {
         if let Some(outfile) = &debug_outfile {
             built_package.write_debug_info(outfile.as_ref())?;
         }
-        built_package.write_output(minify, &pkg_manifest.project.name, &output_dir)?;
+        built_package.write_output(
+            minify,
+            &pkg_manifest.project.name,
+            &output_dir,
+            &build_profile.emit,
+        )?;
         built_workspace.push(Arc::new(built_package));
     }
 
@@ -2421,6 +2846,7 @@ pub fn build(
             bail!("Failed to compile {}", pkg.name);
         };
 
+        let is_member = plan.member_nodes().any(|member| member == node);
         let is_contract_dependency = is_contract_dependency(plan.graph(), node);
         // If we are building a contract and tests are enabled or we are building a contract
         // dependency, we need the tests excluded bytecode.
@@ -2436,6 +2862,12 @@ pub fn build(
             //      other pkg, so that injected contract id is not effected by the tests.
             let profile = BuildProfile {
                 include_tests: false,
+                features: if is_member {
+                    profile.features.clone()
+                } else {
+                    vec![]
+                },
+                emit: vec![],
                 ..profile.clone()
             };
 
@@ -2494,10 +2926,15 @@ pub fn build(
             None
         };
 
-        // Build all non member nodes with tests disabled by overriding the current profile.
-        let profile = if !plan.member_nodes().any(|member| member == node) {
+        // Build all non member nodes with tests, features, emitted outputs and function complexity
+        // checks disabled by overriding the current profile.
+        let profile = if !is_member {
             BuildProfile {
                 include_tests: false,
+                features: vec![],
+                emit: vec![],
+                max_function_parameters: None,
+                max_cyclomatic_complexity: None,
                 ..profile.clone()
             }
         } else {
@@ -2556,6 +2993,7 @@ pub fn build(
             bytecode: compiled.bytecode,
             warnings: compiled.warnings,
             bytecode_without_tests,
+            emitted: compiled.emitted,
         };
 
         if outputs.contains(&node) {
@@ -2730,12 +3168,7 @@ mod test {
     use regex::Regex;
 
     fn setup_build_plan() -> BuildPlan {
-        let current_dir = env!("CARGO_MANIFEST_DIR");
-        let manifest_dir = PathBuf::from(current_dir)
-            .parent()
-            .unwrap()
-            .join("test/src/e2e_vm_tests/test_programs/should_pass/forc/workspace_building/");
-        let manifest_file = ManifestFile::from_dir(manifest_dir).unwrap();
+        let manifest_file = ManifestFile::from_dir(workspace_building_dir()).unwrap();
         let member_manifests = manifest_file.member_manifests().unwrap();
         let lock_path = manifest_file.lock_path().unwrap();
         BuildPlan::from_lock_and_manifests(
@@ -2748,6 +3181,215 @@ mod test {
         .unwrap()
     }
 
+    fn workspace_building_dir() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("test/src/e2e_vm_tests/test_programs/should_pass/forc/workspace_building/")
+    }
+
+    fn path_pkg(name: &str, path: PathBuf) -> Pkg {
+        Pkg {
+            name: name.to_string(),
+            source: Source::Path(path),
+        }
+    }
+
+    #[test]
+    fn test_fetch_from_manifests_matches_lock() {
+        let manifest_file = ManifestFile::from_dir(workspace_building_dir()).unwrap();
+        let member_manifests = manifest_file.member_manifests().unwrap();
+        let lock = Lock::from_path(&manifest_file.lock_path().unwrap()).unwrap();
+        let build_plan =
+            BuildPlan::from_manifests(&member_manifests, false, &IPFSNode::default()).unwrap();
+        let new_lock = Lock::from_graph(build_plan.graph());
+        let diff = new_lock.diff(&lock);
+        assert!(diff.added.is_empty(), "{:?}", diff.added);
+        assert!(diff.removed.is_empty(), "{:?}", diff.removed);
+    }
+
+    #[test]
+    fn test_fetch_remote_pkgs_preserves_order() {
+        let dir = workspace_building_dir();
+        let pkgs = vec![
+            path_pkg("test_script", dir.join("test_script")),
+            path_pkg("test_lib", dir.join("test_lib")),
+            path_pkg("test_contract", dir.join("test_contract")),
+        ];
+        let path_root = PinnedId::new("test_script", &source::Pinned::MEMBER);
+        let ipfs_node = IPFSNode::default();
+        let fetched =
+            fetch_remote_pkgs(0, true, &ipfs_node, None, path_root, pkgs.clone()).unwrap();
+        let fetched_pkgs: Vec<_> = fetched.iter().map(|(pkg, _)| pkg.clone()).collect();
+        assert_eq!(fetched_pkgs, pkgs);
+        for (pkg, (_, manifests)) in fetched {
+            let manifest = manifests.values().next().unwrap();
+            assert_eq!(manifest.project.name, pkg.name);
+        }
+    }
+
+    /// Creates a git repository containing a library package named `name` under `dir`.
+    fn git_pkg(dir: &Path, name: &str) -> Pkg {
+        let repo_path = dir.join(name);
+        fs::create_dir_all(repo_path.join("src")).unwrap();
+        fs::write(
+            repo_path.join(constants::MANIFEST_FILE_NAME),
+            format!(
+                "[project]\nauthors = [\"Fuel Labs <contact@fuel.sh>\"]\nentry = \"lib.sw\"\nlicense = \"Apache-2.0\"\nname = \"{name}\"\nimplicit-std = false\n"
+            ),
+        )
+        .unwrap();
+        fs::write(repo_path.join("src").join("lib.sw"), "library;\n").unwrap();
+        let repo = git2::Repository::init(&repo_path).unwrap();
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        let tree_id = index.write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let signature = git2::Signature::now("test", "test@fuel.sh").unwrap();
+        repo.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+            .unwrap();
+        let url = format!("file://{}", repo_path.display());
+        Pkg {
+            name: name.to_string(),
+            source: Source::Git(source::git::Source {
+                repo: url.parse().unwrap(),
+                reference: source::git::Reference::DefaultBranch,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_fetch_remote_pkgs_git() {
+        let dir = tempfile::tempdir().unwrap();
+        let checkouts_dir = tempfile::tempdir().unwrap();
+        let pkgs = vec![
+            git_pkg(dir.path(), "git_dep_a"),
+            git_pkg(dir.path(), "git_dep_b"),
+        ];
+        let path_root = PinnedId::new("test_script", &source::Pinned::MEMBER);
+        let ipfs_node = IPFSNode::default();
+        let fetched = fetch_remote_pkgs(
+            0,
+            false,
+            &ipfs_node,
+            Some(checkouts_dir.path()),
+            path_root,
+            pkgs.clone(),
+        )
+        .unwrap();
+        let fetched_pkgs: Vec<_> = fetched.iter().map(|(pkg, _)| pkg.clone()).collect();
+        assert_eq!(fetched_pkgs, pkgs);
+        for (pkg, (pinned, manifests)) in fetched {
+            assert!(matches!(pinned, source::Pinned::Git(_)));
+            let manifest = manifests.values().next().unwrap();
+            assert_eq!(manifest.project.name, pkg.name);
+            assert!(manifest.dir().starts_with(checkouts_dir.path()));
+        }
+    }
+
+    #[test]
+    fn test_vendored_git_dep() {
+        let dir = tempfile::tempdir().unwrap();
+        let suffix = dir
+            .path()
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_lowercase();
+        let suffix: String = suffix.chars().filter(char::is_ascii_alphanumeric).collect();
+        let dep_name = format!("git_dep_vendored_{suffix}");
+        let Source::Git(dep_source) = git_pkg(dir.path(), &dep_name).source else {
+            unreachable!()
+        };
+        let project_dir = dir.path().join("project");
+        fs::create_dir_all(project_dir.join("src")).unwrap();
+        fs::write(project_dir.join("src").join("lib.sw"), "library;\n").unwrap();
+        let manifest = format!(
+            "[project]\nauthors = [\"Fuel Labs <contact@fuel.sh>\"]\nentry = \"lib.sw\"\nlicense = \"Apache-2.0\"\nname = \"project\"\nimplicit-std = false\n\n[dependencies]\n{dep_name} = {{ git = \"{}\" }}\n",
+            dep_source.repo
+        );
+        fs::write(project_dir.join(constants::MANIFEST_FILE_NAME), &manifest).unwrap();
+
+        // Vendor the dependency and remove its checkout, so that it can only be found vendored.
+        let vendor_dir = project_dir.join("vendor");
+        fs::create_dir_all(&vendor_dir).unwrap();
+        let manifest_file = ManifestFile::from_dir(&project_dir).unwrap();
+        let vendored = crate::vendor::vendor(
+            &manifest_file,
+            &vendor_dir,
+            false,
+            false,
+            &IPFSNode::default(),
+        )
+        .unwrap();
+        assert_eq!(vendored.len(), 1);
+        assert_eq!(vendored[0].pkg.name, dep_name);
+        assert!(vendored[0]
+            .path
+            .join(constants::MANIFEST_FILE_NAME)
+            .exists());
+        let source::Pinned::Git(pinned) = &vendored[0].pkg.source else {
+            panic!("expected a git dependency");
+        };
+        let checkout =
+            source::git::commit_path(&dep_name, &pinned.source.repo, &pinned.commit_hash);
+        fs::remove_dir_all(checkout.parent().unwrap()).unwrap();
+
+        let manifest = format!("{manifest}\n[vendor]\ndirectory = \"vendor\"\n");
+        fs::write(project_dir.join(constants::MANIFEST_FILE_NAME), manifest).unwrap();
+        let manifest_file = ManifestFile::from_dir(&project_dir).unwrap();
+        let member_manifests = manifest_file.member_manifests().unwrap();
+        let lock_path = manifest_file.lock_path().unwrap();
+        let plan = BuildPlan::from_lock_and_manifests(
+            &lock_path,
+            &member_manifests,
+            true,
+            true,
+            &IPFSNode::default(),
+        )
+        .unwrap();
+        let dep_manifest = plan
+            .manifest_map()
+            .values()
+            .find(|manifest| manifest.project.name == dep_name)
+            .unwrap();
+        assert!(dep_manifest
+            .dir()
+            .starts_with(vendor_dir.canonicalize().unwrap()));
+
+        // Without the vendored copy, building fails offline naming the missing dependency.
+        fs::remove_dir_all(vendor_dir.join("git")).unwrap();
+        let err = BuildPlan::from_lock_and_manifests(
+            &lock_path,
+            &member_manifests,
+            true,
+            true,
+            &IPFSNode::default(),
+        )
+        .unwrap_err();
+        assert!(
+            format!("{err:#}").contains(&format!("dependency `{dep_name}` from")),
+            "{err:#}"
+        );
+        assert!(format!("{err:#}").contains("is not vendored"), "{err:#}");
+    }
+
+    #[test]
+    fn test_fetch_remote_pkgs_reports_failed_pkg() {
+        let dir = workspace_building_dir();
+        let pkgs = vec![
+            path_pkg("test_lib", dir.join("test_lib")),
+            path_pkg("missing_a", dir.join("missing_a")),
+            path_pkg("missing_b", dir.join("missing_b")),
+        ];
+        let path_root = PinnedId::new("test_script", &source::Pinned::MEMBER);
+        let ipfs_node = IPFSNode::default();
+        let err = fetch_remote_pkgs(0, true, &ipfs_node, None, path_root, pkgs).unwrap_err();
+        assert_eq!(err.to_string(), "Failed to fetch dependency: missing_a");
+    }
+
     #[test]
     fn test_root_pkg_order() {
         let build_plan = setup_build_plan();
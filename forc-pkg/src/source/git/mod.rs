@@ -6,7 +6,6 @@ use crate::{
     source,
 };
 use anyhow::{anyhow, bail, Context, Result};
-use forc_tracing::println_action_green;
 use forc_util::git_checkouts_directory;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -150,12 +149,24 @@ impl Pinned {
 impl source::Pin for Source {
     type Pinned = Pinned;
     fn pin(&self, ctx: source::PinCtx) -> Result<(Self::Pinned, PathBuf)> {
+        // Vendored sources are never fetched, so the commit must be found in the vendor directory.
+        if let Some(vendor_dir) = ctx.vendor_dir() {
+            let commit_hash = search_source_vendored(vendor_dir, ctx.name(), self)?
+                .ok_or_else(|| source::not_vendored_error(ctx.name(), self, vendor_dir))?;
+            let repo_path = vendored_commit_path(vendor_dir, ctx.name(), &self.repo, &commit_hash);
+            let pinned = Pinned {
+                source: self.clone(),
+                commit_hash,
+            };
+            return Ok((pinned, repo_path));
+        }
         // If the git source directly specifies a full commit hash, we should check
         // to see if we have a local copy. Otherwise we cannot know what commit we should pin
         // to without fetching the repo into a temporary directory.
+        let checkouts_dir = ctx.git_checkouts_dir();
         let pinned = if ctx.offline() {
             let (_local_path, commit_hash) =
-                search_source_locally(ctx.name(), self)?.ok_or_else(|| {
+                search_source_locally(&checkouts_dir, ctx.name(), self)?.ok_or_else(|| {
                     anyhow!(
                         "Unable to fetch pkg {:?} from  {:?} in offline mode",
                         ctx.name(),
@@ -170,11 +181,11 @@ impl source::Pin for Source {
             // If the reference is to a branch or to the default branch we need to fetch
             // from remote even though we may have it locally. Because remote may contain a
             // newer commit.
-            pin(ctx.fetch_id(), ctx.name(), self.clone())?
+            pin_in(&checkouts_dir, ctx.fetch_id(), ctx.name(), self.clone())?
         } else {
             // If we are in online mode and the reference is to a specific commit (tag or
             // rev) we can first search it locally and re-use it.
-            match search_source_locally(ctx.name(), self) {
+            match search_source_locally(&checkouts_dir, ctx.name(), self) {
                 Ok(Some((_local_path, commit_hash))) => Pinned {
                     source: self.clone(),
                     commit_hash,
@@ -182,17 +193,28 @@ impl source::Pin for Source {
                 _ => {
                     // If the checkout we are looking for does not exists locally or an
                     // error happened during the search fetch it
-                    pin(ctx.fetch_id(), ctx.name(), self.clone())?
+                    pin_in(&checkouts_dir, ctx.fetch_id(), ctx.name(), self.clone())?
                 }
             }
         };
-        let repo_path = commit_path(ctx.name(), &pinned.source.repo, &pinned.commit_hash);
+        let repo_path = commit_path_in(
+            &checkouts_dir,
+            ctx.name(),
+            &pinned.source.repo,
+            &pinned.commit_hash,
+        );
         Ok((pinned, repo_path))
     }
 }
 
 impl source::Fetch for Pinned {
     fn fetch(&self, ctx: source::PinCtx, repo_path: &Path) -> Result<PackageManifestFile> {
+        // Vendored sources are used as they are.
+        if let Some(vendor_dir) = ctx.vendor_dir() {
+            let path = manifest::find_within(repo_path, ctx.name())
+                .ok_or_else(|| source::not_vendored_error(ctx.name(), self, vendor_dir))?;
+            return PackageManifestFile::from_file(path);
+        }
         // Co-ordinate access to the git checkout directory using an advisory file lock.
         let mut lock = forc_util::path_lock(repo_path)?;
         // TODO: Here we assume that if the local path already exists, that it contains the
@@ -204,15 +226,12 @@ impl source::Fetch for Pinned {
         {
             let _guard = lock.write()?;
             if !repo_path.exists() {
-                println_action_green(
-                    "Fetching",
-                    &format!(
-                        "{} {}",
-                        ansi_term::Style::new().bold().paint(ctx.name),
-                        self
-                    ),
-                );
-                fetch(ctx.fetch_id(), ctx.name(), self)?;
+                ctx.report_fetching(&format!(
+                    "{} {}",
+                    ansi_term::Style::new().bold().paint(ctx.name),
+                    self
+                ));
+                fetch_in(&ctx.git_checkouts_dir(), ctx.fetch_id(), ctx.name(), self)?;
             }
         }
         let path = {
@@ -375,15 +394,15 @@ fn validate_git_commit_hash(commit_hash: &str) -> Result<()> {
 /// The resulting directory is:
 ///
 /// ```ignore
-/// $HOME/.forc/git/checkouts/tmp/<fetch_id>-name-<repo_url_hash>
+/// <checkouts_dir>/tmp/<fetch_id>-name-<repo_url_hash>
 /// ```
 ///
 /// A unique `fetch_id` may be specified to avoid contention over the git repo directory in the
 /// case that multiple processes or threads may be building different projects that may require
 /// fetching the same dependency.
-fn tmp_git_repo_dir(fetch_id: u64, name: &str, repo: &Url) -> PathBuf {
+fn tmp_git_repo_dir(checkouts_dir: &Path, fetch_id: u64, name: &str, repo: &Url) -> PathBuf {
     let repo_dir_name = format!("{:x}-{}", fetch_id, git_repo_dir_name(name, repo));
-    git_checkouts_directory().join("tmp").join(repo_dir_name)
+    checkouts_dir.join("tmp").join(repo_dir_name)
 }
 
 /// Given a git reference, build a list of `refspecs` required for the fetch operation.
@@ -425,12 +444,18 @@ fn git_ref_to_refspecs(reference: &Reference) -> (Vec<String>, bool) {
 
 /// Initializes a temporary git repo for the package and fetches only the reference associated with
 /// the given source.
-fn with_tmp_git_repo<F, O>(fetch_id: u64, name: &str, source: &Source, f: F) -> Result<O>
+fn with_tmp_git_repo<F, O>(
+    checkouts_dir: &Path,
+    fetch_id: u64,
+    name: &str,
+    source: &Source,
+    f: F,
+) -> Result<O>
 where
     F: FnOnce(git2::Repository) -> Result<O>,
 {
     // Clear existing temporary directory if it exists.
-    let repo_dir = tmp_git_repo_dir(fetch_id, name, &source.repo);
+    let repo_dir = tmp_git_repo_dir(checkouts_dir, fetch_id, name, &source.repo);
     if repo_dir.exists() {
         let _ = std::fs::remove_dir_all(&repo_dir);
     }
@@ -483,7 +508,12 @@ where
 /// This clones the repository to a temporary directory in order to determine the commit at the
 /// HEAD of the given git reference.
 pub fn pin(fetch_id: u64, name: &str, source: Source) -> Result<Pinned> {
-    let commit_hash = with_tmp_git_repo(fetch_id, name, &source, |repo| {
+    pin_in(&git_checkouts_directory(), fetch_id, name, source)
+}
+
+/// Pin the given git-sourced package, using a temporary directory within `checkouts_dir`.
+fn pin_in(checkouts_dir: &Path, fetch_id: u64, name: &str, source: Source) -> Result<Pinned> {
+    let commit_hash = with_tmp_git_repo(checkouts_dir, fetch_id, name, &source, |repo| {
         // Resolve the reference to the commit ID.
         let commit_id = source
             .reference
@@ -507,12 +537,39 @@ pub fn pin(fetch_id: u64, name: &str, source: Source) -> Result<Pinned> {
 ///
 /// where `<repo_url_hash>` is a hash of the source repository URL.
 pub fn commit_path(name: &str, repo: &Url, commit_hash: &str) -> PathBuf {
-    let repo_dir_name = git_repo_dir_name(name, repo);
-    git_checkouts_directory()
-        .join(repo_dir_name)
+    commit_path_in(&git_checkouts_directory(), name, repo, commit_hash)
+}
+
+/// The path to which a git package commit should be checked out within `checkouts_dir`.
+fn commit_path_in(checkouts_dir: &Path, name: &str, repo: &Url, commit_hash: &str) -> PathBuf {
+    checkouts_dir
+        .join(git_repo_dir_name(name, repo))
+        .join(commit_hash)
+}
+
+/// The path to which a git package commit is vendored by `forc vendor`.
+///
+/// The resulting directory mirrors the layout of the checkouts directory:
+///
+/// ```ignore
+/// <vendor_dir>/git/name-<repo_url_hash>/<commit_hash>
+/// ```
+pub fn vendored_commit_path(
+    vendor_dir: &Path,
+    name: &str,
+    repo: &Url,
+    commit_hash: &str,
+) -> PathBuf {
+    vendored_checkouts_dir(vendor_dir)
+        .join(git_repo_dir_name(name, repo))
         .join(commit_hash)
 }
 
+/// The directory within the given vendor directory containing the vendored git packages.
+pub(crate) fn vendored_checkouts_dir(vendor_dir: &Path) -> PathBuf {
+    vendor_dir.join("git")
+}
+
 /// Fetch the repo at the given git package's URL and checkout the pinned commit.
 ///
 /// Returns the location of the checked out commit.
@@ -520,9 +577,20 @@ pub fn commit_path(name: &str, repo: &Url, commit_hash: &str) -> PathBuf {
 /// NOTE: This function assumes that the caller has acquired an advisory lock to co-ordinate access
 /// to the git repository checkout path.
 pub fn fetch(fetch_id: u64, name: &str, pinned: &Pinned) -> Result<PathBuf> {
-    let path = commit_path(name, &pinned.source.repo, &pinned.commit_hash);
+    fetch_in(&git_checkouts_directory(), fetch_id, name, pinned)
+}
+
+/// Fetch the repo at the given git package's URL and checkout the pinned commit within
+/// `checkouts_dir`.
+fn fetch_in(checkouts_dir: &Path, fetch_id: u64, name: &str, pinned: &Pinned) -> Result<PathBuf> {
+    let path = commit_path_in(
+        checkouts_dir,
+        name,
+        &pinned.source.repo,
+        &pinned.commit_hash,
+    );
     // Checkout the pinned hash to the path.
-    with_tmp_git_repo(fetch_id, name, &pinned.source, |repo| {
+    with_tmp_git_repo(checkouts_dir, fetch_id, name, &pinned.source, |repo| {
         // Change HEAD to point to the pinned commit.
         let id = git2::Oid::from_str(&pinned.commit_hash)?;
         repo.set_head_detached(id)?;
@@ -564,15 +632,16 @@ pub fn fetch(fetch_id: u64, name: &str, pinned: &Pinned) -> Result<PathBuf> {
 /// Search local checkout dir for git sources, for non-branch git references tries to find the
 /// exact match. For branch references, tries to find the most recent repo present locally with the given repo
 pub(crate) fn search_source_locally(
+    checkouts_dir: &Path,
     name: &str,
     git_source: &Source,
 ) -> Result<Option<(PathBuf, String)>> {
     // In the checkouts dir iterate over dirs whose name starts with `name`
-    let checkouts_dir = git_checkouts_directory();
     match &git_source.reference {
         Reference::Branch(branch) => {
             // Collect repos from this branch with their HEAD time
-            let repos_from_branch = collect_local_repos_with_branch(checkouts_dir, name, branch)?;
+            let repos_from_branch =
+                collect_local_repos_with_branch(checkouts_dir.to_path_buf(), name, branch)?;
             // Get the newest repo by their HEAD commit times
             let newest_branch_repo = repos_from_branch
                 .into_iter()
@@ -580,8 +649,42 @@ pub(crate) fn search_source_locally(
                 .map(|(repo_path, (hash, _))| (repo_path, hash));
             Ok(newest_branch_repo)
         }
-        _ => find_exact_local_repo_with_reference(checkouts_dir, name, &git_source.reference),
+        _ => find_exact_local_repo_with_reference(
+            checkouts_dir.to_path_buf(),
+            name,
+            &git_source.reference,
+        ),
+    }
+}
+
+/// Search the vendor directory for a commit of the given git source, returning the hash of the most
+/// recent one vendored for the source's reference.
+fn search_source_vendored(
+    vendor_dir: &Path,
+    name: &str,
+    git_source: &Source,
+) -> Result<Option<String>> {
+    let repo_dir =
+        vendored_checkouts_dir(vendor_dir).join(git_repo_dir_name(name, &git_source.repo));
+    if !repo_dir.exists() {
+        return Ok(None);
+    }
+    let mut newest_commit: Option<HeadWithTime> = None;
+    for commit_dir in fs::read_dir(repo_dir)? {
+        let index_path = commit_dir?.path().join(".forc_index");
+        let Ok(index_file) = fs::read_to_string(index_path) else {
+            continue;
+        };
+        let index: SourceIndex = serde_json::from_str(&index_file)?;
+        if index.git_reference == git_source.reference
+            && newest_commit
+                .as_ref()
+                .map_or(true, |(_, time)| *time < index.head_with_time.1)
+        {
+            newest_commit = Some(index.head_with_time);
+        }
     }
+    Ok(newest_commit.map(|(commit_hash, _)| commit_hash))
 }
 
 /// Search and collect repos from checkouts_dir that are from given branch and for the given package
@@ -4,7 +4,6 @@ use crate::{
     source,
 };
 use anyhow::Result;
-use forc_tracing::println_action_green;
 use futures::TryStreamExt;
 use ipfs_api::IpfsApi;
 use ipfs_api_backend_hyper as ipfs_api;
@@ -48,9 +47,17 @@ impl FromStr for Cid {
 
 impl source::Pin for Source {
     type Pinned = Pinned;
-    fn pin(&self, _ctx: source::PinCtx) -> Result<(Self::Pinned, PathBuf)> {
+    fn pin(&self, ctx: source::PinCtx) -> Result<(Self::Pinned, PathBuf)> {
         let cid = &self.0;
         let pinned = Pinned(cid.clone());
+        // Vendored sources are never fetched, so they must be present in the vendor directory.
+        if let Some(vendor_dir) = ctx.vendor_dir() {
+            let path = vendored_path(vendor_dir, cid);
+            if !path.exists() {
+                return Err(source::not_vendored_error(ctx.name(), &pinned, vendor_dir));
+            }
+            return Ok((pinned, path));
+        }
         let path = pkg_cache_dir(cid);
         Ok((pinned, path))
     }
@@ -58,6 +65,12 @@ impl source::Pin for Source {
 
 impl source::Fetch for Pinned {
     fn fetch(&self, ctx: source::PinCtx, repo_path: &Path) -> Result<PackageManifestFile> {
+        // Vendored sources are used as they are.
+        if let Some(vendor_dir) = ctx.vendor_dir() {
+            let path = manifest::find_within(repo_path, ctx.name())
+                .ok_or_else(|| source::not_vendored_error(ctx.name(), self, vendor_dir))?;
+            return PackageManifestFile::from_file(path);
+        }
         // TODO: implement local cache search for ipfs sources.
         if ctx.offline {
             anyhow::bail!("offline fetching for IPFS sources is not supported")
@@ -67,31 +80,25 @@ impl source::Fetch for Pinned {
         {
             let _guard = lock.write()?;
             if !repo_path.exists() {
-                println_action_green(
-                    "Fetching",
-                    &format!(
-                        "{} {}",
-                        ansi_term::Style::new().bold().paint(ctx.name),
-                        self
-                    ),
-                );
+                ctx.report_fetching(&format!(
+                    "{} {}",
+                    ansi_term::Style::new().bold().paint(ctx.name),
+                    self
+                ));
                 let cid = &self.0;
                 let ipfs_client = ipfs_client();
                 let dest = cache_dir();
                 futures::executor::block_on(async {
                     match ctx.ipfs_node() {
                         source::IPFSNode::Local => {
-                            println_action_green("Fetching", "with local IPFS node");
+                            ctx.report_fetching("with local IPFS node");
                             cid.fetch_with_client(&ipfs_client, &dest).await
                         }
                         source::IPFSNode::WithUrl(ipfs_node_gateway_url) => {
-                            println_action_green(
-                                "Fetching",
-                                &format!(
-                                    "from {}. Note: This can take several minutes.",
-                                    ipfs_node_gateway_url
-                                ),
-                            );
+                            ctx.report_fetching(&format!(
+                                "from {}. Note: This can take several minutes.",
+                                ipfs_node_gateway_url
+                            ));
                             cid.fetch_with_gateway_url(ipfs_node_gateway_url, &dest)
                                 .await
                         }
@@ -221,10 +228,20 @@ fn cache_dir() -> PathBuf {
     ipfs_dir().join(IPFS_CACHE_DIR_NAME)
 }
 
-fn pkg_cache_dir(cid: &Cid) -> PathBuf {
+pub(crate) fn pkg_cache_dir(cid: &Cid) -> PathBuf {
     cache_dir().join(format!("{}", cid.0))
 }
 
+/// The path to which an IPFS package is vendored by `forc vendor`.
+pub(crate) fn vendored_path(vendor_dir: &Path, cid: &Cid) -> PathBuf {
+    vendored_cache_dir(vendor_dir).join(format!("{}", cid.0))
+}
+
+/// The directory within the given vendor directory containing the vendored IPFS packages.
+pub(crate) fn vendored_cache_dir(vendor_dir: &Path) -> PathBuf {
+    vendor_dir.join(IPFS_DIR_NAME)
+}
+
 /// Returns a `IpfsClient` instance ready to be used to make requests to local ipfs node.
 fn ipfs_client() -> IpfsClient {
     IpfsClient::default()
@@ -20,6 +20,7 @@ use crate::{
     pkg::{ManifestMap, PinnedId},
 };
 use anyhow::{anyhow, bail, Result};
+use forc_tracing::println_action_green;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::hash_map,
@@ -27,6 +28,7 @@ use std::{
     hash::{Hash, Hasher},
     path::{Path, PathBuf},
     str::FromStr,
+    sync::mpsc,
 };
 use sway_utils::DEFAULT_IPFS_GATEWAY_URL;
 
@@ -123,6 +125,23 @@ pub(crate) struct PinCtx<'a> {
     pub(crate) name: &'a str,
     /// The IPFS node to use for fetching IPFS sources.
     pub(crate) ipfs_node: &'a IPFSNode,
+    /// The directory from which git and IPFS sources are taken instead of fetching them, if any.
+    pub(crate) vendor_dir: Option<&'a Path>,
+    /// The directory into which git sources are checked out instead of the user's forc
+    /// directory, if any.
+    pub(crate) git_checkouts_dir: Option<&'a Path>,
+    /// Where to report the progress of fetching the package.
+    pub(crate) progress: FetchProgress<'a>,
+}
+
+/// Where the progress of fetching a package is reported.
+#[derive(Clone, Copy)]
+pub(crate) enum FetchProgress<'a> {
+    /// The progress is printed right away.
+    Print,
+    /// The progress is sent to the thread coordinating concurrent fetches, which is the only
+    /// one printing it.
+    Send(&'a mpsc::Sender<String>),
 }
 
 pub(crate) enum DependencyPath {
@@ -252,6 +271,15 @@ impl Source {
         }
     }
 
+    /// The directory containing the package's manifest if the package is available locally,
+    /// i.e. for member and path sources.
+    pub(crate) fn local_path(&self) -> Option<&Path> {
+        match self {
+            Source::Member(member::Source(path)) | Source::Path(path) => Some(path),
+            Source::Git(_) | Source::Ipfs(_) | Source::Registry(_) => None,
+        }
+    }
+
     /// Attempt to determine the pinned version or commit for the source.
     ///
     /// Also updates the manifest map with a path to the local copy of the pkg.
@@ -286,7 +314,16 @@ impl Pinned {
     pub(crate) const MEMBER: Self = Self::Member(member::Pinned);
 
     /// Return how the pinned source for a dependency can be found on the local file system.
-    pub(crate) fn dep_path(&self, name: &str) -> Result<DependencyPath> {
+    ///
+    /// If a `vendor_dir` is given, git and IPFS sources are looked up within it.
+    pub(crate) fn dep_path(&self, name: &str, vendor_dir: Option<&Path>) -> Result<DependencyPath> {
+        if let Some(vendor_dir) = vendor_dir {
+            if let Some(vendored_path) = self.vendored_path(name, vendor_dir) {
+                let path = manifest::find_within(&vendored_path, name)
+                    .ok_or_else(|| not_vendored_error(name, self, vendor_dir))?;
+                return Ok(DependencyPath::ManifestPath(path));
+            }
+        }
         match self {
             Self::Member(pinned) => pinned.dep_path(name),
             Self::Path(pinned) => pinned.dep_path(name),
@@ -296,6 +333,22 @@ impl Pinned {
         }
     }
 
+    /// The directory within the given `vendor_dir` to which the source is vendored.
+    ///
+    /// Returns `None` for sources that are never vendored, i.e. member and path sources.
+    pub(crate) fn vendored_path(&self, name: &str, vendor_dir: &Path) -> Option<PathBuf> {
+        match self {
+            Self::Git(git) => Some(git::vendored_commit_path(
+                vendor_dir,
+                name,
+                &git.source.repo,
+                &git.commit_hash,
+            )),
+            Self::Ipfs(ipfs) => Some(ipfs::vendored_path(vendor_dir, &ipfs.0)),
+            Self::Member(_) | Self::Path(_) | Self::Registry(_) => None,
+        }
+    }
+
     /// If the source is associated with a specific semver version, emit it.
     ///
     /// Used solely for the package lock file.
@@ -347,6 +400,23 @@ impl<'a> PinCtx<'a> {
     fn ipfs_node(&self) -> &'a IPFSNode {
         self.ipfs_node
     }
+    fn vendor_dir(&self) -> Option<&'a Path> {
+        self.vendor_dir
+    }
+    fn git_checkouts_dir(&self) -> PathBuf {
+        self.git_checkouts_dir
+            .map_or_else(forc_util::git_checkouts_directory, Path::to_path_buf)
+    }
+    /// Report that the package is being fetched, e.g. "Fetching std <source>".
+    fn report_fetching(&self, message: &str) {
+        match self.progress {
+            FetchProgress::Print => println_action_green("Fetching", message),
+            // The receiver only goes away once all fetches are done.
+            FetchProgress::Send(sender) => {
+                let _ = sender.send(message.to_string());
+            }
+        }
+    }
 }
 
 impl fmt::Display for Pinned {
@@ -394,6 +464,19 @@ impl FromStr for Pinned {
     }
 }
 
+/// Format an error message if a dependency is missing from the vendor directory.
+pub(crate) fn not_vendored_error(
+    name: &str,
+    source: impl fmt::Display,
+    vendor_dir: &Path,
+) -> anyhow::Error {
+    anyhow!(
+        "dependency `{name}` from {source} is not vendored in {}. \
+        Run `forc vendor` to update the vendor directory",
+        vendor_dir.display()
+    )
+}
+
 /// Produce a unique ID for a particular fetch pass.
 ///
 /// This is used in the temporary git directory and allows for avoiding contention over the git
@@ -0,0 +1,151 @@
+//! Copying the remote dependencies of a project into a vendor directory, so that the project can
+//! be built without fetching them.
+//!
+//! Git and IPFS dependencies are copied into the vendor directory using the same layout as the
+//! user's `.forc` directory:
+//!
+//! ```ignore
+//! <vendor_dir>/git/name-<repo_url_hash>/<commit_hash>
+//! <vendor_dir>/ipfs/<cid>
+//! ```
+//!
+//! Path dependencies declared within a remote package are copied along with it, preserving their
+//! location relative to the package. The vendored sources are used in place of the remote ones
+//! once the vendor directory is specified in the manifest:
+//!
+//! ```toml
+//! [vendor]
+//! directory = "vendor"
+//! ```
+
+use crate::{
+    manifest::{GenericManifestFile, ManifestFile},
+    source::{self, IPFSNode},
+    BuildPlan, Pinned,
+};
+use anyhow::{anyhow, Context, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A remote package that was copied into the vendor directory.
+#[derive(Clone, Debug)]
+pub struct VendoredPkg {
+    /// The vendored package.
+    pub pkg: Pinned,
+    /// The directory to which the package's source was copied.
+    pub path: PathBuf,
+}
+
+/// Copy all git and IPFS dependencies of the project described by `manifest_file` into the given
+/// `vendor_dir`, replacing any previously vendored sources.
+///
+/// The dependencies are resolved the same way as when building, accounting for the `Forc.lock`
+/// file, but always from their remote sources rather than from a vendor directory specified in the
+/// manifest.
+pub fn vendor(
+    manifest_file: &ManifestFile,
+    vendor_dir: &Path,
+    locked: bool,
+    offline: bool,
+    ipfs_node: &IPFSNode,
+) -> Result<Vec<VendoredPkg>> {
+    let member_manifests = manifest_file.member_manifests()?;
+    let lock_path = manifest_file.lock_path()?;
+    let plan = BuildPlan::from_lock_and_manifests_with_vendor_dir(
+        &lock_path,
+        &member_manifests,
+        locked,
+        offline,
+        ipfs_node,
+        None,
+    )?;
+    let graph = plan.graph();
+
+    for kind_dir in [
+        source::git::vendored_checkouts_dir(vendor_dir),
+        source::ipfs::vendored_cache_dir(vendor_dir),
+    ] {
+        if kind_dir.exists() {
+            fs::remove_dir_all(&kind_dir)
+                .with_context(|| format!("failed to remove {}", kind_dir.display()))?;
+        }
+    }
+
+    let mut vendored = vec![];
+    for node in graph.node_indices() {
+        // Packages are vendored along with the remote package that is the root of their subgraph
+        // of path dependencies.
+        let root_node = crate::pkg::find_path_root(graph, node)?;
+        let root = &graph[root_node];
+        let Some(fetched_root) = fetched_path(root) else {
+            continue;
+        };
+        let vendored_root = root
+            .source
+            .vendored_path(&root.name, vendor_dir)
+            .ok_or_else(|| anyhow!("cannot vendor package `{}`", root.name))?;
+        let fetched_root = fetched_root
+            .canonicalize()
+            .with_context(|| format!("failed to find the source of package `{}`", root.name))?;
+
+        let pkg = &graph[node];
+        let pkg_dir = plan.manifest_map()[&pkg.id()].dir();
+        let relative_dir = pkg_dir.strip_prefix(&fetched_root).map_err(|_| {
+            anyhow!(
+                "package `{}` at {} is outside of the source of package `{}`",
+                pkg.name,
+                pkg_dir.display(),
+                root.name
+            )
+        })?;
+        copy_dir(pkg_dir, &vendored_root.join(relative_dir))?;
+
+        if node == root_node {
+            // The index is required to pin git sources to the vendored commit.
+            let index_path = fetched_root.join(".forc_index");
+            if index_path.exists() {
+                fs::copy(&index_path, vendored_root.join(".forc_index"))?;
+            }
+            vendored.push(VendoredPkg {
+                pkg: pkg.clone(),
+                path: vendored_root,
+            });
+        }
+    }
+    Ok(vendored)
+}
+
+/// The directory from which the given package was fetched, if it is a remote package.
+fn fetched_path(pkg: &Pinned) -> Option<PathBuf> {
+    match &pkg.source {
+        source::Pinned::Git(git) => Some(source::git::commit_path(
+            &pkg.name,
+            &git.source.repo,
+            &git.commit_hash,
+        )),
+        source::Pinned::Ipfs(ipfs) => Some(source::ipfs::pkg_cache_dir(&ipfs.0)),
+        source::Pinned::Member(_) | source::Pinned::Path(_) | source::Pinned::Registry(_) => None,
+    }
+}
+
+/// Recursively copy the contents of the `src` directory into the `dst` directory.
+fn copy_dir(src: &Path, dst: &Path) -> Result<()> {
+    for entry in walkdir::WalkDir::new(src) {
+        let entry = entry?;
+        let target = dst.join(entry.path().strip_prefix(src)?);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else {
+            fs::copy(entry.path(), &target).with_context(|| {
+                format!(
+                    "failed to copy {} to {}",
+                    entry.path().display(),
+                    target.display()
+                )
+            })?;
+        }
+    }
+    Ok(())
+}
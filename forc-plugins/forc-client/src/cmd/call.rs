@@ -0,0 +1,57 @@
+use crate::NodeTarget;
+use clap::Parser;
+pub use forc_tx::Gas;
+use fuel_crypto::SecretKey;
+use std::path::PathBuf;
+
+forc_util::cli_examples! {
+    super::Command {
+        [ Call a contract method => "forc call --abi {path}/counter-abi.json --call \"0x0000000000000000000000000000000000000000000000000000000000000001 increment 1\"" ]
+        [ Call several contract methods in a single transaction => "forc call --abi {path}/counter-abi.json --call \"0x0000000000000000000000000000000000000000000000000000000000000001 increment 1\" --call \"0x0000000000000000000000000000000000000000000000000000000000000001 count\"" ]
+        [ Call methods of contracts with different ABIs => "forc call --abi 0x0000000000000000000000000000000000000000000000000000000000000001:{path}/counter-abi.json --abi 0x0000000000000000000000000000000000000000000000000000000000000002:{path}/token-abi.json --call \"0x0000000000000000000000000000000000000000000000000000000000000001 count\" --call \"0x0000000000000000000000000000000000000000000000000000000000000002 total_supply\"" ]
+    }
+}
+
+/// Call contract methods.
+/// Crafts a single script transaction containing all of the given calls then sends it to a
+/// running node.
+#[derive(Debug, Default, Parser)]
+#[clap(bin_name = "forc call", version, after_help = help())]
+pub struct Command {
+    /// A contract method to call, in the form `"<CONTRACT_ID> <FUNCTION> [ARGS]..."`.
+    ///
+    /// Can be repeated to make several calls within the same transaction. The calls are made
+    /// in the order in which they are given.
+    #[clap(long = "call", required = true)]
+    pub calls: Vec<String>,
+    /// Path to the JSON ABI of the called contracts, used for encoding the arguments and
+    /// decoding the results of the calls.
+    ///
+    /// When calling contracts with different ABIs, use `--abi <CONTRACT_ID>:<PATH>` to specify
+    /// the ABI of each contract. An ABI given without a contract ID is used for all the contracts
+    /// that don't have their own.
+    #[clap(long = "abi", required = true)]
+    pub abis: Vec<String>,
+    #[clap(flatten)]
+    pub gas: Gas,
+    #[clap(flatten)]
+    pub node: NodeTarget,
+    /// Execute the transaction and return the results of the calls without inserting the
+    /// transaction in the node's view of the blockchain, (i.e. it does not affect the chain state).
+    #[clap(long)]
+    pub simulate: bool,
+    /// Write the signed transaction to the given file instead of submitting it.
+    ///
+    /// Paths to files ending with `.json` will be serialized to JSON.
+    /// Paths to files ending with `.bin` will be serialized to bytes.
+    ///
+    /// The transaction can later be submitted with `forc submit`.
+    #[clap(long, conflicts_with = "simulate")]
+    pub output_tx: Option<PathBuf>,
+    /// Sign the transaction with default signer that is pre-funded by fuel-core. Useful for testing against local node.
+    #[clap(long)]
+    pub default_signer: bool,
+    /// Set the key to be used for signing.
+    #[clap(long)]
+    pub signing_key: Option<SecretKey>,
+}
@@ -5,12 +5,14 @@ use forc_pkg::BuildProfile;
 pub use forc_tx::{Gas, Maturity};
 pub use forc_util::tx_utils::Salt;
 use fuel_crypto::SecretKey;
+use std::path::PathBuf;
 
 forc_util::cli_examples! {
    super::Command {
         [ Deploy a single contract => "forc deploy bc09bfa7a11a04ce42b0a5abf04fd437387ee49bf4561d575177e2946468b408" ]
         [ Deploy a single contract from a different path => "forc deploy bc09bfa7a11a04ce42b0a5abf04fd437387ee49bf4561d575177e2946468b408 --path {path}" ]
         [ Deploy to a custom network => "forc deploy --node-url https://beta-5.fuel.network/graphql" ]
+        [ Write the signed deployment transaction to a file instead of submitting it => "forc deploy --output-tx deploy.json" ]
     }
 }
 
@@ -58,6 +60,14 @@ pub struct Command {
     /// Submit the deployment transaction(s) without waiting for execution to complete.
     #[clap(long)]
     pub submit_only: bool,
+    /// Write the signed deployment transaction to the given file instead of submitting it.
+    ///
+    /// Paths to files ending with `.json` will be serialized to JSON.
+    /// Paths to files ending with `.bin` will be serialized to bytes.
+    ///
+    /// The transaction can later be submitted with `forc submit`.
+    #[clap(long, conflicts_with = "submit_only")]
+    pub output_tx: Option<PathBuf>,
     /// Set the key to be used for signing.
     pub signing_key: Option<SecretKey>,
     /// Sign the deployment transaction manually.
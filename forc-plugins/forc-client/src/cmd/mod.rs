@@ -1,7 +1,9 @@
+pub mod call;
 pub mod deploy;
 pub mod run;
 pub mod submit;
 
+pub use call::Command as Call;
 pub use deploy::Command as Deploy;
 pub use run::Command as Run;
 pub use submit::Command as Submit;
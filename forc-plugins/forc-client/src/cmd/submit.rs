@@ -9,6 +9,7 @@ forc_util::cli_examples! {
         [ Submit a transaction from a json file and get output in json => "forc submit {path}/mint.json --tx-status-json true" ]
         [ Submit a transaction from a json file to testnet => "forc submit {path}/mint.json --testnet" ]
         [ Submit a transaction from a json file to a local net => "forc submit {path}/mint.json --target local" ]
+        [ Submit a transaction from a binary file to a custom node => "forc submit {path}/mint.bin --node http://127.0.0.1:4000/v1/graphql" ]
     }
 }
 
@@ -15,7 +15,7 @@ pub struct NodeTarget {
     /// to `http://127.0.0.1:4000`
     ///
     /// You can also use `--target` or `--testnet` to specify the Fuel node.
-    #[clap(long, env = "FUEL_NODE_URL")]
+    #[clap(long, visible_alias = "node", env = "FUEL_NODE_URL")]
     pub node_url: Option<String>,
     /// Use preset configurations for deploying to a specific target.
     ///
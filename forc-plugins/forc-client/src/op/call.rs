@@ -0,0 +1,377 @@
+use crate::{
+    cmd,
+    op::submit::write_tx,
+    util::{
+        encode::{Token, Type},
+        node_url::get_node_url,
+        tx::{prompt_forc_wallet_password, select_account, SignerSelectionMode},
+    },
+};
+use anyhow::{anyhow, bail, Context, Result};
+use fuel_abi_types::abi::full_program::{FullABIFunction, FullProgramABI};
+use fuel_tx::{ContractId, Receipt};
+use fuels::{
+    programs::calls::{CallHandler, CallParameters, ContractCall, Execution},
+    types::{
+        bech32::Bech32ContractId,
+        errors::{transaction::Reason, Error},
+        transaction::TxPolicies,
+    },
+};
+use fuels_accounts::provider::Provider;
+use fuels_core::codec::{encode_fn_selector, ABIEncoder, EncoderConfig};
+use std::{collections::HashMap, path::PathBuf, str::FromStr};
+use tracing::info;
+
+/// A single contract method call, as given via `--call`.
+#[derive(Debug, PartialEq, Eq)]
+struct CallSpec {
+    contract_id: ContractId,
+    function: String,
+    args: Vec<String>,
+}
+
+/// The decoded result of a single contract method call.
+#[derive(Debug)]
+pub struct CallResult {
+    pub contract_id: ContractId,
+    pub function: String,
+    pub value: fuels_core::types::Token,
+}
+
+/// Calls the given contract methods within a single transaction.
+///
+/// Upon success, returns the decoded results of the calls in the order the calls are given.
+/// If one of the calls reverts, the returned error names the call that failed.
+pub async fn call(command: cmd::Call) -> Result<Vec<CallResult>> {
+    let specs = command
+        .calls
+        .iter()
+        .map(|call| parse_call_spec(call))
+        .collect::<Result<Vec<_>>>()?;
+    let abis = parse_abis(&command.abis)?;
+    let contract_calls = specs
+        .iter()
+        .map(|spec| {
+            let abi = abis
+                .get(&Some(spec.contract_id))
+                .or_else(|| abis.get(&None));
+            let (path, abi) =
+                abi.ok_or_else(|| anyhow!("no ABI provided for contract 0x{}", spec.contract_id))?;
+            let function = abi
+                .functions
+                .iter()
+                .find(|function| function.name() == spec.function)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "function `{}` not found in ABI {}",
+                        spec.function,
+                        path.display()
+                    )
+                })?;
+            contract_call(spec, function)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let node_url = get_node_url(&command.node, &None)?;
+    let provider = Provider::connect(node_url).await?;
+    let wallet_mode = if command.default_signer || command.signing_key.is_some() {
+        SignerSelectionMode::Manual
+    } else {
+        let password = prompt_forc_wallet_password()?;
+        SignerSelectionMode::ForcWallet(password)
+    };
+    let tx_count = 1;
+    let account = select_account(
+        &wallet_mode,
+        command.default_signer,
+        command.signing_key,
+        &provider,
+        tx_count,
+    )
+    .await?;
+
+    let mut handler = CallHandler::new_multi_call(account).with_tx_policies(tx_policies(&command));
+    handler.call = contract_calls;
+    if let Some(output_tx) = &command.output_tx {
+        let tx = handler.build_tx().await?;
+        write_tx(output_tx, &tx.into())?;
+        info!("Wrote the call transaction to {}", output_tx.display());
+        return Ok(vec![]);
+    }
+    let response = if command.simulate {
+        handler
+            .simulate::<fuels_core::types::Token>(Execution::Realistic)
+            .await
+    } else {
+        handler.call::<fuels_core::types::Token>().await
+    };
+    let response = response.map_err(|err| match err {
+        Error::Transaction(Reason::Reverted {
+            ref reason,
+            ref receipts,
+            ..
+        }) => match failed_call_index(receipts).and_then(|ix| specs.get(ix).map(|s| (ix, s))) {
+            Some((ix, spec)) => anyhow!(
+                "call #{} `{}` to contract 0x{} reverted: {reason}",
+                ix + 1,
+                spec.function,
+                spec.contract_id
+            ),
+            None => err.into(),
+        },
+        err => err.into(),
+    })?;
+
+    let fuels_core::types::Token::Tuple(values) = response.value else {
+        bail!("unexpected result of the calls: {:?}", response.value);
+    };
+    let results = specs
+        .into_iter()
+        .zip(values)
+        .map(|(spec, value)| {
+            info!(
+                "0x{}::{} returned {}",
+                spec.contract_id,
+                spec.function,
+                Token(value.clone())
+            );
+            CallResult {
+                contract_id: spec.contract_id,
+                function: spec.function,
+                value,
+            }
+        })
+        .collect();
+    Ok(results)
+}
+
+fn tx_policies(cmd: &cmd::Call) -> TxPolicies {
+    let mut tx_policies = TxPolicies::default();
+    if let Some(max_fee) = cmd.gas.max_fee {
+        tx_policies = tx_policies.with_max_fee(max_fee);
+    }
+    if let Some(script_gas_limit) = cmd.gas.script_gas_limit {
+        tx_policies = tx_policies.with_script_gas_limit(script_gas_limit);
+    }
+    tx_policies
+}
+
+fn parse_call_spec(call: &str) -> Result<CallSpec> {
+    let call = call.split_whitespace().collect::<Vec<_>>();
+    let [contract_id, function, args @ ..] = call.as_slice() else {
+        bail!("a call must be given in the form `<CONTRACT_ID> <FUNCTION> [ARGS]...`");
+    };
+    let contract_id = ContractId::from_str(contract_id)
+        .map_err(|e| anyhow!("Failed to parse contract id: {}", e))?;
+    Ok(CallSpec {
+        contract_id,
+        function: function.to_string(),
+        args: args.iter().map(|arg| arg.to_string()).collect(),
+    })
+}
+
+/// Parses the `--abi` arguments into a map from contract IDs to ABIs. The ABI given without a
+/// contract ID is stored under `None`.
+fn parse_abis(
+    abi_args: &[String],
+) -> Result<HashMap<Option<ContractId>, (PathBuf, FullProgramABI)>> {
+    let mut abis = HashMap::new();
+    for abi_arg in abi_args {
+        let (contract_id, path) = split_abi_arg(abi_arg);
+        let json_abi = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read ABI {}", path.display()))?;
+        let abi = FullProgramABI::from_json_abi(&json_abi)
+            .with_context(|| format!("failed to parse ABI {}", path.display()))?;
+        if abis.insert(contract_id, (path, abi)).is_some() {
+            match contract_id {
+                Some(contract_id) => bail!("2 ABIs provided for contract 0x{contract_id}"),
+                None => bail!("2 ABIs provided without a contract ID"),
+            }
+        }
+    }
+    Ok(abis)
+}
+
+/// Splits an `--abi` argument into the optional contract ID and the path to the ABI.
+///
+/// The argument is only split if the part before the first `:` is a `0x`-prefixed contract ID,
+/// so that paths like `C:\abi.json` are taken as a whole.
+fn split_abi_arg(abi_arg: &str) -> (Option<ContractId>, PathBuf) {
+    let split = abi_arg.split_once(':').and_then(|(contract_id, path)| {
+        if !contract_id.starts_with("0x") {
+            return None;
+        }
+        let contract_id = ContractId::from_str(contract_id).ok()?;
+        Some((contract_id, path))
+    });
+    match split {
+        Some((contract_id, path)) => (Some(contract_id), PathBuf::from(path)),
+        None => (None, PathBuf::from(abi_arg)),
+    }
+}
+
+/// Encodes the call described by `spec` to the ABI `function`.
+fn contract_call(spec: &CallSpec, function: &FullABIFunction) -> Result<ContractCall> {
+    let arg_types = function
+        .inputs()
+        .iter()
+        .map(Type::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    if arg_types.len() != spec.args.len() {
+        bail!(
+            "function `{}` takes {} arguments, {} provided",
+            spec.function,
+            arg_types.len(),
+            spec.args.len()
+        );
+    }
+    let args = arg_types
+        .iter()
+        .zip(spec.args.iter())
+        .map(|(ty, value)| Token::from_type_and_value(ty, value).map(|token| token.0))
+        .collect::<Result<Vec<_>>>()?;
+    let output_type = Type::try_from(function.output())?;
+    Ok(ContractCall {
+        contract_id: Bech32ContractId::from(spec.contract_id),
+        encoded_args: ABIEncoder::new(EncoderConfig::default()).encode(&args),
+        encoded_selector: encode_fn_selector(&spec.function),
+        call_parameters: CallParameters::default(),
+        external_contracts: vec![],
+        output_param: (&output_type).into(),
+        is_payable: function.is_payable(),
+        custom_assets: Default::default(),
+    })
+}
+
+/// Returns the index of the call that was in progress when the transaction reverted.
+///
+/// Each of the calls is made by the transaction script, so it's the number of `Call` receipts
+/// issued by the script, i.e. with the zero contract ID, that tells how far the script got.
+fn failed_call_index(receipts: &[Receipt]) -> Option<usize> {
+    receipts
+        .iter()
+        .filter(
+            |receipt| matches!(receipt, Receipt::Call { id, .. } if *id == ContractId::zeroed()),
+        )
+        .count()
+        .checked_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTRACT_ID: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+    const TEST_JSON_ABI: &str = r#"{"programType": "contract","specVersion": "1","encodingVersion": "1","metadataTypes":[],
+    "concreteTypes":[{"concreteTypeId":"2e38e77b22c314a449e91fafed92a43826ac6aa403ae6a8acb6cf58239fbaf5d",
+    "type":"()"},{"concreteTypeId":"b760f44fa5965c2474a3b471467a22c43185152129295af588b022ae50b50903","type":"bool"},
+    {"concreteTypeId":"c89951a24c6ca28c13fd1cfdc646b2b656d69e61a92b91023be7eb58eb914b6b","type":"u8"}],
+    "functions":[{"inputs":[{"name":"test_u8","concreteTypeId":"c89951a24c6ca28c13fd1cfdc646b2b656d69e61a92b91023be7eb58eb914b6b"},
+    {"name":"test_bool","concreteTypeId":"b760f44fa5965c2474a3b471467a22c43185152129295af588b022ae50b50903"}],"name":"test_function",
+    "output":"2e38e77b22c314a449e91fafed92a43826ac6aa403ae6a8acb6cf58239fbaf5d"}],"loggedTypes":[],
+    "messagesTypes":[],"configurables":[]}"#;
+
+    fn call_receipt(id: ContractId, to: ContractId) -> Receipt {
+        Receipt::Call {
+            id,
+            to,
+            amount: 0,
+            asset_id: Default::default(),
+            gas: 0,
+            param1: 0,
+            param2: 0,
+            pc: 0,
+            is: 0,
+        }
+    }
+
+    #[test]
+    fn test_parse_call_spec() {
+        let call = format!("{CONTRACT_ID} transfer 1 true");
+        let spec = parse_call_spec(&call).unwrap();
+        assert_eq!(
+            spec,
+            CallSpec {
+                contract_id: ContractId::from_str(CONTRACT_ID).unwrap(),
+                function: "transfer".to_string(),
+                args: vec!["1".to_string(), "true".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to parse contract id")]
+    fn test_parse_call_spec_fail_invalid_contract_id() {
+        let call = "transfer 1";
+        parse_call_spec(call).unwrap();
+    }
+
+    #[test]
+    fn test_split_abi_arg() {
+        let contract_id = ContractId::from_str(CONTRACT_ID).unwrap();
+        assert_eq!(
+            split_abi_arg(&format!("{CONTRACT_ID}:out/abi.json")),
+            (Some(contract_id), PathBuf::from("out/abi.json"))
+        );
+        assert_eq!(
+            split_abi_arg("out/abi.json"),
+            (None, PathBuf::from("out/abi.json"))
+        );
+        assert_eq!(
+            split_abi_arg(r"C:\abi.json"),
+            (None, PathBuf::from(r"C:\abi.json"))
+        );
+        assert_eq!(
+            split_abi_arg(&format!(r"{CONTRACT_ID}:C:\abi.json")),
+            (Some(contract_id), PathBuf::from(r"C:\abi.json"))
+        );
+    }
+
+    #[test]
+    fn test_contract_call_encoding() {
+        let abi = FullProgramABI::from_json_abi(TEST_JSON_ABI).unwrap();
+        let call = format!("{CONTRACT_ID} test_function 2 true");
+        let spec = parse_call_spec(&call).unwrap();
+        let call = contract_call(&spec, &abi.functions[0]).unwrap();
+        assert_eq!(call.encoded_args.unwrap(), vec![2u8, 1u8]);
+        assert_eq!(call.encoded_selector, encode_fn_selector("test_function"));
+        assert_eq!(
+            call.output_param,
+            fuels_core::types::param_types::ParamType::Unit
+        );
+        assert!(!call.is_payable);
+    }
+
+    #[test]
+    #[should_panic(expected = "function `test_function` takes 2 arguments, 1 provided")]
+    fn test_contract_call_fail_arg_count_mismatch() {
+        let abi = FullProgramABI::from_json_abi(TEST_JSON_ABI).unwrap();
+        let call = format!("{CONTRACT_ID} test_function 2");
+        let spec = parse_call_spec(&call).unwrap();
+        contract_call(&spec, &abi.functions[0]).unwrap();
+    }
+
+    #[test]
+    fn test_failed_call_index() {
+        let script = ContractId::zeroed();
+        let first = ContractId::from([1u8; 32]);
+        let second = ContractId::from([2u8; 32]);
+        let revert = Receipt::Revert {
+            id: second,
+            ra: 0,
+            pc: 0,
+            is: 0,
+        };
+        // The first call makes a nested call of its own, which mustn't be counted.
+        let receipts = [
+            call_receipt(script, first),
+            call_receipt(first, second),
+            call_receipt(script, second),
+            revert,
+        ];
+        assert_eq!(failed_call_index(&receipts), Some(1));
+        assert_eq!(failed_call_index(&[]), None);
+    }
+}
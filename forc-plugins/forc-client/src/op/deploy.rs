@@ -1,6 +1,7 @@
 use crate::{
     cmd,
     constants::TX_SUBMIT_TIMEOUT_MS,
+    op::submit::write_tx,
     util::{
         account::ForcClientAccount,
         node_url::get_node_url,
@@ -323,6 +324,22 @@ pub async fn deploy(command: cmd::Deploy) -> Result<Vec<DeployedContract>> {
         None
     };
 
+    // Writing the transaction to a file is only possible for deployments made of a single
+    // transaction.
+    if command.output_tx.is_some() {
+        if pkgs_to_deploy.len() > 1 {
+            bail!("`--output-tx` can only be used when deploying a single contract");
+        }
+        let pkg = pkgs_to_deploy[0];
+        let proxy_enabled = matches!(
+            pkg.descriptor.manifest_file.proxy,
+            Some(forc_pkg::manifest::Proxy { enabled: true, .. })
+        );
+        if pkg.bytecode.bytes.len() > MAX_CONTRACT_SIZE || proxy_enabled {
+            bail!("`--output-tx` cannot be used for chunked or proxy deployments, as they require several transactions");
+        }
+    }
+
     // Ensure that all packages are being deployed to the same node.
     let node_url = get_node_url(
         &command.node,
@@ -537,6 +554,18 @@ pub async fn deploy_pkg(
     let tx = tb.build(provider).await?;
     let tx = Transaction::from(tx);
 
+    if let Some(output_tx) = &command.output_tx {
+        write_tx(output_tx, &tx)?;
+        println_action_green(
+            "Wrote",
+            &format!(
+                "deployment transaction of contract 0x{contract_id} to {}",
+                output_tx.display()
+            ),
+        );
+        return Ok(contract_id);
+    }
+
     let chain_info = client.chain_info().await?;
     let chain_id = chain_info.consensus_parameters.chain_id();
 
@@ -639,6 +668,7 @@ fn build_opts_from_cmd(cmd: &cmd::Deploy) -> pkg::BuildOpts {
             offline: cmd.pkg.offline,
             terse: cmd.pkg.terse,
             locked: cmd.pkg.locked,
+            frozen: cmd.pkg.frozen,
             output_directory: cmd.pkg.output_directory.clone(),
             ipfs_node: cmd.pkg.ipfs_node.clone().unwrap_or_default(),
         },
@@ -663,8 +693,12 @@ fn build_opts_from_cmd(cmd: &cmd::Deploy) -> pkg::BuildOpts {
         error_on_warnings: false,
         binary_outfile: cmd.build_output.bin_file.clone(),
         debug_outfile: cmd.build_output.debug_file.clone(),
+        emit: cmd.build_output.emit.clone(),
         build_target: BuildTarget::default(),
         tests: false,
+        features: vec![],
+        overflow: None,
+        const_eval_limit: None,
         member_filter: pkg::MemberFilter::only_contracts(),
         experimental: ExperimentalFlags {
             new_encoding: !cmd.no_encoding_v1,
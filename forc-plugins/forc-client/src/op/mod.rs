@@ -1,7 +1,9 @@
+mod call;
 mod deploy;
 mod run;
 mod submit;
 
+pub use call::{call, CallResult};
 pub use deploy::{deploy, DeployedContract};
 pub use run::run;
 pub use submit::submit;
@@ -245,6 +245,7 @@ fn build_opts_from_cmd(cmd: &cmd::Run) -> pkg::BuildOpts {
             offline: cmd.pkg.offline,
             terse: cmd.pkg.terse,
             locked: cmd.pkg.locked,
+            frozen: cmd.pkg.frozen,
             output_directory: cmd.pkg.output_directory.clone(),
             ipfs_node: cmd.pkg.ipfs_node.clone().unwrap_or_default(),
         },
@@ -270,7 +271,11 @@ fn build_opts_from_cmd(cmd: &cmd::Run) -> pkg::BuildOpts {
         metrics_outfile: cmd.print.metrics_outfile.clone(),
         binary_outfile: cmd.build_output.bin_file.clone(),
         debug_outfile: cmd.build_output.debug_file.clone(),
+        emit: cmd.build_output.emit.clone(),
         tests: false,
+        features: cmd.build_profile.features.clone(),
+        overflow: cmd.build_profile.overflow,
+        const_eval_limit: cmd.build_profile.const_eval_limit,
         member_filter: pkg::MemberFilter::only_scripts(),
         experimental: ExperimentalFlags {
             new_encoding: !cmd.no_encoding_v1,
@@ -1,7 +1,9 @@
 use crate::{cmd, util::node_url::get_node_url};
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use fuel_core_client::client::{types::TransactionStatus, FuelClient};
-use fuel_crypto::fuel_types::canonical::Deserialize;
+use fuel_crypto::fuel_types::canonical::{Deserialize, Serialize};
+use fuel_tx::UniqueIdentifier;
+use std::path::Path;
 
 /// A command for submitting transactions to a Fuel network.
 pub async fn submit(cmd: cmd::Submit) -> anyhow::Result<()> {
@@ -9,40 +11,91 @@ pub async fn submit(cmd: cmd::Submit) -> anyhow::Result<()> {
     let node_url = get_node_url(&cmd.network.node, &None)?;
     let client = FuelClient::new(node_url)?;
     if cmd.network.await_ {
+        let chain_id = client
+            .chain_info()
+            .await
+            .context("Failed to fetch the chain info from the node")?
+            .consensus_parameters
+            .chain_id();
         let status = client
             .submit_and_await_commit(&tx)
             .await
-            .context("Submission of tx or awaiting commit failed")?;
+            .map_err(submit_error)?;
         if cmd.tx_status.json {
             print_status_json(&status)?;
         } else {
+            println!("Transaction ID: 0x{}", tx.id(&chain_id));
             print_status(&status);
         }
     } else {
-        let id = client.submit(&tx).await.context("Failed to submit tx")?;
+        let id = client.submit(&tx).await.map_err(submit_error)?;
         println!("{id}");
     }
     Ok(())
 }
 
-/// Deserialize a `Transaction` from the given file into memory.
-pub fn read_tx(path: &std::path::Path) -> anyhow::Result<fuel_tx::Transaction> {
-    let file = std::fs::File::open(path)?;
-    let reader = std::io::BufReader::new(file);
-    fn has_extension(path: &std::path::Path, ext: &str) -> bool {
-        path.extension().and_then(|ex| ex.to_str()) == Some(ext)
-    }
-    let tx: fuel_tx::Transaction = if has_extension(path, "json") {
-        serde_json::from_reader(reader)?
-    } else if has_extension(path, "bin") {
-        let tx_bytes = std::fs::read(path)?;
-        fuel_tx::Transaction::from_bytes(&tx_bytes).map_err(anyhow::Error::msg)?
+/// Converts an error of submitting a transaction. Only the errors the node responded with, e.g.
+/// on failing to validate the transaction, are reported as the node rejecting it. Other errors,
+/// like failing to connect to the node, keep their own message.
+fn submit_error(e: std::io::Error) -> anyhow::Error {
+    // The client reports the errors in the node's response as "Response errors; <errors>".
+    if e.to_string().contains("Response errors") {
+        anyhow!("The node rejected the transaction: {e}")
     } else {
-        anyhow::bail!(r#"Unsupported transaction file extension, expected ".json" or ".bin""#);
+        e.into()
+    }
+}
+
+/// Deserialize a `Transaction` from the given file into memory.
+pub fn read_tx(path: &Path) -> anyhow::Result<fuel_tx::Transaction> {
+    let format = TxFileFormat::from_path(path)?;
+    let tx_bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read transaction file {}", path.display()))?;
+    let tx: fuel_tx::Transaction = match format {
+        TxFileFormat::Json => serde_json::from_slice(&tx_bytes).with_context(|| {
+            format!(
+                "Failed to deserialize a transaction from {}",
+                path.display()
+            )
+        })?,
+        TxFileFormat::Bin => fuel_tx::Transaction::from_bytes(&tx_bytes).map_err(|e| {
+            anyhow!(
+                "Failed to deserialize a transaction from {}: {e}",
+                path.display()
+            )
+        })?,
     };
     Ok(tx)
 }
 
+/// Serialize the given `Transaction` to a file, in a format that can be read by [read_tx].
+pub fn write_tx(path: &Path, tx: &fuel_tx::Transaction) -> anyhow::Result<()> {
+    let tx_bytes = match TxFileFormat::from_path(path)? {
+        TxFileFormat::Json => serde_json::to_vec_pretty(tx)?,
+        TxFileFormat::Bin => tx.to_bytes(),
+    };
+    std::fs::write(path, tx_bytes)
+        .with_context(|| format!("Failed to write transaction file {}", path.display()))
+}
+
+/// The supported formats of transaction files, determined by their extension.
+enum TxFileFormat {
+    Json,
+    Bin,
+}
+
+impl TxFileFormat {
+    fn from_path(path: &Path) -> anyhow::Result<Self> {
+        match path.extension().and_then(|ex| ex.to_str()) {
+            Some("json") => Ok(Self::Json),
+            Some("bin") => Ok(Self::Bin),
+            _ => anyhow::bail!(
+                r#"Unsupported transaction file extension, expected ".json" or ".bin""#
+            ),
+        }
+    }
+}
+
 /// Format the transaction status in a more human-friendly manner.
 pub fn fmt_status(status: &TransactionStatus, s: &mut String) -> anyhow::Result<()> {
     use chrono::TimeZone;
@@ -55,6 +108,7 @@ pub fn fmt_status(status: &TransactionStatus, s: &mut String) -> anyhow::Result<
             block_height,
             time,
             program_state,
+            receipts,
             ..
         } => {
             let utc = chrono::Utc.timestamp_nanos(time.to_unix());
@@ -62,6 +116,7 @@ pub fn fmt_status(status: &TransactionStatus, s: &mut String) -> anyhow::Result<
             writeln!(s, "  Block ID:      {block_height}")?;
             writeln!(s, "  Time:          {utc}",)?;
             writeln!(s, "  Program State: {program_state:?}")?;
+            fmt_receipts(receipts, s)?;
         }
         TransactionStatus::SqueezedOut { reason } => {
             writeln!(s, "Transaction Squeezed Out: {reason}")?;
@@ -71,6 +126,7 @@ pub fn fmt_status(status: &TransactionStatus, s: &mut String) -> anyhow::Result<
             time,
             reason,
             program_state,
+            receipts,
             ..
         } => {
             let utc = chrono::Utc.timestamp_nanos(time.to_unix());
@@ -79,11 +135,22 @@ pub fn fmt_status(status: &TransactionStatus, s: &mut String) -> anyhow::Result<
             writeln!(s, "  Block ID:      {block_height}")?;
             writeln!(s, "  Time:          {utc}")?;
             writeln!(s, "  Program State: {program_state:?}")?;
+            fmt_receipts(receipts, s)?;
         }
     }
     Ok(())
 }
 
+/// Format the receipts of an executed transaction, one per line.
+fn fmt_receipts(receipts: &[fuel_tx::Receipt], s: &mut String) -> anyhow::Result<()> {
+    use std::fmt::Write;
+    writeln!(s, "  Receipts:")?;
+    for receipt in receipts {
+        writeln!(s, "    {receipt:?}")?;
+    }
+    Ok(())
+}
+
 /// Print the status to stdout.
 pub fn print_status(status: &TransactionStatus) {
     let mut string = String::new();
@@ -97,3 +164,57 @@ pub fn print_status_json(status: &TransactionStatus) -> anyhow::Result<()> {
     println!("{json}");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_and_read_tx() {
+        let dir = tempfile::tempdir().unwrap();
+        let tx = fuel_tx::Transaction::default_test_tx();
+        for file_name in ["tx.json", "tx.bin"] {
+            let path = dir.path().join(file_name);
+            write_tx(&path, &tx).unwrap();
+            assert_eq!(read_tx(&path).unwrap(), tx);
+        }
+    }
+
+    #[test]
+    fn test_read_tx_unsupported_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tx.txt");
+        std::fs::write(&path, "{}").unwrap();
+        let err = read_tx(&path).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unsupported transaction file extension"));
+    }
+
+    #[test]
+    fn test_submit_error() {
+        let rejected = std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Response errors; Invalid transaction data",
+        );
+        assert_eq!(
+            submit_error(rejected).to_string(),
+            "The node rejected the transaction: Response errors; Invalid transaction data"
+        );
+        let refused = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "refused");
+        assert_eq!(submit_error(refused).to_string(), "refused");
+    }
+
+    #[test]
+    fn test_read_tx_malformed() {
+        let dir = tempfile::tempdir().unwrap();
+        for file_name in ["tx.json", "tx.bin"] {
+            let path = dir.path().join(file_name);
+            std::fs::write(&path, "not a transaction").unwrap();
+            let err = read_tx(&path).unwrap_err();
+            assert!(err
+                .to_string()
+                .starts_with("Failed to deserialize a transaction from"));
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use anyhow::Context;
 use fuel_abi_types::abi::full_program::FullTypeApplication;
-use std::str::FromStr;
+use fuels_core::types::param_types::ParamType;
+use std::{fmt, str::FromStr};
 use sway_types::u256::U256;
 
 /// A wrapper around fuels_core::types::Token, which enables serde de/serialization.
@@ -65,6 +66,40 @@ impl Token {
     }
 }
 
+impl From<&Type> for ParamType {
+    fn from(value: &Type) -> Self {
+        match value {
+            Type::Unit => ParamType::Unit,
+            Type::U8 => ParamType::U8,
+            Type::U16 => ParamType::U16,
+            Type::U32 => ParamType::U32,
+            Type::U64 => ParamType::U64,
+            Type::U256 => ParamType::U256,
+            Type::Bool => ParamType::Bool,
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    /// Formats the token in the same way its value is given on the command line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            fuels_core::types::Token::Unit => write!(f, "()"),
+            fuels_core::types::Token::U8(value) => write!(f, "{value}"),
+            fuels_core::types::Token::U16(value) => write!(f, "{value}"),
+            fuels_core::types::Token::U32(value) => write!(f, "{value}"),
+            fuels_core::types::Token::U64(value) => write!(f, "{value}"),
+            fuels_core::types::Token::U256(value) => {
+                let mut bytes = [0u8; 32];
+                value.to_big_endian(&mut bytes);
+                write!(f, "{}", U256::from_be_bytes(&bytes))
+            }
+            fuels_core::types::Token::Bool(value) => write!(f, "{value}"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
 impl FromStr for Type {
     type Err = anyhow::Error;
 
@@ -133,6 +168,21 @@ mod tests {
         assert_eq!(types, expected_types)
     }
 
+    #[test]
+    fn test_token_display() {
+        let values = [
+            "()",
+            "1",
+            "true",
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        ];
+        let types = [Type::Unit, Type::U64, Type::Bool, Type::U256];
+        for (ty, value) in types.iter().zip(values) {
+            let token = Token::from_type_and_value(ty, value).unwrap();
+            assert_eq!(token.to_string(), value);
+        }
+    }
+
     #[test]
     #[should_panic(expected = "u2 type is not supported.")]
     fn test_type_generation_fail_invalid_type() {
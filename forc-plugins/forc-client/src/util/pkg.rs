@@ -63,8 +63,8 @@ pub(crate) fn built_pkgs(path: &Path, build_opts: &BuildOpts) -> Result<Vec<Arc<
     let build_plan = BuildPlan::from_lock_and_manifests(
         &lock_path,
         &manifest_file.member_manifests()?,
-        build_opts.pkg.locked,
-        build_opts.pkg.offline,
+        build_opts.pkg.locked || build_opts.pkg.frozen,
+        build_opts.pkg.offline || build_opts.pkg.frozen,
         &build_opts.pkg.ipfs_node,
     )?;
     let graph = build_plan.graph();
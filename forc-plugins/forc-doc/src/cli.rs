@@ -41,6 +41,10 @@ pub struct Command {
     /// needs to be updated, Forc will exit with an error
     #[clap(long)]
     pub locked: bool,
+    /// Requires that the Forc.lock file is up-to-date and prevents Forc from using the network.
+    /// Equivalent to passing both `--locked` and `--offline`.
+    #[clap(long)]
+    pub frozen: bool,
     /// Do not build documentation for dependencies.
     #[clap(long)]
     pub no_deps: bool,
@@ -93,8 +93,8 @@ pub fn compile_html(
     let plan = pkg::BuildPlan::from_lock_and_manifests(
         &lock_path,
         &member_manifests,
-        build_instructions.locked,
-        build_instructions.offline,
+        build_instructions.locked || build_instructions.frozen,
+        build_instructions.offline || build_instructions.frozen,
         &ipfs_node,
     )?;
 
@@ -3,13 +3,16 @@ use clap::Parser;
 use forc_doc::{
     cli::Command, compile_html, get_doc_dir, render::constant::INDEX_FILENAME, ASSETS_DIR_NAME,
 };
+use forc_tracing::{init_tracing_subscriber, println_action_green, println_warning};
 use include_dir::{include_dir, Dir};
 use std::{
+    fs,
+    path::{Path, PathBuf},
     process::Command as Process,
-    {fs, path::PathBuf},
 };
 
 pub fn main() -> Result<()> {
+    init_tracing_subscriber(Default::default());
     let build_instructions = Command::parse();
 
     let (doc_path, pkg_manifest) = compile_html(
@@ -34,30 +37,42 @@ pub fn main() -> Result<()> {
     fs::write(assets_path.join(SWAY_HJS_FILENAME), sway_hjs)?;
 
     // check if the user wants to open the doc in the browser
-    // if opening in the browser fails, attempt to open using a file explorer
     if build_instructions.open {
-        const BROWSER_ENV_VAR: &str = "BROWSER";
-        let path = doc_path
+        let index_path = doc_path
             .join(pkg_manifest.project_name())
             .join(INDEX_FILENAME);
-        let default_browser_opt = std::env::var_os(BROWSER_ENV_VAR);
-        match default_browser_opt {
-            Some(def_browser) => {
-                let browser = PathBuf::from(def_browser);
-                if let Err(e) = Process::new(&browser).arg(path).status() {
-                    bail!(
-                        "Couldn't open docs with {}: {}",
-                        browser.to_string_lossy(),
-                        e
-                    );
-                }
-            }
-            None => {
-                if let Err(e) = opener::open(&path) {
-                    bail!("Couldn't open docs: {}", e);
-                }
-            }
+        if !index_path.exists() {
+            bail!(
+                "Couldn't find the docs entry page at {}",
+                index_path.display()
+            );
         }
+        open_docs(&index_path);
     }
     Ok(())
 }
+
+/// Opens the docs entry page at `path` in the browser named by the `BROWSER` environment variable,
+/// falling back to the system's default way of opening the file.
+///
+/// Opening the docs is a convenience, so failing to do so is not an error. In that case the path
+/// to the docs is printed instead.
+fn open_docs(path: &Path) {
+    const BROWSER_ENV_VAR: &str = "BROWSER";
+    println_action_green("Opening", &path.display().to_string());
+    let result = match std::env::var_os(BROWSER_ENV_VAR) {
+        Some(browser) => {
+            let browser = PathBuf::from(browser);
+            match Process::new(&browser).arg(path).status() {
+                Ok(status) if status.success() => Ok(()),
+                Ok(status) => Err(format!("{} exited with {status}", browser.display())),
+                Err(e) => Err(format!("{}: {e}", browser.display())),
+            }
+        }
+        None => opener::open(path).map_err(|e| e.to_string()),
+    };
+    if let Err(e) = result {
+        println_warning(&format!("Couldn't open docs in a browser: {e}"));
+        println_warning(&format!("The docs are available at {}", path.display()));
+    }
+}
@@ -57,11 +57,8 @@ impl Renderable for Context {
             ContextType::StructFields(fields) => {
                 for field in fields {
                     let struct_field_id = format!("structfield.{}", field.name.as_str());
-                    let type_anchor = render_type_anchor(
-                        (*render_plan.engines.te().get(field.type_argument.type_id)).clone(),
-                        &render_plan,
-                        &self.module_info,
-                    );
+                    let type_anchor =
+                        render_type_anchor(&field.type_argument, &render_plan, &self.module_info);
                     rendered_list.push(box_html! {
                         span(id=&struct_field_id, class="structfield small-section-header") {
                             a(class="anchor field", href=format!("{IDENTITY}{struct_field_id}"));
@@ -85,11 +82,8 @@ impl Renderable for Context {
             ContextType::StorageFields(fields) => {
                 for field in fields {
                     let storage_field_id = format!("storagefield.{}", field.name.as_str());
-                    let type_anchor = render_type_anchor(
-                        (*render_plan.engines.te().get(field.type_argument.type_id)).clone(),
-                        &render_plan,
-                        &self.module_info,
-                    );
+                    let type_anchor =
+                        render_type_anchor(&field.type_argument, &render_plan, &self.module_info);
                     rendered_list.push(box_html! {
                         span(id=&storage_field_id, class="storagefield small-section-header") {
                             a(class="anchor field", href=format!("{IDENTITY}{storage_field_id}"));
@@ -113,11 +107,8 @@ impl Renderable for Context {
             ContextType::EnumVariants(variants) => {
                 for variant in variants {
                     let enum_variant_id = format!("variant.{}", variant.name.as_str());
-                    let type_anchor = render_type_anchor(
-                        (*render_plan.engines.te().get(variant.type_argument.type_id)).clone(),
-                        &render_plan,
-                        &self.module_info,
-                    );
+                    let type_anchor =
+                        render_type_anchor(&variant.type_argument, &render_plan, &self.module_info);
                     rendered_list.push(box_html! {
                         h3(id=&enum_variant_id, class="variant small-section-header") {
                             a(class="anchor field", href=format!("{IDENTITY}{enum_variant_id}"));
@@ -277,6 +268,15 @@ impl DocImplTrait {
         self.impl_trait.trait_name.suffix.as_str().to_string()
     }
 
+    /// The name of the trait's declaration, which differs from the short name
+    /// when the trait is imported under an alias.
+    pub fn decl_name(&self) -> String {
+        self.impl_trait
+            .trait_decl_ref
+            .as_ref()
+            .map_or_else(|| self.short_name(), |decl_ref| decl_ref.name().to_string())
+    }
+
     pub fn type_args(&self) -> Vec<String> {
         self.impl_trait
             .trait_type_arguments
@@ -502,6 +502,7 @@ impl Renderable for DocImplTrait {
             ..
         } = &self.impl_trait;
         let short_name = self.short_name();
+        let decl_name = self.decl_name();
         let name_with_type_args = self.name_with_type_args();
         let type_args = self.type_args();
         let is_inherent = self.is_inherent();
@@ -515,13 +516,13 @@ impl Renderable for DocImplTrait {
 
         let trait_link = if let Some(module_prefixes) = &self.module_info_override {
             ModuleInfo::from_vec_str(module_prefixes).file_path_from_location(
-                &format!("trait.{}.html", short_name),
+                &format!("trait.{}.html", decl_name),
                 impl_for_module,
                 is_external_item,
             )?
         } else {
             ModuleInfo::from_call_path(trait_name).file_path_from_location(
-                &format!("trait.{}.html", short_name),
+                &format!("trait.{}.html", decl_name),
                 impl_for_module,
                 is_external_item,
             )?
@@ -2,7 +2,7 @@
 use crate::{doc::module::ModuleInfo, RenderPlan};
 use anyhow::{anyhow, Result};
 use horrorshow::{box_html, RenderBox};
-use sway_core::{AbiName, TypeInfo};
+use sway_core::{AbiName, TypeArgument, TypeInfo};
 use sway_types::{Named, Spanned};
 
 /// Handles types & nested types that should have links
@@ -16,17 +16,13 @@ use sway_types::{Named, Spanned};
 //
 // TODO: Add checks for multiline types
 pub(crate) fn render_type_anchor(
-    type_info: TypeInfo,
+    type_arg: &TypeArgument,
     render_plan: &RenderPlan,
     current_module_info: &ModuleInfo,
 ) -> Result<Box<dyn RenderBox>> {
-    match type_info {
+    match (*render_plan.engines.te().get(type_arg.type_id)).clone() {
         TypeInfo::Array(ty_arg, len) => {
-            let inner = render_type_anchor(
-                (*render_plan.engines.te().get(ty_arg.type_id)).clone(),
-                render_plan,
-                current_module_info,
-            )?;
+            let inner = render_type_anchor(&ty_arg, render_plan, current_module_info)?;
             Ok(box_html! {
                 : "[";
                 : inner;
@@ -34,11 +30,7 @@ pub(crate) fn render_type_anchor(
             })
         }
         TypeInfo::Slice(ty_arg) => {
-            let inner = render_type_anchor(
-                (*render_plan.engines.te().get(ty_arg.type_id)).clone(),
-                render_plan,
-                current_module_info,
-            )?;
+            let inner = render_type_anchor(&ty_arg, render_plan, current_module_info)?;
             Ok(box_html! {
                 : "__slice[";
                 : inner;
@@ -49,7 +41,7 @@ pub(crate) fn render_type_anchor(
             let mut rendered_args: Vec<_> = Vec::new();
             for ty_arg in ty_args {
                 rendered_args.push(render_type_anchor(
-                    (*render_plan.engines.te().get(ty_arg.type_id)).clone(),
+                    &ty_arg,
                     render_plan,
                     current_module_info,
                 )?);
@@ -64,9 +56,12 @@ pub(crate) fn render_type_anchor(
         }
         TypeInfo::Enum(decl_id) => {
             let enum_decl = render_plan.engines.de().get_enum(&decl_id);
+            let name = written_name(type_arg)
+                .unwrap_or(enum_decl.name().as_str())
+                .to_string();
             if !render_plan.document_private_items && enum_decl.visibility.is_private() {
                 Ok(box_html! {
-                    : enum_decl.name().clone().as_str();
+                    : name;
                 })
             } else {
                 let module_info = ModuleInfo::from_call_path(&enum_decl.call_path);
@@ -75,16 +70,19 @@ pub(crate) fn render_type_anchor(
                     module_info.file_path_from_location(&file_name, current_module_info, false)?;
                 Ok(box_html! {
                     a(class="enum", href=href) {
-                        : enum_decl.name().clone().as_str();
+                        : name;
                     }
                 })
             }
         }
         TypeInfo::Struct(decl_id) => {
             let struct_decl = render_plan.engines.de().get_struct(&decl_id);
+            let name = written_name(type_arg)
+                .unwrap_or(struct_decl.name().as_str())
+                .to_string();
             if !render_plan.document_private_items && struct_decl.visibility.is_private() {
                 Ok(box_html! {
-                    : struct_decl.name().clone().as_str();
+                    : name;
                 })
             } else {
                 let module_info = ModuleInfo::from_call_path(&struct_decl.call_path);
@@ -93,7 +91,7 @@ pub(crate) fn render_type_anchor(
                     module_info.file_path_from_location(&file_name, current_module_info, false)?;
                 Ok(box_html! {
                     a(class="struct", href=href) {
-                        : struct_decl.name().clone().as_str();
+                        : name;
                     }
                 })
             }
@@ -142,3 +140,11 @@ pub(crate) fn render_type_anchor(
         _ => Err(anyhow!("Undetermined or unusable TypeInfo")),
     }
 }
+
+/// The name of a struct or enum as written in the source, e.g. `RedShape` for `RedShape<u64>`,
+/// which is the alias the type is imported under, if any.
+fn written_name(type_arg: &TypeArgument) -> Option<&str> {
+    let path = type_arg.span.as_str().split('<').next()?;
+    let name = path.rsplit("::").next()?.trim();
+    (!name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')).then_some(name)
+}
@@ -0,0 +1,17 @@
+library;
+
+pub mod red;
+pub mod blue;
+
+use red::{Paint as RedPaint, Shape as RedShape};
+use blue::Shape as BlueShape;
+
+/// A canvas holding a red and a blue shape.
+pub struct Canvas {
+    red: RedShape,
+    blue: BlueShape,
+}
+
+impl RedPaint for Canvas {
+    fn paint() {}
+}
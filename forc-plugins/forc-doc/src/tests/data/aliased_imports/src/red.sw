@@ -0,0 +1,7 @@
+library;
+
+pub struct Shape {}
+
+pub trait Paint {
+    fn paint();
+}
@@ -0,0 +1,42 @@
+#![cfg(test)]
+use crate::{
+    cli::Command,
+    compile_html,
+    tests::expects::{check_file, get_doc_dir},
+};
+use expect_test::expect;
+use std::path::PathBuf;
+use sway_core::ExperimentalFlags;
+
+const DATA_DIR: &str = "src/tests/data";
+
+#[test]
+fn test_aliased_imports() {
+    let doc_dir_name: &str = "aliased_imports";
+    let project_name: &str = "aliased_imports";
+    let command = Command {
+        manifest_path: Some(format!("{}/{}", DATA_DIR, project_name)),
+        doc_path: Some(doc_dir_name.into()),
+        no_deps: true,
+        ..Default::default()
+    };
+    let (doc_path, _) = compile_html(
+        &command,
+        &get_doc_dir,
+        ExperimentalFlags {
+            new_encoding: false,
+        },
+    )
+    .unwrap();
+    // Types and traits are shown under their aliases and linked to their declarations.
+    check_file(
+        &doc_path,
+        &PathBuf::from(format!("{}/struct.Canvas.html", project_name)),
+        &expect![[r##"
+            <!DOCTYPE html><html><head><meta charset="utf-8"><meta name="viewport" content="width=device-width, initial-scale=1.0"><meta name="generator" content="swaydoc"><meta name="description" content="API documentation for the Sway `Canvas` struct in `aliased_imports`."><meta name="keywords" content="sway, swaylang, sway-lang, Canvas"><link rel="icon" href="../static.files/sway-logo.svg"><title>Canvas in aliased_imports - Sway</title><link rel="stylesheet" type="text/css" href="../static.files/normalize.css"><link rel="stylesheet" type="text/css" href="../static.files/swaydoc.css" id="mainThemeStyle"><link rel="stylesheet" type="text/css" href="../static.files/ayu.css"><link rel="stylesheet" href="../static.files/ayu.min.css"></head><body class="swaydoc struct"><nav class="sidebar"><a class="sidebar-logo" href="../aliased_imports/index.html"><div class="logo-container"><img class="sway-logo" src="../static.files/sway-logo.svg" alt="logo"></div></a><h2 class="location">Struct Canvas</h2><div class="sidebar-elems"><section><h3><a href="#fields">Fields</a></h3><ul class="block method"><li><a href="#structfield.red">red</a></li><li><a href="#structfield.blue">blue</a></li></ul></section><section><h3><a href="#trait-implementations">Trait Implementations</a></h3><ul class="block method"><li><a href="#impl-RedPaint">RedPaint</a></li></ul></section></div></nav><main><div class="width-limiter"><script src="../search.js" type="text/javascript"></script><script>function onSearchFormSubmit(event){event.preventDefault();const searchQuery=document.getElementById("search-input").value;const url=new URL(window.location.href);if(searchQuery){url.searchParams.set('search',searchQuery)}else{url.searchParams.delete('search')}history.pushState({search:searchQuery},"",url);window.dispatchEvent(new HashChangeEvent("hashchange"))}document.addEventListener('DOMContentLoaded',()=>{const searchbar=document.getElementById("search-input");const searchForm=document.getElementById("search-form");searchbar.addEventListener("keyup",function(event){onSearchFormSubmit(event)});searchbar.addEventListener("search",function(event){onSearchFormSubmit(event)});function onQueryParamsChange(){const searchParams=new URLSearchParams(window.location.search);const query=searchParams.get("search");const searchSection=document.getElementById('search');const mainSection=document.getElementById('main-content');const searchInput=document.getElementById('search-input');if(query){searchInput.value=query;const results=Object.values(SEARCH_INDEX).flat().filter(item=>{const lowerQuery=query.toLowerCase();return item.name.toLowerCase().includes(lowerQuery)});const header=`<h1>Results for ${query}</h1>`;if(results.length>0){const resultList=results.map(item=>{const formattedName=`<span class="type ${item.type_name}">${item.name}</span>`;const name=[...item.module_info,formattedName].join("::");const path=["..",...item.module_info,item.html_filename].join("/");const left=`<td><span>${name}</span></td>`;const right=`<td><p>${item.preview}</p></td>`;return`<tr onclick="window.location='${path}';">${left}${right}</tr>`}).join('');searchSection.innerHTML=`${header}<table>${resultList}</table>`}else{searchSection.innerHTML=`${header}<p>No results found.</p>`}searchSection.setAttribute("class","search-results");mainSection.setAttribute("class","content hidden")}else{searchSection.setAttribute("class","search-results hidden");mainSection.setAttribute("class","content")}}window.addEventListener('hashchange',onQueryParamsChange);onQueryParamsChange()})</script><nav class="sub"><form id="search-form" class="search-form" onsubmit="onSearchFormSubmit(event)"><div class="search-container"><input id="search-input" class="search-input" name="search" autocomplete="off" spellcheck="false" placeholder="Search the docs..." type="search"></div></form></nav><section id="main-content" class="content"><div class="main-heading"><h1 class="fqn"><span class="in-band">Struct <a class="mod" href="index.html">aliased_imports</a><span>::</span><a class="struct" href="#">Canvas</a></span></h1></div><div class="docblock item-decl"><pre class="sway struct"><code>pub struct Canvas {
+                red: RedShape,
+                blue: BlueShape,
+            }</code></pre></div><details class="swaydoc-toggle top-doc" open><summary class="hideme"><span>Expand description</span></summary><div class="docblock"><p>A canvas holding a red and a blue shape.</p>
+            </div></details><h2 id="fields" class="fields small-section-header">Fields<a class="anchor" href="#fields"></a></h2><span id="structfield.red" class="structfield small-section-header"><a class="anchor field" href="#structfield.red"></a><code>red: <a class="struct" href="red/struct.Shape.html">RedShape</a></code></span><span id="structfield.blue" class="structfield small-section-header"><a class="anchor field" href="#structfield.blue"></a><code>blue: <a class="struct" href="blue/struct.Shape.html">BlueShape</a></code></span><h2 id="trait-implementations" class="small-section-header">Trait Implementations<a href="#trait-implementations" class="anchor"></a></h2><div id="trait-implementations-list"><details class="swaydoc-toggle implementors-toggle" open><summary><div id="impl-RedPaint" class="impl has-srclink"><a href="#impl-RedPaint" class="anchor"></a><h3 class="code-header in-band">impl <a class="trait" href="red/trait.Paint.html">RedPaint</a> for Canvas</h3></div></summary><div class="impl-items"><div id="method.paint" class="method trait-impl"><a href="#method.paint" class="anchor"></a><h4 class="code-header">fn <a class="fnname" href="#method.paint">paint</a>()</h4></div></div></details></div></section><section id="search" class="search-results"></section></div></main><script src="../static.files/highlight.js"></script><script>hljs.highlightAll();</script></body></html>"##]],
+    );
+}
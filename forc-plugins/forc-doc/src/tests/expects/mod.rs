@@ -3,6 +3,7 @@ use crate::cli::Command;
 use expect_test::Expect;
 use std::path::{Path, PathBuf};
 
+mod aliased_imports;
 mod impl_trait;
 
 pub(crate) fn check_file(doc_path: &Path, path_to_file: &PathBuf, expect: &Expect) {
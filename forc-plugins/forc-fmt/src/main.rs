@@ -1,6 +1,6 @@
 //! A `forc` plugin for running the Sway code formatter.
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use clap::Parser;
 use forc_pkg::{
     manifest::{GenericManifestFile, ManifestFile},
@@ -12,6 +12,7 @@ use prettydiff::{basic::DiffOp, diff_lines};
 use std::{
     default::Default,
     fs,
+    io::{self, Read, Write},
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -29,6 +30,7 @@ forc_util::cli_examples! {
         [ Run formatter against a given file with short format => "forc fmt -f {path}/src/main.sw"]
         [ Run formatter against a given dir => "forc fmt --path {path}"]
         [ Run formatter against a given dir with short format => "forc fmt -p {path}"]
+        [ Run formatter against source read from standard input => "forc fmt --stdin --stdin-filepath {path}/src/main.sw"]
     }
 }
 
@@ -54,6 +56,16 @@ pub struct App {
     /// If not specified, current working directory will be formatted using a Forc.toml
     /// configuration.
     pub file: Option<String>,
+    /// Reads Sway source from standard input and writes the formatted source to standard output.
+    ///
+    /// No files are modified in this mode. Exits with `1` if the source fails to parse.
+    #[clap(long, conflicts_with_all = ["file", "check"])]
+    pub stdin: bool,
+    /// The path of the file that the source read with `--stdin` belongs to.
+    ///
+    /// Used to find the formatter configuration. The file does not need to exist.
+    #[clap(long, requires = "stdin")]
+    pub stdin_filepath: Option<String>,
 }
 
 fn main() {
@@ -73,6 +85,10 @@ fn run() -> Result<()> {
         None => std::env::current_dir()?,
     };
 
+    if app.stdin {
+        return format_stdin(&app, &dir);
+    }
+
     let mut formatter = Formatter::from_dir(&dir)?;
     if let Some(f) = app.file.as_ref() {
         let file_path = &PathBuf::from(f);
@@ -197,6 +213,36 @@ fn format_file(
     bail!("Could not read file: {:?}", file)
 }
 
+/// Format the source read from standard input, writing the formatted source to standard output.
+///
+/// The formatter configuration is looked up from the directory of `--stdin-filepath`, if provided,
+/// or from the given directory otherwise.
+fn format_stdin(app: &App, dir: &Path) -> Result<()> {
+    let config_dir = app
+        .stdin_filepath
+        .as_ref()
+        .and_then(|path| Path::new(path).parent())
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(dir);
+    let mut formatter = Formatter::from_dir(config_dir)?;
+
+    let mut src = String::new();
+    io::stdin().read_to_string(&mut src)?;
+    let name = app.stdin_filepath.as_deref().unwrap_or("<stdin>");
+    let formatted_content = format_src(&src, name, &mut formatter)?;
+    io::stdout().write_all(formatted_content.as_bytes())?;
+
+    Ok(())
+}
+
+/// Format the given source, using `name` to refer to it in case it fails to parse.
+fn format_src(src: &str, name: &str, formatter: &mut Formatter) -> Result<String> {
+    Formatter::format(formatter, Arc::from(src), None).map_err(|err| {
+        debug!("{}", err);
+        anyhow!("Failed to compile {}\n{}", name, err)
+    })
+}
+
 /// Format the workspace at the given directory.
 fn format_workspace_at_dir(app: &App, workspace: &WorkspaceManifestFile, dir: &Path) -> Result<()> {
     let mut contains_edits = false;
@@ -359,8 +405,26 @@ fn write_file_formatted(file: &Path, formatted_content: &str) -> Result<()> {
 
 #[cfg(test)]
 mod tests {
-    use super::taplo_fmt;
+    use super::{format_src, taplo_fmt};
     use std::default::Default;
+    use swayfmt::Formatter;
+
+    #[test]
+    fn test_format_src() {
+        let src = "library;\nfn foo( ) -> u64 {42}\n";
+        let formatted_content = format_src(src, "<stdin>", &mut Formatter::default()).unwrap();
+        assert_eq!(
+            formatted_content,
+            "library;\nfn foo() -> u64 {\n    42\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_src_parse_error() {
+        let src = "library;\nfn foo( -> u64 {42}\n";
+        let err = format_src(src, "src/lib.sw", &mut Formatter::default()).unwrap_err();
+        assert!(err.to_string().starts_with("Failed to compile src/lib.sw"));
+    }
 
     #[test]
     fn test_forc_indentation() {
@@ -19,7 +19,7 @@ use rand::{Rng, SeedableRng};
 use rayon::prelude::*;
 use std::{collections::HashMap, fs, path::PathBuf, sync::Arc};
 use sway_core::asm_generation::ProgramABI;
-use sway_core::BuildTarget;
+use sway_core::{BuildTarget, EmitKind, OverflowBehavior};
 use sway_types::Span;
 use tx::consensus_parameters::ConsensusParametersV1;
 use tx::{ConsensusParameters, ContractParameters, ScriptParameters, TxParameters};
@@ -41,6 +41,21 @@ pub struct TestedPackage {
     pub tests: Vec<TestResult>,
 }
 
+/// The result of a `forc bench` invocation.
+#[derive(Debug)]
+pub enum Benched {
+    Package(Box<BenchedPackage>),
+    Workspace(Vec<BenchedPackage>),
+}
+
+/// The result of benchmarking a specific package.
+#[derive(Debug)]
+pub struct BenchedPackage {
+    pub built: Box<pkg::BuiltPackage>,
+    /// The results of each benchmark within the package.
+    pub benches: Vec<BenchResult>,
+}
+
 #[derive(Debug)]
 pub struct TestDetails {
     /// The file that contains the test function.
@@ -80,6 +95,21 @@ pub struct TestResult {
     pub gas_used: u64,
 }
 
+/// The result of repeatedly executing a single benchmark within a single package.
+#[derive(Debug, Clone)]
+pub struct BenchResult {
+    /// The name of the function.
+    pub name: String,
+    /// The span for the function declaring this benchmark.
+    pub span: Span,
+    /// The file path for the function declaring this benchmark.
+    pub file_path: Arc<PathBuf>,
+    /// The resulting state after executing the last iteration of the benchmark function.
+    pub state: vm::state::ProgramState,
+    /// Gas used by each executed iteration of the benchmark.
+    pub gas_used: Vec<u64>,
+}
+
 const TEST_METADATA_SEED: u64 = 0x7E57u64;
 /// A mapping from each member package of a build plan to its compiled contract dependencies.
 type ContractDependencyMap = HashMap<pkg::Pinned, Vec<Arc<pkg::BuiltPackage>>>;
@@ -141,6 +171,8 @@ pub struct TestOpts {
     /// If the argument provided ends with .json, a JSON is emitted,
     /// otherwise, an ELF file containing DWARF is emitted.
     pub debug_outfile: Option<String>,
+    /// The kinds of compiler outputs to write to the output directory.
+    pub emit: Vec<EmitKind>,
     /// Build target to use.
     pub build_target: BuildTarget,
     /// Name of the build profile to use.
@@ -154,6 +186,13 @@ pub struct TestOpts {
     pub time_phases: bool,
     /// Output compilation metrics into file.
     pub metrics_outfile: Option<String>,
+    /// The features to enable for the member packages.
+    pub features: Vec<String>,
+    /// The behavior of integer arithmetic on overflow, overriding the one of the build profile.
+    pub overflow: Option<OverflowBehavior>,
+    /// The maximum number of steps taken when evaluating a constant expression, overriding the
+    /// one of the build profile.
+    pub const_eval_limit: Option<usize>,
     /// Set of experimental flags
     pub experimental: ExperimentalFlags,
 }
@@ -176,6 +215,12 @@ impl TestedPackage {
     }
 }
 
+impl BenchedPackage {
+    pub fn benches_succeeded(&self) -> bool {
+        self.benches.iter().all(|bench| !bench.reverted())
+    }
+}
+
 impl PackageWithDeploymentToTest {
     /// Returns a reference to the underlying `BuiltPackage`.
     ///
@@ -381,7 +426,7 @@ impl<'a> PackageTests {
                 .entries
                 .par_iter()
                 .filter_map(|entry| {
-                    if let Some(test_entry) = entry.kind.test() {
+                    if let Some(test_entry) = entry.kind.test().filter(|test| !test.is_bench) {
                         // If a test filter is specified, only the tests containing the filter phrase in
                         // their name are going to be executed.
                         let name = entry.finalized.fn_name.clone();
@@ -418,6 +463,75 @@ impl<'a> PackageTests {
         })
     }
 
+    /// Run all benchmarks after applying the provided filter and collect their results.
+    ///
+    /// Each benchmark is executed `iterations` times, each time against a freshly set up VM.
+    pub(crate) fn run_benches(
+        &self,
+        bench_runners: &rayon::ThreadPool,
+        iterations: usize,
+        bench_filter: Option<&TestFilter>,
+    ) -> anyhow::Result<BenchedPackage> {
+        let pkg_with_tests = self.built_pkg_with_tests();
+        let benches = bench_runners.install(|| {
+            pkg_with_tests
+                .bytecode
+                .entries
+                .par_iter()
+                .filter_map(|entry| {
+                    let bench_entry = entry.kind.test().filter(|test| test.is_bench)?;
+                    let name = entry.finalized.fn_name.clone();
+                    if let Some(filter) = bench_filter {
+                        if !filter.filter(&name) {
+                            return None;
+                        }
+                    }
+                    Some((entry, bench_entry))
+                })
+                .map(|(entry, bench_entry)| {
+                    let offset = u32::try_from(entry.finalized.imm)
+                        .expect("bench instruction offset out of range");
+                    let name = entry.finalized.fn_name.clone();
+                    let mut gas_used = Vec::with_capacity(iterations);
+                    let mut state = None;
+                    for _ in 0..iterations {
+                        let bench_setup = self.setup()?;
+                        let result = TestExecutor::build(
+                            &pkg_with_tests.bytecode.bytes,
+                            offset,
+                            bench_setup,
+                            bench_entry,
+                            name.clone(),
+                        )?
+                        .execute()?;
+                        gas_used.push(result.gas_used);
+                        let reverted = !result.passed();
+                        state = Some(result.state);
+                        // There is no point in measuring a benchmark that reverts any further.
+                        if reverted {
+                            break;
+                        }
+                    }
+                    let state = state.ok_or_else(|| {
+                        anyhow::anyhow!("benchmarks must be executed at least once")
+                    })?;
+                    Ok(BenchResult {
+                        name,
+                        span: bench_entry.span.clone(),
+                        file_path: bench_entry.file_path.clone(),
+                        state,
+                        gas_used,
+                    })
+                })
+                .collect::<anyhow::Result<_>>()
+        })?;
+
+        Ok(BenchedPackage {
+            built: Box::new(pkg_with_tests.clone()),
+            benches,
+        })
+    }
+
     /// Setup the storage for a test and returns a contract id for testing contracts.
     ///
     /// For testing contracts, storage returned from this function contains the deployed contract.
@@ -447,6 +561,7 @@ impl From<TestOpts> for pkg::BuildOpts {
             minify: val.minify,
             binary_outfile: val.binary_outfile,
             debug_outfile: val.debug_outfile,
+            emit: val.emit,
             build_target: val.build_target,
             build_profile: val.build_profile,
             release: val.release,
@@ -454,6 +569,9 @@ impl From<TestOpts> for pkg::BuildOpts {
             time_phases: val.time_phases,
             metrics_outfile: val.metrics_outfile,
             tests: true,
+            features: val.features,
+            overflow: val.overflow,
+            const_eval_limit: val.const_eval_limit,
             member_filter: Default::default(),
             experimental: val.experimental,
         }
@@ -469,6 +587,7 @@ impl TestOpts {
             minify: self.minify,
             binary_outfile: self.binary_outfile,
             debug_outfile: self.debug_outfile,
+            emit: self.emit,
             build_target: self.build_target,
             build_profile: self.build_profile,
             release: self.release,
@@ -476,6 +595,9 @@ impl TestOpts {
             time_phases: self.time_phases,
             metrics_outfile: self.metrics_outfile,
             tests: true,
+            features: self.features,
+            overflow: self.overflow,
+            const_eval_limit: self.const_eval_limit,
             member_filter: Default::default(),
             experimental: self.experimental,
         }
@@ -527,6 +649,29 @@ impl TestResult {
     }
 }
 
+impl BenchResult {
+    /// Whether or not the benchmark reverted during execution.
+    pub fn reverted(&self) -> bool {
+        matches!(self.state, vm::state::ProgramState::Revert(_))
+    }
+
+    /// The mean amount of gas used across all executed iterations.
+    pub fn mean_gas(&self) -> u64 {
+        let total: u128 = self.gas_used.iter().map(|gas| *gas as u128).sum();
+        (total / self.gas_used.len().max(1) as u128) as u64
+    }
+
+    /// The minimum amount of gas used by a single iteration.
+    pub fn min_gas(&self) -> u64 {
+        self.gas_used.iter().copied().min().unwrap_or_default()
+    }
+
+    /// The maximum amount of gas used by a single iteration.
+    pub fn max_gas(&self) -> u64 {
+        self.gas_used.iter().copied().max().unwrap_or_default()
+    }
+}
+
 /// Used to control test runner count for forc-test. Number of runners to use can be specified using
 /// `Manual` or can be left forc-test to decide by using `Auto`.
 pub enum TestRunnerCount {
@@ -563,7 +708,13 @@ impl BuiltTests {
                     .bytecode
                     .entries
                     .iter()
-                    .filter_map(|entry| entry.kind.test().map(|test| (entry, test)))
+                    .filter_map(|entry| {
+                        entry
+                            .kind
+                            .test()
+                            .filter(|test| !test.is_bench)
+                            .map(|test| (entry, test))
+                    })
             })
             .fold(TestCount::default(), |acc, (pkg_entry, _)| {
                 let num_ignored = match &test_filter {
@@ -597,6 +748,35 @@ impl BuiltTests {
         }?;
         run_tests(self, &test_runners, test_filter)
     }
+
+    /// Run all built benchmarks `iterations` times each, return the result.
+    pub fn run_benches(
+        self,
+        bench_runner_count: TestRunnerCount,
+        iterations: usize,
+        bench_filter: Option<TestFilter>,
+    ) -> anyhow::Result<Benched> {
+        let bench_runners = match bench_runner_count {
+            TestRunnerCount::Manual(runner_count) => rayon::ThreadPoolBuilder::new()
+                .num_threads(runner_count)
+                .build(),
+            TestRunnerCount::Auto => rayon::ThreadPoolBuilder::new().build(),
+        }?;
+        match self {
+            BuiltTests::Package(pkg) => {
+                let benched_pkg =
+                    pkg.run_benches(&bench_runners, iterations, bench_filter.as_ref())?;
+                Ok(Benched::Package(Box::new(benched_pkg)))
+            }
+            BuiltTests::Workspace(workspace) => {
+                let benched_pkgs = workspace
+                    .into_iter()
+                    .map(|pkg| pkg.run_benches(&bench_runners, iterations, bench_filter.as_ref()))
+                    .collect::<anyhow::Result<Vec<BenchedPackage>>>()?;
+                Ok(Benched::Workspace(benched_pkgs))
+            }
+        }
+    }
 }
 
 /// First builds the package or workspace, ready for execution.
@@ -731,7 +911,7 @@ fn run_tests(
 mod tests {
     use std::path::PathBuf;
 
-    use crate::{build, BuiltTests, TestFilter, TestOpts, TestResult};
+    use crate::{build, BenchResult, BuiltTests, TestFilter, TestOpts, TestResult};
 
     /// Name of the folder containing required data for tests to run, such as an example forc
     /// project.
@@ -744,6 +924,9 @@ mod tests {
     const TEST_PREDICATE_PACKAGE_NAME: &str = "test_predicate";
     /// Name of the script package in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
     const TEST_SCRIPT_PACKAGE_NAME: &str = "test_script";
+    /// Name of the library package with `should_revert` tests in the
+    /// "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_SHOULD_REVERT_PACKAGE_NAME: &str = "test_should_revert";
 
     /// Build the tests in the test package with the given name located at
     /// "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME/TEST_LIBRARY_PACKAGE_NAME".
@@ -778,6 +961,22 @@ mod tests {
         }
     }
 
+    fn test_package_bench_results(
+        package_name: &str,
+        iterations: usize,
+        bench_filter: Option<TestFilter>,
+    ) -> anyhow::Result<Vec<BenchResult>> {
+        let built_tests = test_package_built_tests(package_name)?;
+        let bench_runner_count = crate::TestRunnerCount::Auto;
+        let benched = built_tests.run_benches(bench_runner_count, iterations, bench_filter)?;
+        match benched {
+            crate::Benched::Package(benched_pkg) => Ok(benched_pkg.benches),
+            crate::Benched::Workspace(_) => {
+                unreachable!("test_library is a package, not a workspace.")
+            }
+        }
+    }
+
     #[test]
     fn test_filter_exact_match() {
         let filter_phrase = "test_bam";
@@ -968,4 +1167,52 @@ mod tests {
         assert_eq!(tested_predicate_test_count, 2);
         assert_eq!(tested_script_test_count, 2);
     }
+
+    #[test]
+    fn test_should_revert() {
+        let test_results =
+            test_package_test_results(TEST_SHOULD_REVERT_PACKAGE_NAME, None).unwrap();
+        assert_eq!(test_results.len(), 6);
+        let passed = |name: &str| {
+            test_results
+                .iter()
+                .find(|test| test.name == name)
+                .unwrap()
+                .passed()
+        };
+
+        assert!(passed("reverts_with_any_code"));
+        assert!(passed("reverts_with_expected_code"));
+        assert!(passed("reverts_with_expected_quoted_code"));
+        assert!(!passed("reverts_with_unexpected_code"));
+        assert!(!passed("does_not_revert"));
+        assert!(!passed("does_not_revert_with_code"));
+    }
+
+    #[test]
+    fn test_run_benches() {
+        let iterations = 3;
+        let bench_results =
+            test_package_bench_results(TEST_LIBRARY_PACKAGE_NAME, iterations, None).unwrap();
+        assert_eq!(bench_results.len(), 1);
+
+        let bench = &bench_results[0];
+        assert_eq!(bench.name, "bench_sum");
+        assert!(!bench.reverted());
+        assert_eq!(bench.gas_used.len(), iterations);
+        assert!(bench.min_gas() > 0);
+        assert!(bench.min_gas() <= bench.mean_gas());
+        assert!(bench.mean_gas() <= bench.max_gas());
+    }
+
+    #[test]
+    fn test_run_benches_with_filter() {
+        let bench_filter = TestFilter {
+            filter_phrase: "test_",
+            exact_match: false,
+        };
+        let bench_results =
+            test_package_bench_results(TEST_LIBRARY_PACKAGE_NAME, 1, Some(bench_filter)).unwrap();
+        assert!(bench_results.is_empty());
+    }
 }
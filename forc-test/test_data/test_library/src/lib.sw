@@ -9,3 +9,14 @@ fn test_bam() {
 fn test_bum() {
   assert(1 == 1)
 }
+
+#[bench]
+fn bench_sum() {
+  let mut sum = 0;
+  let mut i = 0;
+  while i < 10 {
+    sum += i;
+    i += 1;
+  }
+  assert(sum == 45)
+}
@@ -0,0 +1,31 @@
+library;
+
+#[test(should_revert)]
+fn reverts_with_any_code() {
+  revert(7)
+}
+
+#[test(should_revert = 42)]
+fn reverts_with_expected_code() {
+  revert(42)
+}
+
+#[test(should_revert = "42")]
+fn reverts_with_expected_quoted_code() {
+  revert(42)
+}
+
+#[test(should_revert = 42)]
+fn reverts_with_unexpected_code() {
+  revert(7)
+}
+
+#[test(should_revert)]
+fn does_not_revert() {
+  assert(1 == 1)
+}
+
+#[test(should_revert = 42)]
+fn does_not_revert_with_code() {
+  assert(1 == 1)
+}
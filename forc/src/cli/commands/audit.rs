@@ -0,0 +1,65 @@
+use crate::ops::forc_audit;
+use clap::Parser;
+use forc_pkg::source::IPFSNode;
+use forc_util::ForcResult;
+
+forc_util::cli_examples! {
+    crate::cli::Opt {
+        [ Check the dependencies of the current project for known advisories => "forc audit" ]
+        [ Fetch the latest advisory database before checking => "forc audit --db-url <URL>" ]
+        [ Ignore an advisory => "forc audit --ignore FORC-2024-0001" ]
+    }
+}
+
+/// Check the dependencies of the current or target project for known security advisories.
+///
+/// The resolved dependency graph is checked against an advisory database, by default read from
+/// `$HOME/.forc/advisory-db.toml`. Use `--db-url` to fetch the database and update the local
+/// copy. Exits with an error if any advisories apply to the dependencies.
+#[derive(Debug, Default, Parser)]
+#[clap(bin_name = "forc audit", version, after_help = help())]
+pub struct Command {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// Path to the advisory database.
+    ///
+    /// By default, this is `$HOME/.forc/advisory-db.toml`.
+    #[clap(long)]
+    pub db: Option<String>,
+    /// URL from which to fetch the advisory database.
+    ///
+    /// The fetched database replaces the one at the `--db` path.
+    #[clap(long)]
+    pub db_url: Option<String>,
+    /// Advisory ID to ignore. Can be specified multiple times.
+    #[clap(long = "ignore", value_name = "ID")]
+    pub ignore: Vec<String>,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+    /// Requires that the Forc.lock file is up-to-date and prevents Forc from using the network.
+    ///
+    /// Equivalent to passing both `--locked` and `--offline`.
+    #[clap(long)]
+    pub frozen: bool,
+    /// The IPFS Node to use for fetching IPFS sources.
+    ///
+    /// Possible values: PUBLIC, LOCAL, <GATEWAY_URL>
+    #[clap(long)]
+    pub ipfs_node: Option<IPFSNode>,
+}
+
+pub(crate) fn exec(command: Command) -> ForcResult<()> {
+    let found = forc_audit::audit(command)?;
+    if found > 0 {
+        let plural = if found == 1 { "advisory" } else { "advisories" };
+        return Err(format!("{found} security {plural} found").as_str().into());
+    }
+    Ok(())
+}
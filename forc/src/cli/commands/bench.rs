@@ -0,0 +1,174 @@
+use crate::cli::{self, commands::test::opts_from_build};
+use ansi_term::Colour;
+use clap::Parser;
+use forc_test::{BenchedPackage, TestFilter, TestRunnerCount};
+use forc_tracing::println_action_green;
+use forc_util::{ForcError, ForcResult};
+use tracing::info;
+
+forc_util::cli_examples! {
+    crate::cli::Opt {
+        [ Run benchmarks => "forc bench" ]
+        [ Run benchmarks with a filter => "forc bench $filter" ]
+        [ Run each benchmark a hundred times => "forc bench --iterations 100" ]
+    }
+}
+
+/// Run the Sway benchmarks for the current project.
+///
+/// Sway benchmarks are functions decorated with the `#[bench]` attribute. Like unit tests, each
+/// benchmark is compiled as a unique entry point and has access to the namespace of the module in
+/// which it is declared.
+///
+/// Every benchmark is executed in the VM for the given number of iterations and the gas consumed
+/// by each iteration is reported as the mean, minimum and maximum over all iterations. A benchmark
+/// that reverts is reported as a failure.
+#[derive(Debug, Parser)]
+#[clap(bin_name = "forc bench", version, after_help = help())]
+pub struct Command {
+    #[clap(flatten)]
+    pub build: cli::shared::Build,
+    /// When specified, only benchmarks containing the given string will be executed.
+    pub filter: Option<String>,
+    #[clap(long)]
+    /// When specified, only the benchmark exactly matching the given string will be executed.
+    pub filter_exact: bool,
+    /// The number of times each benchmark is executed.
+    #[clap(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    pub iterations: u64,
+    #[clap(long)]
+    /// Number of threads to utilize when running the benchmarks. By default, this is the number of
+    /// threads available in your system.
+    pub bench_threads: Option<usize>,
+
+    /// Disable the "new encoding" feature
+    #[clap(long)]
+    pub no_encoding_v1: bool,
+}
+
+pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
+    let bench_runner_count = match cmd.bench_threads {
+        Some(runner_count) => TestRunnerCount::Manual(runner_count),
+        None => TestRunnerCount::Auto,
+    };
+
+    let bench_filter_phrase = cmd.filter.clone();
+    let bench_filter = bench_filter_phrase
+        .as_ref()
+        .map(|filter_phrase| TestFilter {
+            filter_phrase,
+            exact_match: cmd.filter_exact,
+        });
+    let iterations = cmd.iterations as usize;
+    let opts = opts_from_build(cmd.build, cmd.no_encoding_v1);
+    let built_tests = forc_test::build(opts)?;
+    println_action_green(
+        "Running",
+        &format!(
+            "benchmarks, {} {} each",
+            iterations,
+            if iterations == 1 {
+                "iteration"
+            } else {
+                "iterations"
+            }
+        ),
+    );
+    let start = std::time::Instant::now();
+    let benched = built_tests.run_benches(bench_runner_count, iterations, bench_filter)?;
+    let duration = start.elapsed();
+
+    let all_benches_succeeded = match benched {
+        forc_test::Benched::Workspace(pkgs) => {
+            for pkg in &pkgs {
+                let built = &pkg.built.descriptor.name;
+                info!("\nbenched -- {built}\n");
+                print_benched_pkg(pkg);
+            }
+            pkgs.iter().all(|pkg| pkg.benches_succeeded())
+        }
+        forc_test::Benched::Package(pkg) => {
+            print_benched_pkg(&pkg);
+            pkg.benches_succeeded()
+        }
+    };
+    info!("");
+    println_action_green("Finished", &format!("in {:?}", duration));
+
+    if all_benches_succeeded {
+        Ok(())
+    } else {
+        let forc_error: ForcError = "Some benchmarks reverted.".into();
+        const FAILING_BENCHES_EXIT_CODE: u8 = 101;
+        Err(forc_error.exit_code(FAILING_BENCHES_EXIT_CODE))
+    }
+}
+
+/// Prints the gas usage of each benchmark of the package as a table.
+fn print_benched_pkg(pkg: &BenchedPackage) {
+    if pkg.benches.is_empty() {
+        info!("      no benchmarks found");
+        return;
+    }
+
+    let mut benches: Vec<_> = pkg.benches.iter().collect();
+    benches.sort_by(|a, b| a.name.cmp(&b.name));
+
+    const HEADERS: [&str; 4] = ["bench", "mean (gas)", "min (gas)", "max (gas)"];
+    let rows: Vec<[String; 4]> = benches
+        .iter()
+        .map(|bench| {
+            [
+                bench.name.clone(),
+                bench.mean_gas().to_string(),
+                bench.min_gas().to_string(),
+                bench.max_gas().to_string(),
+            ]
+        })
+        .collect();
+    let widths: Vec<usize> = (0..HEADERS.len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].len())
+                .chain(std::iter::once(HEADERS[col].len()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    info!(
+        "      {:<w0$}  {:>w1$}  {:>w2$}  {:>w3$}",
+        HEADERS[0],
+        HEADERS[1],
+        HEADERS[2],
+        HEADERS[3],
+        w0 = widths[0],
+        w1 = widths[1],
+        w2 = widths[2],
+        w3 = widths[3],
+    );
+    for (bench, row) in benches.iter().zip(&rows) {
+        let line = format!(
+            "      {:<w0$}  {:>w1$}  {:>w2$}  {:>w3$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+        if bench.reverted() {
+            let path = &*bench.file_path;
+            info!("{}  {}", line, Colour::Red.paint("REVERTED"));
+            info!(
+                "        declared at {:?}:{}",
+                path,
+                bench.span.start_pos().line_col().line
+            );
+        } else {
+            info!("{line}");
+        }
+    }
+}
@@ -7,6 +7,9 @@ forc_util::cli_examples! {
         [ Compile the current projectx => "forc build" ]
         [ Compile the current project from a different path => "forc build --path <PATH>" ]
         [ Compile the current project without updating dependencies => "forc build --path <PATH> --locked" ]
+        [ Compile the current project without updating dependencies or using the network => "forc build --path <PATH> --frozen" ]
+        [ Compile the current project and write its IR and ASM => "forc build --emit ir,asm" ]
+        [ Compile the current project and write its ABI along with the source locations of its entries => "forc build --emit bytecode,abi,abi-spans" ]
     }
 }
 
@@ -24,6 +27,17 @@ forc_util::cli_examples! {
 ///
 /// - `contract` and `library` projects will also produce the public ABI in JSON format
 /// `<project-name>-abi.json`.
+///
+/// The outputs can be selected with `--emit`, which can also write the AST, the typed AST, the IR
+/// and the ASM of the project to `<project-name>.ast`, `<project-name>.typed-ast`,
+/// `<project-name>.ir` and `<project-name>.asm` respectively.
+///
+/// `--emit abi-spans` writes the source locations of the ABI functions and of the struct and enum
+/// types of the ABI to `<project-name>-abi-spans.json`, leaving the ABI itself unchanged. The file
+/// contains a `functions` array, with the `name` of each function, and a `metadataTypes` array,
+/// with the `metadataTypeId` and `type` of each type, matching the entries of the ABI. Every entry
+/// has a `span` with the `path` of the source file and the one-based `line` and `col` of its
+/// `start` and `end`.
 #[derive(Debug, Default, Parser)]
 #[clap(bin_name = "forc build", version, after_help = help())]
 pub struct Command {
@@ -0,0 +1,49 @@
+use crate::{cli, ops::forc_bytecode};
+use clap::Parser;
+use forc_util::ForcResult;
+use std::path::PathBuf;
+
+forc_util::cli_examples! {
+    crate::cli::Opt {
+        [ Print the bytecode of the current project => "forc bytecode" ]
+        [ Disassemble the bytecode of the current project => "forc bytecode --disassemble" ]
+        [ Disassemble an already built binary => "forc bytecode --disassemble --bin-file <PATH>" ]
+        [ Disassemble an already built binary with source annotations => "forc bytecode --disassemble --bin-file <PATH> --sourcemap-file <PATH>" ]
+    }
+}
+
+/// Print the final bytecode of the current or target project.
+///
+/// If no `--bin-file` is given, the project is built first and the bytecode of every built
+/// member is printed.
+///
+/// With `--disassemble` the bytecode is decoded into VM opcodes, each printed along with its
+/// offset. When debug information is available the opcodes are interleaved with the source
+/// locations they were generated from.
+#[derive(Debug, Default, Parser)]
+#[clap(bin_name = "forc bytecode", version, after_help = help())]
+pub struct Command {
+    #[clap(flatten)]
+    pub build: cli::shared::Build,
+    /// Decode the bytecode into human-readable VM opcodes.
+    #[clap(long)]
+    pub disassemble: bool,
+    /// Path to an already built bytecode binary.
+    ///
+    /// If specified, the project is not built and the given binary is used instead.
+    #[clap(long = "bin-file")]
+    pub bytecode_path: Option<PathBuf>,
+    /// Path to the source map in JSON format for the binary given by `--bin-file`.
+    ///
+    /// Used to annotate the disassembled opcodes with their source locations.
+    #[clap(long = "sourcemap-file", requires = "bytecode_path")]
+    pub sourcemap_path: Option<PathBuf>,
+    /// Disable the "new encoding" feature
+    #[clap(long)]
+    pub no_encoding_v1: bool,
+}
+
+pub(crate) fn exec(command: Command) -> ForcResult<()> {
+    forc_bytecode::bytecode(command)?;
+    Ok(())
+}
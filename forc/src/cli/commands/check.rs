@@ -9,6 +9,7 @@ forc_util::cli_examples! {
         [ Check the current project => "forc check" ]
         [ Check the current project with a different path => "forc check --path <PATH>" ]
         [ Check the current project without updating dependencies => "forc check --locked" ]
+        [ Check the current project without updating dependencies or using the network => "forc check --frozen" ]
     }
 }
 
@@ -33,6 +34,10 @@ pub struct Command {
     /// needs to be updated, Forc will exit with an error
     #[clap(long)]
     pub locked: bool,
+    /// Requires that the Forc.lock file is up-to-date and prevents Forc from using the network.
+    /// Equivalent to passing both `--locked` and `--offline`.
+    #[clap(long)]
+    pub frozen: bool,
     /// Terse mode. Limited warning and error output.
     #[clap(long = "terse", short = 't')]
     pub terse_mode: bool,
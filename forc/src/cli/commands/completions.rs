@@ -1,9 +1,18 @@
-use std::fmt::Display;
+use std::{fmt::Display, io::Write};
 
 use clap::{Command as ClapCommand, CommandFactory, Parser};
 use clap_complete::{generate, Generator, Shell};
 use forc_util::ForcResult;
 
+forc_util::cli_examples! {
+    crate::cli::Opt {
+        [ Generate completions for bash => "forc completions bash" ]
+        [ Generate completions for zsh => "forc completions zsh" ]
+        [ Generate completions for fish => "forc completions fish" ]
+        [ Generate completions for PowerShell => "forc completions powershell" ]
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, clap::ValueEnum)]
 enum Target {
     /// Bourne Again Shell (bash)
@@ -13,6 +22,7 @@ enum Target {
     /// Friendly Interactive Shell (fish)
     Fish,
     /// PowerShell
+    #[value(alias = "powershell")]
     PowerShell,
     /// Z Shell (zsh)
     Zsh,
@@ -38,32 +48,54 @@ impl Display for Target {
 }
 
 /// Generate tab-completion scripts for your shell
+///
+/// The script is printed to stdout, so it can be redirected to where your shell loads
+/// completions from:
+///
+/// - bash: `forc completions bash > ~/.local/share/bash-completion/completions/forc`
+/// - zsh: `forc completions zsh > ~/.zfunc/_forc`
+/// - fish: `forc completions fish > ~/.config/fish/completions/forc.fish`
+/// - PowerShell: `forc completions powershell >> $PROFILE`
 #[derive(Debug, Parser)]
+#[clap(bin_name = "forc completions", after_help = help())]
 pub struct Command {
     /// Specify shell to enable tab-completion for
     ///
-    /// [possible values: zsh, bash, fish, powershell, elvish]
-    ///
     /// For more info: https://fuellabs.github.io/sway/latest/forc/commands/forc_completions.html
+    #[clap(
+        value_enum,
+        required_unless_present = "target",
+        conflicts_with = "target"
+    )]
+    shell: Option<Target>,
+    /// Specify shell to enable tab-completion for. Same as the positional argument.
     #[clap(short = 'T', long, value_enum)]
-    target: Target,
+    target: Option<Target>,
 }
 
 pub(crate) fn exec(command: Command) -> ForcResult<()> {
+    let Some(target) = command.shell.or(command.target) else {
+        return Err("a shell to generate the completions for is required".into());
+    };
+    write_completions(target, &mut std::io::stdout());
+    Ok(())
+}
+
+/// Writes the completion script of `forc` for `target` to `buf`.
+fn write_completions(target: Target, buf: &mut dyn Write) {
     let mut cmd = super::super::Opt::command();
-    match command.target {
-        Target::Fig => print_completions(clap_complete_fig::Fig, &mut cmd),
-        Target::Bash => print_completions(Shell::Bash, &mut cmd),
-        Target::Elvish => print_completions(Shell::Elvish, &mut cmd),
-        Target::PowerShell => print_completions(Shell::PowerShell, &mut cmd),
-        Target::Zsh => print_completions(Shell::Zsh, &mut cmd),
-        Target::Fish => print_completions(Shell::Fish, &mut cmd),
+    match target {
+        Target::Fig => generate_to(clap_complete_fig::Fig, &mut cmd, buf),
+        Target::Bash => generate_to(Shell::Bash, &mut cmd, buf),
+        Target::Elvish => generate_to(Shell::Elvish, &mut cmd, buf),
+        Target::PowerShell => generate_to(Shell::PowerShell, &mut cmd, buf),
+        Target::Zsh => generate_to(Shell::Zsh, &mut cmd, buf),
+        Target::Fish => generate_to(Shell::Fish, &mut cmd, buf),
     }
-    Ok(())
 }
 
-fn print_completions<G: Generator>(gen: G, cmd: &mut ClapCommand) {
-    generate(gen, cmd, cmd.get_name().to_string(), &mut std::io::stdout());
+fn generate_to<G: Generator>(gen: G, cmd: &mut ClapCommand, buf: &mut dyn Write) {
+    generate(gen, cmd, cmd.get_name().to_string(), buf);
 }
 
 #[cfg(test)]
@@ -71,6 +103,27 @@ mod test {
     use super::*;
     use crate::cli::{Forc, Opt};
 
+    #[test]
+    fn generates_script_for_each_target() {
+        use clap::ValueEnum;
+
+        for target in Target::value_variants() {
+            let mut script = Vec::<u8>::new();
+            write_completions(*target, &mut script);
+            let script = String::from_utf8(script).expect("utf-8 completion script");
+            assert!(
+                script.contains("forc") && script.contains("completions"),
+                "incomplete {target} completion script:\n{script}"
+            );
+        }
+    }
+
+    #[test]
+    fn shell_and_target_conflict() {
+        assert!(Command::try_parse_from(["forc completions"]).is_err());
+        assert!(Command::try_parse_from(["forc completions", "bash", "-T", "zsh"]).is_err());
+    }
+
     #[test]
     fn bash() {
         testsuite::<completest_pty::BashRuntimeBuilder>(Shell::Bash);
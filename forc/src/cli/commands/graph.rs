@@ -0,0 +1,60 @@
+use crate::ops::forc_graph;
+use clap::Parser;
+use forc_pkg::source::IPFSNode;
+use forc_util::ForcResult;
+
+forc_util::cli_examples! {
+    crate::cli::Opt {
+        [ Print the dependency graph of the current project in DOT format => "forc graph" ]
+        [ Print the dependency graph without messages about the lock file => "forc graph --silent" ]
+        [ Print the dependencies of a workspace member as JSON => "forc graph --format json --package app" ]
+    }
+}
+
+/// The format in which the dependency graph is printed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+pub enum GraphFormat {
+    /// GraphViz DOT format.
+    #[default]
+    Dot,
+    /// JSON, listing the packages and the dependencies between them.
+    Json,
+}
+
+/// Print the resolved dependency graph of the current or target project.
+///
+/// Packages are printed with their pinned sources. Packages that appear in the graph with more
+/// than one source are highlighted as duplicates. Use `--silent` to omit any messages about
+/// updating the lock file when piping the graph into another tool, e.g. to render it with
+/// GraphViz using `forc graph --silent | dot -Tpng -o deps.png`.
+#[derive(Debug, Default, Parser)]
+#[clap(bin_name = "forc graph", version, after_help = help())]
+pub struct Command {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// The format in which the graph is printed.
+    #[clap(long, value_enum, default_value_t = GraphFormat::Dot)]
+    pub format: GraphFormat,
+    /// Only print the graph of dependencies of the given workspace member.
+    #[clap(long)]
+    pub package: Option<String>,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+    /// The IPFS Node to use for fetching IPFS sources.
+    ///
+    /// Possible values: PUBLIC, LOCAL, <GATEWAY_URL>
+    #[clap(long)]
+    pub ipfs_node: Option<IPFSNode>,
+}
+
+pub(crate) fn exec(command: Command) -> ForcResult<()> {
+    forc_graph::graph(command)?;
+    Ok(())
+}
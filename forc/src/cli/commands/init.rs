@@ -4,20 +4,32 @@ use forc_util::ForcResult;
 
 forc_util::cli_examples! {
     crate::cli::Opt {
-        [Initialize a new Forc project => "forc init --path <PATH>"]
-        [Initialize a new Forc project as workspace => "forc init --path <PATH> --workspace"]
-        [Initialize a new Forc project with a predicate => "forc init --path <PATH> --predicate"]
-        [Initialize a new Forc library project => "forc init --path <PATH> --library"]
+        [Initialize a new Forc project in the current directory => "forc init"]
+        [Initialize a new Forc project => "forc init <PATH>"]
+        [Initialize a new Forc project as workspace => "forc init <PATH> --workspace"]
+        [Initialize a new Forc project with a predicate => "forc init <PATH> --predicate"]
+        [Initialize a new Forc library project => "forc init <PATH> --library"]
     }
 }
 
 /// Create a new Forc project in an existing directory.
+///
+/// The directory may already contain other files. Existing files are never overwritten, but
+/// initialization fails if the directory already contains a `Forc.toml`.
 #[derive(Debug, Parser)]
 #[clap(bin_name = "forc init", version, after_help = help())]
 pub struct Command {
-    /// The directory in which the forc project will be initialized.
-    #[clap(long)]
+    /// The directory in which the forc project will be initialized. Defaults to the current
+    /// directory.
     pub path: Option<String>,
+    /// Same as the `PATH` argument. Kept for compatibility with earlier versions of forc.
+    #[clap(
+        long = "path",
+        value_name = "PATH",
+        conflicts_with = "path",
+        hide = true
+    )]
+    pub path_flag: Option<String>,
     /// The default program type, excluding all flags or adding this flag creates a basic contract program.
     #[clap(long)]
     pub contract: bool,
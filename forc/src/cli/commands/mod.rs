@@ -1,9 +1,13 @@
 pub mod addr2line;
+pub mod audit;
+pub mod bench;
 pub mod build;
+pub mod bytecode;
 pub mod check;
 pub mod clean;
 pub mod completions;
 pub mod contract_id;
+pub mod graph;
 pub mod init;
 pub mod new;
 pub mod parse_bytecode;
@@ -12,3 +16,4 @@ pub mod predicate_root;
 pub mod template;
 pub mod test;
 pub mod update;
+pub mod vendor;
@@ -72,7 +72,7 @@ pub(crate) fn exec(command: Command) -> ForcResult<()> {
     if dir_path.exists() {
         forc_result_bail!(
             "Directory \"{}\" already exists.\nIf you wish to initialise a forc project inside \
-            this directory, consider using `forc init --path {}`",
+            this directory, consider using `forc init {}`",
             dir_path.canonicalize()?.display(),
             dir_path.display(),
         );
@@ -82,6 +82,7 @@ pub(crate) fn exec(command: Command) -> ForcResult<()> {
 
     let init_cmd = InitCommand {
         path: Some(path),
+        path_flag: None,
         contract,
         script,
         predicate,
@@ -1,3 +1,4 @@
+use crate::ops::forc_bytecode::instruction_notes;
 use anyhow::anyhow;
 use clap::Parser;
 use forc_util::ForcResult;
@@ -42,25 +43,7 @@ pub(crate) fn exec(command: Command) -> ForcResult<()> {
     ]));
     table.style = term_table::TableStyle::empty();
     for (word_ix, (result, raw)) in instructions.enumerate() {
-        use fuel_asm::Instruction;
-        let notes = match result {
-            Ok(Instruction::JI(ji)) => format!("jump to byte {}", u32::from(ji.imm24()) * 4),
-            Ok(Instruction::JNEI(jnei)) => {
-                format!("conditionally jump to byte {}", u32::from(jnei.imm12()) * 4)
-            }
-            Ok(Instruction::JNZI(jnzi)) => {
-                format!("conditionally jump to byte {}", u32::from(jnzi.imm18()) * 4)
-            }
-            Err(fuel_asm::InvalidOpcode) if word_ix == 2 || word_ix == 3 => {
-                let parsed_raw = u32::from_be_bytes([raw[0], raw[1], raw[2], raw[3]]);
-                format!(
-                    "data section offset {} ({})",
-                    if word_ix == 2 { "lo" } else { "hi" },
-                    parsed_raw
-                )
-            }
-            Ok(_) | Err(fuel_asm::InvalidOpcode) => "".into(),
-        };
+        let notes = instruction_notes(word_ix, &result, raw);
         table.add_row(Row::new(vec![
             TableCell::builder(word_ix)
                 .col_span(1)
@@ -5,7 +5,7 @@ use forc_pkg as pkg;
 use forc_test::{decode_log_data, TestFilter, TestRunnerCount, TestedPackage};
 use forc_tracing::println_action_green;
 use forc_util::{tx_utils::format_log_receipts, ForcError, ForcResult};
-use pkg::manifest::build_profile::ExperimentalFlags;
+use pkg::{manifest::build_profile::ExperimentalFlags, TestPassCondition};
 use sway_core::fuel_prelude::fuel_tx::Receipt;
 use tracing::info;
 
@@ -193,6 +193,13 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
                 "      - test {}, {:?}:{} ",
                 failed_test_name, path, line_number
             );
+            match failed_test.condition {
+                TestPassCondition::ShouldRevert(Some(expected_revert_code)) => {
+                    info!("        expected revert code: {expected_revert_code:x}")
+                }
+                TestPassCondition::ShouldRevert(None) => info!("        expected a revert"),
+                TestPassCondition::ShouldNotRevert => {}
+            }
             if let Some(revert_code) = failed_test.revert_code() {
                 // If we have a revert_code, try to get a known error signal
                 let mut failed_info_str = format!("        revert code: {revert_code:x}");
@@ -225,39 +232,52 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
 }
 
 fn opts_from_cmd(cmd: Command) -> forc_test::TestOpts {
+    opts_from_build(cmd.build, cmd.no_encoding_v1)
+}
+
+/// Converts the shared build options of a test-like command into `forc_test::TestOpts`.
+pub(crate) fn opts_from_build(
+    build: cli::shared::Build,
+    no_encoding_v1: bool,
+) -> forc_test::TestOpts {
     forc_test::TestOpts {
         pkg: pkg::PkgOpts {
-            path: cmd.build.pkg.path,
-            offline: cmd.build.pkg.offline,
-            terse: cmd.build.pkg.terse,
-            locked: cmd.build.pkg.locked,
-            output_directory: cmd.build.pkg.output_directory,
-            ipfs_node: cmd.build.pkg.ipfs_node.unwrap_or_default(),
+            path: build.pkg.path,
+            offline: build.pkg.offline,
+            terse: build.pkg.terse,
+            locked: build.pkg.locked,
+            frozen: build.pkg.frozen,
+            output_directory: build.pkg.output_directory,
+            ipfs_node: build.pkg.ipfs_node.unwrap_or_default(),
         },
         print: pkg::PrintOpts {
-            ast: cmd.build.print.ast,
-            dca_graph: cmd.build.print.dca_graph.clone(),
-            dca_graph_url_format: cmd.build.print.dca_graph_url_format.clone(),
-            asm: cmd.build.print.asm(),
-            bytecode: cmd.build.print.bytecode,
+            ast: build.print.ast,
+            dca_graph: build.print.dca_graph.clone(),
+            dca_graph_url_format: build.print.dca_graph_url_format.clone(),
+            asm: build.print.asm(),
+            bytecode: build.print.bytecode,
             bytecode_spans: false,
-            ir: cmd.build.print.ir(),
-            reverse_order: cmd.build.print.reverse_order,
+            ir: build.print.ir(),
+            reverse_order: build.print.reverse_order,
         },
-        time_phases: cmd.build.print.time_phases,
-        metrics_outfile: cmd.build.print.metrics_outfile,
+        time_phases: build.print.time_phases,
+        metrics_outfile: build.print.metrics_outfile,
         minify: pkg::MinifyOpts {
-            json_abi: cmd.build.minify.json_abi,
-            json_storage_slots: cmd.build.minify.json_storage_slots,
+            json_abi: build.minify.json_abi,
+            json_storage_slots: build.minify.json_storage_slots,
         },
-        build_profile: cmd.build.profile.build_profile,
-        release: cmd.build.profile.release,
-        error_on_warnings: cmd.build.profile.error_on_warnings,
-        binary_outfile: cmd.build.output.bin_file,
-        debug_outfile: cmd.build.output.debug_file,
-        build_target: cmd.build.build_target,
+        build_profile: build.profile.build_profile,
+        release: build.profile.release,
+        error_on_warnings: build.profile.error_on_warnings,
+        features: build.profile.features,
+        overflow: build.profile.overflow,
+        const_eval_limit: build.profile.const_eval_limit,
+        binary_outfile: build.output.bin_file,
+        debug_outfile: build.output.debug_file,
+        emit: build.output.emit,
+        build_target: build.build_target,
         experimental: ExperimentalFlags {
-            new_encoding: !cmd.no_encoding_v1,
+            new_encoding: !no_encoding_v1,
         },
     }
 }
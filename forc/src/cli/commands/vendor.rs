@@ -0,0 +1,45 @@
+use crate::ops::forc_vendor;
+use clap::Parser;
+use forc_pkg::source::IPFSNode;
+use forc_util::ForcResult;
+
+forc_util::cli_examples! {
+    crate::cli::Opt {
+        [ Vendor the dependencies of the current project => "forc vendor" ]
+        [ Vendor the dependencies into a custom directory => "forc vendor deps" ]
+    }
+}
+
+/// Copy all git and IPFS dependencies of the current or target project into a vendor directory.
+///
+/// Once the vendor directory is specified in the `[vendor]` table of the manifest, the vendored
+/// dependencies are used instead of fetching them, allowing the project to be built with
+/// `--offline`.
+#[derive(Debug, Default, Parser)]
+#[clap(bin_name = "forc vendor", version, after_help = help())]
+pub struct Command {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// The directory into which the dependencies are copied, relative to the project directory.
+    #[clap(default_value = "vendor")]
+    pub directory: String,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+    /// The IPFS Node to use for fetching IPFS sources.
+    ///
+    /// Possible values: PUBLIC, LOCAL, <GATEWAY_URL>
+    #[clap(long)]
+    pub ipfs_node: Option<IPFSNode>,
+}
+
+pub(crate) fn exec(command: Command) -> ForcResult<()> {
+    forc_vendor::vendor(command)?;
+    Ok(())
+}
@@ -1,10 +1,13 @@
 use self::commands::{
-    addr2line, build, check, clean, completions, contract_id, init, new, parse_bytecode, plugins,
-    predicate_root, template, test, update,
+    addr2line, audit, bench, build, bytecode, check, clean, completions, contract_id, graph, init,
+    new, parse_bytecode, plugins, predicate_root, template, test, update, vendor,
 };
 use addr2line::Command as Addr2LineCommand;
 use anyhow::anyhow;
+pub use audit::Command as AuditCommand;
+pub use bench::Command as BenchCommand;
 pub use build::Command as BuildCommand;
+pub use bytecode::Command as BytecodeCommand;
 pub use check::Command as CheckCommand;
 use clap::{Parser, Subcommand};
 pub use clean::Command as CleanCommand;
@@ -12,6 +15,7 @@ pub use completions::Command as CompletionsCommand;
 pub(crate) use contract_id::Command as ContractIdCommand;
 use forc_tracing::{init_tracing_subscriber, TracingSubscriberOptions};
 use forc_util::ForcResult;
+pub use graph::{Command as GraphCommand, GraphFormat};
 pub use init::Command as InitCommand;
 pub use new::Command as NewCommand;
 use parse_bytecode::Command as ParseBytecodeCommand;
@@ -22,6 +26,7 @@ pub use template::Command as TemplateCommand;
 pub use test::Command as TestCommand;
 use tracing::metadata::LevelFilter;
 pub use update::Command as UpdateCommand;
+pub use vendor::Command as VendorCommand;
 
 mod commands;
 mod plugin;
@@ -66,17 +71,22 @@ struct Opt {
 enum Forc {
     #[clap(name = "addr2line")]
     Addr2Line(Addr2LineCommand),
+    Audit(AuditCommand),
+    Bench(BenchCommand),
     #[clap(visible_alias = "b")]
     Build(BuildCommand),
+    Bytecode(BytecodeCommand),
     Check(CheckCommand),
     Clean(CleanCommand),
     Completions(CompletionsCommand),
+    Graph(GraphCommand),
     New(NewCommand),
     Init(InitCommand),
     ParseBytecode(ParseBytecodeCommand),
     #[clap(visible_alias = "t")]
     Test(TestCommand),
     Update(UpdateCommand),
+    Vendor(VendorCommand),
     Plugins(PluginsCommand),
     Template(TemplateCommand),
     ContractId(ContractIdCommand),
@@ -98,16 +108,21 @@ impl Forc {
     pub fn possible_values() -> Vec<&'static str> {
         vec![
             "addr2line",
+            "audit",
+            "bench",
             "build",
+            "bytecode",
             "check",
             "clean",
             "completions",
+            "graph",
             "init",
             "new",
             "parse-bytecode",
             "plugins",
             "test",
             "update",
+            "vendor",
             "template",
             "contract-id",
             "predicate-root",
@@ -128,16 +143,21 @@ pub async fn run_cli() -> ForcResult<()> {
 
     match opt.command {
         Forc::Addr2Line(command) => addr2line::exec(command),
+        Forc::Audit(command) => audit::exec(command),
+        Forc::Bench(command) => bench::exec(command),
         Forc::Build(command) => build::exec(command),
+        Forc::Bytecode(command) => bytecode::exec(command),
         Forc::Check(command) => check::exec(command),
         Forc::Clean(command) => clean::exec(command),
         Forc::Completions(command) => completions::exec(command),
+        Forc::Graph(command) => graph::exec(command),
         Forc::Init(command) => init::exec(command),
         Forc::New(command) => new::exec(command),
         Forc::ParseBytecode(command) => parse_bytecode::exec(command),
         Forc::Plugins(command) => plugins::exec(command),
         Forc::Test(command) => test::exec(command),
         Forc::Update(command) => update::exec(command),
+        Forc::Vendor(command) => vendor::exec(command),
         Forc::Template(command) => template::exec(command),
         Forc::ContractId(command) => contract_id::exec(command),
         Forc::PredicateRoot(command) => predicate_root::exec(command),
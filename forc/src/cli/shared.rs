@@ -1,7 +1,7 @@
 //! Sets of arguments that are shared between commands.
 use clap::{Args, Parser};
 use forc_pkg::source::IPFSNode;
-use sway_core::{BuildTarget, PrintAsm, PrintIr};
+use sway_core::{BuildTarget, EmitKind, OverflowBehavior, PrintAsm, PrintIr};
 use sway_ir::PassManager;
 
 /// Args that can be shared between all commands that `build` a package. E.g. `build`, `test`,
@@ -34,6 +34,15 @@ pub struct BuildOutput {
     /// If the file extension is .json, JSON format is used. Otherwise, an .elf file containing DWARF format is emitted.
     #[clap(long = "output-debug", short = 'g')]
     pub debug_file: Option<String>,
+    /// Comma separated list of the kinds of compiler outputs to write to the output directory.
+    ///
+    /// Possible values are `ast`, `typed-ast`, `ir`, `asm`, `bytecode`, `abi` and `abi-spans`. Each
+    /// output is written to `<name>.<kind>`, except for the bytecode, which is written to
+    /// `<name>.bin`, the ABI, which is written to `<name>-abi.json`, and the source locations of
+    /// the ABI functions and types, which are written to `<name>-abi-spans.json`. By default, the
+    /// bytecode and the ABI are written.
+    #[clap(long, value_delimiter = ',')]
+    pub emit: Vec<EmitKind>,
 }
 
 /// Build profile options.
@@ -50,6 +59,23 @@ pub struct BuildProfile {
     /// Treat warnings as errors.
     #[clap(long)]
     pub error_on_warnings: bool,
+    /// Comma separated list of features to enable.
+    ///
+    /// Items annotated with `#[cfg(feature = "...")]` are only compiled when their feature is
+    /// enabled. Features are enabled for the workspace members, not for their dependencies.
+    #[clap(long, value_delimiter = ',')]
+    pub features: Vec<String>,
+    /// The behavior of integer arithmetic on overflow: `trap` reverts, `wrap` wraps around.
+    ///
+    /// Overrides the `overflow` key of the build profile, which defaults to `trap`.
+    #[clap(long, value_enum)]
+    pub overflow: Option<OverflowBehavior>,
+    /// The maximum number of steps, i.e. function calls and loop iterations, taken when
+    /// evaluating a constant expression.
+    ///
+    /// Overrides the `const_eval_limit` key of the build profile, which defaults to one million.
+    #[clap(long)]
+    pub const_eval_limit: Option<usize>,
 }
 
 /// Options related to printing stages of compiler output.
@@ -150,6 +176,11 @@ pub struct Pkg {
     /// If the lock file is missing, or it needs to be updated, Forc will exit with an error.
     #[clap(long)]
     pub locked: bool,
+    /// Requires that the Forc.lock file is up-to-date and prevents Forc from using the network.
+    ///
+    /// Equivalent to passing both `--locked` and `--offline`.
+    #[clap(long)]
+    pub frozen: bool,
     /// The IPFS node to use for fetching IPFS sources.
     ///
     /// [possible values: PUBLIC, LOCAL, <GATEWAY_URL>]
@@ -0,0 +1,91 @@
+use crate::cli::AuditCommand;
+use anyhow::{bail, Result};
+use forc_pkg::manifest::GenericManifestFile;
+use forc_pkg::{self as pkg, advisory};
+use forc_tracing::{println_action_green, println_action_red};
+use pkg::manifest::ManifestFile;
+use std::path::PathBuf;
+use tracing::info;
+
+/// Check the resolved dependency graph of the project against the advisory database, printing
+/// every advisory that applies.
+///
+/// Returns the number of advisories found.
+pub fn audit(command: AuditCommand) -> Result<usize> {
+    let AuditCommand {
+        path,
+        db,
+        db_url,
+        ignore,
+        offline_mode: offline,
+        locked,
+        frozen,
+        ipfs_node,
+    } = command;
+
+    let this_dir = match path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let db_path = db
+        .map(PathBuf::from)
+        .unwrap_or_else(advisory::default_db_path);
+    let db = match db_url {
+        Some(url) => {
+            if offline || frozen {
+                bail!("cannot fetch the advisory database in offline mode");
+            }
+            println_action_green("Fetching", &format!("advisory database from {url}"));
+            advisory::Database::fetch(&url, &db_path)?
+        }
+        None if db_path.exists() => advisory::Database::from_path(&db_path)?,
+        None => bail!(
+            "no advisory database found at {}. Use `--db-url` to fetch one.",
+            db_path.display()
+        ),
+    };
+
+    let manifest_file = ManifestFile::from_dir(this_dir)?;
+    let member_manifests = manifest_file.member_manifests()?;
+    let lock_path = manifest_file.lock_path()?;
+    let plan = pkg::BuildPlan::from_lock_and_manifests(
+        &lock_path,
+        &member_manifests,
+        locked || frozen,
+        offline || frozen,
+        &ipfs_node.unwrap_or_default(),
+    )?;
+
+    let graph = plan.graph();
+    println_action_green(
+        "Auditing",
+        &format!(
+            "{} packages against {} advisories",
+            graph.node_count(),
+            db.advisories.len()
+        ),
+    );
+    let findings = db.audit(graph, &ignore);
+    for finding in &findings {
+        let advisory = finding.advisory;
+        println_action_red(
+            "Advisory",
+            &format!(
+                "{} [{}]: {}",
+                advisory.id, advisory.severity, advisory.title
+            ),
+        );
+        info!(
+            "    package: {} {} ({})",
+            finding.pkg.name, finding.version, finding.pkg.source
+        );
+        match &advisory.patched {
+            Some(patched) => info!("   solution: upgrade to {patched} or later"),
+            None => info!("   solution: no patched version available"),
+        }
+        if let Some(url) = &advisory.url {
+            info!("        url: {url}");
+        }
+    }
+    Ok(findings.len())
+}
@@ -16,6 +16,7 @@ fn opts_from_cmd(cmd: BuildCommand) -> pkg::BuildOpts {
             offline: cmd.build.pkg.offline,
             terse: cmd.build.pkg.terse,
             locked: cmd.build.pkg.locked,
+            frozen: cmd.build.pkg.frozen,
             output_directory: cmd.build.pkg.output_directory,
             ipfs_node: cmd.build.pkg.ipfs_node.unwrap_or_default(),
         },
@@ -40,8 +41,12 @@ fn opts_from_cmd(cmd: BuildCommand) -> pkg::BuildOpts {
         error_on_warnings: cmd.build.profile.error_on_warnings,
         binary_outfile: cmd.build.output.bin_file,
         debug_outfile: cmd.build.output.debug_file,
+        emit: cmd.build.output.emit,
         build_target: cmd.build.build_target,
         tests: cmd.tests,
+        features: cmd.build.profile.features,
+        overflow: cmd.build.profile.overflow,
+        const_eval_limit: cmd.build.profile.const_eval_limit,
         member_filter: MemberFilter::default(),
         experimental: ExperimentalFlags {
             new_encoding: !cmd.no_encoding_v1,
@@ -0,0 +1,183 @@
+use crate::cli::{BuildCommand, BytecodeCommand};
+use crate::ops::forc_build;
+use anyhow::{anyhow, Result};
+use forc_tracing::println_green;
+use forc_util::ForcResult;
+use fuel_asm::{Instruction, InvalidOpcode};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use sway_core::source_map::SourceMap;
+use tracing::info;
+
+/// Prints the bytecode either of an already built binary or of every member of the freshly
+/// built project.
+pub fn bytecode(command: BytecodeCommand) -> ForcResult<()> {
+    let BytecodeCommand {
+        build,
+        disassemble,
+        bytecode_path,
+        sourcemap_path,
+        no_encoding_v1,
+    } = command;
+
+    let search_dir = match &build.pkg.path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+
+    if let Some(bytecode_path) = bytecode_path {
+        let bytes = fs::read(&bytecode_path)
+            .map_err(|err| anyhow!("{:?}: could not read: {:?}", bytecode_path, err))?;
+        let source_map = sourcemap_path
+            .map(|path| read_source_map(&path))
+            .transpose()?;
+        print_bytecode(&bytes, source_map.as_ref(), &search_dir, disassemble);
+        return Ok(());
+    }
+
+    let built = forc_build::build(BuildCommand {
+        build,
+        tests: false,
+        no_encoding_v1,
+    })?;
+    for (pinned, built_pkg) in built.into_members() {
+        println_green(&format!(" {}", pinned.name));
+        print_bytecode(
+            &built_pkg.bytecode.bytes,
+            Some(&built_pkg.source_map),
+            &search_dir,
+            disassemble,
+        );
+    }
+    Ok(())
+}
+
+fn read_source_map(path: &Path) -> Result<SourceMap> {
+    let contents =
+        fs::read(path).map_err(|err| anyhow!("{:?}: could not read: {:?}", path, err))?;
+    serde_json::from_slice(&contents)
+        .map_err(|err| anyhow!("{:?}: invalid source map json: {}", path, err))
+}
+
+fn print_bytecode(
+    bytes: &[u8],
+    source_map: Option<&SourceMap>,
+    search_dir: &Path,
+    disassemble: bool,
+) {
+    if !disassemble {
+        for (word_ix, raw) in bytes.chunks(Instruction::SIZE).enumerate() {
+            info!(
+                "{:#010x}  {}",
+                word_ix * Instruction::SIZE,
+                hex::encode(raw)
+            );
+        }
+        return;
+    }
+
+    // The second word of the program is the offset of the data section, relative to the
+    // start of the bytecode. Everything from there on is data and must not be decoded.
+    let data_section_offset = bytes
+        .get(8..16)
+        .map(|word| u64::from_be_bytes(word.try_into().unwrap()) as usize)
+        .unwrap_or(bytes.len());
+
+    let mut sources = SourceCache::default();
+    let mut last_location = None;
+    let instructions =
+        fuel_asm::from_bytes(bytes.iter().cloned()).zip(bytes.chunks(Instruction::SIZE));
+    for (word_ix, (result, raw)) in instructions.enumerate() {
+        let offset = word_ix * Instruction::SIZE;
+        let raw_str = raw
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if offset >= data_section_offset {
+            info!("{offset:#010x}  {raw_str}  .data");
+            continue;
+        }
+
+        if let Some((path, range)) = source_map.and_then(|sm| sm.addr_to_span(word_ix)) {
+            let location = (path, range.start.line);
+            if last_location.as_ref() != Some(&location) {
+                let (path, line) = &location;
+                let line_text = sources.line(search_dir, path, *line).unwrap_or_default();
+                info!(
+                    "; {}:{}:{}  {}",
+                    path.display(),
+                    line,
+                    range.start.col,
+                    line_text.trim()
+                );
+                last_location = Some(location);
+            }
+        }
+
+        let op = match &result {
+            Ok(inst) => format!("{inst:?}"),
+            Err(err) => format!("{err:?}"),
+        };
+        let notes = instruction_notes(word_ix, &result, raw);
+        if notes.is_empty() {
+            info!("{offset:#010x}  {raw_str}  {op}");
+        } else {
+            info!("{offset:#010x}  {raw_str}  {op:<32} ; {notes}");
+        }
+    }
+}
+
+/// Returns a short human-readable description of the given instruction, if there is one worth
+/// showing next to it.
+pub(crate) fn instruction_notes(
+    word_ix: usize,
+    result: &Result<Instruction, InvalidOpcode>,
+    raw: &[u8],
+) -> String {
+    match result {
+        Ok(Instruction::JI(ji)) => format!("jump to byte {}", u32::from(ji.imm24()) * 4),
+        Ok(Instruction::JNEI(jnei)) => {
+            format!("conditionally jump to byte {}", u32::from(jnei.imm12()) * 4)
+        }
+        Ok(Instruction::JNZI(jnzi)) => {
+            format!("conditionally jump to byte {}", u32::from(jnzi.imm18()) * 4)
+        }
+        Err(InvalidOpcode) if word_ix == 2 || word_ix == 3 => {
+            let parsed_raw = u32::from_be_bytes([raw[0], raw[1], raw[2], raw[3]]);
+            format!(
+                "data section offset {} ({})",
+                if word_ix == 2 { "lo" } else { "hi" },
+                parsed_raw
+            )
+        }
+        Ok(_) | Err(InvalidOpcode) => "".into(),
+    }
+}
+
+/// Lazily loaded source files, used to print the source lines opcodes originate from.
+#[derive(Default)]
+struct SourceCache {
+    files: HashMap<PathBuf, Option<Vec<String>>>,
+}
+
+impl SourceCache {
+    fn line(&mut self, search_dir: &Path, path: &Path, line: usize) -> Option<String> {
+        let lines = self.files.entry(path.to_path_buf()).or_insert_with(|| {
+            let full_path = if path.is_relative() {
+                search_dir.join(path)
+            } else {
+                path.to_path_buf()
+            };
+            fs::read_to_string(full_path)
+                .ok()
+                .map(|src| src.lines().map(str::to_string).collect())
+        });
+        lines
+            .as_ref()
+            .and_then(|lines| lines.get(line.checked_sub(1)?))
+            .cloned()
+    }
+}
@@ -14,6 +14,7 @@ pub fn check(command: CheckCommand, engines: &Engines) -> Result<(Option<ty::TyP
         offline_mode: offline,
         terse_mode,
         locked,
+        frozen,
         disable_tests,
         ipfs_node,
         no_encoding_v1,
@@ -30,8 +31,8 @@ pub fn check(command: CheckCommand, engines: &Engines) -> Result<(Option<ty::TyP
     let plan = pkg::BuildPlan::from_lock_and_manifests(
         &lock_path,
         &member_manifests,
-        locked,
-        offline,
+        locked || frozen,
+        offline || frozen,
         &ipfs_node.unwrap_or_default(),
     )?;
     let tests_enabled = !disable_tests;
@@ -51,6 +51,7 @@ fn build_opts_from_cmd(cmd: &ContractIdCommand) -> pkg::BuildOpts {
             offline: cmd.pkg.offline,
             terse: cmd.pkg.terse,
             locked: cmd.pkg.locked,
+            frozen: cmd.pkg.frozen,
             output_directory: cmd.pkg.output_directory.clone(),
             ipfs_node: cmd.pkg.ipfs_node.clone().unwrap_or_default(),
         },
@@ -75,8 +76,12 @@ fn build_opts_from_cmd(cmd: &ContractIdCommand) -> pkg::BuildOpts {
         error_on_warnings: cmd.build_profile.error_on_warnings,
         binary_outfile: cmd.build_output.bin_file.clone(),
         debug_outfile: cmd.build_output.debug_file.clone(),
+        emit: cmd.build_output.emit.clone(),
         build_target: BuildTarget::default(),
         tests: false,
+        features: cmd.build_profile.features.clone(),
+        overflow: cmd.build_profile.overflow,
+        const_eval_limit: cmd.build_profile.const_eval_limit,
         member_filter: pkg::MemberFilter::only_contracts(),
         experimental: ExperimentalFlags {
             new_encoding: !cmd.no_encoding_v1,
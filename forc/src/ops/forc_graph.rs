@@ -0,0 +1,47 @@
+use crate::cli::{GraphCommand, GraphFormat};
+use anyhow::{anyhow, Result};
+use forc_pkg::manifest::GenericManifestFile;
+use forc_pkg::{self as pkg, graph::GraphExport};
+use pkg::manifest::ManifestFile;
+use std::path::PathBuf;
+
+/// Resolve the dependency graph of the project and print it in the requested format.
+pub fn graph(command: GraphCommand) -> Result<()> {
+    let GraphCommand {
+        path,
+        format,
+        package,
+        offline_mode: offline,
+        locked,
+        ipfs_node,
+    } = command;
+
+    let this_dir = match path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let manifest_file = ManifestFile::from_dir(this_dir)?;
+    let member_manifests = manifest_file.member_manifests()?;
+    let lock_path = manifest_file.lock_path()?;
+    let plan = pkg::BuildPlan::from_lock_and_manifests(
+        &lock_path,
+        &member_manifests,
+        locked,
+        offline,
+        &ipfs_node.unwrap_or_default(),
+    )?;
+
+    let root = package
+        .map(|name| {
+            plan.find_member_index(&name)
+                .ok_or_else(|| anyhow!("no member package named `{name}` in the workspace"))
+        })
+        .transpose()?;
+    let export = GraphExport::new(plan.graph(), root);
+    let output = match format {
+        GraphFormat::Dot => export.to_dot(),
+        GraphFormat::Json => export.to_json()?,
+    };
+    println!("{}", output.trim_end());
+    Ok(())
+}
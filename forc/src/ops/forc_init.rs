@@ -1,6 +1,7 @@
 use crate::cli::InitCommand;
 use crate::utils::{defaults, program_type::ProgramType};
 use anyhow::Context;
+use forc_tracing::println_warning;
 use forc_util::{forc_result_bail, validate_project_name, ForcResult};
 use std::fs;
 use std::io::Write;
@@ -43,7 +44,7 @@ fn print_welcome_message() {
 }
 
 pub fn init(command: InitCommand) -> ForcResult<()> {
-    let project_dir = match &command.path {
+    let project_dir = match command.path.as_ref().or(command.path_flag.as_ref()) {
         Some(p) => PathBuf::from(p),
         None => {
             std::env::current_dir().context("Failed to get current directory for forc init.")?
@@ -64,15 +65,14 @@ pub fn init(command: InitCommand) -> ForcResult<()> {
         );
     }
 
-    debug!(
-        "\nUsing project directory at {}",
-        project_dir.canonicalize()?.display()
-    );
+    // Canonicalize the path so that the project name can also be inferred from paths like `.`.
+    let project_dir = project_dir.canonicalize()?;
+    debug!("\nUsing project directory at {}", project_dir.display());
 
     let project_name = match command.name {
         Some(name) => name,
         None => project_dir
-            .file_stem()
+            .file_name()
             .context("Failed to infer project name from directory name.")?
             .to_string_lossy()
             .into_owned(),
@@ -127,28 +127,29 @@ pub fn init(command: InitCommand) -> ForcResult<()> {
         )?,
     }
 
+    // The entry file is only written if the directory doesn't already contain one.
     match init_type {
-        InitType::Package(ProgramType::Contract) => fs::write(
-            Path::new(&project_dir)
+        InitType::Package(ProgramType::Contract) => write_if_missing(
+            &Path::new(&project_dir)
                 .join("src")
                 .join(constants::MAIN_ENTRY),
             defaults::default_contract(),
         )?,
-        InitType::Package(ProgramType::Script) => fs::write(
-            Path::new(&project_dir)
+        InitType::Package(ProgramType::Script) => write_if_missing(
+            &Path::new(&project_dir)
                 .join("src")
                 .join(constants::MAIN_ENTRY),
             defaults::default_script(),
         )?,
-        InitType::Package(ProgramType::Library) => fs::write(
-            Path::new(&project_dir)
+        InitType::Package(ProgramType::Library) => write_if_missing(
+            &Path::new(&project_dir)
                 .join("src")
                 .join(constants::LIB_ENTRY),
             // Library names cannot have `-` in them because the Sway compiler does not allow that
             defaults::default_library(),
         )?,
-        InitType::Package(ProgramType::Predicate) => fs::write(
-            Path::new(&project_dir)
+        InitType::Package(ProgramType::Predicate) => write_if_missing(
+            &Path::new(&project_dir)
                 .join("src")
                 .join(constants::MAIN_ENTRY),
             defaults::default_predicate(),
@@ -158,12 +159,25 @@ pub fn init(command: InitCommand) -> ForcResult<()> {
 
     // Ignore default `out` and `target` directories created by forc and cargo.
     let gitignore_path = Path::new(&project_dir).join(".gitignore");
-    // Append to existing gitignore if it exists otherwise create a new one.
+    // Append the entries missing from the existing gitignore if it exists, otherwise create a
+    // new one.
+    let existing_gitignore = fs::read_to_string(&gitignore_path).unwrap_or_default();
+    let missing_entries = defaults::default_gitignore()
+        .lines()
+        .filter(|entry| !existing_gitignore.lines().any(|line| line.trim() == *entry))
+        .map(|entry| format!("{entry}\n"))
+        .collect::<String>();
     let mut gitignore_file = fs::OpenOptions::new()
         .append(true)
         .create(true)
         .open(&gitignore_path)?;
-    gitignore_file.write_all(defaults::default_gitignore().as_bytes())?;
+    if !missing_entries.is_empty()
+        && !existing_gitignore.is_empty()
+        && !existing_gitignore.ends_with('\n')
+    {
+        gitignore_file.write_all(b"\n")?;
+    }
+    gitignore_file.write_all(missing_entries.as_bytes())?;
 
     debug!(
         "\nCreated .gitignore at {}",
@@ -176,3 +190,16 @@ pub fn init(command: InitCommand) -> ForcResult<()> {
 
     Ok(())
 }
+
+/// Writes `contents` to the file at `path`, unless the file already exists.
+fn write_if_missing(path: &Path, contents: String) -> ForcResult<()> {
+    if path.exists() {
+        println_warning(&format!(
+            "'{}' already exists and was left unchanged.",
+            path.display()
+        ));
+        return Ok(());
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
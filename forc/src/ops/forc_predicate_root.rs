@@ -20,6 +20,7 @@ fn build_opts_from_cmd(cmd: PredicateRootCommand) -> pkg::BuildOpts {
             offline: cmd.pkg.offline,
             terse: cmd.pkg.terse,
             locked: cmd.pkg.locked,
+            frozen: cmd.pkg.frozen,
             output_directory: cmd.pkg.output_directory.clone(),
             ipfs_node: cmd.pkg.ipfs_node.unwrap_or_default(),
         },
@@ -44,8 +45,12 @@ fn build_opts_from_cmd(cmd: PredicateRootCommand) -> pkg::BuildOpts {
         error_on_warnings: cmd.build_profile.error_on_warnings,
         binary_outfile: cmd.build_output.bin_file.clone(),
         debug_outfile: cmd.build_output.debug_file,
+        emit: cmd.build_output.emit,
         build_target: BuildTarget::default(),
         tests: false,
+        features: cmd.build_profile.features,
+        overflow: cmd.build_profile.overflow,
+        const_eval_limit: cmd.build_profile.const_eval_limit,
         member_filter: pkg::MemberFilter::only_predicates(),
         experimental: ExperimentalFlags {
             new_encoding: !cmd.no_encoding_v1,
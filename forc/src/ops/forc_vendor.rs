@@ -0,0 +1,49 @@
+use crate::cli::VendorCommand;
+use anyhow::Result;
+use forc_pkg::manifest::GenericManifestFile;
+use forc_pkg::{self as pkg, vendor};
+use forc_tracing::println_action_green;
+use pkg::manifest::ManifestFile;
+use std::{fs, path::PathBuf};
+use tracing::info;
+
+/// Copy the remote dependencies of the project into the vendor directory and print the manifest
+/// table required to use them.
+pub fn vendor(command: VendorCommand) -> Result<()> {
+    let VendorCommand {
+        path,
+        directory,
+        offline_mode: offline,
+        locked,
+        ipfs_node,
+    } = command;
+
+    let this_dir = match path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let manifest_file = ManifestFile::from_dir(this_dir)?;
+    let vendor_dir = manifest_file.dir().join(&directory);
+    fs::create_dir_all(&vendor_dir)?;
+
+    let vendored = vendor::vendor(
+        &manifest_file,
+        &vendor_dir,
+        locked,
+        offline,
+        &ipfs_node.unwrap_or_default(),
+    )?;
+    for vendored_pkg in &vendored {
+        println_action_green(
+            "Vendored",
+            &format!("{} {}", vendored_pkg.pkg.name, vendored_pkg.pkg.source),
+        );
+    }
+
+    info!(
+        "\nTo use the vendored sources, add the following to {}:\n\n[vendor]\ndirectory = {:?}",
+        manifest_file.path().display(),
+        directory
+    );
+    Ok(())
+}
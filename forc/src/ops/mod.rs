@@ -1,8 +1,12 @@
+pub mod forc_audit;
 pub mod forc_build;
+pub mod forc_bytecode;
 pub mod forc_check;
 pub mod forc_clean;
 pub mod forc_contract_id;
+pub mod forc_graph;
 pub mod forc_init;
 pub mod forc_predicate_root;
 pub mod forc_template;
 pub mod forc_update;
+pub mod forc_vendor;
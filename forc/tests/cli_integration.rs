@@ -82,3 +82,323 @@ fn test_forc_test_both_logs() -> Result<(), rexpect::error::Error> {
     process.process.exit()?;
     Ok(())
 }
+
+#[test]
+fn test_forc_build_locked_outdated_lock() -> Result<(), rexpect::error::Error> {
+    // The manifest of the project declares a dependency that is missing from its lock file.
+    let project_dir = test_fixtures_path().join("outdated_lock");
+    let lock_path = project_dir.join("Forc.lock");
+    let lock_before = std::fs::read_to_string(&lock_path).unwrap();
+
+    for flag in ["--locked", "--frozen"] {
+        let mut process = spawn(
+            &format!(
+                "cargo run --bin forc -- build {flag} --path {}",
+                project_dir.to_string_lossy()
+            ),
+            Some(TIMEOUT_MS),
+        )?;
+
+        process.exp_string("needs to be updated (Cause: lock file did not match manifest)")?;
+        process.exp_eof()?;
+
+        // The lock file must not be updated.
+        assert_eq!(std::fs::read_to_string(&lock_path).unwrap(), lock_before);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_forc_bytecode() -> Result<(), rexpect::error::Error> {
+    let project_dir = test_fixtures_path().join("bytecode_script");
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- bytecode --path {}",
+            project_dir.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+
+    // Without `--disassemble` every word is printed as hex along with its offset.
+    process.exp_string("bytecode_script")?;
+    process.exp_regex(r"0x00000000  [0-9a-f]{8}\r?\n")?;
+    process.exp_regex(r"0x00000004  [0-9a-f]{8}\r?\n")?;
+    process.exp_eof()?;
+    Ok(())
+}
+
+#[test]
+fn test_forc_bytecode_disassemble() -> Result<(), rexpect::error::Error> {
+    let project_dir = test_fixtures_path().join("bytecode_script");
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- bytecode --disassemble --path {}",
+            project_dir.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+
+    // The opcodes are interleaved with the source lines they originate from.
+    process.exp_string("bytecode_script")?;
+    process.exp_regex(r"0x00000000  [0-9a-f]{2} [0-9a-f]{2} [0-9a-f]{2} [0-9a-f]{2}  MOVE")?;
+    process.exp_string("; data section offset hi")?;
+    process.exp_string("src/main.sw:3:1  fn main() -> u64 {")?;
+    process.exp_string("src/main.sw:4:5  let answer = 42;")?;
+    process.exp_eof()?;
+    Ok(())
+}
+
+#[test]
+fn test_forc_bytecode_sourcemap_with_unknown_path() -> Result<(), rexpect::error::Error> {
+    // The second opcode maps to a path index that is not in the source map, and must be
+    // printed without a source annotation instead of crashing.
+    let dir = std::env::temp_dir().join("forc_bytecode_sourcemap_with_unknown_path");
+    std::fs::create_dir_all(&dir).unwrap();
+    let bytecode_path = dir.join("program.bin");
+    let sourcemap_path = dir.join("program-sourcemap.json");
+    // Four `NOOP`s.
+    std::fs::write(&bytecode_path, [0x47, 0x00, 0x00, 0x00].repeat(4)).unwrap();
+    std::fs::write(
+        &sourcemap_path,
+        r#"{
+            "dependency_paths": [],
+            "paths": ["src/main.sw"],
+            "map": {
+                "0": { "path": 0, "range": { "start": { "line": 1, "col": 1 }, "end": { "line": 1, "col": 5 } } },
+                "1": { "path": 7, "range": { "start": { "line": 2, "col": 1 }, "end": { "line": 2, "col": 5 } } }
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- bytecode --disassemble --bin-file {} --sourcemap-file {}",
+            bytecode_path.to_string_lossy(),
+            sourcemap_path.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+
+    process.exp_string("; src/main.sw:1:1")?;
+    process.exp_string("0x00000000  47 00 00 00  NOOP")?;
+    process.exp_string("0x00000004  47 00 00 00  NOOP")?;
+    process.exp_string("0x00000008  47 00 00 00  NOOP")?;
+    process.exp_string("0x0000000c  47 00 00 00  NOOP")?;
+    process.exp_eof()?;
+    Ok(())
+}
+
+#[test]
+fn test_forc_init_in_existing_directory() -> Result<(), rexpect::error::Error> {
+    let dir = std::env::temp_dir()
+        .join("forc_init_in_existing_directory")
+        .join("my_project");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(dir.join("README.md"), "# My project\n").unwrap();
+    std::fs::write(dir.join("src").join("main.sw"), "script;\n").unwrap();
+    std::fs::write(dir.join(".gitignore"), "target").unwrap();
+
+    let mut process = spawn(
+        &format!("cargo run --bin forc -- init {}", dir.to_string_lossy()),
+        Some(TIMEOUT_MS),
+    )?;
+    process.exp_string("main.sw' already exists and was left unchanged.")?;
+    process.exp_eof()?;
+
+    // The package name is inferred from the directory name and the existing files are kept.
+    let manifest = std::fs::read_to_string(dir.join("Forc.toml")).unwrap();
+    assert!(manifest.contains("name = \"my_project\""));
+    assert_eq!(
+        std::fs::read_to_string(dir.join("README.md")).unwrap(),
+        "# My project\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.join("src").join("main.sw")).unwrap(),
+        "script;\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.join(".gitignore")).unwrap(),
+        "target\nout\n"
+    );
+
+    // A directory that already contains a manifest can't be initialized again.
+    let mut process = spawn(
+        &format!("cargo run --bin forc -- init {}", dir.to_string_lossy()),
+        Some(TIMEOUT_MS),
+    )?;
+    process.exp_string("already includes a Forc.toml file.")?;
+    process.exp_eof()?;
+    assert_eq!(
+        std::fs::read_to_string(dir.join("Forc.toml")).unwrap(),
+        manifest
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_forc_build_emit() -> Result<(), rexpect::error::Error> {
+    let project_dir = test_fixtures_path().join("bytecode_script");
+    let output_dir = std::env::temp_dir().join("forc_build_emit");
+    let _ = std::fs::remove_dir_all(&output_dir);
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- build --emit ir,asm --path {} --output-directory {}",
+            project_dir.to_string_lossy(),
+            output_dir.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+    process.exp_string("Finished")?;
+    process.exp_eof()?;
+
+    // Only the requested outputs are written.
+    let ir = std::fs::read_to_string(output_dir.join("bytecode_script.ir")).unwrap();
+    assert!(ir.starts_with("script {"));
+    let asm = std::fs::read_to_string(output_dir.join("bytecode_script.asm")).unwrap();
+    assert!(asm.contains(".program:"));
+    assert!(!output_dir.join("bytecode_script.bin").exists());
+    assert!(!output_dir.join("bytecode_script-abi.json").exists());
+    Ok(())
+}
+
+#[test]
+fn test_forc_build_emit_unknown_kind() -> Result<(), rexpect::error::Error> {
+    let project_dir = test_fixtures_path().join("bytecode_script");
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- build --emit ir,bin --path {}",
+            project_dir.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+    process.exp_string(
+        "unknown emit kind `bin`, expected one of: ast, typed-ast, ir, asm, bytecode, abi, abi-spans",
+    )?;
+    process.exp_eof()?;
+    Ok(())
+}
+
+#[test]
+fn test_forc_build_emit_abi_spans() -> Result<(), rexpect::error::Error> {
+    let project_dir = test_fixtures_path().join("abi_spans_contract");
+    let output_dir = std::env::temp_dir().join("forc_build_emit_abi_spans");
+    let _ = std::fs::remove_dir_all(&output_dir);
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- build --emit abi,abi-spans --path {} --output-directory {}",
+            project_dir.to_string_lossy(),
+            output_dir.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+    process.exp_string("Finished")?;
+    process.exp_eof()?;
+
+    let source = std::fs::read_to_string(project_dir.join("src").join("main.sw")).unwrap();
+    let source_lines: Vec<_> = source.lines().collect();
+    let spans: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(output_dir.join("abi_spans_contract-abi-spans.json")).unwrap(),
+    )
+    .unwrap();
+    let abi: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(output_dir.join("abi_spans_contract-abi.json")).unwrap(),
+    )
+    .unwrap();
+
+    // Returns the source line at which the span of the given entry starts.
+    let start_line = |entry: &serde_json::Value| {
+        let span = &entry["span"];
+        assert!(span["path"].as_str().unwrap().ends_with("main.sw"));
+        source_lines[span["start"]["line"].as_u64().unwrap() as usize - 1]
+    };
+
+    let functions = spans["functions"].as_array().unwrap();
+    assert_eq!(functions.len(), 2);
+    for function in functions {
+        let name = function["name"].as_str().unwrap();
+        assert!(start_line(function)
+            .trim_start()
+            .starts_with(&format!("fn {name}(")));
+    }
+
+    // Every struct and enum of the ABI has a span pointing at its declaration.
+    let metadata_types = spans["metadataTypes"].as_array().unwrap();
+    assert_eq!(metadata_types.len(), 2);
+    for metadata_type in metadata_types {
+        let type_field = metadata_type["type"].as_str().unwrap();
+        assert!(start_line(metadata_type).starts_with(&format!("{type_field} {{")));
+        assert!(abi["metadataTypes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|decl| decl["type"] == metadata_type["type"]
+                && decl["metadataTypeId"] == metadata_type["metadataTypeId"]));
+    }
+
+    // The ABI itself does not contain any spans.
+    assert!(abi["functions"][0].get("span").is_none());
+    Ok(())
+}
+
+#[test]
+fn test_forc_build_overflow() -> Result<(), rexpect::error::Error> {
+    let project_dir = test_fixtures_path().join("bytecode_script");
+
+    // Returns the final ASM of the script built with the given `--overflow` option.
+    let build_asm = |overflow: Option<&str>| -> Result<String, rexpect::error::Error> {
+        let output_dir = std::env::temp_dir().join(format!(
+            "forc_build_overflow_{}",
+            overflow.unwrap_or("default")
+        ));
+        let _ = std::fs::remove_dir_all(&output_dir);
+        let overflow = overflow
+            .map(|overflow| format!(" --overflow {overflow}"))
+            .unwrap_or_default();
+        let mut process = spawn(
+            &format!(
+                "cargo run --bin forc -- build --emit asm --path {} --output-directory {}{overflow}",
+                project_dir.to_string_lossy(),
+                output_dir.to_string_lossy()
+            ),
+            Some(TIMEOUT_MS),
+        )?;
+        process.exp_string("Finished")?;
+        process.exp_eof()?;
+        Ok(std::fs::read_to_string(output_dir.join("bytecode_script.asm")).unwrap())
+    };
+
+    // Overflowing arithmetic traps by default, which is the default state of the VM flags.
+    assert!(!build_asm(None)?.contains("flag "));
+    assert!(!build_asm(Some("trap"))?.contains("flag "));
+
+    // Wrapping arithmetic sets the flags on entry.
+    assert!(build_asm(Some("wrap"))?.contains("flag "));
+    Ok(())
+}
+
+#[test]
+fn test_forc_build_const_eval_limit() -> Result<(), rexpect::error::Error> {
+    let project_dir = test_fixtures_path().join("const_eval_script");
+    let build = |const_eval_limit: usize| {
+        spawn(
+            &format!(
+                "cargo run --bin forc -- build --path {} --const-eval-limit {const_eval_limit}",
+                project_dir.to_string_lossy()
+            ),
+            Some(TIMEOUT_MS),
+        )
+    };
+
+    let mut process = build(6)?;
+    process.exp_string("Finished")?;
+    process.exp_eof()?;
+
+    let mut process = build(5)?;
+    process.exp_string("Constant evaluation exceeded the limit of 5 steps.")?;
+    process.exp_eof()?;
+    Ok(())
+}
@@ -0,0 +1,26 @@
+contract;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+enum Shape {
+    Dot: Point,
+    Line: (Point, Point),
+}
+
+abi Geometry {
+    fn origin() -> Point;
+    fn shape(point: Point) -> Shape;
+}
+
+impl Geometry for Contract {
+    fn origin() -> Point {
+        Point { x: 0, y: 0 }
+    }
+
+    fn shape(point: Point) -> Shape {
+        Shape::Dot(point)
+    }
+}
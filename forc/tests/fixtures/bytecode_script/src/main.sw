@@ -0,0 +1,6 @@
+script;
+
+fn main() -> u64 {
+    let answer = 42;
+    answer
+}
@@ -0,0 +1,16 @@
+script;
+
+fn factorial(n: u64) -> u64 {
+    if __eq(n, 0) {
+        1
+    } else {
+        __mul(n, factorial(__sub(n, 1)))
+    }
+}
+
+// Takes 6 function calls.
+const FACTORIAL: u64 = factorial(5);
+
+fn main() -> u64 {
+    FACTORIAL
+}
@@ -12,6 +12,10 @@ pub struct Annotated<T> {
 //    #[attribute()]
 //    #[attribute(value)]
 //    #[attribute(value0, value1, value2)]
+//
+// Arguments can themselves have a parenthesized list of arguments:
+//
+//    #[attribute(value0(value1, value2))]
 
 #[derive(Clone, Debug, Serialize)]
 pub struct AttributeDecl {
@@ -56,12 +60,15 @@ pub enum AttributeHashKind {
 pub struct AttributeArg {
     pub name: Ident,
     pub value: Option<Literal>,
+    pub args: Option<Parens<Punctuated<AttributeArg, CommaToken>>>,
 }
 
 impl Spanned for AttributeArg {
     fn span(&self) -> Span {
         if let Some(value) = &self.value {
             Span::join(self.name.span(), &value.span())
+        } else if let Some(args) = &self.args {
+            Span::join(self.name.span(), &args.span())
         } else {
             self.name.span()
         }
@@ -23,6 +23,7 @@ pub enum Expr {
     },
     Tuple(Parens<ExprTupleDescriptor>),
     Parens(Parens<Box<Expr>>),
+    TypeAscription(Parens<ExprTypeAscription>),
     Block(Braces<CodeBlockContents>),
     Array(SquareBrackets<ExprArrayDescriptor>),
     Asm(AsmBlock),
@@ -36,11 +37,26 @@ pub enum Expr {
         value: Box<Expr>,
         branches: Braces<Vec<MatchBranch>>,
     },
+    /// `matches!(value, pattern)` or `matches!(value, pattern if guard)`.
+    ///
+    /// Evaluates to `true` if `value` matches `pattern` and the optional `guard` holds.
+    Matches {
+        matches_ident: Ident,
+        bang_token: BangToken,
+        args: Parens<MatchesArgs>,
+    },
     While {
         while_token: WhileToken,
         condition: Box<Expr>,
         block: Braces<CodeBlockContents>,
     },
+    /// `loop { ... }`.
+    ///
+    /// Evaluates to the value given to the `break` that exits the loop.
+    Loop {
+        loop_token: LoopToken,
+        block: Braces<CodeBlockContents>,
+    },
     For {
         for_token: ForToken,
         in_token: InToken,
@@ -74,6 +90,14 @@ pub enum Expr {
         field: BigUint,
         field_span: Span,
     },
+    /// `expr?`
+    ///
+    /// Unwraps an `Option` or `Result`, returning early from the enclosing function
+    /// on `None` or `Err`.
+    Try {
+        expr: Box<Expr>,
+        question_mark_token: QuestionMarkToken,
+    },
     Ref {
         ampersand_token: AmpersandToken,
         mut_token: Option<MutToken>,
@@ -189,6 +213,7 @@ pub enum Expr {
     },
     Break {
         break_token: BreakToken,
+        expr_opt: Option<Box<Expr>>,
     },
     Continue {
         continue_token: ContinueToken,
@@ -209,6 +234,7 @@ impl Spanned for Expr {
             Expr::Struct { path, fields } => Span::join(path.span(), &fields.span()),
             Expr::Tuple(tuple_expr) => tuple_expr.span(),
             Expr::Parens(parens) => parens.span(),
+            Expr::TypeAscription(parens) => parens.span(),
             Expr::Block(block_expr) => block_expr.span(),
             Expr::Array(array_expr) => array_expr.span(),
             Expr::Asm(asm_block) => asm_block.span(),
@@ -229,9 +255,15 @@ impl Spanned for Expr {
                 branches,
                 ..
             } => Span::join(match_token.span(), &branches.span()),
+            Expr::Matches {
+                matches_ident,
+                args,
+                ..
+            } => Span::join(matches_ident.span(), &args.span()),
             Expr::While {
                 while_token, block, ..
             } => Span::join(while_token.span(), &block.span()),
+            Expr::Loop { loop_token, block } => Span::join(loop_token.span(), &block.span()),
             Expr::For {
                 for_token, block, ..
             } => Span::join(for_token.span(), &block.span()),
@@ -242,6 +274,10 @@ impl Spanned for Expr {
             Expr::TupleFieldProjection {
                 target, field_span, ..
             } => Span::join(target.span(), field_span),
+            Expr::Try {
+                expr,
+                question_mark_token,
+            } => Span::join(expr.span(), &question_mark_token.span()),
             Expr::Ref {
                 ampersand_token,
                 expr,
@@ -271,7 +307,13 @@ impl Spanned for Expr {
             Expr::Reassignment {
                 assignable, expr, ..
             } => Span::join(assignable.span(), &expr.span()),
-            Expr::Break { break_token } => break_token.span(),
+            Expr::Break {
+                break_token,
+                expr_opt,
+            } => match expr_opt {
+                Some(expr) => Span::join(break_token.span(), &expr.span()),
+                None => break_token.span(),
+            },
             Expr::Continue { continue_token } => continue_token.span(),
         }
     }
@@ -315,6 +357,14 @@ pub struct AbiCastArgs {
     pub address: Box<Expr>,
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct MatchesArgs {
+    pub value: Box<Expr>,
+    pub comma_token: CommaToken,
+    pub pattern: Pattern,
+    pub guard_opt: Option<(IfToken, Box<Expr>)>,
+}
+
 #[allow(clippy::type_complexity)]
 #[derive(Clone, Debug, Serialize)]
 pub struct IfExpr {
@@ -368,6 +418,22 @@ pub enum ExprTupleDescriptor {
     },
 }
 
+/// An expression with an explicitly given type, e.g. `(x : u8)`.
+///
+/// A type ascription is only allowed within parentheses.
+#[derive(Clone, Debug, Serialize)]
+pub struct ExprTypeAscription {
+    pub expr: Box<Expr>,
+    pub colon_token: ColonToken,
+    pub ty: Ty,
+}
+
+impl Spanned for ExprTypeAscription {
+    fn span(&self) -> Span {
+        Span::join(self.expr.span(), &self.ty.span())
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub enum ExprArrayDescriptor {
     Sequence(Punctuated<Expr, CommaToken>),
@@ -515,14 +581,17 @@ impl Expr {
             | Expr::If(..)
             | Expr::Match { .. }
             | Expr::While { .. }
+            | Expr::Loop { .. }
             | Expr::For { .. } => true,
             Expr::Error(..)
             | Expr::Path(..)
             | Expr::Literal(..)
             | Expr::AbiCast { .. }
+            | Expr::Matches { .. }
             | Expr::Struct { .. }
             | Expr::Tuple(..)
             | Expr::Parens(..)
+            | Expr::TypeAscription(..)
             | Expr::Array(..)
             | Expr::Return { .. }
             | Expr::FuncApp { .. }
@@ -530,6 +599,7 @@ impl Expr {
             | Expr::MethodCall { .. }
             | Expr::FieldProjection { .. }
             | Expr::TupleFieldProjection { .. }
+            | Expr::Try { .. }
             | Expr::Ref { .. }
             | Expr::Deref { .. }
             | Expr::Not { .. }
@@ -568,19 +638,23 @@ impl Expr {
             Expr::Struct { .. } => "struct instantiation",
             Expr::Tuple(_) => "tuple",
             Expr::Parens(_) => "parentheses", // Note the plural!
+            Expr::TypeAscription(_) => "type ascription",
             Expr::Block(_) => "block",
             Expr::Array(_) => "array",
             Expr::Asm(_) => "assembly block",
             Expr::Return { .. } => "return",
             Expr::If(_) => "if expression",
             Expr::Match { .. } => "match expression",
+            Expr::Matches { .. } => "matches expression",
             Expr::While { .. } => "while loop",
+            Expr::Loop { .. } => "loop",
             Expr::For { .. } => "for loop",
             Expr::FuncApp { .. } => "function call",
             Expr::Index { .. } => "array element access",
             Expr::MethodCall { .. } => "method call",
             Expr::FieldProjection { .. } => "struct field access",
             Expr::TupleFieldProjection { .. } => "tuple element access",
+            Expr::Try { .. } => "`?` operator",
             Expr::Ref { .. } => "referencing",
             Expr::Deref { .. } => "dereferencing",
             Expr::Not { .. } => "negation",
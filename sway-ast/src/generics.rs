@@ -2,7 +2,24 @@ use crate::priv_prelude::*;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct GenericParams {
-    pub parameters: AngleBrackets<Punctuated<Ident, CommaToken>>,
+    pub parameters: AngleBrackets<Punctuated<GenericParam, CommaToken>>,
+}
+
+/// A single generic type parameter, optionally followed by inline trait bounds,
+/// e.g. `T` or `T: Eq + Ord`.
+#[derive(Clone, Debug, Serialize)]
+pub struct GenericParam {
+    pub name: Ident,
+    pub bounds_opt: Option<(ColonToken, Traits)>,
+}
+
+impl Spanned for GenericParam {
+    fn span(&self) -> Span {
+        match &self.bounds_opt {
+            Some((_colon_token, bounds)) => Span::join(self.name.span(), &bounds.span()),
+            None => self.name.span(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -6,6 +6,7 @@ pub enum Intrinsic {
     SizeOfType,
     SizeOfVal,
     SizeOfStr,
+    AlignOfType,
     IsStrArray,
     AssertIsStrArray,
     ToStrArray,
@@ -43,6 +44,13 @@ pub enum Intrinsic {
     EncodeBufferAsRawSlice, // let slice: raw_slice = __encode_buffer_as_raw_slice(buffer)
     Slice, // let ref_to_slice = __slice::<T: array or ref_to_slice>(item: T, inclusive_start_index, exclusive_end_index)
     ElemAt, // let elem: &T = __elem_at::<T: array or ref_to_slice>(item: T, index)
+    TxInputCount, // let count: u64 = __tx_input_count()
+    TxInputType, // let ty: u8 = __tx_input_type(index)
+    TxInputAmount, // let amount: u64 = __tx_input_amount(index)
+    TxOutputCount, // let count: u64 = __tx_output_count()
+    TxOutputType, // let ty: u8 = __tx_output_type(index)
+    TxOutputAmount, // let amount: u64 = __tx_output_amount(index)
+    Fmt,   // let data: (str, T1, ..., Tn) = __fmt("x={}, y={}", x, y)
 }
 
 impl fmt::Display for Intrinsic {
@@ -53,6 +61,7 @@ impl fmt::Display for Intrinsic {
             Intrinsic::SizeOfType => "size_of",
             Intrinsic::SizeOfVal => "size_of_val",
             Intrinsic::SizeOfStr => "size_of_str_array",
+            Intrinsic::AlignOfType => "align_of",
             Intrinsic::AssertIsStrArray => "assert_is_str_array",
             Intrinsic::ToStrArray => "to_str_array",
             Intrinsic::Eq => "eq",
@@ -89,6 +98,13 @@ impl fmt::Display for Intrinsic {
             Intrinsic::EncodeBufferAsRawSlice => "encode_buffer_as_raw_slice",
             Intrinsic::Slice => "slice",
             Intrinsic::ElemAt => "elem_at",
+            Intrinsic::TxInputCount => "tx_input_count",
+            Intrinsic::TxInputType => "tx_input_type",
+            Intrinsic::TxInputAmount => "tx_input_amount",
+            Intrinsic::TxOutputCount => "tx_output_count",
+            Intrinsic::TxOutputType => "tx_output_type",
+            Intrinsic::TxOutputAmount => "tx_output_amount",
+            Intrinsic::Fmt => "fmt",
         };
         write!(f, "{s}")
     }
@@ -103,6 +119,7 @@ impl Intrinsic {
             "__size_of" => SizeOfType,
             "__size_of_val" => SizeOfVal,
             "__size_of_str_array" => SizeOfStr,
+            "__align_of" => AlignOfType,
             "__assert_is_str_array" => AssertIsStrArray,
             "__to_str_array" => ToStrArray,
             "__eq" => Eq,
@@ -139,6 +156,13 @@ impl Intrinsic {
             "__encode_buffer_as_raw_slice" => EncodeBufferAsRawSlice,
             "__slice" => Slice,
             "__elem_at" => ElemAt,
+            "__tx_input_count" => TxInputCount,
+            "__tx_input_type" => TxInputType,
+            "__tx_input_amount" => TxInputAmount,
+            "__tx_output_count" => TxOutputCount,
+            "__tx_output_type" => TxOutputType,
+            "__tx_output_amount" => TxOutputAmount,
+            "__fmt" => Fmt,
             _ => return None,
         })
     }
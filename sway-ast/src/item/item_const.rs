@@ -2,7 +2,7 @@ use crate::priv_prelude::*;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ItemConst {
-    pub visibility: Option<PubToken>,
+    pub visibility: Option<VisibilityQualifier>,
     pub const_token: ConstToken,
     pub name: Ident,
     pub ty_opt: Option<(ColonToken, Ty)>,
@@ -14,7 +14,7 @@ pub struct ItemConst {
 impl Spanned for ItemConst {
     fn span(&self) -> Span {
         let start = match &self.visibility {
-            Some(pub_token) => pub_token.span(),
+            Some(visibility) => visibility.span(),
             None => self.const_token.span(),
         };
         let end = match &self.expr_opt {
@@ -2,7 +2,7 @@ use crate::priv_prelude::*;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ItemEnum {
-    pub visibility: Option<PubToken>,
+    pub visibility: Option<VisibilityQualifier>,
     pub enum_token: EnumToken,
     pub name: Ident,
     pub generics: Option<GenericParams>,
@@ -13,7 +13,7 @@ pub struct ItemEnum {
 impl Spanned for ItemEnum {
     fn span(&self) -> Span {
         let start = match &self.visibility {
-            Some(pub_token) => pub_token.span(),
+            Some(visibility) => visibility.span(),
             None => self.enum_token.span(),
         };
         let end = self.fields.span();
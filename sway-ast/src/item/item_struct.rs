@@ -2,7 +2,7 @@ use crate::priv_prelude::*;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ItemStruct {
-    pub visibility: Option<PubToken>,
+    pub visibility: Option<VisibilityQualifier>,
     pub struct_token: StructToken,
     pub name: Ident,
     pub generics: Option<GenericParams>,
@@ -13,7 +13,7 @@ pub struct ItemStruct {
 impl Spanned for ItemStruct {
     fn span(&self) -> Span {
         let start = match &self.visibility {
-            Some(pub_token) => pub_token.span(),
+            Some(visibility) => visibility.span(),
             None => self.struct_token.span(),
         };
         let end = self.fields.span();
@@ -13,7 +13,7 @@ pub enum ItemTraitItem {
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ItemTrait {
-    pub visibility: Option<PubToken>,
+    pub visibility: Option<VisibilityQualifier>,
     pub trait_token: TraitToken,
     pub name: Ident,
     pub generics: Option<GenericParams>,
@@ -26,7 +26,7 @@ pub struct ItemTrait {
 impl Spanned for ItemTrait {
     fn span(&self) -> Span {
         let start = match &self.visibility {
-            Some(pub_token) => pub_token.span(),
+            Some(visibility) => visibility.span(),
             None => self.trait_token.span(),
         };
         let end = match &self.trait_defs_opt {
@@ -2,7 +2,7 @@ use crate::priv_prelude::*;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ItemTypeAlias {
-    pub visibility: Option<PubToken>,
+    pub visibility: Option<VisibilityQualifier>,
     pub name: Ident,
     pub type_token: TypeToken,
     pub eq_token: EqToken,
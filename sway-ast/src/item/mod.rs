@@ -70,6 +70,7 @@ pub struct TypeField {
     pub name: Ident,
     pub colon_token: ColonToken,
     pub ty: Ty,
+    pub default_opt: Option<(EqToken, Expr)>,
 }
 
 impl Spanned for TypeField {
@@ -78,7 +79,10 @@ impl Spanned for TypeField {
             Some(pub_token) => pub_token.span(),
             None => self.name.span(),
         };
-        let end = self.ty.span();
+        let end = match &self.default_opt {
+            Some((_eq_token, default)) => default.span(),
+            None => self.ty.span(),
+        };
         Span::join(start, &end)
     }
 }
@@ -109,7 +113,7 @@ impl Spanned for FnArg {
 
 #[derive(Clone, Debug, Serialize)]
 pub struct FnSignature {
-    pub visibility: Option<PubToken>,
+    pub visibility: Option<VisibilityQualifier>,
     pub fn_token: FnToken,
     pub name: Ident,
     pub generics: Option<GenericParams>,
@@ -121,7 +125,7 @@ pub struct FnSignature {
 impl Spanned for FnSignature {
     fn span(&self) -> Span {
         let start = match &self.visibility {
-            Some(pub_token) => pub_token.span(),
+            Some(visibility) => visibility.span(),
             None => self.fn_token.span(),
         };
         let end = match &self.where_clause_opt {
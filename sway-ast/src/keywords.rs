@@ -74,6 +74,7 @@ define_keyword!(MatchToken, "match");
 define_keyword!(MutToken, "mut");
 define_keyword!(LetToken, "let");
 define_keyword!(WhileToken, "while");
+define_keyword!(LoopToken, "loop"); // Not reserved, so that `loop` can still be used as a name.
 define_keyword!(WhereToken, "where");
 define_keyword!(RefToken, "ref");
 define_keyword!(TrueToken, "true");
@@ -232,3 +233,5 @@ define_token!(DoublePipeToken, "`||`", [Pipe, Pipe], [Pipe]);
 define_token!(UnderscoreToken, "`_`", [Underscore], [Underscore]);
 define_token!(HashToken, "`#`", [Sharp], []);
 define_token!(HashBangToken, "`#!`", [Sharp, Bang], []);
+define_token!(QuestionMarkToken, "`?`", [QuestionMark], []);
+define_token!(AtToken, "`@`", [At], []);
@@ -16,6 +16,7 @@ pub mod statement;
 pub mod submodule;
 pub mod token;
 pub mod ty;
+pub mod visibility;
 pub mod where_clause;
 
 pub use crate::{
@@ -26,9 +27,10 @@ pub use crate::{
         asm::{AsmBlock, AsmRegisterDeclaration},
         op_code::Instruction,
         AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
+        ExprTupleDescriptor, ExprTypeAscription, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
+        MatchesArgs,
     },
-    generics::{GenericArgs, GenericParams},
+    generics::{GenericArgs, GenericParam, GenericParams},
     intrinsics::*,
     item::{
         item_abi::ItemAbi,
@@ -48,10 +50,11 @@ pub use crate::{
     literal::{LitInt, LitIntType, Literal},
     module::{Module, ModuleKind},
     path::{PathExpr, PathExprSegment, PathType, PathTypeSegment, QualifiedPathRoot},
-    pattern::{Pattern, PatternStructField},
+    pattern::{Pattern, PatternSliceElement, PatternStructField},
     punctuated::Punctuated,
     statement::{Statement, StatementLet},
     submodule::Submodule,
     ty::Ty,
+    visibility::{VisibilityQualifier, VisibilityRestriction},
     where_clause::{WhereBound, WhereClause},
 };
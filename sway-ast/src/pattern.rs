@@ -30,6 +30,7 @@ pub enum Pattern {
         fields: Braces<Punctuated<PatternStructField, CommaToken>>,
     },
     Tuple(Parens<Punctuated<Pattern, CommaToken>>),
+    Slice(SquareBrackets<Punctuated<PatternSliceElement, CommaToken>>),
     // to handle parser recovery: Error represents an incomplete Constructor
     Error(Box<[Span]>, #[serde(skip_serializing)] ErrorEmitted),
 }
@@ -62,6 +63,7 @@ impl Spanned for Pattern {
             Pattern::Constructor { path, args } => Span::join(path.span(), &args.span()),
             Pattern::Struct { path, fields } => Span::join(path.span(), &fields.span()),
             Pattern::Tuple(pat_tuple) => pat_tuple.span(),
+            Pattern::Slice(pat_slice) => pat_slice.span(),
             Pattern::Error(spans, _) => spans
                 .iter()
                 .cloned()
@@ -97,3 +99,26 @@ impl Spanned for PatternStructField {
         }
     }
 }
+
+#[derive(Clone, Debug, Serialize)]
+pub enum PatternSliceElement {
+    /// The rest pattern `..`, optionally bound to a name as in `name @ ..`.
+    Rest {
+        name_opt: Option<(Ident, AtToken)>,
+        token: DoubleDotToken,
+    },
+    Pattern(Box<Pattern>),
+}
+
+impl Spanned for PatternSliceElement {
+    fn span(&self) -> Span {
+        use PatternSliceElement::*;
+        match &self {
+            Rest { name_opt, token } => match name_opt {
+                Some((name, _at_token)) => Span::join(name.span(), &token.span()),
+                None => token.span(),
+            },
+            Pattern(pattern) => pattern.span(),
+        }
+    }
+}
@@ -31,6 +31,7 @@ pub use {
         statement::Statement,
         submodule::Submodule,
         ty::Ty,
+        visibility::VisibilityQualifier,
         where_clause::WhereClause,
     },
     extension_trait::extension_trait,
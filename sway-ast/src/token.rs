@@ -45,6 +45,7 @@ pub enum CommentKind {
     /// pub fn main() -> bool {
     ///
     ///     // Newlined comment
+    ///     /* Newlined block comment */
     ///     true
     /// }
     /// ```
@@ -254,6 +255,8 @@ impl CharExt for char {
             '|' => Some(PunctKind::Pipe),
             '_' => Some(PunctKind::Underscore),
             '#' => Some(PunctKind::Sharp),
+            '?' => Some(PunctKind::QuestionMark),
+            '@' => Some(PunctKind::At),
             _ => None,
         }
     }
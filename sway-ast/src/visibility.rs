@@ -0,0 +1,46 @@
+use crate::priv_prelude::*;
+
+/// The `pub` qualifier of an item, optionally restricting the visibility
+/// of the item to a part of the package, e.g. `pub(crate)` or `pub(in a::b)`.
+#[derive(Clone, Debug, Serialize)]
+pub struct VisibilityQualifier {
+    pub pub_token: PubToken,
+    pub restriction_opt: Option<Parens<VisibilityRestriction>>,
+}
+
+impl Spanned for VisibilityQualifier {
+    fn span(&self) -> Span {
+        match &self.restriction_opt {
+            Some(restriction) => Span::join(self.pub_token.span(), &restriction.span()),
+            None => self.pub_token.span(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub enum VisibilityRestriction {
+    /// `pub(crate)`, the item is visible everywhere within the current package.
+    Crate { crate_ident: Ident },
+    /// `pub(in a::b)`, the item is visible within the module `a::b` and its submodules.
+    In {
+        in_token: InToken,
+        path: Punctuated<Ident, DoubleColonToken>,
+    },
+}
+
+impl Spanned for VisibilityRestriction {
+    fn span(&self) -> Span {
+        match self {
+            VisibilityRestriction::Crate { crate_ident } => crate_ident.span(),
+            VisibilityRestriction::In { in_token, path } => match path.final_value_opt.as_ref() {
+                Some(last) => Span::join(in_token.span(), &last.span()),
+                None => match path.value_separator_pairs.last() {
+                    Some((_, double_colon_token)) => {
+                        Span::join(in_token.span(), &double_colon_token.span())
+                    }
+                    None => in_token.span(),
+                },
+            },
+        }
+    }
+}
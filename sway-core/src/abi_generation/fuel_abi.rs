@@ -1,10 +1,14 @@
 use fuel_abi_types::abi::program::{
     self as program_abi, ConcreteTypeId, MetadataTypeId, TypeConcreteDeclaration,
 };
+use serde::Serialize;
 use sha2::{Digest, Sha256};
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
 use sway_error::handler::{ErrorEmitted, Handler};
-use sway_types::Span;
+use sway_types::{LineCol, Span};
 
 use crate::{
     language::ty::{TyFunctionDecl, TyProgram, TyProgramKind},
@@ -18,9 +22,29 @@ pub struct AbiContext<'a> {
     pub program: &'a TyProgram,
     pub abi_with_callpaths: bool,
     pub type_ids_to_full_type_str: HashMap<String, String>,
+    /// The spans of the struct and enum declarations of the metadata types, by their type field.
+    pub type_field_spans: HashMap<String, Span>,
 }
 
 impl<'a> AbiContext<'a> {
+    /// Records the span of the declaration of `resolved_type_id` for the metadata type with the
+    /// given `type_field`, if the type is a struct or an enum.
+    fn record_type_field_span(
+        &mut self,
+        engines: &Engines,
+        type_field: &str,
+        resolved_type_id: TypeId,
+    ) {
+        let span = match &*engines.te().get(resolved_type_id) {
+            TypeInfo::Struct(decl_id) => engines.de().get_struct(decl_id).span.clone(),
+            TypeInfo::Enum(decl_id) => engines.de().get_enum(decl_id).span.clone(),
+            _ => return,
+        };
+        self.type_field_spans
+            .entry(type_field.to_string())
+            .or_insert(span);
+    }
+
     fn to_str_context(&self, engines: &Engines, abi_full: bool) -> AbiStrContext {
         AbiStrContext {
             program_name: self
@@ -206,6 +230,106 @@ pub fn generate_program_abi(
     Ok(program_abi)
 }
 
+/// The source locations of the functions and the struct and enum types of a JSON ABI.
+///
+/// The spans are emitted in a companion file, so that the JSON ABI itself stays unchanged.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgramAbiSpans {
+    pub functions: Vec<AbiFunctionSpan>,
+    pub metadata_types: Vec<AbiTypeSpan>,
+}
+
+/// The source location of the declaration of an ABI function.
+#[derive(Clone, Debug, Serialize)]
+pub struct AbiFunctionSpan {
+    /// The name of the function, as in the `functions` of the JSON ABI.
+    pub name: String,
+    pub span: AbiSpan,
+}
+
+/// The source location of the declaration of a struct or enum metadata type.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AbiTypeSpan {
+    /// The ID of the type, as in the `metadataTypes` of the JSON ABI.
+    pub metadata_type_id: usize,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub span: AbiSpan,
+}
+
+/// A range within a source file, with one-based lines and columns.
+#[derive(Clone, Debug, Serialize)]
+pub struct AbiSpan {
+    pub path: PathBuf,
+    pub start: LineCol,
+    pub end: LineCol,
+}
+
+impl AbiSpan {
+    fn new(engines: &Engines, span: &Span) -> Option<Self> {
+        let source_id = span.source_id()?;
+        Some(AbiSpan {
+            path: engines.se().get_path(source_id),
+            start: span.start_pos().line_col(),
+            end: span.end_pos().line_col(),
+        })
+    }
+}
+
+/// Collects the spans of the declarations of the functions and the struct and enum types of
+/// `program_abi`, which must have been generated with `ctx`.
+///
+/// The span of a contract ABI function is the span of its implementation for the contract.
+pub fn generate_program_abi_spans(
+    ctx: &AbiContext,
+    engines: &Engines,
+    program_abi: &program_abi::ProgramABI,
+) -> ProgramAbiSpans {
+    let decl_engine = engines.de();
+    let fn_decls = match &ctx.program.kind {
+        TyProgramKind::Contract { abi_entries, .. } => abi_entries
+            .iter()
+            .map(|decl_id| decl_engine.get_function(decl_id))
+            .collect(),
+        TyProgramKind::Script { main_function, .. }
+        | TyProgramKind::Predicate { main_function, .. } => {
+            vec![decl_engine.get_function(main_function)]
+        }
+        TyProgramKind::Library { .. } => vec![],
+    };
+    let functions = program_abi
+        .functions
+        .iter()
+        .filter_map(|function| {
+            let fn_decl = fn_decls
+                .iter()
+                .find(|fn_decl| fn_decl.name.as_str() == function.name)?;
+            Some(AbiFunctionSpan {
+                name: function.name.clone(),
+                span: AbiSpan::new(engines, &fn_decl.span)?,
+            })
+        })
+        .collect();
+    let metadata_types = program_abi
+        .metadata_types
+        .iter()
+        .filter_map(|decl| {
+            let span = ctx.type_field_spans.get(&decl.type_field)?;
+            Some(AbiTypeSpan {
+                metadata_type_id: decl.metadata_type_id.0,
+                type_field: decl.type_field.clone(),
+                span: AbiSpan::new(engines, span)?,
+            })
+        })
+        .collect();
+    ProgramAbiSpans {
+        functions,
+        metadata_types,
+    }
+}
+
 /// Standardize the JSON ABI data structure by eliminating duplicate types. This is an iterative
 /// process because every time two types are merged, new opportunities for more merging arise.
 fn standardize_json_abi_types(json_abi_program: &mut program_abi::ProgramABI) {
@@ -379,13 +503,15 @@ fn generate_concrete_type_declaration(
     resolved_type_id: TypeId,
 ) -> Result<ConcreteTypeId, ErrorEmitted> {
     let mut new_metadata_types_to_add = Vec::<program_abi::TypeMetadataDeclaration>::new();
+    let type_field = type_id.get_abi_type_str(
+        &ctx.to_str_context(engines, false),
+        engines,
+        resolved_type_id,
+    );
+    ctx.record_type_field_span(engines, &type_field, resolved_type_id);
     let type_metadata_decl = program_abi::TypeMetadataDeclaration {
         metadata_type_id: MetadataTypeId(type_id.index()),
-        type_field: type_id.get_abi_type_str(
-            &ctx.to_str_context(engines, false),
-            engines,
-            resolved_type_id,
-        ),
+        type_field,
         components: type_id.get_abi_type_components(
             handler,
             ctx,
@@ -473,13 +599,15 @@ fn generate_type_metadata_declaration(
         resolved_type_id,
         &mut new_metadata_types_to_add,
     )?;
+    let type_field = type_id.get_abi_type_str(
+        &ctx.to_str_context(engines, false),
+        engines,
+        resolved_type_id,
+    );
+    ctx.record_type_field_span(engines, &type_field, resolved_type_id);
     let type_metadata_decl = program_abi::TypeMetadataDeclaration {
         metadata_type_id: MetadataTypeId(type_id.index()),
-        type_field: type_id.get_abi_type_str(
-            &ctx.to_str_context(engines, false),
-            engines,
-            resolved_type_id,
-        ),
+        type_field,
         components,
         type_parameters,
     };
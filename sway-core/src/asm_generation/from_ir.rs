@@ -9,12 +9,14 @@ use super::{
     },
     MidenVMAsmBuilder,
 };
-use crate::{asm_generation::ProgramKind, BuildConfig, BuildTarget};
+use crate::{asm_generation::ProgramKind, BuildConfig, BuildTarget, OverflowBehavior};
 
 use crate::asm_lang::VirtualImmediate18;
 
+use std::collections::HashSet;
+
 use sway_error::handler::{ErrorEmitted, Handler};
-use sway_ir::{Context, Kind, Module};
+use sway_ir::{ConfigContent, Context, Function, InstOp, Kind, Module};
 
 pub fn compile_ir_context_to_finalized_asm(
     handler: &Handler,
@@ -41,6 +43,10 @@ pub fn compile_ir_context_to_finalized_asm(
         Some(cfg) => cfg.build_target,
         None => BuildTarget::default(),
     };
+    let overflow_behavior = match build_config {
+        Some(cfg) => cfg.overflow_behavior,
+        None => OverflowBehavior::default(),
+    };
 
     let finalized_asm = match build_target {
         BuildTarget::Fuel => compile(
@@ -48,7 +54,13 @@ pub fn compile_ir_context_to_finalized_asm(
             ir,
             module,
             build_config,
-            FuelAsmBuilder::new(kind, DataSection::default(), reg_seqr, ir),
+            FuelAsmBuilder::new(
+                kind,
+                DataSection::default(),
+                reg_seqr,
+                ir,
+                overflow_behavior,
+            ),
         ),
         BuildTarget::EVM => compile(
             handler,
@@ -80,10 +92,13 @@ fn compile(
 ) -> Result<FinalizedAsm, ErrorEmitted> {
     let mut fallback_fn = None;
 
+    // Functions which can't be reached from the entry points would only bloat the bytecode.
+    let functions = reachable_functions(context, module);
+
     // Pre-create labels for all functions before we generate other code, so we can call them
     // before compiling them if needed.
-    for func in module.function_iter(context) {
-        let (start, _) = builder.func_to_labels(&func);
+    for func in &functions {
+        let (start, _) = builder.func_to_labels(func);
         if func.is_fallback(context) {
             fallback_fn = Some(start);
         }
@@ -93,13 +108,58 @@ fn compile(
         builder.compile_configurable(config);
     }
 
-    for function in module.function_iter(context) {
+    for function in functions {
         builder.compile_function(handler, function)?;
     }
 
     builder.finalize(handler, build_config, fallback_fn)
 }
 
+/// Returns the functions of the `module` which are reachable from its entry points, in the order
+/// they appear in the module.
+///
+/// The roots are the entry functions, i.e. `main`, the contract methods and the `#[test]`
+/// functions, the contract fallback and the configurable decoders. Contract methods are called
+/// dynamically through their selectors, so every function with a selector is a root as well.
+/// Libraries have no entry points and keep all of their functions.
+fn reachable_functions(context: &Context, module: Module) -> Vec<Function> {
+    let is_library = module.get_kind(context) == Kind::Library;
+    let mut worklist = module
+        .function_iter(context)
+        .filter(|func| {
+            is_library
+                || func.is_entry(context)
+                || func.is_fallback(context)
+                || func.has_selector(context)
+        })
+        .collect::<Vec<_>>();
+    worklist.extend(
+        module
+            .iter_configs(context)
+            .filter_map(|config| match config {
+                ConfigContent::V0 { .. } => None,
+                ConfigContent::V1 { decode_fn, .. } => Some(*decode_fn),
+            }),
+    );
+
+    let mut reachable = HashSet::new();
+    while let Some(func) = worklist.pop() {
+        if reachable.insert(func) {
+            worklist.extend(func.instruction_iter(context).filter_map(|(_block, inst)| {
+                match &inst.get_instruction(context)?.op {
+                    InstOp::Call(callee, _args) => Some(*callee),
+                    _otherwise => None,
+                }
+            }));
+        }
+    }
+
+    module
+        .function_iter(context)
+        .filter(|func| reachable.contains(func))
+        .collect()
+}
+
 // -------------------------------------------------------------------------------------------------
 
 // NOTE: For stack storage we need to be aware:
@@ -117,3 +177,63 @@ pub enum StateAccessType {
     Read,
     Write,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sway_types::SourceEngine;
+
+    #[test]
+    fn unreachable_functions_are_not_compiled() {
+        let source_engine = SourceEngine::default();
+        let ir = sway_ir::parser::parse(
+            r#"
+script {
+    entry fn main() -> u64 {
+        entry():
+        v0 = call used()
+        ret u64 v0
+    }
+
+    fn used() -> u64 {
+        entry():
+        v0 = const u64 1
+        ret u64 v0
+    }
+
+    fn unused() -> u64 {
+        entry():
+        v0 = const u64 2
+        v1 = call only_called_by_unused(v0)
+        ret u64 v1
+    }
+
+    fn only_called_by_unused(x: u64) -> u64 {
+        entry(x: u64):
+        v0 = mul x, x
+        ret u64 v0
+    }
+}
+"#,
+            &source_engine,
+            sway_ir::ExperimentalFlags {
+                new_encoding: false,
+            },
+        )
+        .unwrap();
+
+        let module = ir.module_iter().next().unwrap();
+        let reachable = reachable_functions(&ir, module)
+            .iter()
+            .map(|func| func.get_name(&ir).to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(reachable, ["main", "used"]);
+
+        let handler = Handler::default();
+        let asm = compile_ir_context_to_finalized_asm(&handler, &ir, None)
+            .unwrap()
+            .to_string();
+        assert!(!asm.contains("unused"));
+        assert!(!asm.contains("mul"));
+    }
+}
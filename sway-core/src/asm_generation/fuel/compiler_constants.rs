@@ -36,5 +36,9 @@ pub(crate) const EIGHTEEN_BITS: u64 = 0b11_1111_1111_1111_1111;
 pub(crate) const TWELVE_BITS: u64 = 0b1111_1111_1111;
 pub(crate) const SIX_BITS: u64 = 0b11_1111;
 
+/// The bit of the `$flag` register which makes overflowing arithmetic wrap around instead of
+/// panicking.
+pub(crate) const F_WRAPPING: u64 = 0b10;
+
 /// Some arbitrary values used for error codes.
 pub(crate) const MISMATCHED_SELECTOR_REVERT_CODE: u32 = 123;
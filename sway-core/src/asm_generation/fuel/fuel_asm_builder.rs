@@ -20,7 +20,7 @@ use crate::{
     },
     decl_engine::DeclRefFunction,
     metadata::MetadataManager,
-    BuildConfig,
+    BuildConfig, OverflowBehavior,
 };
 
 use sway_error::{
@@ -88,6 +88,9 @@ pub struct FuelAsmBuilder<'ir, 'eng> {
 
     // Instructions that will be appended after globals allocation, but before the entry function is called.
     pub(super) before_entries: Vec<Op>,
+
+    // The behavior of integer arithmetic on overflow, set up at the start of every entry function.
+    pub(super) overflow_behavior: OverflowBehavior,
 }
 
 impl<'ir, 'eng> AsmBuilder for FuelAsmBuilder<'ir, 'eng> {
@@ -291,6 +294,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         data_section: DataSection,
         reg_seqr: RegisterSequencer,
         context: &'ir Context<'eng>,
+        overflow_behavior: OverflowBehavior,
     ) -> Self {
         FuelAsmBuilder {
             program_kind,
@@ -311,6 +315,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
             non_entries: Vec::new(),
             cur_bytecode: Vec::new(),
             before_entries: vec![],
+            overflow_behavior,
         }
     }
 
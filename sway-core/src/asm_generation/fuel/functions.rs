@@ -15,6 +15,7 @@ use crate::{
     },
     decl_engine::DeclRef,
     fuel_prelude::fuel_asm::GTFArgs,
+    OverflowBehavior,
 };
 
 use sway_ir::*;
@@ -281,6 +282,26 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
             None => Op::unowned_jump_label_comment(start_label, comment),
         });
 
+        // Let overflowing arithmetic wrap around, for the whole program, by setting `F_WRAPPING`
+        // before anything else runs. Every entry sets it, as tests jump straight to their entry.
+        if func_is_entry && self.overflow_behavior == OverflowBehavior::Wrap {
+            let flags = self.reg_seqr.next();
+            self.cur_bytecode.push(Op::unowned_new_with_comment(
+                VirtualOp::ORI(
+                    flags.clone(),
+                    VirtualRegister::Constant(ConstantRegister::Flags),
+                    VirtualImmediate12 {
+                        value: compiler_constants::F_WRAPPING as u16,
+                    },
+                ),
+                "set wrapping flag",
+            ));
+            self.cur_bytecode.push(Op::unowned_new_with_comment(
+                VirtualOp::FLAG(flags),
+                "disable panic on overflow",
+            ));
+        }
+
         // Manage the call frame.
         if !func_is_entry {
             // Save any general purpose registers used here on the stack.
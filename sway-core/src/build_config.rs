@@ -56,6 +56,36 @@ impl<'de> serde::Deserialize<'de> for OptLevel {
     }
 }
 
+/// The behavior of integer arithmetic on overflow, for the whole program.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Display,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    clap::ValueEnum,
+    EnumString,
+)]
+pub enum OverflowBehavior {
+    /// Overflowing operations panic, reverting the transaction.
+    #[default]
+    #[serde(rename = "trap")]
+    #[clap(name = "trap")]
+    #[strum(serialize = "trap")]
+    Trap,
+    /// Overflowing operations wrap around, as if `std::flags::disable_panic_on_overflow` was
+    /// called at the start of the program.
+    #[serde(rename = "wrap")]
+    #[clap(name = "wrap")]
+    #[strum(serialize = "wrap")]
+    Wrap,
+}
+
 /// Which ASM to print.
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct PrintAsm {
@@ -175,6 +205,76 @@ impl From<&PrintIr> for PrintPassesOpts {
     }
 }
 
+/// A kind of compiler output that can be written to the output directory of a package.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmitKind {
+    /// The parsed program, before type checking.
+    Ast,
+    /// The type-checked program.
+    TypedAst,
+    /// The final, optimized IR.
+    Ir,
+    /// The final ASM.
+    Asm,
+    /// The bytecode, along with the outputs derived from it, like the contract storage slots.
+    Bytecode,
+    /// The JSON ABI.
+    Abi,
+    /// The source locations of the functions and types of the JSON ABI.
+    AbiSpans,
+}
+
+impl EmitKind {
+    /// All kinds of compiler outputs, in the order they are produced during compilation.
+    pub const ALL: [EmitKind; 7] = [
+        EmitKind::Ast,
+        EmitKind::TypedAst,
+        EmitKind::Ir,
+        EmitKind::Asm,
+        EmitKind::Bytecode,
+        EmitKind::Abi,
+        EmitKind::AbiSpans,
+    ];
+
+    /// The outputs written when no kinds are explicitly requested.
+    pub const DEFAULT: [EmitKind; 2] = [EmitKind::Bytecode, EmitKind::Abi];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmitKind::Ast => "ast",
+            EmitKind::TypedAst => "typed-ast",
+            EmitKind::Ir => "ir",
+            EmitKind::Asm => "asm",
+            EmitKind::Bytecode => "bytecode",
+            EmitKind::Abi => "abi",
+            EmitKind::AbiSpans => "abi-spans",
+        }
+    }
+}
+
+impl std::fmt::Display for EmitKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for EmitKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EmitKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+            .ok_or_else(|| {
+                format!(
+                    "unknown emit kind `{s}`, expected one of: {}",
+                    EmitKind::ALL.iter().join(", ")
+                )
+            })
+    }
+}
+
 /// Configuration for the overall build and compilation process.
 #[derive(Clone)]
 pub struct BuildConfig {
@@ -190,7 +290,21 @@ pub struct BuildConfig {
     pub(crate) print_bytecode_spans: bool,
     pub(crate) print_ir: PrintIr,
     pub(crate) include_tests: bool,
+    // Whether to keep the textual form of the final IR in the `CompiledAsm`.
+    pub(crate) emit_ir: bool,
+    // The features enabling items annotated with `#[cfg(feature = "...")]`.
+    pub(crate) features: Vec<String>,
     pub(crate) optimization_level: OptLevel,
+    // The behavior of integer arithmetic on overflow.
+    pub(crate) overflow_behavior: OverflowBehavior,
+    // Whether to warn on `.clone()` calls on values that are already implicitly copied.
+    pub(crate) warn_redundant_clone: bool,
+    // The maximum number of steps taken when evaluating a constant expression.
+    pub(crate) const_eval_limit: Option<usize>,
+    // The number of parameters above which a function is warned about.
+    pub(crate) max_function_parameters: Option<usize>,
+    // The cyclomatic complexity above which a function is warned about.
+    pub(crate) max_cyclomatic_complexity: Option<usize>,
     pub time_phases: bool,
     pub metrics_outfile: Option<String>,
     pub experimental: ExperimentalFlags,
@@ -238,9 +352,16 @@ impl BuildConfig {
             print_bytecode_spans: false,
             print_ir: PrintIr::default(),
             include_tests: false,
+            emit_ir: false,
+            features: vec![],
             time_phases: false,
             metrics_outfile: None,
             optimization_level: OptLevel::Opt0,
+            overflow_behavior: OverflowBehavior::Trap,
+            warn_redundant_clone: false,
+            const_eval_limit: None,
+            max_function_parameters: None,
+            max_cyclomatic_complexity: None,
             experimental: ExperimentalFlags {
                 new_encoding: false,
             },
@@ -302,6 +423,18 @@ impl BuildConfig {
         }
     }
 
+    /// The behavior of integer arithmetic on overflow. With [OverflowBehavior::Wrap], every
+    /// entry point of the program disables the default panic on overflow of the FuelVM before
+    /// running any other code.
+    ///
+    /// Default: [OverflowBehavior::Trap]
+    pub fn with_overflow_behavior(self, overflow_behavior: OverflowBehavior) -> Self {
+        Self {
+            overflow_behavior,
+            ..self
+        }
+    }
+
     /// Whether or not to include test functions in parsing, type-checking and codegen.
     ///
     /// This should be set to `true` by invocations like `forc test` or `forc check --tests`.
@@ -314,6 +447,64 @@ impl BuildConfig {
         }
     }
 
+    /// Whether or not to keep the textual form of the final, optimized IR in the resulting
+    /// `CompiledAsm`, so that it can be written to a file.
+    ///
+    /// Default: `false`
+    pub fn with_emit_ir(self, emit_ir: bool) -> Self {
+        Self { emit_ir, ..self }
+    }
+
+    /// The features to enable, which include the items annotated with `#[cfg(feature = "...")]`
+    /// with a matching feature name in the compilation.
+    ///
+    /// Default: no features.
+    pub fn with_features(self, features: Vec<String>) -> Self {
+        Self { features, ..self }
+    }
+
+    /// Whether or not to warn on `.clone()` calls on values of types that are already
+    /// implicitly copied, like `u64` or `bool`.
+    ///
+    /// Default: `false`
+    pub fn with_warn_redundant_clone(self, warn_redundant_clone: bool) -> Self {
+        Self {
+            warn_redundant_clone,
+            ..self
+        }
+    }
+
+    /// The maximum number of steps, function calls and loop iterations, taken when evaluating
+    /// a constant expression at compile time. Evaluations exceeding it result in an error.
+    ///
+    /// Default: `None`, meaning the compiler's default limit of one million steps.
+    pub fn with_const_eval_limit(self, const_eval_limit: Option<usize>) -> Self {
+        Self {
+            const_eval_limit,
+            ..self
+        }
+    }
+
+    /// Warn on functions that have more parameters than the given maximum.
+    ///
+    /// Default: `None`, meaning the number of parameters is not checked.
+    pub fn with_max_function_parameters(self, max_function_parameters: Option<usize>) -> Self {
+        Self {
+            max_function_parameters,
+            ..self
+        }
+    }
+
+    /// Warn on functions whose cyclomatic complexity is higher than the given maximum.
+    ///
+    /// Default: `None`, meaning the cyclomatic complexity is not checked.
+    pub fn with_max_cyclomatic_complexity(self, max_cyclomatic_complexity: Option<usize>) -> Self {
+        Self {
+            max_cyclomatic_complexity,
+            ..self
+        }
+    }
+
     pub fn with_experimental(self, experimental: ExperimentalFlags) -> Self {
         Self {
             experimental,
@@ -371,4 +562,18 @@ mod test {
             BuildTarget::default(),
         );
     }
+
+    #[test]
+    fn test_emit_kind_from_str() {
+        for kind in EmitKind::ALL {
+            assert_eq!(kind.as_str().parse::<EmitKind>(), Ok(kind));
+        }
+        assert_eq!(
+            "bin".parse::<EmitKind>(),
+            Err(
+                "unknown emit kind `bin`, expected one of: ast, typed-ast, ir, asm, bytecode, abi, abi-spans"
+                    .into()
+            )
+        );
+    }
 }
@@ -25,6 +25,34 @@ pub fn is_generated_destructured_struct_var_name(name: &str) -> bool {
     name.starts_with(DESTRUCTURED_STRUCT_VAR_NAME_PREFIX)
 }
 
+/// The prefix for the compiler generated names of arrays, and their elements,
+/// used in destructuring arrays with slice patterns in `let` statements.
+const DESTRUCTURED_SLICE_VAR_NAME_PREFIX: &str = "__destructured_slice_";
+
+pub(crate) fn generate_destructured_slice_var_name(suffix: usize) -> String {
+    format!("{DESTRUCTURED_SLICE_VAR_NAME_PREFIX}{suffix}")
+}
+
+pub(crate) fn generate_destructured_slice_element_var_name(suffix: usize, index: usize) -> String {
+    format!("{DESTRUCTURED_SLICE_VAR_NAME_PREFIX}{suffix}_{index}")
+}
+
+pub fn is_generated_destructured_slice_var_name(name: &str) -> bool {
+    name.starts_with(DESTRUCTURED_SLICE_VAR_NAME_PREFIX)
+}
+
+/// The prefix for the compiler generated names of
+/// variables that store values of type ascription expressions.
+const TYPE_ASCRIPTION_VAR_NAME_PREFIX: &str = "__type_ascription_";
+
+pub(crate) fn generate_type_ascription_var_name(suffix: usize) -> String {
+    format!("{TYPE_ASCRIPTION_VAR_NAME_PREFIX}{suffix}")
+}
+
+pub fn is_generated_type_ascription_var_name(name: &str) -> bool {
+    name.starts_with(TYPE_ASCRIPTION_VAR_NAME_PREFIX)
+}
+
 /// The prefix for the compiler generated names of
 /// variables that store values matched in match expressions.
 const MATCHED_VALUE_VAR_NAME_PREFIX: &str = "__matched_value_";
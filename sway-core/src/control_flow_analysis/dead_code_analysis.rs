@@ -22,7 +22,7 @@ use sway_error::{
     warning::{CompileWarning, Warning},
 };
 use sway_types::{
-    constants::{ALLOW_DEAD_CODE_NAME, STD},
+    constants::{ALLOW_DEAD_CODE_NAME, ALLOW_UNUSED_STORAGE_NAME, STD},
     span::Span,
     Ident, Named, Spanned,
 };
@@ -48,6 +48,8 @@ fn is_entry_point(node: &TyAstNode, decl_engine: &DeclEngine, tree_type: &TreeTy
                 _ => false,
             }
         }
+        // Only `pub` items are exported. Items with a restricted visibility, e.g. `pub(crate)`,
+        // are not entry points and are reported as dead code if unused within the package.
         TreeType::Contract | TreeType::Library { .. } => match node {
             TyAstNode {
                 content:
@@ -60,7 +62,7 @@ fn is_entry_point(node: &TyAstNode, decl_engine: &DeclEngine, tree_type: &TreeTy
             TyAstNode {
                 content: TyAstNodeContent::Declaration(TyDecl::TraitDecl(TraitDecl { decl_id })),
                 ..
-            } => decl_engine.get_trait(decl_id).visibility.is_public(),
+            } => decl_engine.get_trait(decl_id).visibility == Visibility::Public,
             TyAstNode {
                 content:
                     TyAstNodeContent::Declaration(TyDecl::StructDecl(StructDecl { decl_id, .. })),
@@ -79,7 +81,7 @@ fn is_entry_point(node: &TyAstNode, decl_engine: &DeclEngine, tree_type: &TreeTy
                 ..
             } => {
                 let decl = decl_engine.get_constant(decl_id);
-                decl.visibility.is_public()
+                decl.visibility == Visibility::Public
             }
             TyAstNode {
                 content:
@@ -94,7 +96,7 @@ fn is_entry_point(node: &TyAstNode, decl_engine: &DeclEngine, tree_type: &TreeTy
                 ..
             } => {
                 let decl = decl_engine.get_type_alias(decl_id);
-                decl.visibility.is_public()
+                decl.visibility == Visibility::Public
             }
             _ => false,
         },
@@ -1974,6 +1976,30 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             }
             Ok(vec![while_loop_exit])
         }
+        Loop { body } => {
+            // A loop can loop back to the beginning, or it can be exited by a `break`.
+            // Breaks are not connected to the loop exit, so we conservatively connect
+            // the beginning of the loop to its exit, as if the loop could be skipped.
+            let entry = leaves[0];
+
+            let loop_exit = graph.add_node("loop exit".to_string().into());
+            graph.add_edge(entry, loop_exit, "loop is exited by a break".into());
+
+            let (l_leaves, _l_exit_node) = depth_first_insertion_code_block(
+                engines,
+                body,
+                graph,
+                &[entry],
+                exit_node,
+                tree_type,
+                options,
+            )?;
+            // insert edges from end of block back to beginning of it
+            for leaf in &l_leaves {
+                graph.add_edge(*leaf, entry, "loop repeats".into());
+            }
+            Ok(vec![loop_exit])
+        }
         ForLoop { desugared, .. } => connect_expression(
             engines,
             &desugared.expression,
@@ -1985,10 +2011,24 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             expression_span,
             options,
         ),
-        Break => {
+        Break(value) => {
+            let leaves = match value {
+                Some(value) => connect_expression(
+                    engines,
+                    &value.expression,
+                    graph,
+                    leaves,
+                    exit_node,
+                    "break value",
+                    tree_type,
+                    value.span.clone(),
+                    options,
+                )?,
+                None => leaves.to_vec(),
+            };
             let break_node = graph.add_node("break".to_string().into());
             for leaf in leaves {
-                graph.add_edge(*leaf, break_node, "".into());
+                graph.add_edge(leaf, break_node, "".into());
             }
             Ok(vec![])
         }
@@ -2512,6 +2552,19 @@ fn allow_dead_code(attributes: AttributesMap) -> bool {
     allow_dead_code_helper(attributes).unwrap_or_default()
 }
 
+/// Checks [AttributesMap] for `#[allow(unused_storage)]` usage, if so returns true
+/// otherwise returns false.
+fn allow_unused_storage(attributes: &AttributesMap) -> bool {
+    attributes
+        .get(&transform::AttributeKind::Allow)
+        .is_some_and(|allows| {
+            allows
+                .iter()
+                .flat_map(|allow| allow.args.iter())
+                .any(|arg| arg.name.as_str() == ALLOW_UNUSED_STORAGE_NAME)
+        })
+}
+
 /// Returns true when the given `node` contains the attribute `#[allow(dead_code)]`
 fn allow_dead_code_ast_node(decl_engine: &DeclEngine, node: &ty::TyAstNode) -> bool {
     match &node.content {
@@ -2599,7 +2652,7 @@ fn allow_dead_code_node(
                 allow_dead_code(decl_engine.get_struct(struct_decl_id).attributes.clone())
             }
         }
-        ControlFlowGraphNode::StorageField { .. } => false,
+        ControlFlowGraphNode::StorageField { attributes, .. } => allow_unused_storage(attributes),
         ControlFlowGraphNode::OrganizationalDominator(..) => false,
         ControlFlowGraphNode::FunctionParameter { .. } => false,
     }
@@ -93,6 +93,7 @@ pub enum ControlFlowGraphNode<'cfg> {
     },
     StorageField {
         field_name: Ident,
+        attributes: transform::AttributesMap,
     },
     FunctionParameter {
         param_name: Ident,
@@ -122,6 +123,7 @@ impl<'cfg> std::convert::From<&ty::TyStorageField> for ControlFlowGraphNode<'cfg
     fn from(other: &ty::TyStorageField) -> Self {
         ControlFlowGraphNode::StorageField {
             field_name: other.name.clone(),
+            attributes: other.attributes.clone(),
         }
     }
 }
@@ -171,7 +173,7 @@ impl<'cfg> DebugWithEngines for ControlFlowGraphNode<'cfg> {
             } => {
                 format!("Struct field {}", struct_field_name.as_str())
             }
-            ControlFlowGraphNode::StorageField { field_name } => {
+            ControlFlowGraphNode::StorageField { field_name, .. } => {
                 format!("Storage field {}", field_name.as_str())
             }
             ControlFlowGraphNode::FunctionParameter { param_name, .. } => {
@@ -323,7 +325,7 @@ impl<'cfg> ControlFlowGraphNode<'cfg> {
             ControlFlowGraphNode::StructField {
                 struct_field_name, ..
             } => Some(struct_field_name.span()),
-            ControlFlowGraphNode::StorageField { field_name } => Some(field_name.span()),
+            ControlFlowGraphNode::StorageField { field_name, .. } => Some(field_name.span()),
             ControlFlowGraphNode::FunctionParameter { param_name, .. } => Some(param_name.span()),
         }
     }
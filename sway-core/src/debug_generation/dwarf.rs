@@ -92,7 +92,12 @@ fn build_line_number_program(
     program.begin_sequence(Some(write::Address::Constant(0)));
 
     for (ix, span) in &source_map.map {
-        let (path, span) = span.to_span(&source_map.paths, &source_map.dependency_paths);
+        let (path, span) = span
+            .to_span(&source_map.paths, &source_map.dependency_paths)
+            .ok_or(sway_error::error::CompileError::InternalOwned(
+                "Source map span refers to an unknown path".to_string(),
+                Span::dummy(),
+            ))?;
 
         let dir = path
             .parent()
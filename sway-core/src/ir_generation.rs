@@ -121,6 +121,7 @@ pub fn compile_program<'eng>(
     include_tests: bool,
     engines: &'eng Engines,
     experimental: ExperimentalFlags,
+    const_eval_limit: Option<usize>,
 ) -> Result<Context<'eng>, Vec<CompileError>> {
     let declaration_engine = engines.de();
 
@@ -160,6 +161,9 @@ pub fn compile_program<'eng>(
         ty::TyProgramKind::Contract { .. } => Kind::Contract,
         ty::TyProgramKind::Library { .. } => Kind::Library,
     };
+    if let Some(const_eval_limit) = const_eval_limit {
+        ctx.const_eval_limit = const_eval_limit;
+    }
 
     let mut cache = CompiledFunctionCache::default();
 
@@ -264,6 +264,9 @@ pub(crate) fn compile_constants(
                         module,
                         module_ns: Some(module_ns),
                         function_compiler: None,
+                        steps: 0,
+                        call_depth: 0,
+                        call_depth_exceeded: false,
                         lookup: compile_const_decl,
                     },
                     &call_path,
@@ -1,6 +1,7 @@
 use std::ops::{BitAnd, BitOr, BitXor, Not, Rem};
 
 use crate::{
+    decl_engine::AssociatedItemDeclId,
     engine_threading::*,
     language::{
         ty::{self, TyConstantDecl, TyIntrinsicFunctionKind},
@@ -39,8 +40,17 @@ enum ConstEvalError {
         #[allow(dead_code)]
         span: Span,
     },
+    /// The evaluation took more steps than allowed by the [Context::const_eval_limit].
+    LimitExceeded,
+    /// The evaluation nested more function calls than [MAX_CONST_EVAL_CALL_DEPTH].
+    CallDepthExceeded,
 }
 
+/// The maximum number of nested function calls in the evaluation of a constant expression.
+/// Recursive functions are evaluated by recursion in the compiler, so unlike the number of steps,
+/// the depth is bounded by the stack of the compiler and cannot be configured.
+pub(crate) const MAX_CONST_EVAL_CALL_DEPTH: usize = 64;
+
 pub(crate) struct LookupEnv<'a, 'eng> {
     pub(crate) engines: &'a Engines,
     pub(crate) context: &'a mut Context<'eng>,
@@ -48,6 +58,12 @@ pub(crate) struct LookupEnv<'a, 'eng> {
     pub(crate) module: Module,
     pub(crate) module_ns: Option<&'a namespace::Module>,
     pub(crate) function_compiler: Option<&'a FnCompiler<'a>>,
+    /// The number of steps, function calls and loop iterations, taken so far by the evaluation.
+    pub(crate) steps: usize,
+    /// The number of nested function calls currently being evaluated.
+    pub(crate) call_depth: usize,
+    /// Whether the evaluation exceeded the [MAX_CONST_EVAL_CALL_DEPTH].
+    pub(crate) call_depth_exceeded: bool,
     #[allow(clippy::type_complexity)]
     pub(crate) lookup: fn(
         &mut LookupEnv,
@@ -71,6 +87,9 @@ pub(crate) fn compile_const_decl(
             if let Some(constant) = local_var.get_initializer(env.context) {
                 return Ok(Some(Value::new_constant(env.context, constant.clone())));
             }
+            if let Some(constant) = fn_compiler.get_local_const(&local_var) {
+                return Ok(Some(Value::new_constant(env.context, constant.clone())));
+            }
 
             // Check if a constant was stored to a local variable in the current block.
             let mut stored_const_opt: Option<&Constant> = None;
@@ -213,6 +232,9 @@ pub(crate) fn compile_constant_expression_to_constant(
         module,
         module_ns,
         function_compiler,
+        steps: 0,
+        call_depth: 0,
+        call_depth_exceeded: false,
         lookup: compile_const_decl,
     };
 
@@ -234,13 +256,37 @@ pub(crate) fn compile_constant_expression_to_constant(
     };
     let mut known_consts = MappedStack::<Ident, Constant>::new();
 
-    match const_eval_typed_expr(lookup, &mut known_consts, const_expr) {
+    let result = const_eval_typed_expr(lookup, &mut known_consts, const_expr);
+    // The limit error can get replaced by a more generic one on its way up, e.g. in code blocks.
+    if lookup.steps > lookup.context.const_eval_limit {
+        return Err(CompileError::ConstEvalLimitExceeded {
+            limit: lookup.context.const_eval_limit,
+            span: const_expr.span.clone(),
+        });
+    }
+    if lookup.call_depth_exceeded {
+        return Err(CompileError::ConstEvalCallDepthExceeded {
+            limit: MAX_CONST_EVAL_CALL_DEPTH,
+            span: const_expr.span.clone(),
+        });
+    }
+    match result {
         Ok(Some(constant)) => Ok(constant),
         Ok(None) => err,
         Err(_) => err,
     }
 }
 
+/// Counts a step of the evaluation, i.e. a function call or a loop iteration, and fails if the
+/// evaluation exceeds the [Context::const_eval_limit].
+fn count_const_eval_step(lookup: &mut LookupEnv) -> Result<(), ConstEvalError> {
+    lookup.steps += 1;
+    if lookup.steps > lookup.context.const_eval_limit {
+        return Err(ConstEvalError::LimitExceeded);
+    }
+    Ok(())
+}
+
 /// Given an environment mapping names to constants,
 /// attempt to evaluate a typed expression to a constant.
 fn const_eval_typed_expr(
@@ -269,6 +315,8 @@ fn const_eval_typed_expr(
             call_path,
             ..
         } => {
+            count_const_eval_step(lookup)?;
+
             let mut actuals_const: Vec<_> = vec![];
 
             for arg in arguments {
@@ -291,8 +339,32 @@ fn const_eval_typed_expr(
                 known_consts.push(name.clone(), cval);
             }
 
-            let function_decl = lookup.engines.de().get_function(fn_ref);
+            // Recursive calls are type checked before the body of the function, so they refer
+            // to a copy of its signature. Evaluate the body of the function they were copied from.
+            let decl_engine = lookup.engines.de();
+            let mut function_decl = decl_engine.get_function(fn_ref);
+            if !function_decl.is_type_check_finalized {
+                if let Some(parent_decl) = decl_engine
+                    .find_all_parents(lookup.engines, fn_ref.id())
+                    .into_iter()
+                    .filter_map(|parent| match parent {
+                        AssociatedItemDeclId::Function(parent_id) => {
+                            Some(decl_engine.get_function(&parent_id))
+                        }
+                        _ => None,
+                    })
+                    .find(|parent_decl| parent_decl.is_type_check_finalized)
+                {
+                    function_decl = parent_decl;
+                }
+            }
+            if lookup.call_depth == MAX_CONST_EVAL_CALL_DEPTH {
+                lookup.call_depth_exceeded = true;
+                return Err(ConstEvalError::CallDepthExceeded);
+            }
+            lookup.call_depth += 1;
             let res = const_eval_codeblock(lookup, known_consts, &function_decl.body);
+            lookup.call_depth -= 1;
 
             for (name, _) in arguments {
                 known_consts.pop(name);
@@ -659,12 +731,9 @@ fn const_eval_typed_expr(
         ty::TyExpressionVariant::WhileLoop {
             condition, body, ..
         } => {
-            // Arbitrary limit of iterations to avoid infinite loops like
-            // while true {}
-            let mut limit = 1_000_000;
-
-            while limit >= 0 {
-                limit -= 1;
+            // Infinite loops like `while true {}` end once the evaluation exceeds its limit.
+            loop {
+                count_const_eval_step(lookup)?;
 
                 let condition = const_eval_typed_expr(lookup, known_consts, condition)?;
                 match condition.map(|x| x.value) {
@@ -711,7 +780,8 @@ fn const_eval_typed_expr(
         | ty::TyExpressionVariant::AbiCast { .. }
         | ty::TyExpressionVariant::StorageAccess(_)
         | ty::TyExpressionVariant::AbiName(_)
-        | ty::TyExpressionVariant::Break
+        | ty::TyExpressionVariant::Break(_)
+        | ty::TyExpressionVariant::Loop { .. }
         | ty::TyExpressionVariant::Continue
         | ty::TyExpressionVariant::ForLoop { .. } => {
             return Err(ConstEvalError::CannotBeEvaluatedToConst {
@@ -1099,6 +1169,21 @@ fn const_eval_intrinsic(
                 ),
             }))
         }
+        Intrinsic::AlignOfType => {
+            let targ = &intrinsic.type_arguments[0];
+            let ir_type = convert_resolved_type_id(
+                lookup.engines.te(),
+                lookup.engines.de(),
+                lookup.context,
+                targ.type_id,
+                &targ.span,
+            )
+            .map_err(|_| ConstEvalError::CompileError)?;
+            Ok(Some(Constant {
+                ty: Type::get_uint64(lookup.context),
+                value: ConstantValue::Uint(ir_type.alignment(lookup.context)),
+            }))
+        }
         Intrinsic::AssertIsStrArray => {
             let targ = &intrinsic.type_arguments[0];
             let ir_type = convert_resolved_type_id(
@@ -1167,6 +1252,12 @@ fn const_eval_intrinsic(
         | Intrinsic::IsReferenceType
         | Intrinsic::IsStrArray
         | Intrinsic::Gtf
+        | Intrinsic::TxInputCount
+        | Intrinsic::TxInputType
+        | Intrinsic::TxInputAmount
+        | Intrinsic::TxOutputCount
+        | Intrinsic::TxOutputType
+        | Intrinsic::TxOutputAmount
         | Intrinsic::StateClear
         | Intrinsic::StateLoadWord
         | Intrinsic::StateStoreWord
@@ -1175,7 +1266,8 @@ fn const_eval_intrinsic(
         | Intrinsic::Log
         | Intrinsic::Revert
         | Intrinsic::JmpMem
-        | Intrinsic::Smo => Err(ConstEvalError::CannotBeEvaluatedToConst {
+        | Intrinsic::Smo
+        | Intrinsic::Fmt => Err(ConstEvalError::CannotBeEvaluatedToConst {
             span: intrinsic.span.clone(),
         }),
         Intrinsic::Not => {
@@ -1372,20 +1464,17 @@ mod tests {
     use sway_error::handler::Handler;
     use sway_ir::Kind;
 
-    /// This function validates if an expression can be converted to [Constant].
+    /// Type checks the expression `expr` within a function, and evaluates it to a [Constant]
+    /// taking at most `const_eval_limit` steps.
     ///
-    /// The flag `is_constant` is used to define if the expression should be convertible or not.
     /// `prefix` is any valid code at top level, useful to declare types.
     ///
-    /// Example:
-    ///
-    /// ```rust,ignore
-    /// assert_is_constant(true, "enum Color { Blue: u64 }", "Color::Blue(1)");
-    /// assert_is_constant(false, "", "{return 1; 1}");
-    /// ```
-    ///
     /// It DOES NOT have access to the std lib, and constants, and other features that demand full compilation.
-    fn assert_is_constant(is_constant: bool, prefix: &str, expr: &str) {
+    fn eval_expression(
+        prefix: &str,
+        expr: &str,
+        const_eval_limit: usize,
+    ) -> (ty::TyExpression, Result<Constant, CompileError>) {
         let engines = Engines::default();
         let handler = Handler::default();
         let mut context = Context::new(
@@ -1394,6 +1483,7 @@ mod tests {
                 new_encoding: false,
             },
         );
+        context.const_eval_limit = const_eval_limit;
         let mut md_mgr = MetadataManager::default();
         let mut core_lib = namespace::Root::from(namespace::Module::new(
             sway_types::Ident::new_no_span("assert_is_constant_test".to_string()),
@@ -1458,6 +1548,26 @@ mod tests {
             &expr_under_test,
         );
 
+        (expr_under_test, actual_constant)
+    }
+
+    /// This function validates if an expression can be converted to [Constant].
+    ///
+    /// The flag `is_constant` is used to define if the expression should be convertible or not.
+    /// `prefix` is any valid code at top level, useful to declare types.
+    ///
+    /// Example:
+    ///
+    /// ```rust,ignore
+    /// assert_is_constant(true, "enum Color { Blue: u64 }", "Color::Blue(1)");
+    /// assert_is_constant(false, "", "{return 1; 1}");
+    /// ```
+    ///
+    /// It DOES NOT have access to the std lib, and constants, and other features that demand full compilation.
+    fn assert_is_constant(is_constant: bool, prefix: &str, expr: &str) {
+        let (expr_under_test, actual_constant) =
+            eval_expression(prefix, expr, sway_ir::DEFAULT_CONST_EVAL_LIMIT);
+
         match (is_constant, actual_constant) {
             (true, Ok(_)) => {}
             (true, Err(err)) => {
@@ -1565,4 +1675,31 @@ mod tests {
         assert_is_constant(false, "", "{ }");
         assert_is_constant(false, "fn id(x: u64) -> u64 { { return 1; }; x }", "id(1)");
     }
+
+    #[test]
+    fn const_eval_limit_test() {
+        let factorial = "fn factorial(n: u64) -> u64 { let mut i = 1; let mut result = 1; while __lt(i, __add(n, 1)) { result = __mul(result, i); i = __add(i, 1); } result }";
+
+        // 1 call of `factorial` and 6 iterations of the loop, including the last check.
+        let (_, result) = eval_expression(factorial, "factorial(5)", 7);
+        assert!(matches!(
+            result,
+            Ok(Constant {
+                value: ConstantValue::Uint(120),
+                ..
+            })
+        ));
+
+        let (_, result) = eval_expression(factorial, "factorial(5)", 6);
+        assert!(matches!(
+            result,
+            Err(CompileError::ConstEvalLimitExceeded { limit: 6, .. })
+        ));
+
+        let (_, result) = eval_expression("", "{ while true {}; 1 }", 1000);
+        assert!(matches!(
+            result,
+            Err(CompileError::ConstEvalLimitExceeded { limit: 1000, .. })
+        ));
+    }
 }
@@ -7,6 +7,7 @@ use super::{
 };
 use crate::{
     engine_threading::*,
+    fuel_prelude::fuel_asm::GTFArgs,
     ir_generation::const_eval::{
         compile_constant_expression, compile_constant_expression_to_constant,
     },
@@ -102,6 +103,9 @@ pub(crate) struct FnCompiler<'eng> {
     logged_types_map: HashMap<TypeId, LogId>,
     // This is a map from the type IDs of a message data type and the ID of the corresponding smo
     messages_types_map: HashMap<TypeId, MessageId>,
+    // This is a map from the local variables holding the constants declared within the function
+    // to their values, so that they can be used when evaluating other constants.
+    local_consts: HashMap<LocalVar, Constant>,
 }
 
 fn to_constant(_s: &mut FnCompiler<'_>, context: &mut Context, value: u64) -> Value {
@@ -175,6 +179,7 @@ impl<'eng> FnCompiler<'eng> {
             current_fn_param: None,
             logged_types_map: logged_types_map.clone(),
             messages_types_map: messages_types_map.clone(),
+            local_consts: HashMap::new(),
         }
     }
 
@@ -345,6 +350,14 @@ impl<'eng> FnCompiler<'eng> {
         md_mgr: &mut MetadataManager,
         ast_expr: &ty::TyExpression,
     ) -> Result<TerminatorValue, CompileError> {
+        // Dereferencing a reference gives the pointer to the referenced value.
+        if let ty::TyExpressionVariant::Deref(reference_exp) = &ast_expr.expression {
+            let span_md_idx = md_mgr.span_to_md(context, &ast_expr.span);
+            let (ptr, _) =
+                self.compile_deref_up_to_ptr(context, md_mgr, reference_exp, span_md_idx)?;
+            return Ok(ptr);
+        }
+
         // Compile expression which *may* be a pointer.
         let val =
             return_on_termination_or_extract!(self.compile_expression(context, md_mgr, ast_expr)?);
@@ -681,16 +694,32 @@ impl<'eng> FnCompiler<'eng> {
             ty::TyExpressionVariant::ForLoop { desugared } => {
                 self.compile_expression(context, md_mgr, desugared)
             }
-            ty::TyExpressionVariant::Break => {
+            ty::TyExpressionVariant::Loop { body } => {
+                self.compile_loop(context, md_mgr, body, ast_expr.return_type)
+            }
+            ty::TyExpressionVariant::Break(value) => {
                 match self.block_to_break_to {
                     // If `self.block_to_break_to` is not None, then it has been set inside
                     // a loop and the use of `break` here is legal, so create a branch
                     // instruction. Error out otherwise.
                     Some(block_to_break_to) => {
+                        // The break block of a `loop` expects the value of the loop as its
+                        // argument. A `break` without a value breaks with the unit value.
+                        let args = if block_to_break_to.num_args(context) > 0 {
+                            let value = match value {
+                                Some(value) => return_on_termination_or_extract!(
+                                    self.compile_expression_to_value(context, md_mgr, value)?
+                                ),
+                                None => Constant::get_unit(context),
+                            };
+                            vec![value]
+                        } else {
+                            vec![]
+                        };
                         let val = self
                             .current_block
                             .append(context)
-                            .branch(block_to_break_to, vec![]);
+                            .branch(block_to_break_to, args);
                         Ok(TerminatorValue::new(val, context))
                     }
                     None => Err(CompileError::BreakOutsideLoop {
@@ -950,6 +979,18 @@ impl<'eng> FnCompiler<'eng> {
                 );
                 Ok(TerminatorValue::new(val, context))
             }
+            Intrinsic::AlignOfType => {
+                let targ = type_arguments[0].clone();
+                let ir_type = convert_resolved_type_id(
+                    engines.te(),
+                    engines.de(),
+                    context,
+                    targ.type_id,
+                    &targ.span,
+                )?;
+                let val = Constant::get_uint(context, 64, ir_type.alignment(context));
+                Ok(TerminatorValue::new(val, context))
+            }
             Intrinsic::IsReferenceType => {
                 let targ = type_arguments[0].clone();
                 let is_val = !engines.te().get_unaliased(targ.type_id).is_copy_type();
@@ -2170,9 +2211,68 @@ impl<'eng> FnCompiler<'eng> {
             }
             Intrinsic::Slice => self.compile_intrinsic_slice(arguments, context, md_mgr),
             Intrinsic::ElemAt => self.compile_intrinsic_elem_at(arguments, context, md_mgr),
+            Intrinsic::TxInputCount
+            | Intrinsic::TxInputType
+            | Intrinsic::TxInputAmount
+            | Intrinsic::TxOutputCount
+            | Intrinsic::TxOutputType
+            | Intrinsic::TxOutputAmount => {
+                self.compile_intrinsic_tx_field(kind, arguments, context, md_mgr, &span)
+            }
+            Intrinsic::Fmt => {
+                // The format string and the arguments are composed into a tuple.
+                let span_md_idx = md_mgr.span_to_md(context, &span);
+                self.compile_tuple_expr(context, md_mgr, arguments, span_md_idx)
+            }
         }
     }
 
+    /// Lowers the transaction field intrinsics to a `gtf` instruction with the field's selector.
+    ///
+    /// The count intrinsics use the script selectors, which the VM treats the same as the create
+    /// ones and which work for every transaction type. For an `index` out of range, or for the
+    /// amount of an input or output that isn't a coin, the VM panics with `InputNotFound` or
+    /// `OutputNotFound`.
+    fn compile_intrinsic_tx_field(
+        &mut self,
+        kind: &Intrinsic,
+        arguments: &[ty::TyExpression],
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        span: &Span,
+    ) -> Result<TerminatorValue, CompileError> {
+        let (tx_field_id, return_type) = match kind {
+            Intrinsic::TxInputCount => (GTFArgs::ScriptInputsCount, Type::get_uint64(context)),
+            Intrinsic::TxInputType => (GTFArgs::InputType, Type::get_uint8(context)),
+            Intrinsic::TxInputAmount => (GTFArgs::InputCoinAmount, Type::get_uint64(context)),
+            Intrinsic::TxOutputCount => (GTFArgs::ScriptOutputsCount, Type::get_uint64(context)),
+            Intrinsic::TxOutputType => (GTFArgs::OutputType, Type::get_uint8(context)),
+            Intrinsic::TxOutputAmount => (GTFArgs::OutputCoinAmount, Type::get_uint64(context)),
+            _ => unreachable!("only transaction field intrinsics are compiled here"),
+        };
+
+        // The counts aren't indexed, but `gtf` still takes an index register.
+        let index = match arguments.first() {
+            Some(index) => return_on_termination_or_extract!(
+                self.compile_expression_to_value(context, md_mgr, index)?
+            ),
+            None => Constant::get_uint(context, 64, 0),
+        };
+
+        let span_md_idx = md_mgr.span_to_md(context, span);
+        let gtf_reg = self
+            .current_block
+            .append(context)
+            .gtf(index, tx_field_id as u64)
+            .add_metadatum(context, span_md_idx);
+        let val = self
+            .current_block
+            .append(context)
+            .bitcast(gtf_reg, return_type)
+            .add_metadatum(context, span_md_idx);
+        Ok(TerminatorValue::new(val, context))
+    }
+
     fn ptr_to_first_element(
         &mut self,
         context: &mut Context,
@@ -3160,12 +3260,88 @@ impl<'eng> FnCompiler<'eng> {
         Ok(TerminatorValue::new(val, context))
     }
 
+    fn compile_loop(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        body: &ty::TyCodeBlock,
+        return_type: TypeId,
+    ) -> Result<TerminatorValue, CompileError> {
+        // The blocks are ordered like those of a `while` loop, see `compile_while_loop`.  The
+        // break block takes the value of the loop as its argument and passes it on to the
+        // final block.
+        let break_block = self
+            .function
+            .create_block(context, Some("loop_break".into()));
+        let loop_type = convert_resolved_typeid_no_span(
+            self.engines.te(),
+            self.engines.de(),
+            context,
+            return_type,
+        )
+        .unwrap_or_else(|_| Type::get_unit(context));
+        let break_val_arg_idx = break_block.new_arg(context, loop_type);
+
+        // Jump to the body block.
+        let body_block = self
+            .function
+            .create_block(context, Some("loop_body".into()));
+        self.current_block
+            .append(context)
+            .branch(body_block, vec![]);
+
+        let prev_block_to_break_to = self.block_to_break_to;
+        let prev_block_to_continue_to = self.block_to_continue_to;
+        self.block_to_break_to = Some(break_block);
+        self.block_to_continue_to = Some(body_block);
+
+        // Fill in the body block now, jump unconditionally back to its start at its end.
+        self.current_block = body_block;
+        let body_block_val = self
+            .compile_code_block(context, md_mgr, body)
+            .map_err(|mut x| x.pop().unwrap())?;
+        if !body_block_val.is_terminator {
+            self.current_block
+                .append(context)
+                .branch(body_block, vec![]);
+        }
+
+        // Restore the blocks to jump to now that we're done with the current loop
+        self.block_to_break_to = prev_block_to_break_to;
+        self.block_to_continue_to = prev_block_to_continue_to;
+
+        if break_block.num_predecessors(context) == 0 {
+            // Corner case: If the loop is never broken out of, it diverges.  Like for an `if` whose
+            // branches both diverge, we terminate the unreachable break block with a diverging
+            // dummy value to signal that the expression diverges.
+            let val = break_block.append(context).branch(body_block, vec![]);
+            return Ok(TerminatorValue::new(val, context));
+        }
+
+        // Create the final block now we're finished with the body.
+        let final_block = self.function.create_block(context, Some("end_loop".into()));
+        let final_val_arg_idx = final_block.new_arg(context, loop_type);
+        let break_val = break_block.get_arg(context, break_val_arg_idx).unwrap();
+        break_block
+            .append(context)
+            .branch(final_block, vec![break_val]);
+
+        self.current_block = final_block;
+        let val = final_block.get_arg(context, final_val_arg_idx).unwrap();
+        Ok(TerminatorValue::new(val, context))
+    }
+
     pub(crate) fn get_function_var(&self, context: &mut Context, name: &str) -> Option<LocalVar> {
         self.lexical_map
             .get(name)
             .and_then(|local_name| self.function.get_local_var(context, local_name))
     }
 
+    /// Returns the value of the constant declared within the function and held by `local_var`.
+    pub(crate) fn get_local_const(&self, local_var: &LocalVar) -> Option<&Constant> {
+        self.local_consts.get(local_var)
+    }
+
     pub(crate) fn get_function_arg(&self, context: &mut Context, name: &str) -> Option<Value> {
         self.function.get_arg(context, name)
     }
@@ -3399,6 +3575,10 @@ impl<'eng> FnCompiler<'eng> {
                     return Ok(TerminatorValue::new(val, context));
                 };
 
+                if let Some(constant) = val.get_constant(context) {
+                    self.local_consts.insert(local_var, constant.clone());
+                }
+
                 // We can have empty aggregates, especially arrays, which shouldn't be initialised, but
                 // otherwise use a store.
                 let var_ty = local_var.get_type(context);
@@ -3515,9 +3695,15 @@ impl<'eng> FnCompiler<'eng> {
                             }
                             (
                                 ProjectionKind::ArrayIndex { index, .. },
-                                TypeInfo::Array(elem_ty, _),
+                                TypeInfo::Array(elem_ty, length),
                             ) => {
                                 cur_type_id = elem_ty.type_id;
+                                self.check_const_array_index_bounds(
+                                    context,
+                                    md_mgr,
+                                    index,
+                                    length.val() as u64,
+                                )?;
                                 let val = return_on_termination_or_extract!(
                                     self.compile_expression_to_value(context, md_mgr, index)?
                                 );
@@ -3750,9 +3936,38 @@ impl<'eng> FnCompiler<'eng> {
                 )
             })?;
 
-        let index_expr_span = index_expr.span.clone();
+        let count = array_type.get_array_len(context).unwrap();
+        self.check_const_array_index_bounds(context, md_mgr, index_expr, count)?;
+
+        let index_val = return_on_termination_or_extract!(
+            self.compile_expression_to_value(context, md_mgr, index_expr)?
+        );
+
+        let elem_type = array_type.get_array_elem_type(context).ok_or_else(|| {
+            CompileError::Internal(
+                "Array type is already confirmed as an array. Getting the element type can't fail.",
+                array_expr.span.clone(),
+            )
+        })?;
+
+        let val = self
+            .current_block
+            .append(context)
+            .get_elem_ptr(array_val, elem_type, vec![index_val])
+            .add_metadatum(context, span_md_idx);
+        Ok(TerminatorValue::new(val, context))
+    }
 
-        // Perform a bounds check if the array index is a constant int.
+    /// Perform a bounds check if the array index is a constant int.
+    ///
+    /// Indices which can't be evaluated at compile time are not checked.
+    fn check_const_array_index_bounds(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        index_expr: &ty::TyExpression,
+        count: u64,
+    ) -> Result<(), CompileError> {
         if let Ok(Constant {
             value: ConstantValue::Uint(constant_value),
             ..
@@ -3765,33 +3980,15 @@ impl<'eng> FnCompiler<'eng> {
             Some(self),
             index_expr,
         ) {
-            let count = array_type.get_array_len(context).unwrap();
             if constant_value >= count {
                 return Err(CompileError::ArrayOutOfBounds {
                     index: constant_value,
                     count,
-                    span: index_expr_span,
+                    span: index_expr.span.clone(),
                 });
             }
         }
-
-        let index_val = return_on_termination_or_extract!(
-            self.compile_expression_to_value(context, md_mgr, index_expr)?
-        );
-
-        let elem_type = array_type.get_array_elem_type(context).ok_or_else(|| {
-            CompileError::Internal(
-                "Array type is already confirmed as an array. Getting the element type can't fail.",
-                array_expr.span.clone(),
-            )
-        })?;
-
-        let val = self
-            .current_block
-            .append(context)
-            .get_elem_ptr(array_val, elem_type, vec![index_val])
-            .add_metadatum(context, span_md_idx);
-        Ok(TerminatorValue::new(val, context))
+        Ok(())
     }
 
     fn compile_struct_expr(
@@ -419,6 +419,29 @@ impl CallPath {
 
             prefixes.extend(synonym_prefixes);
 
+            CallPath {
+                prefixes,
+                suffix: self.suffix.clone(),
+                is_absolute: true,
+            }
+        } else if let Some(synonym_path) =
+            namespace
+                .root()
+                .module_synonym(engines, namespace.mod_path(), &self.prefixes)
+        {
+            // If the path starts with the name of an imported module, replace that name with the
+            // path of the module, which is relative to the package root, unless it is external.
+            let is_external = namespace
+                .root_module()
+                .submodule(engines, &synonym_path[..1])
+                .is_some_and(|m| m.is_external);
+            let mut prefixes: Vec<Ident> = vec![];
+            if !is_external {
+                prefixes.push(namespace.root_module().name().clone());
+            }
+            prefixes.extend(synonym_path);
+            prefixes.extend(self.prefixes[1..].iter().cloned());
+
             CallPath {
                 prefixes,
                 suffix: self.suffix.clone(),
@@ -169,23 +169,29 @@ impl Declaration {
     #[allow(unused)]
     pub(crate) fn visibility(&self, decl_engine: &ParsedDeclEngine) -> Visibility {
         match self {
-            Declaration::TraitDeclaration(decl_id) => decl_engine.get_trait(decl_id).visibility,
+            Declaration::TraitDeclaration(decl_id) => {
+                decl_engine.get_trait(decl_id).visibility.clone()
+            }
             Declaration::ConstantDeclaration(decl_id) => {
-                decl_engine.get_constant(decl_id).visibility
+                decl_engine.get_constant(decl_id).visibility.clone()
             }
             Declaration::ConfigurableDeclaration(decl_id) => {
-                decl_engine.get_configurable(decl_id).visibility
+                decl_engine.get_configurable(decl_id).visibility.clone()
+            }
+            Declaration::StructDeclaration(decl_id) => {
+                decl_engine.get_struct(decl_id).visibility.clone()
+            }
+            Declaration::EnumDeclaration(decl_id) => {
+                decl_engine.get_enum(decl_id).visibility.clone()
             }
-            Declaration::StructDeclaration(decl_id) => decl_engine.get_struct(decl_id).visibility,
-            Declaration::EnumDeclaration(decl_id) => decl_engine.get_enum(decl_id).visibility,
             Declaration::EnumVariantDeclaration(decl) => {
-                decl_engine.get_enum(&decl.enum_ref).visibility
+                decl_engine.get_enum(&decl.enum_ref).visibility.clone()
             }
             Declaration::FunctionDeclaration(decl_id) => {
-                decl_engine.get_function(decl_id).visibility
+                decl_engine.get_function(decl_id).visibility.clone()
             }
             Declaration::TypeAliasDeclaration(decl_id) => {
-                decl_engine.get_type_alias(decl_id).visibility
+                decl_engine.get_type_alias(decl_id).visibility.clone()
             }
             Declaration::VariableDeclaration(_decl_id) => Visibility::Private,
             Declaration::ImplSelfOrTrait(_)
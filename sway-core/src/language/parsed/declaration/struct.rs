@@ -1,6 +1,6 @@
 use crate::{
     engine_threading::{EqWithEngines, PartialEqWithEngines, PartialEqWithEnginesContext},
-    language::Visibility,
+    language::{parsed::Expression, Visibility},
     transform,
     type_system::TypeParameter,
     TypeArgument,
@@ -49,6 +49,8 @@ pub struct StructField {
     pub attributes: transform::AttributesMap,
     pub(crate) span: Span,
     pub type_argument: TypeArgument,
+    /// The value of the field used when it is omitted from a struct instantiation.
+    pub default_value: Option<Expression>,
 }
 
 impl EqWithEngines for StructField {}
@@ -59,5 +61,6 @@ impl PartialEqWithEngines for StructField {
             && self.attributes == other.attributes
             && self.span == other.span
             && self.type_argument.eq(&other.type_argument, ctx)
+            && self.default_value.eq(&other.default_value, ctx)
     }
 }
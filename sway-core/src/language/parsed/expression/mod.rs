@@ -136,6 +136,29 @@ impl PartialEqWithEngines for MatchExpression {
     }
 }
 
+/// The `?` operator applied to `value`, desugared into a match on `value`.
+///
+/// Which set of branches is used depends on the return type of the enclosing function,
+/// which is only known during type checking: `option_branches` unwrap an `Option` and
+/// return its `None`, while `result_branches` unwrap a `Result` and return its `Err`.
+#[derive(Debug, Clone)]
+pub struct TryExpression {
+    pub value: Box<Expression>,
+    pub option_branches: Vec<MatchBranch>,
+    pub result_branches: Vec<MatchBranch>,
+    /// The span of the `?` operator.
+    pub question_mark_span: Span,
+}
+
+impl EqWithEngines for TryExpression {}
+impl PartialEqWithEngines for TryExpression {
+    fn eq(&self, other: &Self, ctx: &PartialEqWithEnginesContext) -> bool {
+        self.value.eq(&other.value, ctx)
+            && self.option_branches.eq(&other.option_branches, ctx)
+            && self.result_branches.eq(&other.result_branches, ctx)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MethodApplicationExpression {
     pub method_name_binding: TypeBinding<MethodName>,
@@ -379,6 +402,18 @@ impl PartialEqWithEngines for WhileLoopExpression {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct LoopExpression {
+    pub body: CodeBlock,
+}
+
+impl EqWithEngines for LoopExpression {}
+impl PartialEqWithEngines for LoopExpression {
+    fn eq(&self, other: &Self, ctx: &PartialEqWithEnginesContext) -> bool {
+        self.body.eq(&other.body, ctx)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ForLoopExpression {
     pub desugared: Box<Expression>,
@@ -428,6 +463,8 @@ pub enum ExpressionKind {
     CodeBlock(CodeBlock),
     If(IfExpression),
     Match(MatchExpression),
+    /// A `value?` expression.
+    Try(Box<TryExpression>),
     // separated into other struct for parsing reasons
     Asm(Box<AsmExpression>),
     MethodApplication(Box<MethodApplicationExpression>),
@@ -467,9 +504,14 @@ pub enum ExpressionKind {
     /// A control flow element which loops continually until some boolean expression evaluates as
     /// `false`.
     WhileLoop(WhileLoopExpression),
+    /// A control flow element which loops continually until a `break` is reached.
+    /// Evaluates to the value of the `break`.
+    Loop(LoopExpression),
     /// A control flow element which loops between values of an iterator.
     ForLoop(ForLoopExpression),
-    Break,
+    /// Exits the enclosing loop. Within a `loop`, the optional value becomes the value
+    /// of the `loop`.
+    Break(Option<Box<Expression>>),
     Continue,
     Reassignment(ReassignmentExpression),
     /// An implicit return expression is different from a [Expression::Return] because
@@ -523,6 +565,7 @@ impl PartialEqWithEngines for ExpressionKind {
             (ExpressionKind::CodeBlock(lhs), ExpressionKind::CodeBlock(rhs)) => lhs.eq(rhs, ctx),
             (ExpressionKind::If(lhs), ExpressionKind::If(rhs)) => lhs.eq(rhs, ctx),
             (ExpressionKind::Match(lhs), ExpressionKind::Match(rhs)) => lhs.eq(rhs, ctx),
+            (ExpressionKind::Try(lhs), ExpressionKind::Try(rhs)) => lhs.eq(rhs, ctx),
             (ExpressionKind::Asm(lhs), ExpressionKind::Asm(rhs)) => lhs.eq(rhs, ctx),
             (ExpressionKind::MethodApplication(lhs), ExpressionKind::MethodApplication(rhs)) => {
                 lhs.eq(rhs, ctx)
@@ -540,8 +583,9 @@ impl PartialEqWithEngines for ExpressionKind {
                 lhs.eq(rhs, ctx)
             }
             (ExpressionKind::WhileLoop(lhs), ExpressionKind::WhileLoop(rhs)) => lhs.eq(rhs, ctx),
+            (ExpressionKind::Loop(lhs), ExpressionKind::Loop(rhs)) => lhs.eq(rhs, ctx),
             (ExpressionKind::ForLoop(lhs), ExpressionKind::ForLoop(rhs)) => lhs.eq(rhs, ctx),
-            (ExpressionKind::Break, ExpressionKind::Break) => true,
+            (ExpressionKind::Break(lhs), ExpressionKind::Break(rhs)) => lhs.eq(rhs, ctx),
             (ExpressionKind::Continue, ExpressionKind::Continue) => true,
             (ExpressionKind::Reassignment(lhs), ExpressionKind::Reassignment(rhs)) => {
                 lhs.eq(rhs, ctx)
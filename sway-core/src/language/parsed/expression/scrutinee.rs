@@ -43,6 +43,17 @@ pub enum Scrutinee {
         elems: Vec<Scrutinee>,
         span: Span,
     },
+    Slice {
+        /// The elements preceding the rest pattern `..`,
+        /// or all of the elements if there is no rest pattern.
+        prefix: Vec<Scrutinee>,
+        /// The scrutinee that matches the elements covered by the rest pattern, if any.
+        /// It is a [Scrutinee::CatchAll] for `..` and a [Scrutinee::Variable] for `name @ ..`.
+        rest: Option<Box<Scrutinee>>,
+        /// The elements following the rest pattern `..`.
+        suffix: Vec<Scrutinee>,
+        span: Span,
+    },
     // this is to handle parser recovery
     Error {
         spans: Box<[Span]>,
@@ -117,6 +128,25 @@ impl PartialEqWithEngines for Scrutinee {
                     span: r_span,
                 },
             ) => elems.eq(r_elems, ctx) && span.eq(r_span),
+            (
+                Scrutinee::Slice {
+                    prefix,
+                    rest,
+                    suffix,
+                    span,
+                },
+                Scrutinee::Slice {
+                    prefix: r_prefix,
+                    rest: r_rest,
+                    suffix: r_suffix,
+                    span: r_span,
+                },
+            ) => {
+                prefix.eq(r_prefix, ctx)
+                    && rest.eq(r_rest, ctx)
+                    && suffix.eq(r_suffix, ctx)
+                    && span.eq(r_span)
+            }
             (
                 Scrutinee::Error { spans, err },
                 Scrutinee::Error {
@@ -177,6 +207,7 @@ impl Spanned for Scrutinee {
             Scrutinee::StructScrutinee { span, .. } => span.clone(),
             Scrutinee::EnumScrutinee { span, .. } => span.clone(),
             Scrutinee::Tuple { span, .. } => span.clone(),
+            Scrutinee::Slice { span, .. } => span.clone(),
             Scrutinee::Error { spans, .. } => spans
                 .iter()
                 .cloned()
@@ -280,6 +311,11 @@ impl Scrutinee {
                 .iter()
                 .flat_map(|scrutinee| scrutinee.gather_approximate_typeinfo_dependencies())
                 .collect::<Vec<TypeInfo>>(),
+            Scrutinee::Slice { prefix, suffix, .. } => prefix
+                .iter()
+                .chain(suffix.iter())
+                .flat_map(|scrutinee| scrutinee.gather_approximate_typeinfo_dependencies())
+                .collect::<Vec<TypeInfo>>(),
             Scrutinee::Literal { .. }
             | Scrutinee::CatchAll { .. }
             | Scrutinee::AmbiguousSingleIdent(..)
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     fmt::{self, Debug},
     hash::{Hash, Hasher},
 };
@@ -9,7 +10,7 @@ use sway_types::{Ident, Span};
 use crate::{
     decl_engine::*,
     engine_threading::*,
-    language::ty::*,
+    language::{ty::*, Visibility},
     semantic_analysis::{
         TypeCheckAnalysis, TypeCheckAnalysisContext, TypeCheckContext, TypeCheckFinalization,
         TypeCheckFinalizationContext,
@@ -142,9 +143,14 @@ impl GetDeclIdent for TyAstNode {
 
 impl TyAstNode {
     /// Returns `true` if this AST node will be exported in a library, i.e. it is a public declaration.
+    ///
+    /// Declarations with a restricted visibility, e.g. `pub(crate)`, are not exported.
     pub(crate) fn is_public(&self, decl_engine: &DeclEngine) -> bool {
         match &self.content {
-            TyAstNodeContent::Declaration(decl) => decl.visibility(decl_engine).is_public(),
+            TyAstNodeContent::Declaration(decl) => match decl.visibility(decl_engine) {
+                Visibility::Public => true,
+                Visibility::Private | Visibility::Restricted(_) => false,
+            },
             TyAstNodeContent::Expression(_)
             | TyAstNodeContent::SideEffect(_)
             | TyAstNodeContent::Error(_, _) => false,
@@ -172,7 +178,8 @@ impl TyAstNode {
         }
     }
 
-    /// Check to see if this node is a function declaration of a function annotated as test.
+    /// Check to see if this node is a function declaration of a function annotated as test or
+    /// bench.
     pub(crate) fn is_test_function(&self, decl_engine: &DeclEngine) -> bool {
         match &self {
             TyAstNode {
@@ -186,6 +193,7 @@ impl TyAstNode {
                 let fn_decl = decl_engine.get_function(decl_id);
                 let TyFunctionDecl { attributes, .. } = &*fn_decl;
                 attributes.contains_key(&AttributeKind::Test)
+                    || attributes.contains_key(&AttributeKind::Bench)
             }
             _ => false,
         }
@@ -275,10 +283,36 @@ impl TyAstNode {
         }
     }
 
+    /// Adds the calls made by the functions and constants declared by this node to `ctx`.
+    pub(crate) fn analyze_calls(
+        &self,
+        engines: &Engines,
+        handler: &Handler,
+        ctx: &mut TypeCheckAnalysisContext,
+    ) {
+        match &self.content {
+            TyAstNodeContent::Declaration(TyDecl::FunctionDecl(decl)) => {
+                let _ = decl.decl_id.type_check_analyze(handler, ctx);
+            }
+            TyAstNodeContent::Declaration(TyDecl::ConstantDecl(decl)) => {
+                let const_decl = engines.de().get_constant(&decl.decl_id);
+                let _ = const_decl.type_check_analyze(handler, ctx);
+            }
+            TyAstNodeContent::Declaration(TyDecl::ImplSelfOrTrait(decl)) => {
+                let decl = engines.de().get(&decl.decl_id);
+                for item in decl.items.iter() {
+                    let _ = item.type_check_analyze(handler, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
     pub(crate) fn check_recursive(
         &self,
         engines: &Engines,
         handler: &Handler,
+        const_eval_fns: &HashSet<DeclId<TyFunctionDecl>>,
     ) -> Result<(), ErrorEmitted> {
         handler.scope(|handler| {
             match &self.content {
@@ -291,14 +325,14 @@ impl TyAstNode {
                         let fn_decl_id = decl.decl_id;
                         let mut ctx = TypeCheckAnalysisContext::new(engines);
                         let _ = fn_decl_id.type_check_analyze(handler, &mut ctx);
-                        let _ = ctx.check_recursive_calls(handler);
+                        let _ = ctx.check_recursive_calls(handler, const_eval_fns);
                     }
                     TyDecl::ImplSelfOrTrait(decl) => {
                         let decl = engines.de().get(&decl.decl_id);
                         for item in decl.items.iter() {
                             let mut ctx = TypeCheckAnalysisContext::new(engines);
                             let _ = item.type_check_analyze(handler, &mut ctx);
-                            let _ = ctx.check_recursive_calls(handler);
+                            let _ = ctx.check_recursive_calls(handler, const_eval_fns);
                         }
                     }
                     TyDecl::AbiDecl(_)
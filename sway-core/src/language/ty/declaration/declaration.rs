@@ -786,26 +786,28 @@ impl TyDecl {
     pub(crate) fn visibility(&self, decl_engine: &DeclEngine) -> Visibility {
         match self {
             TyDecl::TraitDecl(TraitDecl { decl_id, .. }) => {
-                decl_engine.get_trait(decl_id).visibility
+                decl_engine.get_trait(decl_id).visibility.clone()
             }
             TyDecl::ConstantDecl(ConstantDecl { decl_id, .. }) => {
-                decl_engine.get_constant(decl_id).visibility
+                decl_engine.get_constant(decl_id).visibility.clone()
             }
             TyDecl::ConfigurableDecl(ConfigurableDecl { decl_id, .. }) => {
-                decl_engine.get_configurable(decl_id).visibility
+                decl_engine.get_configurable(decl_id).visibility.clone()
             }
             TyDecl::StructDecl(StructDecl { decl_id, .. }) => {
-                decl_engine.get_struct(decl_id).visibility
+                decl_engine.get_struct(decl_id).visibility.clone()
+            }
+            TyDecl::EnumDecl(EnumDecl { decl_id, .. }) => {
+                decl_engine.get_enum(decl_id).visibility.clone()
             }
-            TyDecl::EnumDecl(EnumDecl { decl_id, .. }) => decl_engine.get_enum(decl_id).visibility,
             TyDecl::EnumVariantDecl(EnumVariantDecl { enum_ref, .. }) => {
-                decl_engine.get_enum(enum_ref.id()).visibility
+                decl_engine.get_enum(enum_ref.id()).visibility.clone()
             }
             TyDecl::FunctionDecl(FunctionDecl { decl_id, .. }) => {
-                decl_engine.get_function(decl_id).visibility
+                decl_engine.get_function(decl_id).visibility.clone()
             }
             TyDecl::TypeAliasDecl(TypeAliasDecl { decl_id, .. }) => {
-                decl_engine.get_type_alias(decl_id).visibility
+                decl_engine.get_type_alias(decl_id).visibility.clone()
             }
             TyDecl::GenericTypeForFunctionScope(_)
             | TyDecl::ImplSelfOrTrait(_)
@@ -333,7 +333,7 @@ impl TyFunctionDecl {
             attributes: Default::default(),
             is_contract_call: false,
             parameters: Default::default(),
-            visibility: *visibility,
+            visibility: visibility.clone(),
             return_type: return_type.clone(),
             type_parameters: Default::default(),
             where_clause: where_clause.clone(),
@@ -424,11 +424,19 @@ impl TyFunctionDecl {
         matches!(self.kind, TyFunctionDeclKind::Main)
     }
 
-    /// Whether or not this function is a unit test, i.e. decorated with `#[test]`.
+    /// Whether or not this function is a unit test or a benchmark, i.e. decorated with
+    /// `#[test]` or `#[bench]`.
     pub fn is_test(&self) -> bool {
         //TODO match kind to Test
         self.attributes
             .contains_key(&transform::AttributeKind::Test)
+            || self.is_bench()
+    }
+
+    /// Whether or not this function is a benchmark, i.e. decorated with `#[bench]`.
+    pub fn is_bench(&self) -> bool {
+        self.attributes
+            .contains_key(&transform::AttributeKind::Bench)
     }
 
     pub fn inline(&self) -> Option<Inline> {
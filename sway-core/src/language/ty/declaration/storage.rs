@@ -250,6 +250,7 @@ impl TyStorageDecl {
                     span: span.clone(),
                     type_argument: type_argument.clone(),
                     attributes: attributes.clone(),
+                    default_value: None,
                 },
             )
             .collect()
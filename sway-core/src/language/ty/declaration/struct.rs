@@ -9,7 +9,7 @@ use crate::{
     engine_threading::*,
     error::module_can_be_changed,
     has_changes,
-    language::{parsed::StructDeclaration, CallPath, Visibility},
+    language::{parsed::StructDeclaration, ty::TyExpression, CallPath, Visibility},
     semantic_analysis::type_check_context::MonomorphizeHelper,
     transform,
     type_system::*,
@@ -189,6 +189,8 @@ pub struct TyStructField {
     pub span: Span,
     pub type_argument: TypeArgument,
     pub attributes: transform::AttributesMap,
+    /// The value of the field used when it is omitted from a struct instantiation.
+    pub default_value: Option<Box<TyExpression>>,
 }
 
 impl TyStructField {
@@ -242,6 +244,7 @@ impl HashWithEngines for TyStructField {
             // reliable source of obj v. obj distinction
             span: _,
             attributes: _,
+            default_value: _,
         } = self;
         visibility.hash(state);
         name.hash(state);
@@ -265,6 +268,7 @@ impl OrdWithEngines for TyStructField {
             span: _,
             attributes: _,
             visibility: _,
+            default_value: _,
         } = self;
         let TyStructField {
             name: rn,
@@ -273,6 +277,7 @@ impl OrdWithEngines for TyStructField {
             span: _,
             attributes: _,
             visibility: _,
+            default_value: _,
         } = other;
         ln.cmp(rn).then_with(|| lta.cmp(rta, ctx))
     }
@@ -280,6 +285,9 @@ impl OrdWithEngines for TyStructField {
 
 impl SubstTypes for TyStructField {
     fn subst_inner(&mut self, type_mapping: &TypeSubstMap, ctx: &SubstTypesContext) -> HasChanges {
-        self.type_argument.subst_inner(type_mapping, ctx)
+        has_changes! {
+            self.type_argument.subst(type_mapping, ctx);
+            self.default_value.subst(type_mapping, ctx);
+        }
     }
 }
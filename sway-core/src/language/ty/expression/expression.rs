@@ -337,9 +337,15 @@ impl CollectTypesMetadata for TyExpression {
                     res.append(&mut content.collect_types_metadata(handler, ctx)?);
                 }
             }
+            Loop { body } => {
+                for content in body.contents.iter() {
+                    res.append(&mut content.collect_types_metadata(handler, ctx)?);
+                }
+            }
             ForLoop { desugared } => {
                 res.append(&mut desugared.collect_types_metadata(handler, ctx)?);
             }
+            Break(Some(exp)) => res.append(&mut exp.collect_types_metadata(handler, ctx)?),
             ImplicitReturn(exp) | Return(exp) => {
                 res.append(&mut exp.collect_types_metadata(handler, ctx)?)
             }
@@ -353,7 +359,7 @@ impl CollectTypesMetadata for TyExpression {
             | StorageAccess { .. }
             | Literal(_)
             | AbiName(_)
-            | Break
+            | Break(None)
             | Continue
             | FunctionParameter => {}
             Reassignment(reassignment) => {
@@ -160,10 +160,13 @@ pub enum TyExpressionVariant {
         condition: Box<TyExpression>,
         body: TyCodeBlock,
     },
+    Loop {
+        body: TyCodeBlock,
+    },
     ForLoop {
         desugared: Box<TyExpression>,
     },
-    Break,
+    Break(Option<Box<TyExpression>>),
     Continue,
     Reassignment(Box<TyReassignment>),
     ImplicitReturn(Box<TyExpression>),
@@ -431,6 +434,8 @@ impl PartialEqWithEngines for TyExpressionVariant {
                     condition: r_condition,
                 },
             ) => l_body.eq(r_body, ctx) && l_condition.eq(r_condition, ctx),
+            (Self::Loop { body: l_body }, Self::Loop { body: r_body }) => l_body.eq(r_body, ctx),
+            (Self::Break(l_exp), Self::Break(r_exp)) => l_exp.eq(r_exp, ctx),
             (l, r) => std::mem::discriminant(l) == std::mem::discriminant(r),
         }
     }
@@ -631,10 +636,18 @@ impl HashWithEngines for TyExpressionVariant {
                 condition.hash(state, engines);
                 body.hash(state, engines);
             }
+            Self::Loop { body } => {
+                body.hash(state, engines);
+            }
             Self::ForLoop { desugared } => {
                 desugared.hash(state, engines);
             }
-            Self::Break | Self::Continue | Self::FunctionParameter => {}
+            Self::Break(exp) => {
+                if let Some(exp) = exp {
+                    exp.hash(state, engines);
+                }
+            }
+            Self::Continue | Self::FunctionParameter => {}
             Self::Reassignment(exp) => {
                 exp.hash(state, engines);
             }
@@ -777,8 +790,10 @@ impl SubstTypes for TyExpressionVariant {
                 condition.subst(type_mapping, ctx);
                 body.subst(type_mapping, ctx)
             }
+            Loop { ref mut body } => body.subst(type_mapping, ctx),
             ForLoop { ref mut desugared } => desugared.subst(type_mapping, ctx),
-            Break => HasChanges::No,
+            Break(Some(expr)) => expr.subst(type_mapping, ctx),
+            Break(None) => HasChanges::No,
             Continue => HasChanges::No,
             Reassignment(reassignment) => reassignment.subst(type_mapping, ctx),
             ImplicitReturn(expr) | Return(expr) => expr.subst(type_mapping, ctx),
@@ -982,10 +997,12 @@ impl ReplaceDecls for TyExpressionVariant {
                     }
                     Ok(has_changes)
                 }
+                Loop { ref mut body } => body.replace_decls(decl_mapping, handler, ctx),
                 ForLoop { ref mut desugared } => {
                     desugared.replace_decls(decl_mapping, handler, ctx)
                 }
-                Break => Ok(false),
+                Break(Some(expr)) => expr.replace_decls(decl_mapping, handler, ctx),
+                Break(None) => Ok(false),
                 Continue => Ok(false),
                 Reassignment(reassignment) => {
                     reassignment.replace_decls(decl_mapping, handler, ctx)
@@ -1119,10 +1136,16 @@ impl TypeCheckAnalysis for TyExpressionVariant {
                 condition.type_check_analyze(handler, ctx)?;
                 body.type_check_analyze(handler, ctx)?;
             }
+            TyExpressionVariant::Loop { body } => {
+                body.type_check_analyze(handler, ctx)?;
+            }
             TyExpressionVariant::ForLoop { desugared } => {
                 desugared.type_check_analyze(handler, ctx)?;
             }
-            TyExpressionVariant::Break => {}
+            TyExpressionVariant::Break(Some(node)) => {
+                node.type_check_analyze(handler, ctx)?;
+            }
+            TyExpressionVariant::Break(None) => {}
             TyExpressionVariant::Continue => {}
             TyExpressionVariant::Reassignment(node) => {
                 node.type_check_analyze(handler, ctx)?;
@@ -1242,10 +1265,16 @@ impl TypeCheckFinalization for TyExpressionVariant {
                     condition.type_check_finalize(handler, ctx)?;
                     body.type_check_finalize(handler, ctx)?;
                 }
+                TyExpressionVariant::Loop { body } => {
+                    body.type_check_finalize(handler, ctx)?;
+                }
                 TyExpressionVariant::ForLoop { desugared } => {
                     desugared.type_check_finalize(handler, ctx)?;
                 }
-                TyExpressionVariant::Break => {}
+                TyExpressionVariant::Break(Some(node)) => {
+                    node.type_check_finalize(handler, ctx)?;
+                }
+                TyExpressionVariant::Break(None) => {}
                 TyExpressionVariant::Continue => {}
                 TyExpressionVariant::Reassignment(node) => {
                     node.type_check_finalize(handler, ctx)?;
@@ -1353,10 +1382,14 @@ impl UpdateConstantExpression for TyExpressionVariant {
                 condition.update_constant_expression(engines, implementing_type);
                 body.update_constant_expression(engines, implementing_type);
             }
+            Loop { ref mut body } => {
+                body.update_constant_expression(engines, implementing_type);
+            }
             ForLoop { ref mut desugared } => {
                 desugared.update_constant_expression(engines, implementing_type);
             }
-            Break => (),
+            Break(Some(expr)) => expr.update_constant_expression(engines, implementing_type),
+            Break(None) => (),
             Continue => (),
             Reassignment(reassignment) => {
                 reassignment.update_constant_expression(engines, implementing_type)
@@ -1510,8 +1543,12 @@ impl DebugWithEngines for TyExpressionVariant {
             TyExpressionVariant::WhileLoop { condition, .. } => {
                 format!("while loop on {:?}", engines.help_out(&**condition))
             }
+            TyExpressionVariant::Loop { .. } => "loop".to_string(),
             TyExpressionVariant::ForLoop { .. } => "for loop".to_string(),
-            TyExpressionVariant::Break => "break".to_string(),
+            TyExpressionVariant::Break(Some(exp)) => {
+                format!("break {:?}", engines.help_out(&**exp))
+            }
+            TyExpressionVariant::Break(None) => "break".to_string(),
             TyExpressionVariant::Continue => "continue".to_string(),
             TyExpressionVariant::Reassignment(reassignment) => {
                 let target = match &reassignment.lhs {
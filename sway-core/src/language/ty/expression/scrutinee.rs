@@ -34,6 +34,16 @@ pub enum TyScrutineeVariant {
         instantiation_call_path: CallPath,
     },
     Tuple(Vec<TyScrutinee>),
+    Slice {
+        /// The elements preceding the rest pattern `..`,
+        /// or all of the elements if there is no rest pattern.
+        prefix: Vec<TyScrutinee>,
+        /// The scrutinee that matches the array of the elements covered
+        /// by the rest pattern, if any.
+        rest: Option<Box<TyScrutinee>>,
+        /// The elements following the rest pattern `..`.
+        suffix: Vec<TyScrutinee>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_types::Span;
@@ -6,7 +6,7 @@ use sway_types::Span;
 use crate::{
     decl_engine::{DeclEngine, DeclEngineGet, DeclId, DeclRef, DeclRefFunction},
     language::{ty::*, HasModule, HasSubmodules, ModName},
-    semantic_analysis::namespace,
+    semantic_analysis::{namespace, TypeCheckAnalysisContext},
     transform::{self, AllowDeprecatedState},
     Engines,
 };
@@ -156,18 +156,38 @@ impl TyModule {
         }
     }
 
+    /// Adds the calls made by the functions and constants of this module and its submodules
+    /// to `ctx`.
+    pub(crate) fn analyze_calls(
+        &self,
+        engines: &Engines,
+        handler: &Handler,
+        ctx: &mut TypeCheckAnalysisContext,
+    ) {
+        for (_, submodule) in self.submodules.iter() {
+            submodule.module.analyze_calls(engines, handler, ctx);
+        }
+
+        for node in self.all_nodes.iter() {
+            node.analyze_calls(engines, handler, ctx);
+        }
+    }
+
     pub(crate) fn check_recursive(
         &self,
         engines: &Engines,
         handler: &Handler,
+        const_eval_fns: &HashSet<DeclId<TyFunctionDecl>>,
     ) -> Result<(), ErrorEmitted> {
         handler.scope(|handler| {
             for (_, submodule) in self.submodules.iter() {
-                let _ = submodule.module.check_recursive(engines, handler);
+                let _ = submodule
+                    .module
+                    .check_recursive(engines, handler, const_eval_fns);
             }
 
             for node in self.all_nodes.iter() {
-                let _ = node.check_recursive(engines, handler);
+                let _ = node.check_recursive(engines, handler, const_eval_fns);
             }
 
             Ok(())
@@ -4,6 +4,7 @@ use crate::{
     decl_engine::*,
     fuel_prelude::fuel_tx::StorageSlot,
     language::{parsed, ty::*, Purity},
+    semantic_analysis::TypeCheckAnalysisContext,
     transform::AllowDeprecatedState,
     type_system::*,
     types::*,
@@ -445,7 +446,13 @@ impl TyProgram {
         engines: &Engines,
         handler: &Handler,
     ) -> Result<(), ErrorEmitted> {
-        self.root.check_recursive(engines, handler)
+        // Functions only used by constants are evaluated at compile time and may be recursive.
+        let mut ctx = TypeCheckAnalysisContext::new(engines);
+        self.root
+            .analyze_calls(engines, &Handler::default(), &mut ctx);
+        let const_eval_fns = ctx.const_eval_fns();
+
+        self.root.check_recursive(engines, handler, &const_eval_fns)
     }
 }
 
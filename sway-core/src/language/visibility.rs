@@ -1,7 +1,15 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+use std::fmt;
+
+use sway_types::Ident;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Visibility {
     Private,
     Public,
+    /// Visible only within the module at the given path, relative to the root
+    /// of the package the item is declared in, and its submodules.
+    /// The empty path stands for `pub(crate)`, any other for `pub(in path)`.
+    Restricted(Vec<Ident>),
 }
 
 impl Visibility {
@@ -12,3 +20,21 @@ impl Visibility {
         !self.is_public()
     }
 }
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Visibility::Private => write!(f, "private"),
+            Visibility::Public => write!(f, "pub"),
+            Visibility::Restricted(path) if path.is_empty() => write!(f, "pub(crate)"),
+            Visibility::Restricted(path) => {
+                let path = path
+                    .iter()
+                    .map(|ident| ident.as_str())
+                    .collect::<Vec<_>>()
+                    .join("::");
+                write!(f, "pub(in {path})")
+            }
+        }
+    }
+}
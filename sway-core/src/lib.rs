@@ -30,7 +30,9 @@ use crate::source_map::SourceMap;
 pub use asm_generation::from_ir::compile_ir_context_to_finalized_asm;
 use asm_generation::FinalizedAsm;
 pub use asm_generation::{CompiledBytecode, FinalizedEntry};
-pub use build_config::{BuildConfig, BuildTarget, LspConfig, OptLevel, PrintAsm, PrintIr};
+pub use build_config::{
+    BuildConfig, BuildTarget, EmitKind, LspConfig, OptLevel, OverflowBehavior, PrintAsm, PrintIr,
+};
 use control_flow_analysis::ControlFlowGraph;
 pub use debug_generation::write_dwarf;
 use indexmap::IndexMap;
@@ -111,6 +113,7 @@ pub fn parse(
             None,
             config.build_target,
             config.include_tests,
+            &config.features,
             config.experimental,
             config.lsp_mode.as_ref(),
         )
@@ -164,18 +167,7 @@ fn module_attrs_to_map(
             let args = attr
                 .args
                 .as_ref()
-                .map(|parens| {
-                    parens
-                        .get()
-                        .into_iter()
-                        .cloned()
-                        .map(|arg| AttributeArg {
-                            name: arg.name.clone(),
-                            value: arg.value.clone(),
-                            span: arg.span(),
-                        })
-                        .collect()
-                })
+                .map(|parens| parens.get().into_iter().map(AttributeArg::from).collect())
                 .unwrap_or_else(Vec::new);
 
             let attribute = Attribute {
@@ -208,7 +200,7 @@ fn parse_in_memory(
     let module = sway_parse::parse_file(handler, src, None)?;
 
     let (kind, tree) = to_parsed_lang::convert_parse_tree(
-        &mut to_parsed_lang::Context::new(BuildTarget::EVM, experimental),
+        &mut to_parsed_lang::Context::new(BuildTarget::EVM, false, vec![], experimental),
         handler,
         engines,
         module.value.clone(),
@@ -256,6 +248,7 @@ fn parse_submodules(
     module_dir: &Path,
     build_target: BuildTarget,
     include_tests: bool,
+    features: &[String],
     experimental: ExperimentalFlags,
     lsp_mode: Option<&LspConfig>,
 ) -> Submodules {
@@ -288,6 +281,7 @@ fn parse_submodules(
             Some(submod.name.as_str()),
             build_target,
             include_tests,
+            features,
             experimental,
             lsp_mode,
         ) {
@@ -341,6 +335,7 @@ fn parse_module_tree(
     module_name: Option<&str>,
     build_target: BuildTarget,
     include_tests: bool,
+    features: &[String],
     experimental: ExperimentalFlags,
     lsp_mode: Option<&LspConfig>,
 ) -> Result<ParsedModuleTree, ErrorEmitted> {
@@ -361,13 +356,19 @@ fn parse_module_tree(
         module_dir,
         build_target,
         include_tests,
+        features,
         experimental,
         lsp_mode,
     );
 
     // Convert from the raw parsed module to the `ParseTree` ready for type-check.
     let (kind, tree) = to_parsed_lang::convert_parse_tree(
-        &mut to_parsed_lang::Context::new(build_target, experimental),
+        &mut to_parsed_lang::Context::new(
+            build_target,
+            include_tests,
+            features.to_vec(),
+            experimental,
+        ),
         handler,
         engines,
         module.value.clone(),
@@ -547,7 +548,9 @@ pub fn build_module_dep_graph(
     Ok(())
 }
 
-pub struct CompiledAsm(pub FinalizedAsm);
+/// The finalized ASM of a program, along with the textual form of its final, optimized IR if it
+/// was requested with [BuildConfig::with_emit_ir].
+pub struct CompiledAsm(pub FinalizedAsm, pub Option<String>);
 
 pub fn parsed_to_ast(
     handler: &Handler,
@@ -667,6 +670,9 @@ pub fn parsed_to_ast(
             new_encoding: experimental.new_encoding,
         },
     );
+    if let Some(const_eval_limit) = build_config.and_then(|config| config.const_eval_limit) {
+        ctx.const_eval_limit = const_eval_limit;
+    }
     let mut md_mgr = MetadataManager::default();
     let module = Module::new(&mut ctx, Kind::Contract);
     if let Err(e) = ir_generation::compile::compile_constants(
@@ -686,6 +692,31 @@ pub fn parsed_to_ast(
         handler.emit_warn(warn);
     }
 
+    // Redundant clone analysis
+    if build_config.is_some_and(|config| config.warn_redundant_clone) {
+        let redundant_clone_warnings =
+            semantic_analysis::redundant_clone_analysis::analyze_program(engines, &typed_program);
+        for warn in redundant_clone_warnings {
+            handler.emit_warn(warn);
+        }
+    }
+
+    // Function complexity analysis
+    if let Some(config) = build_config.filter(|config| {
+        config.max_function_parameters.is_some() || config.max_cyclomatic_complexity.is_some()
+    }) {
+        let function_complexity_warnings =
+            semantic_analysis::function_complexity_analysis::analyze_program(
+                engines,
+                &typed_program,
+                config.max_function_parameters,
+                config.max_cyclomatic_complexity,
+            );
+        for warn in function_complexity_warnings {
+            handler.emit_warn(warn);
+        }
+    }
+
     // Check that all storage initializers can be evaluated at compile time.
     let typed_wiss_res = typed_program.get_typed_program_with_initialized_storage_slots(
         handler,
@@ -848,14 +879,13 @@ pub fn ast_to_asm(
         Err(err) => return Err(*err),
     };
 
-    let asm = match compile_ast_to_ir_to_asm(handler, engines, typed_program, build_config) {
-        Ok(res) => res,
+    match compile_ast_to_ir_to_asm(handler, engines, typed_program, build_config) {
+        Ok(res) => Ok(res),
         Err(err) => {
             handler.dedup();
-            return Err(err);
+            Err(err)
         }
-    };
-    Ok(CompiledAsm(asm))
+    }
 }
 
 pub(crate) fn compile_ast_to_ir_to_asm(
@@ -863,7 +893,7 @@ pub(crate) fn compile_ast_to_ir_to_asm(
     engines: &Engines,
     program: &ty::TyProgram,
     build_config: &BuildConfig,
-) -> Result<FinalizedAsm, ErrorEmitted> {
+) -> Result<CompiledAsm, ErrorEmitted> {
     // The IR pipeline relies on type information being fully resolved.
     // If type information is found to still be generic or unresolved inside of
     // IR, this is considered an internal compiler error. To resolve this situation,
@@ -880,6 +910,7 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         build_config.include_tests,
         engines,
         build_config.experimental,
+        build_config.const_eval_limit,
     ) {
         Ok(ir) => ir,
         Err(errors) => {
@@ -971,7 +1002,9 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         };
     res?;
 
-    compile_ir_context_to_finalized_asm(handler, &ir, Some(build_config))
+    let final_ir = build_config.emit_ir.then(|| ir.to_string());
+    let asm = compile_ir_context_to_finalized_asm(handler, &ir, Some(build_config))?;
+    Ok(CompiledAsm(asm, final_ir))
 }
 
 /// Given input Sway source code, compile to [CompiledBytecode], containing the asm in bytecode form.
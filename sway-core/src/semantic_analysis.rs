@@ -2,10 +2,13 @@
 pub mod ast_node;
 pub(crate) mod cei_pattern_analysis;
 pub(crate) mod coins_analysis;
+pub(crate) mod function_complexity_analysis;
 mod module;
 pub mod namespace;
+mod no_effect_analysis;
 mod node_dependencies;
 mod program;
+pub(crate) mod redundant_clone_analysis;
 pub mod symbol_collection_context;
 pub mod symbol_resolve;
 pub mod symbol_resolve_context;
@@ -117,7 +117,7 @@ impl ty::TyCodeBlock {
                             ty::TyAstNodeContent::Expression(ty::TyExpression {
                                 expression:
                                     ty::TyExpressionVariant::Return(_)
-                                    | ty::TyExpressionVariant::Break
+                                    | ty::TyExpressionVariant::Break(_)
                                     | ty::TyExpressionVariant::Continue,
                                 ..
                             }),
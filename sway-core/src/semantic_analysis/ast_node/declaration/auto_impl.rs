@@ -8,14 +8,20 @@ use crate::{
         Purity,
     },
     semantic_analysis::TypeCheckContext,
-    Engines, TypeId, TypeInfo, TypeParameter,
+    transform::{AttributeKind, AttributesMap},
+    type_system::TreatNumericAs,
+    Engines, TraitConstraint, TypeId, TypeInfo, TypeParameter,
 };
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
 use sway_parse::Parse;
-use sway_types::{integer_bits::IntegerBits, BaseIdent, Named, ProgramId, Span, Spanned};
+use sway_types::{
+    constants::{DERIVE_ORD_ARG_NAME, DERIVE_PARTIAL_ORD_ARG_NAME},
+    integer_bits::IntegerBits,
+    BaseIdent, Named, ProgramId, Span, Spanned,
+};
 
 /// Contains all information needed to implement AbiEncode
 pub struct EncodingAutoImplContext<'a, 'b>
@@ -287,6 +293,8 @@ where
     ) -> Result<TyAstNode, Handler> {
         let mut ctx = crate::transform::to_parsed_lang::Context::new(
             crate::BuildTarget::Fuel,
+            false,
+            vec![],
             self.ctx.experimental,
         );
 
@@ -367,6 +375,8 @@ where
     ) -> Result<TyAstNode, Handler> {
         let mut ctx = crate::transform::to_parsed_lang::Context::new(
             crate::BuildTarget::Fuel,
+            false,
+            vec![],
             self.ctx.experimental,
         );
 
@@ -499,6 +509,257 @@ where
         }
     }
 
+    /// Returns true if the `#[derive(...)]` attributes request an implementation of `Ord`.
+    ///
+    /// Sway has a single ordering trait, so deriving `PartialOrd` implements `Ord` as well.
+    fn derives_ord(attributes: &AttributesMap) -> bool {
+        attributes
+            .get(&AttributeKind::Derive)
+            .into_iter()
+            .flatten()
+            .flat_map(|attr| attr.args.iter())
+            .any(|arg| {
+                matches!(
+                    arg.name.as_str(),
+                    DERIVE_ORD_ARG_NAME | DERIVE_PARTIAL_ORD_ARG_NAME
+                )
+            })
+    }
+
+    /// Checks that the types of all the given struct fields or enum variants implement the
+    /// derived trait, emitting an error for each one that does not.
+    ///
+    /// Members whose types depend on type parameters are not checked, as the derived
+    /// implementation requires the type parameters to implement the trait instead.
+    fn check_derived_members<'m>(
+        &mut self,
+        handler: &Handler,
+        engines: &Engines,
+        trait_name: &str,
+        type_name: &BaseIdent,
+        member_kind: &'static str,
+        members: impl Iterator<Item = (&'m BaseIdent, TypeId, Span)>,
+    ) -> Result<(), ErrorEmitted> {
+        let constraint = TraitConstraint {
+            trait_name: BaseIdent::new_no_span(trait_name.to_string()).into(),
+            type_arguments: vec![],
+        };
+        handler.scope(|handler| {
+            for (member_name, type_id, span) in members {
+                if engines.te().get(type_id).is_unit()
+                    || !type_id.is_concrete(engines, TreatNumericAs::Concrete)
+                {
+                    continue;
+                }
+                if !self
+                    .ctx
+                    .check_type_impls_traits(type_id, std::slice::from_ref(&constraint))
+                {
+                    handler.emit_err(CompileError::DeriveMemberDoesNotImplementTrait {
+                        trait_name: trait_name.to_string(),
+                        type_name: type_name.to_string(),
+                        member_kind,
+                        member_name: member_name.to_string(),
+                        member_type: engines.help_out(type_id).to_string(),
+                        span,
+                    });
+                }
+            }
+            Ok(())
+        })
+    }
+
+    fn generate_ord_code(
+        &self,
+        name: &BaseIdent,
+        type_parameters: &[TypeParameter],
+        gt_body: String,
+        lt_body: String,
+    ) -> String {
+        let type_parameters_declaration =
+            self.generate_type_parameters_declaration_code(type_parameters);
+        let type_parameters_constraints =
+            self.generate_type_parameters_constraints_code(type_parameters, "Ord");
+
+        let name = name.as_str();
+
+        format!("#[allow(dead_code)] impl{type_parameters_declaration} Ord for {name}{type_parameters_declaration}{type_parameters_constraints} {{
+            #[allow(dead_code)] fn gt(self, other: Self) -> bool {{
+                {gt_body}
+            }}
+            #[allow(dead_code)] fn lt(self, other: Self) -> bool {{
+                {lt_body}
+            }}
+        }}")
+    }
+
+    /// Compares the fields of two structs in declaration order, so that the first field that
+    /// differs decides the result of `op`, which is either `gt` or `lt`.
+    ///
+    /// Fields of the unit type are always equal and are skipped.
+    fn generate_ord_struct_body(&self, engines: &Engines, decl: &TyStructDecl, op: &str) -> String {
+        let inverse_op = if op == "gt" { "lt" } else { "gt" };
+        let mut code = String::new();
+
+        for f in decl
+            .fields
+            .iter()
+            .filter(|f| !engines.te().get(f.type_argument.type_id).is_unit())
+        {
+            code.push_str(&format!(
+                "if self.{field_name}.{op}(other.{field_name}) {{ return true; }}
+                if self.{field_name}.{inverse_op}(other.{field_name}) {{ return false; }}\n",
+                field_name = f.name.as_str(),
+            ));
+        }
+
+        code.push_str("false");
+        code
+    }
+
+    /// Compares the variants of two enums in declaration order, and the values of the variants if
+    /// both enums are of the same variant.
+    fn generate_ord_enum_body(&self, engines: &Engines, decl: &TyEnumDecl, op: &str) -> String {
+        if decl.variants.is_empty() {
+            return "false".into();
+        }
+
+        let enum_name = decl.call_path.suffix.as_str();
+        let tag_arms = decl
+            .variants
+            .iter()
+            .map(|x| {
+                let name = x.name.as_str();
+                if engines.te().get(x.type_argument.type_id).is_unit() {
+                    format!("{enum_name}::{name} => {}u64, \n", x.tag)
+                } else {
+                    format!("{enum_name}::{name}(_) => {}u64, \n", x.tag)
+                }
+            })
+            .collect::<String>();
+        use std::fmt::Write;
+        let value_arms = decl
+            .variants
+            .iter()
+            .filter(|x| !engines.te().get(x.type_argument.type_id).is_unit())
+            .fold(String::new(), |mut arms, x| {
+                let name = x.name.as_str();
+                let _ = writeln!(
+                    &mut arms,
+                    "({enum_name}::{name}(a), {enum_name}::{name}(b)) => a.{op}(b), "
+                );
+                arms
+            });
+        let tag_op = if op == "gt" { ">" } else { "<" };
+
+        let mut code = String::new();
+        let _ = writeln!(&mut code, "let self_tag = match self {{ {tag_arms} }};");
+        let _ = writeln!(&mut code, "let other_tag = match other {{ {tag_arms} }};");
+        let _ = writeln!(
+            &mut code,
+            "if self_tag != other_tag {{ return self_tag {tag_op} other_tag; }}"
+        );
+        if value_arms.is_empty() {
+            let _ = write!(&mut code, "false");
+        } else {
+            let _ = write!(
+                &mut code,
+                "match (self, other) {{ {value_arms} _ => false, }}"
+            );
+        }
+
+        code
+    }
+
+    /// Implements the traits requested by the `#[derive(...)]` attributes of a struct or an enum
+    /// and returns their `AstNode`s.
+    pub fn generate_derives(
+        &mut self,
+        handler: &Handler,
+        engines: &Engines,
+        decl: &ty::TyDecl,
+    ) -> Vec<TyAstNode> {
+        if self.ctx.namespace.root().module.name().as_str() == "core" {
+            return vec![];
+        }
+
+        let (program_id, code) = match decl {
+            TyDecl::StructDecl(_) => {
+                let decl_id = decl.to_struct_decl(&Handler::default(), engines).unwrap();
+                let struct_decl = self.ctx.engines().de().get(&decl_id);
+                if !Self::derives_ord(&struct_decl.attributes) {
+                    return vec![];
+                }
+                let members = struct_decl
+                    .fields
+                    .iter()
+                    .map(|f| (&f.name, f.type_argument.type_id, f.span.clone()));
+                if self
+                    .check_derived_members(
+                        handler,
+                        engines,
+                        "Ord",
+                        struct_decl.name(),
+                        "field",
+                        members,
+                    )
+                    .is_err()
+                {
+                    return vec![];
+                }
+                let program_id = struct_decl.span().source_id().map(|sid| sid.program_id());
+                let code = self.generate_ord_code(
+                    struct_decl.name(),
+                    &struct_decl.type_parameters,
+                    self.generate_ord_struct_body(engines, &struct_decl, "gt"),
+                    self.generate_ord_struct_body(engines, &struct_decl, "lt"),
+                );
+                (program_id, code)
+            }
+            TyDecl::EnumDecl(_) => {
+                let decl_id = decl.to_enum_id(&Handler::default(), engines).unwrap();
+                let enum_decl = self.ctx.engines().de().get(&decl_id);
+                if !Self::derives_ord(&enum_decl.attributes) {
+                    return vec![];
+                }
+                let members = enum_decl
+                    .variants
+                    .iter()
+                    .map(|v| (&v.name, v.type_argument.type_id, v.span.clone()));
+                if self
+                    .check_derived_members(
+                        handler,
+                        engines,
+                        "Ord",
+                        enum_decl.name(),
+                        "variant",
+                        members,
+                    )
+                    .is_err()
+                {
+                    return vec![];
+                }
+                let program_id = enum_decl.span().source_id().map(|sid| sid.program_id());
+                let code = self.generate_ord_code(
+                    enum_decl.name(),
+                    &enum_decl.type_parameters,
+                    self.generate_ord_enum_body(engines, &enum_decl, "gt"),
+                    self.generate_ord_enum_body(engines, &enum_decl, "lt"),
+                );
+                (program_id, code)
+            }
+            _ => return vec![],
+        };
+
+        match self.parse_impl_trait_to_ty_ast_node(engines, program_id, &code) {
+            Ok(node) => vec![node],
+            Err(gen_handler) => {
+                handler.append(gen_handler);
+                vec![]
+            }
+        }
+    }
+
     fn generate_type(engines: &Engines, type_id: TypeId) -> Option<String> {
         let name = match &*engines.te().get(type_id) {
             TypeInfo::UnknownGeneric { name, .. } => name.to_string(),
@@ -24,6 +24,7 @@ impl ty::TyConstantDecl {
         decl_id: &ParsedDeclId<ConstantDeclaration>,
     ) -> Result<(), ErrorEmitted> {
         let constant_decl = engines.pe().get_constant(decl_id);
+        ctx.check_visibility_restriction(handler, &constant_decl.visibility);
         ctx.insert_parsed_symbol(
             handler,
             engines,
@@ -147,7 +148,12 @@ impl TypeCheckAnalysis for TyConstantDecl {
         ctx: &mut TypeCheckAnalysisContext,
     ) -> Result<(), ErrorEmitted> {
         if let Some(value) = self.value.as_ref() {
-            value.type_check_analyze(handler, ctx)?;
+            // The initializer is evaluated at compile time, so its calls are not made by the
+            // function the constant is declared or used in.
+            let node_stack = std::mem::take(&mut ctx.node_stack);
+            let result = value.type_check_analyze(handler, ctx);
+            ctx.node_stack = node_stack;
+            result?;
         }
         Ok(())
     }
@@ -2,7 +2,10 @@ use sway_error::handler::{ErrorEmitted, Handler};
 use sway_types::{Ident, Named, Spanned};
 
 use crate::{
-    decl_engine::{DeclEngineGet, DeclEngineInsert, DeclRef, ReplaceFunctionImplementingType},
+    decl_engine::{
+        DeclEngineGet, DeclEngineInsert, DeclEngineReplace, DeclRef,
+        ReplaceFunctionImplementingType,
+    },
     language::{
         parsed::{self, StorageEntry},
         ty::{
@@ -160,7 +163,7 @@ impl TyDecl {
 
                 let mut ctx =
                     ctx.with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown, None));
-                let fn_decl = match ty::TyFunctionDecl::type_check(
+                let mut ty_fn_decl = match ty::TyFunctionDecl::type_check_signature(
                     handler,
                     ctx.by_ref(),
                     &fn_decl,
@@ -172,9 +175,23 @@ impl TyDecl {
                     Err(err) => return Ok(ty::TyDecl::ErrorRecovery(span, err)),
                 };
 
-                let name = fn_decl.name.clone();
-                let decl: ty::TyDecl = decl_engine.insert(fn_decl, Some(&decl_id)).into();
+                // Insert the signature before checking the body, so that functions evaluated
+                // at compile time can call themselves.
+                let name = ty_fn_decl.name.clone();
+                let decl_ref = decl_engine.insert(ty_fn_decl.clone(), Some(&decl_id));
+                let decl: ty::TyDecl = decl_ref.clone().into();
                 let _ = ctx.insert_symbol(handler, name, decl.clone());
+
+                let ty_fn_decl = match ty::TyFunctionDecl::type_check_body(
+                    handler,
+                    ctx.by_ref(),
+                    &fn_decl,
+                    &mut ty_fn_decl,
+                ) {
+                    Ok(res) => res,
+                    Err(err) => return Ok(ty::TyDecl::ErrorRecovery(span, err)),
+                };
+                decl_engine.replace(*decl_ref.id(), ty_fn_decl);
                 decl
             }
             parsed::Declaration::TraitDeclaration(decl_id) => {
@@ -538,7 +555,7 @@ impl TyDecl {
                         call_path_tree: ty.call_path_tree.clone(),
                         span: ty.span.clone(),
                     },
-                    visibility: decl.visibility,
+                    visibility: decl.visibility.clone(),
                     span,
                 };
 
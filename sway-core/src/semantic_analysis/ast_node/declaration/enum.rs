@@ -16,6 +16,7 @@ impl ty::TyEnumDecl {
         decl_id: &ParsedDeclId<EnumDeclaration>,
     ) -> Result<(), ErrorEmitted> {
         let enum_decl = engines.pe().get_enum(decl_id);
+        ctx.check_visibility_restriction(handler, &enum_decl.visibility);
         ctx.insert_parsed_symbol(
             handler,
             engines,
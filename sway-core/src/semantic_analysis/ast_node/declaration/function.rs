@@ -28,6 +28,7 @@ impl ty::TyFunctionDecl {
         decl_id: &ParsedDeclId<FunctionDeclaration>,
     ) -> Result<(), ErrorEmitted> {
         let fn_decl = engines.pe().get_function(decl_id);
+        ctx.check_visibility_restriction(handler, &fn_decl.visibility);
         let _ = ctx.insert_parsed_symbol(
             handler,
             engines,
@@ -152,13 +153,13 @@ impl ty::TyFunctionDecl {
 
                 let (visibility, is_contract_call) = if is_method {
                     if is_in_impl_self {
-                        (*visibility, false)
+                        (visibility.clone(), false)
                     } else {
                         (Visibility::Public, false)
                     }
                 } else {
                     (
-                        *visibility,
+                        visibility.clone(),
                         matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)),
                     )
                 };
@@ -236,7 +237,8 @@ impl ty::TyFunctionDecl {
                         "Function body's return type does not match up with its return type annotation.",
                     )
                     .with_type_annotation(return_type.type_id)
-                    .with_function_type_annotation(return_type.type_id);
+                    .with_function_type_annotation(return_type.type_id)
+                    .with_loop_break_type_annotation(None);
 
                 let body = ty::TyCodeBlock::type_check(handler, ctx.by_ref(), body, true)
                     .unwrap_or_else(|_err| ty::TyCodeBlock::default());
@@ -1,11 +1,18 @@
 use crate::{
     decl_engine::parsed_id::ParsedDeclId,
+    ir_generation::const_eval::compile_constant_expression_to_constant,
     language::{parsed::*, ty, CallPath},
+    metadata::MetadataManager,
     semantic_analysis::{type_check_context::EnforceTypeArguments, *},
     type_system::*,
     Engines,
 };
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
+use sway_ir::{Context, Kind, Module};
+use sway_types::Ident;
 use symbol_collection_context::SymbolCollectionContext;
 
 impl ty::TyStructDecl {
@@ -16,6 +23,7 @@ impl ty::TyStructDecl {
         decl_id: &ParsedDeclId<StructDeclaration>,
     ) -> Result<(), ErrorEmitted> {
         let struct_decl = engines.pe().get_struct(decl_id);
+        ctx.check_visibility_restriction(handler, &struct_decl.visibility);
         ctx.insert_parsed_symbol(
             handler,
             engines,
@@ -25,6 +33,14 @@ impl ty::TyStructDecl {
 
         // create a namespace for the decl, used to create a scope for generics
         let _ = ctx.scoped(engines, struct_decl.span.clone(), |_scoped_ctx| Ok(()));
+
+        for default_value in struct_decl
+            .fields
+            .iter()
+            .filter_map(|field| field.default_value.as_ref())
+        {
+            ty::TyExpression::collect(handler, engines, ctx, default_value)?;
+        }
         Ok(())
     }
 
@@ -59,6 +75,28 @@ impl ty::TyStructDecl {
                 new_fields.push(ty::TyStructField::type_check(handler, ctx.by_ref(), field)?);
             }
 
+            // check that the default values of the fields are constant
+            let engines = ctx.engines();
+            let unit_type_id = engines.te().insert(engines, TypeInfo::Tuple(vec![]), None);
+            let type_mapping = TypeSubstMap::from_type_parameters_and_type_arguments(
+                new_type_parameters
+                    .iter()
+                    .map(|type_param| type_param.type_id)
+                    .collect(),
+                vec![unit_type_id; new_type_parameters.len()],
+            );
+            for field in new_fields.iter() {
+                if let Some(default_value) = &field.default_value {
+                    check_default_value_is_constant(
+                        handler,
+                        &ctx,
+                        &field.name,
+                        default_value,
+                        &type_mapping,
+                    )?;
+                }
+            }
+
             let path = CallPath::ident_to_fullpath(name, ctx.namespace());
 
             // create the struct decl
@@ -96,17 +134,75 @@ impl ty::TyStructField {
             .unwrap_or_else(|err| {
                 type_engine.insert(ctx.engines(), TypeInfo::ErrorRecovery(err), None)
             });
+
+        let default_value = match field.default_value {
+            Some(default_value) => {
+                let mut ctx = ctx
+                    .by_ref()
+                    .with_type_annotation(type_argument.type_id)
+                    .with_help_text(
+                        "The default value of this struct field does not match up with \
+                        the field's type.",
+                    );
+                let default_value =
+                    ty::TyExpression::type_check(handler, ctx.by_ref(), &default_value)?;
+                Some(Box::new(default_value))
+            }
+            None => None,
+        };
+
         let field = ty::TyStructField {
             visibility: field.visibility,
             name: field.name,
             span: field.span,
             type_argument,
             attributes: field.attributes,
+            default_value,
         };
         Ok(field)
     }
 }
 
+/// Default values are filled in wherever a struct is instantiated, so they must be
+/// constant expressions. Whether an expression is constant does not depend on the
+/// types it is instantiated with, so the default values of generic fields are checked
+/// with the struct's type parameters replaced according to `type_mapping`.
+fn check_default_value_is_constant(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    field_name: &Ident,
+    default_value: &ty::TyExpression,
+    type_mapping: &TypeSubstMap,
+) -> Result<(), ErrorEmitted> {
+    let engines = ctx.engines();
+    let mut default_value = default_value.clone();
+    default_value.subst(type_mapping, &SubstTypesContext::new(engines, true));
+    let mut context = Context::new(
+        engines.se(),
+        sway_ir::ExperimentalFlags {
+            new_encoding: ctx.experimental.new_encoding,
+        },
+    );
+    let mut md_mgr = MetadataManager::default();
+    let module = Module::new(&mut context, Kind::Library);
+    compile_constant_expression_to_constant(
+        engines,
+        &mut context,
+        &mut md_mgr,
+        module,
+        Some(ctx.namespace().module(engines)),
+        None,
+        &default_value,
+    )
+    .map(|_| ())
+    .map_err(|_| {
+        handler.emit_err(CompileError::NonConstantStructFieldDefaultValue {
+            field_name: field_name.clone(),
+            span: default_value.span.clone(),
+        })
+    })
+}
+
 impl TypeCheckAnalysis for ty::TyStructDecl {
     fn type_check_analyze(
         &self,
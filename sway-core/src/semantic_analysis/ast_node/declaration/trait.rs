@@ -53,6 +53,7 @@ impl TyTraitDecl {
         decl_id: &ParsedDeclId<TraitDeclaration>,
     ) -> Result<(), ErrorEmitted> {
         let trait_decl = engines.pe().get_trait(decl_id);
+        ctx.check_visibility_restriction(handler, &trait_decl.visibility);
         ctx.insert_parsed_symbol(
             handler,
             engines,
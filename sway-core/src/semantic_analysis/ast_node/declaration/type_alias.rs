@@ -20,6 +20,7 @@ impl TyTypeAliasDecl {
         decl_id: &ParsedDeclId<TypeAliasDeclaration>,
     ) -> Result<(), ErrorEmitted> {
         let type_alias = engines.pe().get_type_alias(decl_id);
+        ctx.check_visibility_restriction(handler, &type_alias.visibility);
         ctx.insert_parsed_symbol(
             handler,
             engines,
@@ -1,4 +1,5 @@
 use crate::{
+    compiler_generated::is_generated_type_ascription_var_name,
     decl_engine::parsed_id::ParsedDeclId,
     language::{
         parsed::*,
@@ -49,12 +50,15 @@ impl ty::TyVariableDecl {
                 None,
             )
             .unwrap_or_else(|err| type_engine.insert(engines, TypeInfo::ErrorRecovery(err), None));
+        let help_text = if is_generated_type_ascription_var_name(var_decl.name.as_str()) {
+            "The ascribed type does not match up with the expression's type."
+        } else {
+            "Variable declaration's type annotation does not match up \
+                    with the assigned expression's type."
+        };
         let mut ctx = ctx
             .with_type_annotation(type_ascription.type_id)
-            .with_help_text(
-                "Variable declaration's type annotation does not match up \
-                        with the assigned expression's type.",
-            );
+            .with_help_text(help_text);
         let result = ty::TyExpression::type_check(handler, ctx.by_ref(), &var_decl.body);
         let body = result
             .unwrap_or_else(|err| ty::TyExpression::error(err, var_decl.name.span(), engines));
@@ -41,6 +41,9 @@ impl ty::TyIntrinsicFunctionKind {
             Intrinsic::SizeOfStr => {
                 type_check_size_of_type(handler, ctx, kind, arguments, type_arguments, span)
             }
+            Intrinsic::AlignOfType => {
+                type_check_size_of_type(handler, ctx, kind, arguments, type_arguments, span)
+            }
             Intrinsic::IsReferenceType => {
                 type_check_is_reference_type(handler, ctx, kind, arguments, type_arguments, span)
             }
@@ -106,6 +109,15 @@ impl ty::TyIntrinsicFunctionKind {
                 type_check_slice(handler, ctx, kind, arguments, type_arguments, span)
             }
             Intrinsic::ElemAt => type_check_elem_at(arguments, handler, kind, span, ctx),
+            Intrinsic::TxInputCount
+            | Intrinsic::TxInputType
+            | Intrinsic::TxInputAmount
+            | Intrinsic::TxOutputCount
+            | Intrinsic::TxOutputType
+            | Intrinsic::TxOutputAmount => {
+                type_check_tx_field(handler, ctx, kind, arguments, type_arguments, span)
+            }
+            Intrinsic::Fmt => type_check_fmt(handler, ctx, kind, arguments, span),
         }
     }
 }
@@ -1011,6 +1023,87 @@ fn type_check_gtf(
     ))
 }
 
+/// Signature: `__tx_input_count() -> u64`
+/// Description: Returns the number of inputs of the transaction.
+/// Constraints: None.
+///
+/// Signature: `__tx_output_count() -> u64`
+/// Description: Returns the number of outputs of the transaction.
+/// Constraints: None.
+///
+/// Signature: `__tx_input_type(index: u64) -> u8`
+/// Description: Returns the type of the input at `index`.
+/// Constraints: None.
+///
+/// Signature: `__tx_input_amount(index: u64) -> u64`
+/// Description: Returns the amount of the coin input at `index`.
+/// Constraints: None.
+///
+/// Signature: `__tx_output_type(index: u64) -> u8`
+/// Description: Returns the type of the output at `index`.
+/// Constraints: None.
+///
+/// Signature: `__tx_output_amount(index: u64) -> u64`
+/// Description: Returns the amount of the coin output at `index`.
+/// Constraints: None.
+fn type_check_tx_field(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    kind: sway_ast::Intrinsic,
+    arguments: &[Expression],
+    type_arguments: &[TypeArgument],
+    span: Span,
+) -> Result<(ty::TyIntrinsicFunctionKind, TypeId), ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let engines = ctx.engines();
+
+    let (num_args, return_bits) = match kind {
+        Intrinsic::TxInputCount | Intrinsic::TxOutputCount => (0, IntegerBits::SixtyFour),
+        Intrinsic::TxInputType | Intrinsic::TxOutputType => (1, IntegerBits::Eight),
+        Intrinsic::TxInputAmount | Intrinsic::TxOutputAmount => (1, IntegerBits::SixtyFour),
+        _ => unreachable!("only transaction field intrinsics are type checked here"),
+    };
+
+    if arguments.len() != num_args {
+        return Err(handler.emit_err(CompileError::IntrinsicIncorrectNumArgs {
+            name: kind.to_string(),
+            expected: num_args as u64,
+            span,
+        }));
+    }
+
+    if !type_arguments.is_empty() {
+        return Err(handler.emit_err(CompileError::IntrinsicIncorrectNumTArgs {
+            name: kind.to_string(),
+            expected: 0,
+            span,
+        }));
+    }
+
+    // Type check the index, if any.
+    let arguments = arguments
+        .iter()
+        .map(|argument| {
+            let ctx = ctx.by_ref().with_type_annotation(type_engine.insert(
+                engines,
+                TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+                None,
+            ));
+            ty::TyExpression::type_check(handler, ctx, argument)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((
+        ty::TyIntrinsicFunctionKind {
+            kind,
+            arguments,
+            type_arguments: vec![],
+            span,
+        },
+        type_engine.insert(engines, TypeInfo::UnsignedInteger(return_bits), None),
+    ))
+}
+
 /// Signature: `__addr_of<T>(val: T) -> raw_ptr`
 /// Description: Returns the address in memory where `val` is stored.
 /// Constraints: `T` is a reference type.
@@ -1386,6 +1479,126 @@ fn type_check_log(
     Ok((intrinsic_function, return_type))
 }
 
+/// Signature: `__fmt<T1, ..., Tn>(format: str, arg1: T1, ..., argn: Tn) -> (str, T1, ..., Tn)`
+/// Description: Composes the format string and the arguments into a single tuple
+///              that can be logged, e.g. `log(__fmt("x={}, y={}", x, y))`.
+/// Constraints: `format` is a string literal containing exactly `n` `{}` placeholders.
+///              `{{` and `}}` are escaped braces. `T1`, ..., `Tn` do not contain
+///              references or pointers.
+fn type_check_fmt(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    kind: sway_ast::Intrinsic,
+    arguments: &[Expression],
+    span: Span,
+) -> Result<(ty::TyIntrinsicFunctionKind, TypeId), ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let engines = ctx.engines();
+
+    let Some((format, format_args)) = arguments.split_first() else {
+        return Err(handler.emit_err(CompileError::IntrinsicIncorrectNumArgs {
+            name: kind.to_string(),
+            expected: 1,
+            span,
+        }));
+    };
+    let ExpressionKind::Literal(Literal::String(format_str)) = &format.kind else {
+        return Err(handler.emit_err(CompileError::ExpectedStringLiteral {
+            span: format.span.clone(),
+        }));
+    };
+
+    let placeholders = count_format_placeholders(format_str.as_str());
+    if placeholders != format_args.len() {
+        return Err(handler.emit_err(CompileError::FormatArgumentCountMismatch {
+            placeholders,
+            arguments: format_args.len(),
+            span,
+        }));
+    }
+
+    let format_exp = ty::TyExpression::type_check(
+        handler,
+        ctx.by_ref()
+            .with_type_annotation(type_engine.insert(engines, TypeInfo::StringSlice, None)),
+        format,
+    )?;
+    let mut exps = vec![format_exp];
+    for arg in format_args {
+        let ctx = ctx
+            .by_ref()
+            .with_help_text("")
+            .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown, None));
+        let exp = ty::TyExpression::type_check(handler, ctx, arg)?;
+        let is_loggable = exp
+            .return_type
+            .extract_any_including_self(
+                engines,
+                &|t| {
+                    matches!(
+                        t,
+                        TypeInfo::Ref { .. } | TypeInfo::Ptr(_) | TypeInfo::RawUntypedPtr
+                    )
+                },
+                vec![],
+                0,
+            )
+            .is_empty();
+        if !is_loggable {
+            return Err(handler.emit_err(CompileError::IntrinsicUnsupportedArgType {
+                name: kind.to_string(),
+                span: arg.span.clone(),
+                hint: format!(
+                    "Type \"{}\" cannot be logged, because it contains a reference or a pointer.",
+                    engines.help_out(exp.return_type)
+                ),
+            }));
+        }
+        exps.push(exp);
+    }
+
+    let return_type = type_engine.insert(
+        engines,
+        TypeInfo::Tuple(
+            exps.iter()
+                .map(|exp| TypeArgument {
+                    type_id: exp.return_type,
+                    initial_type_id: exp.return_type,
+                    span: exp.span.clone(),
+                    call_path_tree: None,
+                })
+                .collect(),
+        ),
+        None,
+    );
+    let intrinsic_function = ty::TyIntrinsicFunctionKind {
+        kind,
+        arguments: exps,
+        type_arguments: vec![],
+        span,
+    };
+    Ok((intrinsic_function, return_type))
+}
+
+/// Returns the number of `{}` placeholders in `format`, not counting the escaped braces `{{` and `}}`.
+fn count_format_placeholders(format: &str) -> usize {
+    let mut placeholders = 0;
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+            }
+            ('{', Some('}')) => {
+                chars.next();
+                placeholders += 1;
+            }
+            _ => {}
+        }
+    }
+    placeholders
+}
+
 /// Signature: `__add<T>(lhs: T, rhs: T) -> T`
 /// Description: Adds `lhs` and `rhs` and returns the result.
 /// Constraints: `T` is an integer type, i.e. `u8`, `u16`, `u32`, `u64`.
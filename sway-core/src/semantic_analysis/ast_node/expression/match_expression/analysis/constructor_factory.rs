@@ -251,6 +251,7 @@ impl ConstructorFactory {
                 )?
             }
             Pattern::Tuple(elems) => Pattern::Tuple(PatStack::fill_wildcards(elems.len())),
+            Pattern::Array(elems) => Pattern::Array(PatStack::fill_wildcards(elems.len())),
             Pattern::Or(elems) => {
                 let mut pat_stack = PatStack::empty();
                 for pat in elems.into_iter() {
@@ -472,6 +473,14 @@ impl ConstructorFactory {
                 }
                 Ok(true)
             }
+            ref arr @ Pattern::Array(_) => {
+                for pat in rest.iter() {
+                    if !pat.has_the_same_constructor(arr) {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
             ref strct @ Pattern::Struct(_) => {
                 for pat in rest.iter() {
                     if !pat.has_the_same_constructor(strct) {
@@ -229,6 +229,19 @@ pub(crate) fn collect_duplicate_match_pattern_variables(
                     };
                 }
             }
+            ty::TyScrutineeVariant::Slice {
+                prefix,
+                rest,
+                suffix,
+            } => {
+                for scrutinee in prefix
+                    .iter()
+                    .chain(rest.iter().map(|rest| rest.as_ref()))
+                    .chain(suffix.iter())
+                {
+                    recursively_collect_duplicate_variables(branches, left_most_branch, scrutinee);
+                }
+            }
             ty::TyScrutineeVariant::EnumScrutinee { value, .. } => {
                 recursively_collect_duplicate_variables(branches, left_most_branch, value)
             }
@@ -286,6 +299,19 @@ pub(crate) fn collect_match_pattern_variables(scrutinee: &TyScrutinee) -> Vec<(I
                     recursively_collect_variables(variables, scrutinee);
                 }
             }
+            ty::TyScrutineeVariant::Slice {
+                prefix,
+                rest,
+                suffix,
+            } => {
+                for scrutinee in prefix
+                    .iter()
+                    .chain(rest.iter().map(|rest| rest.as_ref()))
+                    .chain(suffix.iter())
+                {
+                    recursively_collect_variables(variables, scrutinee);
+                }
+            }
             ty::TyScrutineeVariant::EnumScrutinee { value, .. } => {
                 recursively_collect_variables(variables, value)
             }
@@ -6,7 +6,7 @@ use sway_error::handler::{ErrorEmitted, Handler};
 use sway_types::Span;
 
 use crate::decl_engine::DeclEngine;
-use crate::{language::ty, language::Literal, TypeInfo};
+use crate::{language::ty, language::Literal, Engines, TypeInfo};
 
 use super::{patstack::PatStack, range::Range};
 
@@ -109,12 +109,13 @@ pub(crate) enum Pattern {
     Struct(StructPattern),
     Enum(EnumPattern),
     Tuple(PatStack),
+    Array(PatStack),
     Or(PatStack),
 }
 
 impl Pattern {
     /// Converts a `Scrutinee` to a `Pattern`.
-    pub(crate) fn from_scrutinee(scrutinee: ty::TyScrutinee) -> Self {
+    pub(crate) fn from_scrutinee(engines: &Engines, scrutinee: ty::TyScrutinee) -> Self {
         let pat = match scrutinee.variant {
             ty::TyScrutineeVariant::CatchAll => Pattern::Wildcard,
             ty::TyScrutineeVariant::Variable(_) => Pattern::Wildcard,
@@ -128,7 +129,7 @@ impl Pattern {
                 let mut new_fields = vec![];
                 for field in fields.into_iter() {
                     let f = match field.scrutinee {
-                        Some(scrutinee) => Pattern::from_scrutinee(scrutinee),
+                        Some(scrutinee) => Pattern::from_scrutinee(engines, scrutinee),
                         None => Pattern::Wildcard,
                     };
                     new_fields.push((field.field.as_str().to_string(), f));
@@ -141,17 +142,42 @@ impl Pattern {
             ty::TyScrutineeVariant::Or(elems) => {
                 let mut new_elems = PatStack::empty();
                 for elem in elems.into_iter() {
-                    new_elems.push(Pattern::from_scrutinee(elem));
+                    new_elems.push(Pattern::from_scrutinee(engines, elem));
                 }
                 Pattern::Or(new_elems)
             }
             ty::TyScrutineeVariant::Tuple(elems) => {
                 let mut new_elems = PatStack::empty();
                 for elem in elems.into_iter() {
-                    new_elems.push(Pattern::from_scrutinee(elem));
+                    new_elems.push(Pattern::from_scrutinee(engines, elem));
                 }
                 Pattern::Tuple(new_elems)
             }
+            ty::TyScrutineeVariant::Slice {
+                prefix,
+                rest: _,
+                suffix,
+            } => {
+                // The rest pattern always matches, so it is equivalent to
+                // a wildcard for every element of the array that it covers.
+                let rest_len = match &*engines.te().get_unaliased(scrutinee.type_id) {
+                    TypeInfo::Array(_, length) => {
+                        length.val().saturating_sub(prefix.len() + suffix.len())
+                    }
+                    _ => 0,
+                };
+                let mut new_elems = PatStack::empty();
+                for elem in prefix.into_iter() {
+                    new_elems.push(Pattern::from_scrutinee(engines, elem));
+                }
+                for _ in 0..rest_len {
+                    new_elems.push(Pattern::Wildcard);
+                }
+                for elem in suffix.into_iter() {
+                    new_elems.push(Pattern::from_scrutinee(engines, elem));
+                }
+                Pattern::Array(new_elems)
+            }
             ty::TyScrutineeVariant::EnumScrutinee {
                 enum_ref,
                 variant,
@@ -160,7 +186,7 @@ impl Pattern {
             } => Pattern::Enum(EnumPattern {
                 enum_name: enum_ref.name().to_string(),
                 variant_name: variant.name.to_string(),
-                value: Box::new(Pattern::from_scrutinee(*value)),
+                value: Box::new(Pattern::from_scrutinee(engines, *value)),
             }),
         };
         pat
@@ -448,6 +474,21 @@ impl Pattern {
                     .into();
                 Pattern::from_pat_stack(handler, pats, span)?
             }
+            Pattern::Array(elems) => {
+                if elems.len() != args.len() {
+                    return Err(handler.emit_err(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    )));
+                }
+                let pats: PatStack = args
+                    .serialize_multi_patterns(handler, span)?
+                    .into_iter()
+                    .map(Pattern::Array)
+                    .collect::<Vec<_>>()
+                    .into();
+                Pattern::from_pat_stack(handler, pats, span)?
+            }
             Pattern::Or(elems) => {
                 if elems.len() != args.len() {
                     return Err(handler.emit_err(CompileError::Internal(
@@ -488,6 +529,7 @@ impl Pattern {
             Pattern::Struct(StructPattern { fields, .. }) => fields.len(),
             Pattern::Enum(_) => 1,
             Pattern::Tuple(elems) => elems.len(),
+            Pattern::Array(elems) => elems.len(),
             Pattern::Wildcard => 0,
             Pattern::Or(elems) => elems.len(),
         }
@@ -555,6 +597,7 @@ impl Pattern {
                 }),
             ) => enum_name1 == enum_name2 && variant_name1 == variant_name2,
             (Pattern::Tuple(elems1), Pattern::Tuple(elems2)) => elems1.len() == elems2.len(),
+            (Pattern::Array(elems1), Pattern::Array(elems2)) => elems1.len() == elems2.len(),
             (Pattern::Or(_), Pattern::Or(_)) => unreachable!(),
             _ => false,
         }
@@ -597,7 +640,7 @@ impl Pattern {
                 .collect::<Vec<_>>()
                 .into(),
             Pattern::Enum(EnumPattern { value, .. }) => PatStack::from_pattern((**value).clone()),
-            Pattern::Tuple(elems) => elems.to_owned(),
+            Pattern::Tuple(elems) | Pattern::Array(elems) => elems.to_owned(),
             _ => PatStack::empty(),
         };
         if self.a() != pats.len() {
@@ -636,6 +679,7 @@ impl Pattern {
             Pattern::Struct(pat) => Pattern::Struct(pat.into_root_constructor()),
             Pattern::Enum(pat) => Pattern::Enum(pat.into_root_constructor()),
             Pattern::Tuple(elems) => Pattern::Tuple(PatStack::fill_wildcards(elems.len())),
+            Pattern::Array(elems) => Pattern::Array(PatStack::fill_wildcards(elems.len())),
             Pattern::Or(elems) => {
                 let mut pat_stack = PatStack::empty();
                 for elem in elems.into_iter() {
@@ -682,7 +726,8 @@ impl Pattern {
             Pattern::Struct(_) => 9,
             Pattern::Enum(_) => 10,
             Pattern::Tuple(_) => 11,
-            Pattern::Or(_) => 12,
+            Pattern::Array(_) => 12,
+            Pattern::Or(_) => 13,
         }
     }
 }
@@ -708,6 +753,13 @@ impl fmt::Display for Pattern {
                 builder.push(')');
                 builder
             }
+            Pattern::Array(elems) => {
+                let mut builder = String::new();
+                builder.push('[');
+                write!(builder, "{elems}")?;
+                builder.push(']');
+                builder
+            }
             Pattern::Or(elems) => elems
                 .iter()
                 .map(|x| x.to_string())
@@ -735,6 +787,7 @@ impl std::cmp::Ord for Pattern {
             (Pattern::Struct(x), Pattern::Struct(y)) => x.cmp(y),
             (Pattern::Enum(x), Pattern::Enum(y)) => x.cmp(y),
             (Pattern::Tuple(x), Pattern::Tuple(y)) => x.cmp(y),
+            (Pattern::Array(x), Pattern::Array(y)) => x.cmp(y),
             (Pattern::Or(x), Pattern::Or(y)) => x.cmp(y),
             (x, y) => x.discriminant_value().cmp(&y.discriminant_value()),
         }
@@ -12,7 +12,7 @@ use crate::{
         },
         TypeCheckContext,
     },
-    Ident, TypeId, TypeInfo, UnifyCheck,
+    Ident, Length, TypeId, TypeInfo, UnifyCheck,
 };
 
 use sway_error::{
@@ -252,6 +252,20 @@ pub(super) fn matcher(
         ty::TyScrutineeVariant::Tuple(elems) => {
             match_tuple(handler, ctx, match_value, exp, elems, span)
         }
+        ty::TyScrutineeVariant::Slice {
+            prefix,
+            rest,
+            suffix,
+        } => match_slice(
+            handler,
+            ctx,
+            match_value,
+            exp,
+            prefix,
+            rest.map(|rest| *rest),
+            suffix,
+            span,
+        ),
     }
 }
 
@@ -563,3 +577,109 @@ fn match_tuple(
 
     Ok(ReqDeclTree::and(nodes))
 }
+
+#[allow(clippy::too_many_arguments)]
+fn match_slice(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    match_value: &ty::TyExpression,
+    exp: &ty::TyExpression,
+    prefix: Vec<ty::TyScrutinee>,
+    rest: Option<ty::TyScrutinee>,
+    suffix: Vec<ty::TyScrutinee>,
+    span: Span,
+) -> Result<ReqDeclTree, ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+
+    let (elem_type, array_length) = match &*type_engine.get_unaliased(exp.return_type) {
+        TypeInfo::Array(elem_type, length) => (elem_type.clone(), length.val()),
+        _ => {
+            return Err(handler.emit_err(CompileError::MatchSlicePatternOnNonArray {
+                actually: ctx.engines.help_out(exp.return_type).to_string(),
+                span,
+            }))
+        }
+    };
+
+    // The slice pattern must match exactly as many elements as the array has,
+    // or at most as many, if the rest `..` matches the remaining ones.
+    let pattern_length = prefix.len() + suffix.len();
+    let has_rest = rest.is_some();
+    if pattern_length > array_length || (!has_rest && pattern_length != array_length) {
+        return Err(
+            handler.emit_err(CompileError::MatchSlicePatternLengthMismatch {
+                pattern_length,
+                array_length,
+                has_rest,
+                span,
+            }),
+        );
+    }
+
+    let u64_type = type_engine.insert(
+        ctx.engines,
+        TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+        None,
+    );
+    // Get the expression that accesses the array element e.g., `my_array[3]`.
+    let array_index_access = |index: usize| ty::TyExpression {
+        expression: ty::TyExpressionVariant::ArrayIndex {
+            prefix: Box::new(exp.clone()),
+            index: Box::new(ty::TyExpression {
+                expression: ty::TyExpressionVariant::Literal(Literal::U64(index as u64)),
+                return_type: u64_type,
+                span: span.clone(),
+            }),
+        },
+        return_type: elem_type.type_id,
+        span: span.clone(),
+    };
+
+    let suffix_start = array_length - suffix.len();
+    let mut nodes = vec![];
+
+    for (index, elem) in prefix.into_iter().enumerate() {
+        let req_decl_tree = matcher(
+            handler,
+            ctx.by_ref(),
+            match_value,
+            &array_index_access(index),
+            elem,
+        )?;
+        nodes.push(req_decl_tree.root);
+    }
+
+    // The rest is matched against a new array made of the elements it covers.
+    if let Some(rest) = rest {
+        let rest_length = suffix_start - nodes.len();
+        let rest_array = ty::TyExpression {
+            expression: ty::TyExpressionVariant::Array {
+                elem_type: elem_type.type_id,
+                contents: (nodes.len()..suffix_start)
+                    .map(array_index_access)
+                    .collect(),
+            },
+            return_type: type_engine.insert(
+                ctx.engines,
+                TypeInfo::Array(elem_type.clone(), Length::new(rest_length, span.clone())),
+                None,
+            ),
+            span: rest.span.clone(),
+        };
+        let req_decl_tree = matcher(handler, ctx.by_ref(), match_value, &rest_array, rest)?;
+        nodes.push(req_decl_tree.root);
+    }
+
+    for (index, elem) in suffix.into_iter().enumerate() {
+        let req_decl_tree = matcher(
+            handler,
+            ctx.by_ref(),
+            match_value,
+            &array_index_access(suffix_start + index),
+            elem,
+        )?;
+        nodes.push(req_decl_tree.root);
+    }
+
+    Ok(ReqDeclTree::and(nodes))
+}
@@ -109,6 +109,12 @@ impl TyScrutinee {
                 }
             }
             Scrutinee::Tuple { elems, span } => type_check_tuple(handler, ctx, elems, span),
+            Scrutinee::Slice {
+                prefix,
+                rest,
+                suffix,
+                span,
+            } => type_check_slice(handler, ctx, prefix, rest.map(|rest| *rest), suffix, span),
             Scrutinee::Error { err, .. } => Err(err),
         }
     }
@@ -149,6 +155,15 @@ impl TyScrutinee {
                 .all(|x| x.is_catch_all()),
             ty::TyScrutineeVariant::Or(elems) => elems.iter().any(|x| x.is_catch_all()),
             ty::TyScrutineeVariant::Tuple(elems) => elems.iter().all(|x| x.is_catch_all()),
+            ty::TyScrutineeVariant::Slice {
+                prefix,
+                rest,
+                suffix,
+            } => prefix
+                .iter()
+                .chain(rest.iter().map(|rest| rest.as_ref()))
+                .chain(suffix.iter())
+                .all(|x| x.is_catch_all()),
             ty::TyScrutineeVariant::EnumScrutinee { .. } => false,
         }
     }
@@ -607,3 +622,39 @@ fn type_check_tuple(
 
     Ok(typed_scrutinee)
 }
+
+fn type_check_slice(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    prefix: Vec<Scrutinee>,
+    rest: Option<Scrutinee>,
+    suffix: Vec<Scrutinee>,
+    span: Span,
+) -> Result<ty::TyScrutinee, ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let engines = ctx.engines();
+
+    let mut type_check_elems = |elems: Vec<Scrutinee>| {
+        elems
+            .into_iter()
+            .filter_map(|elem| ty::TyScrutinee::type_check(handler, ctx.by_ref(), elem).ok())
+            .collect::<Vec<_>>()
+    };
+    let typed_prefix = type_check_elems(prefix);
+    let typed_rest = type_check_elems(rest.into_iter().collect()).pop();
+    let typed_suffix = type_check_elems(suffix);
+
+    // The length of the array is not known until the scrutinee is matched against the
+    // matched value, so the type of the scrutinee gets resolved in the matcher.
+    let typed_scrutinee = ty::TyScrutinee {
+        variant: ty::TyScrutineeVariant::Slice {
+            prefix: typed_prefix,
+            rest: typed_rest.map(Box::new),
+            suffix: typed_suffix,
+        },
+        type_id: type_engine.insert(engines, TypeInfo::Unknown, None),
+        span,
+    };
+
+    Ok(typed_scrutinee)
+}
@@ -28,7 +28,7 @@ use crate::{
         },
         *,
     },
-    namespace::{IsExtendingExistingImpl, IsImplSelf},
+    namespace::{IsExtendingExistingImpl, IsImplSelf, ResolvedDeclaration},
     semantic_analysis::{expression::ReachableReport, type_check_context::EnforceTypeArguments, *},
     transform::to_parsed_lang::type_name_to_type_info_opt,
     type_system::*,
@@ -43,7 +43,7 @@ use std::collections::{HashMap, VecDeque};
 use sway_ast::intrinsics::Intrinsic;
 use sway_error::{
     convert_parse_tree_error::ConvertParseTreeError,
-    error::CompileError,
+    error::{CompileError, NamesInScope},
     handler::{ErrorEmitted, Handler},
     warning::{CompileWarning, Warning},
 };
@@ -209,6 +209,19 @@ impl ty::TyExpression {
                     })
                     .collect::<Result<Vec<_>, ErrorEmitted>>()?;
             }
+            ExpressionKind::Try(expr) => {
+                Self::collect(handler, engines, ctx, &expr.value)?;
+                expr.option_branches
+                    .iter()
+                    .chain(expr.result_branches.iter())
+                    .map(|branch| {
+                        ctx.scoped(engines, branch.span.clone(), |scoped_ctx| {
+                            Self::collect(handler, engines, scoped_ctx, &branch.result)
+                        })
+                        .0
+                    })
+                    .collect::<Result<Vec<_>, ErrorEmitted>>()?;
+            }
             ExpressionKind::Asm(_) => {}
             ExpressionKind::MethodApplication(expr) => {
                 expr.arguments
@@ -245,10 +258,15 @@ impl ty::TyExpression {
                 Self::collect(handler, engines, ctx, &expr.condition)?;
                 TyCodeBlock::collect(handler, engines, ctx, &expr.body)?
             }
+            ExpressionKind::Loop(expr) => TyCodeBlock::collect(handler, engines, ctx, &expr.body)?,
             ExpressionKind::ForLoop(expr) => {
                 Self::collect(handler, engines, ctx, &expr.desugared)?;
             }
-            ExpressionKind::Break => {}
+            ExpressionKind::Break(expr) => {
+                if let Some(expr) = expr {
+                    Self::collect(handler, engines, ctx, expr)?;
+                }
+            }
             ExpressionKind::Continue => {}
             ExpressionKind::Reassignment(expr) => {
                 match &expr.lhs {
@@ -377,6 +395,12 @@ impl ty::TyExpression {
                     span,
                 )
             }
+            ExpressionKind::Try(try_expression) => Self::type_check_try_expression(
+                handler,
+                ctx.by_ref().with_help_text(""),
+                try_expression,
+                span,
+            ),
             ExpressionKind::Asm(asm) => {
                 Self::type_check_asm_expression(handler, ctx.by_ref(), *asm.clone(), span)
             }
@@ -501,16 +525,14 @@ impl ty::TyExpression {
             ExpressionKind::WhileLoop(WhileLoopExpression { condition, body }) => {
                 Self::type_check_while_loop(handler, ctx.by_ref(), condition, body, span)
             }
+            ExpressionKind::Loop(LoopExpression { body }) => {
+                Self::type_check_loop(handler, ctx.by_ref(), body, span)
+            }
             ExpressionKind::ForLoop(ForLoopExpression { desugared }) => {
                 Self::type_check_for_loop(handler, ctx.by_ref(), desugared)
             }
-            ExpressionKind::Break => {
-                let expr = ty::TyExpression {
-                    expression: ty::TyExpressionVariant::Break,
-                    return_type: type_engine.insert(engines, TypeInfo::Never, None),
-                    span,
-                };
-                Ok(expr)
+            ExpressionKind::Break(expr) => {
+                Self::type_check_break(handler, ctx.by_ref(), expr.as_deref(), span)
             }
             ExpressionKind::Continue => {
                 let expr = ty::TyExpression {
@@ -714,6 +736,11 @@ impl ty::TyExpression {
                 let err = handler.emit_err(CompileError::UnknownVariable {
                     var_name: name.clone(),
                     span,
+                    names_in_scope: ctx
+                        .namespace()
+                        .module(engines)
+                        .current_items()
+                        .names_in_scope(ResolvedDeclaration::is_value),
                 });
                 ty::TyExpression::error(err, name.span(), engines)
             }
@@ -856,6 +883,55 @@ impl ty::TyExpression {
         Ok(exp)
     }
 
+    /// Type checks `value?` as a match on `value`, using the branches that unwrap the
+    /// `Option` or the `Result` returned by the enclosing function.
+    fn type_check_try_expression(
+        handler: &Handler,
+        ctx: TypeCheckContext,
+        try_expression: &TryExpression,
+        span: Span,
+    ) -> Result<ty::TyExpression, ErrorEmitted> {
+        let engines = ctx.engines();
+        let return_type_id = ctx.function_type_annotation();
+        let branches = match &*engines.te().get_unaliased(return_type_id) {
+            TypeInfo::Enum(decl_id) => {
+                let call_path = &engines.de().get_enum(decl_id).call_path;
+                let is_std_enum = |module_name: &str, enum_name: &str| {
+                    call_path.suffix.as_str() == enum_name
+                        && call_path
+                            .prefixes
+                            .iter()
+                            .map(|ident| ident.as_str())
+                            .eq(["std", module_name])
+                };
+                if is_std_enum("option", "Option") {
+                    Some(&try_expression.option_branches)
+                } else if is_std_enum("result", "Result") {
+                    Some(&try_expression.result_branches)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        let Some(branches) = branches else {
+            return Err(
+                handler.emit_err(CompileError::TryOperatorInvalidReturnType {
+                    return_type: engines.help_out(return_type_id).to_string(),
+                    span: try_expression.question_mark_span.clone(),
+                }),
+            );
+        };
+
+        Self::type_check_match_expression(
+            handler,
+            ctx,
+            &try_expression.value,
+            branches.clone(),
+            span,
+        )
+    }
+
     fn type_check_match_expression(
         handler: &Handler,
         mut ctx: TypeCheckContext,
@@ -1453,12 +1529,7 @@ impl ty::TyExpression {
         let mut path = Vec::with_capacity(prefixes.len() + 1);
         path.extend(prefixes.iter().cloned());
         path.push(before.inner.clone());
-        let not_module = {
-            let h = Handler::default();
-            ctx.namespace()
-                .program_id(engines)
-                .read(engines, |m| m.lookup_submodule(&h, engines, &path).is_err())
-        };
+        let not_module = !ctx.namespace().is_module_path(engines, &path);
 
         // Not a module? Not a `Enum::Variant` either?
         // Type check as an associated function call instead.
@@ -1562,7 +1633,6 @@ impl ty::TyExpression {
         let mut maybe_function: Option<(DeclRefFunction, _)> = None;
         let mut maybe_enum: Option<(DeclRefEnum, _, _, _)> = None;
 
-        let module_probe_handler = Handler::default();
         let function_probe_handler = Handler::default();
         let enum_probe_handler = Handler::default();
         let const_probe_handler = Handler::default();
@@ -1575,21 +1645,14 @@ impl ty::TyExpression {
             // Check if this could be a module
             is_module = {
                 let call_path_binding = unknown_call_path_binding.clone();
-                ctx.namespace()
-                    .program_id(ctx.engines())
-                    .read(ctx.engines(), |m| {
-                        m.lookup_submodule(
-                            &module_probe_handler,
-                            ctx.engines(),
-                            &[
-                                call_path_binding.inner.call_path.prefixes.clone(),
-                                vec![call_path_binding.inner.call_path.suffix.clone()],
-                            ]
-                            .concat(),
-                        )
-                        .ok()
-                        .is_some()
-                    })
+                ctx.namespace().is_module_path(
+                    ctx.engines(),
+                    &[
+                        call_path_binding.inner.call_path.prefixes.clone(),
+                        vec![call_path_binding.inner.call_path.suffix.clone()],
+                    ]
+                    .concat(),
+                )
             };
 
             // Check if this could be a function
@@ -1677,7 +1740,6 @@ impl ty::TyExpression {
                 )?
             }
             (true, None, None, None) => {
-                handler.append(module_probe_handler);
                 return Err(handler.emit_err(CompileError::ModulePathIsNotAnExpression {
                     module_path: unknown_call_path_binding.inner.call_path.to_string(),
                     span,
@@ -1698,9 +1760,19 @@ impl ty::TyExpression {
                 instantiate_constant_expression(ctx, const_ref, call_path_binding)
             }
             (false, None, None, None) => {
+                let call_path = &unknown_call_path_binding.inner.call_path;
+                let names_in_scope = if call_path.prefixes.is_empty() {
+                    ctx.namespace()
+                        .module(ctx.engines())
+                        .current_items()
+                        .names_in_scope(|_| true)
+                } else {
+                    NamesInScope::default()
+                };
                 return Err(handler.emit_err(CompileError::SymbolNotFound {
-                    name: unknown_call_path_binding.inner.call_path.suffix.clone(),
-                    span: unknown_call_path_binding.inner.call_path.suffix.span(),
+                    name: call_path.suffix.clone(),
+                    span: call_path.suffix.span(),
+                    names_in_scope,
                 }));
             }
             _ => {
@@ -2173,11 +2245,14 @@ impl ty::TyExpression {
         };
 
         let unit_ty = type_engine.insert(engines, TypeInfo::Tuple(Vec::new()), None);
-        let mut ctx = ctx.with_type_annotation(unit_ty).with_help_text(
-            "A while loop's loop body cannot implicitly return a value. Try \
+        let mut ctx = ctx
+            .with_type_annotation(unit_ty)
+            .with_loop_break_type_annotation(None)
+            .with_help_text(
+                "A while loop's loop body cannot implicitly return a value. Try \
                  assigning it to a mutable variable declared outside of the loop \
                  instead.",
-        );
+            );
         let typed_body = ty::TyCodeBlock::type_check(handler, ctx.by_ref(), body, false)?;
 
         let exp = ty::TyExpression {
@@ -2191,6 +2266,93 @@ impl ty::TyExpression {
         Ok(exp)
     }
 
+    fn type_check_loop(
+        handler: &Handler,
+        ctx: TypeCheckContext,
+        body: &CodeBlock,
+        span: Span,
+    ) -> Result<Self, ErrorEmitted> {
+        let type_engine = ctx.engines.te();
+        let engines = ctx.engines();
+
+        // The type of the loop is the type of the values of its `break`s, which is inferred
+        // while type-checking the body.
+        let break_type = type_engine.insert(engines, TypeInfo::Unknown, None);
+        let unit_ty = type_engine.insert(engines, TypeInfo::Tuple(Vec::new()), None);
+        let mut ctx = ctx
+            .with_type_annotation(unit_ty)
+            .with_loop_break_type_annotation(Some(break_type))
+            .with_help_text(
+                "A loop's body cannot implicitly return a value. Use \"break\" with \
+                 the value instead.",
+            );
+        let typed_body = ty::TyCodeBlock::type_check(handler, ctx.by_ref(), body, false)?;
+
+        // A loop without a `break` never finishes. The first pass over a code block ignores
+        // errors, so a `break` might not have been reached yet and the type is left unknown.
+        let return_type = if !ctx.code_block_first_pass()
+            && matches!(*type_engine.get(break_type), TypeInfo::Unknown)
+        {
+            type_engine.insert(engines, TypeInfo::Never, None)
+        } else {
+            break_type
+        };
+
+        let exp = ty::TyExpression {
+            expression: ty::TyExpressionVariant::Loop { body: typed_body },
+            return_type,
+            span,
+        };
+        Ok(exp)
+    }
+
+    fn type_check_break(
+        handler: &Handler,
+        ctx: TypeCheckContext,
+        value: Option<&Expression>,
+        span: Span,
+    ) -> Result<Self, ErrorEmitted> {
+        let type_engine = ctx.engines.te();
+        let engines = ctx.engines();
+
+        let value = match (value, ctx.loop_break_type_annotation()) {
+            (Some(value), Some(break_type)) => {
+                let mut ctx = ctx
+                    .with_type_annotation(break_type)
+                    .with_help_text("All the \"break\" values of a loop must have the same type.");
+                let value_span = value.span();
+                let value = ty::TyExpression::type_check(handler, ctx.by_ref(), value)
+                    .unwrap_or_else(|err| {
+                        // Recover by giving the loop the error type, so that the loop isn't
+                        // considered to never finish.
+                        let value = ty::TyExpression::error(err, value_span.clone(), engines);
+                        ctx.unify_with_type_annotation(handler, value.return_type, &value_span);
+                        value
+                    });
+                Some(Box::new(value))
+            }
+            (Some(_), None) => {
+                return Err(handler.emit_err(CompileError::BreakWithValueOutsideLoop { span }));
+            }
+            (None, Some(break_type)) => {
+                // A `break` without a value exits the loop with `()`.
+                let unit_ty = type_engine.insert(engines, TypeInfo::Tuple(Vec::new()), None);
+                ctx.with_type_annotation(break_type)
+                    .with_help_text("All the \"break\" values of a loop must have the same type.")
+                    .unify_with_type_annotation(handler, unit_ty, &span);
+                None
+            }
+            (None, None) => None,
+        };
+
+        let exp = ty::TyExpression {
+            expression: ty::TyExpressionVariant::Break(value),
+            return_type: type_engine.insert(engines, TypeInfo::Never, None),
+            span,
+        };
+        Ok(exp)
+    }
+
     fn type_check_for_loop(
         handler: &Handler,
         ctx: TypeCheckContext,
@@ -2523,7 +2685,7 @@ impl ty::TyExpression {
         Ok(typed_expr)
     }
 
-    fn check_ref_mutability_mismatch(
+    pub(crate) fn check_ref_mutability_mismatch(
         expr: &TyExpressionVariant,
         handler: &Handler,
         expr_span: Span,
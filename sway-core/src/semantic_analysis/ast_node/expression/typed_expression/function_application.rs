@@ -6,7 +6,11 @@ use crate::{
         ty::{self, TyFunctionSig},
         *,
     },
-    semantic_analysis::{ast_node::*, TypeCheckContext},
+    semantic_analysis::{
+        ast_node::*, type_check_context::EnforceTypeArguments,
+        typed_expression::is_integer_literal_expression, TypeCheckContext,
+    },
+    type_system::TreatNumericAs,
 };
 use indexmap::IndexMap;
 use sway_error::error::CompileError;
@@ -51,6 +55,35 @@ pub(crate) fn instantiate_function_application(
         false,
     )?;
 
+    // An expected integer type is unified with the return type before the arguments are type
+    // checked, so that it flows into the arguments of generic parameters returned by the function.
+    // This way integer literals in `fn f() -> u8 { id(id(5)) }` are `u8` instead of `u64`.
+    // Only arguments whose type can be inferred from the expected type are considered, so that
+    // mismatches are still reported against the return type.
+    let return_type_id = function_decl.return_type.type_id;
+    let mut returned_arguments = arguments
+        .iter()
+        .zip(&function_decl.parameters)
+        .filter(|(_, param)| param.type_argument.type_id == return_type_id)
+        .map(|(arg, _)| arg)
+        .peekable();
+    if matches!(
+        *engines.te().get(ctx.type_annotation()),
+        TypeInfo::UnsignedInteger(_)
+    ) && returned_arguments.peek().is_some()
+        && returned_arguments.all(is_integer_literal_expression)
+    {
+        engines.te().unify_with_generic(
+            &Handler::default(),
+            engines,
+            return_type_id,
+            ctx.type_annotation(),
+            &call_path_binding.span(),
+            "",
+            None,
+        );
+    }
+
     let typed_arguments =
         type_check_arguments(handler, ctx.by_ref(), arguments, &function_decl.parameters)?;
 
@@ -61,6 +94,23 @@ pub(crate) fn instantiate_function_application(
         &function_decl.parameters,
     )?;
 
+    // The return type can be an associated type of a type parameter, e.g. `<T as Trait>::Item`,
+    // which is only known once the type parameter is inferred from the arguments. If it is
+    // inferred to be a type parameter of the caller, the associated type stays unresolved.
+    if matches!(
+        &*engines.te().get(function_decl.return_type.type_id),
+        TypeInfo::TraitType { trait_type_id, .. }
+            if trait_type_id.is_concrete(engines, TreatNumericAs::Concrete)
+    ) {
+        function_decl.return_type.type_id = ctx.resolve_type(
+            handler,
+            function_decl.return_type.type_id,
+            &span,
+            EnforceTypeArguments::No,
+            None,
+        )?;
+    }
+
     // unify function return type with current ctx.type_annotation().
     engines.te().unify_with_generic(
         handler,
@@ -3,11 +3,13 @@ use crate::{
         engine::{DeclEngineGet, DeclEngineGetParsedDeclId, DeclEngineReplace},
         DeclEngineInsert, DeclRefFunction, ReplaceDecls, UpdateConstantExpression,
     },
+    ir_generation::const_eval::compile_constant_expression_to_constant,
     language::{
         parsed::*,
         ty::{self, TyDecl, TyExpression, TyFunctionSig},
         *,
     },
+    metadata::MetadataManager,
     namespace::TryInsertingTraitImplOnFailure,
     semantic_analysis::{type_check_context::EnforceTypeArguments, *},
     type_system::*,
@@ -20,10 +22,66 @@ use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
-use sway_types::{constants, integer_bits::IntegerBits, BaseIdent, IdentUnique};
+use sway_ir::{Constant, ConstantValue, Context, Kind, Module};
+use sway_types::{constants, integer_bits::IntegerBits, u256::U256, BaseIdent, IdentUnique};
 use sway_types::{constants::CONTRACT_CALL_COINS_PARAMETER_NAME, Spanned};
 use sway_types::{Ident, Span};
 
+/// Returns true if the method is an operator returning a value of the type of its operands, applied
+/// to an integer literal without a type suffix, or to another such operation, like `1 + 2 * 3`.
+fn is_integer_literal_operation(method_name: &MethodName, arguments: &[Expression]) -> bool {
+    const OPERATORS: [&str; 12] = [
+        "add",
+        "subtract",
+        "multiply",
+        "divide",
+        "modulo",
+        "pow",
+        "lsh",
+        "rsh",
+        "binary_and",
+        "binary_or",
+        "binary_xor",
+        "not",
+    ];
+    let MethodName::FromTrait { call_path } = method_name else {
+        return false;
+    };
+    let is_operator = matches!(
+        call_path.prefixes.as_slice(),
+        [core, ops] if core.as_str() == "core" && ops.as_str() == "ops"
+    ) && OPERATORS.contains(&call_path.suffix.as_str());
+    is_operator
+        && arguments
+            .first()
+            .is_some_and(|receiver| match &receiver.kind {
+                ExpressionKind::Literal(Literal::Numeric(_)) => true,
+                ExpressionKind::MethodApplication(method_application) => {
+                    is_integer_literal_operation(
+                        &method_application.method_name_binding.inner,
+                        &method_application.arguments,
+                    )
+                }
+                _ => false,
+            })
+}
+
+/// Returns true if the type of the expression can be inferred from the integer type expected of
+/// it, that is, if it is an integer literal without a type suffix, an operation on such literals,
+/// or a function call whose return type may be inferred from its arguments.
+pub(crate) fn is_integer_literal_expression(expression: &Expression) -> bool {
+    match &expression.kind {
+        ExpressionKind::Literal(Literal::Numeric(_))
+        | ExpressionKind::FunctionApplication(_)
+        | ExpressionKind::AmbiguousPathExpression(_) => true,
+        ExpressionKind::MethodApplication(method_application) => is_integer_literal_operation(
+            &method_application.method_name_binding.inner,
+            &method_application.arguments,
+        ),
+        _ => false,
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn type_check_method_application(
     handler: &Handler,
@@ -42,10 +100,22 @@ pub(crate) fn type_check_method_application(
     // If they fail the args_opt_buf will contain a None value.
     let mut args_opt_buf = VecDeque::new();
     for (index, arg) in arguments.iter().enumerate() {
+        // The operands of an arithmetic or bitwise operation on integer literals, like in
+        // `let x: u8 = 1 + 2;`, take the type expected of the operation instead of `u64`.
+        let type_annotation = if index == 0
+            && is_integer_literal_operation(&method_name_binding.inner, arguments)
+            && matches!(
+                *type_engine.get(ctx.type_annotation()),
+                TypeInfo::UnsignedInteger(_)
+            ) {
+            ctx.type_annotation()
+        } else {
+            type_engine.insert(engines, TypeInfo::Unknown, None)
+        };
         let ctx = ctx
             .by_ref()
             .with_help_text("")
-            .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown, None));
+            .with_type_annotation(type_annotation);
 
         // Ignore errors in method parameters
         // On the second pass we will throw the errors if they persist.
@@ -74,17 +144,11 @@ pub(crate) fn type_check_method_application(
     }
 
     // resolve the method name to a typed function declaration and type_check
-    let (original_decl_ref, call_path_typeid) = resolve_method_name(
+    let (original_decl_ref, call_path_typeid) = resolve_method_name_with_receiver_adjustment(
         handler,
         ctx.by_ref(),
         &method_name_binding,
-        args_opt_buf
-            .iter()
-            .map(|(arg, _has_errors)| match arg {
-                Some(arg) => arg.return_type,
-                None => type_engine.insert(engines, TypeInfo::Unknown, None),
-            })
-            .collect(),
+        &mut args_opt_buf,
     )?;
 
     let mut fn_ref = monomorphize_method(
@@ -141,7 +205,27 @@ pub(crate) fn type_check_method_application(
     }
 
     // check the method visibility
-    if span.source_id() != method.span.source_id() && method.visibility.is_private() {
+    let method_mod_path = &method.call_path.prefixes;
+    if matches!(method.visibility, Visibility::Restricted(_)) && !method_mod_path.is_empty() {
+        // The call path of the method is absolute, starting with the name of its package,
+        // while the module paths of the namespace are relative to the root module.
+        let namespace = ctx.namespace();
+        let src = if namespace.module_is_external(method_mod_path) {
+            &method_mod_path[..]
+        } else {
+            &method_mod_path[1..]
+        };
+        if !namespace
+            .root
+            .is_visible_from(engines, &method.visibility, src, &namespace.mod_path)
+        {
+            return Err(handler.emit_err(CompileError::ImportRestrictedSymbol {
+                name: method.name.clone(),
+                visibility: method.visibility.to_string(),
+                span,
+            }));
+        }
+    } else if span.source_id() != method.span.source_id() && method.visibility.is_private() {
         return Err(handler.emit_err(CompileError::CallingPrivateLibraryMethod {
             name: method.name.as_str().to_string(),
             span,
@@ -312,6 +396,22 @@ pub(crate) fn type_check_method_application(
             ty::TyExpressionVariant::StructFieldAccess { prefix, .. } => {
                 mutability_check(handler, ctx, method_name_binding, span, &prefix.expression)
             }
+            ty::TyExpressionVariant::Deref(reference) => {
+                let type_engine = ctx.engines.te();
+                match &*type_engine.get(reference.return_type) {
+                    TypeInfo::Ref {
+                        to_mutable_value: false,
+                        ..
+                    } => Err(handler.emit_err(
+                        CompileError::MethodRequiresMutableSelfViaNonMutableReference {
+                            method_name: method_name_binding.inner.easy_name(),
+                            reference_type: ctx.engines.help_out(reference.return_type).to_string(),
+                            span: span.clone(),
+                        },
+                    )),
+                    _ => Ok(()),
+                }
+            }
             _ => Ok(()),
         }
     }
@@ -702,6 +802,10 @@ pub(crate) fn type_check_method_application(
         }
     }
 
+    if !ctx.code_block_first_pass() {
+        check_const_divisor_not_zero(handler, &ctx, &call_path, &arguments, &span);
+    }
+
     let fn_app = ty::TyExpressionVariant::FunctionApplication {
         call_path: call_path.clone(),
         arguments,
@@ -722,6 +826,71 @@ pub(crate) fn type_check_method_application(
     Ok(exp)
 }
 
+/// Emits an error if an integer division or modulo operator has a divisor that is a constant zero.
+///
+/// Divisors which can't be evaluated at compile time are not checked.
+fn check_const_divisor_not_zero(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    call_path: &CallPath,
+    arguments: &[(Ident, ty::TyExpression)],
+    span: &Span,
+) {
+    // The `/` and `%` operators are desugared to calls to `core::ops::divide` and
+    // `core::ops::modulo`.
+    let op = match (call_path.prefixes.as_slice(), call_path.suffix.as_str()) {
+        ([core, ops], "divide") if core.as_str() == "core" && ops.as_str() == "ops" => "divide",
+        ([core, ops], "modulo") if core.as_str() == "core" && ops.as_str() == "ops" => {
+            "calculate the remainder"
+        }
+        _ => return,
+    };
+    let [(_, dividend), (_, divisor)] = arguments else {
+        return;
+    };
+    let engines = ctx.engines();
+    if !matches!(
+        *engines.te().get(dividend.return_type),
+        TypeInfo::UnsignedInteger(_) | TypeInfo::Numeric
+    ) {
+        return;
+    }
+
+    let mut context = Context::new(
+        engines.se(),
+        sway_ir::ExperimentalFlags {
+            new_encoding: ctx.experimental.new_encoding,
+        },
+    );
+    let mut md_mgr = MetadataManager::default();
+    let module = Module::new(&mut context, Kind::Library);
+    let is_zero = match compile_constant_expression_to_constant(
+        engines,
+        &mut context,
+        &mut md_mgr,
+        module,
+        Some(ctx.namespace().module(engines)),
+        None,
+        divisor,
+    ) {
+        Ok(Constant {
+            value: ConstantValue::Uint(n),
+            ..
+        }) => n == 0,
+        Ok(Constant {
+            value: ConstantValue::U256(n),
+            ..
+        }) => n == U256::from(0),
+        _ => false,
+    };
+    if is_zero {
+        handler.emit_err(CompileError::DivisionByZero {
+            op,
+            span: span.clone(),
+        });
+    }
+}
+
 /// Unifies the types of the arguments with the types of the parameters. Returns
 /// a list of the arguments with the names of the corresponding parameters.
 fn unify_arguments_and_parameters(
@@ -763,6 +932,187 @@ fn unify_arguments_and_parameters(
     })
 }
 
+/// Resolves the method name like [resolve_method_name] does, but for methods called
+/// using the method call syntax, `receiver.method(args)`, adjusts the receiver
+/// if the method cannot be found for the receiver's type.
+///
+/// The adjustments are tried in order: taking a reference to the receiver, `&receiver`,
+/// and taking a reference to a mutable value, `&mut receiver`. If the method is still not
+/// found and the receiver is a reference, the same is repeated for the dereferenced
+/// receiver, `*receiver`, starting with the dereferenced receiver itself. Values dereferenced
+/// from references to immutable values are never mutably referenced.
+/// The first adjustment for which the method is found replaces the receiver in `args_opt_buf`.
+/// If several methods are applicable for that adjustment, an ambiguity error is emitted.
+fn resolve_method_name_with_receiver_adjustment(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    method_name: &TypeBinding<MethodName>,
+    args_opt_buf: &mut VecDeque<(Option<ty::TyExpression>, bool)>,
+) -> Result<(DeclRefFunction, TypeId), ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let engines = ctx.engines();
+
+    let arguments_types = |receiver: Option<&ty::TyExpression>,
+                           args_opt_buf: &VecDeque<(Option<ty::TyExpression>, bool)>|
+     -> VecDeque<TypeId> {
+        args_opt_buf
+            .iter()
+            .enumerate()
+            .map(|(index, (arg, _has_errors))| match (index, receiver, arg) {
+                (0, Some(receiver), _) => receiver.return_type,
+                (_, _, Some(arg)) => arg.return_type,
+                _ => type_engine.insert(engines, TypeInfo::Unknown, None),
+            })
+            .collect()
+    };
+
+    // Only the receivers of method calls that are successfully type checked are adjusted.
+    let (method_name_ident, receiver) = match (&method_name.inner, args_opt_buf.front()) {
+        (MethodName::FromModule { method_name }, Some((Some(receiver), false))) => {
+            (method_name, receiver.clone())
+        }
+        _ => {
+            return resolve_method_name(
+                handler,
+                ctx,
+                method_name,
+                arguments_types(None, args_opt_buf),
+            )
+        }
+    };
+
+    // Resolves the method for the adjusted receiver, collecting the diagnostics in a separate
+    // handler, so that they can be discarded if the method is not found. Trait implementations
+    // are not lazily inserted for the adjusted receiver types, which can be arbitrarily complex.
+    let try_resolve =
+        |ctx: &mut TypeCheckContext,
+         receiver: &ty::TyExpression,
+         args_opt_buf: &VecDeque<(Option<ty::TyExpression>, bool)>| {
+            let resolve_handler = Handler::default();
+            let module_path = ctx.namespace().prepend_module_path(vec![]);
+            let result = ctx
+                .find_method_for_type(
+                    &resolve_handler,
+                    receiver.return_type,
+                    &module_path,
+                    method_name_ident,
+                    ctx.type_annotation(),
+                    &arguments_types(Some(receiver), args_opt_buf),
+                    None,
+                    TryInsertingTraitImplOnFailure::No,
+                )
+                .map(|decl_ref| (decl_ref, receiver.return_type));
+            (resolve_handler, result)
+        };
+    let is_method_not_found = |resolve_handler: &Handler, result: &Result<_, _>| {
+        result.is_err()
+            && resolve_handler
+                .find_error(|err| !matches!(err, CompileError::MethodNotFound { .. }))
+                .is_none()
+    };
+
+    let reference_to = |receiver: &ty::TyExpression, to_mutable_value: bool| ty::TyExpression {
+        expression: ty::TyExpressionVariant::Ref(Box::new(receiver.clone())),
+        return_type: type_engine.insert(
+            engines,
+            TypeInfo::Ref {
+                to_mutable_value,
+                referenced_type: receiver.return_type.into(),
+            },
+            None,
+        ),
+        span: receiver.span.clone(),
+    };
+
+    // The receiver as is.
+    let unadjusted_handler = Handler::default();
+    let unadjusted_result = resolve_method_name(
+        &unadjusted_handler,
+        ctx.by_ref(),
+        method_name,
+        arguments_types(None, args_opt_buf),
+    );
+    // While collecting unifications the types are not yet fully resolved,
+    // so the receiver is adjusted only in the final type check.
+    if ctx.collecting_unifications()
+        || !is_method_not_found(&unadjusted_handler, &unadjusted_result)
+    {
+        handler.append(unadjusted_handler);
+        return unadjusted_result;
+    }
+
+    let mut receiver = receiver;
+    let mut is_dereferenced = false;
+    loop {
+        let mut candidates = vec![];
+        if is_dereferenced {
+            candidates.push(receiver.clone());
+        }
+        candidates.push(reference_to(&receiver, false));
+        // A value dereferenced from a reference to an immutable value cannot be mutably referenced.
+        let is_deref_of_non_mutable_reference = match &receiver.expression {
+            ty::TyExpressionVariant::Deref(reference) => matches!(
+                &*type_engine.get(reference.return_type),
+                TypeInfo::Ref {
+                    to_mutable_value: false,
+                    ..
+                }
+            ),
+            _ => false,
+        };
+        if !is_deref_of_non_mutable_reference {
+            candidates.push(reference_to(&receiver, true));
+        }
+
+        for candidate in candidates {
+            let (resolve_handler, result) = try_resolve(&mut ctx, &candidate, args_opt_buf);
+            if is_method_not_found(&resolve_handler, &result) {
+                continue;
+            }
+            handler.append(resolve_handler);
+            if let ty::TyExpressionVariant::Ref(value) = &candidate.expression {
+                if let TypeInfo::Ref {
+                    to_mutable_value: true,
+                    ..
+                } = &*type_engine.get(candidate.return_type)
+                {
+                    if let Some(Err(err)) = ty::TyExpression::check_ref_mutability_mismatch(
+                        &value.expression,
+                        handler,
+                        value.span.clone(),
+                        candidate.span.clone(),
+                    ) {
+                        return Err(err);
+                    }
+                }
+            }
+            if result.is_ok() {
+                if let Some((receiver, _)) = args_opt_buf.front_mut() {
+                    *receiver = Some(candidate);
+                }
+            }
+            return result;
+        }
+
+        let referenced_type = match &*type_engine.get(receiver.return_type) {
+            TypeInfo::Ref {
+                referenced_type, ..
+            } => referenced_type.type_id,
+            _ => break,
+        };
+        receiver = ty::TyExpression {
+            span: receiver.span.clone(),
+            expression: ty::TyExpressionVariant::Deref(Box::new(receiver)),
+            return_type: referenced_type,
+        };
+        is_dereferenced = true;
+    }
+
+    // The method is not found for any of the adjustments, so report the original error.
+    handler.append(unadjusted_handler);
+    unadjusted_result
+}
+
 pub(crate) fn resolve_method_name(
     handler: &Handler,
     mut ctx: TypeCheckContext,
@@ -788,7 +1138,7 @@ pub(crate) fn resolve_method_name(
             // find the module that the symbol is in
             let type_info_prefix = ctx
                 .namespace()
-                .prepend_module_path(&call_path_binding.inner.prefixes);
+                .resolve_module_path(engines, &call_path_binding.inner.prefixes);
             ctx.namespace().lookup_submodule_from_absolute_path(
                 handler,
                 engines,
@@ -812,7 +1162,8 @@ pub(crate) fn resolve_method_name(
         MethodName::FromTrait { call_path } => {
             // find the module that the symbol is in
             let module_path = if !call_path.is_absolute {
-                ctx.namespace().prepend_module_path(&call_path.prefixes)
+                ctx.namespace()
+                    .resolve_module_path(engines, &call_path.prefixes)
             } else {
                 let mut module_path = call_path.prefixes.clone();
                 if let (Some(root_mod), root_name) = (
@@ -81,7 +81,7 @@ pub(crate) fn struct_instantiation(
     };
 
     // find the module that the struct decl is in
-    let type_info_prefix = ctx.namespace().prepend_module_path(prefixes);
+    let type_info_prefix = ctx.namespace().resolve_module_path(engines, prefixes);
     ctx.namespace()
         .lookup_submodule_from_absolute_path(handler, engines, &type_info_prefix)?;
 
@@ -423,6 +423,13 @@ fn type_check_field_arguments(
                         name: field.name.clone(),
                     });
                 }
+                // Omitted fields that have a default value are initialized with it.
+                None if struct_field.default_value.is_some() => {
+                    typed_fields.push(ty::TyStructExpressionField {
+                        name: struct_field.name.clone(),
+                        value: *struct_field.default_value.clone().unwrap(),
+                    });
+                }
                 None => {
                     missing_fields.push(struct_field.name.clone());
 
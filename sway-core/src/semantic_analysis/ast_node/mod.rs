@@ -108,21 +108,35 @@ impl ty::TyAstNode {
         };
 
         if let ty::TyAstNode {
-            content: ty::TyAstNodeContent::Expression(ty::TyExpression { expression, .. }),
+            content: ty::TyAstNodeContent::Expression(expr),
             ..
         } = &node
         {
-            match expression {
+            if no_effect_analysis::is_self_assignment(expr) {
+                handler.emit_warn(CompileWarning {
+                    warning_content: Warning::SelfAssignment,
+                    span: node.span.clone(),
+                });
+            }
+            match &expr.expression {
                 ty::TyExpressionVariant::ImplicitReturn(_) => {}
                 _ => {
                     if !node
                         .type_info(type_engine)
                         .can_safely_ignore(type_engine, decl_engine)
                     {
+                        let warning_content =
+                            if no_effect_analysis::is_pure_expression(engines, expr) {
+                                Warning::StatementHasNoEffect
+                            } else {
+                                Warning::UnusedReturnValue {
+                                    r#type: engines
+                                        .help_out(node.type_info(type_engine))
+                                        .to_string(),
+                                }
+                            };
                         handler.emit_warn(CompileWarning {
-                            warning_content: Warning::UnusedReturnValue {
-                                r#type: engines.help_out(node.type_info(type_engine)).to_string(),
-                            },
+                            warning_content,
                             span: node.span.clone(),
                         })
                     };
@@ -141,11 +155,11 @@ fn collect_use_statement(
     stmt: &UseStatement,
 ) {
     let mut is_external = false;
-    if let Some(submodule) = ctx
-        .namespace
-        .module(engines)
-        .submodule(engines, &[stmt.call_path[0].clone()])
-    {
+    if let Some(submodule) = stmt.call_path.first().and_then(|first| {
+        ctx.namespace
+            .module(engines)
+            .submodule(engines, std::slice::from_ref(first))
+    }) {
         is_external |= submodule.read(engines, |m| m.is_external);
     }
     // We create an inner module for each module being processed during the collection.
@@ -153,11 +167,11 @@ fn collect_use_statement(
     // So check again starting from the root to make sure we find the right module.
     // Clean this up once paths are normalized before collection and we can just rely on
     // absolute paths.
-    if let Some(submodule) = ctx
-        .namespace
-        .root_module()
-        .submodule(engines, &[stmt.call_path[0].clone()])
-    {
+    if let Some(submodule) = stmt.call_path.first().and_then(|first| {
+        ctx.namespace
+            .root_module()
+            .submodule(engines, std::slice::from_ref(first))
+    }) {
         is_external |= submodule.read(engines, |m| m.is_external);
     }
     let path = if is_external || stmt.is_absolute {
@@ -169,7 +183,8 @@ fn collect_use_statement(
         ImportType::Star => {
             // try a standard starimport first
             let star_import_handler = Handler::default();
-            let import = ctx.star_import(&star_import_handler, engines, &path, stmt.reexport);
+            let import =
+                ctx.star_import(&star_import_handler, engines, &path, stmt.reexport.clone());
             if import.is_ok() {
                 handler.append(star_import_handler);
                 import
@@ -182,7 +197,7 @@ fn collect_use_statement(
                         engines,
                         path,
                         enum_name,
-                        stmt.reexport,
+                        stmt.reexport.clone(),
                     );
                     if variant_import.is_ok() {
                         handler.append(variant_import_handler);
@@ -197,9 +212,13 @@ fn collect_use_statement(
                 }
             }
         }
-        ImportType::SelfImport(_) => {
-            ctx.self_import(handler, engines, &path, stmt.alias.clone(), stmt.reexport)
-        }
+        ImportType::SelfImport(_) => ctx.self_import(
+            handler,
+            engines,
+            &path,
+            stmt.alias.clone(),
+            stmt.reexport.clone(),
+        ),
         ImportType::Item(ref s) => {
             // try a standard item import first
             let item_import_handler = Handler::default();
@@ -209,7 +228,7 @@ fn collect_use_statement(
                 &path,
                 s,
                 stmt.alias.clone(),
-                stmt.reexport,
+                stmt.reexport.clone(),
             );
 
             if import.is_ok() {
@@ -226,7 +245,7 @@ fn collect_use_statement(
                         enum_name,
                         s,
                         stmt.alias.clone(),
-                        stmt.reexport,
+                        stmt.reexport.clone(),
                     );
                     if variant_import.is_ok() {
                         handler.append(variant_import_handler);
@@ -252,11 +271,11 @@ fn handle_use_statement(
     handler: &Handler,
 ) {
     let mut is_external = false;
-    if let Some(submodule) = ctx
-        .namespace()
-        .module(engines)
-        .submodule(engines, &[stmt.call_path[0].clone()])
-    {
+    if let Some(submodule) = stmt.call_path.first().and_then(|first| {
+        ctx.namespace()
+            .module(engines)
+            .submodule(engines, std::slice::from_ref(first))
+    }) {
         is_external = submodule.read(engines, |m| m.is_external);
     }
     let path = if is_external || stmt.is_absolute {
@@ -268,7 +287,7 @@ fn handle_use_statement(
         ImportType::Star => {
             // try a standard starimport first
             let star_import_handler = Handler::default();
-            let import = ctx.star_import(&star_import_handler, &path, stmt.reexport);
+            let import = ctx.star_import(&star_import_handler, &path, stmt.reexport.clone());
             if import.is_ok() {
                 handler.append(star_import_handler);
                 import
@@ -280,7 +299,7 @@ fn handle_use_statement(
                         &variant_import_handler,
                         path,
                         enum_name,
-                        stmt.reexport,
+                        stmt.reexport.clone(),
                     );
                     if variant_import.is_ok() {
                         handler.append(variant_import_handler);
@@ -296,7 +315,7 @@ fn handle_use_statement(
             }
         }
         ImportType::SelfImport(_) => {
-            ctx.self_import(handler, &path, stmt.alias.clone(), stmt.reexport)
+            ctx.self_import(handler, &path, stmt.alias.clone(), stmt.reexport.clone())
         }
         ImportType::Item(ref s) => {
             // try a standard item import first
@@ -306,7 +325,7 @@ fn handle_use_statement(
                 &path,
                 s,
                 stmt.alias.clone(),
-                stmt.reexport,
+                stmt.reexport.clone(),
             );
 
             if import.is_ok() {
@@ -322,7 +341,7 @@ fn handle_use_statement(
                         enum_name,
                         s,
                         stmt.alias.clone(),
-                        stmt.reexport,
+                        stmt.reexport.clone(),
                     );
                     if variant_import.is_ok() {
                         handler.append(variant_import_handler);
@@ -225,7 +225,7 @@ fn analyze_expression(
         | VariableExpression { .. }
         | FunctionParameter
         | StorageAccess(_)
-        | Break
+        | Break(None)
         | Continue
         | AbiName(_) => effects_of_expression(engines, expr),
         Reassignment(reassgn) => analyze_expression(engines, &reassgn.rhs, block_name, warnings),
@@ -311,6 +311,7 @@ fn analyze_expression(
         | TupleElemAccess { prefix: expr, .. }
         | ImplicitReturn(expr)
         | Return(expr)
+        | Break(Some(expr))
         | EnumTag { exp: expr }
         | UnsafeDowncast { exp: expr, .. }
         | AbiCast { address: expr, .. }
@@ -350,6 +351,16 @@ fn analyze_expression(
             }
             res_effs
         }
+        Loop { body } => {
+            // if the loop body contains both interaction and state effects
+            // in _any_ order, we report CEI pattern violation
+            let res_effs = analyze_code_block(engines, body, block_name, warnings);
+            if res_effs.contains(&Effect::Interaction) {
+                let span = expr.span.clone();
+                warn_after_interaction(&res_effs, &span, &span, &block_name.clone(), warnings)
+            }
+            res_effs
+        }
         ForLoop { desugared } => analyze_expression(engines, desugared, block_name, warnings),
         AsmExpression {
             registers, body, ..
@@ -504,7 +515,7 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
         | ConfigurableExpression { .. }
         | VariableExpression { .. }
         | FunctionParameter
-        | Break
+        | Break(None)
         | Continue
         | AbiName(_) => HashSet::new(),
         // this type of assignment only mutates local variables and not storage
@@ -565,6 +576,7 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
         | UnsafeDowncast { exp: expr, .. }
         | ImplicitReturn(expr)
         | Return(expr)
+        | Break(Some(expr))
         | Ref(expr)
         | Deref(expr) => effects_of_expression(engines, expr),
         EnumInstantiation { contents, .. } => match contents {
@@ -580,6 +592,7 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
             .union(&effects_of_codeblock(engines, body))
             .cloned()
             .collect(),
+        Loop { body } => effects_of_codeblock(engines, body),
         ForLoop { desugared } => effects_of_expression(engines, desugared),
         FunctionApplication {
             fn_ref,
@@ -623,6 +636,7 @@ fn effects_of_intrinsic(intr: &sway_ast::Intrinsic) -> HashSet<Effect> {
         | SizeOfType
         | SizeOfVal
         | SizeOfStr
+        | AlignOfType
         | ContractRet
         | AssertIsStrArray
         | ToStrArray
@@ -649,7 +663,14 @@ fn effects_of_intrinsic(intr: &sway_ast::Intrinsic) -> HashSet<Effect> {
         | EncodeBufferAppend
         | EncodeBufferAsRawSlice
         | Slice
-        | ElemAt => HashSet::new(),
+        | ElemAt
+        | TxInputCount
+        | TxInputType
+        | TxInputAmount
+        | TxOutputCount
+        | TxOutputType
+        | TxOutputAmount
+        | Fmt => HashSet::new(),
     }
 }
 
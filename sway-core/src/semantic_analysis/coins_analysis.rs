@@ -69,6 +69,7 @@ pub fn possibly_nonzero_u64_expression(
         | TupleElemAccess { .. }
         | StorageAccess(_)
         | WhileLoop { .. }
+        | Loop { .. }
         | ForLoop { .. } => true,
         // The following expression variants are unreachable, because of the type system
         // but we still consider these as non-zero to be on the safe side
@@ -83,7 +84,7 @@ pub fn possibly_nonzero_u64_expression(
         | AbiName(_)
         | UnsafeDowncast { .. }
         | EnumTag { .. }
-        | Break
+        | Break(_)
         | Continue
         | Reassignment(_)
         | ImplicitReturn(_)
@@ -0,0 +1,212 @@
+// Issues warnings for functions that have more parameters than
+// `BuildConfig::with_max_function_parameters`, or whose cyclomatic complexity is higher than
+// `BuildConfig::with_max_cyclomatic_complexity`. Both checks are opt-in.
+//
+// The cyclomatic complexity of a function is one plus the number of decision points in its
+// body: `if` expressions and `while` and `for` loops. `match` expressions are measured in their
+// desugared form, in which every arm, except a final catch-all arm, becomes an `if` expression.
+// The `if` expressions generated by the compiler when desugaring `for` loops and matching on
+// string slices or OR patterns have dummy spans and are not counted.
+
+use crate::{
+    language::ty::{self, TyDecl, TyTraitItem},
+    Engines,
+};
+use sway_error::warning::{CompileWarning, Warning};
+use sway_types::Spanned;
+
+struct Limits {
+    max_parameters: Option<usize>,
+    max_complexity: Option<usize>,
+}
+
+pub(crate) fn analyze_program(
+    engines: &Engines,
+    prog: &ty::TyProgram,
+    max_parameters: Option<usize>,
+    max_complexity: Option<usize>,
+) -> Vec<CompileWarning> {
+    let limits = Limits {
+        max_parameters,
+        max_complexity,
+    };
+    let mut warnings = vec![];
+    analyze_module(engines, &limits, &prog.root, &mut warnings);
+    warnings
+}
+
+fn analyze_module(
+    engines: &Engines,
+    limits: &Limits,
+    module: &ty::TyModule,
+    warnings: &mut Vec<CompileWarning>,
+) {
+    for (_, submodule) in module.submodules.iter() {
+        analyze_module(engines, limits, &submodule.module, warnings);
+    }
+    for node in module.all_nodes.iter() {
+        if let ty::TyAstNodeContent::Declaration(decl) = &node.content {
+            analyze_decl(engines, limits, decl, warnings);
+        }
+    }
+}
+
+fn analyze_decl(
+    engines: &Engines,
+    limits: &Limits,
+    decl: &TyDecl,
+    warnings: &mut Vec<CompileWarning>,
+) {
+    let decl_engine = engines.de();
+    let items = match decl {
+        TyDecl::FunctionDecl(decl) => {
+            let decl = decl_engine.get_function(&decl.decl_id);
+            analyze_function(engines, limits, &decl, warnings);
+            return;
+        }
+        TyDecl::ImplSelfOrTrait(decl) => decl_engine
+            .get_impl_self_or_trait(&decl.decl_id)
+            .items
+            .clone(),
+        TyDecl::TraitDecl(decl) => decl_engine.get_trait(&decl.decl_id).items.clone(),
+        _ => return,
+    };
+    for item in items.iter() {
+        if let TyTraitItem::Fn(decl_ref) = item {
+            let decl = decl_engine.get_function(decl_ref.id());
+            analyze_function(engines, limits, &decl, warnings);
+        }
+    }
+}
+
+fn analyze_function(
+    engines: &Engines,
+    limits: &Limits,
+    fn_decl: &ty::TyFunctionDecl,
+    warnings: &mut Vec<CompileWarning>,
+) {
+    // Skip the functions generated by the compiler, like the entry function and the
+    // implementations of the encoding traits.
+    let is_autogenerated = fn_decl
+        .span
+        .source_id()
+        .is_some_and(|source_id| engines.se().is_source_id_autogenerated(source_id));
+    if fn_decl.is_entry() || is_autogenerated {
+        return;
+    }
+
+    if let Some(max_parameters) = limits.max_parameters {
+        let parameters = fn_decl.parameters.len();
+        if parameters > max_parameters {
+            warnings.push(CompileWarning {
+                span: fn_decl.name.span(),
+                warning_content: Warning::TooManyFunctionParameters {
+                    fn_name: fn_decl.name.clone(),
+                    parameters,
+                    max_parameters,
+                },
+            });
+        }
+    }
+
+    if let Some(max_complexity) = limits.max_complexity {
+        let complexity = 1 + code_block_decision_points(&fn_decl.body);
+        if complexity > max_complexity {
+            warnings.push(CompileWarning {
+                span: fn_decl.name.span(),
+                warning_content: Warning::CyclomaticComplexityTooHigh {
+                    fn_name: fn_decl.name.clone(),
+                    complexity,
+                    max_complexity,
+                },
+            });
+        }
+    }
+}
+
+fn code_block_decision_points(codeblock: &ty::TyCodeBlock) -> usize {
+    codeblock
+        .contents
+        .iter()
+        .map(|node| match &node.content {
+            ty::TyAstNodeContent::Declaration(TyDecl::VariableDecl(decl)) => {
+                expression_decision_points(&decl.body)
+            }
+            ty::TyAstNodeContent::Expression(expr) => expression_decision_points(expr),
+            _ => 0,
+        })
+        .sum()
+}
+
+fn expressions_decision_points<'a>(
+    expressions: impl Iterator<Item = &'a ty::TyExpression>,
+) -> usize {
+    expressions.map(expression_decision_points).sum()
+}
+
+fn expression_decision_points(expr: &ty::TyExpression) -> usize {
+    use crate::ty::TyExpressionVariant::*;
+    match &expr.expression {
+        Literal(_)
+        | ConstantExpression { .. }
+        | ConfigurableExpression { .. }
+        | VariableExpression { .. }
+        | FunctionParameter
+        | StorageAccess(_)
+        | Break(None)
+        | Continue
+        | AbiName(_) => 0,
+        FunctionApplication { arguments, .. } => {
+            expressions_decision_points(arguments.iter().map(|(_, e)| e))
+        }
+        IntrinsicFunction(intrinsic) => expressions_decision_points(intrinsic.arguments.iter()),
+        LazyOperator { lhs, rhs, .. } => {
+            expression_decision_points(lhs) + expression_decision_points(rhs)
+        }
+        ArrayIndex { prefix, index } => {
+            expression_decision_points(prefix) + expression_decision_points(index)
+        }
+        Tuple { fields: exprs }
+        | Array {
+            elem_type: _,
+            contents: exprs,
+        } => expressions_decision_points(exprs.iter()),
+        StructExpression { fields, .. } => {
+            expressions_decision_points(fields.iter().map(|f| &f.value))
+        }
+        CodeBlock(codeblock) => code_block_decision_points(codeblock),
+        StructFieldAccess { prefix: expr, .. }
+        | TupleElemAccess { prefix: expr, .. }
+        | EnumTag { exp: expr }
+        | UnsafeDowncast { exp: expr, .. }
+        | AbiCast { address: expr, .. }
+        | ImplicitReturn(expr)
+        | Return(expr)
+        | Break(Some(expr))
+        | Ref(expr)
+        | Deref(expr) => expression_decision_points(expr),
+        EnumInstantiation { contents, .. } => {
+            contents.as_deref().map_or(0, expression_decision_points)
+        }
+        MatchExp { desugared, .. } => expression_decision_points(desugared),
+        IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            usize::from(!expr.span.is_dummy())
+                + expression_decision_points(condition)
+                + expression_decision_points(then)
+                + r#else.as_deref().map_or(0, expression_decision_points)
+        }
+        WhileLoop { condition, body } => {
+            1 + expression_decision_points(condition) + code_block_decision_points(body)
+        }
+        Loop { body } => 1 + code_block_decision_points(body),
+        ForLoop { desugared } => expression_decision_points(desugared),
+        AsmExpression { registers, .. } => {
+            expressions_decision_points(registers.iter().filter_map(|r| r.initializer.as_ref()))
+        }
+        Reassignment(reassignment) => expression_decision_points(&reassignment.rhs),
+    }
+}
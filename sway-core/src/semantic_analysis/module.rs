@@ -562,28 +562,27 @@ impl ty::TyModule {
                 continue;
             };
 
-            if ctx.experimental.new_encoding {
-                let mut generated = vec![];
-                if let (true, Some(mut ctx)) = (
+            let mut generated = vec![];
+            if let TyAstNodeContent::Declaration(
+                decl @ (TyDecl::StructDecl(_) | TyDecl::EnumDecl(_)),
+            ) = &node.content
+            {
+                if let (true, true, Some(mut ctx)) = (
+                    ctx.experimental.new_encoding,
                     auto_impl_encoding_traits,
                     EncodingAutoImplContext::new(&mut ctx),
                 ) {
-                    match &node.content {
-                        TyAstNodeContent::Declaration(decl @ TyDecl::StructDecl(_))
-                        | TyAstNodeContent::Declaration(decl @ TyDecl::EnumDecl(_)) => {
-                            let (a, b) = ctx.generate(engines, decl);
-                            generated.extend(a);
-                            generated.extend(b);
-                        }
-                        _ => {}
-                    }
-                };
-
-                typed_nodes.push(node);
-                typed_nodes.extend(generated);
-            } else {
-                typed_nodes.push(node);
+                    let (a, b) = ctx.generate(engines, decl);
+                    generated.extend(a);
+                    generated.extend(b);
+                }
+                if let Some(mut ctx) = EncodingAutoImplContext::new(&mut ctx) {
+                    generated.extend(ctx.generate_derives(handler, engines, decl));
+                }
             }
+
+            typed_nodes.push(node);
+            typed_nodes.extend(generated);
         }
 
         Ok(typed_nodes)
@@ -689,7 +688,7 @@ impl ty::TySubmodule {
         parent_ctx.enter_submodule(
             engines,
             mod_name,
-            *visibility,
+            visibility.clone(),
             module.span.clone(),
             |submod_ctx| ty::TyModule::collect(handler, engines, submod_ctx, module),
         )
@@ -709,13 +708,24 @@ impl ty::TySubmodule {
             mod_name_span,
             visibility,
         } = submodule;
-        parent_ctx.enter_submodule(mod_name, *visibility, module.span.clone(), |submod_ctx| {
-            let module_res =
-                ty::TyModule::type_check(handler, submod_ctx, engines, kind, module, build_config);
-            module_res.map(|module| ty::TySubmodule {
-                module,
-                mod_name_span: mod_name_span.clone(),
-            })
-        })
+        parent_ctx.enter_submodule(
+            mod_name,
+            visibility.clone(),
+            module.span.clone(),
+            |submod_ctx| {
+                let module_res = ty::TyModule::type_check(
+                    handler,
+                    submod_ctx,
+                    engines,
+                    kind,
+                    module,
+                    build_config,
+                );
+                module_res.map(|module| ty::TySubmodule {
+                    module,
+                    mod_name_span: mod_name_span.clone(),
+                })
+            },
+        )
     }
 }
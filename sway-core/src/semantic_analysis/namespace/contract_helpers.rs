@@ -77,7 +77,7 @@ fn default_with_contract_id_inner(
     let attributes = Default::default();
     // convert to const decl
     let const_decl_id = to_parsed_lang::item_const_to_constant_declaration(
-        &mut to_parsed_lang::Context::new(crate::BuildTarget::EVM, experimental),
+        &mut to_parsed_lang::Context::new(crate::BuildTarget::EVM, false, vec![], experimental),
         handler,
         engines,
         const_item,
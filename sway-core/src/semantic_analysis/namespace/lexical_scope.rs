@@ -15,12 +15,12 @@ use super::{root::ResolvedDeclaration, TraitMap};
 
 use parking_lot::RwLock;
 use sway_error::{
-    error::{CompileError, ShadowingSource, StructFieldUsageContext},
+    error::{CompileError, NamesInScope, ShadowingSource, StructFieldUsageContext},
     handler::{ErrorEmitted, Handler},
 };
 use sway_types::{span::Span, IdentUnique, Spanned};
 
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
 pub enum ResolvedFunctionDecl {
     Parsed(ParsedDeclId<FunctionDeclaration>),
@@ -52,6 +52,7 @@ pub(super) type ItemSynonyms = im::HashMap<
         Visibility,
     ),
 >;
+pub(super) type ModuleSynonyms = im::HashMap<Ident, (ModulePathBuf, Visibility)>;
 
 /// Represents a lexical scope integer-based identifier, which can be used to reference
 /// specific a lexical scope.
@@ -98,6 +99,11 @@ pub struct Items {
     /// For aliased item imports `use ::foo::bar::Baz as Wiz` the map key is `Wiz`. `Baz` is stored
     /// as the optional source identifier for error reporting purposes.
     pub(crate) use_item_synonyms: ItemSynonyms,
+    /// Contains modules imported using module imports (`use foo::bar` where `bar` is a module).
+    ///
+    /// For aliased module imports `use ::foo::bar as baz` the map key is `baz`, and only `baz`
+    /// can be used as the first segment of paths into the imported module.
+    pub(crate) use_module_synonyms: ModuleSynonyms,
     /// If there is a storage declaration (which are only valid in contracts), store it here.
     pub(crate) declared_storage: Option<DeclRefStorage>,
 }
@@ -108,6 +114,20 @@ impl Items {
         &self.symbols
     }
 
+    /// Returns the declaration `name` refers to in this scope, whether it is declared in the scope
+    /// or imported into it, e.g. by a re-export.
+    ///
+    /// Names bound by more than one glob import are ambiguous and return `None`.
+    pub fn resolve_symbol(&self, name: &Ident) -> Option<&ResolvedDeclaration> {
+        self.symbols
+            .get(name)
+            .or_else(|| self.use_item_synonyms.get(name).map(|(_, _, decl, _)| decl))
+            .or_else(|| match self.use_glob_synonyms.get(name) {
+                Some(decls) if decls.len() == 1 => Some(&decls[0].1),
+                _ => None,
+            })
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn apply_storage_load(
         &self,
@@ -694,6 +714,30 @@ impl Items {
         }
     }
 
+    /// Returns the names of the symbols declared in or imported into this scope whose
+    /// declarations satisfy the `filter`. Used to suggest similar names for unresolved symbols.
+    pub(crate) fn names_in_scope(
+        &self,
+        filter: impl Fn(&ResolvedDeclaration) -> bool,
+    ) -> NamesInScope {
+        let declared = self.symbols.iter();
+        let item_imports = self
+            .use_item_synonyms
+            .iter()
+            .map(|(name, (_, _, decl, _))| (name, decl));
+        let glob_imports = self
+            .use_glob_synonyms
+            .iter()
+            .flat_map(|(name, decls)| decls.iter().map(move |(_, decl, _)| (name, decl)));
+        let names = declared
+            .chain(item_imports)
+            .chain(glob_imports)
+            .filter(|(_, decl)| filter(decl))
+            .map(|(name, _)| name.to_string())
+            .collect::<BTreeSet<_>>();
+        NamesInScope(names.into_iter().collect())
+    }
+
     pub(crate) fn check_symbol(&self, name: &Ident) -> Result<ResolvedDeclaration, CompileError> {
         self.symbols
             .get(name)
@@ -701,6 +745,7 @@ impl Items {
             .ok_or_else(|| CompileError::SymbolNotFound {
                 name: name.clone(),
                 span: name.span(),
+                names_in_scope: NamesInScope::default(),
             })
     }
 
@@ -715,6 +760,7 @@ impl Items {
             .ok_or_else(|| CompileError::SymbolNotFound {
                 name: name.clone(),
                 span: name.span(),
+                names_in_scope: NamesInScope::default(),
             })
     }
 
@@ -815,6 +861,7 @@ impl Items {
                 return Err(handler.emit_err(CompileError::UnknownVariable {
                     var_name: base_name.clone(),
                     span: base_name.span(),
+                    names_in_scope: self.names_in_scope(ResolvedDeclaration::is_value),
                 }));
             }
         };
@@ -62,6 +62,34 @@ impl Namespace {
             .unwrap_or_else(|| panic!("Could not retrieve submodule for mod_path."))
     }
 
+    /// Returns the absolute path of the module that the `prefixes` refer to from within the
+    /// current module.
+    ///
+    /// Like [Self::prepend_module_path], except that if the first of the `prefixes` is the name
+    /// of a module imported into the current module, it is replaced by the path of that module.
+    pub(crate) fn resolve_module_path(
+        &self,
+        engines: &Engines,
+        prefixes: &[Ident],
+    ) -> ModulePathBuf {
+        match self.root.module_synonym(engines, &self.mod_path, prefixes) {
+            Some(synonym_path) => synonym_path
+                .into_iter()
+                .chain(prefixes[1..].iter().cloned())
+                .collect(),
+            None => self.prepend_module_path(prefixes),
+        }
+    }
+
+    /// Returns true if the `path`, relative to the current module, refers to a module, i.e. to
+    /// a submodule of the current module, or to a module imported into the current module.
+    pub(crate) fn is_module_path(&self, engines: &Engines, path: &ModulePath) -> bool {
+        self.root
+            .module
+            .submodule(engines, &self.resolve_module_path(engines, path))
+            .is_some()
+    }
+
     /// Initialise the namespace at its root from the given initial namespace.
     /// If the root module contains submodules these are now considered external.
     pub fn init_root(root: &mut Root) -> Self {
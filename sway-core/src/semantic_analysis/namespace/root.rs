@@ -15,7 +15,7 @@ use crate::{
     TypeId, TypeInfo,
 };
 use sway_error::{
-    error::CompileError,
+    error::{CompileError, NamesInScope},
     handler::{ErrorEmitted, Handler},
 };
 use sway_types::{Named, Spanned};
@@ -69,6 +69,44 @@ impl ResolvedDeclaration {
         }
     }
 
+    /// Returns true if the declaration can be referred to by a variable expression,
+    /// i.e., if it is a variable, a constant or a configurable.
+    pub(crate) fn is_value(&self) -> bool {
+        match self {
+            ResolvedDeclaration::Parsed(decl) => matches!(
+                decl,
+                Declaration::VariableDeclaration(_)
+                    | Declaration::ConstantDeclaration(_)
+                    | Declaration::ConfigurableDeclaration(_)
+            ),
+            ResolvedDeclaration::Typed(decl) => matches!(
+                decl,
+                TyDecl::VariableDecl(_) | TyDecl::ConstantDecl(_) | TyDecl::ConfigurableDecl(_)
+            ),
+        }
+    }
+
+    /// Returns true if the declaration can be referred to as a type.
+    pub(crate) fn is_type(&self) -> bool {
+        match self {
+            ResolvedDeclaration::Parsed(decl) => matches!(
+                decl,
+                Declaration::StructDeclaration(_)
+                    | Declaration::EnumDeclaration(_)
+                    | Declaration::TypeAliasDeclaration(_)
+                    | Declaration::TraitTypeDeclaration(_)
+            ),
+            ResolvedDeclaration::Typed(decl) => matches!(
+                decl,
+                TyDecl::StructDecl(_)
+                    | TyDecl::EnumDecl(_)
+                    | TyDecl::TypeAliasDecl(_)
+                    | TyDecl::TraitTypeDecl(_)
+                    | TyDecl::GenericTypeForFunctionScope(_)
+            ),
+        }
+    }
+
     pub fn resolve_parsed(self, decl_engine: &DeclEngine) -> Declaration {
         match self {
             ResolvedDeclaration::Parsed(decl) => decl,
@@ -205,17 +243,33 @@ impl Root {
 
         // Collect all items declared in the source module
         for (symbol, decl) in src_mod.current_items().symbols.iter() {
-            if is_ancestor(src, dst) || decl.visibility(engines).is_public() {
+            if self.is_visible_from(engines, &decl.visibility(engines), src, dst) {
                 decls_and_item_imports.push((symbol.clone(), decl.clone(), src.to_vec()));
             }
         }
+        // A `pub use` may reexport a restricted item from within its scope, so restricted items
+        // must additionally be visible from `dst` at their original declaration.
+        let is_reexported =
+            |src_visibility: &Visibility, decl: &ResolvedDeclaration, path: &[Ident]| {
+                match src_visibility {
+                    Visibility::Public => match decl.visibility(engines) {
+                        restricted @ Visibility::Restricted(_) => {
+                            self.is_visible_from(engines, &restricted, path, dst)
+                        }
+                        Visibility::Public | Visibility::Private => true,
+                    },
+                    Visibility::Private | Visibility::Restricted(_) => false,
+                }
+            };
+
         // Collect those item-imported items that the source module reexports
         // These live in the same namespace as local declarations, so no shadowing is possible
         for (symbol, (_, path, decl, src_visibility)) in
             src_mod.current_items().use_item_synonyms.iter()
         {
-            if src_visibility.is_public() {
-                decls_and_item_imports.push((symbol.clone(), decl.clone(), get_path(path.clone())))
+            let path = get_path(path.clone());
+            if is_reexported(src_visibility, decl, &path) {
+                decls_and_item_imports.push((symbol.clone(), decl.clone(), path))
             }
         }
 
@@ -230,8 +284,9 @@ impl Root {
                 .any(|(other_symbol, _, _)| symbol == other_symbol)
             {
                 for (path, decl, src_visibility) in bindings.iter() {
-                    if src_visibility.is_public() {
-                        glob_imports.push((symbol.clone(), decl.clone(), get_path(path.clone())))
+                    let path = get_path(path.clone());
+                    if is_reexported(src_visibility, decl, &path) {
+                        glob_imports.push((symbol.clone(), decl.clone(), path))
                     }
                 }
             }
@@ -253,7 +308,7 @@ impl Root {
                     symbol.clone(),
                     path.clone(),
                     decl,
-                    visibility,
+                    visibility.clone(),
                 )
             });
 
@@ -289,18 +344,19 @@ impl Root {
         let src_items = src_mod.current_items();
 
         let (decl, path, src_visibility) = if let Some(decl) = src_items.symbols.get(item) {
-            let visibility = if is_ancestor(src, dst) {
+            let visibility = decl.visibility(engines);
+            let visibility = if self.is_visible_from(engines, &visibility, src, dst) {
                 Visibility::Public
             } else {
-                decl.visibility(engines)
+                visibility
             };
             (decl.clone(), src.to_vec(), visibility)
         } else if let Some((_, path, decl, reexport)) = src_items.use_item_synonyms.get(item) {
-            (decl.clone(), path.clone(), *reexport)
+            (decl.clone(), path.clone(), reexport.clone())
         } else if let Some(decls) = src_items.use_glob_synonyms.get(item) {
             if decls.len() == 1 {
                 let (path, decl, reexport) = &decls[0];
-                (decl.clone(), path.clone(), *reexport)
+                (decl.clone(), path.clone(), reexport.clone())
             } else if decls.is_empty() {
                 return Err(handler.emit_err(CompileError::Internal(
 			"The name {symbol} was bound in a star import, but no corresponding module paths were found",
@@ -331,14 +387,25 @@ impl Root {
             return Err(handler.emit_err(CompileError::SymbolNotFound {
                 name: item.clone(),
                 span: item.span(),
+                names_in_scope: src_items.names_in_scope(|_| true),
             }));
         };
 
-        if !src_visibility.is_public() {
-            handler.emit_err(CompileError::ImportPrivateSymbol {
-                name: item.clone(),
-                span: item.span(),
-            });
+        match src_visibility {
+            Visibility::Public => {}
+            Visibility::Private => {
+                handler.emit_err(CompileError::ImportPrivateSymbol {
+                    name: item.clone(),
+                    span: item.span(),
+                });
+            }
+            Visibility::Restricted(_) => {
+                handler.emit_err(CompileError::ImportRestrictedSymbol {
+                    name: item.clone(),
+                    visibility: src_visibility.to_string(),
+                    span: item.span(),
+                });
+            }
         }
 
         Ok((decl, path))
@@ -361,6 +428,18 @@ impl Root {
         self.check_module_privacy(handler, engines, src)?;
         let src_mod = self.module.lookup_submodule(handler, engines, src)?;
 
+        if let Some(module_path) = self.module_lookup(engines, item, src, dst) {
+            return self.module_import(
+                handler,
+                engines,
+                &module_path,
+                item,
+                dst,
+                alias,
+                visibility,
+            );
+        }
+
         let (decl, path) = self.item_lookup(handler, engines, item, src, dst)?;
 
         let mut impls_to_insert = TraitMap::default();
@@ -430,6 +509,96 @@ impl Root {
         Ok(())
     }
 
+    /// Returns the path of the module named `item` within the `src` module, if `item` refers to
+    /// a module and not to a symbol.
+    ///
+    /// The module is either a submodule of `src`, or a module imported into `src` that is
+    /// visible from the `dst` module.
+    fn module_lookup(
+        &self,
+        engines: &Engines,
+        item: &Ident,
+        src: &ModulePath,
+        dst: &ModulePath,
+    ) -> Option<ModulePathBuf> {
+        let src_mod = self.module.submodule(engines, src)?;
+        let src_items = src_mod.current_items();
+        if src_items.symbols.contains_key(item)
+            || src_items.use_item_synonyms.contains_key(item)
+            || src_items.use_glob_synonyms.contains_key(item)
+        {
+            return None;
+        }
+        if src_mod.submodules.contains_key(item.as_str()) {
+            return Some(src.iter().chain(std::iter::once(item)).cloned().collect());
+        }
+        match src_items.use_module_synonyms.get(item) {
+            Some((path, visibility)) if self.is_visible_from(engines, visibility, src, dst) => {
+                Some(path.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Import the module at the absolute `src` path into the `dst` module, under the name
+    /// `alias` if given, or under the module's own name `item` otherwise.
+    ///
+    /// Paths within the imported module can then be written starting with the name the module
+    /// was imported under, e.g. `use ::foo::bar as baz;` allows for `baz::symbol`.
+    #[allow(clippy::too_many_arguments)]
+    fn module_import(
+        &mut self,
+        handler: &Handler,
+        engines: &Engines,
+        src: &ModulePath,
+        item: &Ident,
+        dst: &ModulePath,
+        alias: Option<Ident>,
+        visibility: Visibility,
+    ) -> Result<(), ErrorEmitted> {
+        if !is_ancestor(src, dst) {
+            self.check_module_privacy(handler, engines, src)?;
+        }
+
+        let dst_mod = self.module.lookup_submodule_mut(handler, engines, dst)?;
+        let name = alias.unwrap_or_else(|| item.clone());
+        let dst_items = dst_mod.current_items_mut();
+        if dst_items.use_item_synonyms.contains_key(&name)
+            || dst_items.use_module_synonyms.contains_key(&name)
+        {
+            handler.emit_err(CompileError::ShadowsOtherSymbol {
+                name: (&name).into(),
+            });
+        }
+        dst_items
+            .use_module_synonyms
+            .insert(name, (src.to_vec(), visibility));
+
+        Ok(())
+    }
+
+    /// Returns the path of the module imported into the `mod_path` module under the name
+    /// of the first of the `prefixes`, if there is one.
+    ///
+    /// Submodules of the `mod_path` module take precedence over imported modules.
+    pub(crate) fn module_synonym(
+        &self,
+        engines: &Engines,
+        mod_path: &ModulePath,
+        prefixes: &[Ident],
+    ) -> Option<ModulePathBuf> {
+        let first = prefixes.first()?;
+        let module = self.module.submodule(engines, mod_path)?;
+        if module.submodules.contains_key(first.as_str()) {
+            return None;
+        }
+        module
+            .current_items()
+            .use_module_synonyms
+            .get(first)
+            .map(|(path, _)| path.clone())
+    }
+
     /// Pull a single variant `variant` from the enum `enum_name` from the given `src` module and import it into the `dst` module.
     ///
     /// Paths are assumed to be absolute.
@@ -516,6 +685,7 @@ impl Root {
                         return Err(handler.emit_err(CompileError::SymbolNotFound {
                             name: variant_name.clone(),
                             span: variant_name.span(),
+                            names_in_scope: NamesInScope::default(),
                         }));
                     }
                 }
@@ -584,6 +754,7 @@ impl Root {
                         return Err(handler.emit_err(CompileError::SymbolNotFound {
                             name: variant_name.clone(),
                             span: variant_name.span(),
+                            names_in_scope: NamesInScope::default(),
                         }));
                     }
                 } else {
@@ -639,7 +810,7 @@ impl Root {
                             variant_name.clone(),
                             path.clone(),
                             &ResolvedDeclaration::Parsed(variant_decl),
-                            visibility,
+                            visibility.clone(),
                         );
                 }
             }
@@ -669,7 +840,7 @@ impl Root {
                             variant_name.clone(),
                             path.clone(),
                             &decl,
-                            visibility,
+                            visibility.clone(),
                         );
                 }
             }
@@ -708,6 +879,39 @@ impl Root {
         Ok(())
     }
 
+    /// Returns true if an item with the given `visibility`, declared in the `src` module,
+    /// can be accessed from the `dst` module.
+    ///
+    /// The path of a restricted visibility is relative to the root of the package the item
+    /// is declared in, which, for items of external packages, is a submodule of the root.
+    pub(crate) fn is_visible_from(
+        &self,
+        engines: &Engines,
+        visibility: &Visibility,
+        src: &ModulePath,
+        dst: &ModulePath,
+    ) -> bool {
+        match visibility {
+            Visibility::Public => true,
+            Visibility::Private => is_ancestor(src, dst),
+            Visibility::Restricted(path) => {
+                let package_path = match src.first() {
+                    Some(first)
+                        if self
+                            .module
+                            .submodule(engines, std::slice::from_ref(first))
+                            .is_some_and(|module| module.is_external) =>
+                    {
+                        &src[..1]
+                    }
+                    _ => &[],
+                };
+                let scope = package_path.iter().chain(path).cloned().collect::<Vec<_>>();
+                is_ancestor(&scope, dst)
+            }
+        }
+    }
+
     ////// NAME RESOLUTION //////
 
     /// Resolve a symbol that is potentially prefixed with some path, e.g. `foo::bar::symbol`.
@@ -735,11 +939,19 @@ impl Root {
         call_path: &CallPath,
         self_type: Option<TypeId>,
     ) -> Result<(ResolvedDeclaration, Vec<Ident>), ErrorEmitted> {
-        let symbol_path: Vec<_> = mod_path
-            .iter()
-            .chain(&call_path.prefixes)
-            .cloned()
-            .collect();
+        let symbol_path: Vec<_> = match self.module_synonym(engines, mod_path, &call_path.prefixes)
+        {
+            Some(synonym_path) => synonym_path
+                .iter()
+                .chain(&call_path.prefixes[1..])
+                .cloned()
+                .collect(),
+            None => mod_path
+                .iter()
+                .chain(&call_path.prefixes)
+                .cloned()
+                .collect(),
+        };
         self.resolve_symbol_and_mod_path(
             handler,
             engines,
@@ -947,6 +1159,7 @@ impl Root {
                     return Err(handler.emit_err(CompileError::SymbolNotFound {
                         name: symbol.clone(),
                         span: symbol.span(),
+                        names_in_scope: NamesInScope::default(),
                     }))
                 }
             }),
@@ -1070,6 +1283,7 @@ impl Root {
         Err(handler.emit_err(CompileError::SymbolNotFound {
             name: symbol.clone(),
             span: symbol.span(),
+            names_in_scope: module.current_items().names_in_scope(|_| true),
         }))
     }
 }
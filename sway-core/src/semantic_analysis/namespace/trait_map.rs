@@ -7,7 +7,7 @@ use std::{
 };
 
 use sway_error::{
-    error::CompileError,
+    error::{CompileError, NamesInScope},
     handler::{ErrorEmitted, Handler},
 };
 use sway_types::{integer_bits::IntegerBits, BaseIdent, Ident, Span, Spanned};
@@ -1321,6 +1321,7 @@ impl TraitMap {
             Ordering::Less => Err(handler.emit_err(CompileError::SymbolNotFound {
                 name: symbol.clone(),
                 span: symbol.span(),
+                names_in_scope: NamesInScope::default(),
             })),
             Ordering::Equal => Ok(candidates.values().next().unwrap().clone()),
         }
@@ -0,0 +1,201 @@
+// Recognizes statements that have no effect, i.e. self-assignments like `x = x;` and
+// expression statements, like `a == b;`, whose value is discarded and whose evaluation
+// has no side effects. Warnings for those are issued while type checking the statements.
+// An expression is considered to have no side effects if it does not mutate anything,
+// does not interact with storage, contracts or the outside world, does not change the
+// control flow, and cannot revert. E.g., `a + b;` is not reported, because the addition
+// can overflow and revert. Function calls are followed into the bodies of the
+// called functions, so that e.g. operators on primitive types are recognized as pure.
+// The analysis is conservative: whenever it is not certain that an expression is pure,
+// it assumes that it has side effects, and no warning is issued.
+
+use crate::{
+    decl_engine::DeclId,
+    language::ty::{self, TyDecl},
+    Engines,
+};
+use std::collections::HashSet;
+
+/// Returns true if the expression is a reassignment of a variable to itself, e.g. `x = x`.
+pub(crate) fn is_self_assignment(expr: &ty::TyExpression) -> bool {
+    use crate::ty::TyExpressionVariant::*;
+    match &expr.expression {
+        Reassignment(reassignment) => match (&reassignment.lhs, &reassignment.rhs.expression) {
+            (
+                ty::TyReassignmentTarget::ElementAccess {
+                    base_name, indices, ..
+                },
+                VariableExpression { name, .. },
+            ) => indices.is_empty() && base_name == name,
+            _ => false,
+        },
+        ImplicitReturn(expr) => is_self_assignment(expr),
+        _ => false,
+    }
+}
+
+/// Returns true if evaluating the expression certainly has no side effects.
+pub(crate) fn is_pure_expression(engines: &Engines, expr: &ty::TyExpression) -> bool {
+    PurityAnalysis {
+        engines,
+        visited_fns: HashSet::new(),
+    }
+    .is_pure_expression(expr)
+}
+
+struct PurityAnalysis<'a> {
+    engines: &'a Engines,
+    // Functions whose bodies are being analyzed, to guard against recursive calls.
+    visited_fns: HashSet<DeclId<ty::TyFunctionDecl>>,
+}
+
+impl PurityAnalysis<'_> {
+    fn is_pure_expressions<'e>(
+        &mut self,
+        mut exprs: impl Iterator<Item = &'e ty::TyExpression>,
+    ) -> bool {
+        exprs.all(|expr| self.is_pure_expression(expr))
+    }
+
+    fn is_pure_expression(&mut self, expr: &ty::TyExpression) -> bool {
+        use crate::ty::TyExpressionVariant::*;
+        match &expr.expression {
+            Literal(_)
+            | ConstantExpression { .. }
+            | ConfigurableExpression { .. }
+            | VariableExpression { .. }
+            | FunctionParameter
+            | StorageAccess(_)
+            | AbiName(_) => true,
+            // these change the control flow, mutate values, or might never terminate
+            Break(_)
+            | Continue
+            | Return(_)
+            | Reassignment(_)
+            | WhileLoop { .. }
+            | Loop { .. }
+            | ForLoop { .. } => false,
+            LazyOperator { lhs, rhs, .. }
+            | ArrayIndex {
+                prefix: lhs,
+                index: rhs,
+            } => self.is_pure_expression(lhs) && self.is_pure_expression(rhs),
+            Tuple { fields: exprs }
+            | Array {
+                elem_type: _,
+                contents: exprs,
+            } => self.is_pure_expressions(exprs.iter()),
+            StructExpression { fields, .. } => {
+                self.is_pure_expressions(fields.iter().map(|f| &f.value))
+            }
+            CodeBlock(codeblock) => self.is_pure_code_block(codeblock),
+            MatchExp { desugared, .. } => self.is_pure_expression(desugared),
+            IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                self.is_pure_expression(condition)
+                    && self.is_pure_expression(then)
+                    && r#else
+                        .as_ref()
+                        .map_or(true, |r#else| self.is_pure_expression(r#else))
+            }
+            StructFieldAccess { prefix: expr, .. }
+            | TupleElemAccess { prefix: expr, .. }
+            | EnumTag { exp: expr }
+            | UnsafeDowncast { exp: expr, .. }
+            | AbiCast { address: expr, .. }
+            | ImplicitReturn(expr)
+            | Ref(expr)
+            | Deref(expr) => self.is_pure_expression(expr),
+            EnumInstantiation { contents, .. } => contents
+                .as_ref()
+                .map_or(true, |expr| self.is_pure_expression(expr)),
+            IntrinsicFunction(intrinsic) => {
+                is_pure_intrinsic(&intrinsic.kind)
+                    && self.is_pure_expressions(intrinsic.arguments.iter())
+            }
+            // only asm blocks without instructions, used for reinterpreting values, are pure
+            AsmExpression {
+                registers, body, ..
+            } => {
+                body.is_empty()
+                    && self.is_pure_expressions(
+                        registers.iter().filter_map(|r| r.initializer.as_ref()),
+                    )
+            }
+            FunctionApplication {
+                fn_ref,
+                arguments,
+                selector,
+                ..
+            } => {
+                // external contract calls are interactions
+                selector.is_none()
+                    && self.is_pure_expressions(arguments.iter().map(|(_, e)| e))
+                    && self.is_pure_function(fn_ref.id())
+            }
+        }
+    }
+
+    fn is_pure_function(&mut self, fn_id: &DeclId<ty::TyFunctionDecl>) -> bool {
+        if !self.visited_fns.insert(*fn_id) {
+            return false;
+        }
+        let fn_decl = self.engines.de().get_function(fn_id);
+        // an empty body belongs to a function whose implementation is not known,
+        // e.g. to a trait method called on a generic type
+        let is_pure = !fn_decl.body.contents.is_empty() && self.is_pure_code_block(&fn_decl.body);
+        self.visited_fns.remove(fn_id);
+        is_pure
+    }
+
+    fn is_pure_code_block(&mut self, codeblock: &ty::TyCodeBlock) -> bool {
+        codeblock.contents.iter().all(|node| match &node.content {
+            ty::TyAstNodeContent::Declaration(TyDecl::VariableDecl(decl)) => {
+                self.is_pure_expression(&decl.body)
+            }
+            ty::TyAstNodeContent::Declaration(_) | ty::TyAstNodeContent::SideEffect(_) => true,
+            ty::TyAstNodeContent::Expression(expr) => self.is_pure_expression(expr),
+            ty::TyAstNodeContent::Error(_, _) => false,
+        })
+    }
+}
+
+fn is_pure_intrinsic(intr: &sway_ast::Intrinsic) -> bool {
+    use sway_ast::Intrinsic::*;
+    match intr {
+        Revert | Log | StateClear | StateStoreWord | StateStoreQuad | StateLoadWord
+        | StateLoadQuad | Smo | ContractCall | ContractRet | JmpMem => false,
+        // arithmetic can overflow or divide by zero, and reading transaction fields
+        // panics for out of range indices, so all of them can revert
+        Add | Sub | Mul | Div | Mod | Lsh | PtrAdd | PtrSub | Gtf | TxInputType | TxInputAmount
+        | TxOutputType | TxOutputAmount => false,
+        IsReferenceType
+        | IsStrArray
+        | SizeOfType
+        | SizeOfVal
+        | SizeOfStr
+        | AlignOfType
+        | AssertIsStrArray
+        | ToStrArray
+        | Eq
+        | Gt
+        | Lt
+        | AddrOf
+        | And
+        | Or
+        | Xor
+        | Rsh
+        | Not
+        | EncodeBufferEmpty
+        | EncodeBufferAppend
+        | EncodeBufferAsRawSlice
+        | Slice
+        | ElemAt
+        | TxInputCount
+        | TxOutputCount
+        | Fmt => true,
+    }
+}
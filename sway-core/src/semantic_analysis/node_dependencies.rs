@@ -29,7 +29,9 @@ pub(crate) fn order_ast_nodes_by_dependency(
     );
 
     // Check here for recursive calls now that we have a nice map of the dependencies to help us.
-    let mut errors = find_recursive_decls(&decl_dependencies);
+    // Functions only used by constants are evaluated at compile time and may call themselves.
+    let const_eval_fns = find_const_eval_fns(engines, &nodes, &decl_dependencies);
+    let mut errors = find_recursive_decls(&decl_dependencies, &const_eval_fns);
 
     handler.scope(|handler| {
         // Because we're pulling these errors out of a HashMap they'll probably be in a funny
@@ -55,21 +57,33 @@ pub(crate) fn order_ast_nodes_by_dependency(
 // -------------------------------------------------------------------------------------------------
 // Recursion detection.
 
-fn find_recursive_decls(decl_dependencies: &DependencyMap) -> Vec<CompileError> {
+fn find_recursive_decls(
+    decl_dependencies: &DependencyMap,
+    const_eval_fns: &HashSet<&DependentSymbol>,
+) -> Vec<CompileError> {
     decl_dependencies
         .iter()
-        .filter_map(|(dep_sym, _)| find_recursive_decl(decl_dependencies, dep_sym))
+        .filter_map(|(dep_sym, _)| find_recursive_decl(decl_dependencies, const_eval_fns, dep_sym))
         .collect()
 }
 
 fn find_recursive_decl(
     decl_dependencies: &DependencyMap,
+    const_eval_fns: &HashSet<&DependentSymbol>,
     dep_sym: &DependentSymbol,
 ) -> Option<CompileError> {
     match dep_sym {
         DependentSymbol::Fn(_, Some(fn_span)) => {
             let mut chain = Vec::new();
-            find_recursive_call_chain(decl_dependencies, dep_sym, fn_span, &mut chain)
+            find_recursive_call_chain(decl_dependencies, dep_sym, fn_span, &mut chain).filter(
+                |err| {
+                    // Functions evaluated at compile time can call themselves, because the
+                    // recursion is bounded by the const eval limits. Mutual recursion is not
+                    // supported, because a function is only in scope after its declaration.
+                    !(const_eval_fns.contains(dep_sym)
+                        && matches!(err, CompileError::RecursiveCall { .. }))
+                },
+            )
         }
         DependentSymbol::Symbol(_) => {
             let mut chain = Vec::new();
@@ -79,6 +93,55 @@ fn find_recursive_decl(
     }
 }
 
+/// Returns the functions that are only called from the initializers of constants, directly or
+/// through other such functions, and thus are only ever evaluated at compile time.
+fn find_const_eval_fns<'a>(
+    engines: &Engines,
+    nodes: &[AstNode],
+    decl_dependencies: &'a DependencyMap,
+) -> HashSet<&'a DependentSymbol> {
+    let consts = nodes
+        .iter()
+        .filter_map(|node| match &node.content {
+            AstNodeContent::Declaration(decl @ Declaration::ConstantDeclaration(_)) => {
+                decl_name(engines, decl)
+            }
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    // Start with all the functions reachable from the constants...
+    let mut fns = HashSet::new();
+    let mut stack = consts
+        .iter()
+        .filter_map(|sym| decl_dependencies.get(sym))
+        .flat_map(|deps| deps.deps.iter())
+        .collect::<Vec<_>>();
+    while let Some(sym) = stack.pop() {
+        if matches!(sym, DependentSymbol::Fn(..)) && fns.insert(sym) {
+            if let Some(deps) = decl_dependencies.get(sym) {
+                stack.extend(deps.deps.iter());
+            }
+        }
+    }
+
+    // ...and drop the ones also used by other declarations, until none of them are.
+    loop {
+        let used_elsewhere = decl_dependencies
+            .iter()
+            .filter(|(sym, _)| !consts.contains(*sym) && !fns.contains(sym))
+            .flat_map(|(_, deps)| deps.deps.iter())
+            .filter(|sym| fns.contains(sym))
+            .collect::<Vec<_>>();
+        if used_elsewhere.is_empty() {
+            return fns;
+        }
+        for sym in used_elsewhere {
+            fns.remove(sym);
+        }
+    }
+}
+
 fn find_recursive_call_chain(
     decl_dependencies: &DependencyMap,
     fn_sym: &DependentSymbol,
@@ -331,6 +394,7 @@ impl Dependencies {
                 } = &*engines.pe().get_struct(decl_id);
                 self.gather_from_iter(fields.iter(), |deps, field| {
                     deps.gather_from_type_argument(engines, &field.type_argument)
+                        .gather_from_opt_expr(engines, field.default_value.as_ref())
                 })
                 .gather_from_type_parameters(type_parameters)
             }
@@ -581,6 +645,15 @@ impl Dependencies {
                 .gather_from_iter(branches.iter(), |deps, branch| {
                     deps.gather_from_match_branch(engines, branch)
                 }),
+            ExpressionKind::Try(try_expression) => self
+                .gather_from_expr(engines, &try_expression.value)
+                .gather_from_iter(
+                    try_expression
+                        .option_branches
+                        .iter()
+                        .chain(try_expression.result_branches.iter()),
+                    |deps, branch| deps.gather_from_match_branch(engines, branch),
+                ),
             ExpressionKind::CodeBlock(contents) => self.gather_from_block(engines, contents),
             ExpressionKind::Array(array_expression) => self
                 .gather_from_iter(array_expression.contents.iter(), |deps, expr| {
@@ -664,7 +737,7 @@ impl Dependencies {
             }
 
             ExpressionKind::Literal(_)
-            | ExpressionKind::Break
+            | ExpressionKind::Break(None)
             | ExpressionKind::Continue
             | ExpressionKind::StorageAccess(_)
             | ExpressionKind::Error(_, _) => self,
@@ -685,9 +758,11 @@ impl Dependencies {
             }) => self
                 .gather_from_expr(engines, condition)
                 .gather_from_block(engines, body),
+            ExpressionKind::Loop(LoopExpression { body }) => self.gather_from_block(engines, body),
             ExpressionKind::ForLoop(ForLoopExpression { desugared, .. }) => {
                 self.gather_from_expr(engines, desugared)
             }
+            ExpressionKind::Break(Some(expr)) => self.gather_from_expr(engines, expr),
             ExpressionKind::Reassignment(reassignment) => {
                 self.gather_from_expr(engines, &reassignment.rhs)
             }
@@ -1052,11 +1127,18 @@ fn recursively_depends_on(
     dependee: &DependentSymbol,
     decl_dependencies: &DependencyMap,
 ) -> bool {
-    set.contains(dependee)
-        || set.iter().any(|dep| {
-            decl_dependencies
-                .get(dep)
-                .map(|dep| recursively_depends_on(&dep.deps, dependee, decl_dependencies))
-                .unwrap_or(false)
-        })
+    // Functions evaluated at compile time may be recursive, so track the visited symbols.
+    let mut visited = HashSet::new();
+    let mut stack = set.iter().collect::<Vec<_>>();
+    while let Some(dep) = stack.pop() {
+        if dep == dependee {
+            return true;
+        }
+        if visited.insert(dep) {
+            if let Some(deps) = decl_dependencies.get(dep) {
+                stack.extend(deps.deps.iter());
+            }
+        }
+    }
+    false
 }
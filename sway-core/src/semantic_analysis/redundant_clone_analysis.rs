@@ -0,0 +1,254 @@
+// Looks for `.clone()` calls, i.e. calls to `std::clone::Clone::clone`, on values of
+// types that are already implicitly copied, like `u64` or `bool`, and issues warnings
+// suggesting to remove them. Values of such types are copied on every use, so
+// cloning them explicitly has no effect.
+// The analysis is opt-in via `BuildConfig::with_warn_redundant_clone`, and can be
+// turned off for a particular function with `#[allow(redundant_clone)]`.
+
+use crate::{
+    decl_engine::{DeclId, InterfaceDeclId},
+    language::ty::{self, TyDecl, TyTraitItem},
+    transform::{AttributeKind, AttributesMap},
+    Engines,
+};
+use sway_error::warning::{CompileWarning, Warning};
+use sway_types::constants::ALLOW_REDUNDANT_CLONE_NAME;
+
+const CLONE_TRAIT_PATH: [&str; 2] = ["std", "clone"];
+const CLONE_TRAIT_NAME: &str = "Clone";
+const CLONE_METHOD_NAME: &str = "clone";
+
+pub(crate) fn analyze_program(engines: &Engines, prog: &ty::TyProgram) -> Vec<CompileWarning> {
+    let mut warnings = vec![];
+    analyze_module(engines, &prog.root, &mut warnings);
+    warnings
+}
+
+fn analyze_module(engines: &Engines, module: &ty::TyModule, warnings: &mut Vec<CompileWarning>) {
+    for (_, submodule) in module.submodules.iter() {
+        analyze_module(engines, &submodule.module, warnings);
+    }
+    for node in module.all_nodes.iter() {
+        analyze_ast_node(engines, node, warnings);
+    }
+}
+
+fn analyze_ast_node(engines: &Engines, node: &ty::TyAstNode, warnings: &mut Vec<CompileWarning>) {
+    match &node.content {
+        ty::TyAstNodeContent::Declaration(decl) => analyze_decl(engines, decl, warnings),
+        ty::TyAstNodeContent::Expression(expr) => analyze_expression(engines, expr, warnings),
+        ty::TyAstNodeContent::SideEffect(_) | ty::TyAstNodeContent::Error(_, _) => {}
+    }
+}
+
+fn analyze_decl(engines: &Engines, decl: &TyDecl, warnings: &mut Vec<CompileWarning>) {
+    let decl_engine = engines.de();
+    match decl {
+        TyDecl::VariableDecl(decl) => analyze_expression(engines, &decl.body, warnings),
+        TyDecl::ConstantDecl(decl) => {
+            let decl = decl_engine.get_constant(&decl.decl_id);
+            if let Some(value) = &decl.value {
+                analyze_expression(engines, value, warnings);
+            }
+        }
+        TyDecl::ConfigurableDecl(decl) => {
+            let decl = decl_engine.get_configurable(&decl.decl_id);
+            if let Some(value) = &decl.value {
+                analyze_expression(engines, value, warnings);
+            }
+        }
+        TyDecl::FunctionDecl(decl) => {
+            let decl = decl_engine.get_function(&decl.decl_id);
+            analyze_function(engines, &decl, warnings);
+        }
+        TyDecl::ImplSelfOrTrait(decl) => {
+            let decl = decl_engine.get_impl_self_or_trait(&decl.decl_id);
+            for item in decl.items.iter() {
+                analyze_trait_item(engines, item, warnings);
+            }
+        }
+        TyDecl::TraitDecl(decl) => {
+            let decl = decl_engine.get_trait(&decl.decl_id);
+            for item in decl.items.iter() {
+                analyze_trait_item(engines, item, warnings);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn analyze_trait_item(engines: &Engines, item: &TyTraitItem, warnings: &mut Vec<CompileWarning>) {
+    let decl_engine = engines.de();
+    match item {
+        TyTraitItem::Fn(decl_ref) => {
+            let decl = decl_engine.get_function(decl_ref.id());
+            analyze_function(engines, &decl, warnings);
+        }
+        TyTraitItem::Constant(decl_ref) => {
+            let decl = decl_engine.get_constant(decl_ref.id());
+            if let Some(value) = &decl.value {
+                analyze_expression(engines, value, warnings);
+            }
+        }
+        TyTraitItem::Type(_) => {}
+    }
+}
+
+fn analyze_function(
+    engines: &Engines,
+    fn_decl: &ty::TyFunctionDecl,
+    warnings: &mut Vec<CompileWarning>,
+) {
+    if is_redundant_clone_allowed(&fn_decl.attributes) {
+        return;
+    }
+    analyze_code_block(engines, &fn_decl.body, warnings);
+}
+
+fn analyze_code_block(
+    engines: &Engines,
+    codeblock: &ty::TyCodeBlock,
+    warnings: &mut Vec<CompileWarning>,
+) {
+    for node in codeblock.contents.iter() {
+        analyze_ast_node(engines, node, warnings);
+    }
+}
+
+fn analyze_expressions<'a>(
+    engines: &Engines,
+    expressions: impl Iterator<Item = &'a ty::TyExpression>,
+    warnings: &mut Vec<CompileWarning>,
+) {
+    for expr in expressions {
+        analyze_expression(engines, expr, warnings);
+    }
+}
+
+fn analyze_expression(
+    engines: &Engines,
+    expr: &ty::TyExpression,
+    warnings: &mut Vec<CompileWarning>,
+) {
+    use crate::ty::TyExpressionVariant::*;
+    match &expr.expression {
+        Literal(_)
+        | ConstantExpression { .. }
+        | ConfigurableExpression { .. }
+        | VariableExpression { .. }
+        | FunctionParameter
+        | StorageAccess(_)
+        | Break(None)
+        | Continue
+        | AbiName(_) => {}
+        FunctionApplication {
+            arguments, fn_ref, ..
+        } => {
+            if let Some((_, receiver)) = arguments.first() {
+                let receiver_type = engines.te().get(receiver.return_type);
+                if receiver_type.is_copy_type() && is_clone_method(engines, fn_ref.id()) {
+                    warnings.push(CompileWarning {
+                        span: expr.span.clone(),
+                        warning_content: Warning::RedundantClone {
+                            ty: engines.help_out(receiver.return_type).to_string(),
+                        },
+                    });
+                }
+            }
+            analyze_expressions(engines, arguments.iter().map(|(_, e)| e), warnings);
+        }
+        IntrinsicFunction(intrinsic) => {
+            analyze_expressions(engines, intrinsic.arguments.iter(), warnings)
+        }
+        LazyOperator { lhs, rhs, .. } => {
+            analyze_expression(engines, lhs, warnings);
+            analyze_expression(engines, rhs, warnings);
+        }
+        ArrayIndex { prefix, index } => {
+            analyze_expression(engines, prefix, warnings);
+            analyze_expression(engines, index, warnings);
+        }
+        Tuple { fields: exprs }
+        | Array {
+            elem_type: _,
+            contents: exprs,
+        } => analyze_expressions(engines, exprs.iter(), warnings),
+        StructExpression { fields, .. } => {
+            analyze_expressions(engines, fields.iter().map(|f| &f.value), warnings)
+        }
+        CodeBlock(codeblock) => analyze_code_block(engines, codeblock, warnings),
+        StructFieldAccess { prefix: expr, .. }
+        | TupleElemAccess { prefix: expr, .. }
+        | EnumTag { exp: expr }
+        | UnsafeDowncast { exp: expr, .. }
+        | AbiCast { address: expr, .. }
+        | ImplicitReturn(expr)
+        | Return(expr)
+        | Break(Some(expr))
+        | Ref(expr)
+        | Deref(expr) => analyze_expression(engines, expr, warnings),
+        EnumInstantiation { contents, .. } => {
+            if let Some(expr) = contents {
+                analyze_expression(engines, expr, warnings);
+            }
+        }
+        MatchExp { desugared, .. } => analyze_expression(engines, desugared, warnings),
+        IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            analyze_expression(engines, condition, warnings);
+            analyze_expression(engines, then, warnings);
+            if let Some(r#else) = r#else {
+                analyze_expression(engines, r#else, warnings);
+            }
+        }
+        WhileLoop { condition, body } => {
+            analyze_expression(engines, condition, warnings);
+            analyze_code_block(engines, body, warnings);
+        }
+        Loop { body } => analyze_code_block(engines, body, warnings),
+        ForLoop { desugared } => analyze_expression(engines, desugared, warnings),
+        AsmExpression { registers, .. } => analyze_expressions(
+            engines,
+            registers.iter().filter_map(|r| r.initializer.as_ref()),
+            warnings,
+        ),
+        Reassignment(reassignment) => analyze_expression(engines, &reassignment.rhs, warnings),
+    }
+}
+
+/// Returns true if the function is the `clone` method of an
+/// implementation of the `std::clone::Clone` trait.
+fn is_clone_method(engines: &Engines, fn_id: &DeclId<ty::TyFunctionDecl>) -> bool {
+    let decl_engine = engines.de();
+    let fn_decl = decl_engine.get_function(fn_id);
+    if fn_decl.name.as_str() != CLONE_METHOD_NAME {
+        return false;
+    }
+    let Some(TyDecl::ImplSelfOrTrait(impl_decl)) = &fn_decl.implementing_type else {
+        return false;
+    };
+    let impl_decl = decl_engine.get_impl_self_or_trait(&impl_decl.decl_id);
+    let Some(InterfaceDeclId::Trait(trait_id)) = impl_decl.trait_decl_ref.as_ref().map(|r| r.id())
+    else {
+        return false;
+    };
+    let trait_name = &decl_engine.get_trait(trait_id).call_path;
+    trait_name.suffix.as_str() == CLONE_TRAIT_NAME
+        && trait_name
+            .prefixes
+            .iter()
+            .map(|ident| ident.as_str())
+            .eq(CLONE_TRAIT_PATH)
+}
+
+fn is_redundant_clone_allowed(attributes: &AttributesMap) -> bool {
+    attributes
+        .get(&AttributeKind::Allow)
+        .into_iter()
+        .flatten()
+        .flat_map(|allow| allow.args.iter())
+        .any(|arg| arg.name.as_str() == ALLOW_REDUNDANT_CLONE_NAME)
+}
@@ -5,8 +5,11 @@ use crate::{
     semantic_analysis::Namespace,
     Engines,
 };
-use sway_error::handler::{ErrorEmitted, Handler};
-use sway_types::{span::Span, Ident};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
+use sway_types::{span::Span, Ident, Spanned};
 
 use super::{ConstShadowingMode, GenericShadowingMode};
 
@@ -116,6 +119,28 @@ impl SymbolCollectionContext {
         })
     }
 
+    /// Checks that the module path of a `pub(in path)` visibility refers to the module
+    /// currently being collected or one of its ancestors. Any other path, e.g., a
+    /// sibling module or a module that does not exist, would make the item inaccessible.
+    pub(crate) fn check_visibility_restriction(&self, handler: &Handler, visibility: &Visibility) {
+        let Visibility::Restricted(path) = visibility else {
+            return;
+        };
+        let mod_path = self.namespace.mod_path();
+        let is_ancestor =
+            path.len() <= mod_path.len() && path.iter().zip(mod_path).all(|(a, b)| a == b);
+        if !is_ancestor {
+            handler.emit_err(CompileError::InvalidVisibilityRestrictionPath {
+                path: path
+                    .iter()
+                    .map(|ident| ident.as_str())
+                    .collect::<Vec<_>>()
+                    .join("::"),
+                span: Span::join_all(path.iter().map(|ident| ident.span())),
+            });
+        }
+    }
+
     /// Returns a mutable reference to the current namespace.
     pub fn namespace_mut(&mut self) -> &mut Namespace {
         &mut self.namespace
@@ -243,8 +243,11 @@ impl ResolveSymbols for StructDeclaration {
 }
 
 impl ResolveSymbols for StructField {
-    fn resolve_symbols(&mut self, handler: &Handler, ctx: SymbolResolveContext) {
-        self.type_argument.resolve_symbols(handler, ctx);
+    fn resolve_symbols(&mut self, handler: &Handler, mut ctx: SymbolResolveContext) {
+        self.type_argument.resolve_symbols(handler, ctx.by_ref());
+        if let Some(default_value) = self.default_value.as_mut() {
+            default_value.resolve_symbols(handler, ctx.by_ref())
+        }
     }
 }
 
@@ -472,6 +475,18 @@ impl ResolveSymbols for Scrutinee {
                     .iter_mut()
                     .for_each(|s| s.resolve_symbols(handler, ctx.by_ref()));
             }
+            Scrutinee::Slice {
+                prefix,
+                rest,
+                suffix,
+                span: _,
+            } => {
+                prefix
+                    .iter_mut()
+                    .chain(rest.iter_mut().map(|rest| rest.as_mut()))
+                    .chain(suffix.iter_mut())
+                    .for_each(|s| s.resolve_symbols(handler, ctx.by_ref()));
+            }
             Scrutinee::Error { .. } => {}
         }
     }
@@ -587,6 +602,16 @@ impl ResolveSymbols for ExpressionKind {
                     branch.result.resolve_symbols(handler, ctx.by_ref());
                 });
             }
+            ExpressionKind::Try(expr) => {
+                expr.value.resolve_symbols(handler, ctx.by_ref());
+                expr.option_branches
+                    .iter_mut()
+                    .chain(expr.result_branches.iter_mut())
+                    .for_each(|branch| {
+                        branch.scrutinee.resolve_symbols(handler, ctx.by_ref());
+                        branch.result.resolve_symbols(handler, ctx.by_ref());
+                    });
+            }
             ExpressionKind::Asm(asm_expr) => asm_expr.registers.iter_mut().for_each(|reg| {
                 if let Some(initializer) = reg.initializer.as_mut() {
                     initializer.resolve_symbols(handler, ctx.by_ref());
@@ -626,7 +651,12 @@ impl ResolveSymbols for ExpressionKind {
                 expr.body.resolve_symbols(handler, ctx.by_ref());
             }
             ExpressionKind::ForLoop(expr) => expr.desugared.resolve_symbols(handler, ctx.by_ref()),
-            ExpressionKind::Break => {}
+            ExpressionKind::Loop(expr) => expr.body.resolve_symbols(handler, ctx.by_ref()),
+            ExpressionKind::Break(expr) => {
+                if let Some(expr) = expr {
+                    expr.resolve_symbols(handler, ctx.by_ref())
+                }
+            }
             ExpressionKind::Continue => {}
             ExpressionKind::Reassignment(expr) => {
                 match &mut expr.lhs {
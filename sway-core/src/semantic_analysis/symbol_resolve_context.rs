@@ -205,7 +205,7 @@ impl<'a> SymbolResolveContext<'a> {
         mod_path: &ModulePath,
         call_path: &CallPath,
     ) -> Result<ResolvedDeclaration, ErrorEmitted> {
-        let (decl, mod_path) = self.namespace().root.resolve_call_path_and_mod_path(
+        let (decl, decl_mod_path) = self.namespace().root.resolve_call_path_and_mod_path(
             handler,
             self.engines,
             mod_path,
@@ -214,7 +214,7 @@ impl<'a> SymbolResolveContext<'a> {
         )?;
 
         // In case there is no mod path we don't need to check visibility
-        if mod_path.is_empty() {
+        if decl_mod_path.is_empty() {
             return Ok(decl);
         }
 
@@ -224,8 +224,25 @@ impl<'a> SymbolResolveContext<'a> {
         }
 
         // check the visibility of the call path elements
-        // we don't check the first prefix because direct children are always accessible
-        for prefix in iter_prefixes(&call_path.prefixes).skip(1) {
+        // we don't check the first prefix because direct children are always accessible,
+        // nor the path of an imported module, which is checked when importing it
+        let (prefixes, checked_prefixes) =
+            match self
+                .namespace()
+                .root
+                .module_synonym(self.engines, mod_path, &call_path.prefixes)
+            {
+                Some(synonym_path) => {
+                    let checked_prefixes = synonym_path.len();
+                    let prefixes = synonym_path
+                        .into_iter()
+                        .chain(call_path.prefixes[1..].iter().cloned())
+                        .collect::<Vec<_>>();
+                    (prefixes, checked_prefixes)
+                }
+                None => (call_path.prefixes.clone(), 1),
+            };
+        for prefix in iter_prefixes(&prefixes).skip(checked_prefixes) {
             let module = self.namespace().lookup_submodule_from_absolute_path(
                 handler,
                 self.engines(),
@@ -241,11 +258,28 @@ impl<'a> SymbolResolveContext<'a> {
         }
 
         // check the visibility of the symbol itself
-        if !decl.visibility(self.engines).is_public() {
-            handler.emit_err(CompileError::ImportPrivateSymbol {
-                name: call_path.suffix.clone(),
-                span: call_path.suffix.span(),
-            });
+        match decl.visibility(self.engines) {
+            Visibility::Public => {}
+            Visibility::Private => {
+                handler.emit_err(CompileError::ImportPrivateSymbol {
+                    name: call_path.suffix.clone(),
+                    span: call_path.suffix.span(),
+                });
+            }
+            visibility @ Visibility::Restricted(_) => {
+                if !self.namespace().root.is_visible_from(
+                    self.engines,
+                    &visibility,
+                    &decl_mod_path,
+                    mod_path,
+                ) {
+                    handler.emit_err(CompileError::ImportRestrictedSymbol {
+                        name: call_path.suffix.clone(),
+                        visibility: visibility.to_string(),
+                        span: call_path.suffix.span(),
+                    });
+                }
+            }
         }
 
         Ok(decl)
@@ -53,6 +53,8 @@ pub struct TypeCheckAnalysisContext<'cx> {
     pub(crate) nodes: HashMap<DeclUniqueId, TyNodeDepGraphNodeId>,
     pub(crate) items_node_stack: Vec<TyNodeDepGraphNodeId>,
     pub(crate) node_stack: Vec<TyNodeDepGraphNodeId>,
+    /// The functions called from initializers of constants, which are evaluated at compile time.
+    pub(crate) const_eval_calls: HashSet<TyNodeDepGraphNodeId>,
 }
 
 impl TypeCheckAnalysisContext<'_> {
@@ -61,12 +63,66 @@ impl TypeCheckAnalysisContext<'_> {
     }
 
     pub fn add_edge_from_current(&mut self, to: TyNodeDepGraphNodeId, edge: TyNodeDepGraphEdge) {
-        let from = *self.node_stack.last().unwrap();
+        // Outside of any function we are in the initializer of a constant.
+        let Some(from) = self.node_stack.last().copied() else {
+            self.const_eval_calls.insert(to);
+            return;
+        };
         if !self.dep_graph.contains_edge(from, to) {
             self.dep_graph.add_edge(from, to, edge);
         }
     }
 
+    /// Returns the functions that are only called from the initializers of constants, directly
+    /// or through other such functions, and thus are only ever evaluated at compile time.
+    pub(crate) fn const_eval_fns(&self) -> HashSet<DeclId<TyFunctionDecl>> {
+        // Start with all the functions reachable from the constants...
+        let mut nodes = HashSet::new();
+        let mut stack = self.const_eval_calls.iter().copied().collect::<Vec<_>>();
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node) {
+                stack.extend(self.dep_graph.neighbors(node));
+            }
+        }
+
+        // ...and drop the ones also called from other functions, until none of them are.
+        loop {
+            let called_elsewhere = nodes
+                .iter()
+                .copied()
+                .filter(|node| {
+                    self.dep_graph
+                        .neighbors_directed(*node, petgraph::Direction::Incoming)
+                        .any(|caller| {
+                            !nodes.contains(&caller)
+                                && !matches!(
+                                    self.dep_graph[caller],
+                                    TyNodeDepGraphNode::ImplTrait { .. }
+                                )
+                        })
+                })
+                .collect::<Vec<_>>();
+            if called_elsewhere.is_empty() {
+                break;
+            }
+            for node in called_elsewhere {
+                nodes.remove(&node);
+            }
+        }
+
+        nodes
+            .into_iter()
+            .filter_map(|node| match &self.dep_graph[node] {
+                TyNodeDepGraphNode::Fn { node } => Some(*node),
+                TyNodeDepGraphNode::ImplTraitItem {
+                    node: TyTraitItem::Fn(node),
+                } => Some(*node.id()),
+                _ => None,
+            })
+            .map(|fn_decl_id| self.get_normalized_fn_node_id(&fn_decl_id))
+            .collect()
+    }
+
     #[allow(clippy::map_entry)]
     pub fn get_or_create_node_for_impl_item(&mut self, item: &TyTraitItem) -> TyNodeDepGraphNodeId {
         let id = match item {
@@ -257,13 +313,28 @@ impl TypeCheckAnalysisContext<'_> {
 
     /// Performs recursive analysis by running the Johnson's algorithm to find all cycles
     /// in the previously constructed dependency graph.
-    pub(crate) fn check_recursive_calls(&self, handler: &Handler) -> Result<(), ErrorEmitted> {
+    ///
+    /// The `const_eval_fns` are allowed to call themselves, because constant evaluation bounds
+    /// the recursion by the const eval limits.
+    pub(crate) fn check_recursive_calls(
+        &self,
+        handler: &Handler,
+        const_eval_fns: &HashSet<DeclId<TyFunctionDecl>>,
+    ) -> Result<(), ErrorEmitted> {
         handler.scope(|handler| {
             let cycles = self.dep_graph.cycles();
             if cycles.is_empty() {
                 return Ok(());
             }
             for mut sub_cycles in cycles {
+                if sub_cycles.len() == 1 {
+                    let node = self.dep_graph.node_weight(sub_cycles[0]).unwrap();
+                    let fn_decl_id = self.get_fn_decl_id_from_node(node);
+                    if const_eval_fns.contains(&self.get_normalized_fn_node_id(&fn_decl_id)) {
+                        continue;
+                    }
+                }
+
                 // Manipulate the cycles order to get the same ordering as the source code's lexical order.
                 sub_cycles.rotate_left(1);
                 if sub_cycles.len() == 1 {
@@ -392,6 +463,7 @@ impl<'cx> TypeCheckAnalysisContext<'cx> {
             nodes: Default::default(),
             items_node_stack: Default::default(),
             node_stack: Default::default(),
+            const_eval_calls: Default::default(),
         }
     }
 }
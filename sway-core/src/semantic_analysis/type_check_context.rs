@@ -5,7 +5,7 @@ use crate::{
     decl_engine::{DeclEngineGet, DeclEngineGetParsedDeclId, DeclEngineInsert, DeclRefFunction},
     engine_threading::*,
     language::{
-        parsed::TreeType,
+        parsed::{QualifiedPathType, TreeType},
         ty::{self, TyDecl, TyTraitItem},
         CallPath, QualifiedCallPath, Visibility,
     },
@@ -21,7 +21,7 @@ use crate::{
     CreateTypeId, SubstTypesContext, TraitConstraint, TypeParameter, TypeSubstMap, UnifyCheck,
 };
 use sway_error::{
-    error::CompileError,
+    error::{CompileError, NamesInScope},
     handler::{ErrorEmitted, Handler},
 };
 use sway_types::{span::Span, Ident, Spanned};
@@ -59,6 +59,11 @@ pub struct TypeCheckContext<'a> {
     type_annotation: TypeId,
     /// Assists type inference.
     function_type_annotation: TypeId,
+    /// While type-checking the body of a `loop`, this indicates the type of the values of its
+    /// `break`s, which is the type of the `loop` itself.
+    ///
+    /// `None` outside of a `loop`, and within a `while` loop, where `break` cannot have a value.
+    loop_break_type_annotation: Option<TypeId>,
     /// When true unify_with_type_annotation will use unify_with_generic instead of the default unify.
     /// This ensures that expected generic types are unified to more specific received types.
     unify_generic: bool,
@@ -121,6 +126,7 @@ impl<'a> TypeCheckContext<'a> {
             collection_ctx,
             type_annotation: engines.te().insert(engines, TypeInfo::Unknown, None),
             function_type_annotation: engines.te().insert(engines, TypeInfo::Unknown, None),
+            loop_break_type_annotation: None,
             unify_generic: false,
             self_type: None,
             type_subst: TypeSubstMap::new(),
@@ -165,6 +171,7 @@ impl<'a> TypeCheckContext<'a> {
             engines,
             type_annotation: engines.te().insert(engines, TypeInfo::Unknown, None),
             function_type_annotation: engines.te().insert(engines, TypeInfo::Unknown, None),
+            loop_break_type_annotation: None,
             unify_generic: false,
             self_type: None,
             type_subst: TypeSubstMap::new(),
@@ -195,6 +202,7 @@ impl<'a> TypeCheckContext<'a> {
             collection_ctx: self.collection_ctx,
             type_annotation: self.type_annotation,
             function_type_annotation: self.function_type_annotation,
+            loop_break_type_annotation: self.loop_break_type_annotation,
             unify_generic: self.unify_generic,
             self_type: self.self_type,
             type_subst: self.type_subst.clone(),
@@ -232,6 +240,7 @@ impl<'a> TypeCheckContext<'a> {
                         collection_ctx: scoped_collection_ctx,
                         type_annotation: self.type_annotation,
                         function_type_annotation: self.function_type_annotation,
+                        loop_break_type_annotation: self.loop_break_type_annotation,
                         unify_generic: self.unify_generic,
                         self_type: self.self_type,
                         type_subst: self.type_subst,
@@ -256,6 +265,7 @@ impl<'a> TypeCheckContext<'a> {
                 namespace: &mut namespace,
                 type_annotation: self.type_annotation,
                 function_type_annotation: self.function_type_annotation,
+                loop_break_type_annotation: self.loop_break_type_annotation,
                 unify_generic: self.unify_generic,
                 self_type: self.self_type,
                 type_subst: self.type_subst,
@@ -296,6 +306,7 @@ impl<'a> TypeCheckContext<'a> {
                         namespace: &mut namespace,
                         type_annotation: self.type_annotation,
                         function_type_annotation: self.function_type_annotation,
+                        loop_break_type_annotation: self.loop_break_type_annotation,
                         unify_generic: self.unify_generic,
                         self_type: self.self_type,
                         type_subst: self.type_subst,
@@ -320,6 +331,7 @@ impl<'a> TypeCheckContext<'a> {
                 namespace: &mut namespace,
                 type_annotation: self.type_annotation,
                 function_type_annotation: self.function_type_annotation,
+                loop_break_type_annotation: self.loop_break_type_annotation,
                 unify_generic: self.unify_generic,
                 self_type: self.self_type,
                 type_subst: self.type_subst,
@@ -358,7 +370,7 @@ impl<'a> TypeCheckContext<'a> {
         let mut submod_ns = self.namespace.enter_submodule(
             engines,
             mod_name.clone(),
-            visibility,
+            visibility.clone(),
             module_span.clone(),
         );
 
@@ -410,6 +422,18 @@ impl<'a> TypeCheckContext<'a> {
         }
     }
 
+    /// Map this `TypeCheckContext` instance to a new one with the given `loop` `break` type
+    /// annotation.
+    pub(crate) fn with_loop_break_type_annotation(
+        self,
+        loop_break_type_annotation: Option<TypeId>,
+    ) -> Self {
+        Self {
+            loop_break_type_annotation,
+            ..self
+        }
+    }
+
     /// Map this `TypeCheckContext` instance to a new one with the given type annotation.
     pub(crate) fn with_unify_generic(self, unify_generic: bool) -> Self {
         Self {
@@ -519,6 +543,10 @@ impl<'a> TypeCheckContext<'a> {
         self.function_type_annotation
     }
 
+    pub(crate) fn loop_break_type_annotation(&self) -> Option<TypeId> {
+        self.loop_break_type_annotation
+    }
+
     pub(crate) fn unify_generic(&self) -> bool {
         self.unify_generic
     }
@@ -682,15 +710,29 @@ impl<'a> TypeCheckContext<'a> {
                     )
                     .ok()
                 };
-                self.type_decl_opt_to_type_id(
-                    handler,
-                    type_decl_opt,
-                    &qualified_call_path.call_path,
-                    span,
-                    enforce_type_arguments,
-                    mod_path,
-                    type_arguments.clone(),
-                )?
+                // The associated types of a qualified path, e.g. `<T as Trait>::Item`, belong to
+                // the root type of the path rather than to `Self`.
+                let self_type = match &qualified_call_path.qualified_path_root {
+                    Some(qualified_path_root) => self
+                        .resolve_qualified_path_root_type_id(
+                            &Handler::default(),
+                            module_path,
+                            qualified_path_root,
+                        )
+                        .ok(),
+                    None => self.self_type(),
+                };
+                self.by_ref()
+                    .with_self_type(self_type)
+                    .type_decl_opt_to_type_id(
+                        handler,
+                        type_decl_opt,
+                        &qualified_call_path.call_path,
+                        span,
+                        enforce_type_arguments,
+                        mod_path,
+                        type_arguments.clone(),
+                    )?
             }
             TypeInfo::Array(mut elem_ty, n) => {
                 elem_ty.type_id = self
@@ -895,7 +937,7 @@ impl<'a> TypeCheckContext<'a> {
         call_path: &CallPath,
     ) -> Result<ty::TyDecl, ErrorEmitted> {
         let engines = self.engines;
-        let (decl, mod_path) = self.namespace().root.resolve_call_path_and_mod_path(
+        let (decl, decl_mod_path) = self.namespace().root.resolve_call_path_and_mod_path(
             handler,
             self.engines,
             mod_path,
@@ -905,7 +947,7 @@ impl<'a> TypeCheckContext<'a> {
         let decl = decl.expect_typed();
 
         // In case there is no mod path we don't need to check visibility
-        if mod_path.is_empty() {
+        if decl_mod_path.is_empty() {
             return Ok(decl);
         }
 
@@ -915,8 +957,25 @@ impl<'a> TypeCheckContext<'a> {
         }
 
         // check the visibility of the call path elements
-        // we don't check the first prefix because direct children are always accessible
-        for prefix in iter_prefixes(&call_path.prefixes).skip(1) {
+        // we don't check the first prefix because direct children are always accessible,
+        // nor the path of an imported module, which is checked when importing it
+        let (prefixes, checked_prefixes) =
+            match self
+                .namespace()
+                .root
+                .module_synonym(self.engines, mod_path, &call_path.prefixes)
+            {
+                Some(synonym_path) => {
+                    let checked_prefixes = synonym_path.len();
+                    let prefixes = synonym_path
+                        .into_iter()
+                        .chain(call_path.prefixes[1..].iter().cloned())
+                        .collect::<Vec<_>>();
+                    (prefixes, checked_prefixes)
+                }
+                None => (call_path.prefixes.clone(), 1),
+            };
+        for prefix in iter_prefixes(&prefixes).skip(checked_prefixes) {
             let module = self
                 .namespace()
                 .lookup_submodule_from_absolute_path(handler, engines, prefix)?;
@@ -930,11 +989,28 @@ impl<'a> TypeCheckContext<'a> {
         }
 
         // check the visibility of the symbol itself
-        if !decl.visibility(self.engines.de()).is_public() {
-            handler.emit_err(CompileError::ImportPrivateSymbol {
-                name: call_path.suffix.clone(),
-                span: call_path.suffix.span(),
-            });
+        match decl.visibility(self.engines.de()) {
+            Visibility::Public => {}
+            Visibility::Private => {
+                handler.emit_err(CompileError::ImportPrivateSymbol {
+                    name: call_path.suffix.clone(),
+                    span: call_path.suffix.span(),
+                });
+            }
+            visibility @ Visibility::Restricted(_) => {
+                if !self.namespace().root.is_visible_from(
+                    self.engines,
+                    &visibility,
+                    &decl_mod_path,
+                    mod_path,
+                ) {
+                    handler.emit_err(CompileError::ImportRestrictedSymbol {
+                        name: call_path.suffix.clone(),
+                        visibility: visibility.to_string(),
+                        span: call_path.suffix.span(),
+                    });
+                }
+            }
         }
 
         Ok(decl)
@@ -960,29 +1036,8 @@ impl<'a> TypeCheckContext<'a> {
     ) -> Result<ty::TyDecl, ErrorEmitted> {
         let type_engine = self.engines().te();
         if let Some(qualified_path_root) = qualified_call_path.clone().qualified_path_root {
-            let root_type_id = match &&*type_engine.get(qualified_path_root.ty.type_id) {
-                TypeInfo::Custom {
-                    qualified_call_path,
-                    type_arguments,
-                    ..
-                } => {
-                    let type_decl = self.resolve_call_path_with_visibility_check_and_modpath(
-                        handler,
-                        mod_path,
-                        &qualified_call_path.clone().to_call_path(handler)?,
-                    )?;
-                    self.type_decl_opt_to_type_id(
-                        handler,
-                        Some(type_decl),
-                        &qualified_call_path.call_path,
-                        &qualified_path_root.ty.span(),
-                        EnforceTypeArguments::No,
-                        mod_path,
-                        type_arguments.clone(),
-                    )?
-                }
-                _ => qualified_path_root.ty.type_id,
-            };
+            let root_type_id =
+                self.resolve_qualified_path_root_type_id(handler, mod_path, &qualified_path_root)?;
 
             let as_trait_opt = match &&*type_engine.get(qualified_path_root.as_trait) {
                 TypeInfo::Custom {
@@ -1018,6 +1073,39 @@ impl<'a> TypeCheckContext<'a> {
         }
     }
 
+    /// Resolve the type of the root of a qualified path, e.g. `T` in `<T as Trait>::Item`.
+    fn resolve_qualified_path_root_type_id(
+        &mut self,
+        handler: &Handler,
+        mod_path: &ModulePath,
+        qualified_path_root: &QualifiedPathType,
+    ) -> Result<TypeId, ErrorEmitted> {
+        let type_engine = self.engines().te();
+        Ok(match &&*type_engine.get(qualified_path_root.ty.type_id) {
+            TypeInfo::Custom {
+                qualified_call_path,
+                type_arguments,
+                ..
+            } => {
+                let type_decl = self.resolve_call_path_with_visibility_check_and_modpath(
+                    handler,
+                    mod_path,
+                    &qualified_call_path.clone().to_call_path(handler)?,
+                )?;
+                self.type_decl_opt_to_type_id(
+                    handler,
+                    Some(type_decl),
+                    &qualified_call_path.call_path,
+                    &qualified_path_root.ty.span(),
+                    EnforceTypeArguments::No,
+                    mod_path,
+                    type_arguments.clone(),
+                )?
+            }
+            _ => qualified_path_root.ty.type_id,
+        })
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn type_decl_opt_to_type_id(
         &mut self,
@@ -1129,9 +1217,20 @@ impl<'a> TypeCheckContext<'a> {
                 }
             }
             _ => {
+                let names_in_scope = match self.namespace().root_module().lookup_submodule(
+                    &Handler::default(),
+                    self.engines,
+                    mod_path,
+                ) {
+                    Ok(module) if call_path.prefixes.is_empty() => module
+                        .current_items()
+                        .names_in_scope(ResolvedDeclaration::is_type),
+                    _ => NamesInScope::default(),
+                };
                 let err = handler.emit_err(CompileError::UnknownTypeName {
                     name: call_path.to_string(),
                     span: call_path.span(),
+                    names_in_scope,
                 });
                 type_engine.insert(self.engines, TypeInfo::ErrorRecovery(err), None)
             }
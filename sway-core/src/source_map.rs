@@ -66,10 +66,13 @@ impl SourceMap {
     }
 
     /// Inverse source mapping
+    ///
+    /// Returns `None` if there is no span for `pc`, or if the span refers to a path that
+    /// is not in the source map, e.g., because the source map was modified by hand.
     pub fn addr_to_span(&self, pc: usize) -> Option<(PathBuf, LocationRange)> {
         self.map
             .get(&pc)
-            .map(|sms| sms.to_span(&self.paths, &self.dependency_paths))
+            .and_then(|sms| sms.to_span(&self.paths, &self.dependency_paths))
     }
 }
 
@@ -80,25 +83,27 @@ pub struct SourceMapSpan {
 }
 
 impl SourceMapSpan {
+    /// Returns the path and the range of the span, or `None` if the path index of the span
+    /// is out of the bounds of `paths`.
     pub fn to_span(
         &self,
         paths: &[PathBuf],
         dependency_paths: &[PathBuf],
-    ) -> (PathBuf, LocationRange) {
-        let p = &paths[self.path.0];
+    ) -> Option<(PathBuf, LocationRange)> {
+        let p = paths.get(self.path.0)?;
         for dep in dependency_paths {
-            if p.starts_with(dep.file_name().unwrap()) {
+            if dep.file_name().is_some_and(|name| p.starts_with(name)) {
                 let mut path = home_dir().expect("Could not get homedir").join(".forc");
 
                 if let Some(dp) = dep.parent() {
                     path = path.join(dp);
                 }
 
-                return (path.join(p), self.range);
+                return Some((path.join(p), self.range));
             }
         }
 
-        (p.to_owned(), self.range)
+        Some((p.to_owned(), self.range))
     }
 }
 
@@ -107,3 +112,33 @@ pub struct LocationRange {
     pub start: LineCol,
     pub end: LineCol,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(path_index: usize) -> SourceMapSpan {
+        let line_col = LineCol { line: 1, col: 1 };
+        SourceMapSpan {
+            path: PathIndex(path_index),
+            range: LocationRange {
+                start: line_col,
+                end: line_col,
+            },
+        }
+    }
+
+    #[test]
+    fn addr_to_span_with_unknown_path() {
+        let source_map = SourceMap {
+            dependency_paths: vec![PathBuf::new()],
+            paths: vec![PathBuf::from("src/main.sw")],
+            map: BTreeMap::from([(0, span(0)), (1, span(1))]),
+        };
+        let (path, range) = source_map.addr_to_span(0).unwrap();
+        assert_eq!(path, PathBuf::from("src/main.sw"));
+        assert_eq!(range.start.line, 1);
+        assert!(source_map.addr_to_span(1).is_none());
+        assert!(source_map.addr_to_span(2).is_none());
+    }
+}
@@ -24,8 +24,11 @@ use indexmap::IndexMap;
 use sway_ast::Literal;
 use sway_types::{
     constants::{
-        ALLOW_DEAD_CODE_NAME, ALLOW_DEPRECATED_NAME, CFG_EXPERIMENTAL_NEW_ENCODING,
-        CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME,
+        ALLOW_DEAD_CODE_NAME, ALLOW_DEPRECATED_NAME, ALLOW_REDUNDANT_CLONE_NAME,
+        ALLOW_UNUSED_STORAGE_NAME, CFG_ALL_ARG_NAME, CFG_ANY_ARG_NAME,
+        CFG_EXPERIMENTAL_NEW_ENCODING, CFG_FEATURE_ARG_NAME, CFG_NOT_ARG_NAME,
+        CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME, CFG_TEST_ARG_NAME, DERIVE_ORD_ARG_NAME,
+        DERIVE_PARTIAL_ORD_ARG_NAME,
     },
     Ident, Span, Spanned,
 };
@@ -36,9 +39,26 @@ use std::{hash::Hash, sync::Arc};
 pub struct AttributeArg {
     pub name: Ident,
     pub value: Option<Literal>,
+    /// The nested arguments, e.g. `a` and `b` in `#[cfg(all(a, b))]`.
+    pub args: Vec<AttributeArg>,
     pub span: Span,
 }
 
+impl From<&sway_ast::attribute::AttributeArg> for AttributeArg {
+    fn from(arg: &sway_ast::attribute::AttributeArg) -> Self {
+        AttributeArg {
+            name: arg.name.clone(),
+            value: arg.value.clone(),
+            args: arg
+                .args
+                .as_ref()
+                .map(|parens| parens.get().into_iter().map(AttributeArg::from).collect())
+                .unwrap_or_default(),
+            span: arg.span(),
+        }
+    }
+}
+
 impl Spanned for AttributeArg {
     fn span(&self) -> Span {
         self.span.clone()
@@ -63,10 +83,12 @@ pub enum AttributeKind {
     Storage,
     Inline,
     Test,
+    Bench,
     Payable,
     Allow,
     Cfg,
     Deprecated,
+    Derive,
     Fallback,
 }
 
@@ -76,10 +98,10 @@ impl AttributeKind {
     pub fn expected_args_len_min_max(self) -> (usize, Option<usize>) {
         use AttributeKind::*;
         match self {
-            Doc | DocComment | Storage | Inline | Test | Payable | Deprecated | Fallback => {
-                (0, None)
-            }
+            Doc | DocComment | Storage | Inline | Test | Bench | Payable | Deprecated
+            | Fallback => (0, None),
             Allow | Cfg => (1, Some(1)),
+            Derive => (1, None),
         }
     }
 
@@ -87,15 +109,27 @@ impl AttributeKind {
     pub fn expected_args_values(self, _arg_index: usize) -> Option<Vec<String>> {
         use AttributeKind::*;
         match self {
-            Deprecated | Doc | DocComment | Storage | Inline | Test | Payable | Fallback => None,
+            Deprecated | Doc | DocComment | Storage | Inline | Test | Bench | Payable
+            | Fallback => None,
             Allow => Some(vec![
                 ALLOW_DEAD_CODE_NAME.to_string(),
                 ALLOW_DEPRECATED_NAME.to_string(),
+                ALLOW_UNUSED_STORAGE_NAME.to_string(),
+                ALLOW_REDUNDANT_CLONE_NAME.to_string(),
             ]),
             Cfg => Some(vec![
                 CFG_TARGET_ARG_NAME.to_string(),
                 CFG_PROGRAM_TYPE_ARG_NAME.to_string(),
                 CFG_EXPERIMENTAL_NEW_ENCODING.to_string(),
+                CFG_TEST_ARG_NAME.to_string(),
+                CFG_FEATURE_ARG_NAME.to_string(),
+                CFG_ALL_ARG_NAME.to_string(),
+                CFG_ANY_ARG_NAME.to_string(),
+                CFG_NOT_ARG_NAME.to_string(),
+            ]),
+            Derive => Some(vec![
+                DERIVE_ORD_ARG_NAME.to_string(),
+                DERIVE_PARTIAL_ORD_ARG_NAME.to_string(),
             ]),
         }
     }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use crate::{
     build_config::ExperimentalFlags,
     language::parsed::{Declaration, TreeType},
@@ -16,6 +18,9 @@ pub struct Context {
     /// Unique suffix used to generate unique names for destructured tuples.
     destructured_tuple_unique_suffix: usize,
 
+    /// Unique suffix used to generate unique names for arrays destructured with slice patterns.
+    destructured_slice_unique_suffix: usize,
+
     /// Unique suffix used to generate unique names for variables
     /// that store values matched in match expressions.
     match_expression_matched_value_unique_suffix: usize,
@@ -23,29 +28,54 @@ pub struct Context {
     /// Unique suffix used to generate unique names for for loops.
     for_unique_suffix: usize,
 
+    /// Unique suffix used to generate unique names for variables
+    /// that store values of type ascription expressions.
+    type_ascription_unique_suffix: usize,
+
     /// The build target.
     build_target: BuildTarget,
 
     /// The program type.
     program_type: Option<TreeType>,
 
+    /// Whether tests are included in the build, which enables `#[cfg(test)]` items.
+    include_tests: bool,
+
+    /// The features enabled for the build, which enable `#[cfg(feature = "...")]` items.
+    features: Vec<String>,
+
     /// Keeps track of the implementing type as we convert the tree.
     pub(crate) implementing_type: Option<Declaration>,
+
+    /// The integer values of the constants declared so far that can be computed while
+    /// converting the tree, one map per enclosing scope, innermost last.
+    /// These allow constants to be used as array lengths.
+    const_values: Vec<HashMap<String, usize>>,
 }
 
 impl Context {
     /// Create a new context.
-    pub fn new(build_target: BuildTarget, experimental: ExperimentalFlags) -> Self {
+    pub fn new(
+        build_target: BuildTarget,
+        include_tests: bool,
+        features: Vec<String>,
+        experimental: ExperimentalFlags,
+    ) -> Self {
         Self {
             build_target,
+            include_tests,
+            features,
             experimental,
             module_has_configurable_block: std::default::Default::default(),
             destructured_struct_unique_suffix: std::default::Default::default(),
             destructured_tuple_unique_suffix: std::default::Default::default(),
+            destructured_slice_unique_suffix: std::default::Default::default(),
             match_expression_matched_value_unique_suffix: std::default::Default::default(),
             for_unique_suffix: std::default::Default::default(),
+            type_ascription_unique_suffix: std::default::Default::default(),
             program_type: std::default::Default::default(),
             implementing_type: None,
+            const_values: vec![HashMap::new()],
         }
     }
 
@@ -71,6 +101,13 @@ impl Context {
         self.destructured_tuple_unique_suffix
     }
 
+    /// Returns a unique suffix used to generate a unique name for an array
+    /// destructured with a slice pattern.
+    pub fn next_destructured_slice_unique_suffix(&mut self) -> usize {
+        self.destructured_slice_unique_suffix += 1;
+        self.destructured_slice_unique_suffix
+    }
+
     /// Returns a unique suffix used to generate a unique name for a variable
     /// that stores the value matched in a match expression.
     pub fn next_match_expression_matched_value_unique_suffix(&mut self) -> usize {
@@ -84,11 +121,28 @@ impl Context {
         self.for_unique_suffix
     }
 
+    /// Returns a unique suffix used to generate a unique name for a variable
+    /// that stores the value of a type ascription expression.
+    pub fn next_type_ascription_unique_suffix(&mut self) -> usize {
+        self.type_ascription_unique_suffix += 1;
+        self.type_ascription_unique_suffix
+    }
+
     /// Returns the build target.
     pub fn build_target(&self) -> BuildTarget {
         self.build_target
     }
 
+    /// Returns whether tests are included in the build.
+    pub fn include_tests(&self) -> bool {
+        self.include_tests
+    }
+
+    /// Returns whether the given feature is enabled for the build.
+    pub fn is_feature_enabled(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
+    }
+
     /// Returns the program type.
     pub fn program_type(&self) -> Option<TreeType> {
         self.program_type
@@ -98,4 +152,28 @@ impl Context {
     pub fn set_program_type(&mut self, program_type: TreeType) {
         self.program_type = Some(program_type);
     }
+
+    /// Runs `f` within a new scope. Constant values inserted by `f` are not visible after it
+    /// returns.
+    pub fn with_const_scope<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        self.const_values.push(HashMap::new());
+        let result = f(self);
+        self.const_values.pop();
+        result
+    }
+
+    /// Records the integer value of the constant `name` in the innermost scope.
+    pub fn insert_const_value(&mut self, name: String, value: usize) {
+        if let Some(scope) = self.const_values.last_mut() {
+            scope.insert(name, value);
+        }
+    }
+
+    /// Returns the integer value of the constant `name` visible in the current scope, if known.
+    pub fn const_value(&self, name: &str) -> Option<usize> {
+        self.const_values
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).copied())
+    }
 }
@@ -1,7 +1,8 @@
 use crate::{
     compiler_generated::{
+        generate_destructured_slice_element_var_name, generate_destructured_slice_var_name,
         generate_destructured_struct_var_name, generate_matched_value_var_name,
-        generate_tuple_var_name,
+        generate_tuple_var_name, generate_type_ascription_var_name,
     },
     decl_engine::{parsed_engine::ParsedDeclEngineInsert, parsed_id::ParsedDeclId},
     language::{parsed::*, *},
@@ -16,27 +17,31 @@ use sway_ast::{
     assignable::ElementAccess,
     attribute::Annotated,
     expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant},
+    keywords::{FatRightArrowToken, Keyword, QuestionMarkToken, ReturnToken, Token},
     ty::TyTupleDescriptor,
     AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CodeBlockContents,
     CommaToken, DoubleColonToken, Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor,
-    FnArg, FnArgs, FnSignature, GenericArgs, GenericParams, IfCondition, IfExpr, Instruction,
-    Intrinsic, Item, ItemAbi, ItemConfigurable, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemKind,
-    ItemStorage, ItemStruct, ItemTrait, ItemTraitItem, ItemTypeAlias, ItemUse, LitInt, LitIntType,
-    MatchBranchKind, Module, ModuleKind, Parens, PathExpr, PathExprSegment, PathType,
-    PathTypeSegment, Pattern, PatternStructField, PubToken, Punctuated, QualifiedPathRoot,
-    Statement, StatementLet, Submodule, TraitType, Traits, Ty, TypeField, UseTree, WhereClause,
+    ExprTypeAscription, FnArg, FnArgs, FnSignature, GenericArgs, GenericParam, GenericParams,
+    IfCondition, IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConfigurable, ItemConst,
+    ItemEnum, ItemFn, ItemImpl, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemTraitItem,
+    ItemTypeAlias, ItemUse, LitInt, LitIntType, MatchBranchKind, MatchesArgs, Module, ModuleKind,
+    Parens, PathExpr, PathExprSegment, PathType, PathTypeSegment, Pattern, PatternSliceElement,
+    PatternStructField, PubToken, Punctuated, QualifiedPathRoot, Statement, StatementLet,
+    Submodule, TraitType, Traits, Ty, TypeField, UseTree, VisibilityQualifier,
+    VisibilityRestriction, WhereClause,
 };
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_error::warning::{CompileWarning, Warning};
 use sway_error::{convert_parse_tree_error::ConvertParseTreeError, error::CompileError};
 use sway_types::{
     constants::{
-        ALLOW_ATTRIBUTE_NAME, CFG_ATTRIBUTE_NAME, CFG_EXPERIMENTAL_NEW_ENCODING,
-        CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME, DEPRECATED_ATTRIBUTE_NAME,
-        DOC_ATTRIBUTE_NAME, DOC_COMMENT_ATTRIBUTE_NAME, FALLBACK_ATTRIBUTE_NAME,
-        INLINE_ATTRIBUTE_NAME, PAYABLE_ATTRIBUTE_NAME, STORAGE_PURITY_ATTRIBUTE_NAME,
-        STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME, TEST_ATTRIBUTE_NAME,
-        VALID_ATTRIBUTE_NAMES,
+        ALLOW_ATTRIBUTE_NAME, BENCH_ATTRIBUTE_NAME, CFG_ALL_ARG_NAME, CFG_ANY_ARG_NAME,
+        CFG_ATTRIBUTE_NAME, CFG_EXPERIMENTAL_NEW_ENCODING, CFG_FEATURE_ARG_NAME, CFG_NOT_ARG_NAME,
+        CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME, CFG_TEST_ARG_NAME,
+        DEPRECATED_ATTRIBUTE_NAME, DERIVE_ATTRIBUTE_NAME, DOC_ATTRIBUTE_NAME,
+        DOC_COMMENT_ATTRIBUTE_NAME, FALLBACK_ATTRIBUTE_NAME, INLINE_ATTRIBUTE_NAME,
+        PAYABLE_ATTRIBUTE_NAME, STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME,
+        STORAGE_PURITY_WRITE_NAME, TEST_ATTRIBUTE_NAME, VALID_ATTRIBUTE_NAMES,
     },
     integer_bits::IntegerBits,
     BaseIdent,
@@ -100,7 +105,7 @@ pub fn module_to_sway_parse_tree(
 fn ast_node_is_test_fn(engines: &Engines, node: &AstNode) -> bool {
     if let AstNodeContent::Declaration(Declaration::FunctionDeclaration(decl_id)) = node.content {
         let decl = engines.pe().get_function(&decl_id);
-        if decl.attributes.contains_key(&AttributeKind::Test) {
+        if decl.is_test() {
             return true;
         }
     }
@@ -231,6 +236,13 @@ pub fn item_to_ast_nodes(
             decl(abi_decl)
         }
         ItemKind::Const(item_const) => decl(Declaration::ConstantDeclaration({
+            if let Some(value) = item_const
+                .expr_opt
+                .as_ref()
+                .and_then(|expr| const_expr_to_usize(context, expr, true).ok())
+            {
+                context.insert_const_value(item_const.name.as_str().to_string(), value);
+            }
             item_const_to_constant_declaration(
                 context, handler, engines, item_const, attributes, true,
             )?
@@ -297,10 +309,11 @@ fn item_use_to_use_statements(
         item_span,
     );
 
-    // Check that all use statements have a call_path
-    // This is not the case for `use foo;`, which is currently not supported
+    // Check that all relative use statements have a call_path
+    // This is not the case for `use foo;`, which is currently not supported,
+    // unlike `use ::foo;` which imports `foo` from the root of the package
     for use_stmt in ret.iter() {
-        if use_stmt.call_path.is_empty() {
+        if use_stmt.call_path.is_empty() && !use_stmt.is_absolute {
             let error = ConvertParseTreeError::ImportsWithoutItemsNotSupported {
                 span: use_stmt.span.clone(),
             };
@@ -326,7 +339,7 @@ fn use_tree_to_use_statements(
                 use_tree_to_use_statements(
                     use_tree,
                     is_absolute,
-                    reexport,
+                    reexport.clone(),
                     path,
                     ret,
                     item_span.clone(),
@@ -452,7 +465,7 @@ fn item_struct_to_struct_declaration(
             item_struct.generics,
             item_struct.where_clause_opt,
         )?,
-        visibility: pub_token_opt_to_visibility(item_struct.visibility),
+        visibility: visibility_qualifier_opt_to_visibility(item_struct.visibility),
         span,
     });
     Ok(struct_declaration_id)
@@ -521,7 +534,7 @@ fn item_enum_to_enum_declaration(
         )?,
         variants,
         span,
-        visibility: pub_token_opt_to_visibility(item_enum.visibility),
+        visibility: visibility_qualifier_opt_to_visibility(item_enum.visibility),
         attributes,
     });
     Ok(enum_declaration_id)
@@ -569,7 +582,7 @@ pub fn item_fn_to_function_declaration(
         purity: get_attributed_purity(context, handler, &attributes)?,
         attributes,
         name: item_fn.fn_signature.name,
-        visibility: pub_token_opt_to_visibility(item_fn.fn_signature.visibility),
+        visibility: visibility_qualifier_opt_to_visibility(item_fn.fn_signature.visibility),
         body: braced_code_block_contents_to_code_block(context, handler, engines, item_fn.body)?,
         parameters: fn_args_to_function_parameters(
             context,
@@ -725,7 +738,7 @@ fn item_trait_to_trait_declaration(
         None => Vec::new(),
         Some((_colon_token, traits)) => traits_to_supertraits(context, handler, traits)?,
     };
-    let visibility = pub_token_opt_to_visibility(item_trait.visibility);
+    let visibility = visibility_qualifier_opt_to_visibility(item_trait.visibility);
     let trait_decl_id = engines.pe().insert(TraitDeclaration {
         name: item_trait.name,
         type_parameters,
@@ -1000,7 +1013,7 @@ pub(crate) fn item_const_to_constant_declaration(
         name: item_const.name,
         type_ascription,
         value: expr,
-        visibility: pub_token_opt_to_visibility(item_const.visibility),
+        visibility: visibility_qualifier_opt_to_visibility(item_const.visibility),
         attributes,
         span,
     };
@@ -1176,7 +1189,7 @@ fn item_type_alias_to_type_alias_declaration(
         name: item_type_alias.name.clone(),
         attributes,
         ty: ty_to_type_argument(context, handler, engines, item_type_alias.ty)?,
-        visibility: pub_token_opt_to_visibility(item_type_alias.visibility),
+        visibility: visibility_qualifier_opt_to_visibility(item_type_alias.visibility),
         span,
     };
     let type_alias_decl = engines.pe().insert(type_alias_decl);
@@ -1191,12 +1204,17 @@ fn type_field_to_struct_field(
     attributes: AttributesMap,
 ) -> Result<StructField, ErrorEmitted> {
     let span = type_field.span();
+    let default_value = type_field
+        .default_opt
+        .map(|(_eq_token, default)| expr_to_expression(context, handler, engines, default))
+        .transpose()?;
     let struct_field = StructField {
         visibility: pub_token_opt_to_visibility(type_field.visibility),
         name: type_field.name,
         attributes,
         type_argument: ty_to_type_argument(context, handler, engines, type_field.ty)?,
         span,
+        default_value,
     };
     Ok(struct_field)
 }
@@ -1230,23 +1248,35 @@ fn generic_params_opt_to_type_parameters_with_parent(
 ) -> Result<Vec<TypeParameter>, ErrorEmitted> {
     let type_engine = engines.te();
 
-    let trait_constraints = match where_clause_opt {
-        Some(where_clause) => where_clause
-            .bounds
+    // Inline bounds, e.g. `<T: Eq>`, are treated exactly like `where` clause bounds.
+    let inline_trait_constraints = |generics: &Option<GenericParams>| match generics {
+        Some(generic_params) => generic_params
+            .parameters
+            .clone()
+            .into_inner()
             .into_iter()
-            .map(|where_bound| (where_bound.ty_name, where_bound.bounds))
+            .filter_map(|param| param.bounds_opt.map(|(_, bounds)| (param.name, bounds)))
             .collect::<Vec<_>>(),
         None => Vec::new(),
     };
 
-    let parent_trait_constraints = match parent_where_clause_opt {
-        Some(where_clause) => where_clause
-            .bounds
-            .into_iter()
-            .map(|where_bound| (where_bound.ty_name, where_bound.bounds))
-            .collect::<Vec<_>>(),
-        None => Vec::new(),
-    };
+    let where_clause_trait_constraints =
+        |where_clause_opt: Option<WhereClause>| match where_clause_opt {
+            Some(where_clause) => where_clause
+                .bounds
+                .into_iter()
+                .map(|where_bound| (where_bound.ty_name, where_bound.bounds))
+                .collect::<Vec<_>>(),
+            None => Vec::new(),
+        };
+
+    let trait_constraints = inline_trait_constraints(&generic_params_opt)
+        .into_iter()
+        .chain(where_clause_trait_constraints(where_clause_opt));
+
+    let parent_trait_constraints = inline_trait_constraints(&parent_generic_params_opt)
+        .into_iter()
+        .chain(where_clause_trait_constraints(parent_where_clause_opt));
 
     let generics_to_params = |generics: Option<GenericParams>, is_from_parent: bool| match generics
     {
@@ -1254,7 +1284,7 @@ fn generic_params_opt_to_type_parameters_with_parent(
             .parameters
             .into_inner()
             .into_iter()
-            .map(|ident| {
+            .map(|GenericParam { name: ident, .. }| {
                 let custom_type = type_engine.insert(
                     engines,
                     TypeInfo::Custom {
@@ -1326,6 +1356,29 @@ fn pub_token_opt_to_visibility(pub_token_opt: Option<PubToken>) -> Visibility {
     }
 }
 
+fn visibility_qualifier_opt_to_visibility(
+    visibility_opt: Option<VisibilityQualifier>,
+) -> Visibility {
+    let Some(visibility) = visibility_opt else {
+        return Visibility::Private;
+    };
+    match visibility
+        .restriction_opt
+        .map(|restriction| restriction.inner)
+    {
+        None => Visibility::Public,
+        Some(VisibilityRestriction::Crate { .. }) => Visibility::Restricted(vec![]),
+        Some(VisibilityRestriction::In { path, .. }) => {
+            // The path is relative to the package root, which can be explicitly named `crate`.
+            let mut path = path.into_iter().collect::<Vec<_>>();
+            if path.first().is_some_and(|ident| ident.as_str() == "crate") {
+                path.remove(0);
+            }
+            Visibility::Restricted(path)
+        }
+    }
+}
+
 fn type_field_to_enum_variant(
     context: &mut Context,
     handler: &Handler,
@@ -1336,6 +1389,13 @@ fn type_field_to_enum_variant(
 ) -> Result<EnumVariant, ErrorEmitted> {
     let span = type_field.span();
 
+    if let Some((_eq_token, default)) = &type_field.default_opt {
+        let error = ConvertParseTreeError::EnumVariantDefaultValue {
+            span: default.span(),
+        };
+        return Err(handler.emit_err(error.into()));
+    }
+
     let enum_variant = EnumVariant {
         name: type_field.name,
         attributes,
@@ -1354,7 +1414,7 @@ fn braced_code_block_contents_to_code_block(
 ) -> Result<CodeBlock, ErrorEmitted> {
     let whole_block_span = braced_code_block_contents.span();
     let code_block_contents = braced_code_block_contents.into_inner();
-    let contents = {
+    let contents = context.with_const_scope(|context| {
         let mut error = None;
 
         let mut contents = Vec::new();
@@ -1371,11 +1431,11 @@ fn braced_code_block_contents_to_code_block(
         }
 
         if let Some(error) = error {
-            return Err(error);
+            Err(error)
         } else {
-            contents
+            Ok(contents)
         }
-    };
+    })?;
 
     Ok(CodeBlock {
         contents,
@@ -2069,6 +2129,9 @@ fn expr_to_expression(
         Expr::Parens(parens) => {
             expr_to_expression(context, handler, engines, *parens.into_inner())?
         }
+        Expr::TypeAscription(ascription) => {
+            type_ascription_to_expression(context, handler, engines, ascription.into_inner(), span)?
+        }
         Expr::Block(braced_code_block_contents) => braced_code_block_contents_to_expression(
             context,
             handler,
@@ -2145,15 +2208,38 @@ fn expr_to_expression(
 
             match_expr_to_expression(context, handler, engines, *value, branches, span)?
         }
+        Expr::Matches { args, .. } => {
+            matches_args_to_expression(context, handler, engines, args.into_inner(), span)?
+        }
+        Expr::Try {
+            expr,
+            question_mark_token,
+        } => try_to_expression(context, handler, engines, expr, question_mark_token, span)?,
         Expr::While {
             condition, block, ..
-        } => Expression {
-            kind: ExpressionKind::WhileLoop(WhileLoopExpression {
-                condition: Box::new(expr_to_expression(context, handler, engines, *condition)?),
-                body: braced_code_block_contents_to_code_block(context, handler, engines, block)?,
-            }),
-            span,
-        },
+        } => {
+            warn_if_block_is_empty(handler, "while", &block);
+            Expression {
+                kind: ExpressionKind::WhileLoop(WhileLoopExpression {
+                    condition: Box::new(expr_to_expression(context, handler, engines, *condition)?),
+                    body: braced_code_block_contents_to_code_block(
+                        context, handler, engines, block,
+                    )?,
+                }),
+                span,
+            }
+        }
+        Expr::Loop { block, .. } => {
+            warn_if_block_is_empty(handler, "loop", &block);
+            Expression {
+                kind: ExpressionKind::Loop(LoopExpression {
+                    body: braced_code_block_contents_to_code_block(
+                        context, handler, engines, block,
+                    )?,
+                }),
+                span,
+            }
+        }
         Expr::For {
             value_pattern,
             iterator,
@@ -2493,10 +2579,18 @@ fn expr_to_expression(
                 }
             }
         },
-        Expr::Break { .. } => Expression {
-            kind: ExpressionKind::Break,
-            span,
-        },
+        Expr::Break { expr_opt, .. } => {
+            let expression = match expr_opt {
+                Some(expr) => Some(Box::new(expr_to_expression(
+                    context, handler, engines, *expr,
+                )?)),
+                None => None,
+            };
+            Expression {
+                kind: ExpressionKind::Break(expression),
+                span,
+            }
+        }
         Expr::Continue { .. } => Expression {
             kind: ExpressionKind::Continue,
             span,
@@ -2732,6 +2826,10 @@ fn fn_arg_to_function_parameter(
             let error = ConvertParseTreeError::TuplePatternsNotSupportedHere { span: pat_span };
             return Err(handler.emit_err(error.into()));
         }
+        Pattern::Slice(..) => {
+            let error = ConvertParseTreeError::SlicePatternsNotSupportedHere { span: pat_span };
+            return Err(handler.emit_err(error.into()));
+        }
     };
     let mutability_span = match (&reference, &mutable) {
         (None, None) => Span::dummy(),
@@ -2759,34 +2857,135 @@ fn expr_to_length(
 }
 
 fn expr_to_usize(
-    _context: &mut Context,
+    context: &mut Context,
     handler: &Handler,
     expr: Expr,
 ) -> Result<usize, ErrorEmitted> {
+    const_expr_to_usize(context, &expr, false).map_err(|error| handler.emit_err(error.into()))
+}
+
+/// Computes the value of an integer expression whose value must be known while converting the
+/// tree, like an array length.
+///
+/// Besides integer literals, the expression may refer to constants declared earlier in an
+/// enclosing scope whose values are known, use `__size_of` and `__align_of` of types whose layout
+/// is known, and combine those using `+`, `-`, `*`, `/` and `%`.
+/// Type suffixes on integer literals are only accepted if `allow_int_suffix` is set.
+fn const_expr_to_usize(
+    context: &Context,
+    expr: &Expr,
+    allow_int_suffix: bool,
+) -> Result<usize, ConvertParseTreeError> {
     let span = expr.span();
-    let value = match expr {
+    let binary = |lhs: &Expr, rhs: &Expr, op: fn(usize, usize) -> Option<usize>| {
+        let lhs = const_expr_to_usize(context, lhs, allow_int_suffix)?;
+        let rhs = const_expr_to_usize(context, rhs, allow_int_suffix)?;
+        op(lhs, rhs).ok_or(ConvertParseTreeError::IntLiteralOutOfRange { span: span.clone() })
+    };
+    match expr {
         Expr::Literal(sway_ast::Literal::Int(lit_int)) => {
-            match lit_int.ty_opt {
-                None => (),
-                Some(..) => {
-                    let error = ConvertParseTreeError::IntTySuffixNotSupported { span };
-                    return Err(handler.emit_err(error.into()));
-                }
+            if lit_int.ty_opt.is_some() && !allow_int_suffix {
+                return Err(ConvertParseTreeError::IntTySuffixNotSupported { span });
             }
-            match usize::try_from(lit_int.parsed) {
-                Ok(value) => value,
-                Err(..) => {
-                    let error = ConvertParseTreeError::IntLiteralOutOfRange { span };
-                    return Err(handler.emit_err(error.into()));
+            usize::try_from(&lit_int.parsed)
+                .map_err(|_| ConvertParseTreeError::IntLiteralOutOfRange { span })
+        }
+        Expr::Path(path_expr) => match path_expr.clone().try_into_ident() {
+            Ok(name) => context
+                .const_value(name.as_str())
+                .ok_or(ConvertParseTreeError::ConstantValueNotKnown { name, span }),
+            Err(_) => Err(ConvertParseTreeError::IntLiteralExpected { span }),
+        },
+        Expr::Parens(parens) => const_expr_to_usize(context, parens.get(), allow_int_suffix),
+        Expr::Add { lhs, rhs, .. } => binary(lhs, rhs, usize::checked_add),
+        Expr::Sub { lhs, rhs, .. } => binary(lhs, rhs, usize::checked_sub),
+        Expr::Mul { lhs, rhs, .. } => binary(lhs, rhs, usize::checked_mul),
+        Expr::Div { lhs, rhs, .. } => binary(lhs, rhs, usize::checked_div),
+        Expr::Modulo { lhs, rhs, .. } => binary(lhs, rhs, usize::checked_rem),
+        Expr::FuncApp { func, args } => {
+            let intrinsic = match &**func {
+                Expr::Path(PathExpr {
+                    root_opt: None,
+                    prefix,
+                    suffix,
+                    ..
+                }) if suffix.is_empty() => {
+                    Intrinsic::try_from_str(prefix.name.as_str()).zip(prefix.generics_opt.as_ref())
                 }
-            }
-        }
-        _ => {
-            let error = ConvertParseTreeError::IntLiteralExpected { span };
-            return Err(handler.emit_err(error.into()));
+                _ => None,
+            };
+            let mut tys = match intrinsic {
+                Some((Intrinsic::SizeOfType | Intrinsic::AlignOfType, (_, generic_args)))
+                    if args.get().into_iter().next().is_none() =>
+                {
+                    (&generic_args.parameters.inner).into_iter()
+                }
+                _ => return Err(ConvertParseTreeError::IntLiteralExpected { span }),
+            };
+            let (Some(ty), None) = (tys.next(), tys.next()) else {
+                return Err(ConvertParseTreeError::IntLiteralExpected { span });
+            };
+            let (size, alignment) = const_ty_layout(context, ty)?;
+            Ok(match intrinsic {
+                Some((Intrinsic::SizeOfType, _)) => size,
+                _ => alignment,
+            })
         }
-    };
-    Ok(value)
+        _ => Err(ConvertParseTreeError::IntLiteralExpected { span }),
+    }
+}
+
+/// Computes the size and the alignment in bytes of a type whose layout is known while converting
+/// the tree, i.e. of a built-in type, or of a tuple or an array of such types.
+///
+/// The layout is the same as the one of the IR types, see `sway_ir::Type::size` and
+/// `sway_ir::Type::alignment`.
+fn const_ty_layout(context: &Context, ty: &Ty) -> Result<(usize, usize), ConvertParseTreeError> {
+    let span = ty.span();
+    let word_aligned = |size: usize| size.div_ceil(8) * 8;
+    match ty {
+        Ty::Path(PathType {
+            root_opt: None,
+            prefix:
+                PathTypeSegment {
+                    name,
+                    generics_opt: None,
+                },
+            suffix,
+        }) if suffix.is_empty() => match name.as_str() {
+            "u8" | "bool" => Ok((1, 1)),
+            "u16" | "u32" | "u64" | "raw_ptr" => Ok((8, 8)),
+            "u256" | "b256" => Ok((32, 8)),
+            "str" | "raw_slice" => Ok((16, 8)),
+            _ => Err(ConvertParseTreeError::TypeLayoutNotKnown { span }),
+        },
+        Ty::StringSlice(..) => Ok((16, 8)),
+        Ty::StringArray { length, .. } => {
+            let length = const_expr_to_usize(context, length.get(), false)?;
+            Ok((word_aligned(length), 8))
+        }
+        Ty::Tuple(tys) => match tys.get() {
+            TyTupleDescriptor::Nil => Ok((1, 1)),
+            TyTupleDescriptor::Cons { head, tail, .. } => {
+                // Tuples are structs, whose fields are word aligned.
+                let mut size = word_aligned(const_ty_layout(context, head)?.0);
+                for ty in tail {
+                    size += word_aligned(const_ty_layout(context, ty)?.0);
+                }
+                Ok((size, 8))
+            }
+        },
+        Ty::Array(descriptor) => {
+            let descriptor = descriptor.get();
+            let (elem_size, elem_alignment) = const_ty_layout(context, &descriptor.ty)?;
+            let length = const_expr_to_usize(context, &descriptor.length, false)?;
+            elem_size
+                .checked_mul(length)
+                .map(|size| (size, elem_alignment))
+                .ok_or(ConvertParseTreeError::IntLiteralOutOfRange { span })
+        }
+        _ => Err(ConvertParseTreeError::TypeLayoutNotKnown { span }),
+    }
 }
 
 fn path_type_to_supertrait(
@@ -2930,6 +3129,30 @@ fn braced_code_block_contents_to_expression(
     })
 }
 
+/// Emits a warning if the block of the control flow construct denoted by `keyword` contains
+/// neither statements nor comments. A block containing only comments is considered to be
+/// intentionally empty.
+fn warn_if_block_is_empty(
+    handler: &Handler,
+    keyword: &'static str,
+    block: &Braces<CodeBlockContents>,
+) {
+    let contents = block.get();
+    if !contents.statements.is_empty() || contents.final_expr_opt.is_some() {
+        return;
+    }
+    let span = block.span();
+    let inner = span.as_str();
+    let inner = inner.strip_prefix('{').unwrap_or(inner);
+    let inner = inner.strip_suffix('}').unwrap_or(inner);
+    if inner.trim().is_empty() {
+        handler.emit_warn(CompileWarning {
+            span,
+            warning_content: Warning::ControlFlowBlockIsEmpty { keyword },
+        });
+    }
+}
+
 fn if_expr_to_expression(
     context: &mut Context,
     handler: &Handler,
@@ -2943,6 +3166,7 @@ fn if_expr_to_expression(
         else_opt,
         ..
     } = if_expr;
+    warn_if_block_is_empty(handler, "if", &then_block);
     let then_block_span = then_block.span();
     let then_block = Expression {
         kind: ExpressionKind::CodeBlock(braced_code_block_contents_to_code_block(
@@ -2955,6 +3179,7 @@ fn if_expr_to_expression(
         Some((_else_token, tail)) => {
             let expression = match tail {
                 LoopControlFlow::Break(braced_code_block_contents) => {
+                    warn_if_block_is_empty(handler, "else", &braced_code_block_contents);
                     braced_code_block_contents_to_expression(
                         context,
                         handler,
@@ -3030,6 +3255,83 @@ fn match_expr_to_expression(
     value: Expr,
     branches: Vec<MatchBranch>,
     span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    matched_value_to_expression(context, handler, engines, value, span, |value| {
+        ExpressionKind::Match(MatchExpression { value, branches })
+    })
+}
+
+/// Desugars the type ascription `(expr : T)` into a code block that binds `expr` to a
+/// compiler generated variable of type `T` and returns it:
+///
+/// ```ignore
+/// {
+///     let __type_ascription_1: T = expr;
+///     __type_ascription_1
+/// }
+/// ```
+///
+/// The ascribed type is thus checked against the type of `expr` and guides its inference
+/// exactly like the type annotation of a `let` statement does.
+fn type_ascription_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    ascription: ExprTypeAscription,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    let value = expr_to_expression(context, handler, engines, *ascription.expr)?;
+    let type_ascription = ty_to_type_argument(context, handler, engines, ascription.ty)?;
+
+    let var_decl_name = Ident::new_with_override(
+        generate_type_ascription_var_name(context.next_type_ascription_unique_suffix()),
+        value.span(),
+    );
+
+    let var_decl_exp = Expression {
+        kind: ExpressionKind::Variable(var_decl_name.clone()),
+        span: span.clone(),
+    };
+
+    let var_decl = engines.pe().insert(VariableDeclaration {
+        name: var_decl_name,
+        type_ascription,
+        body: value,
+        is_mutable: false,
+    });
+
+    Ok(Expression {
+        kind: ExpressionKind::CodeBlock(CodeBlock {
+            contents: vec![
+                AstNode {
+                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                        var_decl,
+                    )),
+                    span: span.clone(),
+                },
+                AstNode {
+                    content: AstNodeContent::Expression(Expression {
+                        kind: ExpressionKind::ImplicitReturn(Box::new(var_decl_exp)),
+                        span: span.clone(),
+                    }),
+                    span: span.clone(),
+                },
+            ],
+            whole_block_span: span.clone(),
+        }),
+        span,
+    })
+}
+
+/// Binds the matched `value` to a compiler generated variable, and returns a code block
+/// whose implicit return is the expression created by `match_kind` from that variable.
+fn matched_value_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    value: Expr,
+    span: Span,
+    match_kind: impl FnOnce(Box<Expression>) -> ExpressionKind,
 ) -> Result<Expression, ErrorEmitted> {
     let value = expr_to_expression(context, handler, engines, value)?;
     let var_decl_span = value.span();
@@ -3073,10 +3375,7 @@ fn match_expr_to_expression(
                 AstNode {
                     content: AstNodeContent::Expression(Expression {
                         kind: ExpressionKind::ImplicitReturn(Box::new(Expression {
-                            kind: ExpressionKind::Match(MatchExpression {
-                                value: Box::new(var_decl_exp),
-                                branches,
-                            }),
+                            kind: match_kind(Box::new(var_decl_exp)),
                             span: span.clone(),
                         })),
                         span: span.clone(),
@@ -3090,6 +3389,215 @@ fn match_expr_to_expression(
     })
 }
 
+/// Desugars `matches!(value, pattern if guard)` into
+/// `match value { pattern => guard, _ => false }`.
+/// Without a guard, the first branch simply evaluates to `true`.
+fn matches_args_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    matches_args: MatchesArgs,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    let MatchesArgs {
+        value,
+        pattern,
+        guard_opt,
+        ..
+    } = matches_args;
+    let bool_expression = |value: bool, span: Span| Expression {
+        kind: ExpressionKind::Literal(Literal::Boolean(value)),
+        span,
+    };
+
+    let scrutinee = pattern_to_scrutinee(context, handler, pattern)?;
+    let scrutinee_span = scrutinee.span();
+    let result = match guard_opt {
+        Some((_if_token, guard)) => expr_to_expression(context, handler, engines, *guard)?,
+        None => bool_expression(true, scrutinee_span.clone()),
+    };
+    let branches = vec![
+        MatchBranch {
+            span: Span::join(scrutinee_span, &result.span()),
+            scrutinee,
+            result,
+        },
+        MatchBranch {
+            scrutinee: Scrutinee::CatchAll { span: span.clone() },
+            result: bool_expression(false, span.clone()),
+            span: span.clone(),
+        },
+    ];
+
+    match_expr_to_expression(context, handler, engines, *value, branches, span)
+}
+
+/// Desugars `value?` into a match on `value`. Since the return type of the enclosing
+/// function is only known during type checking, the branches for both `Option` and
+/// `Result` are created, and the type checker picks the ones matching the return type:
+///
+/// ```ignore
+/// match value {
+///     ::std::option::Option::Some(v) => v,
+///     ::std::option::Option::None => return ::std::option::Option::None,
+/// }
+///
+/// match value {
+///     ::std::result::Result::Ok(v) => v,
+///     ::std::result::Result::Err(e) => return ::std::result::Result::Err(e),
+/// }
+/// ```
+fn try_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    value: Box<Expr>,
+    question_mark_token: QuestionMarkToken,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    let question_mark_span = question_mark_token.span();
+    let option_branches = try_match_branches(
+        context,
+        handler,
+        engines,
+        TryKind::Option,
+        &question_mark_span,
+    )?;
+    let result_branches = try_match_branches(
+        context,
+        handler,
+        engines,
+        TryKind::Result,
+        &question_mark_span,
+    )?;
+
+    matched_value_to_expression(context, handler, engines, *value, span, |value| {
+        ExpressionKind::Try(Box::new(TryExpression {
+            value,
+            option_branches,
+            result_branches,
+            question_mark_span,
+        }))
+    })
+}
+
+/// The types the `?` operator can be applied to and returned from.
+#[derive(Clone, Copy)]
+enum TryKind {
+    Option,
+    Result,
+}
+
+/// Creates the branches of the match `value?` desugars into, for the given `try_kind`.
+fn try_match_branches(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    try_kind: TryKind,
+    op_span: &Span,
+) -> Result<Vec<MatchBranch>, ErrorEmitted> {
+    // All the generated identifiers and tokens point to the `?` operator, so that
+    // errors in the desugared match point to it.
+    let ident = |name: &str| Ident::new_with_override(name.to_string(), op_span.clone());
+    let (module_name, type_name, success_name, failure_name) = match try_kind {
+        TryKind::Option => ("option", "Option", "Some", "None"),
+        TryKind::Result => ("result", "Result", "Ok", "Err"),
+    };
+    // The variants are referred to by their absolute paths, e.g. `::std::option::Option::Some`,
+    // so that they can't be shadowed by items declared or imported by the user.
+    let path_segment = |name: &str| {
+        (
+            DoubleColonToken::new(op_span.clone()),
+            PathExprSegment {
+                name: ident(name),
+                generics_opt: None,
+            },
+        )
+    };
+    let variant_path = |variant_name: &str| PathExpr {
+        root_opt: Some((None, DoubleColonToken::new(op_span.clone()))),
+        prefix: PathExprSegment {
+            name: ident("std"),
+            generics_opt: None,
+        },
+        suffix: vec![
+            path_segment(module_name),
+            path_segment(type_name),
+            path_segment(variant_name),
+        ],
+        incomplete_suffix: false,
+    };
+    fn single_arg<T>(arg: T) -> Punctuated<T, CommaToken> {
+        Punctuated {
+            value_separator_pairs: vec![],
+            final_value_opt: Some(Box::new(arg)),
+        }
+    }
+    let constructor_pattern = |variant_name: &str, var_name: &str| Pattern::Constructor {
+        path: variant_path(variant_name),
+        args: Parens::new(
+            single_arg(Pattern::Var {
+                reference: None,
+                mutable: None,
+                name: ident(var_name),
+            }),
+            op_span.clone(),
+        ),
+    };
+    let var_expr = |var_name: &str| {
+        Expr::Path(PathExpr {
+            root_opt: None,
+            prefix: PathExprSegment {
+                name: ident(var_name),
+                generics_opt: None,
+            },
+            suffix: vec![],
+            incomplete_suffix: false,
+        })
+    };
+
+    const SUCCESS_VAR_NAME: &str = "__try_value";
+    const FAILURE_VAR_NAME: &str = "__try_error";
+    let (failure_pattern, failure_value) = match try_kind {
+        TryKind::Option => (
+            Pattern::Constant(variant_path(failure_name)),
+            Expr::Path(variant_path(failure_name)),
+        ),
+        TryKind::Result => (
+            constructor_pattern(failure_name, FAILURE_VAR_NAME),
+            Expr::FuncApp {
+                func: Box::new(Expr::Path(variant_path(failure_name))),
+                args: Parens::new(single_arg(var_expr(FAILURE_VAR_NAME)), op_span.clone()),
+            },
+        ),
+    };
+
+    vec![
+        sway_ast::MatchBranch {
+            pattern: constructor_pattern(success_name, SUCCESS_VAR_NAME),
+            fat_right_arrow_token: FatRightArrowToken::new(op_span.clone()),
+            kind: MatchBranchKind::Expr {
+                expr: var_expr(SUCCESS_VAR_NAME),
+                comma_token: CommaToken::new(op_span.clone()),
+            },
+        },
+        sway_ast::MatchBranch {
+            pattern: failure_pattern,
+            fat_right_arrow_token: FatRightArrowToken::new(op_span.clone()),
+            kind: MatchBranchKind::Expr {
+                expr: Expr::Return {
+                    return_token: ReturnToken::new(op_span.clone()),
+                    expr_opt: Some(Box::new(failure_value)),
+                },
+                comma_token: CommaToken::new(op_span.clone()),
+            },
+        },
+    ]
+    .into_iter()
+    .map(|match_branch| match_branch_to_match_branch(context, handler, engines, match_branch))
+    .collect()
+}
+
 fn for_expr_to_expression(
     context: &mut Context,
     handler: &Handler,
@@ -3099,6 +3607,8 @@ fn for_expr_to_expression(
     block: Braces<CodeBlockContents>,
     span: Span,
 ) -> Result<Expression, ErrorEmitted> {
+    warn_if_block_is_empty(handler, "for", &block);
+
     // Desugar for loop into:
     //      let mut iterable = iterator;
     //    while true {
@@ -3250,7 +3760,7 @@ fn for_expr_to_expression(
                         kind: ExpressionKind::CodeBlock(CodeBlock {
                             contents: vec![AstNode {
                                 content: AstNodeContent::Expression(Expression {
-                                    kind: ExpressionKind::Break,
+                                    kind: ExpressionKind::Break(None),
                                     span: Span::dummy(),
                                 }),
                                 span: Span::dummy(),
@@ -4085,6 +4595,181 @@ fn statement_let_to_ast_nodes_unfold(
             }
             ast_nodes
         }
+        Pattern::Slice(pat_slice) => {
+            let mut ast_nodes = Vec::new();
+
+            // Generate a deterministic name for the destructured array.
+            let suffix = context.next_destructured_slice_unique_suffix();
+            let destructured_slice_name = Ident::new_with_override(
+                generate_destructured_slice_var_name(suffix),
+                pat_slice.span(),
+            );
+
+            // Parse the type ascription and the type ascription span.
+            // In the event that the user did not provide a type ascription,
+            // it is set to TypeInfo::Unknown and the span to None.
+            let type_ascription = match &ty_opt {
+                Some(ty) => ty_to_type_argument(context, handler, engines, ty.clone())?,
+                None => {
+                    let type_id = engines.te().insert(engines, TypeInfo::Unknown, None);
+                    TypeArgument {
+                        type_id,
+                        initial_type_id: type_id,
+                        span: destructured_slice_name.span(),
+                        call_path_tree: None,
+                    }
+                }
+            };
+
+            // Save the array to the new name as a new variable declaration.
+            let save_body_first = engines.pe().insert(VariableDeclaration {
+                name: destructured_slice_name.clone(),
+                type_ascription,
+                body: expression,
+                is_mutable: false,
+            });
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    save_body_first,
+                )),
+                span: span.clone(),
+            });
+
+            // The positions of the elements, and the length of the rest, depend on the length
+            // of the array which is not known until type checking. So, we match the array
+            // against the slice pattern in which every element pattern is replaced by a variable,
+            // and collect those variables into a tuple. The arity of the pattern is then checked
+            // against the length of the array when type checking the match expression.
+            let pat_slice_span = pat_slice.span();
+            let mut prefix = vec![];
+            let mut rest = None;
+            let mut suffix_elems = vec![];
+            let mut recursive_patterns = vec![];
+            for (index, element) in pat_slice.into_inner().into_iter().enumerate() {
+                let element_span = element.span();
+                let element_name = Ident::new_with_override(
+                    generate_destructured_slice_element_var_name(suffix, index),
+                    element_span.clone(),
+                );
+                let element_scrutinee = Scrutinee::Variable {
+                    name: element_name.clone(),
+                    span: element_span,
+                };
+                let recursive_pattern = match element {
+                    PatternSliceElement::Rest {
+                        name_opt: None,
+                        token,
+                    } => {
+                        rest = Some(Box::new(Scrutinee::CatchAll { span: token.span() }));
+                        continue;
+                    }
+                    PatternSliceElement::Rest {
+                        name_opt: Some((name, _at_token)),
+                        ..
+                    } => {
+                        rest = Some(Box::new(element_scrutinee));
+                        Pattern::Var {
+                            reference: None,
+                            mutable: None,
+                            name,
+                        }
+                    }
+                    PatternSliceElement::Pattern(pattern) => {
+                        match rest {
+                            Some(_) => suffix_elems.push(element_scrutinee),
+                            None => prefix.push(element_scrutinee),
+                        }
+                        *pattern
+                    }
+                };
+                recursive_patterns.push((element_name, recursive_pattern));
+            }
+
+            let match_expression = Expression {
+                kind: ExpressionKind::Match(MatchExpression {
+                    value: Box::new(Expression {
+                        kind: ExpressionKind::Variable(destructured_slice_name),
+                        span: span.clone(),
+                    }),
+                    branches: vec![MatchBranch {
+                        scrutinee: Scrutinee::Slice {
+                            prefix,
+                            rest,
+                            suffix: suffix_elems,
+                            span: pat_slice_span.clone(),
+                        },
+                        result: Expression {
+                            kind: ExpressionKind::Tuple(
+                                recursive_patterns
+                                    .iter()
+                                    .map(|(element_name, _)| Expression {
+                                        kind: ExpressionKind::Variable(element_name.clone()),
+                                        span: element_name.span(),
+                                    })
+                                    .collect(),
+                            ),
+                            span: span.clone(),
+                        },
+                        span: span.clone(),
+                    }],
+                }),
+                span: span.clone(),
+            };
+
+            // Save the elements matched by the slice pattern to a tuple.
+            let tuple_name = Ident::new_with_override(
+                generate_tuple_var_name(context.next_destructured_tuple_unique_suffix()),
+                pat_slice_span,
+            );
+            let save_elements = engines.pe().insert(VariableDeclaration {
+                name: tuple_name.clone(),
+                type_ascription: {
+                    let type_id = engines.te().insert(engines, TypeInfo::Unknown, None);
+                    TypeArgument {
+                        type_id,
+                        initial_type_id: type_id,
+                        span: tuple_name.span(),
+                        call_path_tree: None,
+                    }
+                },
+                body: match_expression,
+                is_mutable: false,
+            });
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    save_elements,
+                )),
+                span: span.clone(),
+            });
+
+            // create a variable expression that points to the new tuple name that we just created
+            let new_expr = Expression {
+                kind: ExpressionKind::Variable(tuple_name),
+                span: span.clone(),
+            };
+
+            // for all of the elements in the slice destructuring on the LHS,
+            // recursively create variable declarations
+            for (index, (_, pattern)) in recursive_patterns.into_iter().enumerate() {
+                ast_nodes.extend(statement_let_to_ast_nodes_unfold(
+                    context,
+                    handler,
+                    engines,
+                    pattern,
+                    None,
+                    Expression {
+                        kind: ExpressionKind::TupleIndex(TupleIndexExpression {
+                            prefix: Box::new(new_expr.clone()),
+                            index,
+                            index_span: span.clone(),
+                        }),
+                        span: span.clone(),
+                    },
+                    span.clone(),
+                )?);
+            }
+            ast_nodes
+        }
     };
     Ok(ast_nodes)
 }
@@ -4300,6 +4985,37 @@ fn pattern_to_scrutinee(
             },
             span,
         },
+        Pattern::Slice(pat_slice) => {
+            let mut prefix = vec![];
+            let mut rest = None;
+            let mut suffix = vec![];
+            for element in pat_slice.into_inner() {
+                match element {
+                    PatternSliceElement::Rest { name_opt, token } => {
+                        rest = Some(Box::new(match name_opt {
+                            Some((name, _at_token)) => Scrutinee::Variable {
+                                span: Span::join(name.span(), &token.span()),
+                                name,
+                            },
+                            None => Scrutinee::CatchAll { span: token.span() },
+                        }));
+                    }
+                    PatternSliceElement::Pattern(pattern) => {
+                        let scrutinee = pattern_to_scrutinee(context, handler, *pattern)?;
+                        match rest {
+                            Some(_) => suffix.push(scrutinee),
+                            None => prefix.push(scrutinee),
+                        }
+                    }
+                }
+            }
+            Scrutinee::Slice {
+                prefix,
+                rest,
+                suffix,
+                span,
+            }
+        }
         Pattern::Error(spans, err) => Scrutinee::Error { spans, err },
     };
     Ok(scrutinee)
@@ -4750,18 +5466,7 @@ fn item_attrs_to_map(
             let args = attr
                 .args
                 .as_ref()
-                .map(|parens| {
-                    parens
-                        .get()
-                        .into_iter()
-                        .cloned()
-                        .map(|arg| AttributeArg {
-                            name: arg.name.clone(),
-                            value: arg.value.clone(),
-                            span: arg.span(),
-                        })
-                        .collect()
-                })
+                .map(|parens| parens.get().into_iter().map(AttributeArg::from).collect())
                 .unwrap_or_else(Vec::new);
 
             let attribute = Attribute {
@@ -4776,10 +5481,12 @@ fn item_attrs_to_map(
                 STORAGE_PURITY_ATTRIBUTE_NAME => Some(AttributeKind::Storage),
                 INLINE_ATTRIBUTE_NAME => Some(AttributeKind::Inline),
                 TEST_ATTRIBUTE_NAME => Some(AttributeKind::Test),
+                BENCH_ATTRIBUTE_NAME => Some(AttributeKind::Bench),
                 PAYABLE_ATTRIBUTE_NAME => Some(AttributeKind::Payable),
                 ALLOW_ATTRIBUTE_NAME => Some(AttributeKind::Allow),
                 CFG_ATTRIBUTE_NAME => Some(AttributeKind::Cfg),
                 DEPRECATED_ATTRIBUTE_NAME => Some(AttributeKind::Deprecated),
+                DERIVE_ATTRIBUTE_NAME => Some(AttributeKind::Derive),
                 FALLBACK_ATTRIBUTE_NAME => Some(AttributeKind::Fallback),
                 _ => None,
             } {
@@ -4867,92 +5574,148 @@ pub fn cfg_eval(
     if let Some(cfg_attrs) = attrs_map.get(&AttributeKind::Cfg) {
         for cfg_attr in cfg_attrs {
             for arg in &cfg_attr.args {
-                match arg.name.as_str() {
-                    CFG_TARGET_ARG_NAME => {
-                        if let Some(value) = &arg.value {
-                            if let sway_ast::Literal::String(value_str) = value {
-                                if let Ok(target) = BuildTarget::from_str(value_str.parsed.as_str())
-                                {
-                                    if target != context.build_target() {
-                                        return Ok(false);
-                                    }
-                                } else {
-                                    let error = ConvertParseTreeError::InvalidCfgTargetArgValue {
-                                        span: value.span(),
-                                        value: value.span().str(),
-                                    };
-                                    return Err(handler.emit_err(error.into()));
-                                }
-                            } else {
-                                let error = ConvertParseTreeError::InvalidCfgTargetArgValue {
-                                    span: value.span(),
-                                    value: value.span().str(),
-                                };
-                                return Err(handler.emit_err(error.into()));
-                            }
-                        } else {
-                            let error = ConvertParseTreeError::ExpectedCfgTargetArgValue {
-                                span: arg.span(),
-                            };
-                            return Err(handler.emit_err(error.into()));
-                        }
+                if !cfg_predicate_eval(context, handler, arg, experimental)? {
+                    return Ok(false);
+                }
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Evaluates a single cfg predicate, which is either an option, like `target = "fuel"` or
+/// `test`, or one of `all`, `any` and `not` applied to nested predicates.
+fn cfg_predicate_eval(
+    context: &Context,
+    handler: &Handler,
+    arg: &AttributeArg,
+    experimental: ExperimentalFlags,
+) -> Result<bool, ErrorEmitted> {
+    let name = arg.name.as_str();
+    let is_combinator = matches!(name, CFG_ALL_ARG_NAME | CFG_ANY_ARG_NAME | CFG_NOT_ARG_NAME);
+    if is_combinator && arg.value.is_some() {
+        let error = ConvertParseTreeError::UnexpectedCfgArgValue {
+            span: arg.span(),
+            name: name.to_string(),
+        };
+        return Err(handler.emit_err(error.into()));
+    }
+    if !is_combinator && !arg.args.is_empty() {
+        let error = ConvertParseTreeError::UnexpectedCfgNestedArgs {
+            span: arg.span(),
+            name: name.to_string(),
+        };
+        return Err(handler.emit_err(error.into()));
+    }
+
+    match name {
+        CFG_ALL_ARG_NAME | CFG_ANY_ARG_NAME | CFG_NOT_ARG_NAME => {
+            // Evaluate all of the nested predicates, so that invalid ones are always reported.
+            let values = arg
+                .args
+                .iter()
+                .map(|arg| cfg_predicate_eval(context, handler, arg, experimental))
+                .collect::<Result<Vec<_>, _>>()?;
+            match name {
+                CFG_ALL_ARG_NAME => Ok(values.iter().all(|value| *value)),
+                CFG_ANY_ARG_NAME => Ok(values.iter().any(|value| *value)),
+                _ => match values[..] {
+                    [value] => Ok(!value),
+                    _ => {
+                        let error = ConvertParseTreeError::ExpectedSingleCfgNotArg {
+                            span: arg.span(),
+                            count: values.len(),
+                        };
+                        Err(handler.emit_err(error.into()))
                     }
-                    CFG_PROGRAM_TYPE_ARG_NAME => {
-                        if let Some(value) = &arg.value {
-                            if let sway_ast::Literal::String(value_str) = value {
-                                if let Ok(program_type) =
-                                    TreeType::from_str(value_str.parsed.as_str())
-                                {
-                                    if program_type != context.program_type().unwrap() {
-                                        return Ok(false);
-                                    }
-                                } else {
-                                    let error =
-                                        ConvertParseTreeError::InvalidCfgProgramTypeArgValue {
-                                            span: value.span(),
-                                            value: value.span().str(),
-                                        };
-                                    return Err(handler.emit_err(error.into()));
-                                }
-                            } else {
-                                let error = ConvertParseTreeError::InvalidCfgProgramTypeArgValue {
-                                    span: value.span(),
-                                    value: value.span().str(),
-                                };
-                                return Err(handler.emit_err(error.into()));
-                            }
-                        } else {
-                            let error = ConvertParseTreeError::ExpectedCfgTargetArgValue {
-                                span: arg.span(),
-                            };
-                            return Err(handler.emit_err(error.into()));
-                        }
+                },
+            }
+        }
+        CFG_TEST_ARG_NAME => {
+            if arg.value.is_some() {
+                let error = ConvertParseTreeError::UnexpectedCfgArgValue {
+                    span: arg.span(),
+                    name: name.to_string(),
+                };
+                return Err(handler.emit_err(error.into()));
+            }
+            Ok(context.include_tests())
+        }
+        CFG_FEATURE_ARG_NAME => match &arg.value {
+            Some(sway_ast::Literal::String(value_str)) => {
+                Ok(context.is_feature_enabled(value_str.parsed.as_str()))
+            }
+            _ => {
+                let error = ConvertParseTreeError::ExpectedCfgFeatureArgValue { span: arg.span() };
+                Err(handler.emit_err(error.into()))
+            }
+        },
+        CFG_TARGET_ARG_NAME => {
+            if let Some(value) = &arg.value {
+                if let sway_ast::Literal::String(value_str) = value {
+                    if let Ok(target) = BuildTarget::from_str(value_str.parsed.as_str()) {
+                        Ok(target == context.build_target())
+                    } else {
+                        let error = ConvertParseTreeError::InvalidCfgTargetArgValue {
+                            span: value.span(),
+                            value: value.span().str(),
+                        };
+                        Err(handler.emit_err(error.into()))
                     }
-                    CFG_EXPERIMENTAL_NEW_ENCODING => match &arg.value {
-                        Some(sway_ast::Literal::Bool(v)) => {
-                            let is_true = matches!(v.kind, sway_ast::literal::LitBoolType::True);
-                            return Ok(experimental.new_encoding == is_true);
-                        }
-                        _ => {
-                            let error =
-                                ConvertParseTreeError::ExpectedExperimentalNewEncodingArgValue {
-                                    span: arg.span(),
-                                };
-                            return Err(handler.emit_err(error.into()));
-                        }
-                    },
-                    _ => {
-                        return Err(handler.emit_err(
-                            ConvertParseTreeError::InvalidCfgArg {
-                                span: arg.span(),
-                                value: arg.name.as_str().to_string(),
-                            }
-                            .into(),
-                        ));
+                } else {
+                    let error = ConvertParseTreeError::InvalidCfgTargetArgValue {
+                        span: value.span(),
+                        value: value.span().str(),
+                    };
+                    Err(handler.emit_err(error.into()))
+                }
+            } else {
+                let error = ConvertParseTreeError::ExpectedCfgTargetArgValue { span: arg.span() };
+                Err(handler.emit_err(error.into()))
+            }
+        }
+        CFG_PROGRAM_TYPE_ARG_NAME => {
+            if let Some(value) = &arg.value {
+                if let sway_ast::Literal::String(value_str) = value {
+                    if let Ok(program_type) = TreeType::from_str(value_str.parsed.as_str()) {
+                        Ok(program_type == context.program_type().unwrap())
+                    } else {
+                        let error = ConvertParseTreeError::InvalidCfgProgramTypeArgValue {
+                            span: value.span(),
+                            value: value.span().str(),
+                        };
+                        Err(handler.emit_err(error.into()))
                     }
+                } else {
+                    let error = ConvertParseTreeError::InvalidCfgProgramTypeArgValue {
+                        span: value.span(),
+                        value: value.span().str(),
+                    };
+                    Err(handler.emit_err(error.into()))
                 }
+            } else {
+                let error = ConvertParseTreeError::ExpectedCfgTargetArgValue { span: arg.span() };
+                Err(handler.emit_err(error.into()))
             }
         }
+        CFG_EXPERIMENTAL_NEW_ENCODING => match &arg.value {
+            Some(sway_ast::Literal::Bool(v)) => {
+                let is_true = matches!(v.kind, sway_ast::literal::LitBoolType::True);
+                Ok(experimental.new_encoding == is_true)
+            }
+            _ => {
+                let error = ConvertParseTreeError::ExpectedExperimentalNewEncodingArgValue {
+                    span: arg.span(),
+                };
+                Err(handler.emit_err(error.into()))
+            }
+        },
+        _ => Err(handler.emit_err(
+            ConvertParseTreeError::InvalidCfgArg {
+                span: arg.span(),
+                value: name.to_string(),
+            }
+            .into(),
+        )),
     }
-    Ok(true)
 }
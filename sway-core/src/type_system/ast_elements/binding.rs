@@ -218,7 +218,9 @@ impl TypeBinding<CallPath<(TypeInfo, Ident)>> {
         let type_info_span = type_ident.span();
 
         // find the module that the symbol is in
-        let type_info_prefix = ctx.namespace().prepend_module_path(&self.inner.prefixes);
+        let type_info_prefix = ctx
+            .namespace()
+            .resolve_module_path(engines, &self.inner.prefixes);
         ctx.namespace()
             .lookup_submodule_from_absolute_path(handler, engines, &type_info_prefix)?;
 
@@ -1283,8 +1283,9 @@ impl TypeInfo {
         engines: &Engines,
         span: &Span,
     ) -> Result<(), ErrorEmitted> {
-        const CURRENTLY_SUPPORTED_TYPES_MESSAGE: [&str; 9] = [
+        const CURRENTLY_SUPPORTED_TYPES_MESSAGE: [&str; 10] = [
             "Sway currently supports pattern matching on these types:",
+            "  - arrays",
             "  - b256",
             "  - boolean",
             "  - enums",
@@ -1301,6 +1302,7 @@ impl TypeInfo {
             | TypeInfo::Struct { .. }
             | TypeInfo::Boolean
             | TypeInfo::Tuple(_)
+            | TypeInfo::Array(_, _)
             | TypeInfo::B256
             | TypeInfo::UnknownGeneric { .. }
             | TypeInfo::Numeric
@@ -1314,8 +1316,7 @@ impl TypeInfo {
             | TypeInfo::RawUntypedSlice
             | TypeInfo::Ptr(..)
             | TypeInfo::Slice(..)
-            | TypeInfo::StringArray(_)
-            | TypeInfo::Array(_, _) => Err(handler.emit_err(CompileError::Unimplemented {
+            | TypeInfo::StringArray(_) => Err(handler.emit_err(CompileError::Unimplemented {
                 feature: format!(
                     "Matched value has type \"{}\". Matching on this type",
                     engines.help_out(self)
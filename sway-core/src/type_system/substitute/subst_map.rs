@@ -500,7 +500,24 @@ impl TypeSubstMap {
                 ty.type_id = type_id;
                 type_engine.insert(engines, TypeInfo::Ptr(ty.clone()), ty.span.source_id())
             }),
-            TypeInfo::TraitType { .. } => iter_for_match(engines, self, &type_info),
+            TypeInfo::TraitType {
+                name,
+                trait_type_id,
+            } => iter_for_match(engines, self, &type_info).or_else(|| {
+                // The associated type of a substituted type, e.g. `T::Item` becoming `u64::Item`,
+                // is resolved once the type it belongs to is known.
+                self.find_match(trait_type_id, engines)
+                    .map(|trait_type_id| {
+                        type_engine.insert(
+                            engines,
+                            TypeInfo::TraitType {
+                                name: name.clone(),
+                                trait_type_id,
+                            },
+                            name.span().source_id(),
+                        )
+                    })
+            }),
             TypeInfo::Ref {
                 to_mutable_value,
                 referenced_type: mut ty,
@@ -25,6 +25,10 @@ pub enum ConvertParseTreeError {
     IntLiteralOutOfRange { span: Span },
     #[error("expected an integer literal")]
     IntLiteralExpected { span: Span },
+    #[error("the value of `{name}` is not known here; expected an integer literal or a constant with an integer value declared earlier in scope")]
+    ConstantValueNotKnown { name: Ident, span: Span },
+    #[error("the layout of this type is not known here; expected a built-in type, or a tuple or an array of built-in types")]
+    TypeLayoutNotKnown { span: Span },
     #[error("qualified path roots are not implemented")]
     QualifiedPathRootsNotImplemented { span: Span },
     #[error("char literals are not implemented")]
@@ -59,6 +63,8 @@ pub enum ConvertParseTreeError {
     OrPatternsNotSupportedHere { span: Span },
     #[error("tuple patterns not supported in this position")]
     TuplePatternsNotSupportedHere { span: Span },
+    #[error("slice patterns not supported in this position")]
+    SlicePatternsNotSupportedHere { span: Span },
     #[error("ref patterns not supported in this position")]
     RefPatternsNotSupportedHere { span: Span },
     #[error("constructor patterns require a single argument")]
@@ -121,6 +127,16 @@ pub enum ConvertParseTreeError {
     ExpectedExperimentalNewEncodingArgValue { span: Span },
     #[error("Unexpected attribute value: \"{value}\" for attribute: \"cfg\"")]
     InvalidCfgArg { span: Span, value: String },
+    #[error("Expected a string value for the feature argument")]
+    ExpectedCfgFeatureArgValue { span: Span },
+    #[error("The \"{name}\" argument of the \"cfg\" attribute does not take a value")]
+    UnexpectedCfgArgValue { span: Span, name: String },
+    #[error("The \"{name}\" argument of the \"cfg\" attribute does not take nested arguments")]
+    UnexpectedCfgNestedArgs { span: Span, name: String },
+    #[error("Expected exactly one nested argument for \"not\", found {count}")]
+    ExpectedSingleCfgNotArg { span: Span, count: usize },
+    #[error("Enum variants cannot have default values.")]
+    EnumVariantDefaultValue { span: Span },
 }
 
 impl Spanned for ConvertParseTreeError {
@@ -137,6 +153,8 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::IntTySuffixNotSupported { span } => span.clone(),
             ConvertParseTreeError::IntLiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::IntLiteralExpected { span } => span.clone(),
+            ConvertParseTreeError::ConstantValueNotKnown { span, .. } => span.clone(),
+            ConvertParseTreeError::TypeLayoutNotKnown { span } => span.clone(),
             ConvertParseTreeError::QualifiedPathRootsNotImplemented { span } => span.clone(),
             ConvertParseTreeError::CharLiteralsNotImplemented { span } => span.clone(),
             ConvertParseTreeError::HexLiteralLength { span } => span.clone(),
@@ -154,6 +172,7 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::WildcardPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::OrPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::TuplePatternsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::SlicePatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::RefPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternOneArg { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternSubPatterns { span } => span.clone(),
@@ -185,6 +204,11 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::ExpectedCfgProgramTypeArgValue { span } => span.clone(),
             ConvertParseTreeError::ExpectedExperimentalNewEncodingArgValue { span } => span.clone(),
             ConvertParseTreeError::InvalidCfgArg { span, .. } => span.clone(),
+            ConvertParseTreeError::ExpectedCfgFeatureArgValue { span } => span.clone(),
+            ConvertParseTreeError::UnexpectedCfgArgValue { span, .. } => span.clone(),
+            ConvertParseTreeError::UnexpectedCfgNestedArgs { span, .. } => span.clone(),
+            ConvertParseTreeError::ExpectedSingleCfgNotArg { span, .. } => span.clone(),
+            ConvertParseTreeError::EnumVariantDefaultValue { span } => span.clone(),
         }
     }
 }
@@ -61,7 +61,11 @@ pub enum CompileError {
     ModuleDepGraphCyclicReference { modules: Vec<BaseIdent> },
 
     #[error("Variable \"{var_name}\" does not exist in this scope.")]
-    UnknownVariable { var_name: Ident, span: Span },
+    UnknownVariable {
+        var_name: Ident,
+        span: Span,
+        names_in_scope: NamesInScope,
+    },
     #[error("Identifier \"{name}\" was used as a variable, but it is actually a {what_it_is}.")]
     NotAVariable {
         name: Ident,
@@ -192,6 +196,16 @@ pub enum CompileError {
         variable_name: Ident,
         span: Span,
     },
+    #[error(
+        "Cannot call method \"{method_name}\" through a reference of type \"{reference_type}\" \
+            because the method requires mutable self and the reference is not a reference \
+            to a mutable value (`&mut`)."
+    )]
+    MethodRequiresMutableSelfViaNonMutableReference {
+        method_name: Ident,
+        reference_type: String,
+        span: Span,
+    },
     #[error(
         "This parameter was declared as mutable, which is not supported yet, did you mean to use ref mut?"
     )]
@@ -428,7 +442,11 @@ pub enum CompileError {
     #[error("This is a {actually}, not a type alias")]
     DeclIsNotATypeAlias { actually: String, span: Span },
     #[error("Could not find symbol \"{name}\" in this scope.")]
-    SymbolNotFound { name: Ident, span: Span },
+    SymbolNotFound {
+        name: Ident,
+        span: Span,
+        names_in_scope: NamesInScope,
+    },
     #[error("Found multiple bindings for \"{name}\" in this scope.")]
     SymbolWithMultipleBindings {
         name: Ident,
@@ -437,6 +455,18 @@ pub enum CompileError {
     },
     #[error("Symbol \"{name}\" is private.")]
     ImportPrivateSymbol { name: Ident, span: Span },
+    #[error(
+        "Symbol \"{name}\" is only visible with `{visibility}` and cannot be accessed from here."
+    )]
+    ImportRestrictedSymbol {
+        name: Ident,
+        visibility: String,
+        span: Span,
+    },
+    #[error(
+        "Module \"{path}\" in `pub(in {path})` is not the module the item is declared in or one of its ancestors."
+    )]
+    InvalidVisibilityRestrictionPath { path: String, span: Span },
     #[error("Module \"{name}\" is private.")]
     ImportPrivateModule { name: Ident, span: Span },
     #[error(
@@ -502,6 +532,17 @@ pub enum CompileError {
         trait_name: String,
         span: Span,
     },
+    #[error(
+        "Cannot derive \"{trait_name}\" for \"{type_name}\" because the type of {member_kind} \"{member_name}\", \"{member_type}\", does not implement \"{trait_name}\"."
+    )]
+    DeriveMemberDoesNotImplementTrait {
+        trait_name: String,
+        type_name: String,
+        member_kind: &'static str,
+        member_name: String,
+        member_type: String,
+        span: Span,
+    },
     #[error(
         "Expects trait constraint \"{param}: {trait_name}\" which is missing from type parameter \"{param}\"."
     )]
@@ -535,7 +576,11 @@ pub enum CompileError {
     #[error("Unknown type name.")]
     UnknownType { span: Span },
     #[error("Unknown type name \"{name}\".")]
-    UnknownTypeName { name: String, span: Span },
+    UnknownTypeName {
+        name: String,
+        span: Span,
+        names_in_scope: NamesInScope,
+    },
     #[error("The file {file_path} could not be read: {stringified_error}")]
     FileCouldNotBeRead {
         span: Span,
@@ -659,6 +704,8 @@ pub enum CompileError {
     InvalidOpcodeFromPredicate { opcode: String, span: Span },
     #[error("Index out of bounds; the length is {count} but the index is {index}.")]
     ArrayOutOfBounds { index: u64, count: u64, span: Span },
+    #[error("Attempt to {op} with a divisor of zero.")]
+    DivisionByZero { op: &'static str, span: Span },
     #[error(
         "Invalid range; the range end at index {end} is smaller than its start at index {start}"
     )]
@@ -731,6 +778,19 @@ pub enum CompileError {
         missing_patterns: String,
         span: Span,
     },
+    #[error("This slice pattern cannot match a value of type \"{actually}\". Slice patterns can only match arrays.")]
+    MatchSlicePatternOnNonArray { actually: String, span: Span },
+    #[error("This slice pattern matches {}{pattern_length} element{}, but the matched array has {array_length} element{}.",
+        if *has_rest { "at least " } else { "" },
+        plural_s(*pattern_length),
+        plural_s(*array_length),
+    )]
+    MatchSlicePatternLengthMismatch {
+        pattern_length: usize,
+        array_length: usize,
+        has_rest: bool,
+        span: Span,
+    },
     #[error("Struct pattern is missing the {}field{} {}.",
         if *missing_fields_are_public { "public " } else { "" },
         plural_s(missing_fields.len()),
@@ -879,6 +939,14 @@ pub enum CompileError {
     Parse { error: ParseError },
     #[error("Could not evaluate initializer to a const declaration.")]
     NonConstantDeclValue { span: Span },
+    #[error("Constant evaluation exceeded the limit of {limit} steps.")]
+    ConstEvalLimitExceeded { limit: usize, span: Span },
+    #[error("Constant evaluation exceeded the limit of {limit} nested function calls.")]
+    ConstEvalCallDepthExceeded { limit: usize, span: Span },
+    #[error(
+        "The default value of the struct field \"{field_name}\" is not a constant expression."
+    )]
+    NonConstantStructFieldDefaultValue { field_name: Ident, span: Span },
     #[error("Declaring storage in a {program_kind} is not allowed.")]
     StorageDeclarationInNonContract { program_kind: String, span: Span },
     #[error("Unsupported argument type to intrinsic \"{name}\".{}", if hint.is_empty() { "".to_string() } else { format!(" Hint: {hint}") })]
@@ -901,10 +969,27 @@ pub enum CompileError {
     },
     #[error("Expected string literal")]
     ExpectedStringLiteral { span: Span },
+    #[error(
+        "The format string has {placeholders} placeholder{}, but {arguments} argument{} {} provided.",
+        plural_s(*placeholders),
+        plural_s(*arguments),
+        singular_plural(*arguments, "was", "were")
+    )]
+    FormatArgumentCountMismatch {
+        placeholders: usize,
+        arguments: usize,
+        span: Span,
+    },
     #[error("\"break\" used outside of a loop")]
     BreakOutsideLoop { span: Span },
     #[error("\"continue\" used outside of a loop")]
     ContinueOutsideLoop { span: Span },
+    #[error("\"break\" with a value can only be used inside of a \"loop\"")]
+    BreakWithValueOutsideLoop { span: Span },
+    #[error(
+        "The `?` operator can only be used in a function that returns `Option` or `Result`, but this function returns \"{return_type}\"."
+    )]
+    TryOperatorInvalidReturnType { return_type: String, span: Span },
     /// This will be removed once loading contract IDs in a dependency namespace is refactored and no longer manual:
     /// https://github.com/FuelLabs/sway/issues/3077
     #[error("Contract ID is not a constant item.")]
@@ -1059,6 +1144,7 @@ impl Spanned for CompileError {
             RefMutCannotReferenceConstant { span, .. } => span.clone(),
             RefMutCannotReferenceImmutableVariable { span, .. } => span.clone(),
             MethodRequiresMutableSelf { span, .. } => span.clone(),
+            MethodRequiresMutableSelfViaNonMutableReference { span, .. } => span.clone(),
             AssociatedFunctionCalledAsMethod { span, .. } => span.clone(),
             TypeParameterNotInTypeScope { span, .. } => span.clone(),
             MismatchedTypeInInterfaceSurface { span, .. } => span.clone(),
@@ -1089,6 +1175,8 @@ impl Spanned for CompileError {
             SymbolNotFound { span, .. } => span.clone(),
             SymbolWithMultipleBindings { span, .. } => span.clone(),
             ImportPrivateSymbol { span, .. } => span.clone(),
+            ImportRestrictedSymbol { span, .. } => span.clone(),
+            InvalidVisibilityRestrictionPath { span, .. } => span.clone(),
             ImportPrivateModule { span, .. } => span.clone(),
             NoElseBranch { span, .. } => span.clone(),
             NotAType { span, .. } => span.clone(),
@@ -1104,6 +1192,7 @@ impl Spanned for CompileError {
             UnconstrainedGenericParameter { span, .. } => span.clone(),
             TraitConstraintNotSatisfied { span, .. } => span.clone(),
             TraitConstraintMissing { span, .. } => span.clone(),
+            DeriveMemberDoesNotImplementTrait { span, .. } => span.clone(),
             Immediate06TooLarge { span, .. } => span.clone(),
             Immediate12TooLarge { span, .. } => span.clone(),
             Immediate18TooLarge { span, .. } => span.clone(),
@@ -1147,6 +1236,7 @@ impl Spanned for CompileError {
             ContractStorageFromExternalContext { span, .. } => span.clone(),
             InvalidOpcodeFromPredicate { span, .. } => span.clone(),
             ArrayOutOfBounds { span, .. } => span.clone(),
+            DivisionByZero { span, .. } => span.clone(),
             ConstantsCannotBeShadowed { name, .. } => name.span(),
             ConfigurablesCannotBeShadowed { name, .. } => name.span(),
             ConfigurablesCannotBeMatchedAgainst { name, .. } => name.span(),
@@ -1155,6 +1245,8 @@ impl Spanned for CompileError {
             ShadowsOtherSymbol { name } => name.span(),
             GenericShadowsGeneric { name } => name.span(),
             MatchExpressionNonExhaustive { span, .. } => span.clone(),
+            MatchSlicePatternOnNonArray { span, .. } => span.clone(),
+            MatchSlicePatternLengthMismatch { span, .. } => span.clone(),
             MatchStructPatternMissingFields { span, .. } => span.clone(),
             MatchStructPatternMustIgnorePrivateFields { span, .. } => span.clone(),
             MatchArmVariableNotDefinedInAllAlternatives { variable, .. } => variable.span(),
@@ -1197,12 +1289,17 @@ impl Spanned for CompileError {
             EnumNotFound { span, .. } => span.clone(),
             TupleIndexOutOfBounds { span, .. } => span.clone(),
             NonConstantDeclValue { span, .. } => span.clone(),
+            ConstEvalLimitExceeded { span, .. } => span.clone(),
+            ConstEvalCallDepthExceeded { span, .. } => span.clone(),
+            NonConstantStructFieldDefaultValue { span, .. } => span.clone(),
             StorageDeclarationInNonContract { span, .. } => span.clone(),
             IntrinsicUnsupportedArgType { span, .. } => span.clone(),
             IntrinsicIncorrectNumArgs { span, .. } => span.clone(),
             IntrinsicIncorrectNumTArgs { span, .. } => span.clone(),
             BreakOutsideLoop { span } => span.clone(),
             ContinueOutsideLoop { span } => span.clone(),
+            BreakWithValueOutsideLoop { span } => span.clone(),
+            TryOperatorInvalidReturnType { span, .. } => span.clone(),
             ContractIdConstantNotAConstDecl { span } => span.clone(),
             ContractIdValueNotALiteral { span } => span.clone(),
             RefMutableNotAllowedInMain { span, .. } => span.clone(),
@@ -1223,6 +1320,7 @@ impl Spanned for CompileError {
             AbiSupertraitMethodCallAsContractCall { span, .. } => span.clone(),
             TypeNotAllowed { span, .. } => span.clone(),
             ExpectedStringLiteral { span } => span.clone(),
+            FormatArgumentCountMismatch { span, .. } => span.clone(),
             TypeIsNotValidAsImplementingFor { span, .. } => span.clone(),
             UninitRegisterInAsmBlockBeingRead { span } => span.clone(),
             ExpressionCannotBeDereferenced { span, .. } => span.clone(),
@@ -2681,6 +2779,21 @@ impl ToDiagnostic for CompileError {
                         },
                 }
             },
+            UnknownVariable { var_name, span, names_in_scope } => Diagnostic {
+                issue: Issue::error(source_engine, span.clone(), format!("{}", self)),
+                hints: did_you_mean_help(source_engine, span, var_name.as_str(), names_in_scope),
+                ..Default::default()
+            },
+            SymbolNotFound { name, span, names_in_scope } => Diagnostic {
+                issue: Issue::error(source_engine, span.clone(), format!("{}", self)),
+                hints: did_you_mean_help(source_engine, span, name.as_str(), names_in_scope),
+                ..Default::default()
+            },
+            UnknownTypeName { name, span, names_in_scope } => Diagnostic {
+                issue: Issue::error(source_engine, span.clone(), format!("{}", self)),
+                hints: did_you_mean_help(source_engine, span, name, names_in_scope),
+                ..Default::default()
+            },
             ConfigurableMissingAbiDecodeInPlace { span } => Diagnostic {
                 reason: Some(Reason::new(code(1), "Configurables need a function named \"abi_decode_in_place\" to be in scope".to_string())),
                 issue: Issue::error(
@@ -2760,6 +2873,26 @@ impl ToDiagnostic for CompileError {
     }
 }
 
+/// Returns a help [Hint] suggesting the name from `names_in_scope` most similar to the unresolved
+/// `name`, or no hints at all if none of the names is similar enough.
+fn did_you_mean_help(
+    source_engine: &SourceEngine,
+    span: &Span,
+    name: &str,
+    names_in_scope: &NamesInScope,
+) -> Vec<Hint> {
+    did_you_mean(name, names_in_scope.0.iter().filter(|n| *n != name), 1)
+        .into_iter()
+        .map(|suggestion| {
+            Hint::help(
+                source_engine,
+                span.clone(),
+                format!("Did you mean \"{suggestion}\"?"),
+            )
+        })
+        .collect()
+}
+
 #[derive(Error, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypeNotAllowedReason {
     #[error(
@@ -2888,3 +3021,23 @@ impl fmt::Display for StorageAccess {
         }
     }
 }
+
+/// Names of the symbols in scope at the place of an unresolved name, used for
+/// suggesting the names most similar to the unresolved one.
+///
+/// The names do not take part in comparing and hashing errors, so that otherwise
+/// equal errors emitted from different scopes are still deduplicated.
+#[derive(Debug, Clone, Default)]
+pub struct NamesInScope(pub Vec<String>);
+
+impl PartialEq for NamesInScope {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NamesInScope {}
+
+impl std::hash::Hash for NamesInScope {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
@@ -44,6 +44,8 @@ pub enum ParseErrorKind {
     ExpectedFieldName,
     #[error("Expected a comma or closing parenthesis in this tuple or parenthesized expression.")]
     ExpectedCommaOrCloseParenInTupleOrParenExpression,
+    #[error("Expected a closing parenthesis after the type ascription.")]
+    ExpectedCloseParenAfterTypeAscription,
     #[error("Expected an expression.")]
     ExpectedExpression,
     #[error("Unexpected token after array length.")]
@@ -80,6 +82,8 @@ pub enum ParseErrorKind {
     ExpectedKeyword { word: &'static str },
     #[error("Unexpected token after abi address.")]
     UnexpectedTokenAfterAbiAddress,
+    #[error("Unexpected token after `matches!` pattern.")]
+    UnexpectedTokenAfterMatchesPattern,
     #[error("Expected an attribute.")]
     ExpectedAnAttribute,
     #[error("Unexpected token after an attribute.")]
@@ -88,10 +92,18 @@ pub enum ParseErrorKind {
     InvalidDoubleUnderscore,
     #[error("Unexpected rest token, must be at the end of pattern.")]
     UnexpectedRestPattern,
+    #[error("Slice patterns can contain at most one rest pattern `..`.")]
+    MultipleRestPatternsInSlice,
     #[error("Identifiers cannot be a reserved keyword.")]
     ReservedKeywordIdentifier,
     #[error("Unnecessary visibility qualifier, `{}` is implied here.", visibility)]
     UnnecessaryVisibilityQualifier { visibility: Ident },
+    #[error("Expected `crate` or `in` followed by a module path in the visibility restriction.")]
+    ExpectedVisibilityRestriction,
+    #[error("Expected a module path after `in` in the visibility restriction.")]
+    ExpectedVisibilityRestrictionPath,
+    #[error("Visibility restrictions are not supported on {item_kind} items, only `pub` is.")]
+    UnsupportedVisibilityRestriction { item_kind: &'static str },
     #[error("Expected a doc comment.")]
     ExpectedDocComment,
     #[error("Top of file doc comments are reserved for module level documentation.\nTry using the `//!` syntax.")]
@@ -59,6 +59,8 @@ pub enum Warning {
     UnusedReturnValue {
         r#type: String,
     },
+    StatementHasNoEffect,
+    SelfAssignment,
     SimilarMethodFound {
         lib: Ident,
         module: Ident,
@@ -68,6 +70,11 @@ pub enum Warning {
         name: Ident,
     },
     AsmBlockIsEmpty,
+    ControlFlowBlockIsEmpty {
+        /// Text "if", "else", "while", "loop" or "for".
+        /// Denotes the control flow construct whose block is empty.
+        keyword: &'static str,
+    },
     UninitializedAsmRegShadowsItem {
         /// Text "Constant" or "Configurable" or "Variable".
         /// Denotes the type of the `item` that shadows the uninitialized ASM register.
@@ -132,6 +139,19 @@ pub enum Warning {
         field1: String,
         field2: String,
     },
+    RedundantClone {
+        ty: String,
+    },
+    TooManyFunctionParameters {
+        fn_name: Ident,
+        parameters: usize,
+        max_parameters: usize,
+    },
+    CyclomaticComplexityTooHigh {
+        fn_name: Ident,
+        complexity: usize,
+        max_complexity: usize,
+    },
 }
 
 impl fmt::Display for Warning {
@@ -206,6 +226,11 @@ impl fmt::Display for Warning {
                 "This returns a value of type {type}, which is not assigned to anything and is \
                  ignored."
             ),
+            StatementHasNoEffect => write!(
+                f,
+                "This statement has no effect. Its value is discarded and computing it has no side effects."
+            ),
+            SelfAssignment => write!(f, "This assigns a variable to itself, which has no effect."),
             SimilarMethodFound { lib, module, name } => write!(
                 f,
                 "A method with the same name was found for type {name} in dependency \"{lib}::{module}\". \
@@ -219,6 +244,7 @@ impl fmt::Display for Warning {
                 f,
                 "This ASM block is empty."
             ),
+            ControlFlowBlockIsEmpty { keyword } => write!(f, "This `{keyword}` block is empty."),
             UninitializedAsmRegShadowsItem { constant_or_configurable_or_variable, item } => write!(
                 f,
                 "This uninitialized register is shadowing a {}. You probably meant to also initialize it, like \"{item}: {item}\".",
@@ -280,6 +306,9 @@ impl fmt::Display for Warning {
                                             More details are available in the related RFC: https://github.com/FuelLabs/sway-rfcs/blob/master/rfcs/0008-private-modules.md"),
             UsingDeprecated { message } => write!(f, "{}", message),
             DuplicatedStorageKey { key, field1, field2 } => write!(f, "Two storage fields are using the same storage key.\nFirst field: {field1}\nSecond field: {field2}\nKey: {key}"),
+            RedundantClone { ty } => write!(f, "Redundant clone of a value of type \"{ty}\" which is already implicitly copied."),
+            TooManyFunctionParameters { fn_name, parameters, max_parameters } => write!(f, "Function \"{fn_name}\" has {parameters} parameters, which is more than the maximum of {max_parameters}."),
+            CyclomaticComplexityTooHigh { fn_name, complexity, max_complexity } => write!(f, "Function \"{fn_name}\" has a cyclomatic complexity of {complexity}, which is more than the maximum of {max_complexity}."),
         }
     }
 }
@@ -412,6 +441,58 @@ impl ToDiagnostic for CompileWarning {
                     "Consider adding assembly instructions or a return register to the ASM block, or removing the block altogether.".to_string(),
                 ],
             },
+            ControlFlowBlockIsEmpty { keyword } => Diagnostic {
+                reason: Some(Reason::new(code(1), format!("`{keyword}` block is empty"))),
+                issue: Issue::warning(
+                    source_engine,
+                    self.span(),
+                    format!("This `{keyword}` block is empty."),
+                ),
+                hints: vec![],
+                help: vec![
+                    "Consider adding statements to the block, or removing the block altogether.".to_string(),
+                    "If the block is intentionally empty, add a comment to it explaining why.".to_string(),
+                ],
+            },
+            RedundantClone { ty } => Diagnostic {
+                reason: Some(Reason::new(code(1), "Clone of a copy type is redundant".to_string())),
+                issue: Issue::warning(
+                    source_engine,
+                    self.span(),
+                    format!("This clones a value of type \"{ty}\" which is already implicitly copied."),
+                ),
+                hints: vec![],
+                help: vec![
+                    "Consider removing the `.clone()` call.".to_string(),
+                    format!("To suppress this warning, annotate the enclosing function with `#[allow({})]`.", sway_types::constants::ALLOW_REDUNDANT_CLONE_NAME),
+                ],
+            },
+            TooManyFunctionParameters { fn_name, parameters, max_parameters } => Diagnostic {
+                reason: Some(Reason::new(code(1), "Function has too many parameters".to_string())),
+                issue: Issue::warning(
+                    source_engine,
+                    self.span(),
+                    format!("Function \"{fn_name}\" has {parameters} parameters, which is more than the maximum of {max_parameters}."),
+                ),
+                hints: vec![],
+                help: vec![
+                    "Consider grouping related parameters into a struct.".to_string(),
+                    "The maximum is set by the `max_function_parameters` build profile option.".to_string(),
+                ],
+            },
+            CyclomaticComplexityTooHigh { fn_name, complexity, max_complexity } => Diagnostic {
+                reason: Some(Reason::new(code(1), "Function is too complex".to_string())),
+                issue: Issue::warning(
+                    source_engine,
+                    self.span(),
+                    format!("Function \"{fn_name}\" has a cyclomatic complexity of {complexity}, which is more than the maximum of {max_complexity}."),
+                ),
+                hints: vec![],
+                help: vec![
+                    "Consider splitting the function into smaller functions.".to_string(),
+                    "The maximum is set by the `max_cyclomatic_complexity` build profile option.".to_string(),
+                ],
+            },
            _ => Diagnostic {
                     // TODO: Temporary we use self here to achieve backward compatibility.
                     //       In general, self must not be used and will not be used once we
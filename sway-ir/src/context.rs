@@ -20,6 +20,9 @@ use crate::{
     Type, TypeContent,
 };
 
+/// The default for [Context::const_eval_limit].
+pub const DEFAULT_CONST_EVAL_LIMIT: usize = 1_000_000;
+
 /// The main IR context handle.
 ///
 /// Every module, function, block and value is stored here.  Some aggregate metadata is also
@@ -38,6 +41,10 @@ pub struct Context<'eng> {
 
     pub program_kind: Kind,
 
+    /// The maximum number of steps, function calls and loop iterations, that the compiler takes
+    /// when evaluating a constant expression before giving up.
+    pub const_eval_limit: usize,
+
     next_unique_sym_tag: u64,
 
     pub experimental: ExperimentalFlags,
@@ -62,6 +69,7 @@ impl<'eng> Context<'eng> {
             metadata: Default::default(),
             next_unique_sym_tag: Default::default(),
             program_kind: Kind::Contract,
+            const_eval_limit: DEFAULT_CONST_EVAL_LIMIT,
             experimental,
         };
         Type::create_basic_types(&mut def);
@@ -589,6 +589,18 @@ impl Type {
             }
         }
     }
+
+    /// Returns the alignment of the [Type] in bytes.
+    /// Values of the [Type] laid out next to each other, e.g., as array elements, start at
+    /// a multiple of the alignment. Single byte types are byte aligned, arrays have the
+    /// alignment of their elements, and all other types are aligned to the word boundary.
+    pub fn alignment(&self, context: &Context) -> u64 {
+        match self.get_content(context) {
+            TypeContent::Uint(8) | TypeContent::Bool | TypeContent::Unit | TypeContent::Never => 1,
+            TypeContent::Array(el_ty, _) => el_ty.alignment(context),
+            _ => 8,
+        }
+    }
 }
 
 // This is a mouthful...
@@ -968,6 +980,34 @@ mod tests {
             }
         }
 
+        #[test]
+        /// `u8`, `bool` and unit are byte aligned. Arrays have the alignment of their
+        /// elements. All other types are aligned to the word boundary.
+        /// The size of a type is always a multiple of its alignment.
+        fn alignment() {
+            let mut context = create_context();
+
+            assert_eq!(Type::get_uint8(&context).alignment(&context), 1);
+            assert_eq!(Type::get_bool(&context).alignment(&context), 1);
+            assert_eq!(Type::get_unit(&context).alignment(&context), 1);
+            assert_eq!(Type::get_uint64(&context).alignment(&context), 8);
+            assert_eq!(Type::get_uint256(&context).alignment(&context), 8);
+            assert_eq!(Type::get_b256(&context).alignment(&context), 8);
+
+            for (array_ty, _, _) in sample_arrays(&mut context) {
+                let elem_ty = array_ty.get_array_elem_type(&context).unwrap();
+                assert_eq!(array_ty.alignment(&context), elem_ty.alignment(&context));
+            }
+
+            for (struct_ty, _) in sample_structs(&mut context) {
+                assert_eq!(struct_ty.alignment(&context), 8);
+            }
+
+            for ty in all_sample_types(&mut context) {
+                assert_eq!(ty.size(&context).in_bytes() % ty.alignment(&context), 0);
+            }
+        }
+
         // A bit of trickery just to avoid bloating test setups by having `SourceEngine`
         // instantiation in every test.
         // Not that we can't do the same with the `Context` because it must be isolated and
@@ -1,7 +1,7 @@
 //! Traits for conversions between types.
 library;
 
-use ::option::Option;
+use ::result::Result;
 
 /// Used to do value-to-value conversions.
 pub trait From<T> {
@@ -30,29 +30,51 @@ impl<T> From<T> for T {
     }
 }
 
-// TODO: return a Result when https://github.com/FuelLabs/sway/issues/610 is resolved
+/// The error returned by `TryFrom` and `TryInto` when a conversion can't be performed in a
+/// lossless manner.
+pub enum ConversionError {
+    /// The value is out of the range of values of the target type.
+    Overflow: (),
+    /// The value doesn't have the length required by the target type.
+    InvalidLength: (),
+}
+
+impl Eq for ConversionError {
+    fn eq(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Overflow, Self::Overflow) => true,
+            (Self::InvalidLength, Self::InvalidLength) => true,
+            _ => false,
+        }
+    }
+}
+
 /// Used to attempt to do value-to-value conversions.
-/// Returns None if the conversion can't be performed in a lossless manner.
+/// Returns an error if the conversion can't be performed in a lossless manner.
 pub trait TryFrom<T> {
-    /// Performs the conversion. Returns None if the conversion can't be performed in a lossless manner.
-    fn try_from(b: T) -> Option<Self>;
+    /// Performs the conversion. Returns an error if the conversion can't be performed in a lossless manner.
+    fn try_from(b: T) -> Result<Self, ConversionError>;
 }
 
+/// Used to attempt to do value-to-value conversions.
+/// The opposite of `TryFrom`, implemented for all the types `TryFrom` is implemented for.
 pub trait TryInto<T> {
-    fn try_into(self) -> Option<T>;
+    /// Performs the conversion to the (usually inferred) target type. Returns an error if the
+    /// conversion can't be performed in a lossless manner.
+    fn try_into(self) -> Result<T, ConversionError>;
 }
 
 impl<T, U> TryInto<U> for T
 where
     U: TryFrom<T>,
 {
-    fn try_into(self) -> Option<U> {
+    fn try_into(self) -> Result<U, ConversionError> {
         U::try_from(self)
     }
 }
 
 impl<T> TryFrom<T> for T {
-    fn try_from(t: T) -> Option<T> {
-        Option::Some(t)
+    fn try_from(t: T) -> Result<T, ConversionError> {
+        Result::Ok(t)
     }
 }
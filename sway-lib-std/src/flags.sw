@@ -139,11 +139,11 @@ pub fn enable_panic_on_overflow() {
 /// ```sway
 /// use std::{assert::assert, flags::{disable_panic_on_unsafe_math, enable_panic_on_unsafe_math}, registers::error};
 ///
-/// fn main() {
+/// fn main(divisor: u64) {
 ///     disable_panic_on_unsafe_math();
 ///
 ///     // Division by zero is considered unsafe math.
-///     let bar = 1 / 0;
+///     let bar = 1 / divisor;
 ///     // Error flag is set to true whenever unsafe math occurs. Here represented as 1.
 ///     assert(error() == 1);
 ///
@@ -154,11 +154,11 @@ pub fn enable_panic_on_overflow() {
 /// ```sway
 /// use std::{assert::assert, flags::{disable_panic_on_unsafe_math, set_flags}, registers::error};
 ///
-/// fn foo() {
+/// fn foo(divisor: u64) {
 ///     let prior_flags = disable_panic_on_unsafe_math();
 ///
 ///     // Division by zero is considered unsafe math.
-///     let bar = 1 / 0;
+///     let bar = 1 / divisor;
 ///     // Error flag is set to true whenever unsafe math occurs. Here represented as 1.
 ///     assert(error() == 1);
 ///
@@ -191,11 +191,11 @@ pub fn disable_panic_on_unsafe_math() -> u64 {
 /// ```sway
 /// use std::{assert::assert, flags::{disable_panic_on_unsafe_math, enable_panic_on_unsafe_math}, registers::error};
 ///
-/// fn main() {
+/// fn main(divisor: u64) {
 ///     disable_panic_on_unsafe_math();
 ///
 ///     // Division by zero is considered unsafe math.
-///     let bar = 1 / 0;
+///     let bar = 1 / divisor;
 ///     // Error flag is set to true whenever unsafe math occurs. Here represented as 1.
 ///     assert(error() == 1);
 ///
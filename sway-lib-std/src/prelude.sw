@@ -24,7 +24,7 @@ pub use ::result::Result::{self, *};
 pub use ::revert::{require, revert};
 
 // Convert
-pub use ::convert::From;
+pub use ::convert::{From, TryFrom, TryInto};
 
 // Primitive conversions
 pub use ::primitive_conversions::{b256::*, str::*, u16::*, u256::*, u32::*, u64::*, u8::*,};
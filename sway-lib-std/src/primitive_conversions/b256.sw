@@ -1,20 +1,20 @@
 library;
 
 use ::bytes::Bytes;
-use ::convert::{From, TryFrom};
-use ::option::Option::{self, *};
+use ::convert::{ConversionError, From, TryFrom};
+use ::result::Result::{self, *};
 use ::u128::U128;
 use ::b512::B512;
 
 impl TryFrom<Bytes> for b256 {
-    fn try_from(b: Bytes) -> Option<Self> {
+    fn try_from(b: Bytes) -> Result<Self, ConversionError> {
         if b.len() != 32 {
-            None
+            Err(ConversionError::InvalidLength)
         } else {
             let mut val = 0x0000000000000000000000000000000000000000000000000000000000000000;
             let ptr = __addr_of(val);
             b.ptr().copy_to::<b256>(ptr, 1);
-            Some(val)
+            Ok(val)
         }
     }
 }
@@ -32,7 +32,7 @@ impl TryFrom<B512> for b256 {
     ///
     /// # Returns
     ///
-    /// * [Option<b256>] - The `b256` representation of the `B512` value.
+    /// * [Result<b256, ConversionError>] - The `b256` representation of the `B512` value.
     ///
     /// # Examples
     ///
@@ -44,12 +44,12 @@ impl TryFrom<B512> for b256 {
     ///     let b256_value = b256::try_from(b512_value).unwrap();
     /// }
     /// ```
-    fn try_from(val: B512) -> Option<Self> {
+    fn try_from(val: B512) -> Result<Self, ConversionError> {
         let bits = val.bits();
         if bits[0] == b256::zero() {
-            Some(bits[1])
+            Ok(bits[1])
         } else {
-            None
+            Err(ConversionError::Overflow)
         }
     }
 }
@@ -1,7 +1,8 @@
 library;
 
-use ::convert::{From, TryFrom};
+use ::convert::{ConversionError, From, TryFrom};
 use ::option::Option::{self, *};
+use ::result::Result::{self, *};
 use ::u128::U128;
 
 impl u16 {
@@ -61,11 +62,11 @@ impl From<u8> for u16 {
 }
 
 impl TryFrom<u32> for u16 {
-    fn try_from(u: u32) -> Option<Self> {
+    fn try_from(u: u32) -> Result<Self, ConversionError> {
         if u > u16::max().as_u32() {
-            None
+            Err(ConversionError::Overflow)
         } else {
-            Some(asm(r1: u) {
+            Ok(asm(r1: u) {
                 r1: u16
             })
         }
@@ -73,11 +74,11 @@ impl TryFrom<u32> for u16 {
 }
 
 impl TryFrom<u64> for u16 {
-    fn try_from(u: u64) -> Option<Self> {
+    fn try_from(u: u64) -> Result<Self, ConversionError> {
         if u > u16::max().as_u64() {
-            None
+            Err(ConversionError::Overflow)
         } else {
-            Some(asm(r1: u) {
+            Ok(asm(r1: u) {
                 r1: u16
             })
         }
@@ -85,7 +86,7 @@ impl TryFrom<u64> for u16 {
 }
 
 impl TryFrom<u256> for u16 {
-    fn try_from(u: u256) -> Option<Self> {
+    fn try_from(u: u256) -> Result<Self, ConversionError> {
         let parts = asm(r1: u) {
             r1: (u64, u64, u64, u64)
         };
@@ -95,9 +96,9 @@ impl TryFrom<u256> for u16 {
             || parts.2 != 0
             || parts.3 > u16::max().as_u64()
         {
-            None
+            Err(ConversionError::Overflow)
         } else {
-            Some(asm(r1: parts.3) {
+            Ok(asm(r1: parts.3) {
                 r1: u16
             })
         }
@@ -105,11 +106,11 @@ impl TryFrom<u256> for u16 {
 }
 
 impl TryFrom<U128> for u16 {
-    fn try_from(u: U128) -> Option<Self> {
+    fn try_from(u: U128) -> Result<Self, ConversionError> {
         if u.upper() == 0 {
             <u16 as TryFrom<u64>>::try_from(u.lower())
         } else {
-            None
+            Err(ConversionError::Overflow)
         }
     }
 }
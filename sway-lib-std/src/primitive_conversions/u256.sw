@@ -1,7 +1,7 @@
 library;
 
-use ::convert::{From, TryFrom};
-use ::option::Option::{self, *};
+use ::convert::{ConversionError, From, TryFrom};
+use ::result::Result::{self, *};
 use ::u128::U128;
 use ::b512::B512;
 
@@ -18,7 +18,7 @@ impl TryFrom<B512> for u256 {
     ///
     /// # Returns
     ///
-    /// * [Option<u256>] - The `u256` representation of the `B512` value.
+    /// * [Result<u256, ConversionError>] - The `u256` representation of the `B512` value.
     ///
     /// # Examples
     ///
@@ -30,12 +30,12 @@ impl TryFrom<B512> for u256 {
     ///     let u256_value = u256::try_from(b512_value).unwrap();
     /// }
     /// ```
-    fn try_from(val: B512) -> Option<Self> {
+    fn try_from(val: B512) -> Result<Self, ConversionError> {
         let bits = val.bits();
         if bits[0] == b256::zero() {
-            Some(bits[1].as_u256())
+            Ok(bits[1].as_u256())
         } else {
-            None
+            Err(ConversionError::Overflow)
         }
     }
 }
@@ -1,7 +1,8 @@
 library;
 
-use ::convert::{From, TryFrom};
+use ::convert::{ConversionError, From, TryFrom};
 use ::option::Option::{self, *};
+use ::result::Result::{self, *};
 use ::u128::U128;
 
 impl u32 {
@@ -115,11 +116,11 @@ impl From<u16> for u32 {
 }
 
 impl TryFrom<u64> for u32 {
-    fn try_from(u: u64) -> Option<Self> {
+    fn try_from(u: u64) -> Result<Self, ConversionError> {
         if u > u32::max().as_u64() {
-            None
+            Err(ConversionError::Overflow)
         } else {
-            Some(asm(r1: u) {
+            Ok(asm(r1: u) {
                 r1: u32
             })
         }
@@ -127,7 +128,7 @@ impl TryFrom<u64> for u32 {
 }
 
 impl TryFrom<u256> for u32 {
-    fn try_from(u: u256) -> Option<Self> {
+    fn try_from(u: u256) -> Result<Self, ConversionError> {
         let parts = asm(r1: u) {
             r1: (u64, u64, u64, u64)
         };
@@ -137,9 +138,9 @@ impl TryFrom<u256> for u32 {
             || parts.2 != 0
             || parts.3 > u32::max().as_u64()
         {
-            None
+            Err(ConversionError::Overflow)
         } else {
-            Some(asm(r1: parts.3) {
+            Ok(asm(r1: parts.3) {
                 r1: u32
             })
         }
@@ -147,11 +148,11 @@ impl TryFrom<u256> for u32 {
 }
 
 impl TryFrom<U128> for u32 {
-    fn try_from(u: U128) -> Option<Self> {
+    fn try_from(u: U128) -> Result<Self, ConversionError> {
         if u.upper() == 0 {
             <u32 as TryFrom<u64>>::try_from(u.lower())
         } else {
-            None
+            Err(ConversionError::Overflow)
         }
     }
 }
@@ -2,6 +2,7 @@ library;
 
 use ::convert::{TryFrom, TryInto, *};
 use ::option::Option::{self, *};
+use ::result::Result::{self, *};
 use ::u128::U128;
 
 impl u64 {
@@ -169,25 +170,25 @@ impl From<u32> for u64 {
 }
 
 impl TryFrom<u256> for u64 {
-    fn try_from(u: u256) -> Option<Self> {
+    fn try_from(u: u256) -> Result<Self, ConversionError> {
         let parts = asm(r1: u) {
             r1: (u64, u64, u64, u64)
         };
 
         if parts.0 != 0 || parts.1 != 0 || parts.2 != 0 {
-            None
+            Err(ConversionError::Overflow)
         } else {
-            Some(parts.3)
+            Ok(parts.3)
         }
     }
 }
 
 impl TryFrom<U128> for u64 {
-    fn try_from(u: U128) -> Option<Self> {
+    fn try_from(u: U128) -> Result<Self, ConversionError> {
         if u.upper() == 0 {
-            Some(u.lower())
+            Ok(u.lower())
         } else {
-            None
+            Err(ConversionError::Overflow)
         }
     }
 }
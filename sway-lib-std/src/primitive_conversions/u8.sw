@@ -1,15 +1,15 @@
 library;
 
-use ::convert::TryFrom;
-use ::option::Option::{self, *};
+use ::convert::{ConversionError, TryFrom};
+use ::result::Result::{self, *};
 use ::u128::U128;
 
 impl TryFrom<u16> for u8 {
-    fn try_from(u: u16) -> Option<Self> {
+    fn try_from(u: u16) -> Result<Self, ConversionError> {
         if u > u8::max().as_u16() {
-            None
+            Err(ConversionError::Overflow)
         } else {
-            Some(asm(r1: u) {
+            Ok(asm(r1: u) {
                 r1: u8
             })
         }
@@ -17,11 +17,11 @@ impl TryFrom<u16> for u8 {
 }
 
 impl TryFrom<u32> for u8 {
-    fn try_from(u: u32) -> Option<Self> {
+    fn try_from(u: u32) -> Result<Self, ConversionError> {
         if u > u8::max().as_u32() {
-            None
+            Err(ConversionError::Overflow)
         } else {
-            Some(asm(r1: u) {
+            Ok(asm(r1: u) {
                 r1: u8
             })
         }
@@ -29,11 +29,11 @@ impl TryFrom<u32> for u8 {
 }
 
 impl TryFrom<u64> for u8 {
-    fn try_from(u: u64) -> Option<Self> {
+    fn try_from(u: u64) -> Result<Self, ConversionError> {
         if u > u8::max().as_u64() {
-            None
+            Err(ConversionError::Overflow)
         } else {
-            Some(asm(r1: u) {
+            Ok(asm(r1: u) {
                 r1: u8
             })
         }
@@ -41,7 +41,7 @@ impl TryFrom<u64> for u8 {
 }
 
 impl TryFrom<u256> for u8 {
-    fn try_from(u: u256) -> Option<Self> {
+    fn try_from(u: u256) -> Result<Self, ConversionError> {
         let parts = asm(r1: u) {
             r1: (u64, u64, u64, u64)
         };
@@ -51,9 +51,9 @@ impl TryFrom<u256> for u8 {
             || parts.2 != 0
             || parts.3 > u8::max().as_u64()
         {
-            None
+            Err(ConversionError::Overflow)
         } else {
-            Some(asm(r1: parts.3) {
+            Ok(asm(r1: parts.3) {
                 r1: u8
             })
         }
@@ -61,11 +61,11 @@ impl TryFrom<u256> for u8 {
 }
 
 impl TryFrom<U128> for u8 {
-    fn try_from(u: U128) -> Option<Self> {
+    fn try_from(u: U128) -> Result<Self, ConversionError> {
         if u.upper() == 0 {
             <u8 as TryFrom<u64>>::try_from(u.lower())
         } else {
-            None
+            Err(ConversionError::Overflow)
         }
     }
 }
@@ -160,9 +160,9 @@ pub fn heap_ptr() -> raw_ptr {
 /// ```sway
 /// use std::{registers::error, flags::{disable_panic_on_unsafe_math, enable_panic_on_unsafe_math}};
 ///
-/// fn foo() {
+/// fn foo(divisor: u64) {
 ///     disable_panic_on_unsafe_math();
-///     let bar = 1 / 0;
+///     let bar = 1 / divisor;
 ///     assert(error() == 1);
 ///     enable_panic_on_unsafe_math();
 /// }
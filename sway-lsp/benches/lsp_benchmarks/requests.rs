@@ -22,10 +22,11 @@ fn benchmarks(c: &mut Criterion) {
     });
 
     c.bench_function("document_symbol", |b| {
+        let text_document = documents.get_text_document(&uri).unwrap();
         b.iter(|| {
-            session
-                .symbol_information(&uri)
-                .map(DocumentSymbolResponse::Flat)
+            DocumentSymbolResponse::Nested(capabilities::document_symbol::document_symbols(
+                &text_document,
+            ))
         })
     });
 
@@ -59,7 +60,8 @@ fn benchmarks(c: &mut Criterion) {
     });
 
     c.bench_function("goto_definition", |b| {
-        b.iter(|| session.token_definition_response(&uri, position))
+        let text_document = documents.get_text_document(&uri).unwrap();
+        b.iter(|| session.token_definition_response(&uri, position, &text_document))
     });
 
     c.bench_function("inlay_hints", |b| {
@@ -90,8 +92,16 @@ fn benchmarks(c: &mut Criterion) {
 
     c.bench_function("code_action", |b| {
         let range = Range::new(Position::new(4, 10), Position::new(4, 10));
+        let text_document = documents.get_text_document(&uri).unwrap();
         b.iter(|| {
-            capabilities::code_actions::code_actions(session.clone(), &range, &uri, &uri, &vec![])
+            capabilities::code_actions::code_actions(
+                session.clone(),
+                &range,
+                &uri,
+                &uri,
+                &vec![],
+                Some(&text_document),
+            )
         })
     });
 
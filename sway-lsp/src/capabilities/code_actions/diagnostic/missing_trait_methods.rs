@@ -0,0 +1,212 @@
+use crate::{
+    capabilities::{
+        code_actions::{
+            common::generate_impl::TAB, CodeActionContext, CODE_ACTION_MISSING_METHODS_TITLE,
+        },
+        diagnostic::DiagnosticData,
+    },
+    core::token::{get_range_from_span, AstToken, TypedAstToken},
+};
+use lsp_types::{
+    CodeAction as LspCodeAction, CodeActionKind, CodeActionOrCommand, Position, Range, TextEdit,
+    WorkspaceEdit,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use sway_core::{
+    language::{
+        parsed::Declaration,
+        ty::{self, TyFunctionParameter, TyTraitFn},
+    },
+    transform::{AttributeKind, AttributesMap},
+};
+use sway_types::Spanned;
+
+/// Returns a [CodeActionOrCommand] that inserts stubs for the methods missing from a trait or ABI impl.
+pub(crate) fn missing_trait_methods_code_action(
+    ctx: &CodeActionContext,
+    diagnostics: &mut impl Iterator<Item = (Range, DiagnosticData)>,
+) -> Option<Vec<CodeActionOrCommand>> {
+    // Find a diagnostic that has the attached metadata listing the missing methods.
+    let (missing_methods, range) = diagnostics.find_map(|(range, diag)| {
+        let missing_methods = diag.missing_trait_methods?;
+        Some((missing_methods, range))
+    })?;
+
+    // The diagnostic spans the whole impl, so use its range to find the impl declaration.
+    let impl_decl = ctx.tokens.tokens_for_file(ctx.temp_uri).find_map(|item| {
+        if let AstToken::Declaration(Declaration::ImplSelfOrTrait(decl_id)) = &item.value().parsed {
+            let impl_decl = ctx.engines.pe().get_impl_self_or_trait(decl_id);
+            if get_range_from_span(&impl_decl.span()) == range {
+                return Some(impl_decl);
+            }
+        }
+        None
+    })?;
+
+    // Find the declaration of the trait or ABI being implemented.
+    let trait_name = impl_decl.trait_name.suffix.to_string();
+    let (interface_surface, type_parameters) =
+        ctx.tokens.tokens_for_name(&trait_name).find_map(|item| {
+            match item.value().typed.as_ref()? {
+                TypedAstToken::TypedDeclaration(ty::TyDecl::TraitDecl(ty::TraitDecl {
+                    decl_id,
+                    ..
+                })) => {
+                    let trait_decl = ctx.engines.de().get_trait(decl_id);
+                    Some((
+                        trait_decl.interface_surface.clone(),
+                        trait_decl.type_parameters.clone(),
+                    ))
+                }
+                TypedAstToken::TypedDeclaration(ty::TyDecl::AbiDecl(ty::AbiDecl {
+                    decl_id,
+                    ..
+                })) => {
+                    let abi_decl = ctx.engines.de().get_abi(decl_id);
+                    Some((abi_decl.interface_surface.clone(), vec![]))
+                }
+                _ => None,
+            }
+        })?;
+
+    // Replace `Self` and the trait's type parameters with the types used by the impl.
+    let substitutions = std::iter::once((
+        "Self".to_string(),
+        impl_decl.implementing_for.span.as_str().to_string(),
+    ))
+    .chain(
+        type_parameters
+            .iter()
+            .zip(impl_decl.trait_type_arguments.iter())
+            .map(|(param, arg)| (param.name_ident.to_string(), arg.span.as_str().to_string())),
+    )
+    .collect::<Vec<_>>();
+
+    let impl_span = impl_decl.span();
+    let indentation = " ".repeat(impl_span.start_pos().line_col().col - 1);
+    let stubs = interface_surface
+        .iter()
+        .filter_map(|item| match item {
+            ty::TyTraitInterfaceItem::TraitFn(decl_ref) => {
+                let fn_decl = ctx.engines.de().get_trait_fn(decl_ref);
+                missing_methods
+                    .contains(&fn_decl.name.to_string())
+                    .then(|| stub_string(ctx, &fn_decl, &substitutions, &indentation))
+            }
+            ty::TyTraitInterfaceItem::Constant(_) | ty::TyTraitInterfaceItem::Type(_) => None,
+        })
+        .collect::<Vec<_>>();
+    if stubs.is_empty() {
+        return None;
+    }
+
+    // Insert the stubs before the closing brace, which may or may not be on a line of its own.
+    let before_brace = impl_span.as_str().strip_suffix('}')?;
+    let brace_on_own_line = before_brace
+        .rsplit_once('\n')
+        .is_some_and(|(_, line)| line.trim().is_empty());
+    let (position, new_text) = if brace_on_own_line {
+        let separator = if impl_decl.items.is_empty() { "" } else { "\n" };
+        (
+            Position::new(range.end.line, 0),
+            format!("{separator}{}\n", stubs.join("\n\n")),
+        )
+    } else {
+        (
+            Position::new(range.end.line, range.end.character - 1),
+            format!("\n{}\n{indentation}", stubs.join("\n\n")),
+        )
+    };
+
+    let text_edit = TextEdit {
+        range: Range::new(position, position),
+        new_text,
+    };
+    let changes = HashMap::from([(ctx.uri.clone(), vec![text_edit])]);
+
+    Some(vec![CodeActionOrCommand::CodeAction(LspCodeAction {
+        title: format!("{CODE_ACTION_MISSING_METHODS_TITLE} `{trait_name}`"),
+        kind: Some(CodeActionKind::QUICKFIX),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        data: Some(Value::String(ctx.uri.to_string())),
+        ..Default::default()
+    })])
+}
+
+/// Returns a [String] of a method with the signature of the given trait function and a body that reverts.
+fn stub_string(
+    ctx: &CodeActionContext,
+    fn_decl: &TyTraitFn,
+    substitutions: &[(String, String)],
+    indentation: &str,
+) -> String {
+    let indentation = format!("{indentation}{TAB}");
+    let attributes = attribute_string(&fn_decl.attributes, &indentation);
+    let params = fn_decl
+        .parameters
+        .iter()
+        .map(|param| param_string(param, substitutions))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_type = if ctx.engines.te().get(fn_decl.return_type.type_id).is_unit() {
+        String::new()
+    } else {
+        format!(
+            " -> {}",
+            substitute(fn_decl.return_type.span.as_str(), substitutions)
+        )
+    };
+    format!(
+        "{attributes}{indentation}fn {}({params}){return_type} {{\n{indentation}{TAB}__revert(0)\n{indentation}}}",
+        fn_decl.name
+    )
+}
+
+/// Returns a [String] of the attributes of a method, excluding doc comments, one per line.
+fn attribute_string(attr_map: &AttributesMap, indentation: &str) -> String {
+    attr_map
+        .iter()
+        .filter(|(kind, _)| !matches!(kind, AttributeKind::DocComment))
+        .flat_map(|(_, attrs)| attrs.iter())
+        .map(|attr| format!("{indentation}{}\n", attr.span.as_str()))
+        .collect()
+}
+
+fn param_string(param: &TyFunctionParameter, substitutions: &[(String, String)]) -> String {
+    let ref_string = if param.is_reference { "ref " } else { "" };
+    let mut_string = if param.is_mutable { "mut " } else { "" };
+    if param.is_self() {
+        format!("{ref_string}{mut_string}self")
+    } else {
+        format!(
+            "{ref_string}{mut_string}{}: {}",
+            param.name,
+            substitute(param.type_argument.span.as_str(), substitutions)
+        )
+    }
+}
+
+/// Replaces every identifier in `text` that matches the name of a substitution with its replacement.
+fn substitute(text: &str, substitutions: &[(String, String)]) -> String {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(is_ident_char) {
+        result.push_str(&rest[..start]);
+        let len = rest[start..]
+            .find(|c: char| !is_ident_char(c))
+            .unwrap_or(rest.len() - start);
+        let ident = &rest[start..start + len];
+        match substitutions.iter().find(|(name, _)| name == ident) {
+            Some((_, replacement)) => result.push_str(replacement),
+            None => result.push_str(ident),
+        }
+        rest = &rest[start + len..];
+    }
+    result.push_str(rest);
+    result
+}
@@ -1,10 +1,12 @@
 mod auto_import;
+mod missing_trait_methods;
 mod qualify;
 
 use crate::capabilities::{code_actions::CodeActionContext, diagnostic::DiagnosticData};
 use lsp_types::CodeActionOrCommand;
 
 use self::auto_import::import_code_action;
+use self::missing_trait_methods::missing_trait_methods_code_action;
 use self::qualify::qualify_code_action;
 
 /// Returns a list of [CodeActionOrCommand] based on the relevant compiler diagnostics.
@@ -22,6 +24,10 @@ pub(crate) fn code_actions(ctx: &CodeActionContext) -> Option<Vec<CodeActionOrCo
     import_code_action(ctx, &mut diagnostics_with_data.clone())
         .into_iter()
         .chain(qualify_code_action(ctx, &mut diagnostics_with_data.clone()))
+        .chain(missing_trait_methods_code_action(
+            ctx,
+            &mut diagnostics_with_data.clone(),
+        ))
         .reduce(|mut combined, mut curr| {
             combined.append(&mut curr);
             combined
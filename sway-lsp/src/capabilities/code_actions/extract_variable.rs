@@ -0,0 +1,357 @@
+use crate::core::{document::TextDocument, token::get_range_from_span};
+use lsp_types::{
+    CodeAction as LspCodeAction, CodeActionKind, CodeActionOrCommand, Range, TextEdit, Url,
+    WorkspaceEdit,
+};
+use serde_json::Value;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use sway_ast::{
+    expr::LoopControlFlow, CodeBlockContents, Expr, ExprArrayDescriptor, ExprTupleDescriptor,
+    IfCondition, IfExpr, ItemFn, ItemImplItem, ItemKind, MatchBranchKind, Statement,
+};
+use sway_error::handler::Handler;
+use sway_types::{Span, Spanned};
+
+use super::CODE_ACTION_EXTRACT_VARIABLE_TITLE;
+
+/// The name of the extracted variable, followed by a number if the name is already in use.
+const VARIABLE_NAME: &str = "tmp";
+
+/// The byte range of the selected text, without surrounding whitespace.
+#[derive(Clone, Copy)]
+struct Selection {
+    start: usize,
+    end: usize,
+}
+
+impl Selection {
+    fn is_within(&self, span: &Span) -> bool {
+        span.start() <= self.start && self.end <= span.end()
+    }
+
+    fn is(&self, span: &Span) -> bool {
+        span.start() == self.start && span.end() == self.end
+    }
+}
+
+/// The selected expression and the statement before which it is extracted.
+struct Extraction {
+    expr: Span,
+    /// The innermost statement, or final expression of a code block, containing the expression.
+    statement: Option<Span>,
+}
+
+/// Returns the code action that extracts the selected expression into a local variable,
+/// declared right before the statement containing the expression.
+///
+/// The action is only offered if the selection is a complete expression within a function body,
+/// whose value can be computed once, before the statement, without changing the meaning of the
+/// program. For example, expressions in `while` conditions or in the right-hand side of `&&`
+/// are not extracted, as they are not always evaluated exactly once.
+pub(crate) fn code_action(
+    text_document: &TextDocument,
+    range: &Range,
+    uri: &Url,
+) -> Option<CodeActionOrCommand> {
+    let src = text_document.get_text();
+    let start = text_document.position_to_index(range.start);
+    let end = text_document.position_to_index(range.end);
+    let selected = src.get(start..end)?;
+    let selection = Selection {
+        start: start + selected.len() - selected.trim_start().len(),
+        end: end - (selected.len() - selected.trim_end().len()),
+    };
+    if selection.start >= selection.end {
+        return None;
+    }
+
+    let src: Arc<str> = Arc::from(src);
+    let module = sway_parse::parse_file(&Handler::default(), src.clone(), None).ok()?;
+    let (extraction, fn_span) = module
+        .value
+        .items
+        .iter()
+        .find_map(|item| find_in_item(&item.value, selection))?;
+    let statement = extraction.statement?;
+
+    let name = variable_name(fn_span.as_str());
+    let line_start = src[..statement.start()].rfind('\n').map_or(0, |ix| ix + 1);
+    let line_prefix = &src[line_start..statement.start()];
+    let indentation = if line_prefix.trim().is_empty() {
+        line_prefix.to_string()
+    } else {
+        " ".repeat(line_prefix.chars().count())
+    };
+    let text_edit = TextEdit {
+        range: Range {
+            start: get_range_from_span(&statement).start,
+            end: get_range_from_span(&extraction.expr).end,
+        },
+        new_text: format!(
+            "let {name} = {};\n{indentation}{}{name}",
+            extraction.expr.as_str(),
+            &src[statement.start()..selection.start],
+        ),
+    };
+    let changes = HashMap::from([(uri.clone(), vec![text_edit])]);
+
+    Some(CodeActionOrCommand::CodeAction(LspCodeAction {
+        title: CODE_ACTION_EXTRACT_VARIABLE_TITLE.to_string(),
+        kind: Some(CodeActionKind::REFACTOR_EXTRACT),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        data: Some(Value::String(uri.to_string())),
+        ..Default::default()
+    }))
+}
+
+/// Returns [VARIABLE_NAME], followed by the smallest number that makes it differ from all the
+/// identifiers in the source of the enclosing function, so that the variable neither shadows nor
+/// is shadowed by any other name used in the function.
+fn variable_name(fn_src: &str) -> String {
+    let idents = fn_src
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .collect::<HashSet<_>>();
+    let mut name = VARIABLE_NAME.to_string();
+    let mut suffix = 1;
+    while idents.contains(name.as_str()) {
+        name = format!("{VARIABLE_NAME}{suffix}");
+        suffix += 1;
+    }
+    name
+}
+
+/// Finds the selection within the bodies of the functions of `item`, returning the extraction
+/// together with the span of the enclosing function.
+fn find_in_item(item: &ItemKind, selection: Selection) -> Option<(Extraction, Span)> {
+    match item {
+        ItemKind::Fn(item_fn) => find_in_fn(item_fn, selection),
+        ItemKind::Impl(item_impl) => {
+            item_impl
+                .contents
+                .get()
+                .iter()
+                .find_map(|impl_item| match &impl_item.value {
+                    ItemImplItem::Fn(item_fn) => find_in_fn(item_fn, selection),
+                    ItemImplItem::Const(_) | ItemImplItem::Type(_) => None,
+                })
+        }
+        ItemKind::Trait(item_trait) => item_trait.trait_defs_opt.as_ref().and_then(|trait_defs| {
+            trait_defs
+                .get()
+                .iter()
+                .find_map(|item_fn| find_in_fn(&item_fn.value, selection))
+        }),
+        ItemKind::Abi(item_abi) => item_abi.abi_defs_opt.as_ref().and_then(|abi_defs| {
+            abi_defs
+                .get()
+                .iter()
+                .find_map(|item_fn| find_in_fn(&item_fn.value, selection))
+        }),
+        _ => None,
+    }
+}
+
+fn find_in_fn(item_fn: &ItemFn, selection: Selection) -> Option<(Extraction, Span)> {
+    find_in_code_block(item_fn.body.get(), selection).map(|extraction| (extraction, item_fn.span()))
+}
+
+fn find_in_code_block(code_block: &CodeBlockContents, selection: Selection) -> Option<Extraction> {
+    let statements = code_block
+        .statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::Let(statement_let) => Some((statement.span(), &statement_let.expr)),
+            Statement::Expr { expr, .. } => Some((statement.span(), expr)),
+            Statement::Item(_) | Statement::Error(_, _) => None,
+        });
+    let final_expr = code_block
+        .final_expr_opt
+        .as_ref()
+        .map(|expr| (expr.span(), &**expr));
+    let (statement, expr) = statements
+        .chain(final_expr)
+        .find(|(span, _)| selection.is_within(span))?;
+    let mut extraction = find_in_expr(expr, selection)?;
+    extraction.statement.get_or_insert(statement);
+    Some(extraction)
+}
+
+/// Finds the selection within an expression that is not evaluated exactly once when the
+/// enclosing statement is, so that only the selections inside its nested code blocks can
+/// be extracted.
+fn find_in_conditional_expr(expr: &Expr, selection: Selection) -> Option<Extraction> {
+    find_in_expr(expr, selection).filter(|extraction| extraction.statement.is_some())
+}
+
+fn find_in_exprs<'a>(
+    mut exprs: impl Iterator<Item = &'a Expr>,
+    selection: Selection,
+) -> Option<Extraction> {
+    exprs.find_map(|expr| find_in_expr(expr, selection))
+}
+
+fn find_in_if(if_expr: &IfExpr, selection: Selection, is_else_if: bool) -> Option<Extraction> {
+    let condition = match &if_expr.condition {
+        IfCondition::Expr(condition) => condition,
+        IfCondition::Let { rhs, .. } => rhs,
+    };
+    let in_condition = if is_else_if {
+        find_in_conditional_expr(condition, selection)
+    } else {
+        find_in_expr(condition, selection)
+    };
+    in_condition
+        .or_else(|| find_in_code_block(if_expr.then_block.get(), selection))
+        .or_else(|| match &if_expr.else_opt {
+            Some((_, LoopControlFlow::Break(else_block))) => {
+                find_in_code_block(else_block.get(), selection)
+            }
+            Some((_, LoopControlFlow::Continue(else_if))) => find_in_if(else_if, selection, true),
+            None => None,
+        })
+}
+
+/// Whether the value of the expression can be assigned to a variable. Plain paths are left out,
+/// as extracting them would only introduce an alias, and so are the expressions that are only
+/// evaluated for their effect on control flow or state.
+fn is_extractable(expr: &Expr) -> bool {
+    !matches!(
+        expr,
+        Expr::Error(_, _)
+            | Expr::Path(_)
+            | Expr::Return { .. }
+            | Expr::Break { .. }
+            | Expr::Continue { .. }
+            | Expr::Reassignment { .. }
+            | Expr::While { .. }
+            | Expr::Loop { .. }
+            | Expr::For { .. }
+    )
+}
+
+fn find_in_expr(expr: &Expr, selection: Selection) -> Option<Extraction> {
+    let span = expr.span();
+    if !selection.is_within(&span) {
+        return None;
+    }
+    if selection.is(&span) {
+        return is_extractable(expr).then_some(Extraction {
+            expr: span,
+            statement: None,
+        });
+    }
+    match expr {
+        Expr::Error(_, _)
+        | Expr::Path(_)
+        | Expr::Literal(_)
+        | Expr::Continue { .. }
+        | Expr::Asm(_) => None,
+        Expr::AbiCast { args, .. } => find_in_expr(&args.get().address, selection),
+        Expr::Struct { fields, .. } => find_in_exprs(
+            fields
+                .get()
+                .into_iter()
+                .filter_map(|field| field.expr_opt.as_ref().map(|(_, expr)| &**expr)),
+            selection,
+        ),
+        Expr::Tuple(tuple) => match tuple.get() {
+            ExprTupleDescriptor::Cons { head, tail, .. } => {
+                find_in_exprs(std::iter::once(&**head).chain(tail), selection)
+            }
+            ExprTupleDescriptor::Nil => None,
+        },
+        Expr::Array(array) => match array.get() {
+            ExprArrayDescriptor::Sequence(elements) => {
+                find_in_exprs(elements.into_iter(), selection)
+            }
+            ExprArrayDescriptor::Repeat { value, length, .. } => {
+                find_in_exprs([&**value, &**length].into_iter(), selection)
+            }
+        },
+        Expr::Parens(expr) => find_in_expr(expr.get(), selection),
+        Expr::TypeAscription(ascription) => find_in_expr(&ascription.get().expr, selection),
+        Expr::Block(block) => find_in_code_block(block.get(), selection),
+        Expr::If(if_expr) => find_in_if(if_expr, selection, false),
+        Expr::Match {
+            value, branches, ..
+        } => find_in_expr(value, selection).or_else(|| {
+            branches.get().iter().find_map(|branch| match &branch.kind {
+                MatchBranchKind::Block { block, .. } => find_in_code_block(block.get(), selection),
+                MatchBranchKind::Expr { expr, .. } => find_in_conditional_expr(expr, selection),
+            })
+        }),
+        Expr::Matches { args, .. } => find_in_expr(&args.get().value, selection),
+        Expr::While {
+            condition, block, ..
+        } => find_in_conditional_expr(condition, selection)
+            .or_else(|| find_in_code_block(block.get(), selection)),
+        Expr::Loop { block, .. } => find_in_code_block(block.get(), selection),
+        Expr::For {
+            iterator, block, ..
+        } => {
+            find_in_expr(iterator, selection).or_else(|| find_in_code_block(block.get(), selection))
+        }
+        Expr::FuncApp { func, args } => find_in_expr(func, selection)
+            .or_else(|| find_in_exprs(args.get().into_iter(), selection)),
+        Expr::MethodCall {
+            target,
+            contract_args_opt,
+            args,
+            ..
+        } => find_in_expr(target, selection)
+            .or_else(|| {
+                contract_args_opt.as_ref().and_then(|contract_args| {
+                    find_in_exprs(
+                        contract_args
+                            .get()
+                            .into_iter()
+                            .filter_map(|field| field.expr_opt.as_ref().map(|(_, expr)| &**expr)),
+                        selection,
+                    )
+                })
+            })
+            .or_else(|| find_in_exprs(args.get().into_iter(), selection)),
+        Expr::Index { target, arg } => {
+            find_in_expr(target, selection).or_else(|| find_in_expr(arg.get(), selection))
+        }
+        Expr::Return { expr_opt, .. } | Expr::Break { expr_opt, .. } => expr_opt
+            .as_ref()
+            .and_then(|expr| find_in_expr(expr, selection)),
+        Expr::FieldProjection { target: expr, .. }
+        | Expr::TupleFieldProjection { target: expr, .. }
+        | Expr::Try { expr, .. }
+        | Expr::Ref { expr, .. }
+        | Expr::Deref { expr, .. }
+        | Expr::Not { expr, .. }
+        | Expr::Reassignment { expr, .. } => find_in_expr(expr, selection),
+        Expr::Mul { lhs, rhs, .. }
+        | Expr::Div { lhs, rhs, .. }
+        | Expr::Pow { lhs, rhs, .. }
+        | Expr::Modulo { lhs, rhs, .. }
+        | Expr::Add { lhs, rhs, .. }
+        | Expr::Sub { lhs, rhs, .. }
+        | Expr::Shl { lhs, rhs, .. }
+        | Expr::Shr { lhs, rhs, .. }
+        | Expr::BitAnd { lhs, rhs, .. }
+        | Expr::BitXor { lhs, rhs, .. }
+        | Expr::BitOr { lhs, rhs, .. }
+        | Expr::Equal { lhs, rhs, .. }
+        | Expr::NotEqual { lhs, rhs, .. }
+        | Expr::LessThan { lhs, rhs, .. }
+        | Expr::GreaterThan { lhs, rhs, .. }
+        | Expr::LessThanEq { lhs, rhs, .. }
+        | Expr::GreaterThanEq { lhs, rhs, .. } => {
+            find_in_expr(lhs, selection).or_else(|| find_in_expr(rhs, selection))
+        }
+        // The right-hand side is only evaluated depending on the value of the left-hand side.
+        Expr::LogicalAnd { lhs, rhs, .. } | Expr::LogicalOr { lhs, rhs, .. } => {
+            find_in_expr(lhs, selection).or_else(|| find_in_conditional_expr(rhs, selection))
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod constant_decl;
 pub mod diagnostic;
 pub mod enum_decl;
 pub mod enum_variant;
+pub mod extract_variable;
 pub mod function_decl;
 pub mod storage_field;
 pub mod struct_decl;
@@ -11,6 +12,7 @@ pub mod struct_field;
 pub mod trait_fn;
 
 use crate::core::{
+    document::TextDocument,
     session::Session,
     token::{Token, TypedAstToken},
     token_map::TokenMap,
@@ -30,6 +32,8 @@ pub(crate) const CODE_ACTION_NEW_TITLE: &str = "Generate `new`";
 pub(crate) const CODE_ACTION_DOC_TITLE: &str = "Generate a documentation template";
 pub(crate) const CODE_ACTION_IMPORT_TITLE: &str = "Import";
 pub(crate) const CODE_ACTION_QUALIFY_TITLE: &str = "Qualify as";
+pub(crate) const CODE_ACTION_MISSING_METHODS_TITLE: &str = "Generate missing methods for";
+pub(crate) const CODE_ACTION_EXTRACT_VARIABLE_TITLE: &str = "Extract into variable";
 
 #[derive(Clone)]
 pub(crate) struct CodeActionContext<'a> {
@@ -48,10 +52,14 @@ pub fn code_actions(
     uri: &Url,
     temp_uri: &Url,
     diagnostics: &Vec<Diagnostic>,
+    text_document: Option<&TextDocument>,
 ) -> Option<CodeActionResponse> {
-    let t = session
-        .token_map()
-        .token_at_position(temp_uri, range.start)?;
+    let actions_by_selection = text_document
+        .and_then(|text_document| extract_variable::code_action(text_document, range, uri));
+
+    let Some(t) = session.token_map().token_at_position(temp_uri, range.start) else {
+        return actions_by_selection.map(|action| vec![action]);
+    };
     let token = t.value();
 
     let ctx = CodeActionContext {
@@ -96,7 +104,13 @@ pub fn code_actions(
 
     let actions_by_diagnostic = diagnostic::code_actions(&ctx).unwrap_or_default();
 
-    Some([actions_by_type, actions_by_diagnostic].concat())
+    Some(
+        [actions_by_type, actions_by_diagnostic]
+            .concat()
+            .into_iter()
+            .chain(actions_by_selection)
+            .collect(),
+    )
 }
 
 pub(crate) trait CodeAction<'a, T: Spanned> {
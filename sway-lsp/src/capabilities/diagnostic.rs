@@ -94,6 +94,7 @@ fn get_warning_diagnostic_tags(warning: &Warning) -> Option<Vec<DiagnosticTag>>
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct DiagnosticData {
     pub unknown_symbol_name: Option<String>,
+    pub missing_trait_methods: Option<Vec<String>>,
 }
 
 impl TryFrom<CompileWarning> for DiagnosticData {
@@ -111,12 +112,26 @@ impl TryFrom<CompileError> for DiagnosticData {
         match value {
             CompileError::SymbolNotFound { name, .. } => Ok(DiagnosticData {
                 unknown_symbol_name: Some(name.to_string()),
+                ..Default::default()
             }),
             CompileError::TraitNotFound { name, .. } => Ok(DiagnosticData {
                 unknown_symbol_name: Some(name),
+                ..Default::default()
             }),
             CompileError::UnknownVariable { var_name, .. } => Ok(DiagnosticData {
                 unknown_symbol_name: Some(var_name.to_string()),
+                ..Default::default()
+            }),
+            CompileError::MissingInterfaceSurfaceMethods {
+                missing_functions, ..
+            } => Ok(DiagnosticData {
+                missing_trait_methods: Some(
+                    missing_functions
+                        .iter()
+                        .map(|name| name.to_string())
+                        .collect(),
+                ),
+                ..Default::default()
             }),
             _ => anyhow::bail!("Not implemented"),
         }
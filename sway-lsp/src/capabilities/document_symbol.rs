@@ -1,60 +1,299 @@
-use crate::core::token::{SymbolKind, Token, TokenIdent};
-use dashmap::mapref::multiple::RefMulti;
-use lsp_types::{self, Location, SymbolInformation, Url};
-
-pub fn to_symbol_information<'a, I>(tokens: I, url: &Url) -> Vec<SymbolInformation>
-where
-    I: Iterator<Item = RefMulti<'a, TokenIdent, Token>>,
-{
-    tokens
-        .map(|entry| {
-            let (ident, token) = entry.pair();
-            symbol_info(ident, token, url.clone())
-        })
-        .collect()
-}
-
-/// Given a `token::SymbolKind`, return the `lsp_types::SymbolKind` that corresponds to it.
-pub(crate) fn symbol_kind(symbol_kind: &SymbolKind) -> lsp_types::SymbolKind {
-    match symbol_kind {
-        SymbolKind::Field => lsp_types::SymbolKind::FIELD,
-        SymbolKind::BuiltinType | SymbolKind::TypeParameter => {
-            lsp_types::SymbolKind::TYPE_PARAMETER
-        }
-        SymbolKind::Function | SymbolKind::DeriveHelper | SymbolKind::Intrinsic => {
-            lsp_types::SymbolKind::FUNCTION
-        }
-        SymbolKind::Const => lsp_types::SymbolKind::CONSTANT,
-        SymbolKind::Struct => lsp_types::SymbolKind::STRUCT,
-        SymbolKind::Trait => lsp_types::SymbolKind::INTERFACE,
-        SymbolKind::Module => lsp_types::SymbolKind::MODULE,
-        SymbolKind::Enum => lsp_types::SymbolKind::ENUM,
-        SymbolKind::Variant => lsp_types::SymbolKind::ENUM_MEMBER,
-        SymbolKind::BoolLiteral => lsp_types::SymbolKind::BOOLEAN,
-        SymbolKind::StringLiteral => lsp_types::SymbolKind::STRING,
-        SymbolKind::NumericLiteral => lsp_types::SymbolKind::NUMBER,
-        SymbolKind::ValueParam
-        | SymbolKind::ByteLiteral
-        | SymbolKind::Variable
-        | SymbolKind::TypeAlias
-        | SymbolKind::TraitType
-        | SymbolKind::Keyword
-        | SymbolKind::SelfKeyword
-        | SymbolKind::SelfTypeKeyword
-        | SymbolKind::ProgramTypeKeyword
-        | SymbolKind::Unknown => lsp_types::SymbolKind::VARIABLE,
+use crate::core::{document::TextDocument, token::get_range_from_span};
+use lsp_types::{DocumentSymbol, SymbolKind};
+use std::sync::Arc;
+use sway_ast::{
+    attribute::Annotated, ItemConst, ItemFn, ItemImplItem, ItemKind, ItemTraitItem, StorageEntry,
+    TraitType, TypeField,
+};
+use sway_error::handler::Handler;
+use sway_types::{Ident, Span, Spanned};
+
+/// Returns the outline of the document as a tree of symbols.
+///
+/// Like the folding ranges, the symbols are computed from the parse tree, so the outline
+/// doesn't depend on the result of the last compilation and is available while the document
+/// contains type errors. Items nest their members: `impl` blocks, traits and ABIs contain
+/// their methods, structs and enums their fields and variants, and storage its namespaces.
+/// The items of a submodule are declared in the file of the submodule and appear in its outline.
+pub fn document_symbols(text_document: &TextDocument) -> Vec<DocumentSymbol> {
+    let _p = tracing::trace_span!("document_symbols").entered();
+    let src: Arc<str> = Arc::from(text_document.get_text());
+    let handler = Handler::default();
+    match sway_parse::parse_file(&handler, src, None) {
+        Ok(module) => module.value.items.iter().filter_map(item_symbol).collect(),
+        Err(_) => vec![],
     }
 }
 
-#[allow(warnings)]
-// TODO: the "deprecated: None" field is deprecated according to this library
-fn symbol_info(ident: &TokenIdent, token: &Token, url: Url) -> SymbolInformation {
-    SymbolInformation {
-        name: ident.name.to_string(),
-        kind: symbol_kind(&token.kind),
-        location: Location::new(url, ident.range),
+/// Returns the span of `annotated`, including its attributes and doc comments.
+fn annotated_span<T: Spanned>(annotated: &Annotated<T>) -> Span {
+    Span::join_all(
+        annotated
+            .attribute_list
+            .iter()
+            .map(Spanned::span)
+            .chain(std::iter::once(annotated.value.span())),
+    )
+}
+
+/// Creates a symbol covering `span` whose name is highlighted at `name_span`.
+#[allow(deprecated)]
+fn symbol(
+    name: String,
+    kind: SymbolKind,
+    span: &Span,
+    name_span: &Span,
+    detail: Option<String>,
+    children: Vec<DocumentSymbol>,
+) -> DocumentSymbol {
+    DocumentSymbol {
+        name,
+        detail,
+        kind,
         tags: None,
-        container_name: None,
         deprecated: None,
+        range: get_range_from_span(span),
+        selection_range: get_range_from_span(name_span),
+        children: (!children.is_empty()).then_some(children),
+    }
+}
+
+fn ident_symbol(
+    ident: &Ident,
+    kind: SymbolKind,
+    span: &Span,
+    detail: Option<String>,
+    children: Vec<DocumentSymbol>,
+) -> DocumentSymbol {
+    symbol(
+        ident.as_str().to_string(),
+        kind,
+        span,
+        &ident.span(),
+        detail,
+        children,
+    )
+}
+
+fn item_symbol(item: &Annotated<ItemKind>) -> Option<DocumentSymbol> {
+    let span = annotated_span(item);
+    let symbol = match &item.value {
+        ItemKind::Submodule(submodule) => {
+            ident_symbol(&submodule.name, SymbolKind::MODULE, &span, None, vec![])
+        }
+        ItemKind::Struct(item_struct) => ident_symbol(
+            &item_struct.name,
+            SymbolKind::STRUCT,
+            &span,
+            None,
+            item_struct
+                .fields
+                .get()
+                .into_iter()
+                .map(|field| type_field_symbol(field, SymbolKind::FIELD))
+                .collect(),
+        ),
+        ItemKind::Enum(item_enum) => ident_symbol(
+            &item_enum.name,
+            SymbolKind::ENUM,
+            &span,
+            None,
+            item_enum
+                .fields
+                .get()
+                .into_iter()
+                .map(|variant| type_field_symbol(variant, SymbolKind::ENUM_MEMBER))
+                .collect(),
+        ),
+        ItemKind::Fn(item_fn) => fn_symbol(item_fn, SymbolKind::FUNCTION, &span),
+        ItemKind::Trait(item_trait) => ident_symbol(
+            &item_trait.name,
+            SymbolKind::INTERFACE,
+            &span,
+            None,
+            trait_item_symbols(
+                item_trait.trait_items.get(),
+                item_trait.trait_defs_opt.as_ref().map(|defs| defs.get()),
+            ),
+        ),
+        ItemKind::Abi(item_abi) => ident_symbol(
+            &item_abi.name,
+            SymbolKind::INTERFACE,
+            &span,
+            None,
+            trait_item_symbols(
+                item_abi.abi_items.get(),
+                item_abi.abi_defs_opt.as_ref().map(|defs| defs.get()),
+            ),
+        ),
+        ItemKind::Impl(item_impl) => {
+            let ty = item_impl.ty.span();
+            let name = match &item_impl.trait_opt {
+                Some((trait_path, _)) => {
+                    format!("impl {} for {}", trait_path.span().as_str(), ty.as_str())
+                }
+                None => format!("impl {}", ty.as_str()),
+            };
+            let children = item_impl
+                .contents
+                .get()
+                .iter()
+                .map(|impl_item| {
+                    let span = annotated_span(impl_item);
+                    match &impl_item.value {
+                        ItemImplItem::Fn(item_fn) => fn_symbol(item_fn, SymbolKind::METHOD, &span),
+                        ItemImplItem::Const(item_const) => const_symbol(item_const, &span),
+                        ItemImplItem::Type(trait_type) => trait_type_symbol(trait_type, &span),
+                    }
+                })
+                .collect();
+            symbol(name, SymbolKind::OBJECT, &span, &ty, None, children)
+        }
+        ItemKind::Const(item_const) => const_symbol(item_const, &span),
+        ItemKind::Storage(item_storage) => symbol(
+            "storage".to_string(),
+            SymbolKind::STRUCT,
+            &span,
+            &item_storage.storage_token.span(),
+            None,
+            item_storage
+                .entries
+                .get()
+                .into_iter()
+                .map(|entry| storage_entry_symbol(&entry.value, annotated_span(entry)))
+                .collect(),
+        ),
+        ItemKind::Configurable(item_configurable) => symbol(
+            "configurable".to_string(),
+            SymbolKind::STRUCT,
+            &span,
+            &item_configurable.configurable_token.span(),
+            None,
+            item_configurable
+                .fields
+                .get()
+                .into_iter()
+                .map(|field| {
+                    ident_symbol(
+                        &field.value.name,
+                        SymbolKind::CONSTANT,
+                        &annotated_span(field),
+                        Some(field.value.ty.span().as_str().to_string()),
+                        vec![],
+                    )
+                })
+                .collect(),
+        ),
+        ItemKind::TypeAlias(item_type_alias) => ident_symbol(
+            &item_type_alias.name,
+            SymbolKind::TYPE_PARAMETER,
+            &span,
+            Some(item_type_alias.ty.span().as_str().to_string()),
+            vec![],
+        ),
+        ItemKind::Use(_) | ItemKind::Error(_, _) => return None,
+    };
+    Some(symbol)
+}
+
+/// Returns the symbol of a struct field or an enum variant, detailed with its type.
+fn type_field_symbol(field: &Annotated<TypeField>, kind: SymbolKind) -> DocumentSymbol {
+    ident_symbol(
+        &field.value.name,
+        kind,
+        &annotated_span(field),
+        Some(field.value.ty.span().as_str().to_string()),
+        vec![],
+    )
+}
+
+/// Returns the symbol of a function, detailed with its signature.
+fn fn_symbol(item_fn: &ItemFn, kind: SymbolKind, span: &Span) -> DocumentSymbol {
+    let signature = &item_fn.fn_signature;
+    ident_symbol(
+        &signature.name,
+        kind,
+        span,
+        Some(signature.span().as_str().to_string()),
+        vec![],
+    )
+}
+
+fn const_symbol(item_const: &ItemConst, span: &Span) -> DocumentSymbol {
+    ident_symbol(
+        &item_const.name,
+        SymbolKind::CONSTANT,
+        span,
+        item_const
+            .ty_opt
+            .as_ref()
+            .map(|(_, ty)| ty.span().as_str().to_string()),
+        vec![],
+    )
+}
+
+fn trait_type_symbol(trait_type: &TraitType, span: &Span) -> DocumentSymbol {
+    ident_symbol(
+        &trait_type.name,
+        SymbolKind::TYPE_PARAMETER,
+        span,
+        trait_type
+            .ty_opt
+            .as_ref()
+            .map(|ty| ty.span().as_str().to_string()),
+        vec![],
+    )
+}
+
+/// Returns the symbols of the interface items and the provided methods of a trait or an ABI.
+fn trait_item_symbols(
+    items: &[Annotated<ItemTraitItem>],
+    defs: Option<&Vec<Annotated<ItemFn>>>,
+) -> Vec<DocumentSymbol> {
+    let item_symbols = items.iter().filter_map(|item| {
+        let span = annotated_span(item);
+        match &item.value {
+            ItemTraitItem::Fn(signature, _) => Some(ident_symbol(
+                &signature.name,
+                SymbolKind::METHOD,
+                &span,
+                Some(signature.span().as_str().to_string()),
+                vec![],
+            )),
+            ItemTraitItem::Const(item_const, _) => Some(const_symbol(item_const, &span)),
+            ItemTraitItem::Type(trait_type, _) => Some(trait_type_symbol(trait_type, &span)),
+            ItemTraitItem::Error(_, _) => None,
+        }
+    });
+    let def_symbols = defs
+        .into_iter()
+        .flatten()
+        .map(|item_fn| fn_symbol(&item_fn.value, SymbolKind::METHOD, &annotated_span(item_fn)));
+    item_symbols.chain(def_symbols).collect()
+}
+
+/// Returns the symbol of a storage field or of a storage namespace and its entries.
+fn storage_entry_symbol(entry: &StorageEntry, span: Span) -> DocumentSymbol {
+    match (&entry.namespace, &entry.field) {
+        (Some(namespace), _) => ident_symbol(
+            &entry.name,
+            SymbolKind::NAMESPACE,
+            &span,
+            None,
+            namespace
+                .get()
+                .into_iter()
+                .map(|entry| storage_entry_symbol(&entry.value, annotated_span(entry)))
+                .collect(),
+        ),
+        (None, field) => ident_symbol(
+            &entry.name,
+            SymbolKind::FIELD,
+            &span,
+            field
+                .as_ref()
+                .map(|field| field.ty.span().as_str().to_string()),
+            vec![],
+        ),
     }
 }
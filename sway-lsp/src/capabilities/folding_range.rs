@@ -0,0 +1,353 @@
+use crate::core::document::TextDocument;
+use lsp_types::{FoldingRange, FoldingRangeKind};
+use std::sync::Arc;
+use sway_ast::{
+    expr::LoopControlFlow,
+    token::{CommentKind, CommentedTokenStream, CommentedTokenTree, CommentedTree},
+    CodeBlockContents, Expr, ExprArrayDescriptor, ExprTupleDescriptor, IfCondition, IfExpr, ItemFn,
+    ItemImplItem, ItemKind, MatchBranchKind, Statement,
+};
+use sway_error::handler::Handler;
+use sway_types::{Span, Spanned};
+
+/// Returns the folding ranges of the document.
+///
+/// The ranges are computed from the spans of the parse tree, so they only depend on the
+/// text of the document and not on the result of the last compilation. The parser recovers
+/// from errors, meaning that the constructs that did parse are still folded when other
+/// parts of the document contain errors.
+pub fn folding_ranges(text_document: &TextDocument) -> Vec<FoldingRange> {
+    let _p = tracing::trace_span!("folding_ranges").entered();
+    let src: Arc<str> = Arc::from(text_document.get_text());
+    let handler = Handler::default();
+    let mut ranges = vec![];
+    if let Ok(module) = sway_parse::parse_file(&handler, src.clone(), None) {
+        let items = module
+            .value
+            .items
+            .iter()
+            .map(|item| &item.value)
+            .collect::<Vec<_>>();
+        collect_imports(&items, &mut ranges);
+        for item in items {
+            collect_item(item, &mut ranges);
+        }
+    }
+    if let Ok(token_stream) = sway_parse::lex_commented(&handler, &src, 0, src.len(), &None) {
+        collect_comments(&token_stream, &mut ranges);
+    }
+    ranges.sort_by_key(|range| (range.start_line, range.end_line));
+    ranges.dedup_by_key(|range| (range.start_line, range.end_line));
+    ranges
+}
+
+/// Adds a range of the given kind for `span`, unless it fits on a single line.
+fn push_range(ranges: &mut Vec<FoldingRange>, span: &Span, kind: Option<FoldingRangeKind>) {
+    let start_line = span.start_pos().line_col().line as u32 - 1;
+    let end_line = span.end_pos().line_col().line as u32 - 1;
+    if start_line < end_line {
+        ranges.push(FoldingRange {
+            start_line,
+            start_character: None,
+            end_line,
+            end_character: None,
+            kind,
+            collapsed_text: None,
+        });
+    }
+}
+
+/// Adds an [FoldingRangeKind::Imports] range for every run of consecutive `use` items.
+fn collect_imports(items: &[&ItemKind], ranges: &mut Vec<FoldingRange>) {
+    let mut run: Option<Span> = None;
+    for item in items {
+        match (item, &run) {
+            (ItemKind::Use(item_use), Some(span)) => {
+                run = Some(Span::join(span.clone(), &item_use.span()));
+            }
+            (ItemKind::Use(item_use), None) => run = Some(item_use.span()),
+            (_, _) => {
+                if let Some(span) = run.take() {
+                    push_range(ranges, &span, Some(FoldingRangeKind::Imports));
+                }
+            }
+        }
+    }
+    if let Some(span) = run {
+        push_range(ranges, &span, Some(FoldingRangeKind::Imports));
+    }
+}
+
+fn collect_item(item: &ItemKind, ranges: &mut Vec<FoldingRange>) {
+    match item {
+        ItemKind::Fn(item_fn) => collect_fn(item_fn, ranges),
+        ItemKind::Impl(item_impl) => {
+            push_range(ranges, &item_impl.span(), None);
+            for impl_item in item_impl.contents.get() {
+                match &impl_item.value {
+                    ItemImplItem::Fn(item_fn) => collect_fn(item_fn, ranges),
+                    ItemImplItem::Const(item_const) => {
+                        push_range(ranges, &item_const.span(), None);
+                        if let Some(expr) = &item_const.expr_opt {
+                            collect_expr(expr, ranges);
+                        }
+                    }
+                    ItemImplItem::Type(_) => {}
+                }
+            }
+        }
+        ItemKind::Trait(item_trait) => {
+            push_range(ranges, &item_trait.trait_items.span(), None);
+            if let Some(trait_defs) = &item_trait.trait_defs_opt {
+                push_range(ranges, &trait_defs.span(), None);
+                for item_fn in trait_defs.get() {
+                    collect_fn(&item_fn.value, ranges);
+                }
+            }
+        }
+        ItemKind::Abi(item_abi) => {
+            push_range(ranges, &item_abi.abi_items.span(), None);
+            if let Some(abi_defs) = &item_abi.abi_defs_opt {
+                push_range(ranges, &abi_defs.span(), None);
+                for item_fn in abi_defs.get() {
+                    collect_fn(&item_fn.value, ranges);
+                }
+            }
+        }
+        ItemKind::Const(item_const) => {
+            push_range(ranges, &item_const.span(), None);
+            if let Some(expr) = &item_const.expr_opt {
+                collect_expr(expr, ranges);
+            }
+        }
+        ItemKind::Struct(_)
+        | ItemKind::Enum(_)
+        | ItemKind::Storage(_)
+        | ItemKind::Configurable(_) => push_range(ranges, &item.span(), None),
+        ItemKind::Submodule(_) | ItemKind::Use(_) | ItemKind::TypeAlias(_) => {}
+        ItemKind::Error(_, _) => {}
+    }
+}
+
+fn collect_fn(item_fn: &ItemFn, ranges: &mut Vec<FoldingRange>) {
+    push_range(ranges, &item_fn.span(), None);
+    collect_code_block(item_fn.body.get(), ranges);
+}
+
+fn collect_code_block(code_block: &CodeBlockContents, ranges: &mut Vec<FoldingRange>) {
+    for statement in &code_block.statements {
+        match statement {
+            Statement::Let(statement_let) => collect_expr(&statement_let.expr, ranges),
+            Statement::Item(item) => collect_item(&item.value, ranges),
+            Statement::Expr { expr, .. } => collect_expr(expr, ranges),
+            Statement::Error(_, _) => {}
+        }
+    }
+    if let Some(expr) = &code_block.final_expr_opt {
+        collect_expr(expr, ranges);
+    }
+}
+
+fn collect_exprs<'a>(exprs: impl Iterator<Item = &'a Expr>, ranges: &mut Vec<FoldingRange>) {
+    for expr in exprs {
+        collect_expr(expr, ranges);
+    }
+}
+
+fn collect_if(if_expr: &IfExpr, ranges: &mut Vec<FoldingRange>) {
+    match &if_expr.condition {
+        IfCondition::Expr(condition) => collect_expr(condition, ranges),
+        IfCondition::Let { rhs, .. } => collect_expr(rhs, ranges),
+    }
+    push_range(ranges, &if_expr.then_block.span(), None);
+    collect_code_block(if_expr.then_block.get(), ranges);
+    match &if_expr.else_opt {
+        Some((_, LoopControlFlow::Break(else_block))) => {
+            push_range(ranges, &else_block.span(), None);
+            collect_code_block(else_block.get(), ranges);
+        }
+        Some((_, LoopControlFlow::Continue(else_if))) => collect_if(else_if, ranges),
+        None => {}
+    }
+}
+
+fn collect_expr(expr: &Expr, ranges: &mut Vec<FoldingRange>) {
+    match expr {
+        Expr::Error(_, _) | Expr::Path(_) | Expr::Literal(_) | Expr::Continue { .. } => {}
+        Expr::Asm(asm_block) => push_range(ranges, &asm_block.span(), None),
+        Expr::AbiCast { args, .. } => collect_expr(&args.get().address, ranges),
+        Expr::Struct { fields, .. } => {
+            push_range(ranges, &fields.span(), None);
+            collect_exprs(
+                fields
+                    .get()
+                    .into_iter()
+                    .filter_map(|field| field.expr_opt.as_ref().map(|(_, expr)| &**expr)),
+                ranges,
+            );
+        }
+        Expr::Tuple(tuple) => {
+            push_range(ranges, &tuple.span(), None);
+            if let ExprTupleDescriptor::Cons { head, tail, .. } = tuple.get() {
+                collect_expr(head, ranges);
+                collect_exprs(tail.into_iter(), ranges);
+            }
+        }
+        Expr::Array(array) => {
+            push_range(ranges, &array.span(), None);
+            match array.get() {
+                ExprArrayDescriptor::Sequence(elements) => {
+                    collect_exprs(elements.into_iter(), ranges)
+                }
+                ExprArrayDescriptor::Repeat { value, length, .. } => {
+                    collect_expr(value, ranges);
+                    collect_expr(length, ranges);
+                }
+            }
+        }
+        Expr::Parens(expr) => collect_expr(expr.get(), ranges),
+        Expr::TypeAscription(ascription) => collect_expr(&ascription.get().expr, ranges),
+        Expr::Block(block) => {
+            push_range(ranges, &block.span(), None);
+            collect_code_block(block.get(), ranges);
+        }
+        Expr::If(if_expr) => collect_if(if_expr, ranges),
+        Expr::Match {
+            value, branches, ..
+        } => {
+            collect_expr(value, ranges);
+            push_range(ranges, &branches.span(), None);
+            for branch in branches.get() {
+                push_range(ranges, &branch.span(), None);
+                match &branch.kind {
+                    MatchBranchKind::Block { block, .. } => collect_code_block(block.get(), ranges),
+                    MatchBranchKind::Expr { expr, .. } => collect_expr(expr, ranges),
+                }
+            }
+        }
+        Expr::Matches { args, .. } => collect_expr(&args.get().value, ranges),
+        Expr::While {
+            condition, block, ..
+        } => {
+            collect_expr(condition, ranges);
+            push_range(ranges, &block.span(), None);
+            collect_code_block(block.get(), ranges);
+        }
+        Expr::Loop { block, .. } => {
+            push_range(ranges, &block.span(), None);
+            collect_code_block(block.get(), ranges);
+        }
+        Expr::For {
+            iterator, block, ..
+        } => {
+            collect_expr(iterator, ranges);
+            push_range(ranges, &block.span(), None);
+            collect_code_block(block.get(), ranges);
+        }
+        Expr::FuncApp { func, args } => {
+            collect_expr(func, ranges);
+            push_range(ranges, &args.span(), None);
+            collect_exprs(args.get().into_iter(), ranges);
+        }
+        Expr::MethodCall {
+            target,
+            contract_args_opt,
+            args,
+            ..
+        } => {
+            collect_expr(target, ranges);
+            if let Some(contract_args) = contract_args_opt {
+                collect_exprs(
+                    contract_args
+                        .get()
+                        .into_iter()
+                        .filter_map(|field| field.expr_opt.as_ref().map(|(_, expr)| &**expr)),
+                    ranges,
+                );
+            }
+            push_range(ranges, &args.span(), None);
+            collect_exprs(args.get().into_iter(), ranges);
+        }
+        Expr::Index { target, arg } => {
+            collect_expr(target, ranges);
+            collect_expr(arg.get(), ranges);
+        }
+        Expr::Return { expr_opt, .. } | Expr::Break { expr_opt, .. } => {
+            if let Some(expr) = expr_opt {
+                collect_expr(expr, ranges);
+            }
+        }
+        Expr::FieldProjection { target: expr, .. }
+        | Expr::TupleFieldProjection { target: expr, .. }
+        | Expr::Try { expr, .. }
+        | Expr::Ref { expr, .. }
+        | Expr::Deref { expr, .. }
+        | Expr::Not { expr, .. }
+        | Expr::Reassignment { expr, .. } => collect_expr(expr, ranges),
+        Expr::Mul { lhs, rhs, .. }
+        | Expr::Div { lhs, rhs, .. }
+        | Expr::Pow { lhs, rhs, .. }
+        | Expr::Modulo { lhs, rhs, .. }
+        | Expr::Add { lhs, rhs, .. }
+        | Expr::Sub { lhs, rhs, .. }
+        | Expr::Shl { lhs, rhs, .. }
+        | Expr::Shr { lhs, rhs, .. }
+        | Expr::BitAnd { lhs, rhs, .. }
+        | Expr::BitXor { lhs, rhs, .. }
+        | Expr::BitOr { lhs, rhs, .. }
+        | Expr::Equal { lhs, rhs, .. }
+        | Expr::NotEqual { lhs, rhs, .. }
+        | Expr::LessThan { lhs, rhs, .. }
+        | Expr::GreaterThan { lhs, rhs, .. }
+        | Expr::LessThanEq { lhs, rhs, .. }
+        | Expr::GreaterThanEq { lhs, rhs, .. }
+        | Expr::LogicalAnd { lhs, rhs, .. }
+        | Expr::LogicalOr { lhs, rhs, .. } => {
+            collect_expr(lhs, ranges);
+            collect_expr(rhs, ranges);
+        }
+    }
+}
+
+/// Adds a [FoldingRangeKind::Comment] range for every multi-line block comment and every run
+/// of comments on consecutive lines, including doc comments.
+///
+/// Comments that trail or are inlined in code are left out, as folding them would hide the code.
+fn collect_comments(token_stream: &CommentedTokenStream, ranges: &mut Vec<FoldingRange>) {
+    let mut run: Option<Span> = None;
+    for token_tree in token_stream.token_trees() {
+        let comment_span = match token_tree {
+            CommentedTokenTree::Comment(comment)
+                if !matches!(
+                    comment.comment_kind,
+                    CommentKind::Trailing | CommentKind::Inlined
+                ) =>
+            {
+                Some(comment.span())
+            }
+            CommentedTokenTree::Tree(CommentedTree::DocComment(doc_comment)) => {
+                Some(doc_comment.span())
+            }
+            _ => None,
+        };
+        run = match (run.take(), comment_span) {
+            (Some(run), Some(span))
+                if span.start_pos().line_col().line == run.end_pos().line_col().line + 1 =>
+            {
+                Some(Span::join(run, &span))
+            }
+            (run, span) => {
+                if let Some(run) = run {
+                    push_range(ranges, &run, Some(FoldingRangeKind::Comment));
+                }
+                span
+            }
+        };
+        if let CommentedTokenTree::Tree(CommentedTree::Group(group)) = token_tree {
+            collect_comments(&group.token_stream, ranges);
+        }
+    }
+    if let Some(run) = run {
+        push_range(ranges, &run, Some(FoldingRangeKind::Comment));
+    }
+}
@@ -80,10 +80,10 @@ pub fn hover_data(
     })
 }
 
-fn visibility_as_str(visibility: Visibility) -> &'static str {
+fn visibility_as_str(visibility: &Visibility) -> String {
     match visibility {
-        Visibility::Private => "",
-        Visibility::Public => "pub ",
+        Visibility::Private => String::new(),
+        Visibility::Public | Visibility::Restricted(_) => format!("{visibility} "),
     }
 }
 
@@ -104,7 +104,7 @@ fn format_doc_attributes(engines: &Engines, token: &Token) -> String {
     doc_comment
 }
 
-fn format_visibility_hover(visibility: Visibility, decl_name: &str, token_name: &str) -> String {
+fn format_visibility_hover(visibility: &Visibility, decl_name: &str, token_name: &str) -> String {
     format!(
         "{}{} {}",
         visibility_as_str(visibility),
@@ -161,7 +161,7 @@ fn hover_format(
                     let struct_decl = decl_engine.get_struct(decl_id);
                     hover_link_contents.add_implementations_for_decl(decl);
                     Some(format_visibility_hover(
-                        struct_decl.visibility,
+                        &struct_decl.visibility,
                         decl.friendly_type_name(),
                         ident_name,
                     ))
@@ -170,7 +170,7 @@ fn hover_format(
                     let trait_decl = decl_engine.get_trait(decl_id);
                     hover_link_contents.add_implementations_for_trait(&trait_decl);
                     Some(format_visibility_hover(
-                        trait_decl.visibility,
+                        &trait_decl.visibility,
                         decl.friendly_type_name(),
                         ident_name,
                     ))
@@ -179,7 +179,7 @@ fn hover_format(
                     let enum_decl = decl_engine.get_enum(decl_id);
                     hover_link_contents.add_implementations_for_decl(decl);
                     Some(format_visibility_hover(
-                        enum_decl.visibility,
+                        &enum_decl.visibility,
                         decl.friendly_type_name(),
                         ident_name,
                     ))
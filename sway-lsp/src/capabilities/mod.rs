@@ -3,6 +3,7 @@ pub mod code_lens;
 pub mod completion;
 pub mod diagnostic;
 pub mod document_symbol;
+pub mod folding_range;
 pub mod formatting;
 pub mod highlight;
 pub mod hover;
@@ -11,6 +12,7 @@ pub mod on_enter;
 pub mod rename;
 pub mod runnable;
 pub mod semantic_tokens;
+pub mod signature_help;
 
 pub(crate) use code_actions::code_actions;
 pub(crate) use on_enter::on_enter;
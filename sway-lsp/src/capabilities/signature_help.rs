@@ -0,0 +1,373 @@
+use crate::core::{
+    document::TextDocument,
+    session::Session,
+    token::{TokenIdent, TypedAstToken},
+};
+use lsp_types::{
+    Documentation, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel, Position,
+    SignatureHelp, SignatureInformation, Url,
+};
+use std::sync::Arc;
+use sway_core::{
+    language::ty::{TyExpressionVariant, TyFunctionDecl, TyFunctionParameter},
+    transform::AttributeKind,
+    Engines,
+};
+use sway_types::Spanned;
+
+/// The call whose argument list encloses the cursor.
+#[derive(Debug, PartialEq, Eq)]
+struct EnclosingCall {
+    /// The position of the first character of the name of the called function.
+    callee_position: Position,
+    /// Whether the function is called using the method call syntax, e.g. `a.foo(b)`.
+    is_method_call: bool,
+    /// The zero-based index of the argument the cursor is in.
+    active_argument: u32,
+}
+
+/// Returns the signature of the function whose argument list encloses the cursor.
+///
+/// The active parameter is determined by counting the commas between the opening
+/// parenthesis of the call and the cursor. If the called function is a method, the
+/// signatures of all the methods with the same name implemented for the same type
+/// are returned, with the resolved method being the active signature.
+pub fn signature_help(
+    session: Arc<Session>,
+    text_document: &TextDocument,
+    uri: &Url,
+    position: Position,
+) -> Option<SignatureHelp> {
+    let _p = tracing::trace_span!("signature_help").entered();
+    let call = enclosing_call(text_document, position)?;
+    let engines = session.engines.read();
+    let token_map = session.token_map();
+
+    let t = token_map.token_at_position(uri, call.callee_position)?;
+    let (ident, token) = t.pair();
+    // The declaration is preferred over the function referenced by the typed call,
+    // because the latter is monomorphized and loses the type parameters.
+    let declared = token
+        .declared_token_ident(&engines)
+        .and_then(|decl_ident| token_map.try_get(&decl_ident).try_unwrap())
+        .and_then(|decl_token| match &decl_token.value().typed {
+            Some(TypedAstToken::TypedFunctionDeclaration(fn_decl)) => Some(fn_decl.clone()),
+            _ => None,
+        });
+    let resolved = declared.or_else(|| match &token.typed {
+        Some(TypedAstToken::TypedExpression(expr)) => match &expr.expression {
+            TyExpressionVariant::FunctionApplication { fn_ref, .. } => {
+                Some((*engines.de().get_function(fn_ref)).clone())
+            }
+            _ => None,
+        },
+        Some(TypedAstToken::TypedFunctionDeclaration(fn_decl)) => Some(fn_decl.clone()),
+        _ => None,
+    });
+
+    let is_method = |fn_decl: &TyFunctionDecl| fn_decl.implementing_type.is_some();
+    let mut candidates: Vec<TyFunctionDecl> = match &resolved {
+        Some(fn_decl) if !is_method(fn_decl) => vec![fn_decl.clone()],
+        _ => {
+            if resolved.is_none() && !call.is_method_call {
+                return None;
+            }
+            // Methods with the same name can be implemented for a type by different traits,
+            // so all of them are candidates of the call.
+            let implementing_for = |fn_decl: &TyFunctionDecl| {
+                fn_decl
+                    .implementing_for_typeid
+                    .map(|type_id| engines.help_out(type_id).to_string())
+            };
+            let resolved_implementing_for = resolved.as_ref().and_then(implementing_for);
+            let mut methods: Vec<(TokenIdent, TyFunctionDecl)> = token_map
+                .tokens_for_name(&ident.name)
+                .filter_map(|item| match &item.value().typed {
+                    Some(TypedAstToken::TypedFunctionDeclaration(fn_decl))
+                        if is_method(fn_decl)
+                            && (resolved_implementing_for.is_none()
+                                || implementing_for(fn_decl) == resolved_implementing_for) =>
+                    {
+                        Some((item.key().clone(), fn_decl.clone()))
+                    }
+                    _ => None,
+                })
+                .collect();
+            methods
+                .sort_by(|(a, _), (b, _)| (&a.path, a.range.start).cmp(&(&b.path, b.range.start)));
+            methods.dedup_by(|(a, _), (b, _)| a == b);
+            methods.into_iter().map(|(_, fn_decl)| fn_decl).collect()
+        }
+    };
+    if candidates.is_empty() {
+        candidates.extend(resolved.clone());
+    }
+
+    let active_signature = resolved
+        .as_ref()
+        .and_then(|resolved| {
+            candidates
+                .iter()
+                .position(|fn_decl| fn_decl.span == resolved.span)
+        })
+        .unwrap_or_default();
+    let signatures = candidates
+        .iter()
+        .map(|fn_decl| signature_information(&engines, fn_decl, &call))
+        .collect::<Vec<_>>();
+    let active_parameter = signatures
+        .get(active_signature)
+        .and_then(|signature| signature.active_parameter);
+
+    Some(SignatureHelp {
+        signatures,
+        active_signature: Some(active_signature as u32),
+        active_parameter,
+    })
+}
+
+fn signature_information(
+    engines: &Engines,
+    fn_decl: &TyFunctionDecl,
+    call: &EnclosingCall,
+) -> SignatureInformation {
+    let mut label = format!("fn {}", fn_decl.name);
+    // The type parameters are taken from the declaration as written, because the typed
+    // declaration of a method also contains the type parameters of its `impl` block.
+    if let Some(type_parameters) = declared_type_parameters(fn_decl) {
+        label.push_str(type_parameters);
+    }
+    label.push('(');
+    let doc_comment = doc_comment(fn_decl);
+    let mut parameters = vec![];
+    for (i, param) in fn_decl.parameters.iter().enumerate() {
+        if i > 0 {
+            label.push_str(", ");
+        }
+        // The offsets are in UTF-16 code units, like all the character offsets in LSP.
+        let start = label.encode_utf16().count() as u32;
+        label.push_str(&parameter_label(engines, param));
+        let end = label.encode_utf16().count() as u32;
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: doc_comment
+                .as_deref()
+                .and_then(|doc_comment| parameter_doc_comment(doc_comment, param.name.as_str()))
+                .map(|value| {
+                    Documentation::MarkupContent(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value,
+                    })
+                }),
+        });
+    }
+    label.push(')');
+    let return_type = engines.help_out(fn_decl.return_type.type_id).to_string();
+    if return_type != "()" {
+        label.push_str(&format!(" -> {return_type}"));
+    }
+
+    // The `self` parameter is not part of the argument list when using the method call syntax.
+    let takes_self = fn_decl
+        .parameters
+        .first()
+        .is_some_and(TyFunctionParameter::is_self);
+    let active_parameter = if call.is_method_call && takes_self {
+        call.active_argument + 1
+    } else {
+        call.active_argument
+    };
+
+    SignatureInformation {
+        label,
+        documentation: doc_comment.map(|value| {
+            Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            })
+        }),
+        active_parameter: (active_parameter < parameters.len() as u32).then_some(active_parameter),
+        parameters: Some(parameters),
+    }
+}
+
+/// Returns the type parameters of the function as written in its declaration, e.g. `<T, U>`.
+fn declared_type_parameters(fn_decl: &TyFunctionDecl) -> Option<&str> {
+    let name_end = fn_decl
+        .name
+        .span()
+        .end()
+        .checked_sub(fn_decl.span.start())?;
+    let after_name = fn_decl.span.as_str().get(name_end..)?;
+    let type_parameters = &after_name[..after_name.find('(')?];
+    type_parameters
+        .trim()
+        .starts_with('<')
+        .then_some(type_parameters.trim())
+}
+
+fn parameter_label(engines: &Engines, param: &TyFunctionParameter) -> String {
+    let prefix = match (param.is_reference, param.is_mutable) {
+        (true, true) => "ref mut ",
+        (false, true) => "mut ",
+        _ => "",
+    };
+    if param.is_self() {
+        format!("{prefix}self")
+    } else {
+        format!(
+            "{prefix}{}: {}",
+            param.name,
+            engines.help_out(param.type_argument.type_id)
+        )
+    }
+}
+
+fn doc_comment(fn_decl: &TyFunctionDecl) -> Option<String> {
+    let attributes = fn_decl.attributes.get(&AttributeKind::DocComment)?;
+    let doc_comment = attributes.iter().fold(String::new(), |output, attribute| {
+        let comment = attribute.args.first().unwrap().name.as_str();
+        format!("{output}{comment}\n")
+    });
+    Some(doc_comment)
+}
+
+/// Returns the documentation of the parameter `name` from the `# Arguments` section of the
+/// function's doc comment, written as in the standard library, e.g.:
+///
+/// ```text
+/// # Arguments
+///
+/// * `amount`: [u64] - The amount to transfer.
+/// ```
+fn parameter_doc_comment(doc_comment: &str, name: &str) -> Option<String> {
+    let bullet = format!("* `{name}`");
+    doc_comment
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| *line != "# Arguments")
+        .skip(1)
+        .take_while(|line| !line.starts_with('#'))
+        .find_map(|line| line.strip_prefix(bullet.as_str()))
+        .map(|doc| doc.trim_start_matches(':').trim().to_string())
+}
+
+/// Finds the innermost call whose argument list encloses the given position by scanning
+/// the text of the document up to that position.
+fn enclosing_call(text_document: &TextDocument, position: Position) -> Option<EnclosingCall> {
+    let mut chars: Vec<(char, Position)> = vec![];
+    for line in 0..=position.line {
+        let text = text_document.get_line(line as usize);
+        for (character, c) in text.chars().enumerate() {
+            if line == position.line && character as u32 >= position.character {
+                break;
+            }
+            let char_position = Position::new(line, character as u32);
+            chars.push((c, char_position));
+        }
+    }
+
+    struct OpenDelimiter {
+        delimiter: char,
+        index: usize,
+        commas: u32,
+    }
+    let mut open_delimiters: Vec<OpenDelimiter> = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i].0;
+        let next = chars.get(i + 1).map(|(c, _)| *c);
+        match (c, next) {
+            ('/', Some('/')) => {
+                while i < chars.len() && chars[i].0 != '\n' {
+                    i += 1;
+                }
+            }
+            ('/', Some('*')) => {
+                i += 2;
+                while i < chars.len()
+                    && !(chars[i].0 == '*' && chars.get(i + 1).map(|(c, _)| *c) == Some('/'))
+                {
+                    i += 1;
+                }
+                i += 1;
+            }
+            ('"', _) => {
+                i += 1;
+                while i < chars.len() && chars[i].0 != '"' {
+                    if chars[i].0 == '\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            ('(' | '[' | '{', _) => open_delimiters.push(OpenDelimiter {
+                delimiter: c,
+                index: i,
+                commas: 0,
+            }),
+            (')' | ']' | '}', _) => {
+                open_delimiters.pop();
+            }
+            (',', _) => {
+                if let Some(open_delimiter) = open_delimiters.last_mut() {
+                    open_delimiter.commas += 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    // A code block in between the call and the cursor means the cursor is not
+    // in the argument list of the call.
+    let open_paren = open_delimiters
+        .iter()
+        .rev()
+        .take_while(|open_delimiter| open_delimiter.delimiter != '{')
+        .find(|open_delimiter| open_delimiter.delimiter == '(')?;
+
+    let mut i = open_paren.index;
+    let skip_whitespace = |i: &mut usize| {
+        while *i > 0 && chars[*i - 1].0.is_whitespace() {
+            *i -= 1;
+        }
+    };
+    skip_whitespace(&mut i);
+    // Skip the type arguments of the call, e.g. `foo::<u64>(...)`.
+    if i > 0 && chars[i - 1].0 == '>' {
+        let mut depth = 0;
+        while i > 0 {
+            i -= 1;
+            match chars[i].0 {
+                '>' => depth += 1,
+                '<' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                break;
+            }
+        }
+        if i < 2 || chars[i - 1].0 != ':' || chars[i - 2].0 != ':' {
+            return None;
+        }
+        i -= 2;
+        skip_whitespace(&mut i);
+    }
+    let callee_end = i;
+    while i > 0 && (chars[i - 1].0.is_alphanumeric() || chars[i - 1].0 == '_') {
+        i -= 1;
+    }
+    if i == callee_end || chars[i].0.is_numeric() {
+        return None;
+    }
+    let callee_position = chars[i].1;
+    skip_whitespace(&mut i);
+    let is_method_call = i > 0 && chars[i - 1].0 == '.';
+
+    Some(EnclosingCall {
+        callee_position,
+        is_method_call,
+        active_argument: open_paren.commas,
+    })
+}
@@ -93,7 +93,7 @@ impl TextDocument {
         Ok(())
     }
 
-    fn position_to_index(&self, position: Position) -> usize {
+    pub(crate) fn position_to_index(&self, position: Position) -> usize {
         let line_offset = self
             .line_offsets
             .get(position.line as usize)
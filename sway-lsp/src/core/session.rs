@@ -7,7 +7,7 @@ use crate::{
     core::{
         document::{Documents, TextDocument},
         sync::SyncWorkspace,
-        token::{self, TypedAstToken},
+        token::{self, TokenIdent, TypedAstToken},
         token_map::{TokenMap, TokenMapExt},
     },
     error::{DirectoryError, DocumentError, LanguageServerError},
@@ -18,9 +18,7 @@ use crate::{
 };
 use dashmap::DashMap;
 use forc_pkg as pkg;
-use lsp_types::{
-    CompletionItem, GotoDefinitionResponse, Location, Position, Range, SymbolInformation, Url,
-};
+use lsp_types::{CompletionItem, GotoDefinitionResponse, Location, Position, Range, Url};
 use parking_lot::RwLock;
 use pkg::{
     manifest::{GenericManifestFile, ManifestFile},
@@ -191,21 +189,46 @@ impl Session {
         &self,
         uri: &Url,
         position: Position,
+        text_document: &TextDocument,
     ) -> Option<GotoDefinitionResponse> {
         let _p = tracing::trace_span!("token_definition_response").entered();
-        self.token_map
-            .token_at_position(uri, position)
-            .and_then(|item| item.value().declared_token_ident(&self.engines.read()))
-            .and_then(|decl_ident| {
-                decl_ident.path.and_then(|path| {
-                    // We use ok() here because we don't care about propagating the error from from_file_path
-                    Url::from_file_path(path).ok().and_then(|url| {
-                        self.sync.to_workspace_url(url).map(|url| {
-                            GotoDefinitionResponse::Scalar(Location::new(url, decl_ident.range))
-                        })
-                    })
+        let engines = self.engines.read();
+        let location = |ident: TokenIdent| {
+            ident.path.and_then(|path| {
+                // We use ok() here because we don't care about propagating the error from from_file_path
+                Url::from_file_path(path).ok().and_then(|url| {
+                    self.sync
+                        .to_workspace_url(url)
+                        .map(|url| Location::new(url, ident.range))
                 })
             })
+        };
+        let (ident, token) = self
+            .token_map
+            .token_at_position(uri, position)
+            .map(|item| (item.key().clone(), item.value().clone()))?;
+        let definition = location(token.declared_token_ident(&engines)?)?;
+        // Names imported through aliases and re-exports resolve to the original declaration,
+        // and the `use` statements along the way are offered as alternative locations.
+        // Segments of paths like `a::B` are resolved through the path and not through the
+        // imports, even if the file also imports the same declaration under the same name.
+        let is_import = matches!(token.typed, Some(TypedAstToken::TypedUseStatement(_)));
+        let imports: Vec<_> = if is_import || !is_path_segment(text_document, &ident.range) {
+            self.token_map
+                .import_chain(&engines, &ident, &token)
+                .into_iter()
+                .filter_map(location)
+                .collect()
+        } else {
+            vec![]
+        };
+        if imports.is_empty() {
+            Some(GotoDefinitionResponse::Scalar(definition))
+        } else {
+            Some(GotoDefinitionResponse::Array(
+                std::iter::once(definition).chain(imports).collect(),
+            ))
+        }
     }
 
     pub fn completion_items(
@@ -248,14 +271,6 @@ impl Session {
         Some(program.root.namespace)
     }
 
-    pub fn symbol_information(&self, url: &Url) -> Option<Vec<SymbolInformation>> {
-        let _p = tracing::trace_span!("symbol_information").entered();
-        let tokens = self.token_map.tokens_for_file(url);
-        self.sync
-            .to_workspace_url(url.clone())
-            .map(|url| capabilities::document_symbol::to_symbol_information(tokens, &url))
-    }
-
     /// Populate [Documents] with sway files found in the workspace.
     async fn store_sway_files(&self, documents: &Documents) -> Result<(), LanguageServerError> {
         let temp_dir = self.sync.temp_dir()?;
@@ -293,6 +308,18 @@ pub fn build_plan(uri: &Url) -> Result<BuildPlan, LanguageServerError> {
         .map_err(LanguageServerError::BuildPlanFailed)
 }
 
+/// Returns `true` if the identifier at `range` is preceded or followed by `::`,
+/// e.g. `a` and `B` in `a::B`, but not `f` in `f::<u64>()`.
+fn is_path_segment(text_document: &TextDocument, range: &Range) -> bool {
+    let line = text_document.get_line(range.start.line as usize);
+    let before = line
+        .get(..range.start.character as usize)
+        .unwrap_or_default();
+    let after = line.get(range.end.character as usize..).unwrap_or_default();
+    let after = after.trim_start();
+    before.trim_end().ends_with("::") || (after.starts_with("::") && !after.starts_with("::<"))
+}
+
 pub fn compile(
     build_plan: &BuildPlan,
     engines: &Engines,
@@ -8,8 +8,13 @@ use dashmap::{
     DashMap,
 };
 use lsp_types::{Position, Url};
-use std::{path::PathBuf, thread, time::Duration};
-use sway_core::{engine_threading::SpannedWithEngines, language::ty, type_system::TypeId, Engines};
+use std::{collections::HashMap, path::PathBuf, thread, time::Duration};
+use sway_core::{
+    engine_threading::SpannedWithEngines,
+    language::{parsed::ImportType, ty},
+    type_system::TypeId,
+    Engines,
+};
 use sway_types::Ident;
 
 // Re-export the TokenMapExt trait.
@@ -236,6 +241,90 @@ impl<'a> TokenMap {
             })
     }
 
+    /// Returns the idents of the `use` statements the token was imported through, ordered from
+    /// the token's own file towards its declaration.
+    ///
+    /// The chain starts with the import of the token's name in the token's file and follows the
+    /// modules the item is imported from for as long as they re-export the item instead of
+    /// declaring it. If the token is an imported item itself, its own import is left out.
+    pub fn import_chain(
+        &self,
+        engines: &Engines,
+        ident: &TokenIdent,
+        token: &Token,
+    ) -> Vec<TokenIdent> {
+        let (Some(path), Some(decl_ident)) = (&ident.path, token.declared_token_ident(engines))
+        else {
+            return vec![];
+        };
+        let imports = self.imports_of_decl(engines, &decl_ident);
+        let import_of_name = |path: &PathBuf, name: &str| {
+            imports.get(path).and_then(|imports| {
+                imports
+                    .iter()
+                    .find(|(import_ident, _)| import_ident.name == name)
+                    .cloned()
+            })
+        };
+        let mut chain: Vec<TokenIdent> = vec![];
+        let mut import = import_of_name(path, &ident.name);
+        while let Some((import_ident, use_statement)) = import {
+            if chain.contains(&import_ident) {
+                break;
+            }
+            if &import_ident != ident {
+                chain.push(import_ident);
+            }
+            let ImportType::Item(item) = &use_statement.import_type else {
+                break;
+            };
+            // The last segment of the call path is the module the item is imported from.
+            import = use_statement
+                .call_path
+                .last()
+                .and_then(|module| {
+                    self.try_get(&TokenIdent::new(module, engines.se()))
+                        .try_unwrap()
+                })
+                .and_then(|module| module.value().declared_token_ident(engines))
+                .and_then(|module| module.path)
+                .and_then(|path| import_of_name(&path, item.as_str()));
+        }
+        chain
+    }
+
+    /// Returns the idents and the [ty::TyUseStatement]s of the item imports that bind a name to
+    /// the declaration at `decl_ident`, grouped by the file they are in.
+    fn imports_of_decl(
+        &self,
+        engines: &Engines,
+        decl_ident: &TokenIdent,
+    ) -> HashMap<PathBuf, Vec<(TokenIdent, ty::TyUseStatement)>> {
+        let mut imports: HashMap<_, Vec<_>> = HashMap::new();
+        for entry in self.iter() {
+            let (ident, token) = entry.pair();
+            let Some(TypedAstToken::TypedUseStatement(use_statement)) = &token.typed else {
+                continue;
+            };
+            let ImportType::Item(item) = &use_statement.import_type else {
+                continue;
+            };
+            let imported_name = use_statement.alias.as_ref().unwrap_or(item);
+            let Some(path) = &ident.path else {
+                continue;
+            };
+            if imported_name.as_str() == ident.name
+                && token.declared_token_ident(engines).as_ref() == Some(decl_ident)
+            {
+                imports
+                    .entry(path.clone())
+                    .or_default()
+                    .push((ident.clone(), use_statement.clone()));
+            }
+        }
+        imports
+    }
+
     /// Remove all tokens for the given file from the token map.
     pub fn remove_tokens_for_file(&self, path_to_remove: &PathBuf) {
         self.0.retain(|key, _value| {
@@ -61,14 +61,19 @@ pub async fn handle_document_symbol(
     state: &ServerState,
     params: lsp_types::DocumentSymbolParams,
 ) -> Result<Option<lsp_types::DocumentSymbolResponse>> {
-    let _ = state.wait_for_parsing().await;
     match state
         .uri_and_session_from_workspace(&params.text_document.uri)
         .await
     {
-        Ok((uri, session)) => Ok(session
-            .symbol_information(&uri)
-            .map(DocumentSymbolResponse::Flat)),
+        Ok((uri, _)) => match state.documents.get_text_document(&uri) {
+            Ok(text_document) => Ok(Some(DocumentSymbolResponse::Nested(
+                capabilities::document_symbol::document_symbols(&text_document),
+            ))),
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        },
         Err(err) => {
             tracing::error!("{}", err.to_string());
             Ok(None)
@@ -84,10 +89,16 @@ pub async fn handle_goto_definition(
         .uri_and_session_from_workspace(&params.text_document_position_params.text_document.uri)
         .await
     {
-        Ok((uri, session)) => {
-            let position = params.text_document_position_params.position;
-            Ok(session.token_definition_response(&uri, position))
-        }
+        Ok((uri, session)) => match state.documents.get_text_document(&uri) {
+            Ok(text_document) => {
+                let position = params.text_document_position_params.position;
+                Ok(session.token_definition_response(&uri, position, &text_document))
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        },
         Err(err) => {
             tracing::error!("{}", err.to_string());
             Ok(None)
@@ -268,6 +279,7 @@ pub async fn handle_code_action(
             &params.text_document.uri,
             &temp_uri,
             &params.context.diagnostics,
+            state.documents.get_text_document(&temp_uri).ok().as_ref(),
         )),
         Err(err) => {
             tracing::error!("{}", err.to_string());
@@ -358,6 +370,60 @@ pub async fn handle_inlay_hints(
     }
 }
 
+pub async fn handle_signature_help(
+    state: &ServerState,
+    params: lsp_types::SignatureHelpParams,
+) -> Result<Option<lsp_types::SignatureHelp>> {
+    match state
+        .uri_and_session_from_workspace(&params.text_document_position_params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => {
+            let position = params.text_document_position_params.position;
+            match state.documents.get_text_document(&uri) {
+                Ok(text_document) => Ok(capabilities::signature_help::signature_help(
+                    session,
+                    &text_document,
+                    &uri,
+                    position,
+                )),
+                Err(err) => {
+                    tracing::error!("{}", err.to_string());
+                    Ok(None)
+                }
+            }
+        }
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+pub async fn handle_folding_range(
+    state: &ServerState,
+    params: lsp_types::FoldingRangeParams,
+) -> Result<Option<Vec<lsp_types::FoldingRange>>> {
+    match state
+        .uri_and_session_from_workspace(&params.text_document.uri)
+        .await
+    {
+        Ok((uri, _)) => match state.documents.get_text_document(&uri) {
+            Ok(text_document) => Ok(Some(capabilities::folding_range::folding_ranges(
+                &text_document,
+            ))),
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        },
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
 /// This method is triggered by a command palette request in VScode
 /// The 3 commands are: "show lexed ast", "show parsed ast" or "show typed ast"
 ///
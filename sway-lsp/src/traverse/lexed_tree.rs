@@ -135,6 +135,11 @@ impl Parse for Expr {
             Expr::Parens(parens) => {
                 parens.get().parse(ctx);
             }
+            Expr::TypeAscription(ascription) => {
+                let ascription = ascription.get();
+                ascription.expr.parse(ctx);
+                ascription.ty.parse(ctx);
+            }
             Expr::Block(block) => {
                 block.get().parse(ctx);
             }
@@ -165,6 +170,15 @@ impl Parse for Expr {
                     branch.kind.parse(ctx);
                 });
             }
+            Expr::Matches { args, .. } => {
+                let args = args.get();
+                args.value.parse(ctx);
+                args.pattern.parse(ctx);
+                if let Some((if_token, guard)) = &args.guard_opt {
+                    insert_keyword(ctx, if_token.span());
+                    guard.parse(ctx);
+                }
+            }
             Expr::While {
                 while_token,
                 condition,
@@ -210,6 +224,9 @@ impl Parse for Expr {
             Expr::TupleFieldProjection { target, .. } => {
                 target.parse(ctx);
             }
+            Expr::Try { expr, .. } => {
+                expr.parse(ctx);
+            }
             Expr::Ref {
                 mut_token, expr, ..
             } => {
@@ -252,8 +269,18 @@ impl Parse for Expr {
                 assignable.parse(ctx);
                 expr.parse(ctx);
             }
-            Expr::Break { break_token } => {
+            Expr::Loop { loop_token, block } => {
+                insert_keyword(ctx, loop_token.span());
+                block.get().parse(ctx);
+            }
+            Expr::Break {
+                break_token,
+                expr_opt,
+            } => {
                 insert_keyword(ctx, break_token.span());
+                if let Some(expr) = expr_opt {
+                    expr.parse(ctx);
+                }
             }
             Expr::Continue { continue_token } => {
                 insert_keyword(ctx, continue_token.span());
@@ -276,7 +303,7 @@ impl Parse for ItemUse {
 impl Parse for ItemStruct {
     fn parse(&self, ctx: &ParseContext) {
         if let Some(visibility) = &self.visibility {
-            insert_keyword(ctx, visibility.span());
+            insert_keyword(ctx, visibility.pub_token.span());
         }
         insert_keyword(ctx, self.struct_token.span());
 
@@ -295,7 +322,7 @@ impl Parse for ItemStruct {
 impl Parse for ItemEnum {
     fn parse(&self, ctx: &ParseContext) {
         if let Some(visibility) = &self.visibility {
-            insert_keyword(ctx, visibility.span());
+            insert_keyword(ctx, visibility.pub_token.span());
         }
         insert_keyword(ctx, self.enum_token.span());
 
@@ -497,6 +524,9 @@ impl Parse for TypeField {
             insert_keyword(ctx, visibility.span());
         }
         self.ty.parse(ctx);
+        if let Some((_eq_token, default)) = &self.default_opt {
+            default.parse(ctx);
+        }
     }
 }
 
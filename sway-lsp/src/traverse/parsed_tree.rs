@@ -12,8 +12,9 @@ use crate::{
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use sway_core::{
     compiler_generated::{
-        is_generated_any_match_expression_var_name, is_generated_destructured_struct_var_name,
-        is_generated_tuple_var_name,
+        is_generated_any_match_expression_var_name, is_generated_destructured_slice_var_name,
+        is_generated_destructured_struct_var_name, is_generated_tuple_var_name,
+        is_generated_type_ascription_var_name,
     },
     decl_engine::parsed_id::ParsedDeclId,
     language::{
@@ -24,14 +25,14 @@ use sway_core::{
             EnumVariant, Expression, ExpressionKind, ForLoopExpression,
             FunctionApplicationExpression, FunctionDeclaration, FunctionParameter, IfExpression,
             ImplItem, ImplSelfOrTrait, ImportType, IncludeStatement, IntrinsicFunctionExpression,
-            LazyOperatorExpression, MatchExpression, MethodApplicationExpression, MethodName,
-            ParseModule, ParseProgram, ParseSubmodule, QualifiedPathType, ReassignmentExpression,
-            ReassignmentTarget, RefExpression, Scrutinee, StorageAccessExpression,
-            StorageDeclaration, StorageEntry, StorageField, StorageNamespace, StructDeclaration,
-            StructExpression, StructExpressionField, StructField, StructScrutineeField,
-            SubfieldExpression, Supertrait, TraitDeclaration, TraitFn, TraitItem,
-            TraitTypeDeclaration, TupleIndexExpression, TypeAliasDeclaration, UseStatement,
-            VariableDeclaration, WhileLoopExpression,
+            LazyOperatorExpression, LoopExpression, MatchExpression, MethodApplicationExpression,
+            MethodName, ParseModule, ParseProgram, ParseSubmodule, QualifiedPathType,
+            ReassignmentExpression, ReassignmentTarget, RefExpression, Scrutinee,
+            StorageAccessExpression, StorageDeclaration, StorageEntry, StorageField,
+            StorageNamespace, StructDeclaration, StructExpression, StructExpressionField,
+            StructField, StructScrutineeField, SubfieldExpression, Supertrait, TraitDeclaration,
+            TraitFn, TraitItem, TraitTypeDeclaration, TupleIndexExpression, TypeAliasDeclaration,
+            UseStatement, VariableDeclaration, WhileLoopExpression,
         },
         CallPathTree, HasSubmodules, Literal,
     },
@@ -212,7 +213,9 @@ impl Parse for Expression {
             }
             ExpressionKind::Variable(name) => {
                 if !(is_generated_tuple_var_name(name.as_str())
-                    || is_generated_any_match_expression_var_name(name.as_str()))
+                    || is_generated_destructured_slice_var_name(name.as_str())
+                    || is_generated_any_match_expression_var_name(name.as_str())
+                    || is_generated_type_ascription_var_name(name.as_str()))
                 {
                     let symbol_kind = if is_generated_destructured_struct_var_name(name.as_str()) {
                         SymbolKind::Struct
@@ -272,6 +275,9 @@ impl Parse for Expression {
                     branch.result.parse(ctx);
                 });
             }
+            ExpressionKind::Try(try_expression) => {
+                try_expression.value.parse(ctx);
+            }
             ExpressionKind::Asm(asm) => {
                 adaptive_iter(&asm.registers, |register| {
                     if let Some(initializer) = &register.initializer {
@@ -347,6 +353,9 @@ impl Parse for Expression {
                 adaptive_iter(&body.contents, |node| node.parse(ctx));
                 condition.parse(ctx);
             }
+            ExpressionKind::Loop(LoopExpression { body }) => {
+                adaptive_iter(&body.contents, |node| node.parse(ctx));
+            }
             ExpressionKind::ForLoop(ForLoopExpression { desugared }) => {
                 desugared.parse(ctx);
             }
@@ -355,12 +364,13 @@ impl Parse for Expression {
             }
             ExpressionKind::ImplicitReturn(expr)
             | ExpressionKind::Return(expr)
+            | ExpressionKind::Break(Some(expr))
             | ExpressionKind::Ref(RefExpression { value: expr, .. })
             | ExpressionKind::Deref(expr) => {
                 expr.parse(ctx);
             }
             // We are collecting these tokens in the lexed phase.
-            ExpressionKind::Break | ExpressionKind::Continue => {}
+            ExpressionKind::Break(None) | ExpressionKind::Continue => {}
         }
     }
 }
@@ -522,11 +532,15 @@ impl Parse for Scrutinee {
                     .insert(ctx.ident(&Ident::new(span.clone())), token);
             }
             Scrutinee::Variable { name, .. } => {
-                ctx.tokens.insert(
-                    ctx.ident(name),
-                    // it could either be a variable or a constant
-                    Token::from_parsed(AstToken::Scrutinee(self.clone()), SymbolKind::Unknown),
-                );
+                // Don't collect tokens for the elements of a desugared `let` slice destructuring.
+                // The individual elements are handled in the subsequent VariableDeclaration's.
+                if !is_generated_destructured_slice_var_name(name.as_str()) {
+                    ctx.tokens.insert(
+                        ctx.ident(name),
+                        // it could either be a variable or a constant
+                        Token::from_parsed(AstToken::Scrutinee(self.clone()), SymbolKind::Unknown),
+                    );
+                }
             }
             Scrutinee::StructScrutinee {
                 struct_name,
@@ -565,6 +579,18 @@ impl Parse for Scrutinee {
             Scrutinee::Tuple { elems, .. } | Scrutinee::Or { elems, .. } => {
                 adaptive_iter(elems, |elem| elem.parse(ctx));
             }
+            Scrutinee::Slice {
+                prefix,
+                rest,
+                suffix,
+                ..
+            } => {
+                adaptive_iter(prefix, |elem| elem.parse(ctx));
+                if let Some(rest) = rest {
+                    rest.parse(ctx);
+                }
+                adaptive_iter(suffix, |elem| elem.parse(ctx));
+            }
             Scrutinee::Error { .. } => {
                 // FIXME: Left for @JoshuaBatty to use.
             }
@@ -673,7 +699,11 @@ impl Parse for ParsedDeclId<VariableDeclaration> {
         let var_decl = ctx.engines.pe().get_variable(self);
         // Don't collect tokens if the idents are generated tuple or match desugaring names.
         // The individual elements are handled in the subsequent VariableDeclaration's.
-        if !(is_generated_tuple_var_name(var_decl.name.as_str())
+        if is_generated_type_ascription_var_name(var_decl.name.as_str()) {
+            // Only the ascribed type of a desugared type ascription is written by the user.
+            var_decl.type_ascription.parse(ctx);
+        } else if !(is_generated_tuple_var_name(var_decl.name.as_str())
+            || is_generated_destructured_slice_var_name(var_decl.name.as_str())
             || is_generated_any_match_expression_var_name(var_decl.name.as_str()))
         {
             let symbol_kind = if is_generated_destructured_struct_var_name(var_decl.name.as_str()) {
@@ -1003,6 +1033,9 @@ impl Parse for StructField {
             Token::from_parsed(AstToken::StructField(self.clone()), SymbolKind::Field),
         );
         self.type_argument.parse(ctx);
+        if let Some(default_value) = &self.default_value {
+            default_value.parse(ctx);
+        }
         self.attributes.parse(ctx);
     }
 }
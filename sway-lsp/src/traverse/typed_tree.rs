@@ -121,41 +121,34 @@ impl Parse for ty::TySideEffect {
                 }
                 match &import_type {
                     ImportType::Item(item) => {
-                        if let Some(mut token) = ctx.tokens.try_get_mut_with_retry(&ctx.ident(item))
+                        let mut symbol_kind = SymbolKind::Unknown;
+                        let mut type_def = None;
+                        // The item is either declared in the module it is imported from or
+                        // re-exported by it, in which case the resolved declaration is the
+                        // original one at the end of the re-export chain.
+                        if let Some(decl_ident) = ctx
+                            .namespace
+                            .submodule(ctx.engines, call_path)
+                            .and_then(|module| module.current_items().resolve_symbol(item))
+                            .and_then(|decl| decl.expect_typed_ref().get_decl_ident(ctx.engines))
                         {
-                            token.typed =
-                                Some(TypedAstToken::TypedUseStatement(use_statement.clone()));
-                            let mut symbol_kind = SymbolKind::Unknown;
-                            let mut type_def = None;
-                            if let Some(decl_ident) = ctx
-                                .namespace
-                                .submodule(ctx.engines, call_path)
-                                .and_then(|module| module.current_items().symbols().get(item))
-                                .and_then(|decl| {
-                                    decl.expect_typed_ref().get_decl_ident(ctx.engines)
-                                })
+                            // Update the symbol kind to match the declarations symbol kind
+                            if let Some(decl) =
+                                ctx.tokens.try_get(&ctx.ident(&decl_ident)).try_unwrap()
                             {
-                                // Update the symbol kind to match the declarations symbol kind
-                                if let Some(decl) =
-                                    ctx.tokens.try_get(&ctx.ident(&decl_ident)).try_unwrap()
-                                {
-                                    symbol_kind = decl.value().kind.clone();
-                                }
-                                type_def = Some(TypeDefinition::Ident(decl_ident));
+                                symbol_kind = decl.value().kind.clone();
                             }
-                            token.kind = symbol_kind.clone();
-                            token.type_def.clone_from(&type_def);
-                            // the alias should take on the same symbol kind and type definition
-                            if let Some(alias) = alias {
-                                if let Some(mut token) =
-                                    ctx.tokens.try_get_mut_with_retry(&ctx.ident(alias))
-                                {
-                                    token.typed = Some(TypedAstToken::TypedUseStatement(
-                                        use_statement.clone(),
-                                    ));
-                                    token.kind = symbol_kind;
-                                    token.type_def = type_def;
-                                }
+                            type_def = Some(TypeDefinition::Ident(decl_ident));
+                        }
+                        // the alias should take on the same symbol kind and type definition
+                        for ident in std::iter::once(item).chain(alias) {
+                            if let Some(mut token) =
+                                ctx.tokens.try_get_mut_with_retry(&ctx.ident(ident))
+                            {
+                                token.typed =
+                                    Some(TypedAstToken::TypedUseStatement(use_statement.clone()));
+                                token.kind = symbol_kind.clone();
+                                token.type_def.clone_from(&type_def);
                             }
                         }
                     }
@@ -569,12 +562,16 @@ impl Parse for ty::TyExpression {
             ty::TyExpressionVariant::ForLoop { desugared, .. } => {
                 desugared.parse(ctx);
             }
-            ty::TyExpressionVariant::Break | ty::TyExpressionVariant::Continue => (),
+            ty::TyExpressionVariant::Loop { body } => {
+                adaptive_iter(&body.contents, |node| node.parse(ctx));
+            }
+            ty::TyExpressionVariant::Break(None) | ty::TyExpressionVariant::Continue => (),
             ty::TyExpressionVariant::Reassignment(reassignment) => {
                 reassignment.parse(ctx);
             }
             ty::TyExpressionVariant::ImplicitReturn(exp)
             | ty::TyExpressionVariant::Return(exp)
+            | ty::TyExpressionVariant::Break(Some(exp))
             | ty::TyExpressionVariant::Ref(exp)
             | ty::TyExpressionVariant::Deref(exp) => {
                 exp.parse(ctx);
@@ -906,6 +903,9 @@ impl Parse for ty::TyStructField {
             token.type_def = Some(TypeDefinition::Ident(self.name.clone()));
         }
         collect_type_argument(ctx, &self.type_argument);
+        if let Some(default_value) = &self.default_value {
+            default_value.parse(ctx);
+        }
     }
 }
 
@@ -981,7 +981,7 @@ impl Parse for ty::TyIntrinsicFunctionKind {
 impl Parse for ty::TyScrutinee {
     fn parse(&self, ctx: &ParseContext) {
         use ty::TyScrutineeVariant::{
-            CatchAll, Constant, EnumScrutinee, Literal, Or, StructScrutinee, Tuple, Variable,
+            CatchAll, Constant, EnumScrutinee, Literal, Or, Slice, StructScrutinee, Tuple, Variable,
         };
         match &self.variant {
             CatchAll => {}
@@ -1050,6 +1050,17 @@ impl Parse for ty::TyScrutinee {
             Tuple(scrutinees) | Or(scrutinees) => {
                 adaptive_iter(scrutinees, |s| s.parse(ctx));
             }
+            Slice {
+                prefix,
+                rest,
+                suffix,
+            } => {
+                adaptive_iter(prefix, |s| s.parse(ctx));
+                if let Some(rest) = rest {
+                    rest.parse(ctx);
+                }
+                adaptive_iter(suffix, |s| s.parse(ctx));
+            }
         }
     }
 }
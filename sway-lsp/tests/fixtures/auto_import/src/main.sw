@@ -32,8 +32,8 @@ impl DeepTrait for LocalStruct {
     fn deep_method(self) {}
 }
 
-impl TryFrom<u32> for LocalStruct {
-    fn try_from(u: u32) -> Option<Self> {
-        None
+impl Into<u32> for LocalStruct {
+    fn into(self) -> u32 {
+        0
     }
 }
@@ -0,0 +1,50 @@
+contract;
+
+mod utils;
+
+use std::hash::Hash;
+
+/// A point in the plane.
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+enum Shape {
+    Circle: u64,
+    Rectangle: (u64, u64),
+}
+
+const ORIGIN: Point = Point { x: 0, y: 0 };
+
+abi Geometry {
+    fn area(shape: Shape) -> u64;
+}
+
+impl Geometry for Contract {
+    fn area(shape: Shape) -> u64 {
+        match shape {
+            Shape::Circle(radius) => 3 * radius * radius,
+            Shape::Rectangle((width, height)) => width * height,
+        }
+    }
+}
+
+impl Point {
+    fn new(x: u64, y: u64) -> Self {
+        Self { x, y }
+    }
+
+    // The type error doesn't prevent the method from being listed.
+    fn is_origin(self) -> bool {
+        let origin: bool = ORIGIN;
+        self.x == 0 && self.y == 0
+    }
+}
+
+storage {
+    count: u64 = 0,
+    shapes {
+        last: Shape = Shape::Circle(0),
+    },
+}
@@ -0,0 +1,18 @@
+script;
+
+fn double(value: u64) -> u64 {
+    value * 2
+}
+
+fn main() -> u64 {
+    let base = 3;
+    let total = double(base + 1) * 2;
+    if total > 10 {
+        let tmp = total - 10;
+        return double(tmp * base);
+    }
+    while total < double(base) {
+        break;
+    }
+    total
+}
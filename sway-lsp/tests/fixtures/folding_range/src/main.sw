@@ -0,0 +1,47 @@
+script;
+
+use std::hash::Hash;
+use std::hash::{
+    keccak256,
+    sha256,
+};
+use std::bytes::Bytes;
+
+/// A point in the plane.
+///
+/// Used to test folding of doc comments.
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+impl Point {
+    fn new(x: u64, y: u64) -> Self {
+        Self { x, y }
+    }
+
+    fn quadrant(self) -> u64 {
+        match (self.x, self.y) {
+            (0, 0) => 0,
+            (_, 0) => {
+                1
+            },
+            _ => 2,
+        }
+    }
+}
+
+/*
+    A block comment
+    spanning several lines.
+*/
+fn broken() {
+    let a = ;
+}
+
+fn main() {
+    let p = Point::new(1, 2);
+    if p.x > 0 {
+        let _ = p.quadrant();
+    }
+}
@@ -0,0 +1,22 @@
+library;
+
+trait Convert<T> {
+    fn from(value: T) -> Self;
+    fn into(self) -> T;
+    fn value(self) -> u64;
+    fn reset(ref mut self);
+}
+
+struct Number {
+    value: u64,
+}
+
+impl Convert<u64> for Number {
+    fn value(self) -> u64 {
+        self.value
+    }
+}
+
+struct Empty {}
+
+impl Convert<bool> for Empty {}
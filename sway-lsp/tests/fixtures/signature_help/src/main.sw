@@ -0,0 +1,63 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+trait Scale {
+    fn scale(self, factor: u64) -> Self;
+}
+
+trait ScaleAxes {
+    fn scale(self, x_factor: u64, y_factor: u64) -> Self;
+}
+
+impl Scale for Point {
+    /// Scales both coordinates by the same factor.
+    fn scale(self, factor: u64) -> Self {
+        Point {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+impl ScaleAxes for Point {
+    /// Scales each coordinate by its own factor.
+    fn scale(self, x_factor: u64, y_factor: u64) -> Self {
+        Point {
+            x: self.x * x_factor,
+            y: self.y * y_factor,
+        }
+    }
+}
+
+impl Point {
+    fn translate(self, dx: u64, dy: u64) -> Self {
+        Point {
+            x: self.x + dx,
+            y: self.y + dy,
+        }
+    }
+}
+
+/// Adds three numbers.
+///
+/// # Arguments
+///
+/// * `b`: [u64] - The second number.
+fn add(a: u64, b: u64, c: u64) -> u64 {
+    a + b + c
+}
+
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() {
+    let sum = add(1, identity(2), 3);
+    let p = Point { x: 1, y: 2 };
+    let _q = p.translate(sum, 1);
+    let _r = <Point as Scale>::scale(p, 2);
+}
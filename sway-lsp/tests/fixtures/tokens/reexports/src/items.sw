@@ -0,0 +1,10 @@
+library;
+
+pub struct Point {
+    pub x: u64,
+    pub y: u64,
+}
+
+pub fn make_point() -> Point {
+    Point { x: 0, y: 0 }
+}
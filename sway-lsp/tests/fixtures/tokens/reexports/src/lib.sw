@@ -0,0 +1,16 @@
+library;
+
+mod items;
+mod middle;
+mod outer;
+
+use ::items::Point as Coordinate;
+use ::outer::make_point;
+
+pub fn origin() -> Coordinate {
+    make_point()
+}
+
+pub fn qualified_origin() -> Coordinate {
+    ::items::make_point()
+}
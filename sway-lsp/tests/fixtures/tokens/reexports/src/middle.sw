@@ -0,0 +1,3 @@
+library;
+
+pub use ::items::make_point;
@@ -0,0 +1,3 @@
+library;
+
+pub use ::middle::make_point;
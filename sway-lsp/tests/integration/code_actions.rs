@@ -507,6 +507,7 @@ pub(crate) async fn code_action_auto_import_struct_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("EvmAddress".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -550,6 +551,7 @@ pub(crate) async fn code_action_auto_import_struct_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("DeepStruct".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -597,6 +599,7 @@ pub(crate) async fn code_action_auto_import_enum_request(server: &ServerState, u
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("AuthError".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -640,6 +643,7 @@ pub(crate) async fn code_action_auto_import_enum_request(server: &ServerState, u
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("DeepEnum".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -688,6 +692,7 @@ pub(crate) async fn code_action_auto_import_function_request(server: &ServerStat
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("deep_fun".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -736,6 +741,7 @@ pub(crate) async fn code_action_auto_import_constant_request(server: &ServerStat
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("TEST_CONST".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -762,7 +768,7 @@ pub(crate) async fn code_action_auto_import_constant_request(server: &ServerStat
 }
 
 pub(crate) async fn code_action_auto_import_trait_request(server: &ServerState, uri: &Url) {
-    // TryFrom: external library
+    // Into: external library
     let range = Range {
         start: Position {
             line: 34,
@@ -770,7 +776,7 @@ pub(crate) async fn code_action_auto_import_trait_request(server: &ServerState,
         },
         end: Position {
             line: 34,
-            character: 12,
+            character: 9,
         },
     };
 
@@ -780,11 +786,12 @@ pub(crate) async fn code_action_auto_import_trait_request(server: &ServerState,
         create_diagnostic_from_data(
             range,
             DiagnosticData {
-                unknown_symbol_name: Some("TryFrom".to_string()),
+                unknown_symbol_name: Some("Into".to_string()),
+                ..Default::default()
             },
         ),
     );
-    let call_path = "std::convert::TryFrom";
+    let call_path = "std::convert::Into";
     let expected = vec![
         create_code_action(
             uri.clone(),
@@ -796,7 +803,7 @@ pub(crate) async fn code_action_auto_import_trait_request(server: &ServerState,
         create_code_action(
             uri.clone(),
             format!("Qualify as `{}`", call_path),
-            create_changes_for_qualify(uri, 34, 5, 12, call_path),
+            create_changes_for_qualify(uri, 34, 5, 9, call_path),
             None,
             Some(CodeActionKind::QUICKFIX),
         ),
@@ -824,6 +831,7 @@ pub(crate) async fn code_action_auto_import_trait_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("DeepTrait".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -870,6 +878,7 @@ pub(crate) async fn code_action_auto_import_alias_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("A".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -909,3 +918,279 @@ pub(crate) async fn code_action_auto_import_alias_request(server: &ServerState,
     let actual = send_request(server, &params).await;
     assert_eq!(expected, actual);
 }
+
+pub(crate) async fn code_action_missing_trait_methods_request(server: &ServerState, uri: &Url) {
+    // The closing brace of the impl is on its own line.
+    let range = Range {
+        start: Position {
+            line: 13,
+            character: 5,
+        },
+        end: Position {
+            line: 13,
+            character: 12,
+        },
+    };
+    let impl_range = Range {
+        start: Position {
+            line: 13,
+            character: 0,
+        },
+        end: Position {
+            line: 17,
+            character: 1,
+        },
+    };
+
+    let params = create_code_action_params(
+        uri.clone(),
+        range,
+        create_diagnostic_from_data(
+            impl_range,
+            DiagnosticData {
+                missing_trait_methods: Some(vec![
+                    "from".to_string(),
+                    "into".to_string(),
+                    "reset".to_string(),
+                ]),
+                ..Default::default()
+            },
+        ),
+    );
+    let changes = create_changes_map(
+        uri,
+        Range {
+            start: Position {
+                line: 17,
+                character: 0,
+            },
+            end: Position {
+                line: 17,
+                character: 0,
+            },
+        },
+        "\n    fn from(value: u64) -> Number {\n        __revert(0)\n    }\n\n    fn into(self) -> u64 {\n        __revert(0)\n    }\n\n    fn reset(ref mut self) {\n        __revert(0)\n    }\n",
+    );
+    let expected = vec![create_code_action(
+        uri.clone(),
+        "Generate missing methods for `Convert`".to_string(),
+        changes,
+        None,
+        Some(CodeActionKind::QUICKFIX),
+    )];
+
+    let actual = send_request(server, &params).await;
+    assert_eq!(expected, actual);
+
+    // The impl is empty and on a single line.
+    let range = Range {
+        start: Position {
+            line: 21,
+            character: 5,
+        },
+        end: Position {
+            line: 21,
+            character: 12,
+        },
+    };
+    let impl_range = Range {
+        start: Position {
+            line: 21,
+            character: 0,
+        },
+        end: Position {
+            line: 21,
+            character: 31,
+        },
+    };
+
+    let params = create_code_action_params(
+        uri.clone(),
+        range,
+        create_diagnostic_from_data(
+            impl_range,
+            DiagnosticData {
+                missing_trait_methods: Some(vec![
+                    "from".to_string(),
+                    "into".to_string(),
+                    "reset".to_string(),
+                    "value".to_string(),
+                ]),
+                ..Default::default()
+            },
+        ),
+    );
+    let changes = create_changes_map(
+        uri,
+        Range {
+            start: Position {
+                line: 21,
+                character: 30,
+            },
+            end: Position {
+                line: 21,
+                character: 30,
+            },
+        },
+        "\n    fn from(value: bool) -> Empty {\n        __revert(0)\n    }\n\n    fn into(self) -> bool {\n        __revert(0)\n    }\n\n    fn value(self) -> u64 {\n        __revert(0)\n    }\n\n    fn reset(ref mut self) {\n        __revert(0)\n    }\n",
+    );
+    let expected = vec![create_code_action(
+        uri.clone(),
+        "Generate missing methods for `Convert`".to_string(),
+        changes,
+        None,
+        Some(CodeActionKind::QUICKFIX),
+    )];
+
+    let actual = send_request(server, &params).await;
+    assert_eq!(expected, actual);
+}
+
+pub(crate) async fn code_action_extract_variable_request(server: &ServerState, uri: &Url) {
+    // The final expression of a function, selected with its leading whitespace.
+    let params = create_code_action_params(
+        uri.clone(),
+        Range {
+            start: Position {
+                line: 3,
+                character: 0,
+            },
+            end: Position {
+                line: 3,
+                character: 13,
+            },
+        },
+        None,
+    );
+    let changes = create_changes_map(
+        uri,
+        Range {
+            start: Position {
+                line: 3,
+                character: 4,
+            },
+            end: Position {
+                line: 3,
+                character: 13,
+            },
+        },
+        "let tmp = value * 2;\n    tmp",
+    );
+    let expected = vec![create_code_action(
+        uri.clone(),
+        "Extract into variable".to_string(),
+        changes,
+        None,
+        Some(CodeActionKind::REFACTOR_EXTRACT),
+    )];
+
+    let actual = send_request(server, &params).await;
+    assert_eq!(expected, actual);
+
+    // A function argument, where `tmp` is already used in the function.
+    let params = create_code_action_params(
+        uri.clone(),
+        Range {
+            start: Position {
+                line: 8,
+                character: 23,
+            },
+            end: Position {
+                line: 8,
+                character: 31,
+            },
+        },
+        None,
+    );
+    let changes = create_changes_map(
+        uri,
+        Range {
+            start: Position {
+                line: 8,
+                character: 4,
+            },
+            end: Position {
+                line: 8,
+                character: 31,
+            },
+        },
+        "let tmp1 = base + 1;\n    let total = double(tmp1",
+    );
+    let expected = vec![create_code_action(
+        uri.clone(),
+        "Extract into variable".to_string(),
+        changes,
+        None,
+        Some(CodeActionKind::REFACTOR_EXTRACT),
+    )];
+
+    let actual = send_request(server, &params).await;
+    assert_eq!(expected, actual);
+
+    // An expression within a nested block is declared before the statement of that block.
+    let params = create_code_action_params(
+        uri.clone(),
+        Range {
+            start: Position {
+                line: 11,
+                character: 22,
+            },
+            end: Position {
+                line: 11,
+                character: 32,
+            },
+        },
+        None,
+    );
+    let changes = create_changes_map(
+        uri,
+        Range {
+            start: Position {
+                line: 11,
+                character: 8,
+            },
+            end: Position {
+                line: 11,
+                character: 32,
+            },
+        },
+        "let tmp1 = tmp * base;\n        return double(tmp1",
+    );
+    let expected = vec![create_code_action(
+        uri.clone(),
+        "Extract into variable".to_string(),
+        changes,
+        None,
+        Some(CodeActionKind::REFACTOR_EXTRACT),
+    )];
+
+    let actual = send_request(server, &params).await;
+    assert_eq!(expected, actual);
+
+    // Selections that are not a complete expression, a plain variable, and an expression
+    // in a `while` condition, which is evaluated on every iteration.
+    for (line, start, end) in [(8, 23, 29), (16, 4, 9), (13, 18, 30)] {
+        let params = create_code_action_params(
+            uri.clone(),
+            Range {
+                start: Position {
+                    line,
+                    character: start,
+                },
+                end: Position {
+                    line,
+                    character: end,
+                },
+            },
+            None,
+        );
+        let actual = request::handle_code_action(server, params)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        assert!(!actual.iter().any(|action| matches!(
+            action,
+            CodeActionOrCommand::CodeAction(action) if action.title == "Extract into variable"
+        )));
+    }
+}
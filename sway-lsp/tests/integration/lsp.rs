@@ -247,7 +247,10 @@ pub(crate) async fn semantic_tokens_request(server: &ServerState, uri: &Url) {
     }
 }
 
-pub(crate) async fn document_symbol_request(server: &ServerState, uri: &Url) {
+pub(crate) async fn document_symbol_request(
+    server: &ServerState,
+    uri: &Url,
+) -> Vec<DocumentSymbol> {
     let params = DocumentSymbolParams {
         text_document: TextDocumentIdentifier { uri: uri.clone() },
         work_done_progress_params: Default::default(),
@@ -256,8 +259,12 @@ pub(crate) async fn document_symbol_request(server: &ServerState, uri: &Url) {
     let response = request::handle_document_symbol(server, params)
         .await
         .unwrap();
-    if let Some(DocumentSymbolResponse::Flat(res)) = response {
-        assert!(!res.is_empty());
+    match response {
+        Some(DocumentSymbolResponse::Nested(symbols)) => {
+            assert!(!symbols.is_empty());
+            symbols
+        }
+        _ => panic!("Expected nested document symbols, got {response:?}"),
     }
 }
 
@@ -567,6 +574,60 @@ pub(crate) async fn definition_check_with_req_offset(
     definition_check(server, go_to).await;
 }
 
+/// Checks that the request resolves to the definition of `go_to`, followed by the `imports`
+/// it was imported through, each given as its line, start character, end character and path.
+pub(crate) async fn definition_through_imports_check<'a>(
+    server: &ServerState,
+    go_to: &'a GotoDefinition<'a>,
+    imports: &[(u32, u32, u32, &str)],
+) {
+    let params = GotoDefinitionParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier {
+                uri: go_to.req_uri.clone(),
+            },
+            position: Position {
+                line: go_to.req_line,
+                character: go_to.req_char,
+            },
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let res = request::handle_goto_definition(server, params.clone())
+        .await
+        .unwrap();
+    let Some(GotoDefinitionResponse::Array(locations)) = &res else {
+        panic!(
+            "Expected GotoDefinitionResponse::Array with input {:#?}, got {:?}",
+            params, res,
+        );
+    };
+    let expected = std::iter::once((
+        go_to.def_line,
+        go_to.def_start_char,
+        go_to.def_end_char,
+        go_to.def_path,
+    ))
+    .chain(imports.iter().copied());
+    assert_eq!(locations.len(), imports.len() + 1, "{locations:#?}");
+    for (location, (line, start_char, end_char, path)) in locations.iter().zip(expected) {
+        assert_eq!(
+            location.range,
+            Range::new(
+                Position::new(line, start_char),
+                Position::new(line, end_char)
+            ),
+        );
+        assert!(
+            location.uri.as_str().ends_with(path),
+            "{} doesn't end with {}",
+            location.uri,
+            path,
+        );
+    }
+}
+
 pub(crate) async fn hover_request<'a>(
     server: &ServerState,
     hover_docs: &'a HoverDocumentation<'a>,
@@ -786,3 +847,35 @@ fn compare_inlay_hint_tooltips(a: &Option<InlayHintTooltip>, b: &Option<InlayHin
         _ => false,
     }
 }
+
+pub(crate) async fn signature_help_request(
+    server: &ServerState,
+    uri: &Url,
+    line: u32,
+    character: u32,
+) -> SignatureHelp {
+    let params = SignatureHelpParams {
+        context: None,
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position { line, character },
+        },
+        work_done_progress_params: Default::default(),
+    };
+    request::handle_signature_help(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+pub(crate) async fn folding_range_request(server: &ServerState, uri: &Url) -> Vec<FoldingRange> {
+    let params = FoldingRangeParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    request::handle_folding_range(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+}
@@ -1597,6 +1597,157 @@ fn go_to_definition_for_storage() {
     });
 }
 
+#[test]
+fn go_to_definition_through_aliases() {
+    run_async!({
+        let server = ServerState::default();
+        let uri = open(
+            &server,
+            test_fixtures_dir().join("tokens/reexports/src/lib.sw"),
+        )
+        .await;
+
+        let mut go_to = GotoDefinition {
+            req_uri: &uri,
+            req_line: 6,
+            req_char: 24,
+            def_line: 2,
+            def_start_char: 11,
+            def_end_char: 16,
+            def_path: "sway-lsp/tests/fixtures/tokens/reexports/src/items.sw",
+        };
+        // use ::items::Point as Coordinate;
+        lsp::definition_check(&server, &go_to).await;
+        lsp::definition_check_with_req_offset(&server, &mut go_to, 6, 14).await;
+
+        // fn origin() -> Coordinate
+        go_to.req_line = 9;
+        go_to.req_char = 22;
+        lsp::definition_through_imports_check(
+            &server,
+            &go_to,
+            &[(
+                6,
+                22,
+                32,
+                "sway-lsp/tests/fixtures/tokens/reexports/src/lib.sw",
+            )],
+        )
+        .await;
+
+        let _ = server.shutdown_server();
+    });
+}
+
+#[test]
+fn go_to_definition_through_reexports() {
+    run_async!({
+        let server = ServerState::default();
+        let uri = open(
+            &server,
+            test_fixtures_dir().join("tokens/reexports/src/lib.sw"),
+        )
+        .await;
+
+        let reexports = [
+            (
+                2,
+                18,
+                28,
+                "sway-lsp/tests/fixtures/tokens/reexports/src/outer.sw",
+            ),
+            (
+                2,
+                17,
+                27,
+                "sway-lsp/tests/fixtures/tokens/reexports/src/middle.sw",
+            ),
+        ];
+        let mut go_to = GotoDefinition {
+            req_uri: &uri,
+            req_line: 7,
+            req_char: 15,
+            def_line: 7,
+            def_start_char: 7,
+            def_end_char: 17,
+            def_path: "sway-lsp/tests/fixtures/tokens/reexports/src/items.sw",
+        };
+        // use ::outer::make_point;
+        lsp::definition_through_imports_check(&server, &go_to, &reexports).await;
+
+        // make_point()
+        go_to.req_line = 10;
+        go_to.req_char = 6;
+        let imports = [(
+            7,
+            13,
+            23,
+            "sway-lsp/tests/fixtures/tokens/reexports/src/lib.sw",
+        )];
+        lsp::definition_through_imports_check(
+            &server,
+            &go_to,
+            &[imports.as_slice(), reexports.as_slice()].concat(),
+        )
+        .await;
+
+        let _ = server.shutdown_server();
+    });
+}
+
+#[test]
+fn go_to_definition_through_imports_only_for_unqualified_names() {
+    run_async!({
+        let server = ServerState::default();
+        let uri = open(
+            &server,
+            test_fixtures_dir().join("tokens/reexports/src/lib.sw"),
+        )
+        .await;
+
+        let mut go_to = GotoDefinition {
+            req_uri: &uri,
+            req_line: 10,
+            req_char: 6,
+            def_line: 7,
+            def_start_char: 7,
+            def_end_char: 17,
+            def_path: "sway-lsp/tests/fixtures/tokens/reexports/src/items.sw",
+        };
+        // make_point()
+        lsp::definition_through_imports_check(
+            &server,
+            &go_to,
+            &[
+                (
+                    7,
+                    13,
+                    23,
+                    "sway-lsp/tests/fixtures/tokens/reexports/src/lib.sw",
+                ),
+                (
+                    2,
+                    18,
+                    28,
+                    "sway-lsp/tests/fixtures/tokens/reexports/src/outer.sw",
+                ),
+                (
+                    2,
+                    17,
+                    27,
+                    "sway-lsp/tests/fixtures/tokens/reexports/src/middle.sw",
+                ),
+            ],
+        )
+        .await;
+
+        // ::items::make_point()
+        lsp::definition_check_with_req_offset(&server, &mut go_to, 14, 13).await;
+
+        let _ = server.shutdown_server();
+    });
+}
+
 //------------------- HOVER DOCUMENTATION -------------------//
 
 #[test]
@@ -1835,6 +1986,203 @@ fn hover_docs_for_boolean_keywords() {
     });
 }
 
+#[test]
+fn signature_help_for_functions() {
+    run_async!({
+        let server = ServerState::default();
+        let uri = open(
+            &server,
+            test_fixtures_dir().join("signature_help/src/main.sw"),
+        )
+        .await;
+
+        let help = lsp::signature_help_request(&server, &uri, 58, 21).await;
+        assert_eq!(help.signatures.len(), 1);
+        assert_eq!(
+            help.signatures[0].label,
+            "fn add(a: u64, b: u64, c: u64) -> u64"
+        );
+        assert_eq!(
+            help.signatures[0].documentation,
+            Some(Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value:
+                    " Adds three numbers.\n\n # Arguments\n\n * `b`: [u64] - The second number.\n"
+                        .to_string(),
+            }))
+        );
+        let parameters = help.signatures[0].parameters.as_ref().unwrap();
+        assert_eq!(parameters[1].label, ParameterLabel::LabelOffsets([15, 21]));
+        assert_eq!(
+            parameters[1].documentation,
+            Some(Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: "[u64] - The second number.".to_string(),
+            }))
+        );
+        assert_eq!(parameters[0].documentation, None);
+        assert_eq!(help.active_parameter, Some(1));
+
+        // The innermost call enclosing the cursor is used.
+        let help = lsp::signature_help_request(&server, &uri, 58, 30).await;
+        assert_eq!(help.signatures[0].label, "fn identity<T>(value: T) -> T");
+        assert_eq!(help.active_parameter, Some(0));
+        let _ = server.shutdown_server();
+    });
+}
+
+#[test]
+fn signature_help_for_methods() {
+    run_async!({
+        let server = ServerState::default();
+        let uri = open(
+            &server,
+            test_fixtures_dir().join("signature_help/src/main.sw"),
+        )
+        .await;
+
+        // The `self` parameter is skipped when using the method call syntax.
+        let help = lsp::signature_help_request(&server, &uri, 60, 30).await;
+        assert_eq!(help.signatures.len(), 1);
+        assert_eq!(
+            help.signatures[0].label,
+            "fn translate(self, dx: u64, dy: u64) -> Point"
+        );
+        assert_eq!(help.active_parameter, Some(2));
+
+        // All the trait methods with the same name are candidates.
+        let help = lsp::signature_help_request(&server, &uri, 61, 40).await;
+        let labels = help
+            .signatures
+            .iter()
+            .map(|signature| signature.label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            vec![
+                "fn scale(self, factor: u64) -> Point",
+                "fn scale(self, x_factor: u64, y_factor: u64) -> Point",
+            ]
+        );
+        assert_eq!(help.active_signature, Some(0));
+        assert_eq!(help.active_parameter, Some(1));
+        let _ = server.shutdown_server();
+    });
+}
+
+#[test]
+fn folding_ranges() {
+    run_async!({
+        let server = ServerState::default();
+        let uri = open(
+            &server,
+            test_fixtures_dir().join("folding_range/src/main.sw"),
+        )
+        .await;
+
+        let ranges = lsp::folding_range_request(&server, &uri)
+            .await
+            .into_iter()
+            .map(|range| (range.start_line, range.end_line, range.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            vec![
+                // The consecutive `use` statements.
+                (2, 7, Some(FoldingRangeKind::Imports)),
+                // The doc comment of `Point`.
+                (9, 11, Some(FoldingRangeKind::Comment)),
+                (12, 15, None),
+                (17, 31, None),
+                (18, 20, None),
+                (22, 30, None),
+                (23, 29, None),
+                // The match arm with a block.
+                (25, 27, None),
+                (33, 36, Some(FoldingRangeKind::Comment)),
+                // The parse error inside `broken` doesn't prevent folding the other functions.
+                (37, 39, None),
+                (41, 46, None),
+                (43, 45, None),
+            ]
+        );
+        let _ = server.shutdown_server();
+    });
+}
+
+#[test]
+fn document_symbols() {
+    run_async!({
+        let server = ServerState::default();
+        let uri = open(
+            &server,
+            test_fixtures_dir().join("document_symbol/src/main.sw"),
+        )
+        .await;
+
+        let symbols = lsp::document_symbol_request(&server, &uri).await;
+        fn outline(symbols: &[DocumentSymbol], depth: usize, out: &mut Vec<String>) {
+            for symbol in symbols {
+                out.push(format!(
+                    "{}{} {:?}",
+                    "  ".repeat(depth),
+                    symbol.name,
+                    symbol.kind
+                ));
+                outline(
+                    symbol.children.as_deref().unwrap_or_default(),
+                    depth + 1,
+                    out,
+                );
+            }
+        }
+        let mut lines = vec![];
+        outline(&symbols, 0, &mut lines);
+        assert_eq!(
+            lines,
+            vec![
+                "utils Module",
+                "Point Struct",
+                "  x Field",
+                "  y Field",
+                "Shape Enum",
+                "  Circle EnumMember",
+                "  Rectangle EnumMember",
+                "ORIGIN Constant",
+                "Geometry Interface",
+                "  area Method",
+                "impl Geometry for Contract Object",
+                "  area Method",
+                // The type error inside `is_origin` doesn't affect the outline.
+                "impl Point Object",
+                "  new Method",
+                "  is_origin Method",
+                "storage Struct",
+                "  count Field",
+                "  shapes Namespace",
+                "    last Field",
+            ]
+        );
+
+        // The range covers the whole item, including its doc comment, and the
+        // selection range only its name.
+        let point = &symbols[1];
+        assert_eq!(
+            point.range,
+            Range::new(Position::new(6, 0), Position::new(10, 1))
+        );
+        assert_eq!(
+            point.selection_range,
+            Range::new(Position::new(7, 7), Position::new(7, 12))
+        );
+        assert_eq!(
+            point.children.as_ref().unwrap()[0].detail.as_deref(),
+            Some("u64")
+        );
+        let _ = server.shutdown_server();
+    });
+}
+
 #[test]
 fn rename() {
     run_async!({
@@ -2075,6 +2423,16 @@ lsp_capability_test!(
     code_actions::code_action_auto_import_alias_request,
     test_fixtures_dir().join("auto_import/src/main.sw")
 );
+lsp_capability_test!(
+    code_action_missing_trait_methods,
+    code_actions::code_action_missing_trait_methods_request,
+    test_fixtures_dir().join("missing_trait_methods/src/main.sw")
+);
+lsp_capability_test!(
+    code_action_extract_variable,
+    code_actions::code_action_extract_variable_request,
+    test_fixtures_dir().join("extract_variable/src/main.sw")
+);
 lsp_capability_test!(
     code_lens,
     lsp::code_lens_request,
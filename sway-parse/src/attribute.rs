@@ -49,7 +49,11 @@ impl<T: Parse> Parse for Annotated<T> {
                             doc_comment.span.clone(),
                         ),
                         args: Some(Parens::new(
-                            Punctuated::single(AttributeArg { name, value: None }),
+                            Punctuated::single(AttributeArg {
+                                name,
+                                value: None,
+                                args: None,
+                            }),
                             doc_comment.content_span,
                         )),
                     }),
@@ -142,9 +146,14 @@ impl Parse for AttributeArg {
                 Ok(AttributeArg {
                     name,
                     value: Some(value),
+                    args: None,
                 })
             }
-            None => Ok(AttributeArg { name, value: None }),
+            None => Ok(AttributeArg {
+                name,
+                value: None,
+                args: Parens::try_parse(parser)?,
+            }),
         }
     }
 }
@@ -212,6 +221,7 @@ mod tests {
                             span: (85, 108),
                           ),
                           value: None,
+                          args: None,
                         )),
                       ),
                       span: (85, 108),
@@ -242,6 +252,7 @@ mod tests {
                             span: (131, 135),
                           ),
                           value: None,
+                          args: None,
                         )),
                       ),
                       span: (130, 136),
@@ -289,6 +300,56 @@ mod tests {
         "###);
     }
 
+    fn doc_comments(item_fn: &Annotated<ItemFn>) -> Vec<String> {
+        item_fn
+            .attribute_list
+            .iter()
+            .flat_map(|attr_decl| attr_decl.attribute.get().into_iter())
+            .filter(|attr| attr.name.as_str() == DOC_COMMENT_ATTRIBUTE_NAME)
+            .flat_map(|attr| attr.args.iter().flat_map(|args| args.get().into_iter()))
+            .map(|arg| arg.name.as_str().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn parse_block_doc_comment_as_line_doc_comments() {
+        let line_docs = parse::<Annotated<ItemFn>>(
+            r#"
+            /// Returns the answer.
+            ///
+            /// The answer is always `42`.
+            fn main() {}
+        "#,
+        );
+        let expected = doc_comments(&line_docs);
+        assert_eq!(
+            expected,
+            vec![" Returns the answer.", "", " The answer is always `42`."]
+        );
+
+        let starred_block_docs = parse::<Annotated<ItemFn>>(
+            r#"
+            /**
+             * Returns the answer.
+             *
+             * The answer is always `42`.
+             */
+            fn main() {}
+        "#,
+        );
+        assert_eq!(doc_comments(&starred_block_docs), expected);
+
+        let block_docs = parse::<Annotated<ItemFn>>(
+            r#"
+            /** Returns the answer.
+
+                The answer is always `42`. */
+            fn main() {}
+        "#,
+        );
+        assert_eq!(doc_comments(&block_docs), expected);
+    }
+
     #[test]
     fn parse_attribute() {
         assert_ron_snapshot!(parse::<Attribute>(r#"
@@ -308,6 +369,7 @@ mod tests {
                     span: (18, 22),
                   ),
                   value: None,
+                  args: None,
                 ), CommaToken(
                   span: (22, 23),
                 )),
@@ -320,6 +382,7 @@ mod tests {
                     span: (31, 38),
                     parsed: "value",
                   ))),
+                  args: None,
                 ), CommaToken(
                   span: (38, 39),
                 )),
@@ -330,6 +393,7 @@ mod tests {
                   span: (40, 44),
                 ),
                 value: None,
+                args: None,
               )),
             ),
             span: (17, 45),
@@ -337,4 +401,40 @@ mod tests {
         )
         "###);
     }
+
+    #[test]
+    fn parse_attribute_with_nested_args() {
+        let attr = parse::<Attribute>(
+            r#"
+            cfg(all(feature = "a", not(test)))
+        "#,
+        );
+        let args = attr.args.unwrap().into_inner().into_iter().collect::<Vec<_>>();
+        assert_eq!(args.len(), 1);
+        assert_eq!(args[0].name.as_str(), "all");
+        assert!(args[0].value.is_none());
+
+        let all_args = args[0]
+            .args
+            .clone()
+            .unwrap()
+            .into_inner()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(all_args.len(), 2);
+        assert_eq!(all_args[0].name.as_str(), "feature");
+        assert!(matches!(&all_args[0].value, Some(Literal::String(s)) if s.parsed == "a"));
+        assert_eq!(all_args[1].name.as_str(), "not");
+
+        let not_args = all_args[1]
+            .args
+            .clone()
+            .unwrap()
+            .into_inner()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(not_args.len(), 1);
+        assert_eq!(not_args[0].name.as_str(), "test");
+        assert!(not_args[0].args.is_none());
+    }
 }
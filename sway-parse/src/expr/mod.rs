@@ -3,17 +3,18 @@ use crate::{Parse, ParseBracket, ParseResult, ParseToEnd, Parser, ParserConsumed
 use sway_ast::brackets::{Braces, Parens, SquareBrackets};
 use sway_ast::expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant};
 use sway_ast::keywords::{
-    AbiToken, AddEqToken, AmpersandToken, AsmToken, CommaToken, ConfigurableToken, ConstToken,
-    DivEqToken, DoubleColonToken, EnumToken, EqToken, FalseToken, FnToken, IfToken, ImplToken,
-    LetToken, MutToken, OpenAngleBracketToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken,
-    StarEqToken, StorageToken, StructToken, SubEqToken, TraitToken, TrueToken, TypeToken, UseToken,
+    AbiToken, AddEqToken, AmpersandToken, AsmToken, BangToken, CommaToken, ConfigurableToken,
+    ConstToken, DivEqToken, DoubleColonToken, EnumToken, EqToken, FalseToken, FnToken, IfToken,
+    ImplToken, LetToken, LoopToken, MutToken, OpenAngleBracketToken, PubToken, SemicolonToken,
+    ShlEqToken, ShrEqToken, StarEqToken, StorageToken, StructToken, SubEqToken, TraitToken,
+    TrueToken, TypeToken, UseToken,
 };
 use sway_ast::literal::{LitBool, LitBoolType};
 use sway_ast::punctuated::Punctuated;
 use sway_ast::{
     AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-    ExprTupleDescriptor, GenericArgs, IfCondition, IfExpr, LitInt, Literal, MatchBranch,
-    MatchBranchKind, PathExpr, PathExprSegment, Statement, StatementLet,
+    ExprTupleDescriptor, ExprTypeAscription, GenericArgs, IfCondition, IfExpr, LitInt, Literal,
+    MatchBranch, MatchBranchKind, MatchesArgs, PathExpr, PathExprSegment, Statement, StatementLet,
 };
 use sway_error::parser_error::ParseErrorKind;
 use sway_types::{ast::Delimiter, Ident, Span, Spanned};
@@ -42,6 +43,32 @@ impl ParseToEnd for AbiCastArgs {
     }
 }
 
+impl ParseToEnd for MatchesArgs {
+    fn parse_to_end<'a, 'e>(
+        mut parser: Parser<'a, '_>,
+    ) -> ParseResult<(MatchesArgs, ParserConsumed<'a>)> {
+        let value = parser.parse()?;
+        let comma_token = parser.parse()?;
+        let pattern = parser.parse()?;
+        let guard_opt = match parser.take() {
+            Some(if_token) => Some((if_token, parser.parse()?)),
+            None => None,
+        };
+        match parser.check_empty() {
+            Some(consumed) => {
+                let matches_args = MatchesArgs {
+                    value,
+                    comma_token,
+                    pattern,
+                    guard_opt,
+                };
+                Ok((matches_args, consumed))
+            }
+            None => Err(parser.emit_error(ParseErrorKind::UnexpectedTokenAfterMatchesPattern)),
+        }
+    }
+}
+
 impl Parse for IfExpr {
     fn parse(parser: &mut Parser) -> ParseResult<IfExpr> {
         let if_token = parser.parse()?;
@@ -551,6 +578,14 @@ fn parse_projection(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr>
             expr = Expr::Index { target, arg };
             continue;
         }
+        if let Some(question_mark_token) = parser.take() {
+            let expr_inner = Box::new(expr);
+            expr = Expr::Try {
+                expr: expr_inner,
+                question_mark_token,
+            };
+            continue;
+        }
         if let Some(dot_token) = parser.take() {
             let target = Box::new(expr);
 
@@ -676,6 +711,18 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
         if let Some(_consumed) = parser.check_empty() {
             return Ok(Expr::Parens(Parens::new(head, span)));
         }
+        if let Some(colon_token) = parser.take() {
+            let ty = parser.parse()?;
+            if let Some(_consumed) = parser.check_empty() {
+                let ascription = ExprTypeAscription {
+                    expr: head,
+                    colon_token,
+                    ty,
+                };
+                return Ok(Expr::TypeAscription(Parens::new(ascription, span)));
+            }
+            return Err(parser.emit_error(ParseErrorKind::ExpectedCloseParenAfterTypeAscription));
+        }
         return Err(
             parser.emit_error(ParseErrorKind::ExpectedCommaOrCloseParenInTupleOrParenExpression)
         );
@@ -692,7 +739,25 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
         return Ok(Expr::Asm(asm_block));
     }
     if let Some(break_token) = parser.take() {
-        return Ok(Expr::Break { break_token });
+        // In a condition, a `break` followed by a brace is a `break` without a value,
+        // e.g. `if break { ... }`.
+        if parser.is_empty()
+            || parser.peek::<CommaToken>().is_some()
+            || parser.peek::<SemicolonToken>().is_some()
+            || parser.peek::<EqToken>().is_some()
+            || (ctx.parsing_conditional
+                && matches!(parser.peek::<Delimiter>(), Some(Delimiter::Brace)))
+        {
+            return Ok(Expr::Break {
+                break_token,
+                expr_opt: None,
+            });
+        }
+        let expr = parser.parse()?;
+        return Ok(Expr::Break {
+            break_token,
+            expr_opt: Some(expr),
+        });
     }
     if let Some(continue_token) = parser.take() {
         return Ok(Expr::Continue { continue_token });
@@ -739,6 +804,12 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
             block,
         });
     }
+    // `loop` is not a reserved keyword, so `loop { ... }` is recognized by its shape.
+    if let Some((_, Delimiter::Brace)) = parser.peek::<(LoopToken, Delimiter)>() {
+        let loop_token = parser.parse()?;
+        let block = parser.parse()?;
+        return Ok(Expr::Loop { loop_token, block });
+    }
     if let Some(for_token) = parser.take() {
         let value_pattern = parser.parse()?;
         let in_token = parser.parse()?;
@@ -752,6 +823,20 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
             block,
         });
     }
+    // `matches` is not a keyword, so `matches!(...)` is recognized by its shape.
+    if matches!(
+        parser.peek::<(Ident, BangToken, Delimiter)>(),
+        Some((matches_ident, _, Delimiter::Parenthesis)) if matches_ident.as_str() == "matches"
+    ) {
+        let matches_ident = parser.parse()?;
+        let bang_token = parser.parse()?;
+        let args = parser.parse()?;
+        return Ok(Expr::Matches {
+            matches_ident,
+            bang_token,
+            args,
+        });
+    }
     if parser.peek::<OpenAngleBracketToken>().is_some()
         || parser.peek::<DoubleColonToken>().is_some()
         || parser.peek::<Ident>().is_some()
@@ -864,3 +949,186 @@ impl Parse for MatchBranchKind {
         Ok(MatchBranchKind::Expr { expr, comma_token })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse;
+    use assert_matches::assert_matches;
+    use sway_ast::Pattern;
+
+    #[test]
+    fn parse_matches() {
+        let expr = parse::<Expr>("matches!(x, Some(_))");
+        let args = match expr {
+            Expr::Matches {
+                matches_ident,
+                args,
+                ..
+            } => {
+                assert_eq!(matches_ident.as_str(), "matches");
+                args.into_inner()
+            }
+            _ => panic!("expected `matches!` expression"),
+        };
+        assert_matches!(*args.value, Expr::Path(path) if path.span().as_str() == "x");
+        assert_matches!(args.pattern, Pattern::Constructor { .. });
+        assert!(args.guard_opt.is_none());
+    }
+
+    #[test]
+    fn parse_matches_with_guard() {
+        let expr = parse::<Expr>("matches!(x, Some(n) if n > 0)");
+        let args = match expr {
+            Expr::Matches { args, .. } => args.into_inner(),
+            _ => panic!("expected `matches!` expression"),
+        };
+        assert_matches!(args.pattern, Pattern::Constructor { .. });
+        let (_, guard) = args.guard_opt.unwrap();
+        assert_matches!(*guard, Expr::GreaterThan { .. });
+    }
+
+    #[test]
+    fn parse_matches_as_operand() {
+        let expr = parse::<Expr>("!matches!(x, Some(_)) && y");
+        assert_matches!(
+            expr,
+            Expr::LogicalAnd { lhs, .. } if matches!(*lhs, Expr::Not { ref expr, .. } if matches!(**expr, Expr::Matches { .. }))
+        );
+    }
+
+    #[test]
+    fn parse_matches_as_identifier() {
+        let expr = parse::<Expr>("matches != x");
+        assert_matches!(expr, Expr::NotEqual { lhs, .. } if matches!(*lhs, Expr::Path(_)));
+    }
+
+    #[test]
+    fn parse_try() {
+        let expr = parse::<Expr>("foo(x)?.bar()?");
+        let target = match expr {
+            Expr::Try { expr, .. } => expr,
+            _ => panic!("expected `?` expression"),
+        };
+        assert_matches!(
+            *target,
+            Expr::MethodCall { target, .. } if matches!(*target, Expr::Try { ref expr, .. } if matches!(**expr, Expr::FuncApp { .. }))
+        );
+    }
+
+    #[test]
+    fn parse_try_binds_tighter_than_unary_operators() {
+        let expr = parse::<Expr>("!x?");
+        assert_matches!(expr, Expr::Not { expr, .. } if matches!(*expr, Expr::Try { .. }));
+    }
+
+    #[test]
+    fn parse_type_ascription() {
+        let expr = parse::<Expr>("(x.collect() : Vec<u8>)");
+        let ascription = match expr {
+            Expr::TypeAscription(ascription) => ascription.into_inner(),
+            _ => panic!("expected type ascription"),
+        };
+        assert_matches!(*ascription.expr, Expr::MethodCall { .. });
+        assert_eq!(ascription.ty.span().as_str(), "Vec<u8>");
+    }
+
+    #[test]
+    fn parse_type_ascription_as_operand() {
+        let expr = parse::<Expr>("(1 : u8) + 2");
+        assert_matches!(expr, Expr::Add { lhs, .. } if matches!(*lhs, Expr::TypeAscription(_)));
+    }
+
+    #[test]
+    fn parse_loop_with_break_value() {
+        let expr = parse::<Expr>("loop { if x { break x * 2; } }");
+        let block = match expr {
+            Expr::Loop { block, .. } => block.into_inner(),
+            _ => panic!("expected `loop` expression"),
+        };
+        let if_expr = match block.final_expr_opt.as_deref() {
+            Some(Expr::If(if_expr)) => if_expr,
+            _ => panic!("expected `if` expression"),
+        };
+        assert_matches!(
+            &if_expr.then_block.get().statements[0],
+            Statement::Expr { expr: Expr::Break { expr_opt: Some(expr), .. }, .. } if matches!(**expr, Expr::Mul { .. })
+        );
+    }
+
+    #[test]
+    fn parse_break_without_value() {
+        let expr = parse::<Expr>("match x { 0 => break, _ => continue, }");
+        let branches = match expr {
+            Expr::Match { branches, .. } => branches.into_inner(),
+            _ => panic!("expected `match` expression"),
+        };
+        assert_matches!(
+            &branches[0].kind,
+            MatchBranchKind::Expr {
+                expr: Expr::Break { expr_opt: None, .. },
+                ..
+            }
+        );
+    }
+
+    #[test]
+    fn parse_break_in_condition() {
+        let expr = parse::<Expr>("if break { 1 } else { 2 }");
+        assert_matches!(
+            expr,
+            Expr::If(IfExpr { condition: IfCondition::Expr(condition), .. })
+                if matches!(*condition, Expr::Break { expr_opt: None, .. })
+        );
+    }
+
+    #[test]
+    fn parse_loop_as_identifier() {
+        let expr = parse::<Expr>("loop::loop()");
+        assert_matches!(expr, Expr::FuncApp { func, .. } if matches!(*func, Expr::Path(_)));
+    }
+
+    #[test]
+    fn parse_turbofish_function_call() {
+        let expr = parse::<Expr>("foo::<u64, Vec<u8>>()");
+        let func = match expr {
+            Expr::FuncApp { func, .. } => func,
+            _ => panic!("expected function application"),
+        };
+        let path = match *func {
+            Expr::Path(path) => path,
+            _ => panic!("expected path expression"),
+        };
+        let (_, generic_args) = path.prefix.generics_opt.unwrap();
+        let types = generic_args
+            .parameters
+            .into_inner()
+            .into_iter()
+            .map(|ty| ty.span().as_str().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(types, ["u64", "Vec<u8>"]);
+    }
+
+    #[test]
+    fn parse_turbofish_path_call() {
+        let expr = parse::<Expr>("lib::convert::<u8>(x)");
+        let func = match expr {
+            Expr::FuncApp { func, .. } => func,
+            _ => panic!("expected function application"),
+        };
+        assert_matches!(*func, Expr::Path(path) if path.prefix.generics_opt.is_none()
+            && matches!(path.suffix.as_slice(), [(_, seg)] if seg.generics_opt.is_some()));
+    }
+
+    #[test]
+    fn parse_turbofish_method_call() {
+        let expr = parse::<Expr>("x.into::<u256>()");
+        let path_seg = match expr {
+            Expr::MethodCall { path_seg, .. } => path_seg,
+            _ => panic!("expected method call"),
+        };
+        assert_eq!(path_seg.name.as_str(), "into");
+        let (_, generic_args) = path_seg.generics_opt.unwrap();
+        assert_eq!(generic_args.parameters.span().as_str(), "<u256>");
+    }
+}
@@ -2,7 +2,7 @@ use crate::{Parse, ParseResult, Parser};
 
 use sway_ast::keywords::CommaToken;
 use sway_ast::punctuated::Punctuated;
-use sway_ast::{AngleBrackets, GenericArgs, GenericParams};
+use sway_ast::{AngleBrackets, GenericArgs, GenericParam, GenericParams};
 
 impl Parse for GenericParams {
     fn parse(parser: &mut Parser) -> ParseResult<GenericParams> {
@@ -10,6 +10,17 @@ impl Parse for GenericParams {
     }
 }
 
+impl Parse for GenericParam {
+    fn parse(parser: &mut Parser) -> ParseResult<GenericParam> {
+        let name = parser.parse()?;
+        let bounds_opt = match parser.take() {
+            Some(colon_token) => Some((colon_token, parser.parse()?)),
+            None => None,
+        };
+        Ok(GenericParam { name, bounds_opt })
+    }
+}
+
 impl Parse for GenericArgs {
     fn parse(parser: &mut Parser) -> ParseResult<GenericArgs> {
         parse_angle_comma(parser).map(|parameters| GenericArgs { parameters })
@@ -43,3 +54,29 @@ fn parse_angle_comma<T: Parse>(
         close_angle_bracket_token,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse;
+
+    #[test]
+    fn parse_generic_params_with_inline_bounds() {
+        let generic_params = parse::<GenericParams>("<A, T: From<u64> + Eq>");
+        let params = generic_params
+            .parameters
+            .into_inner()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        assert_eq!(params.len(), 2);
+
+        assert_eq!(params[0].name.as_str(), "A");
+        assert!(params[0].bounds_opt.is_none());
+
+        assert_eq!(params[1].name.as_str(), "T");
+        let (_, bounds) = params[1].bounds_opt.as_ref().unwrap();
+        assert_eq!(bounds.prefix.last_segment().name.as_str(), "From");
+        assert_eq!(bounds.suffixes.len(), 1);
+    }
+}
@@ -1,10 +1,10 @@
 use crate::{Parse, ParseResult, Parser};
 
-use sway_ast::ItemConst;
+use sway_ast::{ItemConst, PubToken};
 
 impl Parse for ItemConst {
     fn parse(parser: &mut Parser) -> ParseResult<ItemConst> {
-        let visibility = parser.take();
+        let visibility = parser.guarded_parse::<PubToken, _>()?;
         let const_token = parser.parse()?;
         let name = parser.parse()?;
         let ty_opt = match parser.take() {
@@ -1,12 +1,12 @@
 use crate::{Parse, ParseResult, Parser};
 
-use sway_ast::keywords::{OpenAngleBracketToken, WhereToken};
+use sway_ast::keywords::{OpenAngleBracketToken, PubToken, WhereToken};
 use sway_ast::ItemEnum;
 
 impl Parse for ItemEnum {
     fn parse(parser: &mut Parser) -> ParseResult<ItemEnum> {
         Ok(ItemEnum {
-            visibility: parser.take(),
+            visibility: parser.guarded_parse::<PubToken, _>()?,
             enum_token: parser.parse()?,
             name: parser.parse()?,
             generics: parser.guarded_parse::<OpenAngleBracketToken, _>()?,
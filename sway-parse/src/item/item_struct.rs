@@ -1,13 +1,15 @@
 use crate::{Parse, ParseResult, Parser};
 
-use sway_ast::keywords::{ClassToken, Keyword, OpenAngleBracketToken, StructToken, WhereToken};
+use sway_ast::keywords::{
+    ClassToken, Keyword, OpenAngleBracketToken, PubToken, StructToken, WhereToken,
+};
 use sway_ast::ItemStruct;
 use sway_error::parser_error::ParseErrorKind;
 use sway_types::Spanned;
 
 impl Parse for ItemStruct {
     fn parse(parser: &mut Parser) -> ParseResult<ItemStruct> {
-        let visibility = parser.take();
+        let visibility = parser.guarded_parse::<PubToken, _>()?;
         // Parse `struct`, or recover on `class` as if `struct` was written.
         let struct_token = if let Some(ct) = parser.take::<ClassToken>() {
             parser.emit_error(ParseErrorKind::UnexpectedClass);
@@ -37,7 +37,7 @@ impl Parse for ItemTraitItem {
 
 impl Parse for ItemTrait {
     fn parse(parser: &mut Parser) -> ParseResult<ItemTrait> {
-        let visibility = parser.take();
+        let visibility = parser.guarded_parse::<PubToken, _>()?;
         let trait_token = parser.parse()?;
         let name = parser.parse()?;
         let generics = parser.guarded_parse::<OpenAngleBracketToken, _>()?;
@@ -1,10 +1,10 @@
 use crate::{Parse, ParseResult, Parser};
 
-use sway_ast::ItemTypeAlias;
+use sway_ast::{ItemTypeAlias, PubToken};
 
 impl Parse for ItemTypeAlias {
     fn parse(parser: &mut Parser) -> ParseResult<ItemTypeAlias> {
-        let visibility = parser.take();
+        let visibility = parser.guarded_parse::<PubToken, _>()?;
         let type_token = parser.parse()?;
         let name = parser.parse()?;
         let eq_token = parser.parse()?;
@@ -2,14 +2,15 @@ use crate::{Parse, ParseResult, ParseToEnd, Parser, ParserConsumed};
 
 use sway_ast::keywords::{
     AbiToken, ClassToken, ColonToken, ConfigurableToken, ConstToken, EnumToken, FnToken, ImplToken,
-    ModToken, MutToken, OpenAngleBracketToken, RefToken, SelfToken, SemicolonToken, StorageToken,
-    StructToken, TraitToken, TypeToken, UseToken, WhereToken,
+    ModToken, MutToken, OpenAngleBracketToken, PubToken, RefToken, SelfToken, SemicolonToken,
+    StorageToken, StructToken, TraitToken, TypeToken, UseToken, WhereToken,
 };
 use sway_ast::{
     FnArg, FnArgs, FnSignature, ItemConst, ItemEnum, ItemFn, ItemKind, ItemStruct, ItemTrait,
-    ItemTypeAlias, ItemUse, Submodule, TraitType, TypeField,
+    ItemTypeAlias, ItemUse, Submodule, TraitType, TypeField, VisibilityQualifier,
 };
 use sway_error::parser_error::ParseErrorKind;
+use sway_types::Spanned;
 
 mod item_abi;
 mod item_configurable;
@@ -29,13 +30,13 @@ impl Parse for ItemKind {
         // introducing a struct `Item` that holds the visibility and the kind,
         // and then validate in an "AST validation" step which kinds that should have `pub`s.
 
-        let mut visibility = parser.take();
+        let mut visibility = parser.guarded_parse::<PubToken, VisibilityQualifier>()?;
 
         let kind = if let Some(mut item) = parser.guarded_parse::<ModToken, Submodule>()? {
-            item.visibility = visibility.take();
+            item.visibility = unrestricted_pub_token(parser, visibility.take(), "module");
             ItemKind::Submodule(item)
         } else if let Some(mut item) = parser.guarded_parse::<UseToken, ItemUse>()? {
-            item.visibility = visibility.take();
+            item.visibility = unrestricted_pub_token(parser, visibility.take(), "use");
             ItemKind::Use(item)
         } else if let Some(mut item) = parser.guarded_parse::<ClassToken, ItemStruct>()? {
             item.visibility = visibility.take();
@@ -92,6 +93,23 @@ impl Parse for ItemKind {
     }
 }
 
+/// Returns the `pub` token of the `visibility` of an item that does not support
+/// visibility restrictions, erroring, but with recovery, if a restriction is given.
+fn unrestricted_pub_token(
+    parser: &mut Parser,
+    visibility: Option<VisibilityQualifier>,
+    item_kind: &'static str,
+) -> Option<PubToken> {
+    let visibility = visibility?;
+    if let Some(restriction) = &visibility.restriction_opt {
+        parser.emit_error_with_span(
+            ParseErrorKind::UnsupportedVisibilityRestriction { item_kind },
+            restriction.span(),
+        );
+    }
+    Some(visibility.pub_token)
+}
+
 impl Parse for TypeField {
     fn parse(parser: &mut Parser) -> ParseResult<TypeField> {
         let visibility = parser.take();
@@ -104,6 +122,10 @@ impl Parse for TypeField {
                 Err(parser.emit_error(ParseErrorKind::MissingColonInEnumTypeField))
             }?,
             ty: parser.parse()?,
+            default_opt: match parser.take() {
+                Some(eq_token) => Some((eq_token, parser.parse()?)),
+                None => None,
+            },
         })
     }
 }
@@ -170,7 +192,7 @@ impl Parse for FnArg {
 impl Parse for FnSignature {
     fn parse(parser: &mut Parser) -> ParseResult<FnSignature> {
         Ok(FnSignature {
-            visibility: parser.take(),
+            visibility: parser.guarded_parse::<PubToken, _>()?,
             fn_token: parser.parse()?,
             name: parser.parse()?,
             generics: parser.guarded_parse::<OpenAngleBracketToken, _>()?,
@@ -213,7 +235,8 @@ impl Parse for TraitType {
 mod tests {
     use super::*;
     use crate::test_utils::parse;
-    use sway_ast::{AttributeDecl, Item, ItemTraitItem};
+    use assert_matches::*;
+    use sway_ast::{AttributeDecl, Item, ItemTraitItem, VisibilityRestriction};
 
     // Attribute name and its list of parameters
     type ParameterizedAttr<'a> = (&'a str, Option<Vec<&'a str>>);
@@ -655,4 +678,77 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn parse_struct_field_defaults() {
+        let item = parse::<Item>(
+            r#"
+            struct S {
+                a: u64 = 42,
+                b: bool,
+                pub c: u8 = 1 + 2,
+            }
+            "#,
+        );
+
+        if let ItemKind::Struct(item_struct) = item.value {
+            let fields = item_struct
+                .fields
+                .get()
+                .into_iter()
+                .map(|field| (field.value.name.as_str(), field.value.default_opt.is_some()))
+                .collect::<Vec<_>>();
+            assert_eq!(fields, vec![("a", true), ("b", false), ("c", true)]);
+        } else {
+            panic!("Parsed item is not a struct.");
+        }
+    }
+
+    #[test]
+    fn parse_restricted_visibility() {
+        let item = parse::<Item>("pub(crate) struct S {}");
+        if let ItemKind::Struct(item_struct) = item.value {
+            let visibility = item_struct.visibility.unwrap();
+            assert_matches!(
+                visibility
+                    .restriction_opt
+                    .map(|restriction| restriction.inner),
+                Some(VisibilityRestriction::Crate { .. })
+            );
+        } else {
+            panic!("Parsed item is not a struct.");
+        }
+
+        let item = parse::<Item>("pub(in a::b) fn f() {}");
+        if let ItemKind::Fn(item_fn) = item.value {
+            let visibility = item_fn.fn_signature.visibility.unwrap();
+            match visibility
+                .restriction_opt
+                .map(|restriction| restriction.inner)
+            {
+                Some(VisibilityRestriction::In { path, .. }) => {
+                    let path = path
+                        .into_iter()
+                        .map(|ident| ident.to_string())
+                        .collect::<Vec<_>>();
+                    assert_eq!(path, vec!["a", "b"]);
+                }
+                _ => panic!("Parsed visibility is not restricted to a path."),
+            }
+        } else {
+            panic!("Parsed item is not a function.");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "ExpectedVisibilityRestrictionPath")]
+    fn parse_restricted_visibility_empty_path() {
+        parse::<Item>("pub(in) fn f() {}");
+    }
+
+    #[test]
+    #[should_panic(expected = "ExpectedVisibilityRestrictionPath")]
+    fn parse_restricted_visibility_trailing_double_colon() {
+        parse::<Item>("pub(in a::) fn f() {}");
+    }
 }
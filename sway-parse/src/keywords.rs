@@ -64,6 +64,7 @@ keyword_impls! {
     MutToken,
     LetToken,
     WhileToken,
+    LoopToken,
     WhereToken,
     RefToken,
     TrueToken,
@@ -151,7 +152,9 @@ token_impls! {
     DoublePipeToken,
     UnderscoreToken,
     HashToken,
-    HashBangToken
+    HashBangToken,
+    QuestionMarkToken,
+    AtToken
 }
 
 // Keep this in sync with the list in `sway-ast/keywords.rs` defined by define_keyword!
@@ -17,6 +17,7 @@ mod submodule;
 mod test_utils;
 mod token;
 mod ty;
+mod visibility;
 mod where_clause;
 
 use crate::priv_prelude::*;
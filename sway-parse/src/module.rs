@@ -47,7 +47,11 @@ impl ParseToEnd for Annotated<Module> {
                                 doc_comment.span.clone(),
                             ),
                             args: Some(Parens::new(
-                                Punctuated::single(AttributeArg { name, value: None }),
+                                Punctuated::single(AttributeArg {
+                                    name,
+                                    value: None,
+                                    args: None,
+                                }),
                                 doc_comment.content_span,
                             )),
                         }),
@@ -6,7 +6,7 @@ use sway_ast::literal::Literal;
 use sway_ast::token::{
     DocComment, GenericTokenTree, Group, Punct, Spacing, TokenStream, TokenTree,
 };
-use sway_ast::PubToken;
+use sway_ast::VisibilityQualifier;
 use sway_error::error::CompileError;
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_error::parser_error::{ParseError, ParseErrorKind};
@@ -293,14 +293,17 @@ impl<'a, 'e> Parser<'a, 'e> {
         &self.token_trees[..len]
     }
 
-    /// Errors given `Some(PubToken)`.
-    pub fn ban_visibility_qualifier(&mut self, vis: &Option<PubToken>) -> ParseResult<()> {
-        if let Some(token) = vis {
+    /// Errors given `Some(VisibilityQualifier)`.
+    pub fn ban_visibility_qualifier(
+        &mut self,
+        vis: &Option<VisibilityQualifier>,
+    ) -> ParseResult<()> {
+        if let Some(visibility) = vis {
             return Err(self.emit_error_with_span(
                 ParseErrorKind::UnnecessaryVisibilityQualifier {
-                    visibility: token.ident(),
+                    visibility: visibility.pub_token.ident(),
                 },
-                token.span(),
+                visibility.span(),
             ));
         }
         Ok(())
@@ -1,12 +1,12 @@
 use crate::{Parse, ParseBracket, ParseResult, Parser, Peek};
 
-use sway_ast::brackets::{Braces, Parens};
-use sway_ast::keywords::{DoubleDotToken, FalseToken, TrueToken};
+use sway_ast::brackets::{Braces, Parens, SquareBrackets};
+use sway_ast::keywords::{AtToken, DoubleDotToken, FalseToken, TrueToken};
 use sway_ast::literal::{LitBool, LitBoolType};
 use sway_ast::punctuated::Punctuated;
-use sway_ast::{Literal, PathExpr, Pattern, PatternStructField};
+use sway_ast::{Literal, PathExpr, Pattern, PatternSliceElement, PatternStructField};
 use sway_error::parser_error::ParseErrorKind;
-use sway_types::Spanned;
+use sway_types::{Ident, Spanned};
 
 impl Parse for Pattern {
     fn parse(parser: &mut Parser) -> ParseResult<Pattern> {
@@ -69,6 +69,22 @@ fn parse_atomic_pattern(parser: &mut Parser) -> ParseResult<Pattern> {
         if let Some(tuple) = Parens::try_parse(parser)? {
             return Ok(Pattern::Tuple(tuple));
         }
+        if let Some(slice) = SquareBrackets::try_parse(parser)? {
+            let elements: &Punctuated<_, _> = slice.get();
+            let second_rest_pattern = elements
+                .into_iter()
+                .filter(|p| matches!(p, PatternSliceElement::Rest { .. }))
+                .nth(1);
+
+            if let Some(rest_pattern) = second_rest_pattern {
+                return Err(parser.emit_error_with_span(
+                    ParseErrorKind::MultipleRestPatternsInSlice,
+                    rest_pattern.span(),
+                ));
+            }
+
+            return Ok(Pattern::Slice(slice));
+        }
         if let Some(underscore_token) = parser.take() {
             return Ok(Pattern::Wildcard { underscore_token });
         }
@@ -123,3 +139,25 @@ impl Parse for PatternStructField {
         })
     }
 }
+
+impl Parse for PatternSliceElement {
+    fn parse(parser: &mut Parser) -> ParseResult<PatternSliceElement> {
+        if let Some(token) = parser.take::<DoubleDotToken>() {
+            return Ok(PatternSliceElement::Rest {
+                name_opt: None,
+                token,
+            });
+        }
+        if parser.peek::<(Ident, AtToken)>().is_some() {
+            let name = parser.parse()?;
+            let at_token = parser.parse()?;
+            let token = parser.parse()?;
+            return Ok(PatternSliceElement::Rest {
+                name_opt: Some((name, at_token)),
+                token,
+            });
+        }
+
+        Ok(PatternSliceElement::Pattern(Box::new(parser.parse()?)))
+    }
+}
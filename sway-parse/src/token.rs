@@ -60,6 +60,8 @@ impl CharExt for char {
             '|' => Some(PunctKind::Pipe),
             '_' => Some(PunctKind::Underscore),
             '#' => Some(PunctKind::Sharp),
+            '?' => Some(PunctKind::QuestionMark),
+            '@' => Some(PunctKind::At),
             _ => None,
         }
     }
@@ -189,8 +191,26 @@ pub fn lex_commented(
                     continue;
                 }
                 Some((_, '*')) => {
-                    if let Some(token) = lex_block_comment(&mut l, index) {
-                        token_trees.push(token);
+                    if let Some(comment) = lex_block_comment(&mut l, index) {
+                        match lex_block_doc_comments(
+                            &l,
+                            &comment,
+                            file_start_offset,
+                            gather_module_docs,
+                        ) {
+                            Some(doc_comments) => {
+                                if doc_comments
+                                    .iter()
+                                    .any(|doc_comment| doc_comment.doc_style == DocStyle::Inner)
+                                {
+                                    gather_module_docs = true;
+                                }
+                                token_trees.extend(doc_comments.into_iter().map(|doc_comment| {
+                                    CommentedTokenTree::Tree(doc_comment.into())
+                                }));
+                            }
+                            None => token_trees.push(comment.into()),
+                        }
                     }
                     continue;
                 }
@@ -404,7 +424,7 @@ fn lex_line_comment(
     }
 }
 
-fn lex_block_comment(l: &mut Lexer<'_>, index: usize) -> Option<CommentedTokenTree> {
+fn lex_block_comment(l: &mut Lexer<'_>, index: usize) -> Option<Comment> {
     // Lexing a multi-line comment.
     let _ = l.stream.next();
     let mut unclosed_indices = vec![index];
@@ -432,8 +452,18 @@ fn lex_block_comment(l: &mut Lexer<'_>, index: usize) -> Option<CommentedTokenTr
                         // nested multi-line comments constitute a single multi-line comment.
                         // We could represent them as several ones, but that's unnecessary.
                         let end = slash_ix + '/'.len_utf8();
+                        // A block comment that is alone on its line is treated the same way as
+                        // a newlined line comment.
+                        // Example:
+                        // /* this is a newlined block comment */
+                        // fn foo() {}
+                        if comment_kind == CommentKind::Inlined
+                            && is_alone_on_line(&l.src[..start], &l.src[end..])
+                        {
+                            comment_kind = CommentKind::Newlined;
+                        }
                         let span = span(l, start, end);
-                        return Some(Comment { span, comment_kind }.into());
+                        return Some(Comment { span, comment_kind });
                     }
                 }
                 Some(_) => {}
@@ -456,6 +486,116 @@ fn lex_block_comment(l: &mut Lexer<'_>, index: usize) -> Option<CommentedTokenTr
     }
 }
 
+/// Splits a block doc comment, i.e., `/** ... */` or `/*! ... */`, into one doc comment per line,
+/// so that it results in the same doc attributes as the equivalent `///` or `//!` line doc comments.
+///
+/// A leading margin of `*`s, common to all the lines after the first one, is stripped. Otherwise,
+/// the common indentation of those lines is stripped, keeping a single space in front of the text.
+/// Blank first and last lines, e.g., in `/**\n * Docs.\n */`, are ignored.
+///
+/// Returns `None` if the `comment` is not a block doc comment.
+fn lex_block_doc_comments(
+    l: &Lexer<'_>,
+    comment: &Comment,
+    offset: usize,
+    gather_module_docs: bool,
+) -> Option<Vec<DocComment>> {
+    let start = comment.span.start();
+    let end = comment.span.end();
+    let doc_style = match (
+        comment.span.as_str().chars().nth(2),
+        comment.span.as_str().chars().nth(3),
+    ) {
+        // `/*!` is an inner block doc comment.
+        // TODO(#4112): remove the condition to enable
+        // inner doc comment attributes for all items
+        (Some('!'), _) if start - offset == 0 || gather_module_docs => DocStyle::Inner,
+        // `/***` is not considered a doc comment, and neither is the empty `/**/`.
+        (Some('*'), Some('*' | '/')) => return None,
+        // `/**` is an outer block doc comment.
+        (Some('*'), _) => DocStyle::Outer,
+        _ => return None,
+    };
+
+    // The lines in between the opening `/**` or `/*!` and the closing `*/`.
+    let inner_start = start + 3;
+    let inner_end = end - 2;
+    let mut lines = vec![];
+    let mut line_start = inner_start;
+    for (ix, character) in l.src[inner_start..inner_end].char_indices() {
+        if character == '\n' {
+            lines.push(line_start..inner_start + ix);
+            line_start = inner_start + ix + 1;
+        }
+    }
+    lines.push(line_start..inner_end);
+
+    let is_blank = |line: &std::ops::Range<usize>| l.src[line.clone()].trim().is_empty();
+    let margin_lines = || lines.iter().skip(1).filter(|line| !is_blank(line));
+    let is_starred = margin_lines().all(|line| l.src[line.clone()].trim_start().starts_with('*'));
+    let indentation = margin_lines()
+        .map(|line| {
+            let text = &l.src[line.clone()];
+            text.len() - text.trim_start_matches([' ', '\t']).len()
+        })
+        .min()
+        .unwrap_or_default();
+
+    let last = lines.len() - 1;
+    let mut doc_comments = lines
+        .iter()
+        .enumerate()
+        .filter(|(ix, line)| lines.len() == 1 || !((*ix == 0 || *ix == last) && is_blank(line)))
+        .map(|(ix, line)| {
+            let text = &l.src[line.clone()];
+            let content_start = if ix == 0 {
+                line.start
+            } else if is_blank(line) {
+                line.end
+            } else if is_starred {
+                line.end - text.trim_start().len() + '*'.len_utf8()
+            } else {
+                line.start + indentation.saturating_sub(1)
+            };
+            let content_end = if ix == last {
+                line.start + text.trim_end().len()
+            } else {
+                line.end
+            };
+            DocComment {
+                span: span(l, line.start, line.end),
+                content_span: span(l, content_start, content_end.max(content_start)),
+                doc_style: doc_style.clone(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if doc_comments.is_empty() {
+        return None;
+    }
+
+    // Together, the doc comments span the whole block comment.
+    if let Some(first) = doc_comments.first_mut() {
+        first.span = span(l, start, first.span.end());
+    }
+    if let Some(last) = doc_comments.last_mut() {
+        last.span = span(l, last.span.start(), end);
+    }
+    Some(doc_comments)
+}
+
+/// Returns `true` if there is nothing else but whitespace between the closest newlines
+/// (or the beginning or the end of the source) found in `before` and `after`.
+fn is_alone_on_line(before: &str, after: &str) -> bool {
+    let is_line_break = |c: Option<char>| matches!(c, None | Some('\n'));
+    is_line_break(
+        before
+            .chars()
+            .rev()
+            .find(|c| !c.is_whitespace() || *c == '\n'),
+    ) && is_line_break(after.chars().find(|c| !c.is_whitespace() || *c == '\n'))
+}
+
 fn lex_string(
     l: &mut Lexer<'_>,
     index: usize,
@@ -1013,6 +1153,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn lex_block_comments_check_comment_kind() {
+        let input = r#"
+        /* CommentKind::Newlined */
+        fn foo(/* CommentKind::Inlined */ bar: u64) {
+            let _x = bar; /* CommentKind::Inlined */
+            /* CommentKind::Multilined
+             */
+        }
+        "#;
+        let start = 0;
+        let end = input.len();
+        let path = None;
+        let handler = Handler::default();
+        let stream = lex_commented(&handler, &Arc::from(input), start, end, &path).unwrap();
+        assert!(handler.consume().0.is_empty());
+        let mut tts = stream.token_trees().iter();
+
+        assert_matches!(
+            tts.next(),
+            Some(CommentedTokenTree::Comment(Comment {
+                span,
+                comment_kind: CommentKind::Newlined,
+            })) if span.as_str() ==  "/* CommentKind::Newlined */"
+        );
+        assert_eq!(tts.next().unwrap().span().as_str(), "fn");
+        assert_eq!(tts.next().unwrap().span().as_str(), "foo");
+
+        {
+            let group = match tts.next() {
+                Some(CommentedTokenTree::Tree(CommentedTree::Group(group))) => group,
+                _ => panic!("expected group"),
+            };
+            let mut tts = group.token_stream.token_trees().iter();
+            assert_matches!(
+                tts.next(),
+                Some(CommentedTokenTree::Comment(Comment {
+                    span,
+                    comment_kind: CommentKind::Inlined,
+                })) if span.as_str() ==  "/* CommentKind::Inlined */"
+            );
+        }
+
+        {
+            let group = match tts.next() {
+                Some(CommentedTokenTree::Tree(CommentedTree::Group(group))) => group,
+                _ => panic!("expected group"),
+            };
+            let mut tts = group
+                .token_stream
+                .token_trees()
+                .iter()
+                .filter(|tt| matches!(tt, CommentedTokenTree::Comment(_)));
+            assert_matches!(
+                tts.next(),
+                Some(CommentedTokenTree::Comment(Comment {
+                    span,
+                    comment_kind: CommentKind::Inlined,
+                })) if span.as_str() ==  "/* CommentKind::Inlined */"
+            );
+            assert_matches!(
+                tts.next(),
+                Some(CommentedTokenTree::Comment(Comment {
+                    comment_kind: CommentKind::Multilined,
+                    ..
+                }))
+            );
+            assert!(tts.next().is_none());
+        }
+    }
+
     #[test]
     fn lex_doc_comments() {
         let input = r#"
@@ -1077,6 +1288,57 @@ mod tests {
         assert_eq!(tts.next(), None);
     }
 
+    #[test]
+    fn lex_block_doc_comments() {
+        let input = "/*! Module docs. */\n/***none*/\n/**/\n/** Outer\n * docs.\n */";
+        let start = 0;
+        let end = input.len();
+        let path = None;
+        let handler = Handler::default();
+        let stream = lex_commented(&handler, &Arc::from(input), start, end, &path).unwrap();
+        assert!(handler.consume().0.is_empty());
+        let mut tts = stream.token_trees().iter();
+        assert_matches!(
+            tts.next(),
+            Some(CommentedTokenTree::Tree(CommentedTree::DocComment(DocComment {
+                doc_style: DocStyle::Inner,
+                span,
+                content_span
+            }))) if span.as_str() == "/*! Module docs. */" && content_span.as_str() == " Module docs."
+        );
+        assert_matches!(
+            tts.next(),
+            Some(CommentedTokenTree::Comment(Comment {
+                span,
+                comment_kind: CommentKind::Newlined,
+            })) if span.as_str() == "/***none*/"
+        );
+        assert_matches!(
+            tts.next(),
+            Some(CommentedTokenTree::Comment(Comment {
+                span,
+                comment_kind: CommentKind::Newlined,
+            })) if span.as_str() == "/**/"
+        );
+        assert_matches!(
+            tts.next(),
+            Some(CommentedTokenTree::Tree(CommentedTree::DocComment(DocComment {
+                doc_style: DocStyle::Outer,
+                span,
+                content_span
+            }))) if span.as_str() == "/** Outer" && content_span.as_str() == " Outer"
+        );
+        assert_matches!(
+            tts.next(),
+            Some(CommentedTokenTree::Tree(CommentedTree::DocComment(DocComment {
+                doc_style: DocStyle::Outer,
+                span,
+                content_span
+            }))) if span.as_str() == " * docs.\n */" && content_span.as_str() == " docs."
+        );
+        assert_eq!(tts.next(), None);
+    }
+
     #[test]
     fn lex_char_escaped_quote() {
         let input = r"
@@ -0,0 +1,60 @@
+use crate::{Parse, ParseBracket, ParseResult, ParseToEnd, Parser, ParserConsumed};
+
+use sway_ast::brackets::Parens;
+use sway_ast::keywords::{DoubleColonToken, InToken};
+use sway_ast::{Punctuated, VisibilityQualifier, VisibilityRestriction};
+use sway_error::parser_error::ParseErrorKind;
+use sway_types::{Ident, Span, Spanned};
+
+impl Parse for VisibilityQualifier {
+    fn parse(parser: &mut Parser) -> ParseResult<VisibilityQualifier> {
+        let pub_token = parser.parse()?;
+        let restriction_opt = Parens::try_parse(parser)?;
+        Ok(VisibilityQualifier {
+            pub_token,
+            restriction_opt,
+        })
+    }
+}
+
+impl ParseToEnd for VisibilityRestriction {
+    fn parse_to_end<'a, 'e>(
+        mut parser: Parser<'a, '_>,
+    ) -> ParseResult<(VisibilityRestriction, ParserConsumed<'a>)> {
+        if let Some(in_token) = parser.take::<InToken>() {
+            let mut value_separator_pairs: Vec<(Ident, DoubleColonToken)> = Vec::new();
+            loop {
+                // An empty path, or a path ending in `::`, does not name a module.
+                let Some(ident) = parser.take::<Ident>() else {
+                    let span = match value_separator_pairs.last() {
+                        Some((_, double_colon_token)) => {
+                            Span::join(in_token.span(), &double_colon_token.span())
+                        }
+                        None => in_token.span(),
+                    };
+                    return Err(parser.emit_error_with_span(
+                        ParseErrorKind::ExpectedVisibilityRestrictionPath,
+                        span,
+                    ));
+                };
+                if let Some(consumed) = parser.check_empty() {
+                    let path = Punctuated {
+                        value_separator_pairs,
+                        final_value_opt: Some(Box::new(ident)),
+                    };
+                    return Ok((VisibilityRestriction::In { in_token, path }, consumed));
+                }
+                let double_colon_token = parser.parse()?;
+                value_separator_pairs.push((ident, double_colon_token));
+            }
+        }
+        if let Some(crate_ident) = parser.take::<Ident>() {
+            if crate_ident.as_str() == "crate" {
+                if let Some(consumed) = parser.check_empty() {
+                    return Ok((VisibilityRestriction::Crate { crate_ident }, consumed));
+                }
+            }
+        }
+        Err(parser.emit_error(ParseErrorKind::ExpectedVisibilityRestriction))
+    }
+}
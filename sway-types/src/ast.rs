@@ -42,6 +42,8 @@ pub enum PunctKind {
     Pipe,
     Underscore,
     Sharp,
+    QuestionMark,
+    At,
 }
 
 impl PunctKind {
@@ -65,6 +67,8 @@ impl PunctKind {
             PunctKind::Pipe => '|',
             PunctKind::Underscore => '_',
             PunctKind::Sharp => '#',
+            PunctKind::QuestionMark => '?',
+            PunctKind::At => '@',
         }
     }
 }
@@ -37,6 +37,9 @@ pub const DOC_COMMENT_ATTRIBUTE_NAME: &str = "doc-comment";
 /// The attribute used for Sway in-language unit tests.
 pub const TEST_ATTRIBUTE_NAME: &str = "test";
 
+/// The attribute used for Sway in-language benchmarks.
+pub const BENCH_ATTRIBUTE_NAME: &str = "bench";
+
 /// The valid attribute string used for payable functions.
 pub const PAYABLE_ATTRIBUTE_NAME: &str = "payable";
 
@@ -44,15 +47,27 @@ pub const PAYABLE_ATTRIBUTE_NAME: &str = "payable";
 pub const ALLOW_ATTRIBUTE_NAME: &str = "allow";
 pub const ALLOW_DEAD_CODE_NAME: &str = "dead_code";
 pub const ALLOW_DEPRECATED_NAME: &str = "deprecated";
+pub const ALLOW_UNUSED_STORAGE_NAME: &str = "unused_storage";
+pub const ALLOW_REDUNDANT_CLONE_NAME: &str = "redundant_clone";
 
 /// The valid attribute strings related to conditional compilation.
 pub const CFG_ATTRIBUTE_NAME: &str = "cfg";
 pub const CFG_TARGET_ARG_NAME: &str = "target";
 pub const CFG_PROGRAM_TYPE_ARG_NAME: &str = "program_type";
 pub const CFG_EXPERIMENTAL_NEW_ENCODING: &str = "experimental_new_encoding";
+pub const CFG_TEST_ARG_NAME: &str = "test";
+pub const CFG_FEATURE_ARG_NAME: &str = "feature";
+pub const CFG_ALL_ARG_NAME: &str = "all";
+pub const CFG_ANY_ARG_NAME: &str = "any";
+pub const CFG_NOT_ARG_NAME: &str = "not";
 
 pub const DEPRECATED_ATTRIBUTE_NAME: &str = "deprecated";
 
+/// The valid attribute strings related to deriving trait implementations.
+pub const DERIVE_ATTRIBUTE_NAME: &str = "derive";
+pub const DERIVE_ORD_ARG_NAME: &str = "Ord";
+pub const DERIVE_PARTIAL_ORD_ARG_NAME: &str = "PartialOrd";
+
 pub const FALLBACK_ATTRIBUTE_NAME: &str = "fallback";
 
 /// The list of valid attributes.
@@ -61,11 +76,13 @@ pub const VALID_ATTRIBUTE_NAMES: &[&str] = &[
     DOC_ATTRIBUTE_NAME,
     DOC_COMMENT_ATTRIBUTE_NAME,
     TEST_ATTRIBUTE_NAME,
+    BENCH_ATTRIBUTE_NAME,
     INLINE_ATTRIBUTE_NAME,
     PAYABLE_ATTRIBUTE_NAME,
     ALLOW_ATTRIBUTE_NAME,
     CFG_ATTRIBUTE_NAME,
     DEPRECATED_ATTRIBUTE_NAME,
+    DERIVE_ATTRIBUTE_NAME,
     FALLBACK_ATTRIBUTE_NAME,
 ];
 
@@ -49,26 +49,44 @@ pub fn find_nested_manifest_dir(starter_path: &Path) -> Option<PathBuf> {
     find_nested_dir_with_file(starter_path, constants::MANIFEST_FILE_NAME)
 }
 
+/// Continually go down in the file tree until a Forc manifest file is found, without searching
+/// within the `excluded` directory.
+pub fn find_nested_manifest_dir_excluding(starter_path: &Path, excluded: &Path) -> Option<PathBuf> {
+    find_nested_dir_with_file_excluding(starter_path, constants::MANIFEST_FILE_NAME, Some(excluded))
+}
+
 /// Continually go down in the file tree until a specified file is found.
 ///
 /// Starts the search from child dirs of `starter_path`.
 pub fn find_nested_dir_with_file(starter_path: &Path, file_name: &str) -> Option<PathBuf> {
+    find_nested_dir_with_file_excluding(starter_path, file_name, None)
+}
+
+fn find_nested_dir_with_file_excluding(
+    starter_path: &Path,
+    file_name: &str,
+    excluded: Option<&Path>,
+) -> Option<PathBuf> {
     let starter_dir = if starter_path.is_dir() {
         starter_path
     } else {
         starter_path.parent()?
     };
-    WalkDir::new(starter_path).into_iter().find_map(|e| {
-        let entry = e.ok()?;
-        if entry.path() != starter_dir.join(file_name) && entry.file_name() == OsStr::new(file_name)
-        {
-            let mut entry = entry.path().to_path_buf();
-            entry.pop();
-            Some(entry)
-        } else {
-            None
-        }
-    })
+    WalkDir::new(starter_path)
+        .into_iter()
+        .filter_entry(|entry| Some(entry.path()) != excluded)
+        .find_map(|e| {
+            let entry = e.ok()?;
+            if entry.path() != starter_dir.join(file_name)
+                && entry.file_name() == OsStr::new(file_name)
+            {
+                let mut entry = entry.path().to_path_buf();
+                entry.pop();
+                Some(entry)
+            } else {
+                None
+            }
+        })
 }
 
 /// Continually go up in the file tree until a specified file is found.
@@ -107,8 +107,14 @@ pub fn write_comments(
             writeln!(formatted_code)?;
         }
 
+        // A comment is followed by at most `newline_threshold` empty lines.
+        let max_newlines = formatter.config.whitespace.newline_threshold + 1;
         for comment in comments_iter {
-            let newlines = collect_newlines_after_comment(&formatter.comments_context, comment);
+            let newlines: String =
+                collect_newlines_after_comment(&formatter.comments_context, comment)
+                    .chars()
+                    .take(max_newlines)
+                    .collect();
 
             match comment.comment_kind {
                 CommentKind::Newlined => {
@@ -132,9 +138,10 @@ pub fn write_comments(
                 CommentKind::Multilined => {
                     write!(
                         formatted_code,
-                        "{}{}",
+                        "{}{}{}",
                         formatter.indent_to_str()?,
                         comment.span().as_str(),
+                        newlines
                     )?;
                 }
             }
@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 pub struct Items {
     /// Brace style for items.
     pub item_brace_style: ItemBraceStyle,
-    /// Maximum number of blank lines which can be put between items.
+    /// Maximum number of blank lines which can be put between the statements of a function body.
     pub blank_lines_upper_bound: usize,
     /// Minimum number of blank lines which must be put between items.
     pub blank_lines_lower_bound: usize,
@@ -8,6 +8,10 @@ pub struct Literals {
     pub format_strings: bool,
     /// Format hexadecimal integer literals.
     pub hex_literal_case: HexLiteralCase,
+    /// Separate the digits of decimal integer literals into groups of the given size,
+    /// e.g. `1_000_000` for a size of 3. Only literals with more digits than the group
+    /// size are regrouped. Leave the literals as written if `None`.
+    pub decimal_digit_group_size: Option<usize>,
 }
 
 impl Literals {
@@ -16,6 +20,9 @@ impl Literals {
         Self {
             format_strings: opts.format_strings.unwrap_or(default.format_strings),
             hex_literal_case: opts.hex_literal_case.unwrap_or(default.hex_literal_case),
+            decimal_digit_group_size: opts
+                .decimal_digit_group_size
+                .or(default.decimal_digit_group_size),
         }
     }
 }
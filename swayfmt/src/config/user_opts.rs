@@ -46,6 +46,7 @@ pub struct ItemsOptions {
 pub struct LiteralsOptions {
     pub format_strings: Option<bool>,
     pub hex_literal_case: Option<HexLiteralCase>,
+    pub decimal_digit_group_size: Option<usize>,
 }
 /// See parent struct [Expressions].
 #[derive(Serialize, Deserialize, Debug, Copy, Clone)]
@@ -21,7 +21,8 @@ pub struct Whitespace {
     pub newline_style: NewlineStyle,
     /// How we indent expressions or items.
     pub indent_style: IndentStyle,
-    /// Max number of newlines allowed between statements before collapsing them to threshold
+    /// Max number of newlines allowed between items before collapsing them to threshold.
+    /// Within function bodies, `Items::blank_lines_upper_bound` is used instead.
     pub newline_threshold: usize,
 }
 
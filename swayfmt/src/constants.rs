@@ -29,7 +29,7 @@ pub const DEFAULT_SINGLE_LINE_IF_ELSE_WIDTH: usize = 50;
 
 //ITEMS
 
-/// Default max number of blank lines which can be put between items.
+/// Default max number of blank lines which can be put between the statements of a function body.
 pub const DEFAULT_BLANK_LINES_UPPER_BOUND: usize = 1;
 /// Default min number of blank lines which must be put between items.
 pub const DEFAULT_BLANK_LINES_LOWER_BOUND: usize = 0;
@@ -69,3 +69,9 @@ pub const DEFAULT_NEWLINE_THRESHOLD: usize = 1;
 
 //IDENT
 pub(crate) const RAW_MODIFIER: &str = "r#";
+
+//SKIP
+
+/// Comment that, when put on the line right before an item or a statement, makes the formatter
+/// keep that item or statement exactly as it is written.
+pub(crate) const SKIP_COMMENT: &str = "// swayfmt::skip";
@@ -1,7 +1,8 @@
 use self::shape::Shape;
 use crate::comments::{write_comments, CommentsContext};
+use crate::constants::SKIP_COMMENT;
 use crate::parse::parse_file;
-use crate::utils::map::comments::CommentMap;
+use crate::utils::map::{byte_span::ByteSpan, comments::CommentMap};
 use crate::utils::map::{newline::handle_newlines, newline_style::apply_newline_style};
 pub use crate::{
     config::manifest::Config,
@@ -19,6 +20,9 @@ pub struct Formatter {
     pub shape: Shape,
     pub config: Config,
     pub comments_context: CommentsContext,
+    /// Spans of the unformatted code that were written out as they are, because of a preceding
+    /// `// swayfmt::skip` comment.
+    pub(crate) skipped_spans: Vec<ByteSpan>,
 }
 
 pub type FormattedCode = String;
@@ -80,6 +84,51 @@ impl Formatter {
         Ok(self)
     }
 
+    /// Writes the original source of the node with the given `leaf_spans` into the buffer, if the
+    /// node is preceded by a `// swayfmt::skip` comment on the line right before it.
+    ///
+    /// Only the first line of the node is indented, the following lines are kept exactly as
+    /// they are written, as well as the comments within the node.
+    ///
+    /// Returns `true` if the node was skipped, and `false` if it must be formatted.
+    pub(crate) fn write_skipped(
+        &mut self,
+        formatted_code: &mut FormattedCode,
+        leaf_spans: &[ByteSpan],
+    ) -> Result<bool, FormatterError> {
+        let (Some(first), Some(last)) = (leaf_spans.first(), leaf_spans.last()) else {
+            return Ok(false);
+        };
+        let span = ByteSpan {
+            start: first.start,
+            end: last.end,
+        };
+        let unformatted_code = self.comments_context.unformatted_code();
+        let (Some(preceding_code), Some(skipped_code)) = (
+            unformatted_code.get(..span.start),
+            unformatted_code.get(span.start..span.end),
+        ) else {
+            return Ok(false);
+        };
+        let preceding_line = preceding_code.trim_end().lines().last().unwrap_or_default();
+        if preceding_line.trim_start() != SKIP_COMMENT {
+            return Ok(false);
+        }
+
+        let skipped_code = skipped_code.to_string();
+        self.write_indent_into_buffer(formatted_code)?;
+        write!(formatted_code, "{skipped_code}")?;
+
+        // The comments within the node are already written out with it.
+        let range = span.start..span.end;
+        self.comments_context
+            .map
+            .retain(|bs, _| !bs.contained_within(&range));
+        self.skipped_spans.push(span);
+
+        Ok(true)
+    }
+
     pub fn format(
         &mut self,
         src: Arc<str>,
@@ -102,6 +151,7 @@ impl Formatter {
         let mut raw_formatted_code = String::with_capacity(src.len());
 
         self.with_comments_context(src)?;
+        self.skipped_spans.clear();
 
         let annotated_module = parse_file(&self.source_engine, Arc::from(src), path.clone())?;
         annotated_module.format(&mut raw_formatted_code, self)?;
@@ -132,3 +132,29 @@ intermediate_whitespace
     let val = 1;      // this is a normal comment
 }"
 );
+
+fmt_test_item!(fn_generic_params_with_inline_bounds
+"fn convert<A, T: From<u64> + Eq>(a: A) -> T {
+    T::from(1)
+}",
+intermediate_whitespace
+"fn convert< A ,T :From<u64>+   Eq >(a: A) -> T {
+    T::from(1)
+}"
+);
+
+fmt_test_item!(  restricted_visibility_fn
+"pub(crate) fn foo() -> u64 {
+    42
+}",
+            intermediate_whitespace
+"pub(crate)   fn   foo ( )  ->  u64 {
+   42 }"
+);
+
+fmt_test_item!(  restricted_visibility_in_path_fn
+"pub(in a::b) fn foo() {}",
+            intermediate_whitespace
+"pub(in a::b)   fn foo(  ) {
+}"
+);
@@ -122,6 +122,10 @@ impl Format for ItemStruct {
                                 type_field.colon_token.span().as_str(),
                             )?;
                             type_field.ty.format(formatted_code, formatter)?;
+                            if let Some((eq_token, default)) = &type_field.default_opt {
+                                write!(formatted_code, " {} ", eq_token.span().as_str())?;
+                                default.format(formatted_code, formatter)?;
+                            }
                             writeln!(formatted_code, "{}", comma_token.span().as_str())?;
                         }
                         if let Some(final_value) = &fields.final_value_opt {
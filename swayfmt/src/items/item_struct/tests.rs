@@ -38,3 +38,17 @@ where
     a : A,
 } "
 );
+
+fmt_test_item!(  struct_with_field_defaults
+"pub struct Config {
+    pub max: u64 = 100,
+    name: str,
+    enabled: bool = true,
+}",
+            intermediate_whitespace
+"pub struct Config {
+    pub max : u64   =   100,
+        name: str,
+    enabled  :bool=true ,
+}"
+);
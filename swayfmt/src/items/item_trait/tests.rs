@@ -115,3 +115,17 @@ where
 }",
 intermediate_whitespace
 "trait TraitWithWhere<T, A> where T: Something, A: Something { fn my_fn(); }");
+
+fmt_test_item!(
+trait_nested_cfg_annotated_fn
+"pub trait MyTrait {
+    #[cfg(all(feature = \"extra\", not(test)))]
+    fn foo(self);
+}",
+intermediate_whitespace
+"   pub   trait   MyTrait {
+    #[cfg(  all (feature  =  \"extra\" ,not( test ) ) ) ]
+      
+     fn foo(self);
+}   "
+);
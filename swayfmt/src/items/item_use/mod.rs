@@ -244,7 +244,16 @@ impl LeafSpans for ItemUse {
 impl LeafSpans for UseTree {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
         match self {
-            UseTree::Group { imports } => imports.leaf_spans(),
+            UseTree::Group { imports } => {
+                // a group with a single import is formatted without its braces
+                match (
+                    imports.inner.value_separator_pairs.is_empty(),
+                    &imports.inner.final_value_opt,
+                ) {
+                    (true, Some(single_import)) => single_import.leaf_spans(),
+                    _ => imports.leaf_spans(),
+                }
+            }
             UseTree::Name { name } => vec![ByteSpan::from(name.span())],
             UseTree::Rename {
                 name,
@@ -15,15 +15,24 @@ use sway_types::{
     Spanned,
 };
 
-impl<T: Format + Spanned + std::fmt::Debug> Format for Annotated<T> {
+impl<T: Format + Spanned + LeafSpans + std::fmt::Debug> Format for Annotated<T> {
     fn format(
         &self,
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        // The `// swayfmt::skip` comment either precedes the attributes or the value.
+        let value_leaf_spans = self.value.leaf_spans();
+        let mut leaf_spans = self.attribute_list.leaf_spans();
+        leaf_spans.extend(value_leaf_spans.iter().cloned());
+        if formatter.write_skipped(formatted_code, &leaf_spans)? {
+            return Ok(());
+        }
+
         // format each `Attribute`
         let mut start = None;
-        for attr in &self.attribute_list {
+        let mut attributes = self.attribute_list.iter().peekable();
+        while let Some(attr) = attributes.next() {
             if let Some(start) = start {
                 // Write any comments that may have been defined in between the
                 // attributes and the value
@@ -33,6 +42,21 @@ impl<T: Format + Spanned + std::fmt::Debug> Format for Annotated<T> {
                 }
             }
             formatter.write_indent_into_buffer(formatted_code)?;
+            if let Some(end) = block_doc_comment_end(attr) {
+                // A block doc comment is lexed into one doc comment attribute per line.
+                // Like other block comments, it is written as is, together with its
+                // remaining attributes.
+                let span = attr.span();
+                writeln!(formatted_code, "{}", &span.src()[span.start()..end])?;
+                while attributes
+                    .peek()
+                    .is_some_and(|attr| attr.span().end() <= end)
+                {
+                    attributes.next();
+                }
+                start = Some(end);
+                continue;
+            }
             attr.format(formatted_code, formatter)?;
             start = Some(attr.span().end());
         }
@@ -45,6 +69,9 @@ impl<T: Format + Spanned + std::fmt::Debug> Format for Annotated<T> {
             }
         }
         // format `ItemKind`
+        if formatter.write_skipped(formatted_code, &value_leaf_spans)? {
+            return Ok(());
+        }
         formatter.write_indent_into_buffer(formatted_code)?;
         self.value.format(formatted_code, formatter)?;
 
@@ -52,16 +79,58 @@ impl<T: Format + Spanned + std::fmt::Debug> Format for Annotated<T> {
     }
 }
 
+/// Returns the end of the block doc comment, i.e., `/** ... */` or `/*! ... */`,
+/// that starts with the given doc comment `attr`, or `None` if `attr` is not
+/// the start of a block doc comment.
+fn block_doc_comment_end(attr: &AttributeDecl) -> Option<usize> {
+    let span = match &attr.hash_kind {
+        AttributeHashKind::Inner(hash_bang_token) => hash_bang_token.span(),
+        AttributeHashKind::Outer(hash_token) => hash_token.span(),
+    };
+    if !span.as_str().starts_with("/**") && !span.as_str().starts_with("/*!") {
+        return None;
+    }
+
+    // Block comments can be nested.
+    let mut depth = 0;
+    let mut chars = span.src()[span.start()..].char_indices().peekable();
+    while let Some((ix, character)) = chars.next() {
+        match (character, chars.peek()) {
+            ('/', Some((_, '*'))) => {
+                chars.next();
+                depth += 1;
+            }
+            ('*', Some((_, '/'))) => {
+                chars.next();
+                depth -= 1;
+                if depth == 0 {
+                    return Some(span.start() + ix + "*/".len());
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 impl Format for AttributeArg {
     fn format(
         &self,
         formatted_code: &mut FormattedCode,
-        _formatter: &mut Formatter,
+        formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
         write!(formatted_code, "{}", self.name.span().as_str())?;
         if let Some(value) = &self.value {
             write!(formatted_code, " = {}", value.span().as_str())?;
         }
+        if let Some(args) = &self.args {
+            // `(`
+            AttributeDecl::open_parenthesis(formatted_code, formatter)?;
+            // format and add nested args e.g. `feature = "a", test`
+            args.get().format(formatted_code, formatter)?;
+            // ')'
+            AttributeDecl::close_parenthesis(formatted_code, formatter)?;
+        }
 
         Ok(())
     }
@@ -73,6 +142,9 @@ impl LeafSpans for AttributeArg {
         if let Some(value) = &self.value {
             collected_spans.push(ByteSpan::from(value.span()));
         }
+        if let Some(args) = &self.args {
+            collected_spans.append(&mut args.leaf_spans());
+        }
         collected_spans
     }
 }
@@ -45,14 +45,12 @@ impl Format for CodeBlockContents {
                 }
             }
         } else {
-            let comments: bool = write_comments(
+            // An empty block keeps its comments, otherwise it is closed right away as `{}`.
+            write_comments(
                 formatted_code,
                 self.span().start()..self.span().end(),
                 formatter,
             )?;
-            if !comments {
-                formatter.shape.block_unindent(&formatter.config);
-            }
         }
 
         Ok(())
@@ -76,16 +74,25 @@ impl CurlyBrace for CodeBlockContents {
     ) -> Result<(), FormatterError> {
         // Unindent by one block
         formatter.unindent();
-        write!(
-            line,
-            "{}{}",
-            formatter.indent_to_str()?,
-            Delimiter::Brace.as_close_char()
-        )?;
+        write_close_curly_brace(line, formatter)?;
         Ok(())
     }
 }
 
+/// Writes the closing brace of a block. The brace is indented only if it starts a new line,
+/// so that an empty block is written as `{}` and a block holding only an inlined comment
+/// as `{ /* comment */ }`.
+pub(crate) fn write_close_curly_brace(
+    line: &mut FormattedCode,
+    formatter: &Formatter,
+) -> Result<(), FormatterError> {
+    if line.ends_with('\n') {
+        write!(line, "{}", formatter.indent_to_str()?)?;
+    }
+    write!(line, "{}", Delimiter::Brace.as_close_char())?;
+    Ok(())
+}
+
 impl LeafSpans for CodeBlockContents {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
         let mut collected_span = Vec::new();
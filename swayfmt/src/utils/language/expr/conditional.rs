@@ -5,6 +5,7 @@ use crate::{
         *,
     },
     utils::{
+        language::expr::code_block::write_close_curly_brace,
         map::byte_span::{ByteSpan, LeafSpans},
         CurlyBrace,
     },
@@ -143,21 +144,7 @@ fn format_then_block(
     formatter: &mut Formatter,
 ) -> Result<(), FormatterError> {
     IfExpr::open_curly_brace(formatted_code, formatter)?;
-
-    if !if_expr.then_block.get().statements.is_empty()
-        || if_expr.then_block.get().final_expr_opt.is_some()
-    {
-        if_expr.then_block.get().format(formatted_code, formatter)?;
-    } else {
-        let comments = write_comments(
-            formatted_code,
-            if_expr.then_block.span().start()..if_expr.then_block.span().end(),
-            formatter,
-        )?;
-        if !comments {
-            formatter.shape.block_unindent(&formatter.config);
-        }
-    }
+    if_expr.then_block.get().format(formatted_code, formatter)?;
     if if_expr.else_opt.is_none() {
         IfExpr::close_curly_brace(formatted_code, formatter)?;
     }
@@ -171,36 +158,32 @@ fn format_else_opt(
     formatter: &mut Formatter,
 ) -> Result<(), FormatterError> {
     if let Some((else_token, control_flow)) = &if_expr.else_opt {
-        let mut else_if_str = FormattedCode::new();
-
-        IfExpr::close_curly_brace(&mut else_if_str, formatter)?;
+        IfExpr::close_curly_brace(formatted_code, formatter)?;
         let comments_written = write_comments(
-            &mut else_if_str,
+            formatted_code,
             if_expr.then_block.span().end()..else_token.span().start(),
             formatter,
         )?;
 
         if comments_written {
-            write!(else_if_str, "{}", formatter.indent_to_str()?,)?;
+            write!(formatted_code, "{}", formatter.indent_to_str()?,)?;
         } else {
-            write!(else_if_str, " ")?;
+            write!(formatted_code, " ")?;
         }
-        write!(else_if_str, "{}", else_token.span().as_str())?;
+        write!(formatted_code, "{}", else_token.span().as_str())?;
         match &control_flow {
             LoopControlFlow::Continue(if_expr) => {
-                write!(else_if_str, " ")?;
-                if_expr.format(&mut else_if_str, formatter)?
+                write!(formatted_code, " ")?;
+                if_expr.format(formatted_code, formatter)?
             }
             LoopControlFlow::Break(code_block_contents) => {
-                IfExpr::open_curly_brace(&mut else_if_str, formatter)?;
+                IfExpr::open_curly_brace(formatted_code, formatter)?;
                 code_block_contents
                     .get()
-                    .format(&mut else_if_str, formatter)?;
-                IfExpr::close_curly_brace(&mut else_if_str, formatter)?;
+                    .format(formatted_code, formatter)?;
+                IfExpr::close_curly_brace(formatted_code, formatter)?;
             }
         }
-
-        write!(formatted_code, "{else_if_str}")?;
     }
 
     Ok(())
@@ -238,14 +221,7 @@ impl CurlyBrace for IfExpr {
             LineStyle::Inline => {
                 write!(line, "{}", Delimiter::Brace.as_close_char())?;
             }
-            _ => {
-                write!(
-                    line,
-                    "{}{}",
-                    formatter.indent_to_str()?,
-                    Delimiter::Brace.as_close_char()
-                )?;
-            }
+            _ => write_close_curly_brace(line, formatter)?,
         }
 
         Ok(())
@@ -336,17 +312,7 @@ impl Format for MatchBranchKind {
                 comma_token_opt,
             } => {
                 Self::open_curly_brace(formatted_code, formatter)?;
-                let block = block.get();
-                if block.statements.is_empty() && block.final_expr_opt.is_none() {
-                    // even if there is no code block we still want to unindent
-                    // before the closing brace
-                    formatter.unindent();
-                } else {
-                    block.format(formatted_code, formatter)?;
-                    // we handle this here to avoid needless indents
-                    formatter.unindent();
-                    write!(formatted_code, "{}", formatter.indent_to_str()?)?;
-                }
+                block.get().format(formatted_code, formatter)?;
                 Self::close_curly_brace(formatted_code, formatter)?;
                 if let Some(comma_token) = comma_token_opt {
                     write!(formatted_code, "{}", comma_token.span().as_str())?;
@@ -373,9 +339,10 @@ impl CurlyBrace for MatchBranchKind {
     }
     fn close_curly_brace(
         line: &mut FormattedCode,
-        _formatter: &mut Formatter,
+        formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        write!(line, "{}", Delimiter::Brace.as_close_char())?;
+        formatter.unindent();
+        write_close_curly_brace(line, formatter)?;
         Ok(())
     }
 }
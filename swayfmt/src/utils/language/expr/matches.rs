@@ -0,0 +1,61 @@
+use crate::{
+    formatter::*,
+    utils::{
+        map::byte_span::{ByteSpan, LeafSpans},
+        Parenthesis,
+    },
+};
+use std::fmt::Write;
+use sway_ast::MatchesArgs;
+use sway_types::{ast::Delimiter, Spanned};
+
+impl Format for MatchesArgs {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        Self::open_parenthesis(formatted_code, formatter)?;
+        self.value.format(formatted_code, formatter)?;
+        write!(formatted_code, "{} ", self.comma_token.span().as_str())?;
+        self.pattern.format(formatted_code, formatter)?;
+        if let Some((if_token, guard)) = &self.guard_opt {
+            write!(formatted_code, " {} ", if_token.span().as_str())?;
+            guard.format(formatted_code, formatter)?;
+        }
+        Self::close_parenthesis(formatted_code, formatter)?;
+
+        Ok(())
+    }
+}
+
+impl Parenthesis for MatchesArgs {
+    fn open_parenthesis(
+        line: &mut FormattedCode,
+        _formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        write!(line, "{}", Delimiter::Parenthesis.as_open_char())?;
+        Ok(())
+    }
+
+    fn close_parenthesis(
+        line: &mut FormattedCode,
+        _formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        write!(line, "{}", Delimiter::Parenthesis.as_close_char())?;
+        Ok(())
+    }
+}
+
+impl LeafSpans for MatchesArgs {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        let mut collected_spans = self.value.leaf_spans();
+        collected_spans.push(ByteSpan::from(self.comma_token.span()));
+        collected_spans.append(&mut self.pattern.leaf_spans());
+        if let Some((if_token, guard)) = &self.guard_opt {
+            collected_spans.push(ByteSpan::from(if_token.span()));
+            collected_spans.append(&mut guard.leaf_spans());
+        }
+        collected_spans
+    }
+}
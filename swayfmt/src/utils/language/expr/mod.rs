@@ -1,4 +1,5 @@
 use crate::{
+    comments::has_comments_in_formatter,
     formatter::{
         shape::{ExprKind, LineStyle},
         *,
@@ -24,7 +25,9 @@ pub(crate) mod assignable;
 pub(crate) mod code_block;
 pub(crate) mod collections;
 pub(crate) mod conditional;
+pub(crate) mod matches;
 pub(crate) mod struct_field;
+pub(crate) mod type_ascription;
 
 #[cfg(test)]
 mod tests;
@@ -175,13 +178,27 @@ impl Format for Expr {
                     formatter.unindent();
                 }
             }
+            Self::TypeAscription(ascription) => {
+                Self::open_parenthesis(formatted_code, formatter)?;
+                ascription.get().format(formatted_code, formatter)?;
+                Self::close_parenthesis(formatted_code, formatter)?;
+            }
             Self::Block(code_block) => {
                 if !code_block.get().statements.is_empty()
                     || code_block.get().final_expr_opt.is_some()
+                    || has_comments_in_formatter(formatter, &code_block.span().into())
                 {
-                    CodeBlockContents::open_curly_brace(formatted_code, formatter)?;
-                    code_block.get().format(formatted_code, formatter)?;
-                    CodeBlockContents::close_curly_brace(formatted_code, formatter)?;
+                    // a non-empty block is always written over multiple lines, even if it is
+                    // part of an expression that is otherwise formatted inline
+                    formatter.with_shape(
+                        formatter.shape.with_default_code_line(),
+                        |formatter| -> Result<(), FormatterError> {
+                            CodeBlockContents::open_curly_brace(formatted_code, formatter)?;
+                            code_block.get().format(formatted_code, formatter)?;
+                            CodeBlockContents::close_curly_brace(formatted_code, formatter)?;
+                            Ok(())
+                        },
+                    )?;
                 } else {
                     write!(formatted_code, "{{}}")?;
                 }
@@ -228,6 +245,19 @@ impl Format for Expr {
                 }
             }
             Self::If(if_expr) => if_expr.format(formatted_code, formatter)?,
+            Self::Matches {
+                matches_ident,
+                bang_token,
+                args,
+            } => {
+                write!(
+                    formatted_code,
+                    "{}{}",
+                    matches_ident.span().as_str(),
+                    bang_token.span().as_str()
+                )?;
+                args.get().format(formatted_code, formatter)?;
+            }
             Self::Match {
                 match_token,
                 value,
@@ -268,6 +298,20 @@ impl Format for Expr {
                     },
                 )?;
             }
+            Self::Loop { loop_token, block } => {
+                formatter.with_shape(
+                    formatter
+                        .shape
+                        .with_code_line_from(LineStyle::Normal, ExprKind::Function),
+                    |formatter| -> Result<(), FormatterError> {
+                        write!(formatted_code, "{}", loop_token.span().as_str())?;
+                        IfExpr::open_curly_brace(formatted_code, formatter)?;
+                        block.get().format(formatted_code, formatter)?;
+                        IfExpr::close_curly_brace(formatted_code, formatter)?;
+                        Ok(())
+                    },
+                )?;
+            }
             Self::For {
                 for_token,
                 in_token,
@@ -421,6 +465,13 @@ impl Format for Expr {
                     field_span.as_str(),
                 )?;
             }
+            Self::Try {
+                expr,
+                question_mark_token,
+            } => {
+                expr.format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", question_mark_token.span().as_str())?;
+            }
             Self::Ref {
                 ampersand_token,
                 mut_token,
@@ -669,8 +720,15 @@ impl Format for Expr {
                 reassignment_op.format(formatted_code, formatter)?;
                 expr.format(formatted_code, formatter)?;
             }
-            Self::Break { break_token } => {
+            Self::Break {
+                break_token,
+                expr_opt,
+            } => {
                 write!(formatted_code, "{}", break_token.span().as_str())?;
+                if let Some(expr) = &expr_opt {
+                    write!(formatted_code, " ")?;
+                    expr.format(formatted_code, formatter)?;
+                }
             }
             Self::Continue { continue_token } => {
                 write!(formatted_code, "{}", continue_token.span().as_str())?;
@@ -781,6 +839,7 @@ fn same_line_if_only_argument(expr: &Expr) -> bool {
             | Expr::Tuple(_)
             | Expr::Array(_)
             | Expr::Parens(_)
+            | Expr::TypeAscription(_)
             | Expr::Not {
                 bang_token: _,
                 expr: _
@@ -1001,6 +1060,7 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
         }
         Expr::Tuple(tuple) => tuple.leaf_spans(),
         Expr::Parens(parens) => parens.leaf_spans(),
+        Expr::TypeAscription(ascription) => ascription.leaf_spans(),
         Expr::Block(block) => block.leaf_spans(),
         Expr::Array(array) => array.leaf_spans(),
         Expr::Asm(asm) => asm.leaf_spans(),
@@ -1025,6 +1085,18 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut branches.leaf_spans());
             collected_spans
         }
+        Expr::Matches {
+            matches_ident,
+            bang_token,
+            args,
+        } => {
+            let mut collected_spans = vec![
+                ByteSpan::from(matches_ident.span()),
+                ByteSpan::from(bang_token.span()),
+            ];
+            collected_spans.append(&mut args.leaf_spans());
+            collected_spans
+        }
         Expr::While {
             while_token,
             condition,
@@ -1035,6 +1107,11 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut block.leaf_spans());
             collected_spans
         }
+        Expr::Loop { loop_token, block } => {
+            let mut collected_spans = vec![ByteSpan::from(loop_token.span())];
+            collected_spans.append(&mut block.leaf_spans());
+            collected_spans
+        }
         Expr::For {
             for_token,
             in_token,
@@ -1101,6 +1178,14 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.push(ByteSpan::from(field_span.clone()));
             collected_spans
         }
+        Expr::Try {
+            expr,
+            question_mark_token,
+        } => {
+            let mut collected_spans = expr.leaf_spans();
+            collected_spans.push(ByteSpan::from(question_mark_token.span()));
+            collected_spans
+        }
         Expr::Ref {
             ampersand_token,
             mut_token,
@@ -1323,8 +1408,15 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut expr.leaf_spans());
             collected_spans
         }
-        Expr::Break { break_token } => {
-            vec![ByteSpan::from(break_token.span())]
+        Expr::Break {
+            break_token,
+            expr_opt,
+        } => {
+            let mut collected_spans = vec![ByteSpan::from(break_token.span())];
+            if let Some(expr) = expr_opt {
+                collected_spans.append(&mut expr.leaf_spans());
+            }
+            collected_spans
         }
         Expr::Continue { continue_token } => {
             vec![ByteSpan::from(continue_token.span())]
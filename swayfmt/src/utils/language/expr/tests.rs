@@ -22,6 +22,25 @@ fmt_test_expr!(  abi_cast                "abi(MyAbi, 0x1111111111111111111111111
                                   )  "
 );
 
+fmt_test_expr!(  type_ascription         "(foo.collect(): Vec<u8>)",
+            intermediate_whitespace " ( foo . collect ( )   :   Vec < u8 > ) "
+);
+
+fmt_test_expr!(  matches                 "matches!(foo, Some(_))",
+            intermediate_whitespace " matches ! (
+                  foo
+                   ,   Some ( _ )
+                                  )  "
+);
+
+fmt_test_expr!(  matches_with_guard      "matches!(foo, Some(n) if n > 0)",
+            intermediate_whitespace "matches!( foo ,Some(n)   if   n>0 )"
+);
+
+fmt_test_expr!(  try_operator            "foo(bar)?.baz()?",
+            intermediate_whitespace " foo ( bar ) ? . baz ( ) ?  "
+);
+
 fmt_test_expr!(  basic_func_app          "foo()",
             intermediate_whitespace " foo (
 
@@ -67,6 +86,10 @@ fmt_test_expr!(  multiline_match_stmt    "match foo {\n    Foo::foo => {}\n    F
             intermediate_whitespace "  match   \n  foo  {   \n\n    Foo :: foo  => {        }\n     Foo :: bar  =>  { }   \n}\n"
 );
 
+fmt_test_expr!(  match_slice_patterns    "match foo {\n    [] => {}\n    [head, tail @ ..] => {}\n    [first, .., last] => {}\n}",
+            intermediate_whitespace "  match   foo  {\n    [ ]  => {}\n  [ head ,tail@ .. ] => { }\n    [first,..,   last ,] => {}\n}"
+);
+
 fmt_test_expr!(  if_else_block           "if foo {\n    foo();\n} else if bar { bar(); } else { baz(); }",
             intermediate_whitespace "   if    foo  {   \n       foo( ) ; \n }    else  if   bar  { \n     bar( ) ; \n }  else  { \n    baz(\n) ; \n }\n\n"
 );
@@ -256,6 +279,32 @@ intermediate_whitespace
 let i = 42;
 }");
 
+fmt_test_expr!(basic_loop
+"loop {
+    let i = 42;
+    break i;
+}",
+intermediate_whitespace
+"loop{
+let i = 42;
+break   i;
+}");
+
+fmt_test_expr!(loop_break_value_in_match
+"loop {
+    match foo {
+        0 => break bar(),
+        _ => break,
+    }
+}",
+intermediate_whitespace
+"loop   {
+match foo {
+0 =>   break   bar() ,
+_ => break ,
+}
+}");
+
 fmt_test_expr!(scoped_block
 "{
     let i = 42;
@@ -0,0 +1,30 @@
+use crate::{
+    formatter::*,
+    utils::map::byte_span::{ByteSpan, LeafSpans},
+};
+use std::fmt::Write;
+use sway_ast::ExprTypeAscription;
+use sway_types::Spanned;
+
+impl Format for ExprTypeAscription {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        self.expr.format(formatted_code, formatter)?;
+        write!(formatted_code, "{} ", self.colon_token.span().as_str())?;
+        self.ty.format(formatted_code, formatter)?;
+
+        Ok(())
+    }
+}
+
+impl LeafSpans for ExprTypeAscription {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        let mut collected_spans = self.expr.leaf_spans();
+        collected_spans.push(ByteSpan::from(self.colon_token.span()));
+        collected_spans.append(&mut self.ty.leaf_spans());
+        collected_spans
+    }
+}
@@ -2,7 +2,9 @@ use crate::{
     formatter::*,
     utils::{close_angle_bracket, open_angle_bracket},
 };
-use sway_ast::{GenericArgs, GenericParams};
+use std::fmt::Write;
+use sway_ast::{GenericArgs, GenericParam, GenericParams};
+use sway_types::Spanned;
 
 impl Format for GenericParams {
     fn format(
@@ -29,6 +31,23 @@ impl Format for GenericParams {
     }
 }
 
+impl Format for GenericParam {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        write!(formatted_code, "{}", self.name.as_str())?;
+        // `: Trait + Trait`, if any
+        if let Some((colon_token, bounds)) = &self.bounds_opt {
+            write!(formatted_code, "{} ", colon_token.span().as_str())?;
+            bounds.format(formatted_code, formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Format for GenericArgs {
     fn format(
         &self,
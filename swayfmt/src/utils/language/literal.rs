@@ -1,4 +1,5 @@
 use crate::{
+    config::literals::{HexLiteralCase, Literals},
     formatter::*,
     utils::map::byte_span::{ByteSpan, LeafSpans},
 };
@@ -9,14 +10,18 @@ impl Format for Literal {
     fn format(
         &self,
         formatted_code: &mut FormattedCode,
-        _formatter: &mut Formatter,
+        formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
         match self {
             // TODO: do more digging into `Literal` and see if there is more formatting to do.
             Self::String(lit_string) => write!(formatted_code, "{}", lit_string.span.as_str())?,
             Self::Char(lit_char) => write!(formatted_code, "{}", lit_char.span.as_str())?,
             Self::Int(lit_int) => {
-                write!(formatted_code, "{}", lit_int.span.as_str())?;
+                write!(
+                    formatted_code,
+                    "{}",
+                    format_int_literal(lit_int.span.as_str(), &formatter.config.literals)
+                )?;
                 if let Some((_, ty_span)) = &lit_int.ty_opt {
                     write!(formatted_code, "{}", ty_span.as_str())?;
                 }
@@ -27,6 +32,39 @@ impl Format for Literal {
     }
 }
 
+/// Normalizes the case of the digits of a hexadecimal integer literal and the digit
+/// separators of a decimal integer literal, as configured in `literals`.
+///
+/// The radix prefix is kept as written, since the lexer only accepts lowercase prefixes.
+fn format_int_literal(literal: &str, literals: &Literals) -> String {
+    if let Some(digits) = literal.strip_prefix("0x") {
+        return match literals.hex_literal_case {
+            HexLiteralCase::Preserve => literal.to_string(),
+            HexLiteralCase::Upper => format!("0x{}", digits.to_uppercase()),
+            HexLiteralCase::Lower => format!("0x{}", digits.to_lowercase()),
+        };
+    }
+    if literal.starts_with("0b") || literal.starts_with("0o") {
+        return literal.to_string();
+    }
+    let group_size = match literals.decimal_digit_group_size {
+        Some(group_size) if group_size > 0 => group_size,
+        _ => return literal.to_string(),
+    };
+    let digits: Vec<char> = literal.chars().filter(|c| *c != '_').collect();
+    if digits.len() <= group_size {
+        return literal.to_string();
+    }
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / group_size);
+    for (i, digit) in digits.iter().enumerate() {
+        if i > 0 && (digits.len() - i) % group_size == 0 {
+            grouped.push('_');
+        }
+        grouped.push(*digit);
+    }
+    grouped
+}
+
 impl LeafSpans for Literal {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
         match self {
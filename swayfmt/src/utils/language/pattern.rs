@@ -10,7 +10,8 @@ use crate::{
 };
 use std::fmt::Write;
 use sway_ast::{
-    Braces, CommaToken, ExprTupleDescriptor, PathExpr, Pattern, PatternStructField, Punctuated,
+    Braces, CommaToken, ExprTupleDescriptor, PathExpr, Pattern, PatternSliceElement,
+    PatternStructField, Punctuated,
 };
 use sway_types::{ast::Delimiter, Spanned};
 
@@ -125,6 +126,17 @@ impl Format for Pattern {
                     },
                 )?;
             }
+            Self::Slice(elems) => {
+                formatter.with_shape(
+                    formatter.shape.with_default_code_line(),
+                    |formatter| -> Result<(), FormatterError> {
+                        write!(formatted_code, "{}", Delimiter::Bracket.as_open_char())?;
+                        elems.get().format(formatted_code, formatter)?;
+                        write!(formatted_code, "{}", Delimiter::Bracket.as_close_char())?;
+                        Ok(())
+                    },
+                )?;
+            }
             Self::Error(..) => {
                 return Err(FormatterError::SyntaxError);
             }
@@ -207,6 +219,31 @@ impl Format for PatternStructField {
     }
 }
 
+impl Format for PatternSliceElement {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Rest { name_opt, token } => {
+                if let Some((name, at_token)) = name_opt {
+                    write!(
+                        formatted_code,
+                        "{} {} ",
+                        name.span().as_str(),
+                        at_token.span().as_str()
+                    )?;
+                }
+                write!(formatted_code, "{}", token.span().as_str())?;
+            }
+            Self::Pattern(pattern) => pattern.format(formatted_code, formatter)?,
+        }
+
+        Ok(())
+    }
+}
+
 fn get_field_width(
     fields: &Punctuated<PatternStructField, CommaToken>,
     formatter: &mut Formatter,
@@ -307,6 +344,9 @@ impl LeafSpans for Pattern {
             Pattern::Tuple(tuple) => {
                 collected_spans.append(&mut tuple.leaf_spans());
             }
+            Pattern::Slice(slice) => {
+                collected_spans.append(&mut slice.leaf_spans());
+            }
             Pattern::Error(spans, _) => {
                 let mut leaf_spans = spans.iter().map(|s| ByteSpan::from(s.clone())).collect();
                 collected_spans.append(&mut leaf_spans)
@@ -337,3 +377,22 @@ impl LeafSpans for PatternStructField {
         collected_spans
     }
 }
+
+impl LeafSpans for PatternSliceElement {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        let mut collected_spans = Vec::new();
+        match self {
+            PatternSliceElement::Rest { name_opt, token } => {
+                if let Some((name, at_token)) = name_opt {
+                    collected_spans.push(ByteSpan::from(name.span()));
+                    collected_spans.push(ByteSpan::from(at_token.span()));
+                }
+                collected_spans.push(ByteSpan::from(token.span()));
+            }
+            PatternSliceElement::Pattern(pattern) => {
+                collected_spans.append(&mut pattern.leaf_spans());
+            }
+        }
+        collected_spans
+    }
+}
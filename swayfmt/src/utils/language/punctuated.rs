@@ -84,6 +84,28 @@ where
                         },
                     )?;
 
+                    // Format the final value the same way as the values above, so that the
+                    // output doesn't change once the trailing comma has been added.
+                    let final_value = self
+                        .final_value_opt
+                        .as_ref()
+                        .map(|final_value| {
+                            formatter.with_shape(
+                                formatter.shape.with_default_code_line(),
+                                |formatter| -> Result<String, FormatterError> {
+                                    let mut value = FormattedCode::new();
+                                    final_value.format(&mut value, formatter)?;
+                                    if value.len()
+                                        > formatter.shape.width_heuristics.short_array_element_width
+                                    {
+                                        is_value_too_long = true;
+                                    }
+                                    Ok(value.trim_start().to_owned())
+                                },
+                            )
+                        })
+                        .transpose()?;
+
                     let mut iter = value_separator_pairs.iter().peekable();
 
                     while let Some((type_field, comma_token)) = iter.next() {
@@ -98,8 +120,8 @@ where
                             write!(formatted_code, " ")?;
                         }
                     }
-                    if let Some(final_value) = &self.final_value_opt {
-                        final_value.format(formatted_code, formatter)?;
+                    if let Some(final_value) = final_value {
+                        write!(formatted_code, "{}", final_value)?;
                         write!(formatted_code, "{}", PunctKind::Comma.as_char())?;
                     }
                     if !formatted_code.ends_with('\n') {
@@ -216,6 +238,10 @@ impl Format for TypeField {
             self.colon_token.span().as_str(),
         )?;
         self.ty.format(formatted_code, formatter)?;
+        if let Some((eq_token, default)) = &self.default_opt {
+            write!(formatted_code, " {} ", eq_token.span().as_str())?;
+            default.format(formatted_code, formatter)?;
+        }
 
         Ok(())
     }
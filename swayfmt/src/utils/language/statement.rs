@@ -12,6 +12,9 @@ impl Format for Statement {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        if formatter.write_skipped(formatted_code, &self.leaf_spans())? {
+            return Ok(());
+        }
         // later we need to decide if a statement is long enough to go on next line
         format_statement(self, formatted_code, formatter)?;
 
@@ -83,7 +86,18 @@ fn format_statement(
         } => {
             let mut temp_expr = FormattedCode::new();
 
+            // Formatting the expression writes out its comments and removes them from the
+            // `CommentMap`, so they are put back once the width of the expression is known.
+            let expr_range = expr.span().into();
+            let expr_comments = formatter
+                .comments_context
+                .map
+                .iter()
+                .filter(|(byte_span, _)| byte_span.contained_within(&expr_range))
+                .map(|(byte_span, comment)| (byte_span.clone(), comment.clone()))
+                .collect::<Vec<_>>();
             remove_arguments_from_expr(expr.clone()).format(&mut temp_expr, formatter)?;
+            formatter.comments_context.map.extend(expr_comments);
             if temp_expr.len() > formatter.shape.width_heuristics.chain_width {
                 let update_expr_new_line = if !matches!(
                     expr,
@@ -95,8 +109,9 @@ fn format_statement(
                             condition: _,
                             block: _
                         }
+                        | Expr::Loop { .. }
                 ) {
-                    // Method calls, If, While should not tamper with the
+                    // Method calls, If, While, Loop should not tamper with the
                     // expr_new_line because that would be inherited for all
                     // statements. That should be applied at the lowest level
                     // possible (ideally at the expression level)
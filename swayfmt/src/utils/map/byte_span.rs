@@ -184,6 +184,10 @@ impl LeafSpans for TypeField {
         collected_spans.push(ByteSpan::from(self.name.span()));
         collected_spans.push(ByteSpan::from(self.colon_token.span()));
         collected_spans.append(&mut self.ty.leaf_spans());
+        if let Some((eq_token, default)) = &self.default_opt {
+            collected_spans.push(ByteSpan::from(eq_token.span()));
+            collected_spans.append(&mut default.leaf_spans());
+        }
         collected_spans
     }
 }
@@ -1,8 +1,8 @@
 use anyhow::Result;
 use ropey::{str_utils::byte_to_char_idx, Rope};
 use std::{collections::BTreeMap, fmt::Display, path::PathBuf, sync::Arc};
-use sway_ast::Module;
-use sway_types::SourceEngine;
+use sway_ast::{ItemFn, ItemImplItem, ItemKind, Module};
+use sway_types::{SourceEngine, Spanned};
 
 use crate::{
     constants::NEW_LINE,
@@ -32,6 +32,63 @@ impl Display for NewlineSequence {
 
 type NewlineMap = BTreeMap<ByteSpan, NewlineSequence>;
 
+/// The maximum number of newlines a newline sequence is collapsed to, which depends on
+/// whether the sequence is within a function body or not.
+#[derive(Debug)]
+struct NewlineThresholds {
+    /// Applies outside of function bodies.
+    default: usize,
+    /// Applies within the function bodies, given by their spans in the unformatted code.
+    fn_body: usize,
+    fn_body_spans: Vec<ByteSpan>,
+}
+
+impl NewlineThresholds {
+    /// Returns the threshold for a newline sequence starting at `position` of the unformatted code.
+    fn at(&self, position: usize) -> usize {
+        if self
+            .fn_body_spans
+            .iter()
+            .any(|span| span.start <= position && position < span.end)
+        {
+            self.fn_body
+        } else {
+            self.default
+        }
+    }
+}
+
+/// Collects the spans of the bodies of all the functions and methods declared in the module.
+fn fn_body_spans(module: &Module) -> Vec<ByteSpan> {
+    let body_span = |item_fn: &ItemFn| ByteSpan::from(item_fn.body.span());
+    let mut spans = Vec::new();
+    for item in module.items.iter() {
+        match &item.value {
+            ItemKind::Fn(item_fn) => spans.push(body_span(item_fn)),
+            ItemKind::Impl(item_impl) => {
+                spans.extend(item_impl.contents.get().iter().filter_map(
+                    |item| match &item.value {
+                        ItemImplItem::Fn(item_fn) => Some(body_span(item_fn)),
+                        ItemImplItem::Const(_) | ItemImplItem::Type(_) => None,
+                    },
+                ));
+            }
+            ItemKind::Trait(item_trait) => {
+                if let Some(trait_defs) = &item_trait.trait_defs_opt {
+                    spans.extend(trait_defs.get().iter().map(|item| body_span(&item.value)));
+                }
+            }
+            ItemKind::Abi(item_abi) => {
+                if let Some(abi_defs) = &item_abi.abi_defs_opt {
+                    spans.extend(abi_defs.get().iter().map(|item| body_span(&item.value)));
+                }
+            }
+            _ => {}
+        }
+    }
+    spans
+}
+
 /// Checks if there is a new line at the current position of the rope
 #[inline]
 fn is_new_line_in_rope(rope: &Rope, index: usize) -> bool {
@@ -43,6 +100,58 @@ fn is_new_line_in_rope(rope: &Rope, index: usize) -> bool {
     true
 }
 
+/// Keeps track of whether the characters of the source code being visited are within a comment
+/// or a string literal.
+#[derive(Debug, Default)]
+struct SkippedText {
+    in_line_comment: bool,
+    block_comment_depth: usize,
+    in_string: bool,
+    escaped: bool,
+    prev_char: Option<char>,
+}
+
+impl SkippedText {
+    /// Visits the next `char` and returns `true` if it is part of a comment or of a string literal.
+    fn update(&mut self, char: char, next_char: Option<char>) -> bool {
+        let prev_char = self.prev_char.replace(char);
+        if self.in_line_comment {
+            self.in_line_comment = char != '\n';
+            true
+        } else if self.block_comment_depth > 0 {
+            if prev_char == Some('*') && char == '/' {
+                self.block_comment_depth -= 1;
+                // don't let the closing `/` be part of a new `/*`
+                self.prev_char = None;
+            } else if prev_char == Some('/') && char == '*' {
+                self.block_comment_depth += 1;
+                self.prev_char = None;
+            }
+            true
+        } else if self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if char == '\\' {
+                self.escaped = true;
+            } else if char == '"' {
+                self.in_string = false;
+            }
+            true
+        } else {
+            match (char, next_char) {
+                ('/', Some('/')) => self.in_line_comment = true,
+                ('/', Some('*')) => {
+                    self.block_comment_depth = 1;
+                    self.prev_char = None;
+                }
+                ('"', _) => self.in_string = true,
+                _ => return false,
+            }
+            true
+        }
+    }
+}
+
 /// Search for newline sequences in the unformatted code and collect ByteSpan -> NewlineSequence for the input source
 fn newline_map_from_src(unformatted_input: &str) -> Result<NewlineMap, FormatterError> {
     let mut newline_map = BTreeMap::new();
@@ -52,6 +161,9 @@ fn newline_map_from_src(unformatted_input: &str) -> Result<NewlineMap, Formatter
     let mut in_sequence = false;
     let mut sequence_start = 0;
     let mut bytes_offset = 0;
+    // `;` and `}` found within comments and string literals must not start a sequence,
+    // otherwise the formatter would treat the newlines after them as newlines between items.
+    let mut skipped = SkippedText::default();
     while let Some(char) = input_iter.next() {
         // Keep of byte offset for each char, it is used for indexing the
         // unformatted input (to replace the newline sequences with correct
@@ -70,7 +182,9 @@ fn newline_map_from_src(unformatted_input: &str) -> Result<NewlineMap, Formatter
             .map(|c| c == NEW_LINE)
             .unwrap_or(false);
 
-        if matches!(char, ';' | '}') && is_new_line_next {
+        let is_skipped = skipped.update(char, next_char.copied());
+
+        if matches!(char, ';' | '}') && is_new_line_next && !is_skipped {
             if !in_sequence {
                 sequence_start = char_index + NEW_LINE.len();
                 in_sequence = true;
@@ -114,8 +228,12 @@ pub fn handle_newlines(
     formatted_code: &mut FormattedCode,
     formatter: &Formatter,
 ) -> Result<(), FormatterError> {
-    // Get newline threshold from config
-    let newline_threshold = formatter.config.whitespace.newline_threshold;
+    // Get newline thresholds from config
+    let thresholds = NewlineThresholds {
+        default: formatter.config.whitespace.newline_threshold,
+        fn_body: formatter.config.items.blank_lines_upper_bound,
+        fn_body_spans: fn_body_spans(unformatted_module),
+    };
     // Collect ByteSpan -> NewlineSequence mapping from unformatted input.
     //
     // We remove the extra whitespace the beginning of a file before creating a map of newlines.
@@ -131,7 +249,8 @@ pub fn handle_newlines(
         &formatted_module,
         formatted_code,
         unformatted_input,
-        newline_threshold,
+        &thresholds,
+        &formatter.skipped_spans,
     )?;
     Ok(())
 }
@@ -163,7 +282,8 @@ fn add_newlines(
     formatted_module: &Module,
     formatted_code: &mut FormattedCode,
     unformatted_code: Arc<str>,
-    newline_threshold: usize,
+    thresholds: &NewlineThresholds,
+    skipped_spans: &[ByteSpan],
 ) -> Result<(), FormatterError> {
     let mut unformatted_newline_spans = unformatted_module.leaf_spans();
     let mut formatted_newline_spans = formatted_module.leaf_spans();
@@ -192,7 +312,12 @@ fn add_newlines(
         .skip(1)
         .zip(formatted_newline_spans.iter().skip(1))
     {
-        if previous_unformatted_newline_span.end < unformatted_newline_span.start {
+        // Newlines within skipped nodes are already written out as they are.
+        let is_skipped = skipped_spans.iter().any(|span| {
+            span.start <= previous_unformatted_newline_span.end
+                && unformatted_newline_span.start <= span.end
+        });
+        if previous_unformatted_newline_span.end < unformatted_newline_span.start && !is_skipped {
             // At its core, the spaces between leaf spans are nothing more than just whitespace characters,
             // and sometimes comments, since they are not considered valid AST nodes. We are interested in
             // these spaces (with comments, if any)
@@ -203,6 +328,7 @@ fn add_newlines(
                 whitespaces_with_comments.char_indices().peekable();
 
             let start = previous_unformatted_newline_span.end;
+            let newline_threshold = thresholds.at(start);
             let mut comment_found = false;
 
             // Here, we will try to insert newlines that occur before comments.
@@ -109,7 +109,16 @@ macro_rules! fmt_test_inner {
                     }
                 }
                 println!("{formatted_code}");
-                assert_eq!(&formatted_code, $desired_output)
+                assert_eq!(&formatted_code, $desired_output);
+
+                // Formatting must be idempotent, i.e. formatting already formatted code
+                // must not change it anymore.
+                let reformatted_code = crate::parse::parse_format::<$ty>(&formatted_code).unwrap();
+                if reformatted_code != formatted_code {
+                    println!("FAILED: formatting is not idempotent. Second pass produced:");
+                    println!("{reformatted_code}");
+                }
+                assert_eq!(reformatted_code, formatted_code)
             }
         }
     )+
@@ -1,6 +1,9 @@
 use indoc::indoc;
 use std::sync::Arc;
-use swayfmt::{config::user_def::FieldAlignment, Formatter};
+use swayfmt::{
+    config::{literals::HexLiteralCase, user_def::FieldAlignment},
+    Formatter,
+};
 use test_macros::assert_eq_pretty;
 
 /// Takes a configured formatter as input and formats a given input and checks the actual output against an
@@ -36,6 +39,80 @@ fn module_doc_comments_persist() {
     )
 }
 
+#[test]
+fn block_doc_comments() {
+    check(
+        indoc! {r#"
+        /*! this is a module level doc comment */
+        library;
+
+        /**
+         * Returns the answer.
+         *
+         * Always `42`.
+         */
+        fn answer() -> u64 {
+            42
+        }
+        "#},
+        indoc! {r#"
+        /*! this is a module level doc comment */
+        library;
+
+        /**
+         * Returns the answer.
+         *
+         * Always `42`.
+         */
+        fn answer() -> u64 {
+            42
+        }
+        "#},
+    )
+}
+
+#[test]
+fn block_doc_comments_in_impl() {
+    check(
+        indoc! {r#"
+        library;
+
+        struct Answer {}
+
+        impl Answer {
+        /** Returns the answer. */
+        fn get() -> u64 { 42 }
+            /// Line docs.
+            /**
+             * Block docs.
+             */
+            #[inline(never)]
+            fn get_again() -> u64 { 42 }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        struct Answer {}
+
+        impl Answer {
+            /** Returns the answer. */
+            fn get() -> u64 {
+                42
+            }
+            /// Line docs.
+            /**
+             * Block docs.
+             */
+            #[inline(never)]
+            fn get_again() -> u64 {
+                42
+            }
+        }
+        "#},
+    )
+}
+
 #[test]
 fn conserve_pub_mod() {
     check(
@@ -1106,6 +1183,60 @@ fn newline_sequence_formatting() {
     );
 }
 
+#[test]
+fn blank_lines_upper_bound_in_fn_body() {
+    let mut formatter = Formatter::default();
+    formatter.config.items.blank_lines_upper_bound = 2;
+
+    check_with_formatter(
+        indoc! {r#"
+        script;
+
+
+
+        fn main() {
+            let number: u64 = 10;
+
+            let number2: u64 = 20;
+
+
+            let number3: u64 = 30;
+
+
+
+            if number > number2 {
+                let number4: u64 = 40;
+
+
+
+                let number5: u64 = 50;
+            }
+        }
+        "#},
+        indoc! {r#"
+        script;
+
+        fn main() {
+            let number: u64 = 10;
+
+            let number2: u64 = 20;
+
+
+            let number3: u64 = 30;
+
+
+            if number > number2 {
+                let number4: u64 = 40;
+
+
+                let number5: u64 = 50;
+            }
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
 #[test]
 fn inner_doc_comments() {
     check(
@@ -1848,6 +1979,94 @@ fn empty_if() {
     );
 }
 
+#[test]
+fn empty_blocks_normalized() {
+    check(
+        indoc! {r#"
+        library;
+        fn test() {
+            while true {
+                if true {
+                }
+                if true { } else {
+                }
+                while true { }
+                let x = {
+                };
+                match x {
+                    _ => {
+                    },
+                }
+            }
+        }
+        "#},
+        indoc! {r#"
+        library;
+        fn test() {
+            while true {
+                if true {}
+                if true {} else {}
+                while true {}
+                let x = {};
+                match x {
+                    _ => {},
+                }
+            }
+        }
+        "#},
+    );
+}
+
+#[test]
+fn empty_blocks_with_comments() {
+    check(
+        indoc! {r#"
+        library;
+        fn test() {
+            while true {
+                if true {
+                    // then
+                }
+                if true { } else {
+                    // else
+                }
+                while true { /* loop */ }
+                let x = {
+                    // block
+                };
+                match x {
+                    _ => {
+                        // arm
+                    },
+                }
+            }
+        }
+        "#},
+        indoc! {r#"
+        library;
+        fn test() {
+            while true {
+                if true {
+                    // then
+                }
+                if true {} else {
+                    // else
+                }
+                while true { /* loop */ }
+                let x = {
+                    // block
+                };
+                match x {
+                    _ => {
+                        // arm
+                    },
+                }
+            }
+        }
+        "#},
+    );
+}
+
 #[test]
 fn bug_whitespace_added_after_comment() {
     check(
@@ -3155,3 +3374,698 @@ fn retain_in_keyword() {
         "#},
     );
 }
+
+#[test]
+fn idempotent_long_binary_op_as_single_argument() {
+    check(
+        indoc! {r#"
+        library;
+
+        fn main() {
+            assert(s.t.x == s_.t.x && s.t.y == s_.t.y && s.t.z == s_.t.z && s.t.boolean == s_.t.boolean);
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn main() {
+            assert(
+                s.t.x == s_.t.x && s.t.y == s_.t.y && s.t.z == s_.t.z && s.t.boolean == s_.t.boolean,
+            );
+        }
+        "#},
+    );
+}
+
+#[test]
+fn idempotent_struct_pattern_without_trailing_comma() {
+    check(
+        indoc! {r#"
+        library;
+
+        fn main() {
+            let x = match foo {
+                Point { x: false, y, z: (0, 0, 0) } => 1,
+                _ => 2,
+            };
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn main() {
+            let x = match foo {
+                Point {
+                    x: false,
+                    y,
+                    z: (0, 0, 0),
+                } => 1,
+                _ => 2,
+            };
+        }
+        "#},
+    );
+}
+
+#[test]
+fn idempotent_block_comment_on_its_own_line() {
+    check(
+        indoc! {r#"
+        library;
+
+        fn main() {
+            let value = 42;
+            /* Simple test */
+            assert(value == 42);
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn main() {
+            let value = 42;
+            /* Simple test */
+            assert(value == 42);
+        }
+        "#},
+    );
+}
+
+#[test]
+fn idempotent_multiline_block_comment_before_line_comments() {
+    check(
+        indoc! {r#"
+        library;
+
+        /* Some multiline
+         * block comment */
+        ////////////////////
+        // Line comment
+        ////////////////////
+        fn foo() {}
+        "#},
+        indoc! {r#"
+        library;
+
+        /* Some multiline
+         * block comment */
+        ////////////////////
+        // Line comment
+        ////////////////////
+        fn foo() {}
+        "#},
+    );
+}
+
+#[test]
+fn idempotent_single_import_group() {
+    check(
+        indoc! {r#"
+        library;
+
+        use std::{intrinsics::{size_of, size_of_val}};
+
+        struct Foo {
+            bar: bool,
+        }
+
+        fn main() -> u64 {
+            let foo_len = size_of_val(Foo { bar: true });
+            foo_len
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        use std::intrinsics::{size_of, size_of_val};
+
+        struct Foo {
+            bar: bool,
+        }
+
+        fn main() -> u64 {
+            let foo_len = size_of_val(Foo { bar: true });
+            foo_len
+        }
+        "#},
+    );
+}
+
+#[test]
+fn idempotent_associated_types() {
+    check(
+        indoc! {r#"
+        library;
+
+        trait Trait {
+            type T;
+
+            fn method(self, s1: Self::T) -> Self::T;
+        }
+
+        impl Trait for Struct {
+          type T = Struct;
+
+          fn method(self, s1: Self::T) -> Self::T {
+            s1
+          }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        trait Trait {
+            type T;
+
+            fn method(self, s1: Self::T) -> Self::T;
+        }
+
+        impl Trait for Struct {
+            type T = Struct;
+
+            fn method(self, s1: Self::T) -> Self::T {
+                s1
+            }
+        }
+        "#},
+    );
+}
+
+#[test]
+fn idempotent_commented_out_code_before_item() {
+    check(
+        indoc! {r#"
+        library;
+
+        // fn foo(p: u8) {
+        //    bar(p);
+        // }
+
+        impl Eq for [u64;2] {
+            fn eq(self, other: Self) -> bool {
+                true
+            }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        // fn foo(p: u8) {
+        //    bar(p);
+        // }
+
+        impl Eq for [u64; 2] {
+            fn eq(self, other: Self) -> bool {
+                true
+            }
+        }
+        "#},
+    );
+}
+
+#[test]
+fn idempotent_code_block_with_comment_as_struct_field() {
+    check(
+        indoc! {r#"
+        library;
+
+        fn main() {
+            let s = S {
+                field1:  {
+                    // interaction
+                    foo();
+                    42
+                },
+                field2: 1,
+            };
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn main() {
+            let s = S {
+                field1: {
+                    // interaction
+                    foo();
+                    42
+                },
+                field2: 1,
+            };
+        }
+        "#},
+    );
+}
+
+#[test]
+fn skip_const_array() {
+    check(
+        indoc! {r#"
+        library;
+
+        // swayfmt::skip
+        const IDENTITY: [u64; 9] = [
+            1, 0, 0,
+            0, 1, 0,
+            0, 0, 1,
+        ];
+
+        const   FORMATTED: [u64; 3] = [ 1,0,0 ];
+        "#},
+        indoc! {r#"
+        library;
+
+        // swayfmt::skip
+        const IDENTITY: [u64; 9] = [
+            1, 0, 0,
+            0, 1, 0,
+            0, 0, 1,
+        ];
+
+        const FORMATTED: [u64; 3] = [1, 0, 0];
+        "#},
+    );
+}
+
+#[test]
+fn skip_fn() {
+    check(
+        indoc! {r#"
+        library;
+
+        /// Returns the opcode of the instruction.
+        // swayfmt::skip
+        fn opcode(instruction: u64) -> u64 {
+            match instruction {
+                0   => 0x10, // add
+                1   => 0x11, // sub
+
+
+                255 => 0xff, // noop
+                _   => 0,
+            }
+        }
+
+        fn  formatted( ) { }
+        "#},
+        indoc! {r#"
+        library;
+
+        /// Returns the opcode of the instruction.
+        // swayfmt::skip
+        fn opcode(instruction: u64) -> u64 {
+            match instruction {
+                0   => 0x10, // add
+                1   => 0x11, // sub
+
+
+                255 => 0xff, // noop
+                _   => 0,
+            }
+        }
+
+        fn formatted() {}
+        "#},
+    );
+}
+
+#[test]
+fn skip_statements() {
+    check(
+        indoc! {r#"
+        library;
+
+        fn main() {
+            let  first = 1;
+            // swayfmt::skip
+            let table: [[u64; 3]; 2] = [
+                [  1,  20, 300],
+                [400,   5,  60],
+            ];
+            // swayfmt::skip
+            log( first   +   2 );
+                let   last = 3;
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn main() {
+            let first = 1;
+            // swayfmt::skip
+            let table: [[u64; 3]; 2] = [
+                [  1,  20, 300],
+                [400,   5,  60],
+            ];
+            // swayfmt::skip
+            log( first   +   2 );
+            let last = 3;
+        }
+        "#},
+    );
+}
+
+/// Formats every Sway source file in the repository that can be formatted and checks that
+/// formatting the formatted code again doesn't change it anymore.
+#[test]
+fn idempotent_on_repository_sources() {
+    fn collect_sway_files(dir: &std::path::Path, files: &mut Vec<std::path::PathBuf>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                collect_sway_files(&path, files);
+            } else if path.extension().is_some_and(|ext| ext == "sw") {
+                files.push(path);
+            }
+        }
+    }
+
+    let repo_root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut files = vec![];
+    for dir in [
+        "examples",
+        "sway-lib-core",
+        "sway-lib-std",
+        "test/src/e2e_vm_tests/test_programs/should_pass",
+    ] {
+        collect_sway_files(&repo_root.join(dir), &mut files);
+    }
+
+    let mut non_idempotent = vec![];
+    for path in files {
+        let unformatted = std::fs::read_to_string(&path).unwrap();
+        // Not every test program is valid Sway code, skip those that can't be formatted.
+        let Ok(first_formatted) = Formatter::default().format(Arc::from(unformatted), None) else {
+            continue;
+        };
+        let second_formatted =
+            Formatter::default().format(Arc::from(first_formatted.clone()), None);
+        if second_formatted.ok().as_ref() != Some(&first_formatted) {
+            non_idempotent.push(path);
+        }
+    }
+
+    assert!(
+        non_idempotent.is_empty(),
+        "formatting is not idempotent for:\n{}",
+        non_idempotent
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+/// The input shared by the indentation tests, which format it with different indentation settings.
+const INDENTATION_INPUT: &str = indoc! {r#"
+    contract;
+
+    use std::{hash::{Hash, sha256}, some_module::{some_long_item_name, another_long_item_name}};
+
+    struct Point { x: u64, y: u64 }
+
+    storage {
+        ns {
+            counter: u64 = 0,
+        },
+        point: Point = Point { x: 1, y: 2 },
+    }
+
+    fn compute<T>(value: u64, another_long_argument_name: u64, yet_another_long_argument_name: u64) -> u64 where T: Eq {
+        // a comment
+        let result = some_very_long_function_name(value, another_long_argument_name, yet_another_long_argument_name);
+        let x = match result { 0 => 1, _ => { let y = 2; y + 1 }, };
+        result.first_long_method_name(value).second_long_method_name().third_long_method_name_here();
+        while x > 0 { break; }
+        x
+    }
+    "#};
+
+#[test]
+fn indentation_two_spaces() {
+    let mut formatter = Formatter::default();
+    formatter.config.whitespace.tab_spaces = 2;
+    check_with_formatter(
+        INDENTATION_INPUT,
+        indoc! {r#"
+        contract;
+
+        use std::{hash::{Hash, sha256}, some_module::{another_long_item_name, some_long_item_name}};
+
+        struct Point {
+          x: u64,
+          y: u64,
+        }
+
+        storage {
+          ns {
+            counter: u64 = 0,
+          },
+          point: Point = Point { x: 1, y: 2 },
+        }
+
+        fn compute<T>(
+          value: u64,
+          another_long_argument_name: u64,
+          yet_another_long_argument_name: u64,
+        ) -> u64
+        where
+          T: Eq,
+        {
+          // a comment
+          let result = some_very_long_function_name(
+            value,
+            another_long_argument_name,
+            yet_another_long_argument_name,
+          );
+          let x = match result {
+            0 => 1,
+            _ => {
+              let y = 2;
+              y + 1
+            },
+          };
+          result
+            .first_long_method_name(value)
+            .second_long_method_name()
+            .third_long_method_name_here();
+          while x > 0 {
+            break;
+          }
+          x
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn indentation_four_spaces() {
+    let mut formatter = Formatter::default();
+    formatter.config.whitespace.tab_spaces = 4;
+    check_with_formatter(
+        INDENTATION_INPUT,
+        indoc! {r#"
+        contract;
+
+        use std::{hash::{Hash, sha256}, some_module::{another_long_item_name, some_long_item_name}};
+
+        struct Point {
+            x: u64,
+            y: u64,
+        }
+
+        storage {
+            ns {
+                counter: u64 = 0,
+            },
+            point: Point = Point { x: 1, y: 2 },
+        }
+
+        fn compute<T>(
+            value: u64,
+            another_long_argument_name: u64,
+            yet_another_long_argument_name: u64,
+        ) -> u64
+        where
+            T: Eq,
+        {
+            // a comment
+            let result = some_very_long_function_name(
+                value,
+                another_long_argument_name,
+                yet_another_long_argument_name,
+            );
+            let x = match result {
+                0 => 1,
+                _ => {
+                    let y = 2;
+                    y + 1
+                },
+            };
+            result
+                .first_long_method_name(value)
+                .second_long_method_name()
+                .third_long_method_name_here();
+            while x > 0 {
+                break;
+            }
+            x
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn indentation_hard_tabs() {
+    let mut formatter = Formatter::default();
+    formatter.config.whitespace.hard_tabs = true;
+    check_with_formatter(
+        INDENTATION_INPUT,
+        indoc! {"
+        contract;
+
+        use std::{hash::{Hash, sha256}, some_module::{another_long_item_name, some_long_item_name}};
+
+        struct Point {
+        \tx: u64,
+        \ty: u64,
+        }
+
+        storage {
+        \tns {
+        \t\tcounter: u64 = 0,
+        \t},
+        \tpoint: Point = Point { x: 1, y: 2 },
+        }
+
+        fn compute<T>(
+        \tvalue: u64,
+        \tanother_long_argument_name: u64,
+        \tyet_another_long_argument_name: u64,
+        ) -> u64
+        where
+        \tT: Eq,
+        {
+        \t// a comment
+        \tlet result = some_very_long_function_name(
+        \t\tvalue,
+        \t\tanother_long_argument_name,
+        \t\tyet_another_long_argument_name,
+        \t);
+        \tlet x = match result {
+        \t\t0 => 1,
+        \t\t_ => {
+        \t\t\tlet y = 2;
+        \t\t\ty + 1
+        \t\t},
+        \t};
+        \tresult
+        \t\t.first_long_method_name(value)
+        \t\t.second_long_method_name()
+        \t\t.third_long_method_name_here();
+        \twhile x > 0 {
+        \t\tbreak;
+        \t}
+        \tx
+        }
+        "},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn literals_preserved_by_default() {
+    check(
+        indoc! {r#"
+        library;
+
+        const A: u64 = 0xFf_aB;
+        const B: u64 = 1000000;
+        const C: u64 = 10_00_000;
+        "#},
+        indoc! {r#"
+        library;
+
+        const A: u64 = 0xFf_aB;
+        const B: u64 = 1000000;
+        const C: u64 = 10_00_000;
+        "#},
+    );
+}
+
+#[test]
+fn hex_literal_case() {
+    let mut formatter = Formatter::default();
+    formatter.config.literals.hex_literal_case = HexLiteralCase::Upper;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        const A: u64 = 0xff;
+        const B: u16 = 0xaB_cdu16;
+        const C: b256 = 0x000000000000000000000000000000000000000000000000000000000000abcd;
+        const D: u64 = 0b1010;
+        "#},
+        indoc! {r#"
+        library;
+
+        const A: u64 = 0xFF;
+        const B: u16 = 0xAB_CDu16;
+        const C: b256 = 0x000000000000000000000000000000000000000000000000000000000000ABCD;
+        const D: u64 = 0b1010;
+        "#},
+        &mut formatter,
+    );
+
+    formatter.config.literals.hex_literal_case = HexLiteralCase::Lower;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        const A: u64 = 0xFF;
+        const B: u16 = 0xaB_cdu16;
+        "#},
+        indoc! {r#"
+        library;
+
+        const A: u64 = 0xff;
+        const B: u16 = 0xab_cdu16;
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn decimal_digit_groups() {
+    let mut formatter = Formatter::default();
+    formatter.config.literals.decimal_digit_group_size = Some(3);
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn main() {
+            let a = 1000000;
+            let b = 10_00_000u64;
+            let c = 100;
+            let d = 1_0;
+            let e = 0xFFFFFF;
+            let f = 0b11111111;
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn main() {
+            let a = 1_000_000;
+            let b = 1_000_000u64;
+            let c = 100;
+            let d = 1_0;
+            let e = 0xFFFFFF;
+            let f = 0b11111111;
+        }
+        "#},
+        &mut formatter,
+    );
+}
@@ -483,6 +483,15 @@ impl TestContext {
                         .collect(),
                 };
 
+                let mut bytecode_sizes = compiled_pkgs
+                    .iter()
+                    .map(|(name, built_pkg)| (name, built_pkg.bytecode.bytes.len()))
+                    .collect::<Vec<_>>();
+                bytecode_sizes.sort();
+                for (name, size) in bytecode_sizes {
+                    output.push_str(&format!("Bytecode size: {name} {size} bytes\n"));
+                }
+
                 check_file_checker(checker, &name, output)?;
 
                 if validate_abi {
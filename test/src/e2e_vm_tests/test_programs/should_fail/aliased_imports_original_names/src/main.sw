@@ -0,0 +1,17 @@
+script;
+
+mod red;
+
+use ::red::{make as make_point, Point as RedPoint};
+use ::red::shapes as red_shapes;
+
+fn main() {
+    // Only the aliases are imported, not the original names.
+    let _ = make(1);
+    let _ = Point { x: 1 };
+    let _ = shapes::Square { side: 1 };
+
+    let _ = make_point(1);
+    let _ = RedPoint { x: 1 };
+    let _ = red_shapes::Square { side: 1 };
+}
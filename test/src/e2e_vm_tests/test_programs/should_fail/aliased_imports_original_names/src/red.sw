@@ -0,0 +1,11 @@
+library;
+
+pub mod shapes;
+
+pub struct Point {
+    pub x: u64,
+}
+
+pub fn make(x: u64) -> Point {
+    Point { x }
+}
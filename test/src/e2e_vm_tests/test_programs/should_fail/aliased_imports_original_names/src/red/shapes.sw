@@ -0,0 +1,5 @@
+library;
+
+pub struct Square {
+    pub side: u64,
+}
@@ -0,0 +1,24 @@
+script;
+
+// The same program is built with both overflow behaviors, by `overflow_behavior_trap`
+// and `overflow_behavior_wrap`.
+
+fn add_u64(a: u64, b: u64) -> u64 {
+    a + b
+}
+
+fn add_u8(a: u8, b: u8) -> u8 {
+    a + b
+}
+
+fn mul_u32(a: u32, b: u32) -> u32 {
+    a * b
+}
+
+fn main() -> u64 {
+    let a = add_u64(u64::max(), 2);
+    let b = add_u8(u8::max(), 3);
+    let c = mul_u32(u32::max(), 3);
+
+    a + b.as_u64() + c.as_u64()
+}
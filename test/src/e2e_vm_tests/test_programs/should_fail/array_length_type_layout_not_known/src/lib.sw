@@ -0,0 +1,14 @@
+library;
+
+struct S {
+    a: u64,
+}
+
+fn f<T>() {
+    // The layout of built-in types is known while parsing.
+    let _a: [u8; __size_of::<(u8, u64)>()] = [0; 16];
+
+    // The layout of generic and declared types is only known after type checking.
+    let _b: [u8; __align_of::<T>()] = [0; 8];
+    let _c: [u8; __size_of::<S>()] = [0; 8];
+}
@@ -0,0 +1,21 @@
+script;
+
+struct S {
+    ary: [u64; 2],
+}
+
+fn main() -> u64 {
+    let mut ary = [1, 2, 3];
+    let mut nested = [[1, 2], [3, 4]];
+    let mut s = S { ary: [1, 2] };
+
+    // Indices within bounds are fine.
+    ary[2] = 4;
+    nested[1][1] = 5;
+    s.ary[1] = 6;
+
+    // index out of bounds: the length is 2 but the index is 2
+    nested[1][2] = 7;
+
+    ary[0] + nested[0][0] + s.ary[0]
+}
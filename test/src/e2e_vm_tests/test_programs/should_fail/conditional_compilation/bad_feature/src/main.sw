@@ -0,0 +1,4 @@
+library;
+
+#[cfg(feature)]
+fn no_feature_name() {}
@@ -0,0 +1,4 @@
+library;
+
+#[cfg(not(test, feature = "a"))]
+fn not_with_two_predicates() {}
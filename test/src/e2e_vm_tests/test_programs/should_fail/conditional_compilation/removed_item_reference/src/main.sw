@@ -0,0 +1,10 @@
+library;
+
+#[cfg(feature = "extra")]
+pub fn extra() -> u64 {
+    42
+}
+
+pub fn uses_extra() -> u64 {
+    extra()
+}
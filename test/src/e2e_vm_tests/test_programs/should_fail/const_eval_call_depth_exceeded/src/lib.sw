@@ -0,0 +1,14 @@
+library;
+
+fn sum_up_to(n: u64) -> u64 {
+    if __eq(n, 0) {
+        0
+    } else {
+        __add(n, sum_up_to(__sub(n, 1)))
+    }
+}
+
+// Nests 11 function calls.
+pub const WITHIN_LIMIT: u64 = sum_up_to(10);
+// Nests 101 function calls, which is within the step limit but deeper than allowed.
+pub const EXCEEDING_LIMIT: u64 = sum_up_to(100);
@@ -0,0 +1,16 @@
+library;
+
+fn sum_up_to(n: u64) -> u64 {
+    let mut i = 0;
+    let mut sum = 0;
+    while __lt(i, n) {
+        i = __add(i, 1);
+        sum = __add(sum, i);
+    }
+    sum
+}
+
+// Takes 1 function call and 11 loop iterations.
+pub const WITHIN_LIMIT: u64 = sum_up_to(10);
+// Takes 1 function call and 1001 loop iterations.
+pub const EXCEEDING_LIMIT: u64 = sum_up_to(1000);
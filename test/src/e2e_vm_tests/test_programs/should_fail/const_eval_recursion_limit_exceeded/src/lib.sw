@@ -0,0 +1,14 @@
+library;
+
+fn fibonacci(n: u64) -> u64 {
+    if __lt(n, 2) {
+        n
+    } else {
+        __add(fibonacci(__sub(n, 1)), fibonacci(__sub(n, 2)))
+    }
+}
+
+// Takes 15 function calls.
+pub const WITHIN_LIMIT: u64 = fibonacci(5);
+// Takes 465 function calls.
+pub const EXCEEDING_LIMIT: u64 = fibonacci(12);
@@ -0,0 +1,17 @@
+library;
+
+fn block() -> u64 {
+    {
+        const N: u64 = 3;
+        let _ = N;
+    }
+    N
+}
+
+fn if_branch(c: bool) -> u64 {
+    if c {
+        const M: u64 = 3;
+        let _ = M;
+    }
+    M
+}
@@ -0,0 +1,9 @@
+library;
+
+fn f() {
+    {
+        const N: u64 = 3;
+        let _a: [u64; N] = [0; N];
+    }
+    let _b: [u64; N] = [0, 0, 0];
+}
@@ -0,0 +1,33 @@
+script;
+
+struct Unordered {
+    value: u64,
+}
+
+#[derive(Ord)]
+struct Wrapper {
+    ordered: u64,
+    unordered: Unordered,
+}
+
+#[derive(Ord)]
+enum Choice {
+    Ordered: u64,
+    Unordered: Unordered,
+    Tuple: (u64, u64),
+}
+
+// Type parameters are required to implement `Ord` instead of being checked.
+#[derive(Ord)]
+struct Generic<T> {
+    value: T,
+}
+
+fn main() {
+    let _ = Wrapper {
+        ordered: 1,
+        unordered: Unordered { value: 2 },
+    };
+    let _ = Choice::Ordered(1);
+    let _ = Generic { value: 1u64 };
+}
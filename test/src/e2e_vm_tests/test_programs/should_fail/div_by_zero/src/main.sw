@@ -0,0 +1,6 @@
+script;
+
+fn main() -> u64 {
+    let x = 42;
+    x / 0
+}
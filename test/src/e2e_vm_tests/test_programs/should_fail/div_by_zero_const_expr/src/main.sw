@@ -0,0 +1,8 @@
+script;
+
+const ONE: u64 = 1;
+
+fn main() -> u64 {
+    let x = 42;
+    x / (ONE * 2 - 2)
+}
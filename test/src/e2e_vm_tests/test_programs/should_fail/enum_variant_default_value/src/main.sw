@@ -0,0 +1,10 @@
+script;
+
+enum E {
+    A: u64 = 1,
+    B: (),
+}
+
+fn main() {
+    let _ = E::B;
+}
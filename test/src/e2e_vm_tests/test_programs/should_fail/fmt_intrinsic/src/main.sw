@@ -0,0 +1,16 @@
+script;
+
+fn main() {
+    let x: u64 = 1;
+    let y = 2;
+    let format = "x={}";
+
+    let _ = __fmt("x={}, y={}", x);
+    let _ = __fmt("x={}", x, y);
+    let _ = __fmt("{{x}}", x);
+    let _ = __fmt(format, x);
+    let _ = __fmt();
+    let _ = __fmt("x={}", &x);
+
+    let _ = (x, y, format);
+}
@@ -0,0 +1,18 @@
+script;
+
+struct S {}
+
+impl S {
+    fn first<T>(self, t: T) -> T {
+        t
+    }
+
+    fn second(self, t: T) -> T {
+        t
+    }
+}
+
+fn main() {
+    let _ = S {}.first(42);
+    let _ = S {}.second(42);
+}
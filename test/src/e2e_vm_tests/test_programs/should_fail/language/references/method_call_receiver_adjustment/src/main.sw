@@ -0,0 +1,46 @@
+library;
+
+struct S {
+    x: u64,
+}
+
+impl S {
+    fn inc(ref mut self) {
+        self.x = __add(self.x, 1);
+    }
+}
+
+trait ByRefMut {
+    fn by_ref_mut(self);
+}
+
+impl ByRefMut for &mut S {
+    fn by_ref_mut(self) {}
+}
+
+trait A {
+    fn ambiguous(self);
+}
+
+trait B {
+    fn ambiguous(self);
+}
+
+impl A for &S {
+    fn ambiguous(self) {}
+}
+
+impl B for &S {
+    fn ambiguous(self) {}
+}
+
+pub fn test() {
+    let s = S { x: 0 };
+
+    let r = &s;
+    r.inc();
+
+    s.by_ref_mut();
+
+    s.ambiguous();
+}
@@ -0,0 +1,28 @@
+script;
+
+fn mismatched_break_values(flag: bool) -> u64 {
+    loop {
+        if flag {
+            break 1u64;
+        }
+        break true;
+    }
+}
+
+fn break_value_in_while() {
+    while true {
+        break 5;
+    }
+}
+
+fn loop_implicit_return() -> u64 {
+    loop {
+        42
+    }
+}
+
+fn main() {
+    let _ = mismatched_break_values(true);
+    break_value_in_while();
+    let _ = loop_implicit_return();
+}
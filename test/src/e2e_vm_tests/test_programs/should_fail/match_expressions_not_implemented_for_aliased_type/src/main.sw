@@ -2,22 +2,22 @@ script;
 
 // This test proves that https://github.com/FuelLabs/sway/issues/5846 is fixed.
 
-type ArrayAlias = [u64;3];
+type StrArrayAlias = str[3];
 
 fn main() {
-    let a = [1u64, 2u64, 3u64];
-    array(a);
-    array_alias(a);
+    let a = __to_str_array("abc");
+    str_array(a);
+    str_array_alias(a);
 }
 
-fn array(array: [u64;3]) {
-    let _ = match array {
+fn str_array(str_array: str[3]) {
+    let _ = match str_array {
         _ => true,
     };
 }
 
-fn array_alias(array_alias: ArrayAlias) {
-    let _ = match array_alias {
+fn str_array_alias(str_array_alias: StrArrayAlias) {
+    let _ = match str_array_alias {
         _ => true,
     };
-}
\ No newline at end of file
+}
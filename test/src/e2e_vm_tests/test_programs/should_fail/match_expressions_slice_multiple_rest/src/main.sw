@@ -0,0 +1,8 @@
+script;
+
+fn main() {
+    let a = [1, 2, 3];
+    let _ = match a {
+        [x, .., ..] => x,
+    };
+}
@@ -0,0 +1,44 @@
+script;
+
+fn too_long(a: [u64; 2]) -> u64 {
+    match a {
+        [x, y, z] => x + y + z,
+        _ => 0,
+    }
+}
+
+fn too_short(a: [u64; 2]) -> u64 {
+    match a {
+        [x] => x,
+        _ => 0,
+    }
+}
+
+fn too_long_with_rest(a: [u64; 2]) -> u64 {
+    match a {
+        [x, .., y, z] => x + y + z,
+        _ => 0,
+    }
+}
+
+fn not_an_array(a: (u64, u64)) -> u64 {
+    match a {
+        [x, y] => x + y,
+        _ => 0,
+    }
+}
+
+fn non_exhaustive(a: [bool; 2]) -> u64 {
+    match a {
+        [true, _] => 1,
+        [_, true] => 2,
+    }
+}
+
+fn main() {
+    let _ = too_long([1, 2]);
+    let _ = too_short([1, 2]);
+    let _ = too_long_with_rest([1, 2]);
+    let _ = not_an_array((1, 2));
+    let _ = non_exhaustive([true, false]);
+}
@@ -0,0 +1,15 @@
+library;
+
+pub(in alpha) fn in_self() {}
+
+pub(in crate) fn in_crate() {}
+
+pub(in alhpa) fn in_typo() {}
+
+pub(in beta) struct InSibling {}
+
+pub(in alpha::inner) enum InChild {
+    A: (),
+}
+
+pub(in crate::beta) const IN_SIBLING: u64 = 0;
@@ -0,0 +1,6 @@
+script;
+
+mod alpha;
+mod beta;
+
+fn main() {}
@@ -0,0 +1,11 @@
+library;
+
+pub mod lib01;
+
+pub(crate) fn crate_fn() -> u64 {
+    42
+}
+
+pub fn public_fn() -> u64 {
+    lib01::lib01_crate_fn()
+}
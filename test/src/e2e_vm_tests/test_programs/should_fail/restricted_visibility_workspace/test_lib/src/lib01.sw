@@ -0,0 +1,5 @@
+library;
+
+pub(crate) fn lib01_crate_fn() -> u64 {
+    1
+}
@@ -0,0 +1,15 @@
+script;
+
+mod scoped;
+
+use test_lib::crate_fn;
+use scoped::inner::scoped_fn;
+
+fn main() {
+    let _ = crate_fn();
+    let _ = test_lib::lib01::lib01_crate_fn();
+    let _ = test_lib::public_fn();
+    let _ = scoped_fn();
+    let _ = scoped::inner::scoped_fn();
+    let _ = scoped::call_scoped_fn();
+}
@@ -0,0 +1,7 @@
+library;
+
+pub mod inner;
+
+pub fn call_scoped_fn() -> u64 {
+    inner::scoped_fn()
+}
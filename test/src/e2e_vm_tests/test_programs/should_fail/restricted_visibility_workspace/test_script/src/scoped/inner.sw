@@ -0,0 +1,5 @@
+library;
+
+pub(in scoped) fn scoped_fn() -> u64 {
+    2
+}
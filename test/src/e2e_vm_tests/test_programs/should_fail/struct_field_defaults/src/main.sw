@@ -0,0 +1,42 @@
+script;
+
+fn not_const() -> u64 {
+    asm() {
+        bal: u64
+    }
+}
+
+struct NonConstDefault {
+    x: u64 = not_const(),
+}
+
+enum Maybe<T> {
+    Nothing: (),
+    Just: T,
+}
+
+fn nothing_if_no_balance<T>() -> Maybe<T> {
+    if not_const() == 0 {
+        Maybe::Nothing
+    } else {
+        Maybe::Nothing
+    }
+}
+
+struct GenericNonConstDefault<T> {
+    value: Maybe<T> = nothing_if_no_balance(),
+}
+
+struct Required {
+    required: u64,
+    optional: u64 = 1,
+}
+
+struct MismatchedDefault {
+    x: u64 = true,
+}
+
+fn main() {
+    let _ = Required { optional: 2 };
+    let _ = Required { };
+}
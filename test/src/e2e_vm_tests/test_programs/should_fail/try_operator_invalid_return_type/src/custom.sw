@@ -0,0 +1,6 @@
+library;
+
+pub enum Option {
+    Some: u64,
+    None: (),
+}
@@ -0,0 +1,17 @@
+script;
+
+mod custom;
+
+fn returns_u64(value: Option<u64>) -> u64 {
+    value?
+}
+
+// An enum that is only named like `Option` does not support `?`.
+fn returns_custom_option(value: Option<u64>) -> custom::Option {
+    custom::Option::Some(value?)
+}
+
+fn main() {
+    let _ = returns_u64(Some(1));
+    let _ = returns_custom_option(Some(1));
+}
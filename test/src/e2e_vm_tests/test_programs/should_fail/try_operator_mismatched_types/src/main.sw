@@ -0,0 +1,14 @@
+script;
+
+fn option_in_result_fn(value: Option<u64>) -> Result<u64, u64> {
+    Ok(value?)
+}
+
+fn mismatched_error_types(value: Result<u64, bool>) -> Result<u64, u64> {
+    Ok(value?)
+}
+
+fn main() {
+    let _ = option_in_result_fn(Some(1));
+    let _ = mismatched_error_types(Ok(1));
+}
@@ -0,0 +1,39 @@
+script;
+
+trait Zero {
+    fn zero() -> Self;
+}
+
+impl Zero for u64 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+fn zero<T>() -> T
+where
+    T: Zero,
+{
+    T::zero()
+}
+
+fn pair<A, B>(a: A, b: B) -> (A, B) {
+    (a, b)
+}
+
+struct S {}
+
+impl S {
+    fn make<T>(self) -> T
+    where
+        T: Zero,
+    {
+        T::zero()
+    }
+}
+
+fn main() {
+    let _ = zero::<u64, u64>();
+    let _ = pair::<u64>(1, 2);
+    let _ = S {}.make::<u64, bool>();
+}
@@ -0,0 +1,10 @@
+predicate;
+
+fn main() -> bool {
+    let _ = __tx_input_count(0);
+    let _ = __tx_output_type();
+    let _ = __tx_output_amount::<u8>(0);
+    let _ = __tx_input_amount(true);
+    let _: u64 = __tx_input_type(0);
+    true
+}
@@ -0,0 +1,6 @@
+script;
+
+fn main() {
+    let _ = (true: u64);
+    let _ = (256: u8);
+}
@@ -0,0 +1,20 @@
+script;
+
+struct Counter {
+    value: u64,
+}
+
+fn increment(x: u64) -> u64 {
+    x
+}
+
+fn main() {
+    let counter_value = 1;
+
+    let counter: Countr = Counter { value: counter_vale };
+    let _ = incremnt(counter.value);
+
+    let _ = zzzzqqq;
+    let _ = wxyz(counter_value);
+    let _: Qwerty = counter;
+}
@@ -0,0 +1,47 @@
+script;
+
+#[cfg(feature = "extra")]
+const EXTRA: u64 = 2;
+#[cfg(not(feature = "extra"))]
+const EXTRA: u64 = 1;
+
+struct Values {
+    base: u64,
+    #[cfg(feature = "extra")]
+    extra: u64,
+}
+
+#[cfg(feature = "extra")]
+fn values() -> Values {
+    Values {
+        base: 10,
+        extra: EXTRA,
+    }
+}
+
+#[cfg(not(feature = "extra"))]
+fn values() -> Values {
+    Values { base: 10 }
+}
+
+#[cfg(any(feature = "extra", not(test)))]
+fn multiplier() -> u64 {
+    2
+}
+
+// Never compiled, so the reference to the undeclared function is not an error.
+#[cfg(all(feature = "extra", feature = "unused"))]
+fn unused() -> u64 {
+    undeclared()
+}
+
+#[cfg(feature = "extra")]
+fn main() -> u64 {
+    let values = values();
+    (values.base + values.extra) * multiplier()
+}
+
+#[cfg(not(feature = "extra"))]
+fn main() -> u64 {
+    values().base * EXTRA * multiplier()
+}
@@ -0,0 +1,32 @@
+contract;
+
+use std::hash::Hash;
+
+storage {
+    read_field: u64 = 0,
+    written_field: u64 = 0,
+    map_field: StorageMap<u64, u64> = StorageMap {},
+    key_field: u64 = 0,
+    unused_field: u64 = 0,
+    #[allow(unused_storage)]
+    allowed_unused_field: u64 = 0,
+}
+
+abi MyContract {
+    #[storage(read, write)]
+    fn foo() -> u64;
+}
+
+impl MyContract for Contract {
+    #[storage(read, write)]
+    fn foo() -> u64 {
+        storage.written_field.write(1);
+        storage.map_field.insert(1, 2);
+        read_key(storage.key_field) + storage.read_field.read()
+    }
+}
+
+#[storage(read)]
+fn read_key(key: StorageKey<u64>) -> u64 {
+    key.read()
+}
@@ -0,0 +1,23 @@
+library;
+
+pub mod shapes;
+
+pub const FACTOR: u64 = 10;
+
+pub struct Point {
+    pub x: u64,
+}
+
+pub fn make(x: u64) -> Point {
+    Point { x }
+}
+
+pub trait Describe {
+    fn describe(self) -> u64;
+}
+
+impl Describe for Point {
+    fn describe(self) -> u64 {
+        self.x * FACTOR
+    }
+}
@@ -0,0 +1,9 @@
+library;
+
+pub struct Square {
+    pub side: u64,
+}
+
+pub fn area(square: Square) -> u64 {
+    square.side * square.side * 2
+}
@@ -0,0 +1,75 @@
+script;
+
+mod red;
+mod blue;
+
+use ::red::{make as make_red, Point as RedPoint};
+use ::blue::{make as make_blue, Point as BluePoint};
+use ::red::Describe as RedDescribe;
+use ::blue::Describe as BlueDescribe;
+use ::red::shapes as red_shapes;
+use ::blue::shapes::{self as blue_shapes};
+use ::blue as azure;
+
+struct Shapes {
+    red: red_shapes::Square,
+    blue: blue_shapes::Square,
+}
+
+// Implementing both of the same-named traits for a local type requires their aliases.
+struct Local {}
+
+impl RedDescribe for Local {
+    fn describe(self) -> u64 {
+        1000
+    }
+}
+
+impl BlueDescribe for Local {
+    fn describe(self) -> u64 {
+        2000
+    }
+}
+
+fn describe_red<T>(value: T) -> u64
+where
+    T: RedDescribe,
+{
+    value.describe()
+}
+
+fn describe_blue<T>(value: T) -> u64
+where
+    T: BlueDescribe,
+{
+    value.describe()
+}
+
+fn red_area(square: red_shapes::Square) -> u64 {
+    red_shapes::area(square)
+}
+
+fn main() -> bool {
+    let red: RedPoint = make_red(1);
+    let blue: BluePoint = make_blue(2);
+    assert(red.describe() == 1);
+    assert(blue.describe() == 20);
+    assert(describe_red(Local {}) == 1000);
+    assert(describe_blue(Local {}) == 2000);
+
+    let other_red = RedPoint { x: 3 };
+    let other_blue = azure::Point { x: 4 };
+    assert(other_red.x + other_blue.x == 7);
+    assert(azure::make(5).describe() == 50);
+    assert(azure::FACTOR == 10);
+
+    let shapes = Shapes {
+        red: red_shapes::Square { side: 3 },
+        blue: blue_shapes::Square { side: 3 },
+    };
+    assert(red_area(shapes.red) == 9);
+    assert(blue_shapes::area(shapes.blue) == 18);
+    assert(azure::shapes::area(azure::shapes::Square { side: 1 }) == 2);
+
+    true
+}
@@ -0,0 +1,55 @@
+script;
+
+trait Container {
+    type Item;
+
+    fn get(self) -> Self::Item;
+}
+
+struct Number {
+    value: u64,
+}
+
+struct Flag {}
+
+impl Container for Number {
+    type Item = u64;
+
+    fn get(self) -> Self::Item {
+        self.value
+    }
+}
+
+impl Container for Flag {
+    type Item = bool;
+
+    fn get(self) -> Self::Item {
+        true
+    }
+}
+
+fn get_item<T>(container: T) -> <T as Container>::Item
+where
+    T: Container,
+{
+    container.get()
+}
+
+// Here `T` is not known when `get_item` is called, so its return type stays `<T as Container>::Item`.
+fn get_item_generic<T>(container: T) -> <T as Container>::Item
+where
+    T: Container,
+{
+    get_item(container)
+}
+
+fn main() -> u64 {
+    let flag: bool = get_item(Flag {});
+    assert(flag);
+
+    let flag: bool = get_item_generic(Flag {});
+    assert(flag);
+    assert(get_item_generic(Number { value: 7 }) == 7);
+
+    get_item(Number { value: 42 })
+}
@@ -0,0 +1,25 @@
+script;
+
+fn factorial(n: u64) -> u64 {
+    if n == 0 {
+        1
+    } else {
+        n * factorial(n - 1)
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// Functions only called from constants are evaluated at compile time, so they can call themselves.
+const FACTORIAL: u64 = factorial(5);
+const GCD: u64 = gcd(48, 18);
+
+fn main() -> u64 {
+    FACTORIAL + GCD
+}
@@ -0,0 +1,35 @@
+script;
+
+const SCALE = 2;
+
+fn main() -> u64 {
+    const LEN: u64 = 3;
+
+    let a: [u64; LEN] = [10, 11, 12];
+    let b = [1u64; LEN * SCALE + 1];
+
+    let mut total = 0;
+    let mut i = 0;
+    while i < LEN {
+        total += a[i];
+        i += 1;
+    }
+
+    {
+        // Constants declared in an inner block are only visible within that block.
+        const INNER = LEN - 1;
+        let c: [u64; INNER] = [2; INNER];
+        total += c[0] + c[INNER - 1];
+    }
+
+    {
+        // The same name can be declared again in a sibling block.
+        const INNER = (LEN + 1) % 3;
+        let d: [u64; INNER] = [1];
+        total += d[0];
+    }
+
+    assert(total == 33 + 4 + 1);
+
+    total + b[LEN * SCALE] + 3
+}
@@ -0,0 +1,27 @@
+script;
+
+trait Scale {
+    fn scale(self, factor: u64) -> u64;
+}
+
+impl Scale for u64 {
+    fn scale(self, factor: u64) -> u64 {
+        self * factor
+    }
+}
+
+fn used(x: u64) -> u64 {
+    x + 1
+}
+
+fn only_called_by_unused(x: u64) -> u64 {
+    x.scale(42)
+}
+
+fn unused(x: u64) -> u64 {
+    only_called_by_unused(x) / 3
+}
+
+fn main() -> u64 {
+    used(1)
+}
@@ -0,0 +1,9 @@
+script;
+
+fn used(x: u64) -> u64 {
+    x + 1
+}
+
+fn main() -> u64 {
+    used(1)
+}
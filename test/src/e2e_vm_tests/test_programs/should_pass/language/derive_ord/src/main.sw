@@ -0,0 +1,84 @@
+script;
+
+#[derive(Ord)]
+struct Version {
+    major: u64,
+    minor: u8,
+    patch: u32,
+}
+
+#[derive(PartialOrd)]
+struct Empty {}
+
+#[derive(Ord)]
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+// Fields of the unit type are always equal.
+#[derive(Ord)]
+struct Tagged {
+    marker: (),
+    value: u64,
+}
+
+#[derive(Ord)]
+enum Priority {
+    Low: (),
+    Medium: u64,
+    High: Version,
+}
+
+#[derive(Ord)]
+enum Either<A, B> {
+    Left: A,
+    Right: B,
+}
+
+fn version(major: u64, minor: u8, patch: u32) -> Version {
+    Version {
+        major,
+        minor,
+        patch,
+    }
+}
+
+fn main() -> bool {
+    // Structs are compared field by field, in declaration order.
+    assert(version(1, 2, 3) > version(1, 2, 2));
+    assert(version(1, 2, 3) < version(1, 3, 0));
+    assert(version(2, 0, 0) > version(1, 9, 9));
+    assert(!(version(1, 2, 3) > version(1, 2, 3)));
+    assert(!(version(1, 2, 3) < version(1, 2, 3)));
+
+    assert(!(Empty {} > Empty {}));
+    assert(!(Empty {} < Empty {}));
+
+    let a = Pair { first: 1u16, second: 5 };
+    let b = Pair { first: 1u16, second: 7 };
+    assert(a < b);
+    assert(b > a);
+
+    let one = Tagged { marker: (), value: 1 };
+    let two = Tagged { marker: (), value: 2 };
+    assert(two > one);
+    assert(one < two);
+    assert(!(one > one));
+    let _marker: () = one.marker;
+
+    // Enums are compared by variant first, and by value if the variants are the same.
+    assert(Priority::Low < Priority::Medium(0));
+    assert(Priority::High(version(0, 0, 0)) > Priority::Medium(100));
+    assert(Priority::Medium(2) > Priority::Medium(1));
+    assert(Priority::High(version(1, 0, 0)) < Priority::High(version(1, 0, 1)));
+    assert(!(Priority::Low < Priority::Low));
+    assert(!(Priority::Low > Priority::Low));
+
+    let left: Either<u64, u8> = Either::Left(100);
+    let right: Either<u64, u8> = Either::Right(1);
+    assert(left < right);
+    assert(Either::Left::<u64, u8>(2) > Either::Left(1));
+
+    true
+}
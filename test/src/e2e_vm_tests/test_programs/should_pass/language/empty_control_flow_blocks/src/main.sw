@@ -0,0 +1,26 @@
+script;
+
+fn main() -> u64 {
+    let mut a = 1;
+
+    if a == 1 {}
+
+    if a == 2 {
+        // Intentionally empty.
+    } else {
+    }
+
+    if a == 3 {
+        a = 3;
+    } else if a == 4 {
+        /* Nothing to do. */
+    } else {}
+
+    while a == 0 { }
+
+    if a == 5 {
+        loop {}
+    }
+
+    a
+}
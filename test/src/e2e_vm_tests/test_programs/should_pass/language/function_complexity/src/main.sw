@@ -0,0 +1,71 @@
+script;
+
+use std::vec::Vec;
+
+struct S {}
+
+impl S {
+    // `self` counts as a parameter.
+    fn method_with_four_parameters(self, a: u64, b: u64, c: u64) -> u64 {
+        a + b + c
+    }
+}
+
+fn three_parameters(a: u64, b: u64, c: u64) -> u64 {
+    a + b + c
+}
+
+fn four_parameters(a: u64, b: u64, c: u64, d: u64) -> u64 {
+    a + b + c + d
+}
+
+fn complexity_of_three(a: bool, b: bool) -> u64 {
+    if a {
+        1
+    } else if b {
+        2
+    } else {
+        3
+    }
+}
+
+fn complexity_of_four(a: bool, v: Vec<u64>) -> u64 {
+    let mut sum = 0;
+    if a {
+        sum += 1;
+    }
+    while sum < 10 {
+        sum += 1;
+    }
+    for x in v.iter() {
+        sum += x;
+    }
+    sum
+}
+
+fn match_with_complexity_of_three(x: u64) -> u64 {
+    match x {
+        1 => 10,
+        2 => 20,
+        _ => 30,
+    }
+}
+
+fn match_with_complexity_of_four(x: u64) -> u64 {
+    match x {
+        1 => 10,
+        2 => 20,
+        _ => if x > 100 { 40 } else { 30 },
+    }
+}
+
+fn main() -> u64 {
+    let v = Vec::new();
+    S {}.method_with_four_parameters(1, 2, 3)
+        + three_parameters(1, 2, 3)
+        + four_parameters(1, 2, 3, 4)
+        + complexity_of_three(true, false)
+        + complexity_of_four(true, v)
+        + match_with_complexity_of_three(1)
+        + match_with_complexity_of_four(1)
+}
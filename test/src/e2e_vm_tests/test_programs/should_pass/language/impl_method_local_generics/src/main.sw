@@ -0,0 +1,96 @@
+script;
+
+use std::convert::From;
+
+struct Foo {
+    value: u64,
+}
+
+struct Wrapped {
+    inner: u64,
+}
+
+impl From<u64> for Wrapped {
+    fn from(value: u64) -> Self {
+        Self { inner: value }
+    }
+}
+
+struct Doubled {
+    inner: u64,
+}
+
+impl From<u64> for Doubled {
+    fn from(value: u64) -> Self {
+        Self { inner: value * 2 }
+    }
+}
+
+impl Foo {
+    // `T` is local to this method and independent of `Foo`.
+    fn convert<T>(self) -> T
+    where
+        T: From<u64>,
+    {
+        T::from(self.value)
+    }
+
+    // Same, but the bound is declared inline.
+    fn convert_next<T: From<u64>>(self) -> T {
+        T::from(self.value + 1)
+    }
+
+    // A sibling method declaring its own, unrelated, `T`.
+    fn pick<T>(self, a: T, b: T) -> T {
+        if self.value > 10 { a } else { b }
+    }
+
+    fn value(self) -> u64 {
+        self.value
+    }
+}
+
+struct Generic<A> {
+    a: A,
+}
+
+impl<A> Generic<A> {
+    // Method-local generics next to the generics of the impl.
+    fn convert_with<T>(self, value: u64) -> (A, T)
+    where
+        T: From<u64>,
+    {
+        (self.a, T::from(value))
+    }
+}
+
+fn main() -> bool {
+    let foo = Foo { value: 21 };
+
+    let wrapped: Wrapped = foo.convert();
+    assert(wrapped.inner == 21);
+
+    let doubled: Doubled = foo.convert();
+    assert(doubled.inner == 42);
+
+    let wrapped = foo.convert::<Wrapped>();
+    assert(wrapped.inner == 21);
+
+    let doubled = foo.convert_next::<Doubled>();
+    assert(doubled.inner == 44);
+
+    assert(foo.pick(true, false));
+    assert(foo.pick(1u8, 2u8) == 1u8);
+    assert(foo.value() == 21);
+
+    let generic = Generic { a: true };
+    let (a, doubled) = generic.convert_with::<Doubled>(5);
+    assert(a);
+    assert(doubled.inner == 10);
+
+    let (a, wrapped): (bool, Wrapped) = generic.convert_with(5);
+    assert(a);
+    assert(wrapped.inner == 5);
+
+    true
+}
@@ -0,0 +1,84 @@
+script;
+
+struct Point {
+    x: u8,
+    y: u16,
+}
+
+enum Shape {
+    Dot: u8,
+    Line: (u8, u16),
+}
+
+fn id<T>(value: T) -> T {
+    value
+}
+
+fn double(value: u8) -> u8 {
+    value * 2
+}
+
+// Literals in returned struct fields take the types of the fields.
+fn make_point() -> Point {
+    Point {
+        x: 200 + 55,
+        y: id(id(1000)),
+    }
+}
+
+fn make_shape(dot: bool) -> Shape {
+    if dot {
+        Shape::Dot(1 << 7)
+    } else {
+        Shape::Line((id(2), 3 * 1000))
+    }
+}
+
+fn pick(index: u64) -> u8 {
+    match index {
+        0 => 100 + 100,
+        _ => id(255),
+    }
+}
+
+fn bytes() -> [u8; 3] {
+    [id(1), 2 + 2, !0]
+}
+
+fn last_byte() -> u8 {
+    id(id(255))
+}
+
+fn main() -> bool {
+    // Literals passed for `u8` parameters are `u8`.
+    assert_eq(double(100 + 27), 254);
+    assert_eq(double(id(100)), 200);
+
+    let point = make_point();
+    assert_eq(point.x, 255);
+    assert_eq(point.y, 1000);
+
+    match make_shape(true) {
+        Shape::Dot(x) => assert_eq(x, 128),
+        Shape::Line(_) => revert(0),
+    }
+    match make_shape(false) {
+        Shape::Dot(_) => revert(0),
+        Shape::Line((x, y)) => {
+            assert_eq(x, 2);
+            assert_eq(y, 3000);
+        }
+    }
+
+    assert_eq(pick(0), 200);
+    assert_eq(pick(1), 255);
+
+    let bytes = bytes();
+    assert_eq(bytes[0], 1);
+    assert_eq(bytes[1], 4);
+    assert_eq(bytes[2], 255);
+
+    assert_eq(last_byte(), 255);
+
+    true
+}
@@ -0,0 +1,128 @@
+script;
+
+const N = 10;
+
+enum Found {
+    Yes: u64,
+    No: (),
+}
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn first_multiple_of(n: u64, start: u64) -> u64 {
+    let mut i = start;
+    loop {
+        if i % n == 0 {
+            break i;
+        }
+        i += 1;
+    }
+}
+
+fn break_value_test() {
+    let mut i = 0;
+    let x = loop {
+        i += 1;
+        if i == N {
+            break i * 2;
+        }
+    };
+    assert(x == 2 * N);
+
+    assert(first_multiple_of(7, 15) == 21);
+    assert(first_multiple_of(5, 15) == 15);
+}
+
+fn break_without_value_test() {
+    let mut i = 0;
+    let _unit: () = loop {
+        if i >= N {
+            break;
+        }
+        i += 1;
+    };
+    assert(i == N);
+}
+
+fn break_struct_test() {
+    let mut x = 0;
+    let p = loop {
+        x += 3;
+        if x > N {
+            break Point { x, y: x * x };
+        }
+    };
+    assert(p.x == 12);
+    assert(p.y == 144);
+}
+
+fn continue_test() {
+    let mut i = 0;
+    let mut sum = 0;
+    let result = loop {
+        i += 1;
+        if i % 2 == 0 {
+            continue;
+        }
+        sum += i;
+        if i >= N {
+            break sum;
+        }
+    };
+    // 1 + 3 + 5 + 7 + 9 + 11
+    assert(result == 36);
+}
+
+fn nested_loops_test() {
+    let mut i = 0;
+    let mut inner_breaks = 0;
+    let outer = loop {
+        let inner = loop {
+            let mut j = 0;
+            while true {
+                j += 1;
+                if j == 3 {
+                    break;
+                }
+            }
+            break i + j;
+        };
+        inner_breaks += 1;
+        i += inner;
+        if i > 20 {
+            break (i, inner_breaks);
+        }
+    };
+    assert(outer.0 == 21);
+    assert(outer.1 == 3);
+}
+
+fn break_in_match_test() {
+    let mut i = 0;
+    let v = loop {
+        i += 1;
+        match i {
+            5 => break Found::Yes(i),
+            7 => break Found::No,
+            _ => {},
+        }
+    };
+    match v {
+        Found::Yes(n) => assert(n == 5),
+        Found::No => assert(false),
+    }
+}
+
+fn main() -> bool {
+    break_value_test();
+    break_without_value_test();
+    break_struct_test();
+    continue_test();
+    nested_loops_test();
+    break_in_match_test();
+
+    true
+}
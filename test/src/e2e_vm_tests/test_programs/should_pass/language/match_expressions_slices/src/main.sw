@@ -0,0 +1,89 @@
+script;
+
+fn first_and_rest(a: [u64; 3]) -> (u64, [u64; 2]) {
+    match a {
+        [head, tail @ ..] => (head, tail),
+    }
+}
+
+fn first_and_last(a: [u64; 4]) -> (u64, u64) {
+    match a {
+        [first, .., last] => (first, last),
+    }
+}
+
+fn classify(a: [u64; 3]) -> u64 {
+    match a {
+        [0, 0, 0] => 0,
+        [1, _, _] => 1,
+        [.., 2] => 2,
+        [x, 3, _] | [_, _, x] => x,
+    }
+}
+
+fn bools(a: [bool; 2]) -> u64 {
+    match a {
+        [true, true] => 3,
+        [true, false] => 2,
+        [false, true] => 1,
+        [false, false] => 0,
+    }
+}
+
+fn empty(a: [u64; 0]) -> u64 {
+    match a {
+        [] => 1,
+    }
+}
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn nested(a: [Point; 2]) -> u64 {
+    match a {
+        [Point { x: 0, y }, _] => y,
+        [_, Point { x, .. }] => x,
+    }
+}
+
+fn main() -> u64 {
+    let (head, tail) = first_and_rest([1, 2, 3]);
+    assert(head == 1);
+    assert(tail[0] == 2);
+    assert(tail[1] == 3);
+
+    let (first, last) = first_and_last([4, 5, 6, 7]);
+    assert(first == 4);
+    assert(last == 7);
+
+    assert(classify([0, 0, 0]) == 0);
+    assert(classify([1, 5, 5]) == 1);
+    assert(classify([5, 5, 2]) == 2);
+    assert(classify([7, 3, 5]) == 7);
+    assert(classify([7, 4, 5]) == 5);
+
+    assert(bools([true, true]) == 3);
+    assert(bools([false, true]) == 1);
+
+    assert(empty([]) == 1);
+
+    assert(nested([Point { x: 0, y: 8 }, Point { x: 9, y: 0 }]) == 8);
+    assert(nested([Point { x: 1, y: 8 }, Point { x: 9, y: 0 }]) == 9);
+
+    let [a, b @ .., c] = [10, 11, 12, 13];
+    assert(a == 10);
+    assert(b[0] == 11);
+    assert(b[1] == 12);
+    assert(c == 13);
+
+    let [mut x, _] = [20, 21];
+    x += 1;
+    assert(x == 21);
+
+    let [.., y]: [u64; 3] = [30, 31, 32];
+    assert(y == 32);
+
+    42
+}
@@ -0,0 +1,53 @@
+script;
+
+enum Shape {
+    Circle: u64,
+    Rectangle: (u64, u64),
+    Empty: (),
+}
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn is_square(shape: Shape) -> bool {
+    matches!(shape, Shape::Rectangle((a, b)) if a == b)
+}
+
+fn main() -> bool {
+    let some: Option<u64> = Some(42);
+    let none: Option<u64> = None;
+
+    assert(matches!(some, Some(_)));
+    assert(!matches!(none, Some(_)));
+    assert(matches!(none, None));
+
+    // Guards can use the variables bound by the pattern.
+    assert(matches!(some, Some(n) if n > 41));
+    assert(!matches!(some, Some(n) if n > 42));
+    assert(!matches!(none, Some(n) if n > 0));
+
+    // Literal and or-patterns.
+    let x = 5u64;
+    assert(matches!(x, 1 | 3 | 5));
+    assert(!matches!(x, 2 | 4));
+
+    // Struct and tuple patterns.
+    let p = Point { x: 1, y: 2 };
+    assert(matches!(p, Point { x: 1, .. }));
+    assert(matches!(p, Point { x: 1, y } if y > 1));
+    assert(!matches!(p, Point { x: 1, y } if y > 2));
+    assert(matches!((x, p.y), (5, _)));
+
+    // Enum patterns.
+    assert(is_square(Shape::Rectangle((3, 3))));
+    assert(!is_square(Shape::Rectangle((3, 4))));
+    assert(!is_square(Shape::Circle(3)));
+    assert(matches!(Shape::Empty, Shape::Empty));
+
+    // `matches!` is an ordinary boolean expression.
+    let both = matches!(some, Some(_)) && matches!(none, None);
+    assert(both);
+    if matches!(x, 5) { both } else { false }
+}
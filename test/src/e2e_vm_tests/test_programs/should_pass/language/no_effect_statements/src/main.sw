@@ -0,0 +1,46 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn is_same(a: u64, b: u64) -> bool {
+    a == b
+}
+
+fn sum(a: u64, b: u64) -> u64 {
+    a + b
+}
+
+fn checked_sum(a: u64, b: u64) -> u64 {
+    require(a < 100, "a is too large");
+    a + b
+}
+
+fn check_positive(a: u64) {
+    if a == 0 {
+        revert(42);
+    }
+}
+
+fn main() -> u64 {
+    let mut x = 1;
+    x = x;
+
+    let a = 2;
+    let b = 3;
+    let point = Point { x: a, y: b };
+
+    a == b;
+    point.x;
+    is_same(a, b);
+
+    // Arithmetic and calls that might revert are not pure, even if their values are dropped.
+    a + b;
+    sum(a, b);
+    checked_sum(a, b);
+    check_positive(a);
+
+    x + point.y - b
+}
@@ -0,0 +1,21 @@
+predicate;
+
+// The test harness runs this predicate in a transaction with a single unsigned coin
+// input of amount 1, and no outputs.
+const INPUT_COIN: u8 = 0;
+
+fn main() -> bool {
+    let input_count = __tx_input_count();
+    let mut i = 0;
+    let mut coins = 0;
+    let mut total = 0;
+    while i < input_count {
+        if __tx_input_type(i) == INPUT_COIN {
+            coins += 1;
+            total += __tx_input_amount(i);
+        }
+        i += 1;
+    }
+
+    input_count == 1 && coins == 1 && total == 1 && __tx_output_count() == 0
+}
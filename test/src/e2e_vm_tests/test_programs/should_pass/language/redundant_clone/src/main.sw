@@ -0,0 +1,39 @@
+script;
+
+use std::bytes::Bytes;
+use std::clone::Clone;
+
+impl Clone for u64 {
+    fn clone(self) -> Self {
+        self
+    }
+}
+
+impl Clone for bool {
+    fn clone(self) -> Self {
+        self
+    }
+}
+
+#[allow(redundant_clone)]
+fn clone_allowed(x: u64) -> u64 {
+    x.clone()
+}
+
+fn main() -> u64 {
+    let number: u64 = 42;
+    let cloned_number = number.clone();
+
+    let flag = true;
+    let cloned_flag = flag.clone();
+
+    let mut bytes = Bytes::new();
+    bytes.push(1u8);
+    let cloned_bytes = bytes.clone();
+
+    if cloned_flag {
+        cloned_number + cloned_bytes.len() + clone_allowed(number)
+    } else {
+        0
+    }
+}
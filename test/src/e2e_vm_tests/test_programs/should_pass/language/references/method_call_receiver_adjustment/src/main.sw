@@ -0,0 +1,97 @@
+script;
+
+struct S {
+    x: u64,
+}
+
+impl S {
+    fn get(self) -> u64 {
+        self.x
+    }
+
+    fn inc(ref mut self) {
+        self.x += 1;
+    }
+}
+
+trait ByRef {
+    fn by_ref(self) -> u64;
+}
+
+impl ByRef for &S {
+    fn by_ref(self) -> u64 {
+        self.x + 100
+    }
+}
+
+trait ByRefMut {
+    fn by_ref_mut(self);
+}
+
+impl ByRefMut for &mut S {
+    fn by_ref_mut(self) {
+        *self = S { x: self.x + 10 };
+    }
+}
+
+trait Bump {
+    fn bump(ref mut self);
+}
+
+impl Bump for u64 {
+    fn bump(ref mut self) {
+        self += 1;
+    }
+}
+
+// Methods taking `self` are called on references by dereferencing the receiver.
+fn self_via_references() {
+    let s = S { x: 1 };
+
+    let r = &s;
+    assert_eq(r.get(), 1);
+
+    let r_mut = &mut S { x: 2 };
+    assert_eq(r_mut.get(), 2);
+
+    let r_r = & &s;
+    assert_eq(r_r.get(), 1);
+}
+
+// Methods taking `ref mut self` are called on references to mutable values
+// by dereferencing the receiver, and mutate the referenced value.
+fn ref_mut_self_via_references() {
+    let mut s = S { x: 1 };
+
+    let r_mut = &mut s;
+    r_mut.inc();
+    r_mut.inc();
+    assert_eq(s.x, 3);
+
+    let mut n = 5;
+    let n_mut = &mut n;
+    n_mut.bump();
+    assert_eq(n, 6);
+}
+
+// Methods implemented for references are called on values by referencing the receiver.
+fn self_of_reference_type_via_values() {
+    let mut s = S { x: 1 };
+
+    assert_eq(s.by_ref(), 101);
+
+    s.by_ref_mut();
+    assert_eq(s.x, 11);
+
+    let r_mut = &mut s;
+    r_mut.by_ref_mut();
+    assert_eq(s.x, 21);
+}
+
+fn main() -> u64 {
+    self_via_references();
+    ref_mut_self_via_references();
+    self_of_reference_type_via_values();
+
+    42
+}
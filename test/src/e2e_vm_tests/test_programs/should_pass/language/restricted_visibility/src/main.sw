@@ -0,0 +1,25 @@
+script;
+
+mod utils;
+mod nested;
+
+use utils::{crate_fn, CrateEnum, CrateStruct, CRATE_CONST};
+
+fn main() -> bool {
+    assert(crate_fn() == 42);
+    assert(utils::crate_fn() == 42);
+    assert(CRATE_CONST == 7);
+
+    let s = CrateStruct { value: 3 };
+    assert(s.double() == 6);
+
+    let e = CrateEnum::A(5);
+    match e {
+        CrateEnum::A(value) => assert(value == 5),
+        CrateEnum::B => assert(false),
+    }
+
+    assert(nested::call_scoped() == 11);
+
+    true
+}
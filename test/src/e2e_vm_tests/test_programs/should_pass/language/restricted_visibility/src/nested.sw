@@ -0,0 +1,9 @@
+library;
+
+pub mod inner;
+
+use inner::scoped_fn;
+
+pub fn call_scoped() -> u64 {
+    scoped_fn() + inner::crate_scoped_fn()
+}
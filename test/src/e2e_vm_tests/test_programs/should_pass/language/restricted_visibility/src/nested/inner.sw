@@ -0,0 +1,9 @@
+library;
+
+pub(in nested) fn scoped_fn() -> u64 {
+    10
+}
+
+pub(in crate::nested) fn crate_scoped_fn() -> u64 {
+    1
+}
@@ -0,0 +1,22 @@
+library;
+
+pub(crate) const CRATE_CONST: u64 = 7;
+
+pub(crate) fn crate_fn() -> u64 {
+    42
+}
+
+pub(crate) struct CrateStruct {
+    pub value: u64,
+}
+
+impl CrateStruct {
+    pub(crate) fn double(self) -> u64 {
+        self.value * 2
+    }
+}
+
+pub(crate) enum CrateEnum {
+    A: u64,
+    B: (),
+}
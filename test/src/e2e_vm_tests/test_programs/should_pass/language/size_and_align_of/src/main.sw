@@ -0,0 +1,94 @@
+script;
+
+// 24 bytes, every field is right-padded to a word.
+struct S {
+    a: u8,
+    b: u64,
+    c: bool,
+}
+
+// 8 bytes, the array of bytes is right-padded to a word.
+struct Bytes {
+    bytes: [u8; 3],
+}
+
+// 32 bytes, as many as the size of `b256`.
+struct Hash {
+    bytes: [u8; __size_of::<b256>()],
+}
+
+// 40 bytes, an 8 bytes tag followed by the largest variant.
+enum E {
+    A: u8,
+    B: u64,
+    C: b256,
+}
+
+// 16 bytes, an 8 bytes tag followed by the byte variant right-padded to a word.
+enum Small {
+    A: u8,
+    B: bool,
+}
+
+const SIZE_OF_U8 = __size_of::<u8>();
+const ALIGN_OF_U8 = __align_of::<u8>();
+const SIZE_OF_S = __size_of::<S>();
+const ALIGN_OF_S = __align_of::<S>();
+const SIZE_OF_E = __size_of::<E>();
+const ALIGN_OF_E = __align_of::<E>();
+const SIZE_OF_VAL = __size_of_val(S { a: 1, b: 2, c: true });
+
+fn main() -> u64 {
+    // Primitives.
+    assert(__size_of::<u8>() == 1);
+    assert(__align_of::<u8>() == 1);
+    assert(__size_of::<bool>() == 1);
+    assert(__align_of::<bool>() == 1);
+    assert(__size_of::<u16>() == 8);
+    assert(__align_of::<u16>() == 8);
+    assert(__size_of::<u64>() == 8);
+    assert(__align_of::<u64>() == 8);
+    assert(__size_of::<u256>() == 32);
+    assert(__align_of::<u256>() == 8);
+    assert(__size_of::<b256>() == 32);
+    assert(__align_of::<b256>() == 8);
+    assert(__size_of_val(42u8) == 1);
+
+    // Arrays are packed and have the alignment of their elements.
+    assert(__size_of::<[u8; 3]>() == 3);
+    assert(__align_of::<[u8; 3]>() == 1);
+    assert(__size_of::<[u64; 3]>() == 24);
+    assert(__align_of::<[u64; 3]>() == 8);
+
+    // Structs.
+    assert(__size_of::<S>() == 24);
+    assert(__align_of::<S>() == 8);
+    assert(__size_of::<Bytes>() == 8);
+    assert(__align_of::<Bytes>() == 8);
+    assert(__size_of_val(S { a: 1, b: 2, c: true }) == 24);
+
+    // Enums.
+    assert(__size_of::<E>() == 40);
+    assert(__align_of::<E>() == 8);
+    assert(__size_of::<Small>() == 16);
+    assert(__align_of::<Small>() == 8);
+    assert(__size_of_val(E::A(1)) == 40);
+
+    // Array lengths.
+    let words: [u8; __size_of::<u64>()] = [0; 8];
+    assert(__size_of_val(words) == 8);
+    let pairs: [u8; __size_of::<(u8, u64)>() + __align_of::<[bool; 2]>()] = [0; 17];
+    assert(__size_of_val(pairs) == 17);
+    assert(__size_of::<Hash>() == 32);
+
+    // Constants.
+    assert(SIZE_OF_U8 == 1);
+    assert(ALIGN_OF_U8 == 1);
+    assert(SIZE_OF_S == 24);
+    assert(ALIGN_OF_S == 8);
+    assert(SIZE_OF_E == 40);
+    assert(ALIGN_OF_E == 8);
+    assert(SIZE_OF_VAL == 24);
+
+    1
+}
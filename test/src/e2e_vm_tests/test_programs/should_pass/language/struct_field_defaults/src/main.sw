@@ -0,0 +1,78 @@
+script;
+
+const DEFAULT_LIMIT: u64 = 100;
+
+struct Config {
+    owner: u64,
+    limit: u64 = DEFAULT_LIMIT,
+    ratio: u8 = 6,
+    enabled: bool = true,
+    point: Point = Point { x: 1, y: 2 },
+    pair: (u64, bool) = (7, false),
+}
+
+struct Point {
+    x: u64,
+    y: u64 = 42,
+}
+
+struct Wrapper<T> {
+    value: T,
+    count: u64 = 3,
+}
+
+enum Maybe<T> {
+    Nothing: (),
+    Just: T,
+}
+
+// Defaults of generic fields are checked to be constant as well.
+struct GenericDefault<T> {
+    value: Maybe<T> = Maybe::Nothing,
+}
+
+fn main() -> bool {
+    let all_defaults = Config { owner: 1 };
+    assert(all_defaults.owner == 1);
+    assert(all_defaults.limit == 100);
+    assert(all_defaults.ratio == 6);
+    assert(all_defaults.enabled);
+    assert(all_defaults.point.x == 1 && all_defaults.point.y == 2);
+    assert(all_defaults.pair.0 == 7 && !all_defaults.pair.1);
+
+    let overridden = Config {
+        enabled: false,
+        owner: 2,
+        limit: 5,
+    };
+    assert(overridden.owner == 2);
+    assert(overridden.limit == 5);
+    assert(overridden.ratio == 6);
+    assert(!overridden.enabled);
+
+    let point = Point { x: 10 };
+    assert(point.x == 10 && point.y == 42);
+
+    let wrapper = Wrapper { value: true };
+    assert(wrapper.value && wrapper.count == 3);
+
+    let generic_default = GenericDefault::<u64> {};
+    match generic_default.value {
+        Maybe::Nothing => (),
+        Maybe::Just(_) => assert(false),
+    }
+
+    // Defaults only apply to instantiations. Struct patterns still
+    // have to list the fields they match or ignore them with `..`.
+    let Config { owner, .. } = all_defaults;
+    assert(owner == 1);
+    let matched = match point {
+        Point { x: 10, .. } => true,
+        _ => false,
+    };
+    assert(matched);
+    let Point { x, y } = Point { x: 5 };
+    assert(x == 5 && y == 42);
+
+    true
+}
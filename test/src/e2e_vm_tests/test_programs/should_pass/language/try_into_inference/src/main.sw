@@ -0,0 +1,30 @@
+script;
+
+use std::convert::ConversionError;
+
+fn narrow(value: u64) -> Result<u8, ConversionError> {
+    // The target type is inferred from the return type.
+    value.try_into()
+}
+
+fn is_u16(value: Result<u16, ConversionError>) -> bool {
+    value.is_ok()
+}
+
+fn main() -> bool {
+    // The target type is inferred from the type ascription.
+    let fits: Result<u32, ConversionError> = 42u64.try_into();
+    assert(fits == Ok(42u32));
+
+    let overflows: Result<u32, ConversionError> = (u32::max().as_u64() + 1).try_into();
+    assert(overflows == Err(ConversionError::Overflow));
+
+    assert(narrow(255) == Ok(255u8));
+    assert(narrow(256) == Err(ConversionError::Overflow));
+
+    // The target type is inferred from the type of the function parameter.
+    assert(is_u16(u16::max().as_u64().try_into()));
+    assert(!is_u16((u16::max().as_u64() + 1).try_into()));
+
+    true
+}
@@ -0,0 +1,104 @@
+script;
+
+enum Error {
+    DivisionByZero: (),
+    Overflow: u64,
+}
+
+struct Wrapper {
+    value: Option<u64>,
+}
+
+impl Wrapper {
+    fn inner(self) -> Option<u64> {
+        self.value
+    }
+}
+
+fn checked_div(a: u64, b: u64) -> Result<u64, Error> {
+    if b == 0 {
+        return Err(Error::DivisionByZero);
+    }
+    Ok(a / b)
+}
+
+fn checked_add(a: u64, b: u64) -> Result<u64, Error> {
+    if a > 100 {
+        return Err(Error::Overflow(a));
+    }
+    Ok(a + b)
+}
+
+fn div_then_add(a: u64, b: u64, c: u64) -> Result<u64, Error> {
+    let quotient = checked_div(a, b)?;
+    Ok(checked_add(quotient, c)?)
+}
+
+type DivResult = Result<u64, Error>;
+
+// The return type can also be an alias of `Option` or `Result`.
+fn div_aliased(a: u64, b: u64) -> DivResult {
+    Ok(checked_div(a, b)? + 1)
+}
+
+fn first_plus_second(first: Option<u64>, second: Option<u64>) -> Option<u64> {
+    Some(first? + second?)
+}
+
+fn wrapped_plus_one(wrapper: Option<Wrapper>) -> Option<u64> {
+    Some(wrapper?.inner()? + 1)
+}
+
+// `?` only returns early on the failure path, so code after it must not run.
+fn count_until_none(values: [Option<u64>; 3], ref mut counter: u64) -> Option<()> {
+    let mut i = 0;
+    while i < 3 {
+        let _ = values[i]?;
+        counter += 1;
+        i += 1;
+    }
+    Some(())
+}
+
+fn main() -> bool {
+    // Unwrapping paths.
+    assert(first_plus_second(Some(1), Some(2)) == Some(3));
+    assert(wrapped_plus_one(Some(Wrapper { value: Some(41) })) == Some(42));
+    match div_then_add(10, 2, 3) {
+        Ok(value) => assert(value == 8),
+        Err(_) => assert(false),
+    }
+
+    match div_aliased(10, 2) {
+        Ok(value) => assert(value == 6),
+        Err(_) => assert(false),
+    }
+
+    // Propagating paths.
+    assert(first_plus_second(None, Some(2)).is_none());
+    assert(first_plus_second(Some(1), None).is_none());
+    assert(wrapped_plus_one(None).is_none());
+    assert(wrapped_plus_one(Some(Wrapper { value: None })).is_none());
+    match div_then_add(10, 0, 3) {
+        Err(Error::DivisionByZero) => (),
+        _ => assert(false),
+    }
+    match div_aliased(10, 0) {
+        Err(Error::DivisionByZero) => (),
+        _ => assert(false),
+    }
+    match div_then_add(1000, 2, 3) {
+        Err(Error::Overflow(value)) => assert(value == 500),
+        _ => assert(false),
+    }
+
+    let mut counter = 0;
+    assert(count_until_none([Some(1), Some(2), Some(3)], counter).is_some());
+    assert(counter == 3);
+
+    let mut counter = 0;
+    assert(count_until_none([Some(1), None, Some(3)], counter).is_none());
+    assert(counter == 1);
+
+    true
+}
@@ -0,0 +1,56 @@
+script;
+
+// Types shadowing the `Option` and `Result` from the prelude. The `?` operator
+// still unwraps and returns the `Option` and `Result` from `std`.
+enum Option {
+    Some: u64,
+    None: (),
+}
+
+struct Result {
+    value: u64,
+}
+
+fn first_plus_second(
+    first: std::option::Option<u64>,
+    second: std::option::Option<u64>,
+) -> std::option::Option<u64> {
+    std::option::Option::Some(first? + second?)
+}
+
+fn checked_div(a: u64, b: u64) -> std::result::Result<u64, bool> {
+    if b == 0 {
+        return std::result::Result::Err(true);
+    }
+    std::result::Result::Ok(a / b)
+}
+
+fn div_plus_one(a: u64, b: u64) -> std::result::Result<u64, bool> {
+    std::result::Result::Ok(checked_div(a, b)? + 1)
+}
+
+fn main() -> bool {
+    let some = std::option::Option::Some(1);
+    let none = std::option::Option::None;
+    assert(first_plus_second(some, some).unwrap() == 2);
+    assert(first_plus_second(some, none).is_none());
+
+    match div_plus_one(10, 2) {
+        std::result::Result::Ok(value) => assert(value == 6),
+        std::result::Result::Err(_) => assert(false),
+    }
+    match div_plus_one(10, 0) {
+        std::result::Result::Err(error) => assert(error),
+        std::result::Result::Ok(_) => assert(false),
+    }
+
+    // The local types are still the ones referred to by their names.
+    let local_option = Option::Some(3);
+    let local_result = Result { value: 4 };
+    match local_option {
+        Option::Some(value) => assert(value + local_result.value == 7),
+        Option::None => assert(false),
+    }
+
+    true
+}
@@ -0,0 +1,72 @@
+script;
+
+trait Max {
+    fn max() -> Self;
+}
+
+impl Max for u8 {
+    fn max() -> Self {
+        255
+    }
+}
+
+impl Max for u16 {
+    fn max() -> Self {
+        65535
+    }
+}
+
+impl Max for bool {
+    fn max() -> Self {
+        true
+    }
+}
+
+// `T` only appears in the return type, so it cannot be inferred from the arguments.
+fn max<T>() -> T
+where
+    T: Max,
+{
+    T::max()
+}
+
+fn is_max<T>(value: T) -> bool
+where
+    T: Max + Eq,
+{
+    value == max::<T>()
+}
+
+struct Limits {}
+
+impl Limits {
+    fn get<T>(self) -> T
+    where
+        T: Max,
+    {
+        T::max()
+    }
+}
+
+fn main() -> bool {
+    // The type arguments fix the returned type without an annotation on the variable.
+    let a = max::<u8>();
+    let b = max::<u16>();
+    assert_eq(a, 255);
+    assert_eq(b, 65535);
+    assert(max::<bool>());
+
+    // The returned value can be used directly, where no annotation is possible.
+    assert_eq(max::<u8>().as_u64() + 1, 256);
+
+    // Type arguments can themselves be type parameters.
+    assert(is_max::<u8>(255));
+    assert(!is_max::<u16>(255));
+
+    // Methods take type arguments as well.
+    let limits = Limits {};
+    assert_eq(limits.get::<u16>(), 65535);
+    assert(limits.get::<bool>());
+
+    true
+}
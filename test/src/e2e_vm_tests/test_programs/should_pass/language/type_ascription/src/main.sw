@@ -0,0 +1,67 @@
+script;
+
+use std::convert::ConversionError;
+
+trait Width {
+    fn width(self) -> u64;
+}
+
+impl Width for u8 {
+    fn width(self) -> u64 {
+        8
+    }
+}
+
+impl Width for u64 {
+    fn width(self) -> u64 {
+        64
+    }
+}
+
+trait Zero {
+    fn zero() -> Self;
+}
+
+impl Zero for u8 {
+    fn zero() -> u8 {
+        0
+    }
+}
+
+impl Zero for u64 {
+    fn zero() -> u64 {
+        0
+    }
+}
+
+fn zero<T>() -> T
+where
+    T: Zero,
+{
+    T::zero()
+}
+
+fn main() -> bool {
+    // The ascription resolves the type of a numeric literal.
+    assert((1: u8).width() == 8);
+    assert((1 : u64).width() == 64);
+    let x = (255: u8);
+    assert(x.width() == 8);
+
+    // The ascription resolves the type returned by a generic function.
+    assert((zero(): u8).width() == 8);
+    assert((zero(): u64).width() == 64);
+
+    // The ascription resolves the types of a generic method call.
+    let small = (300u64.try_into(): Result<u8, ConversionError>);
+    assert(small.is_err());
+    assert((7u64.try_into(): Result<u8, ConversionError>).unwrap().width() == 8);
+
+    // The ascription can be nested in other expressions.
+    let sum = (1: u8) + (2: u8);
+    assert(sum.width() == 8);
+    let v = (Vec::new(): Vec<u8>);
+    assert(v.len() == 0);
+
+    true
+}
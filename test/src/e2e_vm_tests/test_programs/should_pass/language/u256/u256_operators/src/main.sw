@@ -160,7 +160,12 @@ fn should_revert_on_underflow() -> u256 {
 #[test(should_revert)]
 fn should_revert_on_div_zero() -> u256 {
     let a = 0x0000000000000000000000000000000000000000000000000000000000000000u256;
-    a / 0x0000000000000000000000000000000000000000000000000000000000000000u256
+    // Division by a constant zero is a compile error, so the divisor is passed to `divide`.
+    divide(a, 0x0000000000000000000000000000000000000000000000000000000000000000u256)
+}
+
+fn divide(a: u256, b: u256) -> u256 {
+    a / b
 }
 
 #[test]
@@ -0,0 +1,23 @@
+library;
+
+pub fn double(x: u64) -> u64 {
+    x * 2
+}
+
+#[cfg(test)]
+fn doubled_values() -> [u64; 3] {
+    [double(1), double(2), double(3)]
+}
+
+#[cfg(not(test))]
+fn doubled_values() -> u64 {
+    0
+}
+
+#[test]
+fn test_doubled_values() {
+    let values = doubled_values();
+    assert(values[0] == 2);
+    assert(values[1] == 4);
+    assert(values[2] == 6);
+}
@@ -0,0 +1,18 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn main() {}
+
+#[test]
+fn test_fn() {
+    let a = 10;
+    let b = true;
+    log(__fmt("a={}, b={}", a, b));
+    log(__fmt("{{point}}: {}", Point { x: 1, y: 2 }));
+    log(__fmt("no arguments"));
+    assert_eq(a, 10)
+}
@@ -13,7 +13,7 @@ fn b256_try_from_bytes() {
     }
     let res1 = b256::try_from(initial_bytes);
     let expected1 = 0x3333333333333333333333333333333333333333333333333333333333333333;
-    assert(res1.is_some());
+    assert(res1.is_ok());
     assert(res1.unwrap() == expected1);
 
     let mut second_bytes = Bytes::with_capacity(33);
@@ -24,7 +24,7 @@ fn b256_try_from_bytes() {
         i += 1;
     }
     let res2 = b256::try_from(second_bytes);
-    assert(res2.is_none());
+    assert(res2.is_err());
 
     // bytes is still available to use:
     assert(second_bytes.len() == 33);
@@ -38,21 +38,21 @@ fn b256_try_from_bytes() {
         i += 1;
     }
     let res3 = b256::try_from(third_bytes);
-    assert(res3.is_none());
+    assert(res3.is_err());
 }
 
 #[test]
 fn b256_try_from_b512() {
     let b512_value = B512::new();
     let b256_value = b256::try_from(b512_value);
-    assert(b256_value.is_some());
+    assert(b256_value.is_ok());
 
     let b512_value = B512::from((
         0x0000000000000000000000000000000000000000000000000000000000000001,
         b256::zero(),
     ));
     let b256_value = b256::try_from(b512_value);
-    assert(b256_value.is_none());
+    assert(b256_value.is_err());
 }
 
 #[test]
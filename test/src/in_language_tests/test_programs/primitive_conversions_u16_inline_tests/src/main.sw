@@ -44,15 +44,15 @@ fn u16_try_from_u32() {
     let u16_3 = u16::try_from(u32_3);
     let u16_4 = u16::try_from(u32_4);
 
-    assert(u16_1.is_some());
+    assert(u16_1.is_ok());
     assert(u16_1.unwrap() == 0u16);
 
-    assert(u16_2.is_some());
+    assert(u16_2.is_ok());
     assert(u16_2.unwrap() == u16::max());
 
-    assert(u16_3.is_none());
+    assert(u16_3.is_err());
 
-    assert(u16_4.is_some());
+    assert(u16_4.is_ok());
     assert(u16_4.unwrap() == 2u16);
 }
 
@@ -68,16 +68,16 @@ fn u16_try_from_u64() {
     let u16_3 = u16::try_from(u64_3);
     let u16_4 = u16::try_from(u64_4);
 
-    assert(u16_1.is_some());
+    assert(u16_1.is_ok());
     assert(u16_1.unwrap() == 0u16);
 
-    assert(u16_2.is_some());
+    assert(u16_2.is_ok());
     assert(u16_2.unwrap() == 2u16);
 
-    assert(u16_3.is_some());
+    assert(u16_3.is_ok());
     assert(u16_3.unwrap() == u16::max());
 
-    assert(u16_4.is_none());
+    assert(u16_4.is_err());
 }
 
 #[test]
@@ -92,16 +92,16 @@ fn u16_try_from_u256() {
     let u16_3 = u16::try_from(u256_3);
     let u16_4 = u16::try_from(u256_4);
 
-    assert(u16_1.is_some());
+    assert(u16_1.is_ok());
     assert(u16_1.unwrap() == 0u16);
 
-    assert(u16_2.is_some());
+    assert(u16_2.is_ok());
     assert(u16_2.unwrap() == 2u16);
 
-    assert(u16_3.is_some());
+    assert(u16_3.is_ok());
     assert(u16_3.unwrap() == u16::max());
 
-    assert(u16_4.is_none());
+    assert(u16_4.is_err());
 }
 
 #[test]
@@ -116,14 +116,14 @@ fn u16_try_from_u128() {
     let u16_3 = u16::try_from(u128_3);
     let u16_4 = u16::try_from(u128_4);
 
-    assert(u16_1.is_some());
+    assert(u16_1.is_ok());
     assert(u16_1.unwrap() == 0u16);
 
-    assert(u16_2.is_some());
+    assert(u16_2.is_ok());
     assert(u16_2.unwrap() == 2u16);
 
-    assert(u16_3.is_some());
+    assert(u16_3.is_ok());
     assert(u16_3.unwrap() == u16::max());
 
-    assert(u16_4.is_none());
+    assert(u16_4.is_err());
 }
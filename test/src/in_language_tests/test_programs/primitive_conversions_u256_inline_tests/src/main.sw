@@ -289,23 +289,23 @@ fn u256_try_from_b512() {
     let u256_3 = u256::try_from(b512_3);
     let u256_4 = u256::try_from(b512_4);
 
-    assert(u256_1.is_some());
+    assert(u256_1.is_ok());
     assert(
         u256_1
             .unwrap() == 0x0000000000000000000000000000000000000000000000000000000000000000_u256,
     );
 
-    assert(u256_2.is_some());
+    assert(u256_2.is_ok());
     assert(
         u256_2
             .unwrap() == 0x0000000000000000000000000000000000000000000000000000000000000002_u256,
     );
 
-    assert(u256_3.is_some());
+    assert(u256_3.is_ok());
     assert(
         u256_3
             .unwrap() == 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF_u256,
     );
 
-    assert(u256_4.is_none());
+    assert(u256_4.is_err());
 }
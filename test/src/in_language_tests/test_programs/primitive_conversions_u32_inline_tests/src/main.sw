@@ -74,16 +74,16 @@ fn u32_try_from_u64() {
     let u32_3 = u32::try_from(u64_3);
     let u32_4 = u32::try_from(u64_4);
 
-    assert(u32_1.is_some());
+    assert(u32_1.is_ok());
     assert(u32_1.unwrap() == 0u32);
 
-    assert(u32_2.is_some());
+    assert(u32_2.is_ok());
     assert(u32_2.unwrap() == 2u32);
 
-    assert(u32_3.is_some());
+    assert(u32_3.is_ok());
     assert(u32_3.unwrap() == u32::max());
 
-    assert(u32_4.is_none());
+    assert(u32_4.is_err());
 }
 
 #[test]
@@ -98,16 +98,16 @@ fn u32_try_from_u256() {
     let u32_3 = u32::try_from(u256_3);
     let u32_4 = u32::try_from(u256_4);
 
-    assert(u32_1.is_some());
+    assert(u32_1.is_ok());
     assert(u32_1.unwrap() == 0u32);
 
-    assert(u32_2.is_some());
+    assert(u32_2.is_ok());
     assert(u32_2.unwrap() == 2u32);
 
-    assert(u32_3.is_some());
+    assert(u32_3.is_ok());
     assert(u32_3.unwrap() == u32::max());
 
-    assert(u32_4.is_none());
+    assert(u32_4.is_err());
 }
 
 #[test]
@@ -122,14 +122,14 @@ fn u32_try_from_u128() {
     let u32_3 = u32::try_from(u128_3);
     let u32_4 = u32::try_from(u128_4);
 
-    assert(u32_1.is_some());
+    assert(u32_1.is_ok());
     assert(u32_1.unwrap() == 0u32);
 
-    assert(u32_2.is_some());
+    assert(u32_2.is_ok());
     assert(u32_2.unwrap() == 2u32);
 
-    assert(u32_3.is_some());
+    assert(u32_3.is_ok());
     assert(u32_3.unwrap() == u32::max());
 
-    assert(u32_4.is_none());
+    assert(u32_4.is_err());
 }
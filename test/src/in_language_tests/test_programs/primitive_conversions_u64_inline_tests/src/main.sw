@@ -104,16 +104,16 @@ fn u64_try_from_u256() {
     let u64_3 = u64::try_from(u256_3);
     let u64_4 = u64::try_from(u256_4);
 
-    assert(u64_1.is_some());
+    assert(u64_1.is_ok());
     assert(u64_1.unwrap() == 0);
 
-    assert(u64_2.is_some());
+    assert(u64_2.is_ok());
     assert(u64_2.unwrap() == 2);
 
-    assert(u64_3.is_some());
+    assert(u64_3.is_ok());
     assert(u64_3.unwrap() == u64::max());
 
-    assert(u64_4.is_none());
+    assert(u64_4.is_err());
 }
 
 #[test]
@@ -128,14 +128,14 @@ fn u64_try_from_u128() {
     let u64_3 = u64::try_from(u128_3);
     let u64_4 = u64::try_from(u128_4);
 
-    assert(u64_1.is_some());
+    assert(u64_1.is_ok());
     assert(u64_1.unwrap() == 0u64);
 
-    assert(u64_2.is_some());
+    assert(u64_2.is_ok());
     assert(u64_2.unwrap() == 2u64);
 
-    assert(u64_3.is_some());
+    assert(u64_3.is_ok());
     assert(u64_3.unwrap() == u64::max());
 
-    assert(u64_4.is_none());
+    assert(u64_4.is_err());
 }
@@ -1,6 +1,6 @@
 library;
 
-use std::{primitive_conversions::u8::*, u128::U128};
+use std::{convert::ConversionError, primitive_conversions::u8::*, u128::U128};
 
 #[test]
 fn u8_try_from_u16() {
@@ -14,16 +14,16 @@ fn u8_try_from_u16() {
     let u8_3 = u8::try_from(u16_3);
     let u8_4 = u8::try_from(u16_4);
 
-    assert(u8_1.is_some());
+    assert(u8_1.is_ok());
     assert(u8_1.unwrap() == u8::min());
 
-    assert(u8_2.is_some());
+    assert(u8_2.is_ok());
     assert(u8_2.unwrap() == 2u8);
 
-    assert(u8_3.is_some());
+    assert(u8_3.is_ok());
     assert(u8_3.unwrap() == u8::max());
 
-    assert(u8_4.is_none());
+    assert(u8_4.is_err());
 }
 
 #[test]
@@ -38,16 +38,16 @@ fn u8_try_from_u32() {
     let u8_3 = u8::try_from(u32_3);
     let u8_4 = u8::try_from(u32_4);
 
-    assert(u8_1.is_some());
+    assert(u8_1.is_ok());
     assert(u8_1.unwrap() == u8::min());
 
-    assert(u8_2.is_some());
+    assert(u8_2.is_ok());
     assert(u8_2.unwrap() == 2u8);
 
-    assert(u8_3.is_some());
+    assert(u8_3.is_ok());
     assert(u8_3.unwrap() == u8::max());
 
-    assert(u8_4.is_none());
+    assert(u8_4.is_err());
 }
 
 #[test]
@@ -62,16 +62,16 @@ fn u8_try_from_u64() {
     let u8_3 = u8::try_from(u64_3);
     let u8_4 = u8::try_from(u64_4);
 
-    assert(u8_1.is_some());
+    assert(u8_1.is_ok());
     assert(u8_1.unwrap() == u8::min());
 
-    assert(u8_2.is_some());
+    assert(u8_2.is_ok());
     assert(u8_2.unwrap() == 2u8);
 
-    assert(u8_3.is_some());
+    assert(u8_3.is_ok());
     assert(u8_3.unwrap() == u8::max());
 
-    assert(u8_4.is_none());
+    assert(u8_4.is_err());
 }
 
 #[test]
@@ -86,16 +86,16 @@ fn u8_try_from_u256() {
     let u8_3 = u8::try_from(u256_3);
     let u8_4 = u8::try_from(u256_4);
 
-    assert(u8_1.is_some());
+    assert(u8_1.is_ok());
     assert(u8_1.unwrap() == u8::min());
 
-    assert(u8_2.is_some());
+    assert(u8_2.is_ok());
     assert(u8_2.unwrap() == 2u8);
 
-    assert(u8_3.is_some());
+    assert(u8_3.is_ok());
     assert(u8_3.unwrap() == u8::max());
 
-    assert(u8_4.is_none());
+    assert(u8_4.is_err());
 }
 
 #[test]
@@ -110,14 +110,23 @@ fn u8_try_from_u128() {
     let u8_3 = u8::try_from(u128_3);
     let u8_4 = u8::try_from(u128_4);
 
-    assert(u8_1.is_some());
+    assert(u8_1.is_ok());
     assert(u8_1.unwrap() == 0u8);
 
-    assert(u8_2.is_some());
+    assert(u8_2.is_ok());
     assert(u8_2.unwrap() == 2u8);
 
-    assert(u8_3.is_some());
+    assert(u8_3.is_ok());
     assert(u8_3.unwrap() == u8::max());
 
-    assert(u8_4.is_none());
+    assert(u8_4.is_err());
+}
+
+#[test]
+fn u8_try_into_from_u64() {
+    let u8_1: Result<u8, ConversionError> = u8::max().as_u64().try_into();
+    let u8_2: Result<u8, ConversionError> = (u8::max().as_u64() + 1).try_into();
+
+    assert(u8_1 == Ok(u8::max()));
+    assert(u8_2 == Err(ConversionError::Overflow));
 }
@@ -276,7 +276,7 @@ pub(super) async fn run(
                 let include_tests = true;
                 let mut ir = compile_program(typed_program, include_tests, &engines, sway_core::ExperimentalFlags {
                     new_encoding: experimental.new_encoding,
-                })
+                }, None)
                     .unwrap_or_else(|e| {
                         use sway_types::span::Spanned;
                         let e = e[0].clone();
@@ -542,6 +542,7 @@ fn compile_core(
         terse_mode: true,
         disable_tests: false,
         locked: false,
+        frozen: false,
         ipfs_node: None,
         no_encoding_v1: !experimental.new_encoding,
     };
@@ -0,0 +1,9 @@
+script;
+
+fn main() {
+    let _ = __align_of::<[u8; 3]>();
+    let _ = __align_of::<b256>();
+}
+
+// check: $VAL = const u64 1
+// check: $VAL = const u64 8
@@ -0,0 +1,18 @@
+script;
+
+fn main() -> u64 {
+    let mut a = [5, 6, 7];
+    a[2] = 4;
+    a[2]
+}
+
+// ::check-ir::
+
+// check: local mut [u64; 3] a
+
+// check: $(idx_val=$VAL) = const u64 2, $MD
+// check: $(elem_ptr=$VAL) = get_elem_ptr $VAL, ptr u64, $idx_val, $MD
+// check: $(new_val=$VAL) = const u64 4, $MD
+// check: store $new_val to $elem_ptr, $MD
+// not: cbr
+// check: ret u64 $VAL
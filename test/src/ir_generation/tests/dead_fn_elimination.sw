@@ -0,0 +1,66 @@
+script;
+
+trait Scale {
+    fn scale(self, factor: u64) -> u64;
+}
+
+impl Scale for u64 {
+    fn scale(self, factor: u64) -> u64 {
+        self * factor
+    }
+}
+
+fn used(x: u64) -> u64 {
+    x + 1
+}
+
+fn only_called_by_unused(x: u64) -> u64 {
+    x.scale(42)
+}
+
+fn unused(x: u64) -> u64 {
+    only_called_by_unused(x) / 3
+}
+
+fn only_called_in_dead_branch(x: u64) -> u64 {
+    x - 7
+}
+
+fn main() -> u64 {
+    let x = used(1);
+    if false {
+        only_called_in_dead_branch(x)
+    } else {
+        x
+    }
+}
+
+// ::check-ir::
+
+// check: fn main() -> u64
+// check: fn used_0
+// check: fn only_called_in_dead_branch
+// not: unused
+// not: scale
+
+// The call in the dead branch is only removed by the optimizations, which leave the function it
+// calls in the module.
+
+// ::check-ir-optimized::
+// pass: const-folding
+// pass: simplify-cfg
+
+// check: fn main() -> u64
+// not: call only_called_in_dead_branch
+// check: fn used_0
+
+// ::check-asm::
+
+// not: start of function: unused
+// not: start of function: only_called_by_unused
+// not: start of function: scale
+// not: call unused
+// not: call only_called_by_unused
+// not: call scale
+// not: mul
+// not: div